@@ -84,7 +84,9 @@ from daft.io import (
     read_deltalake,
     read_hudi,
     read_iceberg,
+    read_ipc,
     read_json,
+    read_orc,
     read_parquet,
     read_sql,
     read_lance,
@@ -94,6 +96,7 @@ from daft.session import Session, current_session, set_session
 from daft.sql import sql, sql_expr
 from daft.udf import udf
 from daft.viz import register_viz_hook
+from daft.window import Window
 
 to_struct = Expression.to_struct
 
@@ -111,6 +114,7 @@ __all__ = [
     "Series",
     "Session",
     "TimeUnit",
+    "Window",
     "coalesce",
     "col",
     "current_session",
@@ -130,8 +134,10 @@ __all__ = [
     "read_deltalake",
     "read_hudi",
     "read_iceberg",
+    "read_ipc",
     "read_json",
     "read_lance",
+    "read_orc",
     "read_parquet",
     "read_sql",
     "read_table",
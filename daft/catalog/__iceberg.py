@@ -3,7 +3,7 @@
 from __future__ import annotations
 
 import warnings
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Literal
 
 from pyiceberg.catalog import Catalog as InnerCatalog
 from pyiceberg.table import Table as InnerTable
@@ -83,3 +83,6 @@ class IcebergTable(Table):
         import daft
 
         return daft.read_iceberg(self._inner)
+
+    def write(self, df: DataFrame, mode: Literal["append", "overwrite"] = "append") -> None:
+        df.write_iceberg(self._inner, mode=mode)
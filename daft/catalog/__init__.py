@@ -48,7 +48,7 @@ from daft.logical.builder import LogicalPlanBuilder
 
 from daft.dataframe import DataFrame
 
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Literal
 
 from daft.logical.schema import Schema
 
@@ -331,6 +331,10 @@ class Table(ABC):
     def read(self) -> DataFrame:
         """Returns a DataFrame from this table."""
 
+    def write(self, df: DataFrame, mode: Literal["append", "overwrite"] = "append") -> None:
+        """Writes the DataFrame to this table, either appending to or overwriting its rows."""
+        raise NotImplementedError(f"Table {type(self).__name__} does not support writes.")
+
     # TODO deprecated catalog APIs #3819
     def to_dataframe(self) -> DataFrame:
         """DEPRECATED: Please use `read` instead; version 0.5.0!"""
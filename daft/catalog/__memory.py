@@ -2,7 +2,7 @@
 
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Literal
 
 from daft.catalog import Catalog, Identifier, Table
 
@@ -54,3 +54,9 @@ class MemoryTable(Table):
 
     def read(self) -> DataFrame:
         return self._inner
+
+    def write(self, df: DataFrame, mode: Literal["append", "overwrite"] = "append") -> None:
+        if mode == "overwrite":
+            self._inner = df
+        else:
+            self._inner = self._inner.concat(df)
@@ -3,7 +3,7 @@
 from __future__ import annotations
 
 import warnings
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Literal
 
 from daft.catalog import Catalog, Identifier, Table
 from daft.unity_catalog import UnityCatalog as InnerCatalog  # noqa: TID253
@@ -103,3 +103,6 @@ class UnityTable(Table):
         import daft
 
         return daft.read_deltalake(self._inner)
+
+    def write(self, df: DataFrame, mode: Literal["append", "overwrite"] = "append") -> None:
+        df.write_deltalake(self._inner, mode=mode)
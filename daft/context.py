@@ -180,6 +180,11 @@ def set_execution_config(
     pre_shuffle_merge_threshold: int | None = None,
     enable_ray_tracing: bool | None = None,
     scantask_splitting_level: int | None = None,
+    native_parquet_writer: bool | None = None,
+    native_csv_writer: bool | None = None,
+    grouped_aggregate_spill_threshold: int | None = None,
+    hash_join_spill_threshold: int | None = None,
+    recursive_cte_max_iterations: int | None = None,
 ) -> DaftContext:
     """Globally sets various configuration parameters which control various aspects of Daft execution.
 
@@ -225,6 +230,11 @@ def set_execution_config(
         pre_shuffle_merge_threshold: Memory threshold in bytes for pre-shuffle merge. Defaults to 1GB
         enable_ray_tracing: Enable tracing for Ray. Accessible in `/tmp/ray/session_latest/logs/daft` after the run completes. Defaults to False.
         scantask_splitting_level: How aggressively to split scan tasks. Setting this to `2` will use a more aggressive ScanTask splitting algorithm which might be more expensive to run but results in more even splits of partitions. Defaults to 1.
+        native_parquet_writer: Whether to write Parquet files with the native Rust writer instead of PyArrow. Defaults to False.
+        native_csv_writer: Whether to write CSV files with the native Rust writer instead of PyArrow. Defaults to False.
+        grouped_aggregate_spill_threshold: Memory budget in bytes for the state of grouped aggregations on the Native Runner, shared across workers. Once exceeded, aggregation state is hash partitioned and spilled to disk. Defaults to 4GiB.
+        hash_join_spill_threshold: Memory budget in bytes for the build side of hash joins on the Native Runner. Once exceeded, both sides of the join are hash partitioned to disk and joined one partition at a time. Defaults to 4GiB.
+        recursive_cte_max_iterations: Maximum number of times the recursive term of a recursive CTE is evaluated on the Native Runner before the query fails. Defaults to 100.
     """
     # Replace values in the DaftExecutionConfig with user-specified overrides
     ctx = get_context()
@@ -257,6 +267,11 @@ def set_execution_config(
             pre_shuffle_merge_threshold=pre_shuffle_merge_threshold,
             enable_ray_tracing=enable_ray_tracing,
             scantask_splitting_level=scantask_splitting_level,
+            native_parquet_writer=native_parquet_writer,
+            native_csv_writer=native_csv_writer,
+            grouped_aggregate_spill_threshold=grouped_aggregate_spill_threshold,
+            hash_join_spill_threshold=hash_join_spill_threshold,
+            recursive_cte_max_iterations=recursive_cte_max_iterations,
         )
 
         ctx._ctx._daft_execution_config = new_daft_execution_config
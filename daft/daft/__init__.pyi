@@ -17,6 +17,7 @@ if TYPE_CHECKING:
     from pyiceberg.schema import Schema as IcebergSchema
     from pyiceberg.table import TableProperties as IcebergTableProperties
 
+    from daft.dataframe.dataframe import DataFrame
     from daft.runners.runner import Runner
 
 class ImageMode(Enum):
@@ -126,6 +127,22 @@ class JoinSide(Enum):
     Left: int
     Right: int
 
+class AsofJoinDirection(Enum):
+    """Which right-side row an ASOF join matches to each left-side row."""
+
+    Backward: int
+    Forward: int
+    Nearest: int
+
+    @staticmethod
+    def from_asof_join_direction_str(direction: str) -> AsofJoinDirection:
+        """Create an AsofJoinDirection from its string representation.
+
+        Args:
+            direction: String representation of the direction, i.e. "backward", "forward", or "nearest".
+        """
+        ...
+
 class CountMode(Enum):
     """Supported count modes for Daft's count aggregation.
 
@@ -180,6 +197,8 @@ class FileFormat(Enum):
     Parquet: int
     Csv: int
     Json: int
+    Orc: int
+    Ipc: int
 
     def ext(self): ...
 
@@ -225,6 +244,22 @@ class CsvSourceConfig:
         chunk_size: int | None = None,
     ): ...
 
+class CsvWriteOptions:
+    """Options for writing CSV files."""
+
+    delimiter: str
+    quote: str
+    header: bool
+    null_value: str
+
+    def __init__(
+        self,
+        delimiter: str = ",",
+        quote: str = '"',
+        header: bool = True,
+        null_value: str = "",
+    ): ...
+
 class JsonSourceConfig:
     """Configuration of a JSON data source."""
 
@@ -237,6 +272,21 @@ class JsonSourceConfig:
         chunk_size: int | None = None,
     ): ...
 
+class OrcSourceConfig:
+    """Configuration of an ORC data source."""
+
+    chunk_size: int | None
+
+    def __init__(
+        self,
+        chunk_size: int | None = None,
+    ): ...
+
+class IpcSourceConfig:
+    """Configuration of an Arrow IPC data source."""
+
+    def __init__(self): ...
+
 class DatabaseSourceConfig:
     """Configuration of a database data source."""
 
@@ -248,7 +298,7 @@ class DatabaseSourceConfig:
 class FileFormatConfig:
     """Configuration for parsing a particular file format (Parquet, CSV, JSON)."""
 
-    config: ParquetSourceConfig | CsvSourceConfig | JsonSourceConfig | DatabaseSourceConfig
+    config: ParquetSourceConfig | CsvSourceConfig | JsonSourceConfig | OrcSourceConfig | IpcSourceConfig | DatabaseSourceConfig
 
     @staticmethod
     def from_parquet_config(config: ParquetSourceConfig) -> FileFormatConfig:
@@ -265,6 +315,16 @@ class FileFormatConfig:
         """Create a JSON file format config."""
         ...
 
+    @staticmethod
+    def from_orc_config(config: OrcSourceConfig) -> FileFormatConfig:
+        """Create an ORC file format config."""
+        ...
+
+    @staticmethod
+    def from_ipc_config(config: IpcSourceConfig) -> FileFormatConfig:
+        """Create an Arrow IPC file format config."""
+        ...
+
     @staticmethod
     def from_database_config(config: DatabaseSourceConfig) -> FileFormatConfig:
         """Create a database file format config."""
@@ -954,6 +1014,16 @@ class PyExpr:
     def agg_list(self) -> PyExpr: ...
     def agg_set(self) -> PyExpr: ...
     def agg_concat(self) -> PyExpr: ...
+    def over(
+        self,
+        partition_by: list[PyExpr],
+        order_by: list[PyExpr],
+        descending: list[bool],
+        nulls_first: list[bool],
+        frame: tuple[str, int | None, int | None] | None = None,
+    ) -> PyExpr: ...
+    def lag(self, offset: int, default: PyExpr | None = None) -> PyExpr: ...
+    def lead(self, offset: int, default: PyExpr | None = None) -> PyExpr: ...
     def __add__(self, other: PyExpr) -> PyExpr: ...
     def __sub__(self, other: PyExpr) -> PyExpr: ...
     def __mul__(self, other: PyExpr) -> PyExpr: ...
@@ -1000,6 +1070,9 @@ class PyExpr:
     def _input_mapping(self) -> builtins.str | None: ...
 
 def eq(expr1: PyExpr, expr2: PyExpr) -> bool: ...
+def row_number() -> PyExpr: ...
+def rank() -> PyExpr: ...
+def dense_rank() -> PyExpr: ...
 def unresolved_col(name: str) -> PyExpr: ...
 def resolved_col(name: str) -> PyExpr: ...
 def lit(item: Any) -> PyExpr: ...
@@ -1087,12 +1160,14 @@ class SQLFunctionStub:
     @property
     def arg_names(self) -> list[str]: ...
 
-def sql(sql: str, catalog: PyCatalog, daft_planning_config: PyDaftPlanningConfig) -> LogicalPlanBuilder: ...
+def sql(
+    sql: str, catalog: PyCatalog, daft_planning_config: PyDaftPlanningConfig, session: PySession | None = None
+) -> LogicalPlanBuilder: ...
 def sql_expr(sql: str) -> PyExpr: ...
 def list_sql_functions() -> list[SQLFunctionStub]: ...
 def utf8_count_matches(expr: PyExpr, patterns: PyExpr, whole_words: bool, case_sensitive: bool) -> PyExpr: ...
 def struct(inputs: list[PyExpr]) -> PyExpr: ...
-def connect_start(addr: str = "sc://0.0.0.0:0") -> ConnectionHandle: ...
+def connect_start(addr: str = "sc://0.0.0.0:0", session: PySession | None = None) -> ConnectionHandle: ...
 
 class ConnectionHandle:
     def shutdown(self) -> None: ...
@@ -1161,6 +1236,8 @@ def dt_month(expr: PyExpr) -> PyExpr: ...
 def dt_year(expr: PyExpr) -> PyExpr: ...
 def dt_day_of_week(expr: PyExpr) -> PyExpr: ...
 def dt_truncate(expr: PyExpr, interval: str, relative_to: PyExpr) -> PyExpr: ...
+def dt_convert_timezone(expr: PyExpr, tz: str) -> PyExpr: ...
+def dt_replace_timezone(expr: PyExpr, tz: str | None = None) -> PyExpr: ...
 
 # ---
 # expr.list namespace
@@ -1226,6 +1303,7 @@ class PyCatalog:
     @staticmethod
     def new() -> PyCatalog: ...
     def register_table(self, name: str, logical_plan_builder: LogicalPlanBuilder) -> None: ...
+    def register_table_function(self, name: str, func: Callable[..., Any]) -> None: ...
     def copy_from(self, other: PyCatalog) -> None: ...
 
 class PySeries:
@@ -1403,7 +1481,7 @@ class PyRecordBatch:
         right_on: list[PyExpr],
         is_sorted: bool,
     ) -> PyRecordBatch: ...
-    def explode(self, to_explode: list[PyExpr]) -> PyRecordBatch: ...
+    def explode(self, to_explode: list[PyExpr], index_column: str | None = None) -> PyRecordBatch: ...
     def head(self, num: int) -> PyRecordBatch: ...
     def sample_by_fraction(self, fraction: float, with_replacement: bool, seed: int | None) -> PyRecordBatch: ...
     def sample_by_size(self, size: int, with_replacement: bool, seed: int | None) -> PyRecordBatch: ...
@@ -1488,7 +1566,7 @@ class PyMicroPartition:
         right: PyMicroPartition,
         outer_loop_side: JoinSide,
     ) -> PyMicroPartition: ...
-    def explode(self, to_explode: list[PyExpr]) -> PyMicroPartition: ...
+    def explode(self, to_explode: list[PyExpr], index_column: str | None = None) -> PyMicroPartition: ...
     def unpivot(
         self,
         ids: list[PyExpr],
@@ -1620,7 +1698,7 @@ class LogicalPlanBuilder:
     def exclude(self, to_exclude: list[str]) -> LogicalPlanBuilder: ...
     def filter(self, predicate: PyExpr) -> LogicalPlanBuilder: ...
     def limit(self, limit: int, eager: bool) -> LogicalPlanBuilder: ...
-    def explode(self, to_explode: list[PyExpr]) -> LogicalPlanBuilder: ...
+    def explode(self, to_explode: list[PyExpr], index_column: str | None = None) -> LogicalPlanBuilder: ...
     def unpivot(
         self,
         ids: list[PyExpr],
@@ -1640,6 +1718,9 @@ class LogicalPlanBuilder:
     def distinct(self) -> LogicalPlanBuilder: ...
     def sample(self, fraction: float, with_replacement: bool, seed: int | None) -> LogicalPlanBuilder: ...
     def aggregate(self, agg_exprs: list[PyExpr], groupby_exprs: list[PyExpr]) -> LogicalPlanBuilder: ...
+    def aggregate_grouping_sets(
+        self, agg_exprs: list[PyExpr], grouping_sets: list[list[PyExpr]], grouping_id_column: str
+    ) -> LogicalPlanBuilder: ...
     def pivot(
         self,
         groupby_exprs: list[PyExpr],
@@ -1658,6 +1739,18 @@ class LogicalPlanBuilder:
         prefix: str | None = None,
         suffix: str | None = None,
     ) -> LogicalPlanBuilder: ...
+    def asof_join(
+        self,
+        right: LogicalPlanBuilder,
+        left_by: list[PyExpr],
+        right_by: list[PyExpr],
+        left_on: PyExpr,
+        right_on: PyExpr,
+        direction: AsofJoinDirection,
+        tolerance: PyExpr | None = None,
+        prefix: str | None = None,
+        suffix: str | None = None,
+    ) -> LogicalPlanBuilder: ...
     def concat(self, other: LogicalPlanBuilder) -> LogicalPlanBuilder: ...
     def intersect(self, other: LogicalPlanBuilder, is_all: bool) -> LogicalPlanBuilder: ...
     def except_(self, other: LogicalPlanBuilder, is_all: bool) -> LogicalPlanBuilder: ...
@@ -1669,6 +1762,7 @@ class LogicalPlanBuilder:
         partition_cols: list[PyExpr] | None = None,
         compression: str | None = None,
         io_config: IOConfig | None = None,
+        csv_options: CsvWriteOptions | None = None,
     ) -> LogicalPlanBuilder: ...
     def iceberg_write(
         self,
@@ -1754,6 +1848,11 @@ class PyDaftExecutionConfig:
         shuffle_algorithm: str | None = None,
         pre_shuffle_merge_threshold: int | None = None,
         scantask_splitting_level: int | None = None,
+        native_parquet_writer: bool | None = None,
+        native_csv_writer: bool | None = None,
+        grouped_aggregate_spill_threshold: int | None = None,
+        hash_join_spill_threshold: int | None = None,
+        recursive_cte_max_iterations: int | None = None,
     ) -> PyDaftExecutionConfig: ...
     @property
     def scan_tasks_min_size_bytes(self) -> int: ...
@@ -1801,6 +1900,16 @@ class PyDaftExecutionConfig:
     def pre_shuffle_merge_threshold(self) -> int: ...
     @property
     def enable_ray_tracing(self) -> bool: ...
+    @property
+    def native_parquet_writer(self) -> bool: ...
+    @property
+    def native_csv_writer(self) -> bool: ...
+    @property
+    def grouped_aggregate_spill_threshold(self) -> int: ...
+    @property
+    def hash_join_spill_threshold(self) -> int: ...
+    @property
+    def recursive_cte_max_iterations(self) -> int: ...
 
 class PyDaftPlanningConfig:
     @staticmethod
@@ -1869,6 +1978,7 @@ class PyIdentifier:
 class PyTable(Table):
     def read(self):
         LogicalPlanBuilder
+    def write(self, df: DataFrame, mode: Literal["append", "overwrite"] = "append") -> None: ...
 
 class PyTableSource:
     @staticmethod
@@ -1889,6 +1999,7 @@ class PySession:
     def detach_table(self, alias: str): ...
     def create_temp_table(self, name: str, source: PyTableSource, replace: bool): ...
     def current_catalog(self) -> Catalog: ...
+    def current_namespace(self) -> list[str]: ...
     def get_catalog(self, name: str) -> Catalog: ...
     def get_table(self, name: PyIdentifier) -> Table: ...
     def has_catalog(self, name: str) -> bool: ...
@@ -1896,3 +2007,4 @@ class PySession:
     def list_catalogs(self, pattern: None | str = None) -> list[str]: ...
     def list_tables(self, pattern: None | str = None) -> list[PyIdentifier]: ...
     def set_catalog(self, name: str): ...
+    def set_namespace(self, namespace: list[str]): ...
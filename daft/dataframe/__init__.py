@@ -1,5 +1,5 @@
 from __future__ import annotations
 
-from .dataframe import DataFrame, GroupedDataFrame
+from .dataframe import DataFrame, GroupedDataFrame, GroupingSetsDataFrame
 
-__all__ = ["DataFrame", "GroupedDataFrame"]
+__all__ = ["DataFrame", "GroupedDataFrame", "GroupingSetsDataFrame"]
@@ -5,6 +5,7 @@
 # For technical details, see https://github.com/Eventual-Inc/Daft/pull/630
 
 import io
+import itertools
 import multiprocessing
 import os
 import pathlib
@@ -33,7 +34,7 @@ from typing import (
 from daft.api_annotations import DataframePublicAPI
 from daft.context import get_context
 from daft.convert import InputListType
-from daft.daft import FileFormat, IOConfig, JoinStrategy, JoinType
+from daft.daft import AsofJoinDirection, CsvWriteOptions, FileFormat, IOConfig, JoinStrategy, JoinType
 from daft.dataframe.preview import DataFramePreview
 from daft.datatype import DataType
 from daft.errors import ExpressionTypeError
@@ -699,6 +700,10 @@ class DataFrame:
         write_mode: Literal["append", "overwrite", "overwrite-partitions"] = "append",
         partition_cols: Optional[List[ColumnInputType]] = None,
         io_config: Optional[IOConfig] = None,
+        delimiter: Optional[str] = None,
+        quote: Optional[str] = None,
+        header: Optional[bool] = None,
+        null_value: Optional[str] = None,
     ) -> "DataFrame":
         """Writes the DataFrame as CSV files, returning a new DataFrame with paths to the files that were written.
 
@@ -712,6 +717,10 @@ class DataFrame:
             write_mode (str, optional): Operation mode of the write. `append` will add new data, `overwrite` will replace the contents of the root directory with new data. `overwrite-partitions` will replace only the contents in the partitions that are being written to. Defaults to "append".
             partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
             io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+            delimiter (Optional[str], optional): The character delimiting individual cells. Defaults to ",".
+            quote (Optional[str], optional): The character used to quote cells that need it. Defaults to '"'.
+            header (Optional[bool], optional): Whether to write a header row with the column names. Defaults to True.
+            null_value (Optional[str], optional): The string written in place of null values. Defaults to "".
 
         Returns:
             DataFrame: The filenames that were written out as strings.
@@ -725,6 +734,15 @@ class DataFrame:
 
         io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
 
+        csv_options: Optional[CsvWriteOptions] = None
+        if any(opt is not None for opt in (delimiter, quote, header, null_value)):
+            csv_options = CsvWriteOptions(
+                delimiter="," if delimiter is None else delimiter,
+                quote='"' if quote is None else quote,
+                header=True if header is None else header,
+                null_value="" if null_value is None else null_value,
+            )
+
         cols: Optional[List[Expression]] = None
         if partition_cols is not None:
             cols = self.__column_input_to_expression(tuple(partition_cols))
@@ -733,6 +751,83 @@ class DataFrame:
             partition_cols=cols,
             file_format=FileFormat.Csv,
             io_config=io_config,
+            csv_options=csv_options,
+        )
+
+        # Block and write, then retrieve data
+        write_df = DataFrame(builder)
+        write_df.collect()
+        assert write_df._result is not None
+
+        if write_mode == "overwrite":
+            overwrite_files(write_df, root_dir, io_config, False)
+        elif write_mode == "overwrite-partitions":
+            overwrite_files(write_df, root_dir, io_config, True)
+
+        if len(write_df) > 0:
+            # Populate and return a new disconnected DataFrame
+            result_df = DataFrame(write_df._builder)
+            result_df._result_cache = write_df._result_cache
+            result_df._preview = write_df._preview
+            return result_df
+        else:
+            from daft import from_pydict
+            from daft.recordbatch.recordbatch_io import write_empty_tabular
+
+            file_path = write_empty_tabular(
+                root_dir, FileFormat.Csv, self.schema(), io_config=io_config, csv_options=csv_options
+            )
+
+            return from_pydict(
+                {
+                    "path": [file_path],
+                }
+            )
+
+    @DataframePublicAPI
+    def write_json(
+        self,
+        root_dir: Union[str, pathlib.Path],
+        write_mode: Literal["append", "overwrite", "overwrite-partitions"] = "append",
+        partition_cols: Optional[List[ColumnInputType]] = None,
+        io_config: Optional[IOConfig] = None,
+    ) -> "DataFrame":
+        """Writes the DataFrame as newline-delimited JSON files, returning a new DataFrame with paths to the files that were written.
+
+        Files will be written to ``<root_dir>/*`` with randomly generated UUIDs as the file names.
+
+        .. NOTE::
+            This call is **blocking** and will execute the DataFrame when called
+
+        .. NOTE::
+            Writing JSON is only supported on the native runner.
+
+        Args:
+            root_dir (str): root file path to write JSON files to.
+            write_mode (str, optional): Operation mode of the write. `append` will add new data, `overwrite` will replace the contents of the root directory with new data. `overwrite-partitions` will replace only the contents in the partitions that are being written to. Defaults to "append".
+            partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
+            io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+
+        Returns:
+            DataFrame: The filenames that were written out as strings.
+        """
+        if write_mode not in ["append", "overwrite", "overwrite-partitions"]:
+            raise ValueError(
+                f"Only support `append`, `overwrite`, or `overwrite-partitions` mode. {write_mode} is unsupported"
+            )
+        if write_mode == "overwrite-partitions" and partition_cols is None:
+            raise ValueError("Partition columns must be specified to use `overwrite-partitions` mode.")
+
+        io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+        cols: Optional[List[Expression]] = None
+        if partition_cols is not None:
+            cols = self.__column_input_to_expression(tuple(partition_cols))
+        builder = self._builder.write_tabular(
+            root_dir=root_dir,
+            partition_cols=cols,
+            file_format=FileFormat.Json,
+            io_config=io_config,
         )
 
         # Block and write, then retrieve data
@@ -755,7 +850,86 @@ class DataFrame:
             from daft import from_pydict
             from daft.recordbatch.recordbatch_io import write_empty_tabular
 
-            file_path = write_empty_tabular(root_dir, FileFormat.Csv, self.schema(), io_config=io_config)
+            file_path = write_empty_tabular(root_dir, FileFormat.Json, self.schema(), io_config=io_config)
+
+            return from_pydict(
+                {
+                    "path": [file_path],
+                }
+            )
+
+    @DataframePublicAPI
+    def write_ipc(
+        self,
+        root_dir: Union[str, pathlib.Path],
+        compression: Optional[str] = None,
+        write_mode: Literal["append", "overwrite", "overwrite-partitions"] = "append",
+        partition_cols: Optional[List[ColumnInputType]] = None,
+        io_config: Optional[IOConfig] = None,
+    ) -> "DataFrame":
+        """Writes the DataFrame as Arrow IPC files (also known as Feather v2 files), returning a new DataFrame with paths to the files that were written.
+
+        Files will be written to ``<root_dir>/*`` with randomly generated UUIDs as the file names.
+
+        .. NOTE::
+            This call is **blocking** and will execute the DataFrame when called
+
+        .. NOTE::
+            Writing Arrow IPC files is only supported on the native runner.
+
+        Args:
+            root_dir (str): root file path to write Arrow IPC files to.
+            compression (str, optional): compression codec of the record batch buffers, either `lz4` or `zstd`. Defaults to None, which writes uncompressed files.
+            write_mode (str, optional): Operation mode of the write. `append` will add new data, `overwrite` will replace the contents of the root directory with new data. `overwrite-partitions` will replace only the contents in the partitions that are being written to. Defaults to "append".
+            partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
+            io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+
+        Returns:
+            DataFrame: The filenames that were written out as strings.
+        """
+        if write_mode not in ["append", "overwrite", "overwrite-partitions"]:
+            raise ValueError(
+                f"Only support `append`, `overwrite`, or `overwrite-partitions` mode. {write_mode} is unsupported"
+            )
+        if write_mode == "overwrite-partitions" and partition_cols is None:
+            raise ValueError("Partition columns must be specified to use `overwrite-partitions` mode.")
+
+        io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+        cols: Optional[List[Expression]] = None
+        if partition_cols is not None:
+            cols = self.__column_input_to_expression(tuple(partition_cols))
+        builder = self._builder.write_tabular(
+            root_dir=root_dir,
+            partition_cols=cols,
+            file_format=FileFormat.Ipc,
+            io_config=io_config,
+            compression=compression,
+        )
+
+        # Block and write, then retrieve data
+        write_df = DataFrame(builder)
+        write_df.collect()
+        assert write_df._result is not None
+
+        if write_mode == "overwrite":
+            overwrite_files(write_df, root_dir, io_config, False)
+        elif write_mode == "overwrite-partitions":
+            overwrite_files(write_df, root_dir, io_config, True)
+
+        if len(write_df) > 0:
+            # Populate and return a new disconnected DataFrame
+            result_df = DataFrame(write_df._builder)
+            result_df._result_cache = write_df._result_cache
+            result_df._preview = write_df._preview
+            return result_df
+        else:
+            from daft import from_pydict
+            from daft.recordbatch.recordbatch_io import write_empty_tabular
+
+            file_path = write_empty_tabular(
+                root_dir, FileFormat.Ipc, self.schema(), compression=compression, io_config=io_config
+            )
 
             return from_pydict(
                 {
@@ -2053,6 +2227,120 @@ class DataFrame:
         )
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def join_asof(
+        self,
+        other: "DataFrame",
+        on: Optional[ColumnInputType] = None,
+        left_on: Optional[ColumnInputType] = None,
+        right_on: Optional[ColumnInputType] = None,
+        by: Optional[Union[List[ColumnInputType], ColumnInputType]] = None,
+        left_by: Optional[Union[List[ColumnInputType], ColumnInputType]] = None,
+        right_by: Optional[Union[List[ColumnInputType], ColumnInputType]] = None,
+        direction: Literal["backward", "forward", "nearest"] = "backward",
+        tolerance: Optional[Any] = None,
+        prefix: Optional[str] = None,
+        suffix: Optional[str] = None,
+    ) -> "DataFrame":
+        """ASOF join of the current DataFrame with an ``other`` DataFrame, matching on the nearest key rather than an equal key.
+
+        Each row of the current DataFrame is joined to at most one row of ``other``: among the rows of ``other`` with equal ``by`` keys,
+        the one whose ``on`` key is nearest to that of the left row in the given ``direction``. Like a left join, every row of the current
+        DataFrame is kept, with nulls for the columns of ``other`` if there is no match.
+
+        - ``"backward"`` matches the last row whose ``on`` key is less than or equal to the left key.
+        - ``"forward"`` matches the first row whose ``on`` key is greater than or equal to the left key.
+        - ``"nearest"`` matches the row whose ``on`` key is closest to the left key, preferring the backward match on ties.
+
+        Conflicting column names are handled as in :meth:`DataFrame.join`.
+
+        .. NOTE::
+            ASOF joins are currently only supported on the native runner.
+
+        Example:
+            >>> import daft
+            >>> df1 = daft.from_pydict({"time": [1, 5, 10], "a": ["x", "y", "z"]})
+            >>> df2 = daft.from_pydict({"time": [2, 3, 7], "b": [10, 20, 30]})
+            >>> df1.join_asof(df2, on="time").sort("time").show()
+            ╭───────┬──────┬───────╮
+            │ time  ┆ a    ┆ b     │
+            │ ---   ┆ ---  ┆ ---   │
+            │ Int64 ┆ Utf8 ┆ Int64 │
+            ╞═══════╪══════╪═══════╡
+            │ 1     ┆ x    ┆ None  │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ 5     ┆ y    ┆ 20    │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+            │ 10    ┆ z    ┆ 30    │
+            ╰───────┴──────┴───────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Args:
+            other (DataFrame): the right DataFrame to join on.
+            on (Optional[ColumnInputType], optional): key to match on, if it is the same on both sides. Defaults to None.
+            left_on (Optional[ColumnInputType], optional): key to match on in the left DataFrame. Defaults to None.
+            right_on (Optional[ColumnInputType], optional): key to match on in the right DataFrame. Defaults to None.
+            by (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): keys that must be equal for rows to match,
+                if they are the same on both sides. Defaults to None.
+            left_by (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): keys that must be equal for rows to match
+                in the left DataFrame. Defaults to None.
+            right_by (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): keys that must be equal for rows to match
+                in the right DataFrame. Defaults to None.
+            direction (str, optional): which row to match; "backward", "forward", or "nearest". Defaults to "backward".
+            tolerance (Optional[Any], optional): the maximum distance between the ``on`` keys of matched rows. Defaults to None.
+            prefix (Optional[str], optional): Prefix to add to the column names in case of a name collision. Defaults to "right.".
+            suffix (Optional[str], optional): Suffix to add to the column names in case of a name collision. Defaults to "".
+
+        Raises:
+            ValueError: if `on` is passed in and `left_on` or `right_on` is not None, or likewise for `by`.
+            ValueError: if `on` is None but `left_on` and `right_on` are not both defined.
+
+        Returns:
+            DataFrame: Joined DataFrame.
+        """
+        if on is None:
+            if left_on is None or right_on is None:
+                raise ValueError("If `on` is None then both `left_on` and `right_on` must not be None")
+        else:
+            if left_on is not None or right_on is not None:
+                raise ValueError("If `on` is not None then both `left_on` and `right_on` must be None")
+            left_on = on
+            right_on = on
+
+        if by is None:
+            if (left_by is None) != (right_by is None):
+                raise ValueError("`left_by` and `right_by` must either both be set or both be None")
+            left_by = left_by if left_by is not None else []
+            right_by = right_by if right_by is not None else []
+        else:
+            if left_by is not None or right_by is not None:
+                raise ValueError("If `by` is not None then both `left_by` and `right_by` must be None")
+            left_by = by
+            right_by = by
+
+        asof_direction = AsofJoinDirection.from_asof_join_direction_str(direction)
+
+        left_on_expr = self.__column_input_to_expression((left_on,))[0]
+        right_on_expr = self.__column_input_to_expression((right_on,))[0]
+        left_by_exprs = self.__column_input_to_expression(tuple(left_by) if isinstance(left_by, list) else (left_by,))
+        right_by_exprs = self.__column_input_to_expression(
+            tuple(right_by) if isinstance(right_by, list) else (right_by,)
+        )
+        tolerance_expr = lit(tolerance) if tolerance is not None else None
+        builder = self._builder.join_asof(
+            other._builder,
+            left_by=left_by_exprs,
+            right_by=right_by_exprs,
+            left_on=left_on_expr,
+            right_on=right_on_expr,
+            direction=asof_direction,
+            tolerance=tolerance_expr,
+            prefix=prefix,
+            suffix=suffix,
+        )
+        return DataFrame(builder)
+
     @DataframePublicAPI
     def concat(self, other: "DataFrame") -> "DataFrame":
         """Concatenates two DataFrames together in a "vertical" concatenation.
@@ -2357,6 +2645,16 @@ class DataFrame:
         builder = self._builder.agg(list(to_agg), list(group_by) if group_by is not None else None)
         return DataFrame(builder)
 
+    def _agg_grouping_sets(
+        self,
+        to_agg: Iterable[Expression],
+        grouping_sets: List[ExpressionsProjection],
+    ) -> "DataFrame":
+        builder = self._builder.agg_grouping_sets(
+            list(to_agg), [list(grouping_set) for grouping_set in grouping_sets], "grouping_id"
+        )
+        return DataFrame(builder)
+
     def _map_agg_string_to_expr(self, expr: Expression, op: str) -> Expression:
         if op == "sum":
             return expr.sum()
@@ -2667,6 +2965,144 @@ class DataFrame:
         """
         return GroupedDataFrame(self, ExpressionsProjection(self._wildcard_inputs_to_expressions(group_by)))
 
+    @DataframePublicAPI
+    def rollup(self, *group_by: ManyColumnsInputType) -> "GroupingSetsDataFrame":
+        """Performs a GroupBy over the hierarchy of the given keys, computing subtotals and a grand total.
+
+        ``df.rollup("a", "b")`` groups by ``(a, b)``, ``(a)`` and ``()`` in a single aggregation. Keys that a row is
+        not grouped by are null, and the ``grouping_id`` column tells the grouping sets of the rows apart.
+
+        Example:
+            >>> import daft
+            >>> from daft import col
+            >>> df = daft.from_pydict({"region": ["eu", "eu", "us"], "city": ["ams", "ber", "nyc"], "sales": [1, 2, 3]})
+            >>> df = df.rollup("region", "city").agg(col("sales").sum()).sort(["grouping_id", "region", "city"])
+            >>> df.show()
+            ╭────────┬──────┬───────┬─────────────╮
+            │ region ┆ city ┆ sales ┆ grouping_id │
+            │ ---    ┆ ---  ┆ ---   ┆ ---         │
+            │ Utf8   ┆ Utf8 ┆ Int64 ┆ Int64       │
+            ╞════════╪══════╪═══════╪═════════════╡
+            │ eu     ┆ ams  ┆ 1     ┆ 0           │
+            ├╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ eu     ┆ ber  ┆ 2     ┆ 0           │
+            ├╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ us     ┆ nyc  ┆ 3     ┆ 0           │
+            ├╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ eu     ┆ None ┆ 3     ┆ 1           │
+            ├╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ us     ┆ None ┆ 3     ┆ 1           │
+            ├╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ None   ┆ None ┆ 6     ┆ 3           │
+            ╰────────┴──────┴───────┴─────────────╯
+            <BLANKLINE>
+            (Showing first 6 of 6 rows)
+
+        Args:
+            *group_by (Union[str, Expression]): columns to roll up, from the outermost to the innermost level
+
+        Returns:
+            GroupingSetsDataFrame: DataFrame to Aggregate
+        """
+        keys = self._wildcard_inputs_to_expressions(group_by)
+        return GroupingSetsDataFrame(
+            self, [ExpressionsProjection(keys[:i]) for i in range(len(keys), -1, -1)]
+        )
+
+    @DataframePublicAPI
+    def cube(self, *group_by: ManyColumnsInputType) -> "GroupingSetsDataFrame":
+        """Performs a GroupBy over every combination of the given keys, including the grand total.
+
+        ``df.cube("a", "b")`` groups by ``(a, b)``, ``(a)``, ``(b)`` and ``()`` in a single aggregation. Keys that a row
+        is not grouped by are null, and the ``grouping_id`` column tells the grouping sets of the rows apart.
+
+        Example:
+            >>> import daft
+            >>> from daft import col
+            >>> df = daft.from_pydict({"a": ["x", "x", "y"], "b": [1, 2, 1], "sales": [1, 2, 3]})
+            >>> df = df.cube("a", "b").agg(col("sales").sum())
+            >>> df.where(col("grouping_id") == 2).sort("b").show()
+            ╭──────┬───────┬───────┬─────────────╮
+            │ a    ┆ b     ┆ sales ┆ grouping_id │
+            │ ---  ┆ ---   ┆ ---   ┆ ---         │
+            │ Utf8 ┆ Int64 ┆ Int64 ┆ Int64       │
+            ╞══════╪═══════╪═══════╪═════════════╡
+            │ None ┆ 1     ┆ 4     ┆ 2           │
+            ├╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ None ┆ 2     ┆ 2     ┆ 2           │
+            ╰──────┴───────┴───────┴─────────────╯
+            <BLANKLINE>
+            (Showing first 2 of 2 rows)
+
+        Args:
+            *group_by (Union[str, Expression]): columns to group by in every combination
+
+        Returns:
+            GroupingSetsDataFrame: DataFrame to Aggregate
+        """
+        keys = self._wildcard_inputs_to_expressions(group_by)
+        return GroupingSetsDataFrame(
+            self,
+            [
+                ExpressionsProjection(list(subset))
+                for size in range(len(keys), -1, -1)
+                for subset in itertools.combinations(keys, size)
+            ],
+        )
+
+    @DataframePublicAPI
+    def grouping_sets(self, *grouping_sets: ManyColumnsInputType) -> "GroupingSetsDataFrame":
+        """Performs a GroupBy over each of the given grouping sets in a single aggregation.
+
+        Keys that a row is not grouped by are null, and the ``grouping_id`` column tells the grouping sets of the rows
+        apart: it is a bitmask over the union of the keys, in order of first appearance, where the bit of a key is set
+        if the row is *not* grouped by it. The first key gets the most significant bit.
+
+        Example:
+            >>> import daft
+            >>> from daft import col
+            >>> df = daft.from_pydict({"a": ["x", "x", "y"], "b": [1, 2, 1], "sales": [1, 2, 3]})
+            >>> df = df.grouping_sets(["a"], ["b"], []).agg(col("sales").sum())
+            >>> df.sort(["grouping_id", "a", "b"]).show()
+            ╭──────┬───────┬───────┬─────────────╮
+            │ a    ┆ b     ┆ sales ┆ grouping_id │
+            │ ---  ┆ ---   ┆ ---   ┆ ---         │
+            │ Utf8 ┆ Int64 ┆ Int64 ┆ Int64       │
+            ╞══════╪═══════╪═══════╪═════════════╡
+            │ x    ┆ None  ┆ 3     ┆ 1           │
+            ├╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ y    ┆ None  ┆ 3     ┆ 1           │
+            ├╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ None ┆ 1     ┆ 4     ┆ 2           │
+            ├╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ None ┆ 2     ┆ 2     ┆ 2           │
+            ├╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ None ┆ None  ┆ 6     ┆ 3           │
+            ╰──────┴───────┴───────┴─────────────╯
+            <BLANKLINE>
+            (Showing first 5 of 5 rows)
+
+        Args:
+            *grouping_sets (Union[str, Expression, List[Union[str, Expression]]]): the grouping sets, each either a
+                single column or a list of columns. An empty list is the grand total.
+
+        Returns:
+            GroupingSetsDataFrame: DataFrame to Aggregate
+        """
+        if len(grouping_sets) == 0:
+            raise ValueError("DataFrame.grouping_sets() requires at least one grouping set")
+        return GroupingSetsDataFrame(
+            self,
+            [
+                ExpressionsProjection(
+                    self._column_inputs_to_expressions(
+                        [grouping_set] if isinstance(grouping_set, (str, Expression)) else grouping_set
+                    )
+                )
+                for grouping_set in grouping_sets
+            ],
+        )
+
     @DataframePublicAPI
     def pivot(
         self,
@@ -3494,3 +3930,46 @@ class GroupedDataFrame:
             DataFrame: DataFrame with grouped aggregations
         """
         return self.df._map_groups(udf, group_by=self.group_by)
+
+
+@dataclass
+class GroupingSetsDataFrame:
+    """A view of a DataFrame that aggregates over several grouping sets at once.
+
+    Returned by :meth:`df.rollup() <daft.DataFrame.rollup>`, :meth:`df.cube() <daft.DataFrame.cube>` and
+    :meth:`df.grouping_sets() <daft.DataFrame.grouping_sets>`.
+    """
+
+    df: DataFrame
+    grouping_sets: List[ExpressionsProjection]
+
+    def __post_init__(self):
+        schema = self.df._builder.schema()
+        for grouping_set in self.grouping_sets:
+            for field, e in zip(grouping_set.resolve_schema(schema), grouping_set):
+                if field.dtype == DataType.null():
+                    raise ExpressionTypeError(f"Cannot groupby on null type expression: {e}")
+
+    def agg(self, *to_agg: Union[Expression, Iterable[Expression]]) -> "DataFrame":
+        """Perform aggregations on every grouping set.
+
+        The result has the union of the keys of all grouping sets, followed by the aggregations and an Int64
+        ``grouping_id`` column. Keys that a row is not grouped by are null.
+
+        Args:
+            *to_agg (Union[Expression, Iterable[Expression]]): aggregation expressions
+
+        Returns:
+            DataFrame: DataFrame with the aggregations of every grouping set
+        """
+        to_agg_list = (
+            list(to_agg[0])
+            if (len(to_agg) == 1 and not isinstance(to_agg[0], Expression))
+            else list(typing.cast("Tuple[Expression]", to_agg))
+        )
+
+        for expr in to_agg_list:
+            if not isinstance(expr, Expression):
+                raise ValueError(f"GroupingSetsDataFrame.agg() only accepts expression type, received: {type(expr)}")
+
+        return self.df._agg_grouping_sets(to_agg_list, self.grouping_sets)
@@ -22,7 +22,7 @@ if TYPE_CHECKING:
     from pyiceberg.schema import Schema as IcebergSchema
     from pyiceberg.table import TableProperties as IcebergTableProperties
 
-    from daft.daft import FileFormat, IOConfig, JoinType, ScanTask
+    from daft.daft import CsvWriteOptions, FileFormat, IOConfig, JoinType, ScanTask
     from daft.logical.map_partition_ops import MapPartitionOp
     from daft.logical.schema import Schema
 
@@ -402,6 +402,7 @@ class WriteFile(SingleOutputInstruction):
     compression: str | None
     partition_cols: ExpressionsProjection | None
     io_config: IOConfig | None
+    csv_options: CsvWriteOptions | None = None
 
     def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
         return self._write_file(inputs)
@@ -431,6 +432,7 @@ class WriteFile(SingleOutputInstruction):
             compression=self.compression,
             partition_cols=self.partition_cols,
             io_config=self.io_config,
+            csv_options=self.csv_options,
         )
 
 
@@ -59,7 +59,7 @@ if TYPE_CHECKING:
     from pyiceberg.schema import Schema as IcebergSchema
     from pyiceberg.table import TableProperties as IcebergTableProperties
 
-    from daft.daft import FileFormat, IOConfig, JoinType
+    from daft.daft import CsvWriteOptions, FileFormat, IOConfig, JoinType
     from daft.logical.schema import Schema
 
 
@@ -98,6 +98,7 @@ def file_write(
     compression: str | None,
     partition_cols: ExpressionsProjection | None,
     io_config: IOConfig | None,
+    csv_options: CsvWriteOptions | None = None,
 ) -> InProgressPhysicalPlan[PartitionT]:
     """Write the results of `child_plan` into files described by `write_info`."""
     yield from (
@@ -109,6 +110,7 @@ def file_write(
                 compression=compression,
                 partition_cols=partition_cols,
                 io_config=io_config,
+                csv_options=csv_options,
             ),
         )
         if isinstance(step, PartitionTaskBuilder)
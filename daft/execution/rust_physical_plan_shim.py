@@ -4,6 +4,7 @@ from typing import TYPE_CHECKING
 
 from daft.context import get_context
 from daft.daft import (
+    CsvWriteOptions,
     FileFormat,
     IOConfig,
     JoinType,
@@ -101,24 +102,28 @@ def actor_pool_project(
 
 class ShimExplodeOp(MapPartitionOp):
     explode_columns: ExpressionsProjection
+    index_column: str | None
 
-    def __init__(self, explode_columns: ExpressionsProjection) -> None:
+    def __init__(self, explode_columns: ExpressionsProjection, index_column: str | None = None) -> None:
         self.explode_columns = explode_columns
+        self.index_column = index_column
 
     def get_output_schema(self) -> Schema:
         raise NotImplementedError("Output schema shouldn't be needed at execution time")
 
     def run(self, input_partition: MicroPartition) -> MicroPartition:
-        return input_partition.explode(self.explode_columns)
+        return input_partition.explode(self.explode_columns, self.index_column)
 
 
 def explode(
-    input: physical_plan.InProgressPhysicalPlan[PartitionT], explode_exprs: list[PyExpr]
+    input: physical_plan.InProgressPhysicalPlan[PartitionT],
+    explode_exprs: list[PyExpr],
+    index_column: str | None = None,
 ) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
     explode_expr_projection = ExpressionsProjection(
         [Expression._from_pyexpr(expr)._explode() for expr in explode_exprs]
     )
-    explode_op = ShimExplodeOp(explode_expr_projection)
+    explode_op = ShimExplodeOp(explode_expr_projection, index_column)
     return physical_plan.pipeline_instruction(
         child_plan=input,
         pipeable_instruction=execution_step.MapPartition(explode_op),
@@ -331,6 +336,7 @@ def write_file(
     compression: str | None,
     partition_cols: list[PyExpr] | None,
     io_config: IOConfig | None,
+    csv_options: CsvWriteOptions | None = None,
 ) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
     if partition_cols is not None:
         expr_projection = ExpressionsProjection([Expression._from_pyexpr(expr) for expr in partition_cols])
@@ -344,6 +350,7 @@ def write_file(
         compression,
         expr_projection,
         io_config,
+        csv_options,
     )
 
 
@@ -54,6 +54,7 @@ from daft.series import Series, item_to_series
 if TYPE_CHECKING:
     from daft.io import IOConfig
     from daft.udf import BoundUDFArgs, InitArgsType, UninitializedUdf
+    from daft.window import Window
 # This allows Sphinx to correctly work against our "namespaced" accessor functions by overriding @property to
 # return a class instance of the namespace instead of a property object.
 elif os.getenv("DAFT_SPHINX_BUILD") == "1":
@@ -1144,6 +1145,39 @@ class Expression:
         expr = self._expr.agg_concat()
         return Expression._from_pyexpr(expr)
 
+    def over(self, window: Window) -> Expression:
+        """Evaluates this aggregation or window function over a window of rows, producing one value per row.
+
+        Example:
+            >>> import daft
+            >>> from daft.window import Window
+            >>> df = daft.from_pydict({"group": ["a", "a", "b"], "x": [1, 2, 3]})
+            >>> df = df.with_column("group_total", df["x"].sum().over(Window().partition_by("group")))
+
+        Args:
+            window (Window): The window to evaluate over.
+
+        Returns:
+            Expression: The window function.
+        """
+        return Expression._from_pyexpr(window._apply(self._expr))
+
+    def lag(self, offset: int = 1, default: Expression | None = None) -> Expression:
+        """The value of this expression `offset` rows before the current row in its window partition, or `default` if there is no such row.
+
+        Must be evaluated over a window, e.g. `df["x"].lag(1).over(Window().order_by("t"))`.
+        """
+        default_expr = Expression._to_expression(default)._expr if default is not None else None
+        return Expression._from_pyexpr(self._expr.lag(offset, default_expr))
+
+    def lead(self, offset: int = 1, default: Expression | None = None) -> Expression:
+        """The value of this expression `offset` rows after the current row in its window partition, or `default` if there is no such row.
+
+        Must be evaluated over a window, e.g. `df["x"].lead(1).over(Window().order_by("t"))`.
+        """
+        default_expr = Expression._to_expression(default)._expr if default is not None else None
+        return Expression._from_pyexpr(self._expr.lead(offset, default_expr))
+
     def _explode(self) -> Expression:
         expr = native.explode(self._expr)
         return Expression._from_pyexpr(expr)
@@ -2093,6 +2127,47 @@ class ExpressionDatetimeNamespace(ExpressionNamespace):
         relative_to = Expression._to_expression(relative_to)
         return Expression._from_pyexpr(native.dt_truncate(self._expr, interval, relative_to._expr))
 
+    def convert_timezone(self, tz: str) -> Expression:
+        """Converts the timestamp column to the timezone `tz`, keeping the instants in time that the timestamps represent.
+
+        Timestamps without a timezone are taken to be in UTC.
+
+        Example:
+            >>> import daft, datetime
+            >>> df = daft.from_pydict({"ts": [datetime.datetime(2021, 1, 1, 12, 0)]})
+            >>> df.select(df["ts"].dt.convert_timezone("America/New_York").dt.replace_timezone().alias("local")).to_pydict()
+            {'local': [datetime.datetime(2021, 1, 1, 7, 0)]}
+
+        Args:
+            tz: The timezone to convert to, either a name from the tz database such as "America/New_York", or a fixed offset such as "+08:00".
+
+        Returns:
+            Expression: a Timestamp expression in the timezone `tz`
+        """
+        return Expression._from_pyexpr(native.dt_convert_timezone(self._expr, tz))
+
+    def replace_timezone(self, tz: str | None = None) -> Expression:
+        """Replaces the timezone of the timestamp column with `tz`, keeping the wall clock times of the timestamps.
+
+        Wall clock times which occur twice when the clocks go back resolve to their earliest occurrence, and wall clock
+        times which are skipped when the clocks go forward are shifted forward by the length of the gap.
+
+        Example:
+            >>> import daft, datetime
+            >>> df = daft.from_pydict({"ts": [datetime.datetime(2021, 1, 1, 12, 0)]})
+            >>> df.select(
+            ...     df["ts"].dt.replace_timezone("America/New_York").dt.convert_timezone("UTC").dt.replace_timezone().alias("utc")
+            ... ).to_pydict()
+            {'utc': [datetime.datetime(2021, 1, 1, 17, 0)]}
+
+        Args:
+            tz: The timezone to replace the existing one with, either a name from the tz database such as "America/New_York", or a fixed offset such as "+08:00". If None, the timezone is removed.
+
+        Returns:
+            Expression: a Timestamp expression in the timezone `tz`
+        """
+        return Expression._from_pyexpr(native.dt_replace_timezone(self._expr, tz))
+
 
 class ExpressionStringNamespace(ExpressionNamespace):
     def contains(self, substr: str | Expression) -> Expression:
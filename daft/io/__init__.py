@@ -12,8 +12,10 @@ from daft.io._csv import read_csv
 from daft.io._deltalake import read_deltalake
 from daft.io._hudi import read_hudi
 from daft.io._iceberg import read_iceberg
+from daft.io._ipc import read_ipc
 from daft.io._json import read_json
 from daft.io._lance import read_lance
+from daft.io._orc import read_orc
 from daft.io._parquet import read_parquet
 from daft.io._sql import read_sql
 from daft.io.catalog import DataCatalogTable, DataCatalogType
@@ -33,8 +35,10 @@ __all__ = [
     "read_deltalake",
     "read_hudi",
     "read_iceberg",
+    "read_ipc",
     "read_json",
     "read_lance",
+    "read_orc",
     "read_parquet",
     "read_sql",
 ]
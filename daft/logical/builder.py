@@ -5,7 +5,9 @@ from typing import TYPE_CHECKING, Callable
 
 from daft.context import get_context
 from daft.daft import (
+    AsofJoinDirection,
     CountMode,
+    CsvWriteOptions,
     FileFormat,
     IOConfig,
     JoinStrategy,
@@ -250,6 +252,18 @@ class LogicalPlanBuilder:
         builder = self._builder.aggregate([expr._expr for expr in to_agg], group_by_pyexprs)
         return LogicalPlanBuilder(builder)
 
+    def agg_grouping_sets(
+        self,
+        to_agg: list[Expression],
+        grouping_sets: list[list[Expression]],
+        grouping_id_column: str,
+    ) -> LogicalPlanBuilder:
+        grouping_sets_pyexprs = [[expr._expr for expr in grouping_set] for grouping_set in grouping_sets]
+        builder = self._builder.aggregate_grouping_sets(
+            [expr._expr for expr in to_agg], grouping_sets_pyexprs, grouping_id_column
+        )
+        return LogicalPlanBuilder(builder)
+
     def map_groups(self, udf: Expression, group_by: list[Expression] | None) -> LogicalPlanBuilder:
         group_by_pyexprs = [expr._expr for expr in group_by] if group_by is not None else []
         builder = self._builder.aggregate([udf._expr], group_by_pyexprs)
@@ -288,6 +302,31 @@ class LogicalPlanBuilder:
         )
         return LogicalPlanBuilder(builder)
 
+    def join_asof(
+        self,
+        right: LogicalPlanBuilder,
+        left_by: list[Expression],
+        right_by: list[Expression],
+        left_on: Expression,
+        right_on: Expression,
+        direction: AsofJoinDirection = AsofJoinDirection.Backward,
+        tolerance: Expression | None = None,
+        prefix: str | None = None,
+        suffix: str | None = None,
+    ) -> LogicalPlanBuilder:
+        builder = self._builder.asof_join(
+            right._builder,
+            [expr._expr for expr in left_by],
+            [expr._expr for expr in right_by],
+            left_on._expr,
+            right_on._expr,
+            direction,
+            tolerance._expr if tolerance is not None else None,
+            prefix,
+            suffix,
+        )
+        return LogicalPlanBuilder(builder)
+
     def concat(self, other: LogicalPlanBuilder) -> LogicalPlanBuilder:  # type: ignore[override]
         builder = self._builder.concat(other._builder)
         return LogicalPlanBuilder(builder)
@@ -319,11 +358,16 @@ class LogicalPlanBuilder:
         io_config: IOConfig,
         partition_cols: list[Expression] | None = None,
         compression: str | None = None,
+        csv_options: CsvWriteOptions | None = None,
     ) -> LogicalPlanBuilder:
-        if file_format != FileFormat.Csv and file_format != FileFormat.Parquet:
-            raise ValueError(f"Writing is only supported for Parquet and CSV file formats, but got: {file_format}")
+        if file_format not in (FileFormat.Csv, FileFormat.Parquet, FileFormat.Json, FileFormat.Ipc):
+            raise ValueError(
+                f"Writing is only supported for Parquet, CSV, JSON and Arrow IPC file formats, but got: {file_format}"
+            )
         part_cols_pyexprs = [expr._expr for expr in partition_cols] if partition_cols is not None else None
-        builder = self._builder.table_write(str(root_dir), file_format, part_cols_pyexprs, compression, io_config)
+        builder = self._builder.table_write(
+            str(root_dir), file_format, part_cols_pyexprs, compression, io_config, csv_options
+        )
         return LogicalPlanBuilder(builder)
 
     def write_iceberg(self, table: IcebergTable, io_config: IOConfig) -> LogicalPlanBuilder:
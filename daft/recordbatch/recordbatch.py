@@ -307,10 +307,10 @@ class RecordBatch:
     def quantiles(self, num: int) -> RecordBatch:
         return RecordBatch._from_pytable(self._table.quantiles(num))
 
-    def explode(self, columns: ExpressionsProjection) -> RecordBatch:
+    def explode(self, columns: ExpressionsProjection, index_column: str | None = None) -> RecordBatch:
         """NOTE: Expressions here must be Explode expressions."""
         to_explode_pyexprs = [e._expr for e in columns]
-        return RecordBatch._from_pytable(self._table.explode(to_explode_pyexprs))
+        return RecordBatch._from_pytable(self._table.explode(to_explode_pyexprs, index_column))
 
     def hash_join(
         self,
@@ -12,6 +12,7 @@ from daft.daft import (
     CsvConvertOptions,
     CsvParseOptions,
     CsvReadOptions,
+    CsvWriteOptions,
     FileFormat,
     IOConfig,
     JsonConvertOptions,
@@ -278,6 +279,13 @@ class TabularWriteVisitors:
         return MicroPartition.from_pydict(metadata)
 
 
+def _pyarrow_csv_write_options(csv_options: CsvWriteOptions) -> pacsv.WriteOptions:
+    # PyArrow's CSV writer always quotes with `"` and writes nulls as empty cells.
+    if csv_options.quote != '"' or csv_options.null_value != "":
+        raise ValueError("Custom `quote` and `null_value` CSV write options require the native CSV writer")
+    return pacsv.WriteOptions(include_header=csv_options.header, delimiter=csv_options.delimiter)
+
+
 def write_tabular(
     table: MicroPartition,
     file_format: FileFormat,
@@ -286,6 +294,7 @@ def write_tabular(
     partition_cols: ExpressionsProjection | None = None,
     compression: str | None = None,
     io_config: IOConfig | None = None,
+    csv_options: CsvWriteOptions | None = None,
 ) -> MicroPartition:
     [resolved_path], fs = _resolve_paths_and_filesystem(path, io_config=io_config)
     if isinstance(path, pathlib.Path):
@@ -310,6 +319,9 @@ def write_tabular(
     elif file_format == FileFormat.Csv:
         format = pads.CsvFileFormat()
         opts = None
+        if csv_options is not None:
+            opts = format.make_write_options()
+            opts.write_options = _pyarrow_csv_write_options(csv_options)
         assert compression is None
         inflation_factor = execution_config.csv_inflation_factor
         target_file_size = execution_config.csv_target_filesize
@@ -596,6 +608,7 @@ def write_empty_tabular(
     schema: Schema,
     compression: str | None = None,
     io_config: IOConfig | None = None,
+    csv_options: CsvWriteOptions | None = None,
 ) -> str:
     table = pa.Table.from_pylist([], schema=schema.to_pyarrow_schema())
 
@@ -618,7 +631,21 @@ def write_empty_tabular(
             )
         elif file_format == FileFormat.Csv:
             output_file = fs.open_output_stream(file_path)
-            pacsv.write_csv(table, output_file)
+            write_options = None
+            if csv_options is not None:
+                # An empty table has no cells to quote or null out, so only the header and delimiter apply.
+                write_options = pacsv.WriteOptions(include_header=csv_options.header, delimiter=csv_options.delimiter)
+            pacsv.write_csv(table, output_file, write_options=write_options)
+        elif file_format == FileFormat.Json:
+            # An empty table is written as an empty newline-delimited JSON file.
+            with fs.open_output_stream(file_path):
+                pass
+        elif file_format == FileFormat.Ipc:
+            ipc_compression = None if compression in (None, "none", "uncompressed") else compression
+            options = pa.ipc.IpcWriteOptions(compression=ipc_compression)
+            with fs.open_output_stream(file_path) as output_file:
+                with pa.ipc.new_file(output_file, table.schema, options=options) as writer:
+                    writer.write_table(table)
         else:
             raise ValueError(f"Unsupported file format {file_format}")
 
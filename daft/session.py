@@ -73,6 +73,10 @@ class Session:
         """Returns the session's current catalog."""
         return self._session.current_catalog()
 
+    def current_namespace(self) -> list[str]:
+        """Returns the session's current namespace within the current catalog."""
+        return self._session.current_namespace()
+
     ###
     # get_*
     ###
@@ -129,6 +133,12 @@ class Session:
         """Set the given catalog as current_catalog or err if not exists."""
         self._session.set_catalog(name)
 
+    def set_namespace(self, namespace: str | list[str]):
+        """Set the given namespace as current_namespace within the current catalog."""
+        if isinstance(namespace, str):
+            namespace = namespace.split(".")
+        self._session.set_namespace(namespace)
+
 
 ###
 # global active session
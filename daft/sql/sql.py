@@ -1,7 +1,7 @@
 # isort: dont-add-import: from __future__ import annotations
 
 import inspect
-from typing import Optional
+from typing import Callable, Optional
 
 from daft.api_annotations import PublicAPI
 from daft.context import get_context
@@ -12,21 +12,28 @@ from daft.dataframe import DataFrame
 from daft.exceptions import DaftCoreException
 from daft.expressions import Expression
 from daft.logical.builder import LogicalPlanBuilder
+from daft.session import current_session
 
 
 class SQLCatalog:
     """SQLCatalog is a simple map from table names to dataframes used in query planning.
 
+    Callables which return dataframes can also be registered as table functions, which are called
+    with their constant arguments as Python values and with their other arguments as expressions.
+
     EXPERIMENTAL: This features is early in development and will change.
     """
 
     _catalog: _PyCatalog = None  # type: ignore
 
     def __init__(self, tables: dict) -> None:
-        """Create a new SQLCatalog from a dictionary of table names to dataframes."""
+        """Create a new SQLCatalog from a dictionary of table names to dataframes or table functions."""
         self._catalog = _PyCatalog.new()
-        for name, df in tables.items():
-            self._catalog.register_table(name, df._get_current_builder()._builder)
+        for name, table in tables.items():
+            if isinstance(table, DataFrame):
+                self.register_table(name, table)
+            else:
+                self.register_table_function(name, table)
 
     def __str__(self) -> str:
         return str(self._catalog)
@@ -34,6 +41,9 @@ class SQLCatalog:
     def register_table(self, name: str, df: DataFrame):
         self._catalog.register_table(name, df._get_current_builder()._builder)
 
+    def register_table_function(self, name: str, func: Callable[..., DataFrame]):
+        self._catalog.register_table_function(name, func)
+
     def _copy_from(self, other: "SQLCatalog") -> None:
         self._catalog.copy_from(other._catalog)
 
@@ -154,6 +164,28 @@ def sql(sql: str, catalog: Optional[SQLCatalog] = None, register_globals: bool =
         <BLANKLINE>
         (Showing first 3 of 3 rows)
 
+        Statements such as ``CREATE TABLE``, ``DROP TABLE`` and ``USE`` are applied to the current session
+        when they are planned, and tables they create can be queried by later statements. ``INSERT INTO`` returns a
+        DataFrame which writes its rows to the table, and the number of written rows, once it is collected.
+
+        >>> import daft
+        >>>
+        >>> df = daft.from_pydict({"a": [1, 2, 3]})
+        >>> _ = daft.sql("CREATE TEMP TABLE evens AS SELECT a FROM df WHERE a % 2 = 0")
+        >>> _ = daft.sql("INSERT INTO evens SELECT 4 AS a").collect()
+        >>> daft.sql("SELECT a FROM evens").show()
+        ╭───────╮
+        │ a     │
+        │ ---   │
+        │ Int64 │
+        ╞═══════╡
+        │ 2     │
+        ├╌╌╌╌╌╌╌┤
+        │ 4     │
+        ╰───────╯
+        <BLANKLINE>
+        (Showing first 2 of 2 rows)
+
     Args:
         sql (str): SQL query to execute
         catalog (SQLCatalog, optional): Catalog of tables to use in the query.
@@ -183,7 +215,8 @@ def sql(sql: str, catalog: Optional[SQLCatalog] = None, register_globals: bool =
         raise DaftCoreException("Must supply a catalog if register_globals is False")
 
     planning_config = get_context().daft_planning_config
+    session = current_session()
 
     _py_catalog = catalog._catalog
-    _py_logical = _sql(sql, _py_catalog, planning_config)
+    _py_logical = _sql(sql, _py_catalog, planning_config, session._session)
     return DataFrame(LogicalPlanBuilder(_py_logical))
@@ -0,0 +1,151 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from daft.daft import dense_rank as _dense_rank
+from daft.daft import rank as _rank
+from daft.daft import row_number as _row_number
+from daft.expressions import Expression, col
+
+if TYPE_CHECKING:
+    from daft.daft import PyExpr
+
+
+class Window:
+    """Describes the partitioning, ordering and frame of the window that a window function is evaluated over.
+
+    Windows are immutable: each method returns a new window.
+
+    Example:
+        >>> import daft
+        >>> from daft.window import Window
+        >>> df = daft.from_pydict({"store": ["a", "a", "b"], "day": [1, 2, 1], "sales": [10, 20, 30]})
+        >>> window = Window().partition_by("store").order_by("day")
+        >>> df = df.with_column("running_sales", df["sales"].sum().over(window))
+    """
+
+    unbounded_preceding = None
+    unbounded_following = None
+    current_row = 0
+
+    def __init__(self) -> None:
+        self._partition_by: list[Expression] = []
+        self._order_by: list[Expression] = []
+        self._descending: list[bool] = []
+        self._nulls_first: list[bool] = []
+        self._frame: tuple[str, int | None, int | None] | None = None
+
+    def _copy(self) -> Window:
+        window = Window()
+        window._partition_by = list(self._partition_by)
+        window._order_by = list(self._order_by)
+        window._descending = list(self._descending)
+        window._nulls_first = list(self._nulls_first)
+        window._frame = self._frame
+        return window
+
+    def partition_by(self, *cols: Expression | str) -> Window:
+        """Partitions the rows by the given columns, so that window functions are evaluated within each partition.
+
+        Args:
+            *cols: Columns to partition by.
+
+        Returns:
+            Window: The window, partitioned by the given columns.
+        """
+        window = self._copy()
+        window._partition_by.extend(col(c) if isinstance(c, str) else c for c in cols)
+        return window
+
+    def order_by(
+        self,
+        *cols: Expression | str,
+        desc: bool | list[bool] = False,
+        nulls_first: bool | list[bool] | None = None,
+    ) -> Window:
+        """Orders the rows within each partition by the given columns.
+
+        Args:
+            *cols: Columns to order by.
+            desc: Whether to order in descending order, either for all columns or per column.
+            nulls_first: Whether nulls come first, either for all columns or per column. Defaults to `desc`.
+
+        Returns:
+            Window: The window, ordered by the given columns.
+        """
+        exprs = [col(c) if isinstance(c, str) else c for c in cols]
+        descending = desc if isinstance(desc, list) else [desc] * len(exprs)
+        if nulls_first is None:
+            nulls_first = descending
+        elif not isinstance(nulls_first, list):
+            nulls_first = [nulls_first] * len(exprs)
+        if len(descending) != len(exprs) or len(nulls_first) != len(exprs):
+            raise ValueError(
+                f"Expected `desc` and `nulls_first` to have one entry per order by column ({len(exprs)}), "
+                f"but received {len(descending)} and {len(nulls_first)}"
+            )
+        window = self._copy()
+        window._order_by.extend(exprs)
+        window._descending.extend(descending)
+        window._nulls_first.extend(nulls_first)
+        return window
+
+    def rows_between(self, start: int | None, end: int | None) -> Window:
+        """Restricts window aggregations to the rows from `start` to `end` rows after the current row, inclusive.
+
+        Negative offsets refer to preceding rows. Use `Window.unbounded_preceding`, `Window.current_row` and
+        `Window.unbounded_following` for the special boundaries.
+
+        Args:
+            start: Offset of the first row of the frame.
+            end: Offset of the last row of the frame.
+
+        Returns:
+            Window: The window, with the given frame.
+        """
+        window = self._copy()
+        window._frame = ("rows", start, end)
+        return window
+
+    def range_between(self, start: int | None, end: int | None) -> Window:
+        """Restricts window aggregations to the rows whose order by value is within `start` to `end` of the current row's.
+
+        Range frames with offsets require the window to be ordered by a single numeric column.
+
+        Args:
+            start: Offset of the smallest order by value in the frame.
+            end: Offset of the largest order by value in the frame.
+
+        Returns:
+            Window: The window, with the given frame.
+        """
+        window = self._copy()
+        window._frame = ("range", start, end)
+        return window
+
+    def _apply(self, expr: PyExpr) -> PyExpr:
+        return expr.over(
+            [e._expr for e in self._partition_by],
+            [e._expr for e in self._order_by],
+            self._descending,
+            self._nulls_first,
+            self._frame,
+        )
+
+
+def row_number() -> Expression:
+    """The 1-based position of each row within its window partition. Must be evaluated over a window."""
+    return Expression._from_pyexpr(_row_number())
+
+
+def rank() -> Expression:
+    """The rank of each row within its window partition, with gaps after ties. Must be evaluated over a window."""
+    return Expression._from_pyexpr(_rank())
+
+
+def dense_rank() -> Expression:
+    """The rank of each row within its window partition, without gaps after ties. Must be evaluated over a window."""
+    return Expression._from_pyexpr(_dense_rank())
+
+
+__all__ = ["Window", "dense_rank", "rank", "row_number"]
@@ -1,6 +1,8 @@
 use ahash::AHashMap;
 
-use std::io::{Read, Seek, SeekFrom};
+use std::collections::VecDeque;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::ops::Range;
 
 use crate::array::Array;
 use crate::chunk::Chunk;
@@ -10,6 +12,7 @@ use crate::io::ipc::IpcSchema;
 
 use super::super::{ARROW_MAGIC_V1, ARROW_MAGIC_V2, CONTINUATION_MARKER};
 use super::common::*;
+use super::deserialize::skip;
 use super::schema::fb_to_schema;
 use super::Dictionaries;
 use super::OutOfSpecKind;
@@ -161,7 +164,9 @@ fn read_footer_len<R: Read + Seek>(reader: &mut R) -> Result<(u64, usize)> {
     Ok((end, footer_len))
 }
 
-pub(super) fn deserialize_footer(footer_data: &[u8], size: u64) -> Result<FileMetadata> {
+/// Deserializes the footer of an Arrow IPC file of `size` bytes, i.e. the `footer_len` bytes
+/// that precede the trailing footer length and magic number.
+pub fn deserialize_footer(footer_data: &[u8], size: u64) -> Result<FileMetadata> {
     let footer = arrow_format::ipc::FooterRef::read_as_root(footer_data)
         .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferFooter(err)))?;
 
@@ -320,3 +325,99 @@ pub fn read_batch<R: Read + Seek>(
         data_scratch,
     )
 }
+
+fn block_offset(block: &arrow_format::ipc::Block) -> Result<(u64, u64)> {
+    let offset: u64 = block
+        .offset
+        .try_into()
+        .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+    let length: u64 = block
+        .meta_data_length
+        .try_into()
+        .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+    Ok((offset, length))
+}
+
+/// Returns the byte ranges of the file that [`read_file_dictionaries`] reads, i.e. the blocks
+/// of its dictionary batches.
+pub fn dictionary_ranges(metadata: &FileMetadata) -> Result<Vec<Range<u64>>> {
+    metadata
+        .dictionaries
+        .iter()
+        .flatten()
+        .map(|block| {
+            let (offset, length) = block_offset(block)?;
+            let body_length: u64 = block
+                .body_length
+                .try_into()
+                .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+            Ok(offset..offset + length + body_length)
+        })
+        .collect()
+}
+
+/// Returns the byte range of the file holding the message of the record batch at `index`.
+/// # Panics
+/// This function panics iff `index >= metadata.blocks.len()`
+pub fn batch_message_range(metadata: &FileMetadata, index: usize) -> Result<Range<u64>> {
+    let (offset, length) = block_offset(&metadata.blocks[index])?;
+    Ok(offset..offset + length)
+}
+
+/// Returns the byte ranges of the file, besides the batch's message, that [`read_batch`] reads to
+/// decode the `projection` of the record batch at `index`, so that the buffers of the other
+/// columns need not be fetched.
+///
+/// `message` holds the bytes in the [`batch_message_range`] of the batch.
+/// # Panics
+/// This function panics iff `index >= metadata.blocks.len()`
+pub fn batch_ranges(
+    metadata: &FileMetadata,
+    projection: Option<&[usize]>,
+    index: usize,
+    message: &[u8],
+) -> Result<Vec<Range<u64>>> {
+    let (offset, length) = block_offset(&metadata.blocks[index])?;
+
+    let mut message_scratch = vec![];
+    read_dictionary_message(&mut Cursor::new(message), 0, &mut message_scratch)?;
+    let message = arrow_format::ipc::MessageRef::read_as_root(message_scratch.as_ref())
+        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferMessage(err)))?;
+    let batch = get_record_batch(message)?;
+
+    let buffers = batch
+        .buffers()
+        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferBuffers(err)))?
+        .ok_or_else(|| Error::from(OutOfSpecKind::MissingMessageBuffers))?
+        .iter()
+        .collect::<Vec<_>>();
+    let mut field_nodes = batch
+        .nodes()
+        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferNodes(err)))?
+        .ok_or_else(|| Error::from(OutOfSpecKind::MissingMessageNodes))?
+        .iter()
+        .collect::<VecDeque<_>>();
+
+    // skipping a column consumes its nodes and buffers, which tells which buffers are its own
+    let mut remaining = buffers.iter().copied().collect::<VecDeque<_>>();
+    let body_offset = offset + length;
+    let mut ranges = vec![];
+    for (i, field) in metadata.schema.fields.iter().enumerate() {
+        let first = buffers.len() - remaining.len();
+        skip(&mut field_nodes, &field.data_type, &mut remaining)?;
+        if projection.map_or(true, |projection| projection.contains(&i)) {
+            for buffer in &buffers[first..buffers.len() - remaining.len()] {
+                let start: u64 = buffer
+                    .offset()
+                    .try_into()
+                    .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+                let length: u64 = buffer
+                    .length()
+                    .try_into()
+                    .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+                ranges.push(body_offset + start..body_offset + start + length);
+            }
+        }
+    }
+    Ok(ranges)
+}
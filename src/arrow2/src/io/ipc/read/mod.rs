@@ -31,7 +31,10 @@ pub mod file_async;
 pub(crate) use common::first_dict_field;
 #[cfg(feature = "io_flight")]
 pub(crate) use common::{read_dictionary, read_record_batch};
-pub use file::{read_batch, read_file_dictionaries, read_file_metadata, FileMetadata};
+pub use file::{
+    batch_message_range, batch_ranges, deserialize_footer, dictionary_ranges, read_batch, read_file_dictionaries,
+    read_file_metadata, FileMetadata,
+};
 pub use reader::FileReader;
 pub use schema::deserialize_schema;
 pub use stream::{read_stream_metadata, StreamMetadata, StreamReader, StreamState};
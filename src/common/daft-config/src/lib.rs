@@ -63,6 +63,11 @@ pub struct DaftExecutionConfig {
     pub pre_shuffle_merge_threshold: usize,
     pub enable_ray_tracing: bool,
     pub scantask_splitting_level: i32,
+    pub native_parquet_writer: bool,
+    pub native_csv_writer: bool,
+    pub grouped_aggregate_spill_threshold: usize,
+    pub hash_join_spill_threshold: usize,
+    pub recursive_cte_max_iterations: usize,
 }
 
 impl Default for DaftExecutionConfig {
@@ -93,6 +98,11 @@ impl Default for DaftExecutionConfig {
             pre_shuffle_merge_threshold: 1024 * 1024 * 1024, // 1GB
             enable_ray_tracing: false,
             scantask_splitting_level: 1,
+            native_parquet_writer: false,
+            native_csv_writer: false,
+            grouped_aggregate_spill_threshold: 4 * 1024 * 1024 * 1024,
+            hash_join_spill_threshold: 4 * 1024 * 1024 * 1024,
+            recursive_cte_max_iterations: 100,
         }
     }
 }
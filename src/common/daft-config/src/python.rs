@@ -100,7 +100,12 @@ impl PyDaftExecutionConfig {
         shuffle_algorithm=None,
         pre_shuffle_merge_threshold=None,
         enable_ray_tracing=None,
-        scantask_splitting_level=None
+        scantask_splitting_level=None,
+        native_parquet_writer=None,
+        native_csv_writer=None,
+        grouped_aggregate_spill_threshold=None,
+        hash_join_spill_threshold=None,
+        recursive_cte_max_iterations=None
     ))]
     fn with_config_values(
         &self,
@@ -129,6 +134,11 @@ impl PyDaftExecutionConfig {
         pre_shuffle_merge_threshold: Option<usize>,
         enable_ray_tracing: Option<bool>,
         scantask_splitting_level: Option<i32>,
+        native_parquet_writer: Option<bool>,
+        native_csv_writer: Option<bool>,
+        grouped_aggregate_spill_threshold: Option<usize>,
+        hash_join_spill_threshold: Option<usize>,
+        recursive_cte_max_iterations: Option<usize>,
     ) -> PyResult<Self> {
         let mut config = self.config.as_ref().clone();
 
@@ -229,6 +239,26 @@ impl PyDaftExecutionConfig {
             config.scantask_splitting_level = scantask_splitting_level;
         }
 
+        if let Some(native_parquet_writer) = native_parquet_writer {
+            config.native_parquet_writer = native_parquet_writer;
+        }
+
+        if let Some(native_csv_writer) = native_csv_writer {
+            config.native_csv_writer = native_csv_writer;
+        }
+
+        if let Some(grouped_aggregate_spill_threshold) = grouped_aggregate_spill_threshold {
+            config.grouped_aggregate_spill_threshold = grouped_aggregate_spill_threshold;
+        }
+
+        if let Some(hash_join_spill_threshold) = hash_join_spill_threshold {
+            config.hash_join_spill_threshold = hash_join_spill_threshold;
+        }
+
+        if let Some(recursive_cte_max_iterations) = recursive_cte_max_iterations {
+            config.recursive_cte_max_iterations = recursive_cte_max_iterations;
+        }
+
         Ok(Self {
             config: Arc::new(config),
         })
@@ -348,6 +378,31 @@ impl PyDaftExecutionConfig {
     fn scantask_splitting_level(&self) -> PyResult<i32> {
         Ok(self.config.scantask_splitting_level)
     }
+
+    #[getter]
+    fn native_parquet_writer(&self) -> PyResult<bool> {
+        Ok(self.config.native_parquet_writer)
+    }
+
+    #[getter]
+    fn native_csv_writer(&self) -> PyResult<bool> {
+        Ok(self.config.native_csv_writer)
+    }
+
+    #[getter]
+    fn grouped_aggregate_spill_threshold(&self) -> PyResult<usize> {
+        Ok(self.config.grouped_aggregate_spill_threshold)
+    }
+
+    #[getter]
+    fn hash_join_spill_threshold(&self) -> PyResult<usize> {
+        Ok(self.config.hash_join_spill_threshold)
+    }
+
+    #[getter]
+    fn recursive_cte_max_iterations(&self) -> PyResult<usize> {
+        Ok(self.config.recursive_cte_max_iterations)
+    }
 }
 
 impl_bincode_py_state_serialization!(PyDaftExecutionConfig);
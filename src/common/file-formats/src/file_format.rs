@@ -17,6 +17,8 @@ pub enum FileFormat {
     Parquet,
     Csv,
     Json,
+    Orc,
+    Ipc,
     Database,
     Python,
 }
@@ -29,6 +31,8 @@ impl FileFormat {
             Self::Parquet => "parquet",
             Self::Csv => "csv",
             Self::Json => "json",
+            Self::Orc => "orc",
+            Self::Ipc => "arrow",
             Self::Database => "db",
             Self::Python => "py",
         }
@@ -39,7 +43,7 @@ impl FromStr for FileFormat {
     type Err = DaftError;
 
     fn from_str(file_format: &str) -> DaftResult<Self> {
-        use FileFormat::{Csv, Database, Json, Parquet};
+        use FileFormat::{Csv, Database, Ipc, Json, Orc, Parquet};
 
         if file_format.trim().eq_ignore_ascii_case("parquet") {
             Ok(Parquet)
@@ -47,6 +51,13 @@ impl FromStr for FileFormat {
             Ok(Csv)
         } else if file_format.trim().eq_ignore_ascii_case("json") {
             Ok(Json)
+        } else if file_format.trim().eq_ignore_ascii_case("orc") {
+            Ok(Orc)
+        } else if ["ipc", "arrow", "feather"]
+            .iter()
+            .any(|name| file_format.trim().eq_ignore_ascii_case(name))
+        {
+            Ok(Ipc)
         } else if file_format.trim().eq_ignore_ascii_case("database") {
             Ok(Database)
         } else {
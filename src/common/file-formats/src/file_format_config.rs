@@ -18,6 +18,8 @@ pub enum FileFormatConfig {
     Parquet(ParquetSourceConfig),
     Csv(CsvSourceConfig),
     Json(JsonSourceConfig),
+    Orc(OrcSourceConfig),
+    Ipc(IpcSourceConfig),
     #[cfg(feature = "python")]
     Database(DatabaseSourceConfig),
     #[cfg(feature = "python")]
@@ -36,6 +38,8 @@ impl FileFormatConfig {
             Self::Parquet(_) => "Parquet",
             Self::Csv(_) => "Csv",
             Self::Json(_) => "Json",
+            Self::Orc(_) => "Orc",
+            Self::Ipc(_) => "Ipc",
             #[cfg(feature = "python")]
             Self::Database(_) => "Database",
             #[cfg(feature = "python")]
@@ -49,6 +53,8 @@ impl FileFormatConfig {
             Self::Parquet(source) => source.multiline_display(),
             Self::Csv(source) => source.multiline_display(),
             Self::Json(source) => source.multiline_display(),
+            Self::Orc(source) => source.multiline_display(),
+            Self::Ipc(_) => vec![],
             #[cfg(feature = "python")]
             Self::Database(source) => source.multiline_display(),
             #[cfg(feature = "python")]
@@ -311,6 +317,63 @@ impl JsonSourceConfig {
 
 impl_bincode_py_state_serialization!(JsonSourceConfig);
 
+/// Configuration for an ORC data source.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash, Default)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct OrcSourceConfig {
+    pub chunk_size: Option<usize>,
+}
+
+impl OrcSourceConfig {
+    #[must_use]
+    pub fn new_internal(chunk_size: Option<usize>) -> Self {
+        Self { chunk_size }
+    }
+
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        if let Some(chunk_size) = self.chunk_size {
+            res.push(format!("Chunk size = {chunk_size}"));
+        }
+        res
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl OrcSourceConfig {
+    /// Create a config for an ORC data source.
+    ///
+    /// # Arguments
+    ///
+    /// * `chunk_size` - Number of rows in each table decoded by the streaming reader.
+    #[new]
+    #[pyo3(signature = (chunk_size=None))]
+    fn new(chunk_size: Option<usize>) -> Self {
+        Self::new_internal(chunk_size)
+    }
+}
+
+impl_bincode_py_state_serialization!(OrcSourceConfig);
+
+/// Configuration for an Arrow IPC (Feather v2) data source, which can be either an IPC file or an IPC stream.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash, Default)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
+pub struct IpcSourceConfig {}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl IpcSourceConfig {
+    /// Create a config for an Arrow IPC data source.
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl_bincode_py_state_serialization!(IpcSourceConfig);
+
 /// Configuration for a Database data source.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg(feature = "python")]
@@ -5,18 +5,24 @@ mod file_format_config;
 #[cfg(feature = "python")]
 pub use file_format_config::DatabaseSourceConfig;
 pub use file_format_config::{
-    CsvSourceConfig, FileFormatConfig, JsonSourceConfig, ParquetSourceConfig,
+    CsvSourceConfig, FileFormatConfig, IpcSourceConfig, JsonSourceConfig, OrcSourceConfig,
+    ParquetSourceConfig,
 };
 
 #[cfg(feature = "python")]
 pub mod python;
 
+mod write_options;
+pub use write_options::CsvWriteOptions;
+
 impl From<&FileFormatConfig> for FileFormat {
     fn from(file_format_config: &FileFormatConfig) -> Self {
         match file_format_config {
             FileFormatConfig::Parquet(_) => Self::Parquet,
             FileFormatConfig::Csv(_) => Self::Csv,
             FileFormatConfig::Json(_) => Self::Json,
+            FileFormatConfig::Orc(_) => Self::Orc,
+            FileFormatConfig::Ipc(_) => Self::Ipc,
             #[cfg(feature = "python")]
             FileFormatConfig::Database(_) => Self::Database,
             #[cfg(feature = "python")]
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     file_format_config::DatabaseSourceConfig, CsvSourceConfig, FileFormat, FileFormatConfig,
-    JsonSourceConfig, ParquetSourceConfig,
+    IpcSourceConfig, JsonSourceConfig, OrcSourceConfig, ParquetSourceConfig,
 };
 
 /// Configuration for parsing a particular file format.
@@ -38,6 +38,18 @@ impl PyFileFormatConfig {
         Self(Arc::new(FileFormatConfig::Json(config)))
     }
 
+    /// Create an ORC file format config.
+    #[staticmethod]
+    fn from_orc_config(config: OrcSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::Orc(config)))
+    }
+
+    /// Create an Arrow IPC file format config.
+    #[staticmethod]
+    fn from_ipc_config(config: IpcSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::Ipc(config)))
+    }
+
     /// Create a Database file format config.
     #[staticmethod]
     fn from_database_config(config: DatabaseSourceConfig) -> Self {
@@ -60,6 +72,14 @@ impl PyFileFormatConfig {
                 .clone()
                 .into_pyobject(py)
                 .map(|c| c.unbind().into_any()),
+            FileFormatConfig::Orc(config) => config
+                .clone()
+                .into_pyobject(py)
+                .map(|c| c.unbind().into_any()),
+            FileFormatConfig::Ipc(config) => config
+                .clone()
+                .into_pyobject(py)
+                .map(|c| c.unbind().into_any()),
             FileFormatConfig::Database(config) => config
                 .clone()
                 .into_pyobject(py)
@@ -0,0 +1,107 @@
+use std::str::FromStr;
+
+use common_error::{DaftError, DaftResult};
+use common_py_serde::impl_bincode_py_state_serialization;
+#[cfg(feature = "python")]
+use pyo3::{exceptions::PyValueError, pyclass, pymethods, PyObject, PyResult, Python};
+use serde::{Deserialize, Serialize};
+
+/// Options for writing CSV files with the native CSV writer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct CsvWriteOptions {
+    /// The character delimiting individual cells.
+    pub delimiter: char,
+    /// The character used to quote cells that contain the delimiter, the quote character or a newline.
+    pub quote: char,
+    /// Whether to write a header row with the column names.
+    pub header: bool,
+    /// The string written in place of null values.
+    pub null_value: String,
+}
+
+impl Default for CsvWriteOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            quote: '"',
+            header: true,
+            null_value: String::new(),
+        }
+    }
+}
+
+impl CsvWriteOptions {
+    /// Builds write options from string key-value pairs, as received from e.g. Spark's `DataFrameWriter.option`.
+    /// Unknown keys are ignored so that callers can pass the full option map through.
+    pub fn try_from_options<'a>(
+        options: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> DaftResult<Self> {
+        let mut res = Self::default();
+        for (key, value) in options {
+            match key.to_lowercase().as_str() {
+                "sep" | "delimiter" => res.delimiter = parse_single_char(key, value)?,
+                "quote" => res.quote = parse_single_char(key, value)?,
+                "header" => {
+                    res.header = bool::from_str(&value.to_lowercase()).map_err(|_| {
+                        DaftError::ValueError(format!(
+                            "Expected a boolean for CSV option `{key}`, got: {value}"
+                        ))
+                    })?;
+                }
+                "nullvalue" => res.null_value = value.to_string(),
+                _ => {}
+            }
+        }
+        Ok(res)
+    }
+
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!("Delimiter = {}", self.delimiter));
+        res.push(format!("Quote = {}", self.quote));
+        res.push(format!("Header = {}", self.header));
+        res.push(format!("Null value = \"{}\"", self.null_value));
+        res
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl CsvWriteOptions {
+    /// Create options for writing CSV files.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - The character delimiting individual cells.
+    /// * `quote` - The character used to quote cells that need it.
+    /// * `header` - Whether to write a header row with the column names.
+    /// * `null_value` - The string written in place of null values.
+    #[new]
+    #[pyo3(signature = (delimiter=',', quote='"', header=true, null_value=String::new()))]
+    fn new(delimiter: char, quote: char, header: bool, null_value: String) -> PyResult<Self> {
+        for (key, value) in [("delimiter", delimiter), ("quote", quote)] {
+            parse_single_char(key, value.encode_utf8(&mut [0; 4]))
+                .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        }
+        Ok(Self {
+            delimiter,
+            quote,
+            header,
+            null_value,
+        })
+    }
+}
+
+impl_bincode_py_state_serialization!(CsvWriteOptions);
+
+fn parse_single_char(key: &str, value: &str) -> DaftResult<char> {
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii() => Ok(c),
+        _ => Err(DaftError::ValueError(format!(
+            "CSV option `{key}` must be a single ASCII character, got: {value}"
+        ))),
+    }
+}
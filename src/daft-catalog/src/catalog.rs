@@ -16,6 +16,9 @@ pub trait Catalog: Sync + Send + std::fmt::Debug {
     /// Returns the given table if it exists.
     fn get_table(&self, name: &Identifier) -> Result<Option<Box<dyn Table>>>;
 
+    /// Returns the names of all tables matching the pattern.
+    fn list_tables(&self, pattern: Option<&str>) -> Result<Vec<String>>;
+
     /// Leverage dynamic dispatch to return the inner object for a PyCatalogImpl (generics?)
     #[cfg(feature = "python")]
     fn to_py(&self, _: pyo3::Python<'_>) -> pyo3::PyResult<pyo3::PyObject> {
@@ -31,6 +31,9 @@ pub enum Error {
     #[snafu(display("{message}"))]
     Unsupported { message: String },
 
+    #[snafu(context(false), display("{source}"))]
+    DaftError { source: common_error::DaftError },
+
     #[cfg(feature = "python")]
     #[snafu(display("Python error during {}: {}", context, source))]
     PythonError {
@@ -67,7 +70,10 @@ impl Error {
 
 impl From<Error> for common_error::DaftError {
     fn from(err: Error) -> Self {
-        common_error::DaftError::CatalogError(err.to_string())
+        match err {
+            Error::DaftError { source } => source,
+            err => common_error::DaftError::CatalogError(err.to_string()),
+        }
     }
 }
 
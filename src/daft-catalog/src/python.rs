@@ -1,12 +1,16 @@
 use std::sync::Arc;
 
 use daft_core::{prelude::SchemaRef, python::PySchema};
-use daft_logical_plan::{LogicalPlanRef, PyLogicalPlanBuilder};
-use pyo3::{exceptions::PyIndexError, intern, prelude::*};
+use daft_logical_plan::{LogicalPlanBuilder, LogicalPlanRef, PyLogicalPlanBuilder};
+use pyo3::{
+    exceptions::{PyIndexError, PyValueError},
+    intern,
+    prelude::*,
+};
 
 use crate::{
-    error::Result, global_catalog, Catalog, CatalogRef, Identifier, Table, TableRef, TableSource,
-    View,
+    error::{Error, Result},
+    global_catalog, Catalog, CatalogRef, Identifier, Table, TableRef, TableSource, View, WriteMode,
 };
 
 /// Read a table from the specified `DaftMetaCatalog`.
@@ -138,8 +142,37 @@ impl Catalog for PyCatalogWrapper {
         todo!()
     }
 
-    fn get_table(&self, _name: &Identifier) -> Result<Option<Box<dyn Table>>> {
-        todo!()
+    fn get_table(&self, name: &Identifier) -> Result<Option<Box<dyn Table>>> {
+        Python::with_gil(|py| {
+            // ident = Identifier._from_pyidentifier(name)
+            let ident = py
+                .import(intern!(py, "daft.catalog"))?
+                .getattr(intern!(py, "Identifier"))?
+                .getattr(intern!(py, "_from_pyidentifier"))?
+                .call1((PyIdentifier::from(name.clone()),))?;
+            // table = catalog.get_table(ident)
+            let table = self
+                .0
+                .call_method1(py, intern!(py, "get_table"), (ident,))?;
+            Ok(Some(Box::new(PyTableWrapper::from(table)) as Box<dyn Table>))
+        })
+        .map_err(|source| Error::PythonError {
+            source,
+            context: format!("get_table {name}"),
+        })
+    }
+
+    fn list_tables(&self, pattern: Option<&str>) -> Result<Vec<String>> {
+        Python::with_gil(|py| {
+            // tables = catalog.list_tables(pattern)
+            self.0
+                .call_method1(py, intern!(py, "list_tables"), (pattern,))?
+                .extract(py)
+        })
+        .map_err(|source| Error::PythonError {
+            source,
+            context: "list_tables".to_string(),
+        })
     }
 
     fn to_py(&self, py: Python<'_>) -> PyResult<PyObject> {
@@ -246,6 +279,26 @@ impl PyTable {
         // df as object
         df.extract()
     }
+
+    /// Writes the DataFrame to this table, err if the table is read-only.
+    #[pyo3(signature = (df, mode="append"))]
+    fn write(&self, py: Python<'_>, df: PyObject, mode: &str) -> PyResult<()> {
+        let mode = match mode {
+            "append" => WriteMode::Append,
+            "overwrite" => WriteMode::Overwrite,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Only `append` and `overwrite` modes are supported, got: {mode}"
+                )))
+            }
+        };
+        // builder = df._builder._builder
+        let builder: PyLogicalPlanBuilder = df
+            .getattr(py, intern!(py, "_builder"))?
+            .getattr(py, intern!(py, "_builder"))?
+            .extract(py)?;
+        Ok(self.0.write(builder.builder.build(), mode)?)
+    }
 }
 
 /// PyTableWrapper wraps a `daft.catalog.Table` implementation (py->rust).
@@ -270,7 +323,58 @@ impl Table for PyTableWrapper {
     }
 
     fn get_logical_plan(&self) -> Result<LogicalPlanRef> {
-        todo!()
+        Python::with_gil(|py| {
+            // builder = table.read()._builder._builder
+            let builder: PyLogicalPlanBuilder = self
+                .0
+                .call_method0(py, intern!(py, "read"))?
+                .getattr(py, intern!(py, "_builder"))?
+                .getattr(py, intern!(py, "_builder"))?
+                .extract(py)?;
+            Ok(builder.builder.build())
+        })
+        .map_err(|source| Error::PythonError {
+            source,
+            context: "get_logical_plan".to_string(),
+        })
+    }
+
+    fn write(&self, plan: LogicalPlanRef, mode: WriteMode) -> Result<()> {
+        Python::with_gil(|py| {
+            // builder = LogicalPlanBuilder.__init__(builder)
+            let builder = PyLogicalPlanBuilder::new(plan.into());
+            let builder = py
+                .import(intern!(py, "daft.logical.builder"))?
+                .getattr(intern!(py, "LogicalPlanBuilder"))?
+                .call1((builder,))?;
+            // df = DataFrame.__init__(builder)
+            let df = py
+                .import(intern!(py, "daft.dataframe"))?
+                .getattr(intern!(py, "DataFrame"))?
+                .call1((builder,))?;
+            // table.write(df, mode)
+            self.0
+                .call_method1(py, intern!(py, "write"), (df, mode.as_str()))?;
+            Ok(())
+        })
+        .map_err(|source| Error::PythonError {
+            source,
+            context: "write".to_string(),
+        })
+    }
+
+    fn write_plan(
+        &self,
+        name: &str,
+        plan: LogicalPlanBuilder,
+        mode: WriteMode,
+    ) -> Result<LogicalPlanBuilder> {
+        let table = Python::with_gil(|py| self.0.clone_ref(py));
+        Ok(plan.catalog_table_write(
+            name.to_string(),
+            mode.as_str().to_string(),
+            Arc::new(table),
+        )?)
     }
 
     fn to_py(&self, py: Python<'_>) -> PyResult<PyObject> {
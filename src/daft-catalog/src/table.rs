@@ -1,9 +1,12 @@
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 use daft_core::prelude::SchemaRef;
 use daft_logical_plan::{LogicalPlanBuilder, LogicalPlanRef};
 
-use crate::{bindings::Bindings, error::Result};
+use crate::{
+    bindings::Bindings,
+    error::{Error, Result},
+};
 
 /// Table implementation reference.
 pub type TableRef = Arc<dyn Table>;
@@ -35,6 +38,23 @@ impl From<LogicalPlanBuilder> for TableSource {
     }
 }
 
+/// Whether a write adds to or replaces the existing rows of a table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteMode {
+    Append,
+    Overwrite,
+}
+
+impl WriteMode {
+    /// Returns the mode as it is named by the python write APIs.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Append => "append",
+            Self::Overwrite => "overwrite",
+        }
+    }
+}
+
 /// TableProvider is a collection of referenceable tables.
 pub type TableProvider = Bindings<TableRef>;
 
@@ -46,6 +66,22 @@ pub trait Table: Sync + Send + std::fmt::Debug {
     /// Returns a logical plan for this table.
     fn get_logical_plan(&self) -> Result<LogicalPlanRef>;
 
+    /// Writes the result of the logical plan to this table, err if the table is read-only.
+    fn write(&self, _: LogicalPlanRef, _: WriteMode) -> Result<()> {
+        Err(Error::unsupported("writing to a read-only table"))
+    }
+
+    /// Returns a plan which writes the result of the logical plan to this table when it is executed,
+    /// err if the table is read-only.
+    fn write_plan(
+        &self,
+        _name: &str,
+        _: LogicalPlanBuilder,
+        _: WriteMode,
+    ) -> Result<LogicalPlanBuilder> {
+        Err(Error::unsupported("writing to a read-only table"))
+    }
+
     /// Leverage dynamic dispatch to return the inner object for a PyTableImpl (generics?)
     #[cfg(feature = "python")]
     fn to_py(&self, _: pyo3::Python<'_>) -> pyo3::PyResult<pyo3::PyObject> {
@@ -91,3 +127,70 @@ impl Table for View {
             .extract()
     }
 }
+
+/// MemTable is a mutable Table which holds the rows that were written to it, e.g. by CREATE TABLE AS.
+#[derive(Debug, Clone)]
+pub struct MemTable(Arc<RwLock<LogicalPlanRef>>);
+
+impl From<LogicalPlanRef> for MemTable {
+    fn from(plan: LogicalPlanRef) -> Self {
+        Self(Arc::new(RwLock::new(plan)))
+    }
+}
+
+impl MemTable {
+    pub fn arced(self) -> Arc<MemTable> {
+        Arc::new(self)
+    }
+}
+
+impl Table for MemTable {
+    fn get_schema(&self) -> SchemaRef {
+        self.0.read().unwrap().schema().clone()
+    }
+
+    fn get_logical_plan(&self) -> Result<LogicalPlanRef> {
+        Ok(self.0.read().unwrap().clone())
+    }
+
+    /// Replaces or appends to the table's plan, so the written plan should already hold its rows.
+    fn write(&self, plan: LogicalPlanRef, mode: WriteMode) -> Result<()> {
+        let mut rows = self.0.write().unwrap();
+        *rows = match mode {
+            WriteMode::Overwrite => plan,
+            WriteMode::Append => LogicalPlanBuilder::from(rows.clone())
+                .concat(&LogicalPlanBuilder::from(plan))?
+                .build(),
+        };
+        Ok(())
+    }
+
+    #[cfg(feature = "python")]
+    fn write_plan(
+        &self,
+        name: &str,
+        plan: LogicalPlanBuilder,
+        mode: WriteMode,
+    ) -> Result<LogicalPlanBuilder> {
+        let table =
+            pyo3::Python::with_gil(|py| self.to_py(py)).map_err(|source| Error::PythonError {
+                source,
+                context: "write_plan".to_string(),
+            })?;
+        Ok(plan.catalog_table_write(
+            name.to_string(),
+            mode.as_str().to_string(),
+            Arc::new(table),
+        )?)
+    }
+
+    #[cfg(feature = "python")]
+    fn to_py(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<pyo3::PyObject> {
+        use pyo3::{types::PyAnyMethods, IntoPyObject};
+
+        use crate::python::PyTable;
+        PyTable::new(self.clone().arced())
+            .into_pyobject(py)?
+            .extract()
+    }
+}
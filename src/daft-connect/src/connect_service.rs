@@ -1,3 +1,6 @@
+use std::sync::Arc;
+
+use daft_session::Session;
 use dashmap::DashMap;
 use spark_connect::{
     analyze_plan_request::explain::ExplainMode, command::CommandType, plan::OpType,
@@ -15,6 +18,7 @@ use uuid::Uuid;
 use crate::{
     display::SparkDisplay,
     error::Context,
+    global_temp::GlobalTempViews,
     invalid_argument_err, not_yet_implemented,
     response_builder::ResponseBuilder,
     session::ConnectSession,
@@ -25,12 +29,22 @@ use crate::{
 #[derive(Default)]
 pub struct DaftSparkConnectService {
     client_to_session: DashMap<Uuid, ConnectSession>, // To track session data
+    /// Daft session shared by all clients, otherwise each client gets its own session.
+    session: Option<Session>,
+    global_temp: Arc<GlobalTempViews>,
 }
 type ExecutePlanStream = std::pin::Pin<
     Box<dyn futures::Stream<Item = Result<ExecutePlanResponse, Status>> + Send + 'static>,
 >;
 
 impl DaftSparkConnectService {
+    pub fn new(session: Option<Session>) -> Self {
+        Self {
+            session,
+            ..Default::default()
+        }
+    }
+
     fn get_session(
         &self,
         session_id: &str,
@@ -41,10 +55,10 @@ impl DaftSparkConnectService {
             ));
         };
 
-        let res = self
-            .client_to_session
-            .entry(uuid)
-            .or_insert_with(|| ConnectSession::new(session_id.to_string()));
+        let res = self.client_to_session.entry(uuid).or_insert_with(|| {
+            let session = self.session.clone().unwrap_or_default();
+            ConnectSession::new(session_id.to_string(), session, self.global_temp.clone())
+        });
 
         Ok(res)
     }
@@ -52,6 +52,14 @@ impl From<daft_micropartition::Error> for ConnectError {
     }
 }
 
+impl From<daft_catalog::error::Error> for ConnectError {
+    fn from(value: daft_catalog::error::Error) -> Self {
+        Self::DaftError {
+            source: value.into(),
+        }
+    }
+}
+
 // -----------------------------------
 // Conversions from common error types
 // -----------------------------------
@@ -1,21 +1,23 @@
 use std::{future::ready, rc::Rc, sync::Arc};
 
 use common_error::DaftResult;
-use common_file_formats::FileFormat;
-use daft_catalog::TableSource;
+use common_file_formats::{CsvWriteOptions, FileFormat};
+use daft_catalog::{Identifier, TableSource, WriteMode};
 use daft_context::get_context;
-use daft_dsl::LiteralValue;
+use daft_dsl::{unresolved_col, LiteralValue};
 use daft_logical_plan::LogicalPlanBuilder;
 use daft_micropartition::MicroPartition;
 use daft_recordbatch::RecordBatch;
+use daft_schema::schema::Schema;
 use futures::{
     stream::{self, BoxStream},
     StreamExt, TryStreamExt,
 };
+use itertools::zip_eq;
 use pyo3::Python;
 use spark_connect::{
     relation::RelType,
-    write_operation::{SaveMode, SaveType},
+    write_operation::{save_table::TableSaveMethod, SaveMode, SaveTable, SaveType},
     CreateDataFrameViewCommand, ExecutePlanResponse, Relation, ShowString, SqlCommand,
     WriteOperation,
 };
@@ -23,8 +25,10 @@ use tonic::{codegen::tokio_stream::wrappers::ReceiverStream, Status};
 use tracing::debug;
 
 use crate::{
+    ensure,
     error::{ConnectError, ConnectResult, Context},
-    not_yet_implemented,
+    global_temp::GLOBAL_TEMP_DATABASE,
+    invalid_argument_err, not_yet_implemented,
     response_builder::ResponseBuilder,
     session::ConnectSession,
     spark_analyzer::SparkAnalyzer,
@@ -72,8 +76,10 @@ impl ConnectSession {
                     }
                     _ => {
                         let lp = translator.to_logical_plan(command).await?;
+                        let schema = lp.schema();
 
                         let mut result_stream = this.run_query(lp).await?;
+                        let mut is_empty = true;
 
                         while let Some(result) = result_stream.next().await {
                             let result = result?;
@@ -84,8 +90,16 @@ impl ConnectSession {
                                 if tx.send(Ok(response)).await.is_err() {
                                     return Ok(());
                                 }
+                                is_empty = false;
                             }
                         }
+
+                        // like spark, always send the schema so clients can build an empty result
+                        if is_empty {
+                            let table = RecordBatch::empty(Some(schema))?;
+                            let response = res.arrow_batch_response(&table)?;
+                            let _ = tx.send(Ok(response)).await;
+                        }
                         Ok(())
                     }
                 }
@@ -124,18 +138,13 @@ impl ConnectSession {
                 not_yet_implemented!("Bucketing by: {:?}", bucket_by);
             }
 
-            if !write_op.options.is_empty() {
-                // todo(completeness): implement options
-                debug!(
-                    "Ignoring options: {:?} (not yet implemented)",
-                    write_op.options
-                );
-            }
-
             let mode = SaveMode::try_from(write_op.mode)
                 .map_err(|_| Status::internal("invalid write mode"))?;
 
-            if mode == SaveMode::Unspecified {
+            // table writes honor the save mode, see `write_table`.
+            if mode == SaveMode::Unspecified
+                || matches!(write_op.save_type, Some(SaveType::Table(_)))
+            {
                 Ok(())
             } else {
                 not_yet_implemented!("save mode: {}", mode.as_str_name())
@@ -155,29 +164,47 @@ impl ConnectSession {
                 let WriteOperation {
                     input,
                     source,
+                    mode,
                     save_type,
+                    options,
                     ..
                 } = operation;
 
                 let input = input.required("input")?;
-                let source = source.required("source")?;
-
-                let file_format: FileFormat = source.parse()?;
-
                 let save_type = save_type.required("save_type")?;
 
+                let translator = SparkAnalyzer::new(&this);
+
                 let path = match save_type {
                     SaveType::Path(path) => path,
-                    SaveType::Table(_) => {
-                        not_yet_implemented!("write to table")
+                    SaveType::Table(table) => {
+                        let mode = SaveMode::try_from(mode)
+                            .map_err(|_| Status::internal("invalid write mode"))?;
+                        let plan = translator.to_logical_plan(input).await?;
+                        return this.write_table(table, mode, plan).await;
                     }
                 };
 
-                let translator = SparkAnalyzer::new(&this);
+                let source = source.required("source")?;
+
+                let file_format: FileFormat = source.parse()?;
+
+                let csv_options = match file_format {
+                    FileFormat::Csv => Some(CsvWriteOptions::try_from_options(
+                        options.iter().map(|(k, v)| (k.as_str(), v.as_str())),
+                    )?),
+                    _ => {
+                        if !options.is_empty() {
+                            // todo(completeness): implement options for other formats
+                            debug!("Ignoring options: {:?} (not yet implemented)", options);
+                        }
+                        None
+                    }
+                };
 
                 let plan = translator.to_logical_plan(input).await?;
 
-                let plan = plan.table_write(&path, file_format, None, None, None)?;
+                let plan = plan.table_write(&path, file_format, None, None, None, csv_options)?;
 
                 let mut result_stream = this.run_query(plan).await?;
 
@@ -208,6 +235,74 @@ impl ConnectSession {
         Ok(Box::pin(stream))
     }
 
+    /// Writes the plan to a table for `saveAsTable` and `insertInto`.
+    ///
+    /// The rows are written through the session, see
+    /// [`Session::write_table`](daft_session::Session::write_table), so views can't be written to.
+    /// `saveAsTable` to a table that doesn't exist creates it as a session table, since catalogs
+    /// can't create tables.
+    async fn write_table(
+        &self,
+        table: SaveTable,
+        mode: SaveMode,
+        plan: LogicalPlanBuilder,
+    ) -> ConnectResult<()> {
+        let SaveTable {
+            table_name,
+            save_method,
+        } = table;
+
+        let save_method = TableSaveMethod::try_from(save_method)
+            .map_err(|_| ConnectError::invalid_argument("invalid table save method"))?;
+
+        let ident = Identifier::from_sql(&table_name, false)?;
+        let session = self.session().clone();
+
+        if !session.has_table(&ident) {
+            ensure!(
+                save_method != TableSaveMethod::InsertInto,
+                "Table {} does not exist",
+                table_name
+            );
+            if ident.has_namespace() {
+                not_yet_implemented!(
+                    "saveAsTable to table {table_name} that does not exist in its catalog; catalogs can't create tables"
+                );
+            }
+            // materializing the rows runs the plan in python, so don't block the runtime.
+            tokio::task::spawn_blocking(move || {
+                session.create_temp_table_as(ident.name, plan, false)
+            })
+            .await??;
+            return Ok(());
+        }
+
+        // spark's saveAsTable defaults to error if exists whereas insertInto defaults to append.
+        let mode = match (save_method, mode) {
+            (_, SaveMode::Overwrite) => WriteMode::Overwrite,
+            (_, SaveMode::Append) | (TableSaveMethod::InsertInto, _) => WriteMode::Append,
+            (_, SaveMode::Ignore) => return Ok(()),
+            _ => invalid_argument_err!("Table {table_name} already exists"),
+        };
+
+        let table = session.get_table(&ident)?;
+
+        // saveAsTable in overwrite mode replaces the table, including its schema
+        let plan = match (save_method, mode) {
+            (TableSaveMethod::SaveAsTable, WriteMode::Overwrite) => plan,
+            _ => conform_to_table_schema(
+                plan,
+                &table.get_schema(),
+                save_method != TableSaveMethod::InsertInto,
+                &table_name,
+            )?,
+        };
+
+        // writes execute the plan in python, so don't block the runtime.
+        tokio::task::spawn_blocking(move || session.write_table(&ident, plan, mode)).await??;
+        Ok(())
+    }
+
     pub async fn execute_create_dataframe_view(
         &self,
         create_dataframe: CreateDataFrameViewCommand,
@@ -220,10 +315,6 @@ impl ConnectSession {
             replace,
         } = create_dataframe;
 
-        if is_global {
-            not_yet_implemented!("Global dataframe view");
-        }
-
         let input = input.required("input")?;
         let input = SparkAnalyzer::new(self)
             .to_logical_plan(input)
@@ -234,16 +325,24 @@ impl ConnectSession {
                 )
             })?;
 
-        let session = self.session_mut();
-        let source = TableSource::from(input);
-
-        session
-            .create_temp_table(name, &source, replace)
-            .map_err(|e| {
-                Status::internal(
-                    textwrap::wrap(&format!("Error in Daft server: {e}"), 120).join("\n"),
-                )
-            })?;
+        if is_global {
+            if !self.global_temp.create_view(&name, input.build(), replace) {
+                return Err(Status::already_exists(format!(
+                    "Global temporary view {name} already exists in database {GLOBAL_TEMP_DATABASE}"
+                )));
+            }
+        } else {
+            let session = self.session_mut();
+            let source = TableSource::from(input);
+
+            session
+                .create_temp_table(name, &source, replace)
+                .map_err(|e| {
+                    Status::internal(
+                        textwrap::wrap(&format!("Error in Daft server: {e}"), 120).join("\n"),
+                    )
+                })?;
+        }
 
         let response = rb.result_complete_response();
         let stream = stream::once(ready(Ok(response)));
@@ -368,3 +467,47 @@ impl ConnectSession {
         response_builder.arrow_batch_response(&tbl)
     }
 }
+
+/// Casts the columns of a plan to the schema of the table they are written to.
+///
+/// Like Spark, `insertInto` matches the columns by position and `saveAsTable` matches them by name.
+fn conform_to_table_schema(
+    plan: LogicalPlanBuilder,
+    schema: &Schema,
+    by_name: bool,
+    table_name: &str,
+) -> ConnectResult<LogicalPlanBuilder> {
+    let input = plan.schema();
+
+    ensure!(
+        input.len() == schema.len(),
+        "Cannot write {} columns to table {table_name} with {} columns",
+        input.len(),
+        schema.len()
+    );
+
+    let columns = if by_name {
+        schema
+            .fields
+            .values()
+            .map(|field| {
+                ensure!(
+                    input.has_field(&field.name),
+                    "Column `{}` of table {table_name} not found in the data to write",
+                    field.name
+                );
+                Ok(unresolved_col(field.name.as_str()).cast(&field.dtype))
+            })
+            .collect::<ConnectResult<Vec<_>>>()?
+    } else {
+        zip_eq(input.names(), schema.fields.values())
+            .map(|(name, field)| {
+                unresolved_col(name)
+                    .cast(&field.dtype)
+                    .alias(field.name.as_str())
+            })
+            .collect()
+    };
+
+    Ok(plan.select(columns)?)
+}
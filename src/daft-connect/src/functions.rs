@@ -2,12 +2,14 @@ use std::{collections::HashMap, sync::Arc};
 
 use daft_dsl::{
     functions::{ScalarFunction, ScalarUDF},
-    ExprRef,
+    ExprRef, LiteralValue,
 };
 use once_cell::sync::Lazy;
 use spark_connect::Expression;
 
-use crate::{error::ConnectResult, invalid_argument_err, spark_analyzer::SparkAnalyzer};
+use crate::{
+    error::ConnectResult, invalid_argument_err, not_yet_implemented, spark_analyzer::SparkAnalyzer,
+};
 mod aggregate;
 mod core;
 mod datetime;
@@ -71,6 +73,11 @@ pub trait FunctionModule {
 
 struct UnaryFunction(fn(ExprRef) -> ExprRef);
 
+struct BinaryFunction(fn(ExprRef, ExprRef) -> ExprRef);
+
+/// A function which is composed from any number of arguments, such as `greatest` or `concat_ws`.
+struct VariadicFunction(fn(Vec<ExprRef>) -> ConnectResult<ExprRef>);
+
 impl<T> SparkFunction for T
 where
     T: ScalarUDF + 'static + Clone,
@@ -106,15 +113,72 @@ impl SparkFunction for UnaryFunction {
     }
 }
 
-#[allow(non_camel_case_types)]
-struct TODO_FUNCTION;
+impl SparkFunction for BinaryFunction {
+    fn to_expr(
+        &self,
+        args: &[Expression],
+        analyzer: &SparkAnalyzer,
+    ) -> ConnectResult<daft_dsl::ExprRef> {
+        match args {
+            [lhs, rhs] => {
+                let lhs = analyzer.to_daft_expr(lhs)?;
+                let rhs = analyzer.to_daft_expr(rhs)?;
+                Ok(self.0(lhs, rhs))
+            }
+            _ => invalid_argument_err!("requires exactly two arguments"),
+        }
+    }
+}
+
+impl SparkFunction for VariadicFunction {
+    fn to_expr(
+        &self,
+        args: &[Expression],
+        analyzer: &SparkAnalyzer,
+    ) -> ConnectResult<daft_dsl::ExprRef> {
+        let args = args
+            .iter()
+            .map(|arg| analyzer.to_daft_expr(arg))
+            .collect::<ConnectResult<Vec<_>>>()?;
+        self.0(args)
+    }
+}
+
+/// Returns the value of an integer literal argument, such as the number of months of `add_months`.
+fn integer_literal(expr: &ExprRef, arg_name: &str) -> ConnectResult<i64> {
+    match expr.as_literal() {
+        Some(LiteralValue::Int8(i)) => Ok(i64::from(*i)),
+        Some(LiteralValue::UInt8(u)) => Ok(i64::from(*u)),
+        Some(LiteralValue::Int16(i)) => Ok(i64::from(*i)),
+        Some(LiteralValue::UInt16(u)) => Ok(i64::from(*u)),
+        Some(LiteralValue::Int32(i)) => Ok(i64::from(*i)),
+        Some(LiteralValue::UInt32(u)) => Ok(i64::from(*u)),
+        Some(LiteralValue::Int64(i)) => Ok(*i),
+        Some(LiteralValue::UInt64(u)) => Ok(*u as i64),
+        _ => invalid_argument_err!("{arg_name} must be an integer literal"),
+    }
+}
+
+/// Returns the value of a string literal argument, such as the format of `date_format`.
+fn string_literal<'a>(expr: &'a ExprRef, arg_name: &str) -> ConnectResult<&'a str> {
+    match expr.as_literal().and_then(LiteralValue::as_str) {
+        Some(s) => Ok(s),
+        None => invalid_argument_err!("{arg_name} must be a string literal"),
+    }
+}
+
+/// A spark function which Daft doesn't support, along with the reason why.
+struct UnsupportedFunction {
+    name: &'static str,
+    reason: &'static str,
+}
 
-impl SparkFunction for TODO_FUNCTION {
+impl SparkFunction for UnsupportedFunction {
     fn to_expr(
         &self,
         _args: &[Expression],
         _analyzer: &SparkAnalyzer,
     ) -> ConnectResult<daft_dsl::ExprRef> {
-        invalid_argument_err!("Function not implemented")
+        not_yet_implemented!("{} ({})", self.name, self.reason)
     }
 }
@@ -1,9 +1,21 @@
-use daft_dsl::{binary_op, Operator};
-use daft_functions::{coalesce::Coalesce, float::IsNan};
+use daft_dsl::{binary_op, lit, null_lit, row_number, Expr, ExprRef, Operator};
+use daft_functions::{
+    coalesce::Coalesce,
+    float::{is_nan, IsNan},
+    hash::hash,
+    list::{max as list_max, min as list_min},
+    numeric::{log::ln, sqrt::sqrt, trigonometry::cos},
+    to_struct::to_struct,
+};
+use daft_schema::dtype::DataType;
 use daft_sql::sql_expr;
+use itertools::Itertools;
 use spark_connect::Expression;
 
-use super::{FunctionModule, SparkFunction, UnaryFunction, TODO_FUNCTION};
+use super::{
+    string_literal, BinaryFunction, FunctionModule, SparkFunction, UnaryFunction,
+    UnsupportedFunction, VariadicFunction,
+};
 use crate::{
     error::{ConnectError, ConnectResult},
     invalid_argument_err,
@@ -16,6 +28,7 @@ pub struct CoreFunctions;
 impl FunctionModule for CoreFunctions {
     fn register(parent: &mut super::SparkFunctions) {
         parent.add_fn("==", BinaryOpFunction(Operator::Eq));
+        parent.add_fn("<=>", BinaryOpFunction(Operator::EqNullSafe));
         parent.add_fn("!=", BinaryOpFunction(Operator::NotEq));
         parent.add_fn("<", BinaryOpFunction(Operator::Lt));
         parent.add_fn("<=", BinaryOpFunction(Operator::LtEq));
@@ -36,22 +49,42 @@ impl FunctionModule for CoreFunctions {
         // https://spark.apache.org/docs/latest/api/python/reference/pyspark.sql/functions.html#normal-functions
 
         parent.add_fn("coalesce", Coalesce {});
-        parent.add_fn("input_file_name", TODO_FUNCTION);
+        parent.add_fn(
+            "input_file_name",
+            UnsupportedFunction {
+                name: "input_file_name",
+                reason: "expressions can't see the file a row was read from",
+            },
+        );
         parent.add_fn("isnan", IsNan {});
         parent.add_fn("isnull", UnaryFunction(|arg| arg.is_null()));
 
-        parent.add_fn("monotically_increasing_id", TODO_FUNCTION);
-        parent.add_fn("named_struct", TODO_FUNCTION);
-        parent.add_fn("nanvl", TODO_FUNCTION);
-        parent.add_fn("rand", TODO_FUNCTION);
-        parent.add_fn("randn", TODO_FUNCTION);
-        parent.add_fn("spark_partition_id", TODO_FUNCTION);
-        parent.add_fn("when", TODO_FUNCTION);
-        parent.add_fn("bitwise_not", TODO_FUNCTION);
-        parent.add_fn("bitwiseNOT", TODO_FUNCTION);
+        parent.add_fn(
+            "monotonically_increasing_id",
+            VariadicFunction(monotonically_increasing_id),
+        );
+        parent.add_fn("named_struct", VariadicFunction(named_struct));
+        parent.add_fn(
+            "nanvl",
+            BinaryFunction(|arg, fallback| is_nan(arg.clone()).if_else(fallback, arg)),
+        );
+        parent.add_fn("rand", VariadicFunction(rand));
+        parent.add_fn("randn", VariadicFunction(randn));
+        parent.add_fn("spark_partition_id", VariadicFunction(spark_partition_id));
+        parent.add_fn("when", VariadicFunction(when));
+        parent.add_fn("~", UnaryFunction(bitwise_not));
+        parent.add_fn("bitwise_not", UnaryFunction(bitwise_not));
+        parent.add_fn("bitwiseNOT", UnaryFunction(bitwise_not));
         parent.add_fn("expr", SqlExpr);
-        parent.add_fn("greatest", TODO_FUNCTION);
-        parent.add_fn("least", TODO_FUNCTION);
+        // like spark, both skip null values and only return null if all values are null
+        parent.add_fn(
+            "greatest",
+            VariadicFunction(|args| Ok(list_max(Expr::List(args).arced()))),
+        );
+        parent.add_fn(
+            "least",
+            VariadicFunction(|args| Ok(list_min(Expr::List(args).arced()))),
+        );
 
         // parent.add_fn("isnan", UnaryFunction(|arg| arg.is_nan()));
 
@@ -61,6 +94,113 @@ impl FunctionModule for CoreFunctions {
     }
 }
 
+/// `monotonically_increasing_id()`, which numbers the rows from 0 in their input order.
+fn monotonically_increasing_id(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    if !args.is_empty() {
+        invalid_argument_err!("monotonically_increasing_id takes no arguments");
+    }
+    Ok(row_number().cast(&DataType::Int64).sub(lit(1i64)))
+}
+
+/// `spark_partition_id()`, which is 0 for every row, as `monotonically_increasing_id` numbers the
+/// rows as a single partition.
+fn spark_partition_id(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    if !args.is_empty() {
+        invalid_argument_err!("spark_partition_id takes no arguments");
+    }
+    Ok(lit(0))
+}
+
+/// `rand([seed])`, which is uniformly distributed in [0, 1).
+///
+/// Daft has no random expression, so the numbers are derived from the hash of each row's number.
+/// They are deterministic for a seed and input order, and like window functions, they are only
+/// allowed in projections.
+fn rand(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    Ok(uniform(random_seed(&args, "rand")?))
+}
+
+/// `randn([seed])`, which is normally distributed with mean 0 and standard deviation 1.
+///
+/// The numbers are derived from two uniform numbers by the Box-Muller transform, see `rand`.
+fn randn(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let seed = random_seed(&args, "randn")?;
+    // 1 - u is in (0, 1], so its logarithm is finite
+    let radius = sqrt(ln(lit(1.0).sub(uniform(seed))).mul(lit(-2.0)));
+    let angle = uniform(seed.wrapping_add(1)).mul(lit(2.0 * std::f64::consts::PI));
+    Ok(radius.mul(cos(angle)))
+}
+
+/// The seed of `rand` and `randn`, which is random if it isn't given.
+fn random_seed(args: &[ExprRef], name: &str) -> ConnectResult<u64> {
+    match args {
+        [] => Ok(uuid::Uuid::new_v4().as_u64_pair().0),
+        [seed] => Ok(super::integer_literal(seed, &format!("{name} seed"))? as u64),
+        _ => invalid_argument_err!("{name} takes at most 1 argument; got {}", args.len()),
+    }
+}
+
+/// A number in [0, 1) for each row from the hash of its row number.
+fn uniform(seed: u64) -> ExprRef {
+    // doubles have 53 bits of precision, so the low 53 bits of the hash are scaled into [0, 1)
+    const PRECISION: u64 = 1 << 53;
+    binary_op(
+        Operator::Modulus,
+        hash(row_number(), Some(lit(seed))),
+        lit(PRECISION),
+    )
+    .cast(&DataType::Float64)
+    .div(lit(PRECISION as f64))
+}
+
+fn bitwise_not(arg: ExprRef) -> ExprRef {
+    binary_op(Operator::Xor, arg, lit(-1))
+}
+
+/// `when(cond1, value1, cond2, value2, ..., [otherwise])`, where a missing `otherwise` is null.
+fn when(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    if args.len() < 2 {
+        invalid_argument_err!("when requires at least 2 arguments; got {}", args.len());
+    }
+
+    let (branches, otherwise) = match args.split_last() {
+        Some((otherwise, branches)) if args.len() % 2 == 1 => (branches, otherwise.clone()),
+        _ => (args.as_slice(), null_lit()),
+    };
+
+    // a null condition does not match, as in a `CASE WHEN`
+    Ok(branches
+        .chunks_exact(2)
+        .rev()
+        .fold(otherwise, |otherwise, branch| {
+            branch[0]
+                .clone()
+                .fill_null(lit(false))
+                .if_else(branch[1].clone(), otherwise)
+        }))
+}
+
+/// `named_struct(name1, value1, name2, value2, ...)`, where the names are string literals.
+fn named_struct(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    if args.is_empty() || args.len() % 2 == 1 {
+        invalid_argument_err!(
+            "named_struct requires an even number of arguments; got {}",
+            args.len()
+        );
+    }
+
+    let fields = args
+        .into_iter()
+        .tuples()
+        .map(|(name, value)| {
+            let name = string_literal(&name, "named_struct field name")?;
+            Ok(value.alias(name))
+        })
+        .collect::<ConnectResult<Vec<_>>>()?;
+
+    Ok(to_struct(fields))
+}
+
 pub struct BinaryOpFunction(Operator);
 
 impl SparkFunction for BinaryOpFunction {
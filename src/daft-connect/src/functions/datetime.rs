@@ -1,78 +1,844 @@
-use daft_core::datatypes::TimeUnit;
-use daft_functions::temporal::{Day, DayOfWeek, Hour, Minute, Month, Second, Year};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use daft_core::datatypes::{IntervalValue, TimeUnit};
+use daft_dsl::{binary_op, lit, null_lit, ExprRef, LiteralValue, Operator};
+use daft_functions::{
+    numeric::round::round,
+    temporal::{
+        dt_day, dt_day_of_week, dt_day_of_year, dt_hour, dt_minute, dt_month, dt_second,
+        dt_week_of_year, dt_year,
+        strftime::dt_strftime,
+        timezone::{dt_convert_timezone, dt_replace_timezone},
+        truncate::dt_truncate,
+        Day, Hour, Minute, Month, Second, Year,
+    },
+    utf8::{
+        to_date as utf8_to_date, to_datetime as utf8_to_datetime, try_to_date as utf8_try_to_date,
+        try_to_datetime as utf8_try_to_datetime,
+    },
+};
 use daft_schema::dtype::DataType;
+use spark_connect::Expression;
 
-use super::{FunctionModule, UnaryFunction, TODO_FUNCTION};
+use super::{
+    string_literal, BinaryFunction, FunctionModule, SparkFunction, UnaryFunction,
+    UnsupportedFunction, VariadicFunction,
+};
+use crate::{
+    error::{ConnectError, ConnectResult},
+    invalid_argument_err, not_yet_implemented,
+    spark_analyzer::SparkAnalyzer,
+};
 
 /// https://spark.apache.org/docs/latest/api/python/reference/pyspark.sql/functions.html#datetime-functions
 pub struct DatetimeFunctions;
 
 impl FunctionModule for DatetimeFunctions {
     fn register(parent: &mut super::SparkFunctions) {
-        parent.add_fn("add_months", TODO_FUNCTION);
-        parent.add_fn("convert_timezone", TODO_FUNCTION);
-        parent.add_fn("curdate", TODO_FUNCTION);
-        parent.add_fn("current_date", TODO_FUNCTION);
-        parent.add_fn("current_timestamp", TODO_FUNCTION);
-        parent.add_fn("current_timezone", TODO_FUNCTION);
-        parent.add_fn("date_add", TODO_FUNCTION);
-        parent.add_fn("date_diff", TODO_FUNCTION);
-        parent.add_fn("date_format", TODO_FUNCTION);
-        parent.add_fn("date_from_unix_date", TODO_FUNCTION);
-        parent.add_fn("date_part", TODO_FUNCTION);
-        parent.add_fn("date_sub", TODO_FUNCTION);
-        parent.add_fn("date_trunc", TODO_FUNCTION);
-        parent.add_fn("dateadd", TODO_FUNCTION);
-        parent.add_fn("datediff", TODO_FUNCTION);
-        parent.add_fn("datepart", TODO_FUNCTION);
+        parent.add_fn("add_months", VariadicFunction(add_months));
+        parent.add_fn("convert_timezone", ConvertTimezoneFunction);
+        parent.add_fn("curdate", CurrentTimeFunction::Date);
+        parent.add_fn("current_date", CurrentTimeFunction::Date);
+        parent.add_fn("current_timestamp", CurrentTimeFunction::Timestamp);
+        parent.add_fn("current_timezone", CurrentTimeFunction::Timezone);
+        parent.add_fn("date_add", BinaryFunction(add_days));
+        parent.add_fn("date_diff", BinaryFunction(date_diff));
+        parent.add_fn("date_format", VariadicFunction(date_format));
+        parent.add_fn(
+            "date_from_unix_date",
+            UnaryFunction(|arg| arg.cast(&DataType::Int32).cast(&DataType::Date)),
+        );
+        parent.add_fn("date_part", VariadicFunction(date_part));
+        parent.add_fn("date_sub", BinaryFunction(date_sub));
+        parent.add_fn("date_trunc", VariadicFunction(date_trunc));
+        parent.add_fn("dateadd", BinaryFunction(add_days));
+        parent.add_fn("datediff", BinaryFunction(date_diff));
+        parent.add_fn("datepart", VariadicFunction(date_part));
         parent.add_fn("day", Day);
-        parent.add_fn("dayofmonth", TODO_FUNCTION);
-        parent.add_fn("dayofweek", DayOfWeek);
-        parent.add_fn("dayofyear", TODO_FUNCTION);
-        parent.add_fn("extract", TODO_FUNCTION);
-        parent.add_fn("from_unixtime", TODO_FUNCTION);
-        parent.add_fn("from_utc_timestamp", TODO_FUNCTION);
+        parent.add_fn("dayofmonth", Day);
+        parent.add_fn("dayofweek", UnaryFunction(day_of_week));
+        parent.add_fn("dayofyear", UnaryFunction(dt_day_of_year));
+        parent.add_fn("extract", VariadicFunction(date_part));
+        parent.add_fn("from_unixtime", VariadicFunction(from_unixtime));
+        parent.add_fn("from_utc_timestamp", FromUtcTimestampFunction);
         parent.add_fn("hour", Hour);
-        parent.add_fn("last_day", TODO_FUNCTION);
-        parent.add_fn("localtimestamp", TODO_FUNCTION);
-        parent.add_fn("make_date", TODO_FUNCTION);
-        parent.add_fn("make_dt_interval", TODO_FUNCTION);
-        parent.add_fn("make_interval", TODO_FUNCTION);
-        parent.add_fn("make_timestamp", TODO_FUNCTION);
-        parent.add_fn("make_timestamp_ltz", TODO_FUNCTION);
-        parent.add_fn("make_timestamp_ntz", TODO_FUNCTION);
-        parent.add_fn("make_ym_interval", TODO_FUNCTION);
+        parent.add_fn("last_day", UnaryFunction(last_day));
+        parent.add_fn("localtimestamp", CurrentTimeFunction::Timestamp);
+        parent.add_fn("make_date", VariadicFunction(make_date));
+        parent.add_fn("make_dt_interval", VariadicFunction(make_dt_interval));
+        parent.add_fn("make_interval", VariadicFunction(make_interval));
+        parent.add_fn("make_timestamp", VariadicFunction(make_timestamp));
+        parent.add_fn("make_timestamp_ltz", VariadicFunction(make_timestamp));
+        parent.add_fn("make_timestamp_ntz", VariadicFunction(make_timestamp));
+        parent.add_fn("make_ym_interval", VariadicFunction(make_ym_interval));
         parent.add_fn("minute", Minute);
         parent.add_fn("month", Month);
-        parent.add_fn("months_between", TODO_FUNCTION);
-        parent.add_fn("next_day", TODO_FUNCTION);
-        parent.add_fn("now", TODO_FUNCTION);
-        parent.add_fn("quarter", TODO_FUNCTION);
+        parent.add_fn("months_between", VariadicFunction(months_between));
+        parent.add_fn("next_day", VariadicFunction(next_day));
+        parent.add_fn("now", CurrentTimeFunction::Timestamp);
+        parent.add_fn("quarter", UnaryFunction(quarter));
         parent.add_fn("second", Second);
-        parent.add_fn("session_window", TODO_FUNCTION);
-        parent.add_fn("timestamp_micros", TODO_FUNCTION);
-        parent.add_fn("timestamp_millis", TODO_FUNCTION);
-        parent.add_fn("timestamp_seconds", TODO_FUNCTION);
-        parent.add_fn("to_date", UnaryFunction(|arg| arg.cast(&DataType::Date)));
         parent.add_fn(
-            "to_timestamp",
-            UnaryFunction(|arg| arg.cast(&DataType::Timestamp(TimeUnit::Milliseconds, None))),
+            "session_window",
+            UnsupportedFunction {
+                name: "session_window",
+                reason: "sessions depend on the gaps between the rows of a key, which a projection can't compute",
+            },
+        );
+        parent.add_fn(
+            "timestamp_micros",
+            UnaryFunction(|arg| from_epoch(arg, TimeUnit::Microseconds)),
+        );
+        parent.add_fn(
+            "timestamp_millis",
+            UnaryFunction(|arg| from_epoch(arg, TimeUnit::Milliseconds)),
+        );
+        parent.add_fn(
+            "timestamp_seconds",
+            UnaryFunction(|arg| from_epoch(arg, TimeUnit::Seconds)),
+        );
+        parent.add_fn("to_date", VariadicFunction(to_date));
+        parent.add_fn("to_timestamp", VariadicFunction(to_timestamp));
+        parent.add_fn("to_timestamp_ltz", VariadicFunction(to_timestamp));
+        parent.add_fn("to_timestamp_ntz", VariadicFunction(to_timestamp));
+        parent.add_fn("to_unix_timestamp", VariadicFunction(unix_timestamp));
+        parent.add_fn("to_utc_timestamp", ToUtcTimestampFunction);
+        parent.add_fn("trunc", VariadicFunction(trunc));
+        parent.add_fn("try_to_timestamp", VariadicFunction(try_to_timestamp));
+        parent.add_fn(
+            "unix_date",
+            UnaryFunction(|arg| arg.cast(&DataType::Date).cast(&DataType::Int32)),
+        );
+        parent.add_fn(
+            "unix_micros",
+            UnaryFunction(|arg| to_epoch(arg, TimeUnit::Microseconds)),
+        );
+        parent.add_fn(
+            "unix_millis",
+            UnaryFunction(|arg| to_epoch(arg, TimeUnit::Milliseconds)),
+        );
+        parent.add_fn(
+            "unix_seconds",
+            UnaryFunction(|arg| to_epoch(arg, TimeUnit::Seconds)),
+        );
+        parent.add_fn("unix_timestamp", VariadicFunction(unix_timestamp));
+        parent.add_fn(
+            "weekday",
+            UnaryFunction(|arg| dt_day_of_week(arg).cast(&DataType::Int32)),
+        );
+        parent.add_fn(
+            "weekofyear",
+            UnaryFunction(|arg| dt_week_of_year(arg).cast(&DataType::Int32)),
+        );
+        parent.add_fn(
+            "window",
+            UnsupportedFunction {
+                name: "window",
+                reason: "spark drops the rows without a time and copies rows into every sliding window they fall in, which a projection can't do",
+            },
+        );
+        parent.add_fn(
+            "window_time",
+            UnsupportedFunction {
+                name: "window_time",
+                reason: "it reads the window column that window produces",
+            },
         );
-        parent.add_fn("to_timestamp_ltz", TODO_FUNCTION);
-        parent.add_fn("to_timestamp_ntz", TODO_FUNCTION);
-        parent.add_fn("to_unix_timestamp", TODO_FUNCTION);
-        parent.add_fn("to_utc_timestamp", TODO_FUNCTION);
-        parent.add_fn("trunc", TODO_FUNCTION);
-        parent.add_fn("try_to_timestamp", TODO_FUNCTION);
-        parent.add_fn("unix_date", TODO_FUNCTION);
-        parent.add_fn("unix_micros", TODO_FUNCTION);
-        parent.add_fn("unix_millis", TODO_FUNCTION);
-        parent.add_fn("unix_seconds", TODO_FUNCTION);
-        parent.add_fn("unix_timestamp", TODO_FUNCTION);
-        parent.add_fn("weekday", TODO_FUNCTION);
-        parent.add_fn("weekofyear", TODO_FUNCTION);
-        parent.add_fn("window", TODO_FUNCTION);
-        parent.add_fn("window_time", TODO_FUNCTION);
         parent.add_fn("year", Year);
     }
 }
+
+/// The default pattern of `from_unixtime`.
+const DEFAULT_TIMESTAMP_PATTERN: &str = "yyyy-MM-dd HH:mm:ss";
+
+fn timestamp_type() -> DataType {
+    DataType::Timestamp(TimeUnit::Microseconds, None)
+}
+
+/// Converts a java `DateTimeFormatter` pattern, as used by spark, into a chrono strftime format.
+pub(super) fn to_strftime_format(pattern: &str) -> ConnectResult<String> {
+    let chars = pattern.chars().collect::<Vec<_>>();
+    let mut format = String::with_capacity(pattern.len());
+
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+
+        // text between single quotes is taken literally, with two single quotes being a quote
+        if c == '\'' {
+            if chars.get(i + 1) == Some(&'\'') {
+                format.push('\'');
+                i += 2;
+                continue;
+            }
+            i += 1;
+            while i < chars.len() {
+                match (chars[i], chars.get(i + 1)) {
+                    ('\'', Some('\'')) => {
+                        format.push('\'');
+                        i += 2;
+                    }
+                    ('\'', _) => break,
+                    ('%', _) => {
+                        format.push_str("%%");
+                        i += 1;
+                    }
+                    (c, _) => {
+                        format.push(c);
+                        i += 1;
+                    }
+                }
+            }
+            i += 1;
+            continue;
+        }
+
+        if !c.is_ascii_alphabetic() {
+            if c == '%' {
+                format.push_str("%%");
+            } else {
+                format.push(c);
+            }
+            i += 1;
+            continue;
+        }
+
+        let count = chars[i..].iter().take_while(|&&next| next == c).count();
+        let specifier = match (c, count) {
+            ('y', 2) => "%y",
+            ('y' | 'u', _) => "%Y",
+            ('M' | 'L', 1) => "%-m",
+            ('M' | 'L', 2) => "%m",
+            ('M' | 'L', 3) => "%b",
+            ('M' | 'L', _) => "%B",
+            ('d', 1) => "%-d",
+            ('d', _) => "%d",
+            ('D', _) => "%j",
+            ('H', 1) => "%-H",
+            ('H', _) => "%H",
+            ('h', 1) => "%-I",
+            ('h', _) => "%I",
+            ('m', 1) => "%-M",
+            ('m', _) => "%M",
+            ('s', 1) => "%-S",
+            ('s', _) => "%S",
+            ('S', 1..=3) => "%3f",
+            ('S', 4..=6) => "%6f",
+            ('S', _) => "%9f",
+            ('a', _) => "%p",
+            ('E', 1..=3) => "%a",
+            ('E', _) => "%A",
+            ('X' | 'x', 3..) => "%:z",
+            ('Z' | 'X' | 'x', _) => "%z",
+            ('z', _) => "%Z",
+            _ => not_yet_implemented!("datetime pattern letter '{c}' in \"{pattern}\""),
+        };
+        format.push_str(specifier);
+        i += count;
+    }
+
+    Ok(format)
+}
+
+/// Whether a strftime format contains any time of day fields.
+fn has_time_fields(format: &str) -> bool {
+    format.split('%').skip(1).any(|spec| {
+        matches!(
+            spec.trim_start_matches('-').chars().next(),
+            Some('H' | 'I' | 'M' | 'S')
+        )
+    })
+}
+
+fn add_days(date: ExprRef, days: ExprRef) -> ExprRef {
+    date.cast(&DataType::Date)
+        .cast(&DataType::Int32)
+        .add(days.cast(&DataType::Int32))
+        .cast(&DataType::Date)
+}
+
+fn date_sub(date: ExprRef, days: ExprRef) -> ExprRef {
+    add_days(date, lit(0).sub(days.cast(&DataType::Int32)))
+}
+
+fn date_diff(end: ExprRef, start: ExprRef) -> ExprRef {
+    end.cast(&DataType::Date)
+        .cast(&DataType::Int32)
+        .sub(start.cast(&DataType::Date).cast(&DataType::Int32))
+}
+
+fn add_interval(date: ExprRef, months: i32, days: i32) -> ExprRef {
+    date.cast(&DataType::Date)
+        .add(lit(IntervalValue::new(months, days, 0)))
+        .cast(&DataType::Date)
+}
+
+/// `add_months(date, months)`, which clamps the day to the end of the resulting month.
+fn add_months(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let [date, months] = args.as_slice() else {
+        invalid_argument_err!("add_months requires exactly 2 arguments");
+    };
+    let months = super::integer_literal(months, "add_months months")?;
+    Ok(add_interval(date.clone(), months as i32, 0))
+}
+
+/// `make_interval([years[, months[, weeks[, days[, hours[, mins[, secs]]]]]]])`.
+fn make_interval(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let [years, months, weeks, days, hours, mins, secs] = interval_fields(&args, "make_interval")?;
+    let months = years.mul_add(12.0, months);
+    let days = weeks.mul_add(7.0, days);
+    Ok(interval_literal(months, days, hours, mins, secs))
+}
+
+/// `make_dt_interval([days[, hours[, mins[, secs]]]])`.
+fn make_dt_interval(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let [days, hours, mins, secs] = interval_fields(&args, "make_dt_interval")?;
+    Ok(interval_literal(0.0, days, hours, mins, secs))
+}
+
+/// `make_ym_interval([years[, months]])`.
+fn make_ym_interval(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let [years, months] = interval_fields(&args, "make_ym_interval")?;
+    Ok(interval_literal(
+        years.mul_add(12.0, months),
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+    ))
+}
+
+/// The fields of an interval constructor, which must be literals and default to 0. Only the seconds may have a
+/// fraction in spark, but any float literal is accepted.
+///
+/// Daft has no expression that builds an interval from columns, so only literal intervals are supported.
+fn interval_fields<const N: usize>(args: &[ExprRef], name: &str) -> ConnectResult<[f64; N]> {
+    if args.len() > N {
+        invalid_argument_err!("{name} takes at most {N} arguments; got {}", args.len());
+    }
+    let mut fields = [0.0; N];
+    for (field, arg) in fields.iter_mut().zip(args) {
+        *field = match arg.as_literal() {
+            Some(LiteralValue::Float64(f)) => *f,
+            _ => super::integer_literal(arg, &format!("{name} argument"))? as f64,
+        };
+    }
+    Ok(fields)
+}
+
+fn interval_literal(months: f64, days: f64, hours: f64, mins: f64, secs: f64) -> ExprRef {
+    let nanos = (hours.mul_add(3600.0, mins.mul_add(60.0, secs)) * 1e9).round();
+    lit(IntervalValue::new(months as i32, days as i32, nanos as i64))
+}
+
+fn first_of_month(date: ExprRef) -> ExprRef {
+    let date = date.cast(&DataType::Date);
+    add_days(
+        date.clone(),
+        lit(1).sub(dt_day(date).cast(&DataType::Int32)),
+    )
+}
+
+fn first_of_year(date: ExprRef) -> ExprRef {
+    let date = date.cast(&DataType::Date);
+    add_days(
+        date.clone(),
+        lit(1).sub(dt_day_of_year(date).cast(&DataType::Int32)),
+    )
+}
+
+fn first_of_quarter(date: ExprRef) -> ExprRef {
+    let date = date.cast(&DataType::Date);
+    let first_of_month = first_of_month(date.clone());
+    let months_into_quarter = dt_month(date)
+        .cast(&DataType::Int32)
+        .sub(lit(1))
+        .rem(lit(3));
+
+    months_into_quarter.clone().eq(lit(0)).if_else(
+        first_of_month.clone(),
+        months_into_quarter.eq(lit(1)).if_else(
+            add_interval(first_of_month.clone(), -1, 0),
+            add_interval(first_of_month, -2, 0),
+        ),
+    )
+}
+
+fn first_of_week(date: ExprRef) -> ExprRef {
+    let date = date.cast(&DataType::Date);
+    add_days(
+        date.clone(),
+        lit(0).sub(dt_day_of_week(date).cast(&DataType::Int32)),
+    )
+}
+
+fn last_day(date: ExprRef) -> ExprRef {
+    add_interval(first_of_month(date), 1, -1)
+}
+
+/// `dayofweek`, which ranges from 1 for sunday to 7 for saturday.
+fn day_of_week(date: ExprRef) -> ExprRef {
+    dt_day_of_week(date)
+        .cast(&DataType::Int32)
+        .add(lit(1))
+        .rem(lit(7))
+        .add(lit(1))
+}
+
+fn quarter(date: ExprRef) -> ExprRef {
+    binary_op(
+        Operator::FloorDivide,
+        dt_month(date).cast(&DataType::Int32).sub(lit(1)),
+        lit(3),
+    )
+    .add(lit(1))
+}
+
+/// `date_part(field, source)`, `datepart(field, source)` and `extract(field, source)`.
+fn date_part(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let [field, source] = args.as_slice() else {
+        invalid_argument_err!("date_part requires exactly 2 arguments");
+    };
+    let source = source.clone();
+
+    let part = match string_literal(field, "date_part field")?
+        .to_uppercase()
+        .as_str()
+    {
+        "YEAR" | "Y" | "YEARS" | "YR" | "YRS" => dt_year(source),
+        "QUARTER" | "QTR" => quarter(source),
+        "MONTH" | "MON" | "MONS" | "MONTHS" => dt_month(source),
+        "WEEK" | "W" | "WEEKS" => dt_week_of_year(source),
+        "DAY" | "D" | "DAYS" => dt_day(source),
+        "DAYOFWEEK" | "DOW" => day_of_week(source),
+        "DAYOFWEEK_ISO" | "DOW_ISO" => dt_day_of_week(source).cast(&DataType::Int32).add(lit(1)),
+        "DOY" => dt_day_of_year(source),
+        "HOUR" | "H" | "HOURS" | "HR" | "HRS" => dt_hour(source),
+        "MINUTE" | "M" | "MIN" | "MINS" | "MINUTES" => dt_minute(source),
+        "SECOND" | "S" | "SEC" | "SECONDS" | "SECS" => dt_second(source),
+        field => invalid_argument_err!("unsupported date_part field: {field}"),
+    };
+    Ok(part.cast(&DataType::Int32))
+}
+
+/// `date_trunc(format, timestamp)`, which returns a timestamp.
+fn date_trunc(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let [format, timestamp] = args.as_slice() else {
+        invalid_argument_err!("date_trunc requires exactly 2 arguments");
+    };
+    let timestamp = timestamp.clone().cast(&timestamp_type());
+
+    let interval = match string_literal(format, "date_trunc format")?
+        .to_uppercase()
+        .as_str()
+    {
+        "YEAR" | "YYYY" | "YY" => return Ok(first_of_year(timestamp).cast(&timestamp_type())),
+        "QUARTER" => return Ok(first_of_quarter(timestamp).cast(&timestamp_type())),
+        "MONTH" | "MM" | "MON" => return Ok(first_of_month(timestamp).cast(&timestamp_type())),
+        "WEEK" => return Ok(first_of_week(timestamp).cast(&timestamp_type())),
+        "DAY" | "DD" => "1 day",
+        "HOUR" => "1 hour",
+        "MINUTE" => "1 minute",
+        "SECOND" => "1 second",
+        "MILLISECOND" => "1 millisecond",
+        "MICROSECOND" => return Ok(timestamp),
+        format => invalid_argument_err!("unsupported date_trunc format: {format}"),
+    };
+    Ok(dt_truncate(timestamp, interval, null_lit()))
+}
+
+/// `trunc(date, format)`, which returns a date.
+fn trunc(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let [date, format] = args.as_slice() else {
+        invalid_argument_err!("trunc requires exactly 2 arguments");
+    };
+    let date = date.clone();
+
+    match string_literal(format, "trunc format")?
+        .to_uppercase()
+        .as_str()
+    {
+        "YEAR" | "YYYY" | "YY" => Ok(first_of_year(date)),
+        "QUARTER" => Ok(first_of_quarter(date)),
+        "MONTH" | "MM" | "MON" => Ok(first_of_month(date)),
+        "WEEK" => Ok(first_of_week(date)),
+        // spark returns null for any other format
+        _ => Ok(null_lit().cast(&DataType::Date)),
+    }
+}
+
+/// `next_day(date, day_of_week)`, the first date after `date` which falls on `day_of_week`.
+fn next_day(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let [date, day_of_week] = args.as_slice() else {
+        invalid_argument_err!("next_day requires exactly 2 arguments");
+    };
+
+    let target = match string_literal(day_of_week, "next_day day of week")?
+        .to_uppercase()
+        .as_str()
+    {
+        "MO" | "MON" | "MONDAY" => 0,
+        "TU" | "TUE" | "TUESDAY" => 1,
+        "WE" | "WED" | "WEDNESDAY" => 2,
+        "TH" | "THU" | "THURSDAY" => 3,
+        "FR" | "FRI" | "FRIDAY" => 4,
+        "SA" | "SAT" | "SATURDAY" => 5,
+        "SU" | "SUN" | "SUNDAY" => 6,
+        day => invalid_argument_err!("invalid day of week: {day}"),
+    };
+
+    let date = date.clone().cast(&DataType::Date);
+    let days = lit(target + 6)
+        .sub(dt_day_of_week(date.clone()).cast(&DataType::Int32))
+        .rem(lit(7))
+        .add(lit(1));
+    Ok(add_days(date, days))
+}
+
+/// `months_between(end, start[, round_off])`, which counts the days of a partial month as
+/// fractions of 31 days, unless both dates are on the same day of the month or the last day of
+/// their months.
+fn months_between(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let (end, start, round_off) = match args.as_slice() {
+        [end, start] => (end, start, true),
+        [end, start, round_off] => match round_off.as_literal() {
+            Some(LiteralValue::Boolean(round_off)) => (end, start, *round_off),
+            _ => invalid_argument_err!("months_between round off must be a boolean literal"),
+        },
+        _ => invalid_argument_err!("months_between requires 2 or 3 arguments"),
+    };
+    let end = end.clone().cast(&timestamp_type());
+    let start = start.clone().cast(&timestamp_type());
+
+    let as_int = |expr: ExprRef| expr.cast(&DataType::Int64);
+    let total_months = |ts: ExprRef| {
+        as_int(dt_year(ts.clone()))
+            .mul(lit(12i64))
+            .add(as_int(dt_month(ts)))
+    };
+    let seconds_into_month = |ts: ExprRef| {
+        as_int(dt_day(ts.clone()))
+            .mul(lit(86_400i64))
+            .add(as_int(dt_hour(ts.clone())).mul(lit(3_600i64)))
+            .add(as_int(dt_minute(ts.clone())).mul(lit(60i64)))
+            .add(as_int(dt_second(ts)))
+    };
+    let is_last_day = |ts: ExprRef| dt_day(ts.clone()).eq(dt_day(last_day(ts)));
+
+    let months = total_months(end.clone())
+        .sub(total_months(start.clone()))
+        .cast(&DataType::Float64);
+    let fraction = seconds_into_month(end.clone())
+        .sub(seconds_into_month(start.clone()))
+        .cast(&DataType::Float64)
+        .div(lit(31.0 * 86_400.0));
+
+    let is_whole = dt_day(end.clone())
+        .eq(dt_day(start.clone()))
+        .or(is_last_day(end).and(is_last_day(start)));
+    let between = is_whole.if_else(months.clone(), months.add(fraction));
+
+    Ok(if round_off {
+        round(between, Some(8))
+    } else {
+        between
+    })
+}
+
+/// Builds a date or timestamp by parsing the concatenation of its fields.
+fn parse_fields(fields: &[ExprRef], separators: &[&str]) -> ExprRef {
+    fields
+        .iter()
+        .zip(std::iter::once("").chain(separators.iter().copied()))
+        .map(|(field, separator)| lit(separator).add(field.clone().cast(&DataType::Utf8)))
+        .reduce(|acc, field| acc.add(field))
+        .expect("fields are not empty")
+}
+
+/// `make_date(year, month, day)`.
+fn make_date(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let [year, month, day] = args.as_slice() else {
+        invalid_argument_err!("make_date requires exactly 3 arguments");
+    };
+    let fields = [year, month, day].map(|field| field.clone().cast(&DataType::Int64));
+    // like spark, fields that don't make up a valid date, such as february 30, are null
+    Ok(utf8_try_to_date(
+        parse_fields(&fields, &["-", "-"]),
+        "%Y-%m-%d",
+    ))
+}
+
+/// `make_timestamp(year, month, day, hour, minute, second[, timezone])`.
+fn make_timestamp(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let (fields, timezone) = match args.as_slice() {
+        fields if fields.len() == 6 => (fields, None),
+        [fields @ .., timezone] if fields.len() == 6 => (
+            fields,
+            Some(string_literal(timezone, "make_timestamp timezone")?),
+        ),
+        _ => invalid_argument_err!("make_timestamp requires 6 or 7 arguments"),
+    };
+
+    // all fields but the seconds, which may have a fraction, are integers
+    let fields = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let dtype = if i == 5 {
+                DataType::Float64
+            } else {
+                DataType::Int64
+            };
+            field.clone().cast(&dtype)
+        })
+        .collect::<Vec<_>>();
+
+    let timestamp = utf8_try_to_datetime(
+        parse_fields(&fields, &["-", "-", " ", ":", ":"]),
+        "%Y-%m-%d %H:%M:%S%.f",
+        None,
+    )
+    .cast(&timestamp_type());
+
+    Ok(match timezone {
+        Some(timezone) => shift_timezone(timestamp, Some(timezone), "UTC"),
+        None => timestamp,
+    })
+}
+
+/// `to_date(input[, format])`, which parses strings with a java `DateTimeFormatter` pattern.
+fn to_date(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    match args.as_slice() {
+        [input] => Ok(input.clone().cast(&DataType::Date)),
+        [input, format] => {
+            let format = to_strftime_format(string_literal(format, "to_date format")?)?;
+            if has_time_fields(&format) {
+                Ok(utf8_to_datetime(input.clone(), format, None).cast(&DataType::Date))
+            } else {
+                Ok(utf8_to_date(input.clone(), format))
+            }
+        }
+        _ => invalid_argument_err!("to_date requires 1 or 2 arguments"),
+    }
+}
+
+/// `to_timestamp(input[, format])`, which parses strings with a java `DateTimeFormatter` pattern.
+fn to_timestamp(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    match args.as_slice() {
+        [input] => Ok(input.clone().cast(&timestamp_type())),
+        [input, format] => {
+            let format = string_literal(format, "to_timestamp format")?;
+            parse_timestamp(input.clone(), format, true)
+        }
+        _ => invalid_argument_err!("to_timestamp requires 1 or 2 arguments"),
+    }
+}
+
+/// `try_to_timestamp(input[, format])`, which returns null for strings that don't match the format.
+fn try_to_timestamp(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    match args.as_slice() {
+        // casts already return null for malformed strings
+        [input] => Ok(input.clone().cast(&timestamp_type())),
+        [input, format] => {
+            let format = string_literal(format, "try_to_timestamp format")?;
+            parse_timestamp(input.clone(), format, false)
+        }
+        _ => invalid_argument_err!("try_to_timestamp requires 1 or 2 arguments"),
+    }
+}
+
+/// Parses strings with a java `DateTimeFormatter` pattern, returning null for strings that don't
+/// match it unless `raise_error_on_failure` is set.
+fn parse_timestamp(
+    input: ExprRef,
+    pattern: &str,
+    raise_error_on_failure: bool,
+) -> ConnectResult<ExprRef> {
+    let format = to_strftime_format(pattern)?;
+
+    // chrono can't parse a datetime without a time, which spark takes to be midnight
+    let timestamp = match (has_time_fields(&format), raise_error_on_failure) {
+        (true, true) => utf8_to_datetime(input, format, None),
+        (true, false) => utf8_try_to_datetime(input, format, None),
+        (false, true) => utf8_to_date(input, format),
+        (false, false) => utf8_try_to_date(input, format),
+    };
+    Ok(timestamp.cast(&timestamp_type()))
+}
+
+fn from_epoch(input: ExprRef, unit: TimeUnit) -> ExprRef {
+    input
+        .cast(&DataType::Int64)
+        .cast(&DataType::Timestamp(unit, None))
+        .cast(&timestamp_type())
+}
+
+fn to_epoch(input: ExprRef, unit: TimeUnit) -> ExprRef {
+    input
+        .cast(&DataType::Timestamp(unit, None))
+        .cast(&DataType::Int64)
+}
+
+/// `date_format(input, format)`, which formats with a java `DateTimeFormatter` pattern.
+fn date_format(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let [input, format] = args.as_slice() else {
+        invalid_argument_err!("date_format requires exactly 2 arguments");
+    };
+
+    let format = to_strftime_format(string_literal(format, "date_format format")?)?;
+    Ok(dt_strftime(input.clone().cast(&timestamp_type()), format))
+}
+
+/// `from_unixtime(seconds[, format])`.
+fn from_unixtime(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let (seconds, pattern) = match args.as_slice() {
+        [seconds] => (seconds, DEFAULT_TIMESTAMP_PATTERN),
+        [seconds, format] => (seconds, string_literal(format, "from_unixtime format")?),
+        _ => invalid_argument_err!("from_unixtime requires 1 or 2 arguments"),
+    };
+
+    let format = to_strftime_format(pattern)?;
+    Ok(dt_strftime(
+        from_epoch(seconds.clone(), TimeUnit::Seconds),
+        format,
+    ))
+}
+
+/// `unix_timestamp([input[, format]])`, the seconds since the epoch of the current time, of a date
+/// or timestamp, or of a string with the given java `DateTimeFormatter` pattern.
+fn unix_timestamp(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    match args.as_slice() {
+        [] => Ok(lit(current_micros() / 1_000_000)),
+        [input] => Ok(to_epoch(input.clone(), TimeUnit::Seconds)),
+        [input, format] => {
+            let format = string_literal(format, "unix_timestamp format")?;
+            Ok(to_epoch(
+                parse_timestamp(input.clone(), format, true)?,
+                TimeUnit::Seconds,
+            ))
+        }
+        _ => invalid_argument_err!("unix_timestamp requires at most 2 arguments"),
+    }
+}
+
+fn current_micros() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_micros() as i64)
+}
+
+/// `current_date()`, `current_timestamp()` and `current_timezone()`, which are evaluated once
+/// when the plan is built.
+enum CurrentTimeFunction {
+    Date,
+    Timestamp,
+    Timezone,
+}
+
+impl SparkFunction for CurrentTimeFunction {
+    fn to_expr(
+        &self,
+        args: &[Expression],
+        _analyzer: &SparkAnalyzer,
+    ) -> ConnectResult<daft_dsl::ExprRef> {
+        if !args.is_empty() {
+            invalid_argument_err!("requires no arguments");
+        }
+
+        let micros = current_micros();
+        Ok(match self {
+            Self::Date => lit((micros / 86_400_000_000) as i32).cast(&DataType::Date),
+            Self::Timestamp => lit(micros).cast(&timestamp_type()),
+            // timestamps without a timezone are taken to be in UTC
+            Self::Timezone => lit("UTC"),
+        })
+    }
+}
+
+/// Returns the wall clock time in `to_tz` of a timestamp whose wall clock time is in `from_tz`.
+///
+/// Timestamps without a timezone are taken to be in UTC.
+fn shift_timezone(input: ExprRef, from_tz: Option<&str>, to_tz: &str) -> ExprRef {
+    let input = match from_tz {
+        Some(from_tz) => dt_replace_timezone(input, Some(from_tz)),
+        None => input,
+    };
+    dt_replace_timezone(dt_convert_timezone(input, to_tz), None::<String>)
+}
+
+fn timezone_literal(expr: &ExprRef) -> ConnectResult<&str> {
+    expr.as_literal()
+        .and_then(|lit| lit.as_str())
+        .ok_or_else(|| ConnectError::invalid_argument("timezone must be a string literal"))
+}
+
+struct ConvertTimezoneFunction;
+
+impl SparkFunction for ConvertTimezoneFunction {
+    fn to_expr(
+        &self,
+        args: &[Expression],
+        analyzer: &SparkAnalyzer,
+    ) -> ConnectResult<daft_dsl::ExprRef> {
+        let args = args
+            .iter()
+            .map(|arg| analyzer.to_daft_expr(arg))
+            .collect::<ConnectResult<Vec<_>>>()?;
+
+        match args.as_slice() {
+            [target_tz, source_ts] => Ok(shift_timezone(
+                source_ts.clone(),
+                None,
+                timezone_literal(target_tz)?,
+            )),
+            [source_tz, target_tz, source_ts] => Ok(shift_timezone(
+                source_ts.clone(),
+                Some(timezone_literal(source_tz)?),
+                timezone_literal(target_tz)?,
+            )),
+            _ => invalid_argument_err!("requires two or three arguments"),
+        }
+    }
+}
+
+struct FromUtcTimestampFunction;
+
+impl SparkFunction for FromUtcTimestampFunction {
+    fn to_expr(
+        &self,
+        args: &[Expression],
+        analyzer: &SparkAnalyzer,
+    ) -> ConnectResult<daft_dsl::ExprRef> {
+        match args {
+            [timestamp, tz] => {
+                let timestamp = analyzer.to_daft_expr(timestamp)?;
+                let tz = analyzer.to_daft_expr(tz)?;
+                Ok(shift_timezone(timestamp, None, timezone_literal(&tz)?))
+            }
+            _ => invalid_argument_err!("requires exactly two arguments"),
+        }
+    }
+}
+
+struct ToUtcTimestampFunction;
+
+impl SparkFunction for ToUtcTimestampFunction {
+    fn to_expr(
+        &self,
+        args: &[Expression],
+        analyzer: &SparkAnalyzer,
+    ) -> ConnectResult<daft_dsl::ExprRef> {
+        match args {
+            [timestamp, tz] => {
+                let timestamp = analyzer.to_daft_expr(timestamp)?;
+                let tz = analyzer.to_daft_expr(tz)?;
+                Ok(shift_timezone(
+                    timestamp,
+                    Some(timezone_literal(&tz)?),
+                    "UTC",
+                ))
+            }
+            _ => invalid_argument_err!("requires exactly two arguments"),
+        }
+    }
+}
@@ -1,21 +1,31 @@
-use daft_dsl::LiteralValue;
-use daft_functions::numeric::{
-    abs::Abs,
-    cbrt::Cbrt,
-    ceil::Ceil,
-    exp::Exp,
-    floor::Floor,
-    log::{log, Ln, Log10, Log2},
-    round::round,
-    sqrt::Sqrt,
-    trigonometry::{
-        ArcCos, ArcCosh, ArcSin, ArcSinh, ArcTan, ArcTanh, Atan2, Cos, Cot, Degrees, Radians, Sin,
-        Tan,
+use daft_dsl::{binary_op, lit, null_lit, ExprRef, LiteralValue, Operator};
+use daft_functions::{
+    binary::{Hex, Unhex},
+    numeric::{
+        abs::Abs,
+        cbrt::Cbrt,
+        ceil::Ceil,
+        conv::conv,
+        exp::{exp, Exp},
+        floor::{floor, Floor},
+        log::{ln, log, Ln, Log10, Log2},
+        pow::Pow,
+        round::round,
+        sign::sign,
+        sqrt::{sqrt, Sqrt},
+        trigonometry::{
+            cos, sin, ArcCos, ArcCosh, ArcSin, ArcSinh, ArcTan, ArcTanh, Atan2, Cos, Cosh, Cot,
+            Degrees, Radians, Sin, Sinh, Tan, Tanh,
+        },
     },
 };
+use daft_schema::dtype::DataType;
 use spark_connect::Expression;
 
-use super::{FunctionModule, SparkFunction, TODO_FUNCTION};
+use super::{
+    integer_literal, string::ToBinaryFunction, BinaryFunction, FunctionModule, SparkFunction,
+    UnaryFunction, UnsupportedFunction, VariadicFunction,
+};
 use crate::{
     error::{ConnectError, ConnectResult},
     invalid_argument_err,
@@ -36,64 +46,186 @@ impl FunctionModule for MathFunctions {
         parent.add_fn("atan", ArcTan);
         parent.add_fn("atanh", ArcTanh);
         parent.add_fn("atan2", Atan2 {});
-        parent.add_fn("bin", TODO_FUNCTION);
+        parent.add_fn("bin", UnaryFunction(|arg| conv(arg, 10, 2)));
         parent.add_fn("cbrt", Cbrt {});
         parent.add_fn("ceil", Ceil {});
         parent.add_fn("ceiling", Ceil {});
-        parent.add_fn("conv", TODO_FUNCTION);
+        parent.add_fn("conv", ConvFunction);
         parent.add_fn("cos", Cos {});
-        parent.add_fn("cosh", TODO_FUNCTION);
+        parent.add_fn("cosh", Cosh);
         parent.add_fn("cot", Cot {});
-        parent.add_fn("csc", TODO_FUNCTION);
-        parent.add_fn("e", TODO_FUNCTION);
+        parent.add_fn("csc", UnaryFunction(|arg| lit(1.0).div(sin(arg))));
+        parent.add_fn("e", ConstantFunction(std::f64::consts::E));
         parent.add_fn("exp", Exp {});
-        parent.add_fn("expm1", TODO_FUNCTION);
-        parent.add_fn("factorial", TODO_FUNCTION);
+        parent.add_fn("expm1", UnaryFunction(|arg| exp(arg).sub(lit(1.0))));
+        parent.add_fn("factorial", UnaryFunction(factorial));
         parent.add_fn("floor", Floor {});
-        parent.add_fn("hex", TODO_FUNCTION);
-        parent.add_fn("unhex", TODO_FUNCTION);
-        parent.add_fn("hypot", TODO_FUNCTION);
+        parent.add_fn("hex", Hex {});
+        parent.add_fn("unhex", Unhex {});
+        parent.add_fn("hypot", BinaryFunction(hypot));
         parent.add_fn("ln", Ln {});
         parent.add_fn("log", LogFunction);
         parent.add_fn("log10", Log10 {});
-        parent.add_fn("log1p", TODO_FUNCTION);
+        parent.add_fn("log1p", UnaryFunction(log1p));
         parent.add_fn("log2", Log2 {});
-        parent.add_fn("negate", TODO_FUNCTION);
-        parent.add_fn("negative", TODO_FUNCTION);
-        parent.add_fn("pi", TODO_FUNCTION);
-        parent.add_fn("pmod", TODO_FUNCTION);
-        parent.add_fn("positive", TODO_FUNCTION);
-        parent.add_fn("pow", TODO_FUNCTION);
-        parent.add_fn("power", TODO_FUNCTION);
-        parent.add_fn("rint", TODO_FUNCTION);
+        parent.add_fn("negate", UnaryFunction(negative));
+        parent.add_fn("negative", UnaryFunction(negative));
+        parent.add_fn("pi", ConstantFunction(std::f64::consts::PI));
+        parent.add_fn("pmod", BinaryFunction(pmod));
+        parent.add_fn("positive", UnaryFunction(|arg| arg));
+        parent.add_fn("pow", Pow {});
+        parent.add_fn("power", Pow {});
+        parent.add_fn("rint", UnaryFunction(round_half_even));
         parent.add_fn("round", RoundFunction);
-        parent.add_fn("bround", TODO_FUNCTION);
-        parent.add_fn("sec", TODO_FUNCTION);
-        parent.add_fn("shiftleft", TODO_FUNCTION);
-        parent.add_fn("shiftright", TODO_FUNCTION);
-        parent.add_fn("sign", TODO_FUNCTION);
-        parent.add_fn("signum", TODO_FUNCTION);
+        parent.add_fn("bround", BroundFunction);
+        parent.add_fn("sec", UnaryFunction(|arg| lit(1.0).div(cos(arg))));
+        parent.add_fn(
+            "shiftleft",
+            BinaryFunction(|arg, n| binary_op(Operator::ShiftLeft, arg, n)),
+        );
+        parent.add_fn(
+            "shiftright",
+            BinaryFunction(|arg, n| binary_op(Operator::ShiftRight, arg, n)),
+        );
+        parent.add_fn("sign", UnaryFunction(signum));
+        parent.add_fn("signum", UnaryFunction(signum));
         parent.add_fn("sin", Sin {});
-        parent.add_fn("sinh", TODO_FUNCTION);
+        parent.add_fn("sinh", Sinh);
         parent.add_fn("tan", Tan {});
-        parent.add_fn("tanh", TODO_FUNCTION);
-        parent.add_fn("toDegrees", TODO_FUNCTION);
-        parent.add_fn("try_add", TODO_FUNCTION);
-        parent.add_fn("try_avg", TODO_FUNCTION);
-        parent.add_fn("try_divide", TODO_FUNCTION);
-        parent.add_fn("try_multiply", TODO_FUNCTION);
-        parent.add_fn("try_subtract", TODO_FUNCTION);
-        parent.add_fn("try_sum", TODO_FUNCTION);
-        parent.add_fn("try_to_binary", TODO_FUNCTION);
-        parent.add_fn("try_to_number", TODO_FUNCTION);
+        parent.add_fn("tanh", Tanh);
+        parent.add_fn("toDegrees", Degrees {});
+        // overflows are not detected, so the `try_` variants of the arithmetic functions only
+        // differ in returning null on division by zero
+        parent.add_fn("try_add", BinaryFunction(|lhs, rhs| lhs.add(rhs)));
+        parent.add_fn("try_avg", UnaryFunction(|arg| arg.mean()));
+        parent.add_fn(
+            "try_divide",
+            BinaryFunction(|lhs, rhs| {
+                rhs.clone()
+                    .eq(lit(0))
+                    .if_else(null_lit(), lhs.div(rhs))
+                    .cast(&DataType::Float64)
+            }),
+        );
+        parent.add_fn("try_multiply", BinaryFunction(|lhs, rhs| lhs.mul(rhs)));
+        parent.add_fn("try_subtract", BinaryFunction(|lhs, rhs| lhs.sub(rhs)));
+        parent.add_fn("try_sum", UnaryFunction(|arg| arg.sum()));
+        parent.add_fn("try_to_binary", ToBinaryFunction);
+        parent.add_fn(
+            "try_to_number",
+            UnsupportedFunction {
+                name: "try_to_number",
+                reason: "like to_number, it needs a parser of spark's number format patterns",
+            },
+        );
         parent.add_fn("degrees", Degrees {});
-        parent.add_fn("toRadians", TODO_FUNCTION);
+        parent.add_fn("toRadians", Radians {});
         parent.add_fn("radians", Radians {});
-        parent.add_fn("width_bucket", TODO_FUNCTION);
-        //
+        parent.add_fn("width_bucket", VariadicFunction(width_bucket));
     }
 }
 
+fn factorial(arg: ExprRef) -> ExprRef {
+    // like spark, only 0 to 20 have a factorial, as 21! overflows a 64-bit integer
+    let arg = arg.cast(&DataType::Int64);
+    (0..=20i64)
+        .rev()
+        .fold(null_lit(), |otherwise, n| {
+            let factorial = (1..=n).product::<i64>();
+            arg.clone().eq(lit(n)).if_else(lit(factorial), otherwise)
+        })
+        .cast(&DataType::Int64)
+}
+
+fn hypot(x: ExprRef, y: ExprRef) -> ExprRef {
+    let x = x.cast(&DataType::Float64);
+    let y = y.cast(&DataType::Float64);
+    sqrt(x.clone().mul(x).add(y.clone().mul(y)))
+}
+
+/// `ln(1 + arg)`, which like spark is null rather than NaN for arguments of -1 or less.
+fn log1p(arg: ExprRef) -> ExprRef {
+    let arg = arg.cast(&DataType::Float64);
+    arg.clone()
+        .lt_eq(lit(-1.0))
+        .if_else(null_lit(), ln(lit(1.0).add(arg)))
+}
+
+fn negative(arg: ExprRef) -> ExprRef {
+    lit(0).sub(arg)
+}
+
+/// The positive remainder of `dividend` divided by `divisor`, as in spark's `pmod`.
+fn pmod(dividend: ExprRef, divisor: ExprRef) -> ExprRef {
+    let remainder = dividend.rem(divisor.clone());
+    remainder.clone().lt(lit(0)).if_else(
+        remainder.clone().add(divisor.clone()).rem(divisor),
+        remainder,
+    )
+}
+
+fn signum(arg: ExprRef) -> ExprRef {
+    sign(arg).cast(&DataType::Float64)
+}
+
+/// Rounds to the nearest integer, rounding halfway values to the nearest even integer.
+fn round_half_even(arg: ExprRef) -> ExprRef {
+    let arg = arg.cast(&DataType::Float64);
+    let floor = floor(arg.clone());
+    let fraction = arg.sub(floor.clone());
+    let is_even = floor.clone().rem(lit(2.0)).eq(lit(0.0));
+
+    fraction.clone().lt(lit(0.5)).if_else(
+        floor.clone(),
+        fraction
+            .gt(lit(0.5))
+            .or(is_even.not())
+            .if_else(floor.clone().add(lit(1.0)), floor),
+    )
+}
+
+/// `width_bucket(value, min, max, num_buckets)`, which returns the 1-based bucket of `value` out of
+/// `num_buckets` equal-width buckets between `min` and `max`, or 0 and `num_buckets + 1` for values
+/// below and above the range.
+fn width_bucket(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let [value, min, max, num_buckets] = args.as_slice() else {
+        invalid_argument_err!("width_bucket requires exactly 4 arguments");
+    };
+
+    let value = value.clone().cast(&DataType::Float64);
+    let min = min.clone().cast(&DataType::Float64);
+    let max = max.clone().cast(&DataType::Float64);
+    let num_buckets = num_buckets.clone().cast(&DataType::Int64);
+
+    // the range may be descending, in which case buckets count down from `min`
+    let bucket = |value: ExprRef, min: ExprRef, max: ExprRef| {
+        let bucket = floor(
+            value
+                .clone()
+                .sub(min.clone())
+                .mul(num_buckets.clone())
+                .div(max.clone().sub(min.clone())),
+        )
+        .cast(&DataType::Int64)
+        .add(lit(1i64));
+        value.clone().lt(min).if_else(
+            lit(0i64),
+            value
+                .gt_eq(max)
+                .if_else(num_buckets.clone().add(lit(1i64)), bucket),
+        )
+    };
+    let ascending = bucket(value.clone(), min.clone(), max.clone());
+    let descending = bucket(
+        lit(0.0).sub(value),
+        lit(0.0).sub(min.clone()),
+        lit(0.0).sub(max.clone()),
+    );
+
+    let is_invalid = num_buckets.lt_eq(lit(0i64)).or(min.clone().eq(max.clone()));
+    Ok(is_invalid.if_else(null_lit(), min.lt(max).if_else(ascending, descending)))
+}
+
 struct LogFunction;
 impl SparkFunction for LogFunction {
     fn to_expr(
@@ -160,3 +292,65 @@ impl SparkFunction for RoundFunction {
         Ok(round(input, scale))
     }
 }
+
+struct ConstantFunction(f64);
+
+impl SparkFunction for ConstantFunction {
+    fn to_expr(
+        &self,
+        args: &[Expression],
+        _analyzer: &SparkAnalyzer,
+    ) -> ConnectResult<daft_dsl::ExprRef> {
+        if !args.is_empty() {
+            invalid_argument_err!("requires no arguments");
+        }
+        Ok(lit(self.0))
+    }
+}
+
+struct ConvFunction;
+
+impl SparkFunction for ConvFunction {
+    fn to_expr(
+        &self,
+        args: &[Expression],
+        analyzer: &SparkAnalyzer,
+    ) -> ConnectResult<daft_dsl::ExprRef> {
+        let args = args
+            .iter()
+            .map(|arg| analyzer.to_daft_expr(arg))
+            .collect::<ConnectResult<Vec<_>>>()?;
+
+        let [input, from_base, to_base] = args.as_slice() else {
+            invalid_argument_err!("conv requires exactly 3 arguments");
+        };
+
+        let from_base = integer_literal(from_base, "conv from base")?;
+        let to_base = integer_literal(to_base, "conv to base")?;
+        Ok(conv(input.clone(), from_base as u32, to_base as i32))
+    }
+}
+
+struct BroundFunction;
+
+impl SparkFunction for BroundFunction {
+    fn to_expr(
+        &self,
+        args: &[Expression],
+        analyzer: &SparkAnalyzer,
+    ) -> ConnectResult<daft_dsl::ExprRef> {
+        let args = args
+            .iter()
+            .map(|arg| analyzer.to_daft_expr(arg))
+            .collect::<ConnectResult<Vec<_>>>()?;
+
+        let (input, scale) = match args.as_slice() {
+            [input] => (input.clone(), 0),
+            [input, scale] => (input.clone(), integer_literal(scale, "bround scale")?),
+            _ => invalid_argument_err!("bround requires 1 or 2 arguments"),
+        };
+
+        let factor = 10f64.powi(scale as i32);
+        Ok(round_half_even(input.cast(&DataType::Float64).mul(lit(factor))).div(lit(factor)))
+    }
+}
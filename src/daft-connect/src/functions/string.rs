@@ -1,86 +1,568 @@
-use daft_dsl::LiteralValue;
-use daft_functions::utf8::{
-    extract, extract_all, Utf8Endswith, Utf8Ilike, Utf8Left, Utf8Length, Utf8LengthBytes, Utf8Like,
-    Utf8Lower, Utf8Lpad, Utf8Replace, Utf8Right, Utf8Rpad, Utf8Split, Utf8Startswith, Utf8Substr,
-    Utf8Upper,
+use daft_core::count_mode::CountMode;
+use daft_dsl::{lit, null_lit, ExprRef, LiteralValue};
+use daft_functions::{
+    binary::{base64_decode, unhex, Base64Decode, Base64Encode},
+    coalesce::coalesce,
+    list,
+    temporal::strftime::dt_strftime,
+    utf8::{
+        extract, extract_all, find, left, length, length_bytes, levenshtein, replace, split,
+        substr, trim, Utf8Ascii, Utf8Chr, Utf8Contains, Utf8Endswith, Utf8Ilike, Utf8Initcap,
+        Utf8Left, Utf8Length, Utf8LengthBytes, Utf8Like, Utf8Lower, Utf8Lpad, Utf8Match,
+        Utf8Repeat, Utf8Replace, Utf8Right, Utf8Rpad, Utf8Soundex, Utf8Split, Utf8Startswith,
+        Utf8Substr, Utf8Translate, Utf8Upper, Utf8UrlDecode, Utf8UrlEncode,
+    },
 };
+use daft_schema::dtype::DataType;
 use spark_connect::Expression;
 
-use super::{FunctionModule, SparkFunction, TODO_FUNCTION};
-use crate::{error::ConnectResult, invalid_argument_err, spark_analyzer::SparkAnalyzer};
+use super::{
+    datetime::to_strftime_format, integer_literal, string_literal, BinaryFunction, FunctionModule,
+    SparkFunction, UnaryFunction, UnsupportedFunction, VariadicFunction,
+};
+use crate::{
+    error::ConnectResult, invalid_argument_err, not_yet_implemented, spark_analyzer::SparkAnalyzer,
+};
 
 // see https://spark.apache.org/docs/latest/api/python/reference/pyspark.sql/functions.html#string-functions
 pub struct StringFunctions;
 
 impl FunctionModule for StringFunctions {
     fn register(parent: &mut super::SparkFunctions) {
-        parent.add_fn("ascii", TODO_FUNCTION);
-        parent.add_fn("base64", TODO_FUNCTION);
-        parent.add_fn("bit_length", TODO_FUNCTION);
-        parent.add_fn("btrim", TODO_FUNCTION);
-        parent.add_fn("char", TODO_FUNCTION);
+        parent.add_fn("ascii", Utf8Ascii {});
+        parent.add_fn("base64", Base64Encode {});
+        parent.add_fn(
+            "bit_length",
+            UnaryFunction(|arg| octet_length(arg).mul(lit(8))),
+        );
+        parent.add_fn("btrim", TrimFunction::both());
+        parent.add_fn("char", Utf8Chr {});
+        parent.add_fn("chr", Utf8Chr {});
         parent.add_fn("character_length", Utf8Length {});
         parent.add_fn("char_length", Utf8Length {});
-        parent.add_fn("concat_ws", TODO_FUNCTION);
-        parent.add_fn("contains", daft_functions::utf8::Utf8Contains {});
-        parent.add_fn("decode", TODO_FUNCTION);
-        parent.add_fn("elt", TODO_FUNCTION);
-        parent.add_fn("encode", Utf8Endswith {});
-        parent.add_fn("endswith", TODO_FUNCTION);
-        parent.add_fn("find_in_set", TODO_FUNCTION);
-        parent.add_fn("format_number", TODO_FUNCTION);
-        parent.add_fn("format_string", TODO_FUNCTION);
+        parent.add_fn("concat_ws", VariadicFunction(concat_ws));
+        parent.add_fn("contains", Utf8Contains {});
+        parent.add_fn("decode", CharsetFunction(DataType::Utf8));
+        parent.add_fn("elt", VariadicFunction(elt));
+        parent.add_fn("encode", CharsetFunction(DataType::Binary));
+        parent.add_fn("endswith", Utf8Endswith {});
+        parent.add_fn("find_in_set", BinaryFunction(find_in_set));
+        parent.add_fn("format_number", VariadicFunction(format_number));
+        parent.add_fn("format_string", VariadicFunction(format_string));
         parent.add_fn("ilike", Utf8Ilike {});
-        parent.add_fn("initcap", TODO_FUNCTION);
-        parent.add_fn("instr", TODO_FUNCTION);
-        parent.add_fn("lcase", TODO_FUNCTION);
+        parent.add_fn("initcap", Utf8Initcap {});
+        parent.add_fn("instr", BinaryFunction(instr));
+        parent.add_fn("lcase", Utf8Lower {});
         parent.add_fn("length", Utf8LengthBytes {});
         parent.add_fn("like", Utf8Like {});
         parent.add_fn("lower", Utf8Lower {});
         parent.add_fn("left", Utf8Left {});
-        parent.add_fn("levenshtein", TODO_FUNCTION);
-        parent.add_fn("locate", TODO_FUNCTION);
+        parent.add_fn("levenshtein", VariadicFunction(levenshtein_distance));
+        parent.add_fn("locate", VariadicFunction(locate));
         parent.add_fn("lpad", Utf8Lpad {});
-        parent.add_fn("ltrim", TODO_FUNCTION);
-        parent.add_fn("mask", TODO_FUNCTION);
-        parent.add_fn("octet_length", TODO_FUNCTION);
-        parent.add_fn("parse_url", TODO_FUNCTION);
-        parent.add_fn("position", TODO_FUNCTION);
-        parent.add_fn("printf", TODO_FUNCTION);
-        parent.add_fn("rlike", TODO_FUNCTION);
-        parent.add_fn("regexp", TODO_FUNCTION);
-        parent.add_fn("regexp_like", TODO_FUNCTION);
-        parent.add_fn("regexp_count", TODO_FUNCTION);
+        parent.add_fn("ltrim", TrimFunction::leading());
+        parent.add_fn("mask", VariadicFunction(mask));
+        parent.add_fn("octet_length", UnaryFunction(octet_length));
+        parent.add_fn("parse_url", VariadicFunction(parse_url));
+        parent.add_fn("position", VariadicFunction(locate));
+        parent.add_fn("printf", VariadicFunction(format_string));
+        parent.add_fn("rlike", Utf8Match {});
+        parent.add_fn("regexp", Utf8Match {});
+        parent.add_fn("regexp_like", Utf8Match {});
+        parent.add_fn("regexp_count", BinaryFunction(regexp_count));
         parent.add_fn("regexp_extract", RegexpExtract);
         parent.add_fn("regexp_extract_all", RegexpExtractAll);
         parent.add_fn("regexp_replace", Utf8Replace { regex: true });
-        parent.add_fn("regexp_substr", TODO_FUNCTION);
-        parent.add_fn("regexp_instr", TODO_FUNCTION);
+        parent.add_fn(
+            "regexp_substr",
+            BinaryFunction(|input, pattern| extract(input, pattern, 0)),
+        );
+        parent.add_fn("regexp_instr", VariadicFunction(regexp_instr));
         parent.add_fn("replace", Utf8Replace { regex: false });
         parent.add_fn("right", Utf8Right {});
-        parent.add_fn("ucase", TODO_FUNCTION);
-        parent.add_fn("unbase64", TODO_FUNCTION);
+        parent.add_fn("ucase", Utf8Upper {});
+        parent.add_fn("unbase64", Base64Decode {});
         parent.add_fn("rpad", Utf8Rpad {});
-        parent.add_fn("repeat", TODO_FUNCTION);
-        parent.add_fn("rtrim", TODO_FUNCTION);
-        parent.add_fn("soundex", TODO_FUNCTION);
+        parent.add_fn("repeat", Utf8Repeat {});
+        parent.add_fn("rtrim", TrimFunction::trailing());
+        parent.add_fn("soundex", Utf8Soundex {});
         parent.add_fn("split", Utf8Split { regex: false });
-        parent.add_fn("split_part", TODO_FUNCTION);
+        parent.add_fn("split_part", VariadicFunction(split_part));
         parent.add_fn("startswith", Utf8Startswith {});
         parent.add_fn("substr", Utf8Substr {});
         parent.add_fn("substring", Utf8Substr {});
-        parent.add_fn("substring_index", TODO_FUNCTION);
-        parent.add_fn("overlay", TODO_FUNCTION);
-        parent.add_fn("sentences", TODO_FUNCTION);
-        parent.add_fn("to_binary", TODO_FUNCTION);
-        parent.add_fn("to_char", TODO_FUNCTION);
-        parent.add_fn("to_number", TODO_FUNCTION);
-        parent.add_fn("to_varchar", TODO_FUNCTION);
-        parent.add_fn("translate", TODO_FUNCTION);
-        parent.add_fn("trim", TODO_FUNCTION);
+        parent.add_fn("substring_index", VariadicFunction(substring_index));
+        parent.add_fn("overlay", VariadicFunction(overlay));
+        parent.add_fn(
+            "sentences",
+            UnsupportedFunction {
+                name: "sentences",
+                reason: "splitting text into sentences and words needs a locale-aware word breaker",
+            },
+        );
+        parent.add_fn("to_binary", ToBinaryFunction);
+        parent.add_fn("to_char", VariadicFunction(to_char));
+        parent.add_fn(
+            "to_number",
+            UnsupportedFunction {
+                name: "to_number",
+                reason: "Daft can't parse strings with spark's number format patterns, such as '$99.99'",
+            },
+        );
+        parent.add_fn("to_varchar", VariadicFunction(to_char));
+        parent.add_fn("translate", Utf8Translate {});
+        parent.add_fn("trim", TrimFunction::both());
         parent.add_fn("upper", Utf8Upper {});
-        parent.add_fn("url_decode", TODO_FUNCTION);
-        parent.add_fn("url_encode", TODO_FUNCTION);
+        parent.add_fn("url_decode", Utf8UrlDecode {});
+        parent.add_fn("url_encode", Utf8UrlEncode {});
+    }
+}
+
+fn octet_length(input: ExprRef) -> ExprRef {
+    length_bytes(input).cast(&DataType::Int32)
+}
+
+/// The 1-based position of the first occurrence of `substr` in `input`, or 0 if there is none.
+fn instr(input: ExprRef, substr: ExprRef) -> ExprRef {
+    find(input, substr).add(lit(1)).cast(&DataType::Int32)
+}
+
+/// `concat_ws(sep, *inputs)`, which joins the inputs with `sep`, skipping null inputs.
+fn concat_ws(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let Some((sep, inputs)) = args.split_first() else {
+        invalid_argument_err!("concat_ws requires at least 1 argument");
+    };
+
+    // each non-null input is prefixed by the separator, which is then stripped from the result
+    let joined = inputs
+        .iter()
+        .map(|input| {
+            input
+                .clone()
+                .is_null()
+                .if_else(lit(""), sep.clone().add(input.clone()))
+        })
+        .reduce(|acc, input| acc.add(input));
+
+    let Some(joined) = joined else {
+        return Ok(sep.clone().is_null().if_else(null_lit(), lit("")));
+    };
+
+    let stripped = coalesce(vec![
+        substr(
+            joined,
+            length(sep.clone()).cast(&DataType::Int64),
+            null_lit(),
+        ),
+        lit(""),
+    ]);
+    Ok(sep.clone().is_null().if_else(null_lit(), stripped))
+}
+
+/// `format_number(input, scale)`, which formats numbers like `#,###,##0.00` with `scale` decimal
+/// places, or returns null if `scale` is negative.
+fn format_number(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let [input, scale] = args.as_slice() else {
+        invalid_argument_err!("format_number requires exactly 2 arguments");
+    };
+    if scale.as_literal().and_then(LiteralValue::as_str).is_some() {
+        not_yet_implemented!("format_number with a DecimalFormat pattern");
+    }
+    match integer_literal(scale, "format_number scale")? {
+        scale if scale < 0 => Ok(null_lit().cast(&DataType::Utf8)),
+        scale => Ok(daft_functions::numeric::format_number::format_number(
+            input.clone(),
+            scale as usize,
+        )),
+    }
+}
+
+/// `elt(n, *inputs)`, which returns the `n`-th input, or null if there is none.
+fn elt(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let Some((n, inputs)) = args.split_first() else {
+        invalid_argument_err!("elt requires at least 2 arguments");
+    };
+
+    Ok(inputs
+        .iter()
+        .enumerate()
+        .rev()
+        .fold(null_lit(), |otherwise, (i, input)| {
+            n.clone()
+                .eq(lit(i as i64 + 1))
+                .if_else(input.clone(), otherwise)
+        }))
+}
+
+/// `find_in_set(input, set)`, which returns the 1-based position of `input` in the comma separated
+/// `set`, or 0 if it is not in the set or contains a comma.
+fn find_in_set(input: ExprRef, set: ExprRef) -> ExprRef {
+    // with commas around both, the position is the number of commas up to the match
+    let set = lit(",").add(set).add(lit(","));
+    let position = find(set.clone(), lit(",").add(input).add(lit(",")));
+    let prefix = left(set, position.clone().add(lit(1)));
+    let commas = length(prefix.clone()).sub(length(replace(prefix, lit(","), lit(""), false)));
+
+    position
+        .lt(lit(0))
+        .if_else(lit(0), commas)
+        .cast(&DataType::Int32)
+}
+
+/// `format_string(format, *inputs)`, which supports the `%s`, `%d` and `%%` format specifiers.
+fn format_string(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let Some((format, inputs)) = args.split_first() else {
+        invalid_argument_err!("format_string requires at least 1 argument");
+    };
+    let format = string_literal(format, "format_string format")?;
+
+    let mut parts = Vec::new();
+    let mut inputs = inputs.iter();
+    let mut literal = String::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            literal.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('%') => literal.push('%'),
+            Some('s' | 'd') => {
+                let Some(input) = inputs.next() else {
+                    invalid_argument_err!(
+                        "format_string has fewer arguments than its format {format:?} requires"
+                    );
+                };
+                parts.push(lit(std::mem::take(&mut literal)));
+                // like java's formatter, null values are formatted as "null"
+                parts.push(input.clone().cast(&DataType::Utf8).fill_null(lit("null")));
+            }
+            Some(other) => {
+                not_yet_implemented!("format_string format specifier %{other}");
+            }
+            None => invalid_argument_err!("format_string format {format:?} ends with %"),
+        }
+    }
+    parts.push(lit(literal));
+
+    Ok(parts
+        .into_iter()
+        .reduce(|acc, part| acc.add(part))
+        .expect("there is at least one part"))
+}
+
+/// `levenshtein(left, right, [threshold])`, which returns -1 if the distance exceeds the threshold.
+fn levenshtein_distance(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    match args.as_slice() {
+        [lhs, rhs] => Ok(levenshtein(lhs.clone(), rhs.clone()).cast(&DataType::Int32)),
+        [lhs, rhs, threshold] => {
+            let distance = levenshtein(lhs.clone(), rhs.clone()).cast(&DataType::Int32);
+            Ok(distance
+                .clone()
+                .gt(threshold.clone())
+                .if_else(lit(-1), distance))
+        }
+        _ => invalid_argument_err!("levenshtein requires 2 or 3 arguments"),
+    }
+}
+
+/// `locate(substr, input, [pos])`, which returns the 1-based position of the first occurrence of
+/// `substr` in `input` at or after `pos`, or 0 if there is none.
+fn locate(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let (substr_, input, pos) = match args.as_slice() {
+        [substr_, input] => (substr_, input, 1),
+        [substr_, input, pos] => (substr_, input, integer_literal(pos, "locate position")?),
+        _ => invalid_argument_err!("locate requires 2 or 3 arguments"),
+    };
+
+    if pos < 1 {
+        return Ok(input.clone().is_null().if_else(null_lit(), lit(0)));
+    }
+
+    let position = find(
+        substr(input.clone(), lit(pos - 1), null_lit()),
+        substr_.clone(),
+    );
+    Ok(input
+        .clone()
+        .is_null()
+        .if_else(
+            null_lit(),
+            coalesce(vec![position.clone(), lit(-1i64)])
+                .lt(lit(0))
+                .if_else(lit(0i64), position.add(lit(pos))),
+        )
+        .cast(&DataType::Int32))
+}
+
+/// `mask(input, upper_char, lower_char, digit_char, other_char)`, where a null replacement
+/// character leaves that kind of character as is.
+fn mask(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let (input, replacements) = match args.as_slice() {
+        [input, replacements @ ..] if replacements.len() <= 4 => (input.clone(), replacements),
+        _ => invalid_argument_err!("mask requires 1 to 5 arguments"),
+    };
+
+    let defaults = [Some("X"), Some("x"), Some("n"), None];
+    let patterns = [r"\p{Lu}", r"\p{Ll}", r"\p{Nd}", r"[^\p{Lu}\p{Ll}\p{Nd}]"];
+
+    // other characters are replaced first so that the replacements are not replaced again
+    let mut masked = input;
+    for i in [3, 2, 1, 0] {
+        let replacement = match replacements.get(i) {
+            Some(replacement) if matches!(replacement.as_literal(), Some(LiteralValue::Null)) => {
+                None
+            }
+            Some(replacement) => Some(string_literal(replacement, "mask character")?),
+            None => defaults[i],
+        };
+        if let Some(replacement) = replacement {
+            let replacement = replacement.replace('$', "$$");
+            masked = replace(masked, lit(patterns[i]), lit(replacement), true);
+        }
+    }
+    Ok(masked)
+}
+
+/// `parse_url(url, part, [key])`, which extracts a part of a URL, or the value of a query parameter.
+fn parse_url(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let (url, part, key) = match args.as_slice() {
+        [url, part] => (url, part, None),
+        [url, part, key] => (url, part, Some(string_literal(key, "parse_url key")?)),
+        _ => invalid_argument_err!("parse_url requires 2 or 3 arguments"),
+    };
+
+    let pattern = match (string_literal(part, "parse_url part")?, key) {
+        ("PROTOCOL", None) => r"^([^:/?#]+):".to_string(),
+        ("HOST", None) => r"^[^:/?#]+://(?:[^@/?#]*@)?([^:/?#]*)".to_string(),
+        ("AUTHORITY", None) => r"^[^:/?#]+://([^/?#]*)".to_string(),
+        ("USERINFO", None) => r"^[^:/?#]+://([^@/?#]*)@".to_string(),
+        ("PATH", None) => r"^(?:[^:/?#]+:)?(?://[^/?#]*)?([^?#]*)".to_string(),
+        ("FILE", None) => r"^(?:[^:/?#]+:)?(?://[^/?#]*)?([^#]*)".to_string(),
+        ("QUERY", None) => r"\?([^#]*)".to_string(),
+        ("QUERY", Some(key)) => format!(r"\?(?:[^#]*&)?{}=([^&#]*)", escape_regex(key)),
+        ("REF", None) => r"#(.*)$".to_string(),
+        (part, None) => invalid_argument_err!("Unsupported parse_url part: {part}"),
+        (part, Some(_)) => {
+            invalid_argument_err!("parse_url key is only supported for QUERY, got {part}")
+        }
+    };
+
+    Ok(extract(url.clone(), lit(pattern), 1))
+}
+
+fn escape_regex(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if r"\.+*?()|[]{}^$#&-~".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn regexp_count(input: ExprRef, pattern: ExprRef) -> ExprRef {
+    list::count(extract_all(input, pattern, 0), CountMode::All).cast(&DataType::Int32)
+}
+
+/// `regexp_instr(input, pattern, [idx])`, which returns the 1-based position of the first match of
+/// `pattern`, or 0 if there is none.
+fn regexp_instr(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let (input, pattern) = match args.as_slice() {
+        [input, pattern] => (input, pattern),
+        [input, pattern, idx] if integer_literal(idx, "regexp_instr index")? == 0 => {
+            (input, pattern)
+        }
+        [_, _, _] => not_yet_implemented!("regexp_instr with a group index other than 0"),
+        _ => invalid_argument_err!("regexp_instr requires 2 or 3 arguments"),
+    };
+
+    let matched = extract(input.clone(), pattern.clone(), 0);
+    Ok(input.clone().is_null().if_else(
+        null_lit(),
+        matched
+            .clone()
+            .is_null()
+            .if_else(lit(0), instr(input.clone(), matched)),
+    ))
+}
+
+/// `split_part(input, delimiter, part)`, where negative parts count from the end, and parts which
+/// are out of range are empty.
+fn split_part(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let [input, delimiter, part] = args.as_slice() else {
+        invalid_argument_err!("split_part requires exactly 3 arguments");
+    };
+
+    let index = match integer_literal(part, "split_part part")? {
+        0 => invalid_argument_err!("split_part part must not be 0"),
+        part if part > 0 => part - 1,
+        part => part,
+    };
+
+    let part = list::get(
+        split(input.clone(), delimiter.clone(), false),
+        lit(index),
+        lit(""),
+    );
+    Ok(input.clone().is_null().if_else(null_lit(), part))
+}
+
+/// `substring_index(input, delimiter, count)`, which returns the substring before `count`
+/// occurrences of `delimiter`, counting from the end if `count` is negative.
+fn substring_index(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let [input, delimiter, count] = args.as_slice() else {
+        invalid_argument_err!("substring_index requires exactly 3 arguments");
+    };
+
+    let parts = split(input.clone(), delimiter.clone(), false);
+    let parts = match integer_literal(count, "substring_index count")? {
+        0 => return Ok(input.clone().is_null().if_else(null_lit(), lit(""))),
+        count if count > 0 => list::slice(parts, lit(0i64), lit(count)),
+        count => list::slice(parts, lit(count), null_lit()),
+    };
+    Ok(coalesce(vec![
+        list::join(parts, delimiter.clone()),
+        input.clone().is_null().if_else(null_lit(), lit("")),
+    ]))
+}
+
+/// `overlay(input, replacement, pos, [len])`, which replaces `len` characters of `input` starting
+/// at the 1-based `pos`, where a negative `len` is the length of `replacement`.
+fn overlay(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let (input, replacement, pos, len) = match args.as_slice() {
+        [input, replacement, pos] => (input, replacement, pos, None),
+        [input, replacement, pos, len] => (input, replacement, pos, Some(len)),
+        _ => invalid_argument_err!("overlay requires 3 or 4 arguments"),
+    };
+
+    let replacement_len = length(replacement.clone()).cast(&DataType::Int64);
+    let len = match len {
+        Some(len) => {
+            let len = len.clone().cast(&DataType::Int64);
+            len.clone().lt(lit(0i64)).if_else(replacement_len, len)
+        }
+        None => replacement_len,
+    };
+    let pos = pos.clone().cast(&DataType::Int64);
+
+    let head = left(input.clone(), pos.clone().sub(lit(1i64)));
+    let tail = coalesce(vec![
+        substr(input.clone(), pos.sub(lit(1i64)).add(len), null_lit()),
+        lit(""),
+    ]);
+    Ok(head.add(replacement.clone()).add(tail))
+}
+
+/// `to_char(input, format)` of a date or timestamp, with a java `DateTimeFormatter` pattern.
+fn to_char(args: Vec<ExprRef>) -> ConnectResult<ExprRef> {
+    let [input, format] = args.as_slice() else {
+        invalid_argument_err!("to_char requires exactly 2 arguments");
+    };
+
+    let format = to_strftime_format(string_literal(format, "to_char format")?)?;
+    Ok(dt_strftime(input.clone(), format))
+}
+
+/// `decode(input, charset)` and `encode(input, charset)`, which only support UTF-8.
+struct CharsetFunction(DataType);
+
+impl SparkFunction for CharsetFunction {
+    fn to_expr(
+        &self,
+        args: &[Expression],
+        analyzer: &SparkAnalyzer,
+    ) -> ConnectResult<daft_dsl::ExprRef> {
+        let [input, charset] = args else {
+            invalid_argument_err!("requires exactly 2 arguments");
+        };
+        let input = analyzer.to_daft_expr(input)?;
+        let charset = analyzer.to_daft_expr(charset)?;
+
+        match string_literal(&charset, "charset")?.to_uppercase().as_str() {
+            "UTF-8" | "UTF8" => Ok(input.cast(&self.0)),
+            charset => not_yet_implemented!("charset {charset}"),
+        }
+    }
+}
+
+/// `btrim`, `ltrim`, `rtrim` and `trim`, which strip spaces, or any of the given characters.
+struct TrimFunction {
+    leading: bool,
+    trailing: bool,
+}
+
+impl TrimFunction {
+    fn both() -> Self {
+        Self {
+            leading: true,
+            trailing: true,
+        }
+    }
+
+    fn leading() -> Self {
+        Self {
+            leading: true,
+            trailing: false,
+        }
+    }
+
+    fn trailing() -> Self {
+        Self {
+            leading: false,
+            trailing: true,
+        }
+    }
+}
+
+impl SparkFunction for TrimFunction {
+    fn to_expr(
+        &self,
+        args: &[Expression],
+        analyzer: &SparkAnalyzer,
+    ) -> ConnectResult<daft_dsl::ExprRef> {
+        let args = args
+            .iter()
+            .map(|arg| analyzer.to_daft_expr(arg))
+            .collect::<ConnectResult<Vec<_>>>()?;
+
+        let (input, chars) = match args.as_slice() {
+            [input] => (input.clone(), lit(" ")),
+            [input, chars] => (input.clone(), chars.clone()),
+            _ => invalid_argument_err!("requires 1 or 2 arguments"),
+        };
+        Ok(trim(input, chars, self.leading, self.trailing))
+    }
+}
+
+/// `to_binary(input, [format])`, which decodes a string in the `hex` (default), `utf-8` or
+/// `base64` format, returning null for invalid input.
+pub(super) struct ToBinaryFunction;
+
+impl SparkFunction for ToBinaryFunction {
+    fn to_expr(
+        &self,
+        args: &[Expression],
+        analyzer: &SparkAnalyzer,
+    ) -> ConnectResult<daft_dsl::ExprRef> {
+        let args = args
+            .iter()
+            .map(|arg| analyzer.to_daft_expr(arg))
+            .collect::<ConnectResult<Vec<_>>>()?;
+
+        let (input, format) = match args.as_slice() {
+            [input] => (input.clone(), "hex"),
+            [input, format] => (input.clone(), string_literal(format, "to_binary format")?),
+            _ => invalid_argument_err!("to_binary requires 1 or 2 arguments"),
+        };
+
+        match format.to_lowercase().as_str() {
+            "hex" => Ok(unhex(input)),
+            "utf-8" | "utf8" => Ok(input.cast(&DataType::Binary)),
+            "base64" => Ok(base64_decode(input)),
+            other => invalid_argument_err!("Unsupported to_binary format: {other}"),
+        }
     }
 }
 
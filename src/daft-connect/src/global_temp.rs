@@ -0,0 +1,68 @@
+//! Global temporary views, which are shared by every Spark session of a server.
+//!
+//! Spark resolves these views in the reserved `global_temp` database, so they are exposed to each
+//! session as a catalog attached under that name.
+
+use std::sync::RwLock;
+
+use daft_catalog::{error::Result, Bindings, Catalog, Identifier, Table, View};
+use daft_logical_plan::LogicalPlanRef;
+
+/// The reserved database (and catalog alias) holding the global temporary views.
+pub const GLOBAL_TEMP_DATABASE: &str = "global_temp";
+
+#[derive(Debug)]
+pub struct GlobalTempViews {
+    views: RwLock<Bindings<View>>,
+}
+
+impl Default for GlobalTempViews {
+    fn default() -> Self {
+        Self {
+            views: RwLock::new(Bindings::empty()),
+        }
+    }
+}
+
+impl GlobalTempViews {
+    /// Creates the view, returning false if it already exists and `replace` is not set.
+    pub fn create_view(&self, name: &str, plan: LogicalPlanRef, replace: bool) -> bool {
+        let mut views = self.views.write().expect("global temp views lock poisoned");
+        if !replace && views.exists(name) {
+            return false;
+        }
+        views.insert(name.to_string(), View::from(plan));
+        true
+    }
+
+    /// Drops the view, returning false if it does not exist.
+    pub fn drop_view(&self, name: &str) -> bool {
+        let mut views = self.views.write().expect("global temp views lock poisoned");
+        if !views.exists(name) {
+            return false;
+        }
+        views.remove(name);
+        true
+    }
+}
+
+impl Catalog for GlobalTempViews {
+    fn name(&self) -> String {
+        GLOBAL_TEMP_DATABASE.to_string()
+    }
+
+    fn get_table(&self, name: &Identifier) -> Result<Option<Box<dyn Table>>> {
+        if name.has_namespace() {
+            return Ok(None);
+        }
+        let views = self.views.read().expect("global temp views lock poisoned");
+        Ok(views
+            .get(&name.name)
+            .map(|view| Box::new(view.clone()) as Box<dyn Table>))
+    }
+
+    fn list_tables(&self, pattern: Option<&str>) -> Result<Vec<String>> {
+        let views = self.views.read().expect("global temp views lock poisoned");
+        Ok(views.list(pattern))
+    }
+}
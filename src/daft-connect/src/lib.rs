@@ -22,6 +22,8 @@ mod error;
 #[cfg(feature = "python")]
 mod execute;
 #[cfg(feature = "python")]
+mod global_temp;
+#[cfg(feature = "python")]
 mod response_builder;
 #[cfg(feature = "python")]
 mod session;
@@ -33,6 +35,8 @@ pub mod util;
 #[cfg(feature = "python")]
 use connect_service::DaftSparkConnectService;
 #[cfg(feature = "python")]
+use daft_session::{python::PySession, Session};
+#[cfg(feature = "python")]
 use pyo3::types::PyModuleMethods;
 #[cfg(feature = "python")]
 use snafu::{ResultExt, Whatever};
@@ -67,7 +71,7 @@ impl ConnectionHandle {
 }
 
 #[cfg(feature = "python")]
-pub fn start(addr: &str) -> Result<ConnectionHandle, Whatever> {
+pub fn start(addr: &str, session: Option<Session>) -> Result<ConnectionHandle, Whatever> {
     info!("Daft-Connect server listening on {addr}");
     let addr = util::parse_spark_connect_address(addr).whatever_context("Invalid address")?;
 
@@ -78,7 +82,7 @@ pub fn start(addr: &str) -> Result<ConnectionHandle, Whatever> {
         .whatever_context("no local_addr")?
         .port();
 
-    let service = DaftSparkConnectService::default();
+    let service = DaftSparkConnectService::new(session);
 
     info!("Daft-Connect server listening on {addr}");
 
@@ -130,9 +134,13 @@ pub fn start(addr: &str) -> Result<ConnectionHandle, Whatever> {
 
 #[cfg(feature = "python")]
 #[cfg_attr(feature = "python", pyo3::pyfunction)]
-#[pyo3(name = "connect_start", signature = (addr = "sc://0.0.0.0:0"))]
-pub fn py_connect_start(addr: &str) -> pyo3::PyResult<ConnectionHandle> {
-    start(addr).map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("{e:?}")))
+#[pyo3(name = "connect_start", signature = (addr = "sc://0.0.0.0:0", session = None))]
+pub fn py_connect_start(
+    addr: &str,
+    session: Option<pyo3::PyRef<PySession>>,
+) -> pyo3::PyResult<ConnectionHandle> {
+    let session = session.map(|session| session.session().clone());
+    start(addr, session).map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("{e:?}")))
 }
 
 #[cfg(feature = "python")]
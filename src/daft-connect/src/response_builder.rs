@@ -10,6 +10,7 @@ use uuid::Uuid;
 use crate::{
     error::{ConnectResult, Context},
     session::ConnectSession,
+    spark_analyzer::spark_column_name,
 };
 
 /// A utility for constructing responses to send back to the client,
@@ -75,7 +76,10 @@ impl ResponseBuilder<ExecutePlanResponse> {
 
         let row_count = table.num_rows();
 
-        let schema = table.schema.to_arrow()?;
+        let mut schema = table.schema.to_arrow()?;
+        for field in &mut schema.fields {
+            field.name = spark_column_name(&field.name).to_string();
+        }
 
         writer
             .start(&schema, None)
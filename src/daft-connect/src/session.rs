@@ -7,6 +7,8 @@ use common_runtime::RuntimeRef;
 use daft_session::Session;
 use uuid::Uuid;
 
+use crate::global_temp::{GlobalTempViews, GLOBAL_TEMP_DATABASE};
+
 #[derive(Clone)]
 pub struct ConnectSession {
     /// so order is preserved, and so we can efficiently do a prefix search
@@ -18,6 +20,8 @@ pub struct ConnectSession {
     server_side_session_id: String,
     pub(crate) compute_runtime: RuntimeRef,
     pub session: Arc<RwLock<Session>>,
+    /// global temporary views shared by all sessions of this server
+    pub(crate) global_temp: Arc<GlobalTempViews>,
 }
 
 impl ConnectSession {
@@ -29,11 +33,16 @@ impl ConnectSession {
         &mut self.config_values
     }
 
-    pub fn new(id: String) -> Self {
+    pub fn new(id: String, session: Session, global_temp: Arc<GlobalTempViews>) -> Self {
         let server_side_session_id = Uuid::new_v4();
         let server_side_session_id = server_side_session_id.to_string();
         let compute_runtime = common_runtime::get_compute_runtime();
-        let session = Arc::new(RwLock::new(Session::default()));
+
+        // a shared session may already have the global temp views attached by another client.
+        if !session.has_catalog(GLOBAL_TEMP_DATABASE) {
+            let _ = session.attach_catalog(global_temp.clone(), GLOBAL_TEMP_DATABASE.to_string());
+        }
+        let session = Arc::new(RwLock::new(session));
 
         Self {
             config_values: Default::default(),
@@ -41,6 +50,7 @@ impl ConnectSession {
             server_side_session_id,
             compute_runtime,
             session,
+            global_temp,
         }
     }
 
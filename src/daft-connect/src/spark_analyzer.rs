@@ -1,18 +1,37 @@
 //! Translation between Spark Connect and Daft
 
+mod catalog;
 mod datatype;
 mod literal;
-
-use std::{collections::HashMap, io::Cursor, rc::Rc, sync::Arc};
+mod na;
+mod stat;
+
+use std::{
+    collections::HashMap,
+    io::Cursor,
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
 
 use arrow2::io::ipc::read::{read_stream_metadata, StreamReader, StreamState};
-use daft_core::series::Series;
-use daft_dsl::unresolved_col;
-use daft_logical_plan::{LogicalPlanBuilder, PyLogicalPlanBuilder};
+use daft_catalog::Identifier;
+use daft_core::{
+    join::{JoinSide, JoinStrategy, JoinType},
+    series::Series,
+};
+use daft_dsl::{
+    common_treenode::{Transformed, TreeNode, TreeNodeRecursion},
+    join_side_col, lit, unresolved_col, Column, Expr, ExprRef, Operator, ResolvedColumn,
+    WindowExpr, WindowSpec,
+};
+use daft_logical_plan::{JoinOptions, LogicalPlanBuilder, PyLogicalPlanBuilder};
 use daft_micropartition::{self, python::PyMicroPartition, MicroPartition};
 use daft_recordbatch::RecordBatch;
 use daft_scan::builder::{delta_scan, CsvScanBuilder, JsonScanBuilder, ParquetScanBuilder};
-use daft_schema::schema::{Schema, SchemaRef};
+use daft_schema::{
+    dtype::DataType,
+    schema::{Schema, SchemaRef},
+};
 use daft_sql::SQLPlanner;
 use datatype::to_daft_datatype;
 pub use datatype::to_spark_datatype;
@@ -25,13 +44,17 @@ use spark_connect::{
     expression::{
         self as spark_expr,
         cast::{CastToType, EvalMode},
+        literal::LiteralType,
         sort_order::{NullOrdering, SortDirection},
         ExprType, SortOrder, UnresolvedFunction,
     },
+    join::JoinType as SparkJoinType,
     read::ReadType,
     relation::RelType,
     set_operation::SetOpType,
-    Deduplicate, Expression, Limit, Range, Relation, SetOperation, Sort, Sql,
+    Deduplicate, Expression, Hint, Join, Limit, Offset, Range, Relation, Repartition,
+    RepartitionByExpression, SetOperation, Sort, Sql, SubqueryAlias, Tail, ToSchema, Unpivot,
+    WithRelations,
 };
 use tracing::debug;
 
@@ -47,11 +70,77 @@ use crate::{
 #[derive(Clone)]
 pub struct SparkAnalyzer<'a> {
     pub session: &'a ConnectSession,
+    /// The inputs of the join whose condition is being translated, if any.
+    join_inputs: Option<JoinInputs>,
+    /// Columns of join inputs that were renamed in the join output, keyed by the plan id of the
+    /// input and the original column name.
+    renamed_columns: Arc<Mutex<HashMap<(i64, String), String>>>,
+}
+
+/// Separates a column name from the plan id of the join input it came from.
+///
+/// Daft does not allow duplicate column names, so a join renames the columns of its right input
+/// that clash with left columns to `<name>#<plan id>`.
+const JOIN_COLUMN_MARKER: char = '#';
+
+/// Returns the name of a column as Spark shows it, i.e. without the plan id that a join added to
+/// deduplicate it.
+pub fn spark_column_name(mut name: &str) -> &str {
+    while let Some((base, plan_id)) = name.rsplit_once(JOIN_COLUMN_MARKER) {
+        if base.is_empty() || !plan_id.bytes().all(|b| b.is_ascii_digit()) {
+            break;
+        }
+        name = base;
+    }
+    name
+}
+
+/// The inputs of a join, used to resolve the columns of a join condition to a side of the join.
+#[derive(Clone)]
+struct JoinInputs {
+    left_plan_id: Option<i64>,
+    right_plan_id: Option<i64>,
+    left_schema: SchemaRef,
+    right_schema: SchemaRef,
+}
+
+impl JoinInputs {
+    /// Resolves a column to a side of the join.
+    ///
+    /// Columns qualified with a plan id (e.g. `df1.id`) belong to the input with that plan id,
+    /// unqualified columns must exist in exactly one of the inputs.
+    fn resolve(&self, name: &str, plan_id: Option<i64>) -> ConnectResult<ExprRef> {
+        let side = match plan_id {
+            Some(plan_id) if Some(plan_id) == self.left_plan_id => JoinSide::Left,
+            Some(plan_id) if Some(plan_id) == self.right_plan_id => JoinSide::Right,
+            _ => match (
+                self.left_schema.has_field(name),
+                self.right_schema.has_field(name),
+            ) {
+                (true, true) => {
+                    invalid_argument_err!(
+                        "Ambiguous reference to column `{name}` in join condition"
+                    )
+                }
+                (true, false) => JoinSide::Left,
+                (false, true) => JoinSide::Right,
+                (false, false) => {
+                    invalid_argument_err!("Column `{name}` not found in either side of the join")
+                }
+            },
+        };
+
+        Ok(join_side_col(name, side))
+    }
 }
 
 impl SparkAnalyzer<'_> {
     pub fn new(session: &ConnectSession) -> SparkAnalyzer<'_> {
-        SparkAnalyzer { session }
+        SparkAnalyzer {
+            session,
+            join_inputs: None,
+            renamed_columns: Arc::default(),
+        }
     }
 
     /// Creates a logical source (scan) operator from a vec of tables.
@@ -115,8 +204,34 @@ impl SparkAnalyzer<'_> {
             RelType::ShowString(_) => unreachable!("should already be handled in execute"),
             RelType::Deduplicate(rel) => self.deduplicate(*rel).await,
             RelType::Sort(rel) => self.sort(*rel).await,
-            RelType::Sql(sql) => self.sql(sql).await,
+            RelType::Sql(sql) => self.sql(sql, Vec::new()).await,
             RelType::SetOp(set_op) => self.set_op(*set_op).await,
+            RelType::Join(join) => self.join(*join).await,
+            RelType::FillNa(fill_na) => self.fill_na(*fill_na).await,
+            RelType::DropNa(drop_na) => self.drop_na(*drop_na).await,
+            RelType::Replace(replace) => self.replace(*replace).await,
+            RelType::Summary(summary) => self.summary(*summary).await,
+            RelType::Describe(describe) => self.describe(*describe).await,
+            RelType::Crosstab(crosstab) => self.crosstab(*crosstab).await,
+            RelType::Cov(cov) => self.cov(*cov).await,
+            RelType::Corr(corr) => self.corr(*corr).await,
+            RelType::ApproxQuantile(approx_quantile) => {
+                self.approx_quantile(*approx_quantile).await
+            }
+            RelType::FreqItems(freq_items) => self.freq_items(*freq_items).await,
+            RelType::SampleBy(sample_by) => self.sample_by(*sample_by).await,
+            RelType::Offset(offset) => self.offset(*offset).await,
+            RelType::Tail(tail) => self.tail(*tail).await,
+            RelType::Repartition(repartition) => self.repartition(*repartition).await,
+            RelType::RepartitionByExpression(repartition) => {
+                self.repartition_by_expression(*repartition).await
+            }
+            RelType::Hint(hint) => self.hint(*hint).await,
+            RelType::Unpivot(unpivot) => self.unpivot(*unpivot).await,
+            RelType::ToSchema(to_schema) => self.to_schema(*to_schema).await,
+            RelType::SubqueryAlias(subquery_alias) => self.subquery_alias(*subquery_alias).await,
+            RelType::WithRelations(with_relations) => self.with_relations(*with_relations).await,
+            RelType::Catalog(catalog) => self.catalog(catalog),
             plan => not_yet_implemented!(r#"relation type: "{}""#, rel_name(&plan)),
         }
     }
@@ -130,6 +245,73 @@ impl SparkAnalyzer<'_> {
         plan.limit(i64::from(limit), false).map_err(Into::into)
     }
 
+    /// Daft has no offset operator, so rows are tagged with monotonically increasing ids in their
+    /// input order, numbered in the order of those ids, and the first `offset` rows are filtered
+    /// out.
+    async fn offset(&self, offset: Offset) -> ConnectResult<LogicalPlanBuilder> {
+        let Offset { input, offset } = offset;
+        let input = input.required("input")?;
+
+        ensure!(offset >= 0, "offset must be non-negative; got {}", offset);
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+        if offset == 0 {
+            return Ok(plan);
+        }
+
+        // the ids are only increasing, with gaps between partitions, so they are turned into row
+        // numbers by a window ordered on them
+        let row_number = Expr::Over(
+            WindowExpr::RowNumber,
+            WindowSpec {
+                order_by: vec![unresolved_col(ROW_ID_COLUMN)],
+                descending: vec![false],
+                nulls_first: vec![false],
+                ..Default::default()
+            },
+        )
+        .arced();
+        let plan = plan
+            .add_monotonically_increasing_id(Some(ROW_ID_COLUMN))?
+            .with_columns(vec![row_number.alias(ROW_NUMBER_COLUMN)])?
+            .filter(unresolved_col(ROW_NUMBER_COLUMN).gt(lit(i64::from(offset))))?
+            .sort(
+                vec![unresolved_col(ROW_ID_COLUMN)],
+                vec![false],
+                vec![false],
+            )?
+            .exclude(vec![
+                ROW_ID_COLUMN.to_string(),
+                ROW_NUMBER_COLUMN.to_string(),
+            ])?;
+
+        Ok(plan)
+    }
+
+    /// The last `limit` rows are taken by tagging the rows with monotonically increasing ids in
+    /// their input order, keeping the highest ids and restoring the input order afterwards.
+    async fn tail(&self, tail: Tail) -> ConnectResult<LogicalPlanBuilder> {
+        let Tail { input, limit } = tail;
+        let input = input.required("input")?;
+
+        ensure!(limit >= 0, "limit must be non-negative; got {}", limit);
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        let plan = plan
+            .add_monotonically_increasing_id(Some(ROW_ID_COLUMN))?
+            .sort(vec![unresolved_col(ROW_ID_COLUMN)], vec![true], vec![false])?
+            .limit(i64::from(limit), false)?
+            .sort(
+                vec![unresolved_col(ROW_ID_COLUMN)],
+                vec![false],
+                vec![false],
+            )?
+            .exclude(vec![ROW_ID_COLUMN.to_string()])?;
+
+        Ok(plan)
+    }
+
     async fn deduplicate(&self, deduplicate: Deduplicate) -> ConnectResult<LogicalPlanBuilder> {
         let Deduplicate {
             input,
@@ -265,11 +447,32 @@ impl SparkAnalyzer<'_> {
         let read_type = read_type.required("read_type")?;
 
         match read_type {
-            ReadType::NamedTable(_) => not_yet_implemented!("NamedTable"),
+            ReadType::NamedTable(table) => self.read_named_table(table),
             ReadType::DataSource(source) => self.read_datasource(source).await,
         }
     }
 
+    /// Reads a table from the session, e.g. `spark.table("t")` or `spark.read.table("t")`.
+    fn read_named_table(
+        &self,
+        named_table: spark_connect::read::NamedTable,
+    ) -> ConnectResult<LogicalPlanBuilder> {
+        let spark_connect::read::NamedTable {
+            unparsed_identifier,
+            options,
+        } = named_table;
+
+        if !options.is_empty() {
+            debug!("Ignoring options: {options:?} (not yet implemented)");
+        }
+
+        let ident = Identifier::from_sql(&unparsed_identifier, false)?;
+        let table = self.session.session().get_table(&ident)?;
+        let plan = table.get_logical_plan()?;
+
+        Ok(LogicalPlanBuilder::from(plan).alias(ident.name))
+    }
+
     async fn read_datasource(
         &self,
         data_source: spark_connect::read::DataSource,
@@ -685,6 +888,186 @@ impl SparkAnalyzer<'_> {
         Ok(plan)
     }
 
+    /// `repartition` shuffles the rows randomly, while `coalesce` merges the existing partitions
+    /// without a shuffle.
+    async fn repartition(&self, repartition: Repartition) -> ConnectResult<LogicalPlanBuilder> {
+        let Repartition {
+            input,
+            num_partitions,
+            shuffle,
+        } = repartition;
+
+        let input = input.required("input")?;
+        let num_partitions = to_num_partitions(num_partitions)?;
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        if shuffle.unwrap_or(false) {
+            Ok(plan.random_shuffle(Some(num_partitions))?)
+        } else {
+            Ok(plan.into_partitions(num_partitions)?)
+        }
+    }
+
+    async fn repartition_by_expression(
+        &self,
+        repartition: RepartitionByExpression,
+    ) -> ConnectResult<LogicalPlanBuilder> {
+        let RepartitionByExpression {
+            input,
+            partition_exprs,
+            num_partitions,
+        } = repartition;
+
+        let input = input.required("input")?;
+        let num_partitions = num_partitions.map(to_num_partitions).transpose()?;
+
+        // `repartitionByRange` sends the partitioning columns as sort orders
+        if partition_exprs
+            .iter()
+            .any(|expr| matches!(expr.expr_type, Some(ExprType::SortOrder(_))))
+        {
+            not_yet_implemented!("repartitionByRange");
+        }
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        let partition_by: Vec<_> = partition_exprs
+            .iter()
+            .map(|expr| self.to_daft_expr(expr))
+            .try_collect()?;
+
+        if partition_by.is_empty() {
+            Ok(plan.random_shuffle(num_partitions)?)
+        } else {
+            Ok(plan.hash_repartition(num_partitions, partition_by)?)
+        }
+    }
+
+    /// Join strategy hints such as `broadcast(df)` are picked up by the join that the hinted
+    /// relation is an input of, see [`join_strategy_hint`]. The `REPARTITION` and `COALESCE` hints
+    /// are applied like the corresponding repartitioning, and like in Spark, other hints are ignored.
+    async fn hint(&self, hint: Hint) -> ConnectResult<LogicalPlanBuilder> {
+        let Hint {
+            input,
+            name,
+            parameters,
+        } = hint;
+
+        let input = input.required("input")?;
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        let hint_name = name.to_lowercase();
+        if join_strategy(&hint_name).is_some() {
+            return Ok(plan);
+        }
+        if hint_name != "repartition" && hint_name != "coalesce" {
+            debug!("Ignoring unsupported hint: {name}");
+            return Ok(plan);
+        }
+
+        // partitioning hints take an optional number of partitions, followed by the columns to
+        // partition by
+        let num_partitions = parameters.first().and_then(integer_literal);
+        let columns = &parameters[usize::from(num_partitions.is_some())..];
+
+        let num_partitions = num_partitions.map(to_num_partitions).transpose()?;
+        let partition_by: Vec<_> = columns
+            .iter()
+            .map(|expr| self.to_daft_expr(expr))
+            .try_collect()?;
+
+        match num_partitions {
+            Some(num_partitions) if hint_name == "coalesce" && partition_by.is_empty() => {
+                Ok(plan.into_partitions(num_partitions)?)
+            }
+            _ if hint_name == "coalesce" => {
+                invalid_argument_err!("COALESCE hint requires only a number of partitions")
+            }
+            _ if partition_by.is_empty() => Ok(plan.random_shuffle(num_partitions)?),
+            _ => Ok(plan.hash_repartition(num_partitions, partition_by)?),
+        }
+    }
+
+    async fn unpivot(&self, unpivot: Unpivot) -> ConnectResult<LogicalPlanBuilder> {
+        let Unpivot {
+            input,
+            ids,
+            values,
+            variable_column_name,
+            value_column_name,
+        } = unpivot;
+
+        let input = input.required("input")?;
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        let ids: Vec<_> = ids
+            .iter()
+            .map(|expr| self.to_daft_expr(expr))
+            .try_collect()?;
+
+        // without values, all columns other than the ids are unpivoted
+        let values: Vec<_> = values
+            .map(|values| values.values)
+            .unwrap_or_default()
+            .iter()
+            .map(|expr| self.to_daft_expr(expr))
+            .try_collect()?;
+
+        Ok(plan.unpivot(ids, values, variable_column_name, value_column_name)?)
+    }
+
+    /// Like Spark, the columns are matched to the fields of the schema by name and cast to the
+    /// types of the fields.
+    async fn to_schema(&self, to_schema: ToSchema) -> ConnectResult<LogicalPlanBuilder> {
+        let ToSchema { input, schema } = to_schema;
+
+        let input = input.required("input")?;
+        let schema = schema.required("schema")?;
+
+        let DataType::Struct(fields) = to_daft_datatype(&schema)? else {
+            invalid_argument_err!("ToSchema requires a struct schema; got {schema:?}");
+        };
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        let columns = fields
+            .into_iter()
+            .map(|field| {
+                unresolved_col(field.name.as_str())
+                    .cast(&field.dtype)
+                    .alias(field.name.as_str())
+            })
+            .collect();
+
+        Ok(plan.select(columns)?)
+    }
+
+    async fn subquery_alias(
+        &self,
+        subquery_alias: SubqueryAlias,
+    ) -> ConnectResult<LogicalPlanBuilder> {
+        let SubqueryAlias {
+            input,
+            alias,
+            qualifier,
+        } = subquery_alias;
+
+        if !qualifier.is_empty() {
+            debug!(
+                "Ignoring qualifier {qualifier:?} of subquery alias {alias}; not yet implemented"
+            );
+        }
+
+        let input = input.required("input")?;
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        Ok(plan.alias(alias))
+    }
+
     async fn set_op(&self, set_op: SetOperation) -> ConnectResult<LogicalPlanBuilder> {
         let set_op_type = set_op.set_op_type;
         let left = set_op.left_input.required("left_input")?;
@@ -708,6 +1091,200 @@ impl SparkAnalyzer<'_> {
         .map_err(Into::into)
     }
 
+    /// Translates a Spark join into a Daft join.
+    ///
+    /// Like Spark, a join on `using_columns` outputs each join key once, followed by the remaining
+    /// columns of the left and right inputs. A join on a condition keeps the columns of both
+    /// inputs. Columns of the right input that share a name with a left column are renamed to
+    /// `<name>#<plan id>` and can be referenced through the right input, e.g. `df2.id`; the plan id
+    /// is removed again from the names returned to the client.
+    async fn join(&self, join: Join) -> ConnectResult<LogicalPlanBuilder> {
+        let Join {
+            left,
+            right,
+            join_condition,
+            join_type,
+            using_columns,
+            join_data_type,
+        } = join;
+
+        let left = left.required("left")?;
+        let right = right.required("right")?;
+
+        if let Some(join_data_type) = join_data_type {
+            not_yet_implemented!("joinWith; got {join_data_type:?}");
+        }
+
+        if join_condition.is_some() && !using_columns.is_empty() {
+            invalid_argument_err!("Join condition and using columns cannot both be specified");
+        }
+
+        let join_type = match SparkJoinType::try_from(join_type).wrap_err("Invalid join type")? {
+            SparkJoinType::Inner | SparkJoinType::Cross => JoinType::Inner,
+            SparkJoinType::FullOuter => JoinType::Outer,
+            SparkJoinType::LeftOuter => JoinType::Left,
+            SparkJoinType::RightOuter => JoinType::Right,
+            SparkJoinType::LeftAnti => JoinType::Anti,
+            SparkJoinType::LeftSemi => JoinType::Semi,
+            SparkJoinType::Unspecified => {
+                invalid_argument_err!("JoinType must be specified; got Unspecified")
+            }
+        };
+
+        let left_plan_id = left.common.as_ref().and_then(|common| common.plan_id);
+        let right_plan_id = right.common.as_ref().and_then(|common| common.plan_id);
+
+        // a join strategy hint on either input applies to the join. Like in Spark, a broadcast hint
+        // is ignored if the join type can't broadcast the hinted side. The hinted side isn't part of
+        // the plan, so inner joins, which can broadcast either side, broadcast the smaller side.
+        let is_broadcast = |strategy: &JoinStrategy| *strategy == JoinStrategy::Broadcast;
+        let left_hint = join_strategy_hint(&left).filter(|strategy| {
+            !is_broadcast(strategy) || matches!(join_type, JoinType::Inner | JoinType::Right)
+        });
+        let right_hint = join_strategy_hint(&right).filter(|strategy| {
+            !is_broadcast(strategy)
+                || matches!(
+                    join_type,
+                    JoinType::Inner | JoinType::Left | JoinType::Anti | JoinType::Semi
+                )
+        });
+        let join_strategy = left_hint.or(right_hint);
+
+        let left = Box::pin(self.to_logical_plan(*left)).await?;
+        let right = Box::pin(self.to_logical_plan(*right)).await?;
+
+        let left_schema = left.schema();
+        let right_schema = right.schema();
+        let suffix = format!(
+            "{JOIN_COLUMN_MARKER}{}",
+            right_plan_id.map(|id| id.to_string()).unwrap_or_default()
+        );
+        let options = JoinOptions::default().suffix(suffix);
+
+        if !using_columns.is_empty() {
+            let keys: Vec<_> = using_columns
+                .iter()
+                .map(|name| unresolved_col(name.as_str()))
+                .collect();
+
+            let plan = left.join(
+                right,
+                keys.clone(),
+                keys,
+                join_type,
+                join_strategy,
+                options.merge_matching_join_keys(true),
+            )?;
+            self.register_renamed_columns(right_plan_id, &left_schema, &right_schema, &plan);
+
+            // the join keys come first, followed by the remaining columns of both inputs
+            let columns = using_columns
+                .iter()
+                .cloned()
+                .chain(
+                    plan.schema()
+                        .names()
+                        .into_iter()
+                        .filter(|name| !using_columns.contains(name)),
+                )
+                .map(unresolved_col)
+                .collect();
+
+            return Ok(plan.select(columns)?);
+        }
+
+        let Some(join_condition) = join_condition else {
+            // a join without a condition is a cross join
+            let plan = left.cross_join(right, options)?;
+            self.register_renamed_columns(right_plan_id, &left_schema, &right_schema, &plan);
+            return Ok(plan);
+        };
+
+        let analyzer = SparkAnalyzer {
+            session: self.session,
+            join_inputs: Some(JoinInputs {
+                left_plan_id,
+                right_plan_id,
+                left_schema: left_schema.clone(),
+                right_schema: right_schema.clone(),
+            }),
+            renamed_columns: self.renamed_columns.clone(),
+        };
+        let join_condition = analyzer.to_daft_expr(&join_condition)?;
+
+        // equalities between the two sides become join keys, everything else is a join predicate
+        let mut left_on = Vec::new();
+        let mut right_on = Vec::new();
+        let mut null_equals_nulls = Vec::new();
+        let mut predicates = Vec::new();
+
+        for conjunct in split_conjunction(join_condition) {
+            if let Expr::BinaryOp {
+                op: op @ (Operator::Eq | Operator::EqNullSafe),
+                left: lhs,
+                right: rhs,
+            } = conjunct.as_ref()
+            {
+                let keys = match (join_side(lhs), join_side(rhs)) {
+                    (Some(JoinSide::Left), Some(JoinSide::Right)) => Some((lhs, rhs)),
+                    (Some(JoinSide::Right), Some(JoinSide::Left)) => Some((rhs, lhs)),
+                    _ => None,
+                };
+
+                if let Some((left_key, right_key)) = keys {
+                    left_on.push(strip_join_side(left_key.clone())?);
+                    right_on.push(strip_join_side(right_key.clone())?);
+                    null_equals_nulls.push(*op == Operator::EqNullSafe);
+                    continue;
+                }
+            }
+
+            predicates.push(conjunct);
+        }
+
+        let predicate = predicates.into_iter().reduce(|acc, expr| acc.and(expr));
+
+        // join strategies only apply to joins on keys
+        let join_strategy = join_strategy.filter(|_| !left_on.is_empty());
+
+        let plan = left.join_with_predicate(
+            right,
+            left_on,
+            right_on,
+            Some(null_equals_nulls),
+            predicate,
+            join_type,
+            join_strategy,
+            options,
+        )?;
+        self.register_renamed_columns(right_plan_id, &left_schema, &right_schema, &plan);
+
+        Ok(plan)
+    }
+
+    /// Records which columns of the right input of a join were renamed in the join output.
+    fn register_renamed_columns(
+        &self,
+        right_plan_id: Option<i64>,
+        left_schema: &Schema,
+        right_schema: &Schema,
+        plan: &LogicalPlanBuilder,
+    ) {
+        let Some(right_plan_id) = right_plan_id else {
+            return;
+        };
+
+        let output_schema = plan.schema();
+        let mut renamed_columns = self.renamed_columns.lock().unwrap();
+
+        for name in right_schema.names() {
+            let renamed = format!("{name}{JOIN_COLUMN_MARKER}{right_plan_id}");
+            if left_schema.has_field(&name) && output_schema.has_field(&renamed) {
+                renamed_columns.insert((right_plan_id, name), renamed);
+            }
+        }
+    }
+
     pub async fn relation_to_spark_schema(
         &self,
         input: Relation,
@@ -720,7 +1297,7 @@ impl SparkAnalyzer<'_> {
             .map(|(name, field)| {
                 let field_type = to_spark_datatype(&field.dtype);
                 Ok(StructField {
-                    name: name.clone(), // todo(correctness): name vs field.name... will they always be the same?
+                    name: spark_column_name(name).to_string(), // todo(correctness): name vs field.name... will they always be the same?
                     data_type: Some(field_type),
                     nullable: true, // todo(correctness): is this correct?
                     metadata: None, // todo(completeness): might want to add metadata here
@@ -752,8 +1329,43 @@ impl SparkAnalyzer<'_> {
         Ok(result)
     }
 
+    /// `spark.sql` with DataFrame arguments, e.g. `spark.sql("SELECT * FROM {df}", df=df)`, sends
+    /// the query along with the DataFrames, each aliased by the name it is referenced by in the
+    /// query.
+    async fn with_relations(
+        &self,
+        with_relations: WithRelations,
+    ) -> ConnectResult<LogicalPlanBuilder> {
+        let WithRelations { root, references } = with_relations;
+
+        let root = root.required("root")?;
+
+        let mut tables = Vec::with_capacity(references.len());
+        for reference in references {
+            let Some(RelType::SubqueryAlias(subquery_alias)) = reference.rel_type else {
+                not_yet_implemented!("WithRelations references other than SubqueryAlias");
+            };
+            let SubqueryAlias { input, alias, .. } = *subquery_alias;
+
+            let input = input.required("input")?;
+            let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+            tables.push((alias, plan));
+        }
+
+        match root.rel_type.required("rel_type")? {
+            RelType::Sql(sql) => self.sql(sql, tables).await,
+            other => not_yet_implemented!(r#"WithRelations root: "{}""#, rel_name(&other)),
+        }
+    }
+
+    /// Plans a SQL query, with the given tables bound by name for the query.
     #[allow(deprecated)]
-    async fn sql(&self, sql: Sql) -> ConnectResult<LogicalPlanBuilder> {
+    async fn sql(
+        &self,
+        sql: Sql,
+        tables: Vec<(String, LogicalPlanBuilder)>,
+    ) -> ConnectResult<LogicalPlanBuilder> {
         let Sql {
             query,
             args,
@@ -779,6 +1391,9 @@ impl SparkAnalyzer<'_> {
         let session = Rc::new(session);
 
         let mut planner = SQLPlanner::new(session);
+        for (name, plan) in tables {
+            planner.bind_table(name, plan);
+        }
         let plan = planner.plan_sql(&query)?;
         Ok(plan.into())
     }
@@ -803,17 +1418,30 @@ impl SparkAnalyzer<'_> {
                     is_metadata_column,
                 } = attr;
 
-                if let Some(plan_id) = plan_id {
-                    debug!(
-                        "Ignoring plan_id {plan_id} for attribute expressions; not yet implemented"
-                    );
-                }
-
                 if let Some(is_metadata_column) = is_metadata_column {
                     debug!("Ignoring is_metadata_column {is_metadata_column} for attribute expressions; not yet implemented");
                 }
 
-                Ok(daft_dsl::unresolved_col(unparsed_identifier.as_str()))
+                // a column of a join input that was renamed in the join output
+                let renamed = plan_id.and_then(|plan_id| {
+                    self.renamed_columns
+                        .lock()
+                        .unwrap()
+                        .get(&(plan_id, unparsed_identifier.clone()))
+                        .cloned()
+                });
+                let name = renamed.as_deref().unwrap_or(unparsed_identifier);
+
+                if let Some(join_inputs) = &self.join_inputs {
+                    // the inputs of the join being translated have not been renamed yet
+                    let is_input = plan_id.is_some()
+                        && (*plan_id == join_inputs.left_plan_id
+                            || *plan_id == join_inputs.right_plan_id);
+                    let name = if is_input { unparsed_identifier } else { name };
+                    return join_inputs.resolve(name, *plan_id);
+                }
+
+                Ok(daft_dsl::unresolved_col(name))
             }
             spark_expr::ExprType::UnresolvedFunction(f) => self.process_function(f),
             spark_expr::ExprType::ExpressionString(_) => {
@@ -929,6 +1557,95 @@ impl SparkAnalyzer<'_> {
     }
 }
 
+/// Splits an expression into its conjuncts, e.g. `a AND (b AND c)` into `[a, b, c]`.
+fn split_conjunction(expr: ExprRef) -> Vec<ExprRef> {
+    match expr.as_ref() {
+        Expr::BinaryOp {
+            op: Operator::And,
+            left,
+            right,
+        } => {
+            let mut conjuncts = split_conjunction(left.clone());
+            conjuncts.extend(split_conjunction(right.clone()));
+            conjuncts
+        }
+        _ => vec![expr],
+    }
+}
+
+/// Returns the side of the join that all columns of a join condition expression come from, if
+/// it references columns of exactly one side.
+fn join_side(expr: &ExprRef) -> Option<JoinSide> {
+    let mut sides = Vec::new();
+    expr.apply(|e| {
+        if let Expr::Column(Column::Resolved(ResolvedColumn::JoinSide(_, side))) = e.as_ref() {
+            sides.push(*side);
+        }
+        Ok(TreeNodeRecursion::Continue)
+    })
+    .ok()?;
+
+    let mut sides = sides.into_iter();
+    let side = sides.next()?;
+    sides.all(|s| s == side).then_some(side)
+}
+
+/// Turns the join side columns of a join key back into regular columns of that side's input.
+fn strip_join_side(expr: ExprRef) -> ConnectResult<ExprRef> {
+    Ok(expr
+        .transform(|e| match e.as_ref() {
+            Expr::Column(Column::Resolved(ResolvedColumn::JoinSide(name, _))) => {
+                Ok(Transformed::yes(unresolved_col(name.clone())))
+            }
+            _ => Ok(Transformed::no(e)),
+        })?
+        .data)
+}
+
+/// The column that rows are numbered in for relations that depend on the row order, like `Offset`.
+const ROW_ID_COLUMN: &str = "__row_id__";
+const ROW_NUMBER_COLUMN: &str = "__row_number__";
+
+/// Validates a number of partitions requested by the client.
+fn to_num_partitions(num_partitions: i32) -> ConnectResult<usize> {
+    let num_partitions =
+        usize::try_from(num_partitions).wrap_err("num_partitions must be a positive integer")?;
+    ensure!(num_partitions > 0, "num_partitions must be greater than 0");
+    Ok(num_partitions)
+}
+
+fn integer_literal(expression: &Expression) -> Option<i32> {
+    match &expression.expr_type {
+        Some(ExprType::Literal(spark_expr::Literal {
+            literal_type: Some(LiteralType::Integer(value)),
+        })) => Some(*value),
+        _ => None,
+    }
+}
+
+/// The join strategy requested by a join hint, e.g. `broadcast` for `broadcast(df)`.
+fn join_strategy(hint_name: &str) -> Option<JoinStrategy> {
+    match hint_name.to_lowercase().as_str() {
+        "broadcast" | "broadcastjoin" | "mapjoin" => Some(JoinStrategy::Broadcast),
+        "shuffle_hash" => Some(JoinStrategy::Hash),
+        _ => None,
+    }
+}
+
+/// Returns the join strategy hinted on an input of a join, looking through aliases such as
+/// `broadcast(df).alias("d")`.
+fn join_strategy_hint(relation: &Relation) -> Option<JoinStrategy> {
+    match relation.rel_type.as_ref()? {
+        RelType::Hint(hint) => {
+            join_strategy(&hint.name).or_else(|| join_strategy_hint(hint.input.as_deref()?))
+        }
+        RelType::SubqueryAlias(subquery_alias) => {
+            join_strategy_hint(subquery_alias.input.as_deref()?)
+        }
+        _ => None,
+    }
+}
+
 fn rel_name(rel: &RelType) -> &str {
     match rel {
         RelType::Read(_) => "Read",
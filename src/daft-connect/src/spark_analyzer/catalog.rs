@@ -0,0 +1,469 @@
+//! Translation of the `spark.catalog` relations, which are backed by the daft session.
+//!
+//! Spark databases are the namespaces of the session's current catalog, where the `default`
+//! database is the empty namespace. The pyspark client reads these results by position, so the
+//! columns follow the order of spark's `Table`, `Database`, `Column` and `CatalogMetadata`.
+
+use std::sync::Arc;
+
+use arrow2::offset::Offsets;
+use daft_catalog::{Catalog as _, Identifier, Namespace};
+use daft_core::prelude::{BooleanArray, DataType, Field, IntoSeries, ListArray, Series, Utf8Array};
+use daft_logical_plan::LogicalPlanBuilder;
+use daft_recordbatch::RecordBatch;
+use daft_schema::schema::Schema;
+use daft_session::Session;
+use spark_connect::{
+    catalog::CatType, Catalog, CurrentCatalog, CurrentDatabase, DatabaseExists, DropGlobalTempView,
+    DropTempView, FunctionExists, GetDatabase, GetTable, ListCatalogs, ListColumns, ListDatabases,
+    ListTables, SetCurrentCatalog, SetCurrentDatabase, TableExists,
+};
+
+use super::SparkAnalyzer;
+use crate::{
+    error::{ConnectResult, Context},
+    functions::CONNECT_FUNCTIONS,
+    global_temp::GLOBAL_TEMP_DATABASE,
+    invalid_argument_err, not_yet_implemented,
+    util::FromOptionalField,
+};
+
+/// The spark database of the empty namespace.
+const DEFAULT_DATABASE: &str = "default";
+
+/// A row of the `listTables` and `getTable` results.
+struct TableInfo {
+    name: String,
+    catalog: Option<String>,
+    namespace: Namespace,
+    table_type: &'static str,
+    is_temporary: bool,
+}
+
+impl TableInfo {
+    /// Session tables (and global temp views) are spark's temporary views.
+    fn temporary(name: String, namespace: Namespace) -> Self {
+        Self {
+            name,
+            catalog: None,
+            namespace,
+            table_type: "TEMPORARY",
+            is_temporary: true,
+        }
+    }
+
+    fn external(name: String, catalog: String, namespace: Namespace) -> Self {
+        Self {
+            name,
+            catalog: Some(catalog),
+            namespace,
+            table_type: "EXTERNAL",
+            is_temporary: false,
+        }
+    }
+}
+
+impl SparkAnalyzer<'_> {
+    pub(super) fn catalog(&self, catalog: Catalog) -> ConnectResult<LogicalPlanBuilder> {
+        let cat_type = catalog.cat_type.required("cat_type")?;
+
+        // the session shares its state, so there's no need to hold the lock.
+        let session = self.session.session().clone();
+
+        match cat_type {
+            CatType::CurrentDatabase(CurrentDatabase {}) => {
+                self.string_result(to_database(&session.current_namespace()))
+            }
+            CatType::SetCurrentDatabase(SetCurrentDatabase { db_name }) => {
+                session.set_namespace(to_namespace(&db_name))?;
+                self.empty_result()
+            }
+            CatType::ListDatabases(ListDatabases { pattern }) => {
+                let databases = list_databases(&session)?
+                    .into_iter()
+                    .filter(|db| matches_pattern(db, pattern.as_deref()))
+                    .collect::<Vec<_>>();
+                self.databases_result(&session, &databases)
+            }
+            CatType::ListTables(ListTables { db_name, pattern }) => {
+                let namespace = match db_name {
+                    Some(db_name) => to_namespace(&db_name),
+                    None => session.current_namespace(),
+                };
+                let tables = self
+                    .list_tables(&session, &namespace)?
+                    .into_iter()
+                    .filter(|table| matches_pattern(&table.name, pattern.as_deref()))
+                    .collect::<Vec<_>>();
+                self.tables_result(&tables)
+            }
+            CatType::ListColumns(ListColumns {
+                table_name,
+                db_name,
+            }) => {
+                let ident = to_identifier(&table_name, db_name.as_deref())?;
+                let schema = session.get_table(&ident)?.get_schema();
+                self.columns_result(&schema)
+            }
+            CatType::GetDatabase(GetDatabase { db_name }) => {
+                if !database_exists(&session, &db_name)? {
+                    invalid_argument_err!("Database {db_name} not found");
+                }
+                self.databases_result(&session, &[db_name])
+            }
+            CatType::GetTable(GetTable {
+                table_name,
+                db_name,
+            }) => {
+                let ident = to_identifier(&table_name, db_name.as_deref())?;
+                let table = table_info(&session, ident)?;
+                self.tables_result(&[table])
+            }
+            CatType::DatabaseExists(DatabaseExists { db_name }) => {
+                self.bool_result(database_exists(&session, &db_name)?)
+            }
+            CatType::TableExists(TableExists {
+                table_name,
+                db_name,
+            }) => {
+                let ident = to_identifier(&table_name, db_name.as_deref())?;
+                self.bool_result(session.has_table(&ident))
+            }
+            CatType::FunctionExists(FunctionExists {
+                function_name,
+                db_name: _,
+            }) => {
+                let exists = CONNECT_FUNCTIONS
+                    .get(&function_name.to_lowercase())
+                    .is_some();
+                self.bool_result(exists)
+            }
+            CatType::DropTempView(DropTempView { view_name }) => {
+                let exists = session.has_temp_table(&view_name);
+                if exists {
+                    session.detach_table(&view_name)?;
+                }
+                self.bool_result(exists)
+            }
+            CatType::DropGlobalTempView(DropGlobalTempView { view_name }) => {
+                self.bool_result(self.session.global_temp.drop_view(&view_name))
+            }
+            // tables are not cached, so these only check that the table exists.
+            CatType::IsCached(c) => {
+                session.get_table(&Identifier::from_sql(&c.table_name, false)?)?;
+                self.bool_result(false)
+            }
+            CatType::CacheTable(c) => {
+                session.get_table(&Identifier::from_sql(&c.table_name, false)?)?;
+                self.empty_result()
+            }
+            CatType::UncacheTable(c) => {
+                session.get_table(&Identifier::from_sql(&c.table_name, false)?)?;
+                self.empty_result()
+            }
+            CatType::RefreshTable(c) => {
+                session.get_table(&Identifier::from_sql(&c.table_name, false)?)?;
+                self.empty_result()
+            }
+            CatType::ClearCache(_) | CatType::RefreshByPath(_) | CatType::RecoverPartitions(_) => {
+                self.empty_result()
+            }
+            CatType::CurrentCatalog(CurrentCatalog {}) => {
+                self.string_result(session.current_catalog_name())
+            }
+            CatType::SetCurrentCatalog(SetCurrentCatalog { catalog_name }) => {
+                session.set_catalog(&catalog_name)?;
+                self.empty_result()
+            }
+            CatType::ListCatalogs(ListCatalogs { pattern }) => {
+                let mut catalogs = session
+                    .list_catalogs(None)?
+                    .into_iter()
+                    .filter(|name| name != GLOBAL_TEMP_DATABASE)
+                    .filter(|name| matches_pattern(name, pattern.as_deref()))
+                    .collect::<Vec<_>>();
+                catalogs.sort();
+                self.create_result(vec![
+                    Utf8Array::from_values("name", catalogs.iter()).into_series(),
+                    Series::full_null("description", &DataType::Utf8, catalogs.len()),
+                ])
+            }
+            CatType::ListFunctions(_) => not_yet_implemented!("listFunctions"),
+            CatType::GetFunction(_) => not_yet_implemented!("getFunction"),
+            CatType::CreateExternalTable(_) => not_yet_implemented!("createExternalTable"),
+            CatType::CreateTable(_) => not_yet_implemented!("createTable"),
+        }
+    }
+
+    /// Lists the tables of the namespace followed by the session's temporary tables, like spark.
+    fn list_tables(
+        &self,
+        session: &Session,
+        namespace: &Namespace,
+    ) -> ConnectResult<Vec<TableInfo>> {
+        let mut tables = if is_global_temp(namespace) {
+            let mut views = self.session.global_temp.list_tables(None)?;
+            views.sort();
+            views
+                .into_iter()
+                .map(|name| TableInfo::temporary(name, namespace.clone()))
+                .collect()
+        } else {
+            let catalog = session.current_catalog_name();
+            let mut idents = catalog_tables(session)?
+                .into_iter()
+                .filter(|ident| &ident.namespace == namespace)
+                .collect::<Vec<_>>();
+            idents.sort_by(|a, b| a.name.cmp(&b.name));
+            idents
+                .into_iter()
+                .map(|ident| TableInfo::external(ident.name, catalog.clone(), ident.namespace))
+                .collect::<Vec<_>>()
+        };
+
+        let mut temp_tables = session.list_tables(None)?;
+        temp_tables.sort();
+        tables.extend(
+            temp_tables
+                .into_iter()
+                .map(|name| TableInfo::temporary(name, Namespace::new())),
+        );
+        Ok(tables)
+    }
+
+    fn tables_result(&self, tables: &[TableInfo]) -> ConnectResult<LogicalPlanBuilder> {
+        let namespaces = tables
+            .iter()
+            .map(|table| table.namespace.clone())
+            .collect::<Vec<_>>();
+        self.create_result(vec![
+            Utf8Array::from_values("name", tables.iter().map(|table| table.name.as_str()))
+                .into_series(),
+            Utf8Array::from_iter(
+                "catalog",
+                tables.iter().map(|table| table.catalog.as_deref()),
+            )
+            .into_series(),
+            string_lists("namespace", &namespaces)?,
+            Series::full_null("description", &DataType::Utf8, tables.len()),
+            Utf8Array::from_values("tableType", tables.iter().map(|table| table.table_type))
+                .into_series(),
+            BooleanArray::from_values("isTemporary", tables.iter().map(|table| table.is_temporary))
+                .into_series(),
+        ])
+    }
+
+    fn databases_result(
+        &self,
+        session: &Session,
+        databases: &[String],
+    ) -> ConnectResult<LogicalPlanBuilder> {
+        let catalog = session.current_catalog_name();
+        self.create_result(vec![
+            Utf8Array::from_values("name", databases.iter()).into_series(),
+            Utf8Array::from_values("catalog", databases.iter().map(|_| catalog.as_str()))
+                .into_series(),
+            Series::full_null("description", &DataType::Utf8, databases.len()),
+            Series::full_null("locationUri", &DataType::Utf8, databases.len()),
+        ])
+    }
+
+    fn columns_result(&self, schema: &Schema) -> ConnectResult<LogicalPlanBuilder> {
+        let fields = schema.fields.values().collect::<Vec<_>>();
+        let types = fields
+            .iter()
+            .map(|field| to_catalog_string(&field.dtype))
+            .collect::<Vec<_>>();
+        self.create_result(vec![
+            Utf8Array::from_values("name", fields.iter().map(|field| field.name.as_str()))
+                .into_series(),
+            Series::full_null("description", &DataType::Utf8, fields.len()),
+            Utf8Array::from_values("dataType", types.iter()).into_series(),
+            BooleanArray::from_values("nullable", fields.iter().map(|_| true)).into_series(),
+            BooleanArray::from_values("isPartition", fields.iter().map(|_| false)).into_series(),
+            BooleanArray::from_values("isBucket", fields.iter().map(|_| false)).into_series(),
+        ])
+    }
+
+    fn string_result(&self, value: String) -> ConnectResult<LogicalPlanBuilder> {
+        self.create_result(vec![Utf8Array::from_values(
+            "value",
+            vec![value].into_iter(),
+        )
+        .into_series()])
+    }
+
+    fn bool_result(&self, value: bool) -> ConnectResult<LogicalPlanBuilder> {
+        self.create_result(vec![BooleanArray::from_values(
+            "value",
+            vec![value].into_iter(),
+        )
+        .into_series()])
+    }
+
+    /// Commands which return nothing, like spark's empty local relation.
+    fn empty_result(&self) -> ConnectResult<LogicalPlanBuilder> {
+        self.create_in_memory_scan(0, Arc::new(Schema::empty()), vec![])
+    }
+
+    fn create_result(&self, columns: Vec<Series>) -> ConnectResult<LogicalPlanBuilder> {
+        let batch = RecordBatch::from_nonempty_columns(columns)?;
+        self.create_in_memory_scan(0, batch.schema.clone(), vec![batch])
+    }
+}
+
+/// Returns the identifiers of the tables in the session's current catalog, if any.
+fn catalog_tables(session: &Session) -> ConnectResult<Vec<Identifier>> {
+    let Ok(catalog) = session.current_catalog() else {
+        return Ok(vec![]);
+    };
+    Ok(catalog
+        .list_tables(None)?
+        .into_iter()
+        .map(|name| Identifier::from_sql(&name, false).unwrap_or_else(|_| Identifier::simple(name)))
+        .collect())
+}
+
+/// Lists the `default` database and the namespaces of the current catalog's tables.
+fn list_databases(session: &Session) -> ConnectResult<Vec<String>> {
+    let mut databases = catalog_tables(session)?
+        .into_iter()
+        .filter(Identifier::has_namespace)
+        .map(|ident| to_database(&ident.namespace))
+        .collect::<Vec<_>>();
+    databases.push(DEFAULT_DATABASE.to_string());
+    databases.sort();
+    databases.dedup();
+    Ok(databases)
+}
+
+fn database_exists(session: &Session, db_name: &str) -> ConnectResult<bool> {
+    if is_global_temp(&to_namespace(db_name)) {
+        return Ok(true);
+    }
+    let database = to_database(&to_namespace(db_name));
+    Ok(list_databases(session)?.contains(&database))
+}
+
+/// Returns the row of an existing table, err if it does not exist.
+fn table_info(session: &Session, ident: Identifier) -> ConnectResult<TableInfo> {
+    session.get_table(&ident)?;
+    if !ident.has_namespace() && session.has_temp_table(&ident.name) {
+        return Ok(TableInfo::temporary(ident.name, Namespace::new()));
+    }
+    if is_global_temp(&ident.namespace) {
+        return Ok(TableInfo::temporary(ident.name, ident.namespace));
+    }
+    let namespace = if ident.has_namespace() {
+        ident.namespace
+    } else {
+        session.current_namespace()
+    };
+    Ok(TableInfo::external(
+        ident.name,
+        session.current_catalog_name(),
+        namespace,
+    ))
+}
+
+/// Returns the identifier of the table, qualified by the database if it's unqualified.
+fn to_identifier(table_name: &str, db_name: Option<&str>) -> ConnectResult<Identifier> {
+    let ident = Identifier::from_sql(table_name, false)?;
+    let namespace = match db_name {
+        Some(db_name) if !ident.has_namespace() => to_namespace(db_name),
+        _ => to_namespace(&ident.namespace.join(".")),
+    };
+    Ok(Identifier::new(namespace, ident.name))
+}
+
+fn to_namespace(database: &str) -> Namespace {
+    if database.is_empty() || database.eq_ignore_ascii_case(DEFAULT_DATABASE) {
+        Namespace::new()
+    } else {
+        database.split('.').map(ToString::to_string).collect()
+    }
+}
+
+fn to_database(namespace: &Namespace) -> String {
+    if namespace.is_empty() {
+        DEFAULT_DATABASE.to_string()
+    } else {
+        namespace.join(".")
+    }
+}
+
+fn is_global_temp(namespace: &Namespace) -> bool {
+    matches!(namespace.as_slice(), [db] if db == GLOBAL_TEMP_DATABASE)
+}
+
+/// Matches the name against a spark pattern, where `*` matches any characters and `|` separates
+/// alternatives, ignoring case.
+fn matches_pattern(name: &str, pattern: Option<&str>) -> bool {
+    fn glob(pattern: &[char], name: &[char]) -> bool {
+        match pattern.split_first() {
+            None => name.is_empty(),
+            Some(('*', rest)) => (0..=name.len()).any(|i| glob(rest, &name[i..])),
+            Some((c, rest)) => name.first() == Some(c) && glob(rest, &name[1..]),
+        }
+    }
+    let Some(pattern) = pattern else {
+        return true;
+    };
+    let name = name.to_lowercase().chars().collect::<Vec<_>>();
+    pattern.split('|').any(|alternative| {
+        let alternative = alternative
+            .trim()
+            .to_lowercase()
+            .chars()
+            .collect::<Vec<_>>();
+        glob(&alternative, &name)
+    })
+}
+
+/// Creates a list of strings column from the lists.
+fn string_lists(name: &str, lists: &[Vec<String>]) -> ConnectResult<Series> {
+    let values = lists.iter().flatten().collect::<Vec<_>>();
+    let child = Utf8Array::from_values("item", values.iter()).into_series();
+    let offsets = Offsets::<i64>::try_from_lengths(lists.iter().map(Vec::len))
+        .wrap_err("Failed to create list offsets")?;
+    let field = Field::new(name, DataType::List(Box::new(DataType::Utf8)));
+    Ok(ListArray::new(field, child, offsets.into(), None).into_series())
+}
+
+/// Returns spark's catalog string of the type, e.g. `bigint` or `array<string>`.
+fn to_catalog_string(dtype: &DataType) -> String {
+    match dtype {
+        DataType::Null => "void".to_string(),
+        DataType::Boolean => "boolean".to_string(),
+        DataType::Int8 | DataType::UInt8 => "tinyint".to_string(),
+        DataType::Int16 | DataType::UInt16 => "smallint".to_string(),
+        DataType::Int32 | DataType::UInt32 => "int".to_string(),
+        DataType::Int64 | DataType::UInt64 => "bigint".to_string(),
+        DataType::Float32 => "float".to_string(),
+        DataType::Float64 => "double".to_string(),
+        DataType::Decimal128(precision, scale) => format!("decimal({precision},{scale})"),
+        DataType::Timestamp(_, None) => "timestamp_ntz".to_string(),
+        DataType::Timestamp(_, Some(_)) => "timestamp".to_string(),
+        DataType::Date => "date".to_string(),
+        DataType::Binary => "binary".to_string(),
+        DataType::Utf8 => "string".to_string(),
+        DataType::List(child) | DataType::FixedSizeList(child, _) => {
+            format!("array<{}>", to_catalog_string(child))
+        }
+        DataType::Map { key, value } => {
+            format!(
+                "map<{},{}>",
+                to_catalog_string(key),
+                to_catalog_string(value)
+            )
+        }
+        DataType::Struct(fields) => {
+            let fields = fields
+                .iter()
+                .map(|field| format!("{}:{}", field.name, to_catalog_string(&field.dtype)))
+                .collect::<Vec<_>>();
+            format!("struct<{}>", fields.join(","))
+        }
+        dtype => dtype.to_string().to_lowercase(),
+    }
+}
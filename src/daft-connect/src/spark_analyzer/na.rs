@@ -0,0 +1,202 @@
+//! Translation of the `DataFrame.na` relations: `fill`, `drop` and `replace`.
+
+use daft_dsl::{lit, unresolved_col, Expr, ExprRef};
+use daft_functions::float::not_nan;
+use daft_logical_plan::LogicalPlanBuilder;
+use daft_schema::dtype::DataType;
+use spark_connect::{na_replace::Replacement, NaDrop, NaFill, NaReplace};
+
+use super::{literal::to_daft_literal, SparkAnalyzer};
+use crate::{error::ConnectResult, invalid_argument_err, util::FromOptionalField};
+
+impl SparkAnalyzer<'_> {
+    pub(super) async fn fill_na(&self, fill_na: NaFill) -> ConnectResult<LogicalPlanBuilder> {
+        let NaFill {
+            input,
+            cols,
+            values,
+        } = fill_na;
+
+        let input = input.required("input")?;
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+        let schema = plan.schema();
+
+        let values: Vec<_> = values.iter().map(to_daft_literal).try_collect()?;
+
+        // a single value fills the given columns, or all columns of a compatible type if none are
+        // given, while multiple values fill the column at the same position
+        let fills: Vec<(String, ExprRef)> = match values.as_slice() {
+            [] => invalid_argument_err!("FillNa requires at least one value"),
+            [value] if cols.is_empty() => schema
+                .fields
+                .iter()
+                .filter(|(_, field)| is_compatible(value, &field.dtype))
+                .map(|(name, _)| (name.clone(), value.clone()))
+                .collect(),
+            [value] => cols
+                .iter()
+                .map(|col| (col.clone(), value.clone()))
+                .collect(),
+            values if values.len() == cols.len() => {
+                cols.into_iter().zip(values.iter().cloned()).collect()
+            }
+            values => invalid_argument_err!(
+                "FillNa requires one value per column; got {} values for columns {cols:?}",
+                values.len()
+            ),
+        };
+
+        if fills.is_empty() {
+            return Ok(plan);
+        }
+
+        let columns = fills
+            .into_iter()
+            .map(|(name, value)| {
+                let dtype = &schema.get_field(&name)?.dtype;
+                Ok(unresolved_col(name.as_str()).fill_null(value.cast(dtype)))
+            })
+            .collect::<ConnectResult<Vec<_>>>()?;
+
+        Ok(plan.with_columns(columns)?)
+    }
+
+    pub(super) async fn drop_na(&self, drop_na: NaDrop) -> ConnectResult<LogicalPlanBuilder> {
+        let NaDrop {
+            input,
+            cols,
+            min_non_nulls,
+        } = drop_na;
+
+        let input = input.required("input")?;
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+        let schema = plan.schema();
+
+        let cols = if cols.is_empty() {
+            schema.names()
+        } else {
+            cols
+        };
+
+        // like spark, NaN values count as missing values as well
+        let present = cols
+            .iter()
+            .map(|name| {
+                let col = unresolved_col(name.as_str());
+                let dtype = &schema.get_field(name)?.dtype;
+
+                Ok(if dtype.is_floating() {
+                    col.clone().not_null().and(not_nan(col))
+                } else {
+                    col.not_null()
+                })
+            })
+            .collect::<ConnectResult<Vec<_>>>()?;
+
+        // without a minimum, a row is only kept if all of the considered columns are present
+        let predicate = match min_non_nulls {
+            None => present.into_iter().reduce(|acc, expr| acc.and(expr)),
+            Some(min_non_nulls) => present
+                .into_iter()
+                .map(|expr| expr.cast(&DataType::Int64))
+                .reduce(|acc, expr| acc.add(expr))
+                .map(|count| count.gt_eq(lit(i64::from(min_non_nulls)))),
+        };
+
+        match predicate {
+            Some(predicate) => Ok(plan.filter(predicate)?),
+            None => Ok(plan),
+        }
+    }
+
+    pub(super) async fn replace(&self, replace: NaReplace) -> ConnectResult<LogicalPlanBuilder> {
+        let NaReplace {
+            input,
+            cols,
+            replacements,
+        } = replace;
+
+        let input = input.required("input")?;
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+        let schema = plan.schema();
+
+        let replacements = replacements
+            .iter()
+            .map(
+                |Replacement {
+                     old_value,
+                     new_value,
+                 }| {
+                    let old_value = to_daft_literal(old_value.as_ref().required("old_value")?)?;
+                    let new_value = to_daft_literal(new_value.as_ref().required("new_value")?)?;
+                    Ok((old_value, new_value))
+                },
+            )
+            .collect::<ConnectResult<Vec<_>>>()?;
+
+        let cols = if cols.is_empty() {
+            schema.names()
+        } else {
+            cols
+        };
+
+        let mut columns = Vec::new();
+        for name in cols {
+            let dtype = &schema.get_field(&name)?.dtype;
+            let col = unresolved_col(name.as_str());
+
+            // only replacements of a type compatible with the column apply to it, with null
+            // values applying to all columns
+            let applicable = replacements
+                .iter()
+                .filter(|(old_value, _)| is_null(old_value) || is_compatible(old_value, dtype))
+                .collect::<Vec<_>>();
+
+            if applicable.is_empty() {
+                continue;
+            }
+
+            let replaced = applicable.into_iter().rev().fold(
+                col.clone(),
+                |otherwise, (old_value, new_value)| {
+                    let matches = if is_null(old_value) {
+                        col.clone().is_null()
+                    } else {
+                        col.clone().eq(old_value.clone())
+                    };
+                    matches.if_else(new_value.clone().cast(dtype), otherwise)
+                },
+            );
+
+            columns.push(replaced.alias(name.as_str()));
+        }
+
+        if columns.is_empty() {
+            return Ok(plan);
+        }
+
+        Ok(plan.with_columns(columns)?)
+    }
+}
+
+fn is_null(value: &ExprRef) -> bool {
+    matches!(value.as_ref(), Expr::Literal(literal) if literal.get_type().is_null())
+}
+
+/// Whether a fill or replacement value applies to a column of the given type.
+///
+/// Spark only supports boolean, numeric and string values, which apply to columns of the same
+/// kind.
+fn is_compatible(value: &ExprRef, dtype: &DataType) -> bool {
+    let Expr::Literal(literal) = value.as_ref() else {
+        return false;
+    };
+    let value_dtype = literal.get_type();
+
+    (value_dtype.is_numeric() && dtype.is_numeric())
+        || (value_dtype.is_string() && dtype.is_string())
+        || (value_dtype.is_boolean() && dtype.is_boolean())
+}
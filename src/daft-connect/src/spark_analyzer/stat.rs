@@ -0,0 +1,424 @@
+//! Translation of the `DataFrame.stat` relations and `DataFrame.describe`/`DataFrame.summary`.
+
+use daft_core::prelude::CountMode;
+use daft_dsl::{lit, unresolved_col, Expr, ExprRef};
+use daft_functions::numeric::sqrt::sqrt;
+use daft_logical_plan::{ops::SummaryStatistic, JoinOptions, LogicalPlanBuilder};
+use daft_schema::dtype::DataType;
+use futures::TryStreamExt;
+use spark_connect::{
+    stat_sample_by::Fraction, StatApproxQuantile, StatCorr, StatCov, StatCrosstab, StatDescribe,
+    StatFreqItems, StatSampleBy, StatSummary,
+};
+use tracing::debug;
+
+use super::{literal::to_daft_literal, SparkAnalyzer};
+use crate::{
+    ensure, error::ConnectResult, invalid_argument_err, not_yet_implemented,
+    util::FromOptionalField,
+};
+
+/// The statistics computed by `DataFrame.summary` when none are given.
+const DEFAULT_SUMMARY_STATISTICS: &[SummaryStatistic] = &[
+    SummaryStatistic::Count,
+    SummaryStatistic::Mean,
+    SummaryStatistic::Stddev,
+    SummaryStatistic::Min,
+    SummaryStatistic::Percentile(25.0),
+    SummaryStatistic::Percentile(50.0),
+    SummaryStatistic::Percentile(75.0),
+    SummaryStatistic::Max,
+];
+
+/// The statistics computed by `DataFrame.describe`.
+const DESCRIBE_STATISTICS: &[SummaryStatistic] = &[
+    SummaryStatistic::Count,
+    SummaryStatistic::Mean,
+    SummaryStatistic::Stddev,
+    SummaryStatistic::Min,
+    SummaryStatistic::Max,
+];
+
+impl SparkAnalyzer<'_> {
+    pub(super) async fn summary(&self, summary: StatSummary) -> ConnectResult<LogicalPlanBuilder> {
+        let StatSummary { input, statistics } = summary;
+
+        let input = input.required("input")?;
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        let statistics = if statistics.is_empty() {
+            DEFAULT_SUMMARY_STATISTICS.to_vec()
+        } else {
+            statistics
+                .iter()
+                .map(|statistic| parse_statistic(statistic))
+                .collect::<ConnectResult<_>>()?
+        };
+
+        let cols = summary_columns(&plan);
+        Ok(plan.summarize_statistics(&cols, &statistics)?)
+    }
+
+    pub(super) async fn describe(
+        &self,
+        describe: StatDescribe,
+    ) -> ConnectResult<LogicalPlanBuilder> {
+        let StatDescribe { input, cols } = describe;
+
+        let input = input.required("input")?;
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        let cols = if cols.is_empty() {
+            summary_columns(&plan)
+        } else {
+            cols
+        };
+
+        Ok(plan.summarize_statistics(&cols, DESCRIBE_STATISTICS)?)
+    }
+
+    pub(super) async fn crosstab(
+        &self,
+        crosstab: StatCrosstab,
+    ) -> ConnectResult<LogicalPlanBuilder> {
+        let StatCrosstab { input, col1, col2 } = crosstab;
+
+        let input = input.required("input")?;
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        // like spark, values are compared by their string representation and nulls become "null"
+        let to_string = |name: &str| {
+            unresolved_col(name)
+                .cast(&DataType::Utf8)
+                .fill_null(lit("null"))
+        };
+        let key = format!("{col1}_{col2}");
+        let plan = plan.select(vec![
+            to_string(&col1).alias(key.as_str()),
+            to_string(&col2).alias("pivot"),
+            lit(1i64).alias("count"),
+        ])?;
+
+        // the distinct values of the second column become the columns of the table, so they
+        // need to be known before the pivot can be planned
+        let names = plan.select(vec![unresolved_col("pivot")])?.distinct()?;
+        let mut names = self.collect_strings(names).await?;
+        names.sort();
+
+        let plan = plan.pivot(
+            vec![unresolved_col(key.as_str())],
+            unresolved_col("pivot"),
+            unresolved_col("count"),
+            unresolved_col("count").sum(),
+            names.clone(),
+        )?;
+
+        // pairs that never occur are counted as zero rather than null
+        let counts = names
+            .iter()
+            .map(|name| unresolved_col(name.as_str()).fill_null(lit(0i64)))
+            .collect();
+
+        Ok(plan.with_columns(counts)?)
+    }
+
+    pub(super) async fn cov(&self, cov: StatCov) -> ConnectResult<LogicalPlanBuilder> {
+        let StatCov { input, col1, col2 } = cov;
+
+        let input = input.required("input")?;
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+        let plan = centered_sums(&plan, &col1, &col2)?;
+
+        // the sample covariance
+        let cov = unresolved_col("sum_xy").div(unresolved_col("n").sub(lit(1.0)));
+
+        Ok(plan.select(vec![cov.alias("cov")])?)
+    }
+
+    pub(super) async fn corr(&self, corr: StatCorr) -> ConnectResult<LogicalPlanBuilder> {
+        let StatCorr {
+            input,
+            col1,
+            col2,
+            method,
+        } = corr;
+
+        let input = input.required("input")?;
+
+        if let Some(method) = method
+            && method != "pearson"
+        {
+            not_yet_implemented!("Correlation method {method}; only pearson is supported");
+        }
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+        let plan = centered_sums(&plan, &col1, &col2)?;
+
+        // the pearson correlation coefficient
+        let corr = unresolved_col("sum_xy")
+            .div(sqrt(unresolved_col("sum_xx").mul(unresolved_col("sum_yy"))));
+
+        Ok(plan.select(vec![corr.alias("corr")])?)
+    }
+
+    pub(super) async fn approx_quantile(
+        &self,
+        approx_quantile: StatApproxQuantile,
+    ) -> ConnectResult<LogicalPlanBuilder> {
+        let StatApproxQuantile {
+            input,
+            cols,
+            probabilities,
+            relative_error,
+        } = approx_quantile;
+
+        let input = input.required("input")?;
+
+        ensure!(
+            !cols.is_empty(),
+            "ApproxQuantile requires at least one column"
+        );
+        ensure!(
+            probabilities.iter().all(|p| (0.0..=1.0).contains(p)),
+            "ApproxQuantile probabilities must be between 0 and 1"
+        );
+        ensure!(
+            (0.0..).contains(&relative_error),
+            "ApproxQuantile relative error must be non-negative"
+        );
+
+        debug!("Ignoring relative error {relative_error}; quantiles are computed with a fixed relative accuracy");
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        // spark returns a single row with the list of quantiles of each column
+        let quantiles = cols
+            .iter()
+            .map(|name| {
+                unresolved_col(name.as_str())
+                    .cast(&DataType::Float64)
+                    .approx_percentiles(&probabilities, true)
+            })
+            .collect();
+
+        Ok(plan.aggregate(vec![list_(quantiles).alias("approx_quantile")], vec![])?)
+    }
+
+    pub(super) async fn freq_items(
+        &self,
+        freq_items: StatFreqItems,
+    ) -> ConnectResult<LogicalPlanBuilder> {
+        let StatFreqItems {
+            input,
+            cols,
+            support,
+        } = freq_items;
+
+        let input = input.required("input")?;
+
+        let support = support.unwrap_or(0.01);
+        ensure!(
+            (1e-4..).contains(&support),
+            "FreqItems support must be at least 1e-4; got {}",
+            support
+        );
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        let Some(first) = cols.first() else {
+            invalid_argument_err!("FreqItems requires at least one column");
+        };
+        let total = plan.aggregate(
+            vec![unresolved_col(first.as_str())
+                .count(CountMode::All)
+                .alias("total")],
+            vec![],
+        )?;
+
+        // the items of each column that occur in at least `support` of the rows, computed exactly
+        let mut result: Option<LogicalPlanBuilder> = None;
+        for name in &cols {
+            let col = unresolved_col(name.as_str());
+            let items = plan
+                .aggregate(
+                    vec![col.clone().count(CountMode::All).alias("count")],
+                    vec![col.clone()],
+                )?
+                .cross_join(total.clone(), JoinOptions::default())?
+                .filter(
+                    unresolved_col("count")
+                        .cast(&DataType::Float64)
+                        .gt_eq(unresolved_col("total").mul(lit(support))),
+                )?
+                .aggregate(
+                    vec![col.agg_list().alias(format!("{name}_freqItems"))],
+                    vec![],
+                )?;
+
+            result = Some(match result {
+                Some(result) => result.cross_join(items, JoinOptions::default())?,
+                None => items,
+            });
+        }
+
+        Ok(result.expect("at least one column"))
+    }
+
+    pub(super) async fn sample_by(
+        &self,
+        sample_by: StatSampleBy,
+    ) -> ConnectResult<LogicalPlanBuilder> {
+        let StatSampleBy {
+            input,
+            col,
+            fractions,
+            seed,
+        } = sample_by;
+
+        let input = input.required("input")?;
+        let col = self.to_daft_expr(&col.required("col")?)?;
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        let seed = seed.map(|seed| seed as u64);
+
+        // each stratum is sampled separately, strata without a fraction are not sampled at all
+        let mut result: Option<LogicalPlanBuilder> = None;
+        for Fraction { stratum, fraction } in fractions {
+            let stratum = to_daft_literal(&stratum.required("stratum")?)?;
+
+            ensure!(
+                (0.0..=1.0).contains(&fraction),
+                "SampleBy fractions must be between 0 and 1; got {}",
+                fraction
+            );
+
+            let sample = plan
+                .filter(col.clone().eq(stratum))?
+                .sample(fraction, false, seed)?;
+
+            result = Some(match result {
+                Some(result) => result.union(&sample, true)?,
+                None => sample,
+            });
+        }
+
+        match result {
+            Some(result) => Ok(result),
+            None => Ok(plan.limit(0, false)?),
+        }
+    }
+
+    /// Runs a plan with a single string column and collects its non-null values.
+    async fn collect_strings(&self, plan: LogicalPlanBuilder) -> ConnectResult<Vec<String>> {
+        let partitions = self
+            .session
+            .run_query(plan)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let mut values = Vec::new();
+        for partition in partitions {
+            for table in partition.get_tables()?.iter() {
+                let column = table.get_column_by_index(0)?;
+                values.extend(
+                    column
+                        .utf8()?
+                        .into_iter()
+                        .flatten()
+                        .map(ToString::to_string),
+                );
+            }
+        }
+
+        Ok(values)
+    }
+}
+
+/// The columns that `DataFrame.summary` and `DataFrame.describe` compute statistics for by
+/// default, which are the numeric and string columns.
+fn summary_columns(plan: &LogicalPlanBuilder) -> Vec<String> {
+    plan.schema()
+        .fields
+        .iter()
+        .filter(|(_, field)| field.dtype.is_numeric() || field.dtype.is_string())
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
+/// Parses a statistic of `DataFrame.summary`, such as `count` or `25%`.
+fn parse_statistic(statistic: &str) -> ConnectResult<SummaryStatistic> {
+    Ok(match statistic {
+        "count" => SummaryStatistic::Count,
+        "count_distinct" => SummaryStatistic::CountDistinct,
+        "approx_count_distinct" => SummaryStatistic::ApproxCountDistinct,
+        "min" => SummaryStatistic::Min,
+        "max" => SummaryStatistic::Max,
+        "mean" => SummaryStatistic::Mean,
+        "stddev" => SummaryStatistic::Stddev,
+        other => {
+            let Some(percentage) = other
+                .strip_suffix('%')
+                .and_then(|p| p.parse::<f64>().ok())
+                .filter(|p| (0.0..=100.0).contains(p))
+            else {
+                invalid_argument_err!("{other} is not a recognised statistic");
+            };
+            SummaryStatistic::Percentile(percentage)
+        }
+    })
+}
+
+/// Computes the sums of the products of the deviations from the mean used for the covariance and
+/// correlation of two columns, over the rows where both columns are present.
+///
+/// The means are computed in a first pass and subtracted before the products are summed, since
+/// subtracting the products of the plain sums loses all precision when the values are large
+/// relative to their variance.
+fn centered_sums(
+    plan: &LogicalPlanBuilder,
+    col1: &str,
+    col2: &str,
+) -> ConnectResult<LogicalPlanBuilder> {
+    let x = unresolved_col(col1).cast(&DataType::Float64);
+    let y = unresolved_col(col2).cast(&DataType::Float64);
+
+    let plan = plan
+        .filter(x.clone().not_null().and(y.clone().not_null()))?
+        .select(vec![x.alias("x"), y.alias("y")])?;
+    let (x, y) = (unresolved_col("x"), unresolved_col("y"));
+
+    let means = plan.aggregate(
+        vec![
+            x.clone()
+                .count(CountMode::Valid)
+                .cast(&DataType::Float64)
+                .alias("n"),
+            x.clone().mean().alias("mean_x"),
+            y.clone().mean().alias("mean_y"),
+        ],
+        vec![],
+    )?;
+
+    let dx = x.sub(unresolved_col("mean_x"));
+    let dy = y.sub(unresolved_col("mean_y"));
+    Ok(plan.cross_join(means, JoinOptions::default())?.aggregate(
+        vec![
+            unresolved_col("n").any_value(false).alias("n"),
+            dx.clone().mul(dy.clone()).sum().alias("sum_xy"),
+            dx.clone().mul(dx).sum().alias("sum_xx"),
+            dy.clone().mul(dy).sum().alias("sum_yy"),
+        ],
+        vec![],
+    )?)
+}
+
+/// Creates a list constructor for the given items.
+fn list_(items: Vec<ExprRef>) -> ExprRef {
+    Expr::List(items).arced()
+}
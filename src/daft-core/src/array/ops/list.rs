@@ -1,7 +1,7 @@
 use std::{iter::repeat, sync::Arc};
 
 use arrow2::offset::{Offsets, OffsetsBuffer};
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 use indexmap::{
     map::{raw_entry_v1::RawEntryMut, RawEntryApiV1},
     IndexMap,
@@ -11,7 +11,7 @@ use super::as_arrow::AsArrow;
 use crate::{
     array::{
         growable::{make_growable, Growable},
-        ops::arrow2::comparison::build_is_equal,
+        ops::{arrow2::comparison::build_is_equal, DaftCompare},
         FixedSizeListArray, ListArray, StructArray,
     },
     count_mode::CountMode,
@@ -441,6 +441,56 @@ impl ListArray {
         Ok(UInt64Array::from((self.name(), array)))
     }
 
+    /// Returns whether each list contains its corresponding item, where a single item is
+    /// broadcast to every list.
+    pub fn contains(&self, item: &Series) -> DaftResult<BooleanArray> {
+        let item = if item.len() == 1 {
+            item.broadcast(self.len())?
+        } else if item.len() == self.len() {
+            item.clone()
+        } else {
+            return Err(DaftError::ValueError(format!(
+                "Expected {} items for list contains, got {}",
+                self.len(),
+                item.len()
+            )));
+        };
+
+        // pair up every list element with its list's item so they can be compared elementwise
+        let mut child_indices = Vec::with_capacity(self.flat_child.len());
+        let mut item_indices = Vec::with_capacity(self.flat_child.len());
+        for (i, range) in self.offsets().ranges().enumerate() {
+            for j in range {
+                child_indices.push(j as u64);
+                item_indices.push(i as u64);
+            }
+        }
+        let children = self
+            .flat_child
+            .take(&UInt64Array::from(("child_indices", child_indices)).into_series())?;
+        let items = item.take(&UInt64Array::from(("item_indices", item_indices)).into_series())?;
+        let matches = children.equal(&items)?;
+
+        let is_valid = |i: usize| {
+            self.validity().map_or(true, |v| v.get_bit(i))
+                && item.validity().map_or(true, |v| v.get_bit(i))
+        };
+        let mut start = 0;
+        let result = self
+            .offsets()
+            .lengths()
+            .enumerate()
+            .map(|(i, len)| {
+                let end = start + len;
+                let contains = (start..end).any(|j| matches.get(j) == Some(true));
+                start = end;
+                is_valid(i).then_some(contains)
+            })
+            .collect::<arrow2::array::BooleanArray>();
+
+        Ok(BooleanArray::from((self.name(), Box::new(result))))
+    }
+
     pub fn explode(&self) -> DaftResult<Series> {
         let offsets = self.offsets();
 
@@ -796,6 +846,10 @@ impl FixedSizeListArray {
         self.to_list().value_counts()
     }
 
+    pub fn contains(&self, item: &Series) -> DaftResult<BooleanArray> {
+        self.to_list().contains(item)
+    }
+
     pub fn count(&self, mode: CountMode) -> DaftResult<UInt64Array> {
         let size = self.fixed_element_len();
         let counts = match (mode, self.flat_child.validity()) {
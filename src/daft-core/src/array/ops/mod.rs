@@ -67,6 +67,7 @@ use std::hash::BuildHasher;
 use common_error::DaftResult;
 pub use hll_sketch::HLL_SKETCH_DTYPE;
 pub use sort::{build_multi_array_bicompare, build_multi_array_compare};
+pub use time::ParsedTimezone;
 pub use utf8::{PadPlacement, Utf8NormalizeOptions};
 
 use crate::count_mode::CountMode;
@@ -112,7 +112,6 @@ where
                 },
                 &others_cmp,
                 arrow_array.len(),
-                first_desc,
                 first_nulls_first,
             )
         } else {
@@ -130,7 +129,6 @@ where
                 },
                 &others_cmp,
                 arrow_array.len(),
-                first_desc,
                 first_nulls_first,
             )
         };
@@ -208,7 +206,6 @@ impl Float32Array {
                 },
                 &others_cmp,
                 arrow_array.len(),
-                first_desc,
                 first_nulls_first,
             )
         } else {
@@ -226,7 +223,6 @@ impl Float32Array {
                 },
                 &others_cmp,
                 arrow_array.len(),
-                first_desc,
                 first_nulls_first,
             )
         };
@@ -304,7 +300,6 @@ impl Float64Array {
                 },
                 &others_cmp,
                 arrow_array.len(),
-                first_desc,
                 first_nulls_first,
             )
         } else {
@@ -322,7 +317,6 @@ impl Float64Array {
                 },
                 &others_cmp,
                 arrow_array.len(),
-                first_desc,
                 first_nulls_first,
             )
         };
@@ -400,7 +394,6 @@ impl Decimal128Array {
                 },
                 &others_cmp,
                 arrow_array.len(),
-                first_desc,
                 first_nulls_first,
             )
         } else {
@@ -418,7 +411,6 @@ impl Decimal128Array {
                 },
                 &others_cmp,
                 arrow_array.len(),
-                first_desc,
                 first_nulls_first,
             )
         };
@@ -464,7 +456,6 @@ impl NullArray {
         I: DaftIntegerType,
         <I as DaftNumericType>::Native: arrow2::types::Index,
     {
-        let first_desc = *descending.first().unwrap();
         let first_nulls_first = *nulls_first.first().unwrap();
 
         let others_cmp = build_multi_array_compare(others, &descending[1..])?;
@@ -478,7 +469,6 @@ impl NullArray {
             },
             &others_cmp,
             self.len(),
-            first_desc,
             first_nulls_first,
         );
 
@@ -544,7 +534,6 @@ impl BooleanArray {
                 },
                 &others_cmp,
                 self.len(),
-                first_desc,
                 first_nulls_first,
             )
         } else {
@@ -562,7 +551,6 @@ impl BooleanArray {
                 },
                 &others_cmp,
                 self.len(),
-                first_desc,
                 first_nulls_first,
             )
         };
@@ -640,7 +628,6 @@ macro_rules! impl_binary_like_sort {
                         },
                         &others_cmp,
                         self.len(),
-                        first_desc,
                         first_nulls_first,
                     )
                 } else {
@@ -658,7 +645,6 @@ macro_rules! impl_binary_like_sort {
                         },
                         &others_cmp,
                         self.len(),
-                        first_desc,
                         first_nulls_first,
                     )
                 };
@@ -820,3 +806,70 @@ impl FixedShapeTensorArray {
         todo!("impl sort for FixedShapeTensorArray")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{DataType, Field, Int64Array, UInt64Array, UInt64Type},
+        series::IntoSeries,
+    };
+
+    fn make_array(name: &str, values: &[Option<i64>]) -> DaftResult<Int64Array> {
+        Int64Array::from_regular_iter(Field::new(name, DataType::Int64), values.iter().copied())
+    }
+
+    fn take_values(array: &Int64Array, indices: &UInt64Array) -> Vec<Option<i64>> {
+        indices
+            .as_arrow()
+            .values_iter()
+            .map(|idx| array.get(*idx as usize))
+            .collect()
+    }
+
+    #[test]
+    fn test_argsort_places_nulls_independently_of_order() -> DaftResult<()> {
+        let array = make_array(
+            "a",
+            &[None, Some(1), None, Some(3), Some(4), Some(2), Some(5)],
+        )?;
+        // the second key is constant, so the multi-key sort must order rows like the first key alone
+        let others = vec![make_array("b", &[Some(0); 7])?.into_series()];
+
+        for (descending, nulls_first, expected) in [
+            (
+                false,
+                false,
+                [Some(1), Some(2), Some(3), Some(4), Some(5), None, None],
+            ),
+            (
+                false,
+                true,
+                [None, None, Some(1), Some(2), Some(3), Some(4), Some(5)],
+            ),
+            (
+                true,
+                false,
+                [Some(5), Some(4), Some(3), Some(2), Some(1), None, None],
+            ),
+            (
+                true,
+                true,
+                [None, None, Some(5), Some(4), Some(3), Some(2), Some(1)],
+            ),
+        ] {
+            let indices = array.argsort::<UInt64Type>(descending, nulls_first)?;
+            assert_eq!(take_values(&array, &indices), expected);
+
+            let indices = array.argsort_multikey::<UInt64Type>(
+                &others,
+                &[descending, false],
+                &[nulls_first, false],
+            )?;
+            assert_eq!(take_values(&array, &indices), expected);
+        }
+        Ok(())
+    }
+}
@@ -7,9 +7,14 @@ use arrow2::{
         ArraySub,
     },
     datatypes::ArrowDataType,
+    temporal_conversions::date32_to_date,
     types::months_days_ns,
 };
-use chrono::{Duration, NaiveDate, NaiveTime, Timelike};
+use chrono::{
+    format::{Item, StrftimeItems},
+    Datelike, Duration, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone,
+    Timelike,
+};
 use common_error::{DaftError, DaftResult};
 
 use super::as_arrow::AsArrow;
@@ -61,6 +66,71 @@ fn process_interval(interval: &str, timeunit: TimeUnit) -> DaftResult<i64> {
     }
 }
 
+/// A timezone, which is either a fixed offset such as "-07:00", or a name from the bundled tz
+/// database such as "America/Los_Angeles".
+pub enum ParsedTimezone {
+    Offset(chrono::FixedOffset),
+    Tz(chrono_tz::Tz),
+}
+
+impl ParsedTimezone {
+    pub fn parse(tz: &str) -> DaftResult<Self> {
+        if let Ok(offset) = arrow2::temporal_conversions::parse_offset(tz) {
+            Ok(Self::Offset(offset))
+        } else if let Ok(tz) = arrow2::temporal_conversions::parse_offset_tz(tz) {
+            Ok(Self::Tz(tz))
+        } else {
+            Err(DaftError::ValueError(format!(
+                "Cannot parse timezone: {tz}"
+            )))
+        }
+    }
+
+    /// Returns the wall clock time in this timezone at the given UTC time.
+    fn to_local(&self, utc: &NaiveDateTime) -> NaiveDateTime {
+        match self {
+            Self::Offset(offset) => offset.from_utc_datetime(utc).naive_local(),
+            Self::Tz(tz) => tz.from_utc_datetime(utc).naive_local(),
+        }
+    }
+
+    /// Returns the UTC time at which the wall clock in this timezone shows `local`.
+    ///
+    /// Wall clock times which are repeated when the clocks go back resolve to their earliest
+    /// occurrence, and wall clock times which are skipped when the clocks go forward are shifted
+    /// forward by the length of the gap.
+    fn to_utc(&self, local: &NaiveDateTime) -> NaiveDateTime {
+        fn resolve<T: TimeZone>(tz: &T, local: &NaiveDateTime) -> NaiveDateTime {
+            match tz.from_local_datetime(local) {
+                LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => dt.naive_utc(),
+                // use the offset from before the gap
+                LocalResult::None => {
+                    *local
+                        - tz.offset_from_utc_datetime(&(*local - Duration::days(1)))
+                            .fix()
+                }
+            }
+        }
+
+        match self {
+            Self::Offset(offset) => resolve(offset, local),
+            Self::Tz(tz) => resolve(tz, local),
+        }
+    }
+}
+
+fn naive_datetime_to_timestamp(dt: &NaiveDateTime, tu: TimeUnit) -> DaftResult<i64> {
+    let dt = dt.and_utc();
+    match tu {
+        TimeUnit::Seconds => Ok(dt.timestamp()),
+        TimeUnit::Milliseconds => Ok(dt.timestamp_millis()),
+        TimeUnit::Microseconds => Ok(dt.timestamp_micros()),
+        TimeUnit::Nanoseconds => dt.timestamp_nanos_opt().ok_or_else(|| {
+            DaftError::ValueError(format!("Timestamp {dt} is out of range for nanoseconds"))
+        }),
+    }
+}
+
 impl DateArray {
     pub fn day(&self) -> DaftResult<UInt32Array> {
         let input_array = self
@@ -101,6 +171,56 @@ impl DateArray {
         let day_arr = arrow2::compute::temporal::weekday(&input_array)?;
         Ok((self.name(), Box::new(day_arr.sub(&1))).into())
     }
+
+    pub fn day_of_year(&self) -> DaftResult<UInt32Array> {
+        let day_arr = self
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|days| days.map(|days| date32_to_date(*days).ordinal()))
+            .collect::<PrimitiveArray<u32>>();
+        Ok((self.name(), Box::new(day_arr)).into())
+    }
+
+    /// Returns the ISO 8601 week number of each date, where weeks start on Monday and the first
+    /// week of a year is the one containing its first Thursday.
+    pub fn week_of_year(&self) -> DaftResult<UInt32Array> {
+        let week_arr = self
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|days| days.map(|days| date32_to_date(*days).iso_week().week()))
+            .collect::<PrimitiveArray<u32>>();
+        Ok((self.name(), Box::new(week_arr)).into())
+    }
+
+    /// Formats each date with a chrono `strftime` format string.
+    pub fn strftime(&self, format: &str) -> DaftResult<Utf8Array> {
+        let items = parse_strftime_format(format)?;
+        let formatted = self
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|days| {
+                days.map(|days| {
+                    date32_to_date(*days)
+                        .format_with_items(items.iter())
+                        .to_string()
+                })
+            })
+            .collect::<arrow2::array::Utf8Array<i64>>();
+        Ok(Utf8Array::from((self.name(), Box::new(formatted))))
+    }
+}
+
+fn parse_strftime_format(format: &str) -> DaftResult<Vec<Item<'_>>> {
+    let items = StrftimeItems::new(format).collect::<Vec<_>>();
+    if items.contains(&Item::Error) {
+        return Err(DaftError::ValueError(format!(
+            "Invalid strftime format string: {format}"
+        )));
+    }
+    Ok(items)
 }
 
 impl TimestampArray {
@@ -368,6 +488,93 @@ impl TimestampArray {
         ))
     }
 
+    /// Converts the timestamps to the timezone `tz`, keeping the instants they represent.
+    ///
+    /// Timestamps without a timezone are taken to be in UTC.
+    /// Formats each timestamp with a chrono `strftime` format string, using the wall clock time
+    /// in the timezone of the timestamp if it has one.
+    pub fn strftime(&self, format: &str) -> DaftResult<Utf8Array> {
+        let DataType::Timestamp(timeunit, tz) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        let tu = timeunit.to_arrow();
+        let tz = tz.as_deref().map(ParsedTimezone::parse).transpose()?;
+        let items = parse_strftime_format(format)?;
+
+        let formatted = self
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|ts| {
+                ts.map(|ts| {
+                    let utc = arrow2::temporal_conversions::timestamp_to_naive_datetime(*ts, tu);
+                    let local = match &tz {
+                        Some(tz) => tz.to_local(&utc),
+                        None => utc,
+                    };
+                    local.format_with_items(items.iter()).to_string()
+                })
+            })
+            .collect::<arrow2::array::Utf8Array<i64>>();
+        Ok(Utf8Array::from((self.name(), Box::new(formatted))))
+    }
+
+    pub fn convert_timezone(&self, tz: &str) -> DaftResult<Self> {
+        let DataType::Timestamp(timeunit, _) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        ParsedTimezone::parse(tz)?;
+
+        // timestamps are stored as offsets from the unix epoch in UTC, so only the type changes
+        Ok(Self::new(
+            Field::new(
+                self.name(),
+                DataType::Timestamp(*timeunit, Some(tz.to_string())),
+            ),
+            self.physical.clone(),
+        ))
+    }
+
+    /// Replaces the timezone of the timestamps with `tz`, keeping their wall clock times.
+    ///
+    /// If `tz` is `None`, the result has no timezone.
+    pub fn replace_timezone(&self, tz: Option<&str>) -> DaftResult<Self> {
+        let physical = self.physical.as_arrow();
+        let DataType::Timestamp(timeunit, from_tz) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        let tu = timeunit.to_arrow();
+        let from_tz = from_tz.as_deref().map(ParsedTimezone::parse).transpose()?;
+        let to_tz = tz.map(ParsedTimezone::parse).transpose()?;
+
+        let result_timestamps = physical
+            .iter()
+            .map(|ts| {
+                ts.map(|ts| {
+                    let utc = arrow2::temporal_conversions::timestamp_to_naive_datetime(*ts, tu);
+                    let local = match &from_tz {
+                        Some(from_tz) => from_tz.to_local(&utc),
+                        None => utc,
+                    };
+                    let utc = match &to_tz {
+                        Some(to_tz) => to_tz.to_utc(&local),
+                        None => local,
+                    };
+                    naive_datetime_to_timestamp(&utc, *timeunit)
+                })
+                .transpose()
+            })
+            .collect::<DaftResult<arrow2::array::PrimitiveArray<i64>>>()?;
+
+        Ok(Self::new(
+            Field::new(
+                self.name(),
+                DataType::Timestamp(*timeunit, tz.map(str::to_string)),
+            ),
+            Int64Array::from((self.name(), Box::new(result_timestamps))),
+        ))
+    }
+
     pub fn add_interval(&self, interval: &IntervalArray) -> DaftResult<Self> {
         self.interval_helper(interval, add_interval)
     }
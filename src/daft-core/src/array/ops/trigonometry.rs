@@ -22,6 +22,9 @@ pub enum TrigonometricFunction {
     ArcTanh,
     ArcCosh,
     ArcSinh,
+    Sinh,
+    Cosh,
+    Tanh,
 }
 
 impl TrigonometricFunction {
@@ -39,6 +42,9 @@ impl TrigonometricFunction {
             Self::ArcTanh => "arctanh",
             Self::ArcCosh => "arccosh",
             Self::ArcSinh => "arcsinh",
+            Self::Sinh => "sinh",
+            Self::Cosh => "cosh",
+            Self::Tanh => "tanh",
         }
     }
 }
@@ -62,6 +68,9 @@ where
             TrigonometricFunction::ArcTanh => self.apply(|v| v.atanh()),
             TrigonometricFunction::ArcCosh => self.apply(|v| v.acosh()),
             TrigonometricFunction::ArcSinh => self.apply(|v| v.asinh()),
+            TrigonometricFunction::Sinh => self.apply(|v| v.sinh()),
+            TrigonometricFunction::Cosh => self.apply(|v| v.cosh()),
+            TrigonometricFunction::Tanh => self.apply(|v| v.tanh()),
         }
     }
 }
@@ -1,5 +1,7 @@
 use std::{
     borrow::Cow,
+    collections::HashMap,
+    fmt::Write,
     iter::{self, Repeat, Take},
     sync::Arc,
 };
@@ -626,6 +628,45 @@ impl Utf8Array {
         self.unary_broadcasted_op(|val| val.trim_end().into())
     }
 
+    /// Strips any of the characters in `chars` from the start and/or end of each string.
+    pub fn strip_chars(&self, chars: &Self, leading: bool, trailing: bool) -> DaftResult<Self> {
+        let (is_full_null, expected_size) = parse_inputs(self, &[chars])
+            .map_err(|e| DaftError::ValueError(format!("Error in strip_chars: {e}")))?;
+        if is_full_null {
+            return Ok(Self::full_null(self.name(), &DataType::Utf8, expected_size));
+        }
+        if expected_size == 0 {
+            return Ok(Self::empty(self.name(), &DataType::Utf8));
+        }
+
+        let self_iter = create_broadcasted_str_iter(self, expected_size);
+        let chars_iter = create_broadcasted_str_iter(chars, expected_size);
+        let arrow_result = self_iter
+            .zip(chars_iter)
+            .map(|(val, chars)| match (val, chars) {
+                (Some(val), Some(chars)) => {
+                    let is_stripped = |c: char| chars.contains(c);
+                    let val = if leading {
+                        val.trim_start_matches(is_stripped)
+                    } else {
+                        val
+                    };
+                    let val = if trailing {
+                        val.trim_end_matches(is_stripped)
+                    } else {
+                        val
+                    };
+                    Some(val)
+                }
+                _ => None,
+            })
+            .collect::<arrow2::array::Utf8Array<i64>>();
+
+        let result = Self::from((self.name(), Box::new(arrow_result)));
+        assert_eq!(result.len(), expected_size);
+        Ok(result)
+    }
+
     pub fn reverse(&self) -> DaftResult<Self> {
         self.unary_broadcasted_op(|val| val.chars().rev().collect::<String>().into())
     }
@@ -646,6 +687,144 @@ impl Utf8Array {
         })
     }
 
+    /// Uppercases the first letter of each space separated word and lowercases all other letters.
+    pub fn initcap(&self) -> DaftResult<Self> {
+        self.unary_broadcasted_op(|val| {
+            let mut res = String::with_capacity(val.len());
+            let mut is_word_start = true;
+            for c in val.chars() {
+                if is_word_start {
+                    res.extend(c.to_uppercase());
+                } else {
+                    res.extend(c.to_lowercase());
+                }
+                is_word_start = c == ' ';
+            }
+            res.into()
+        })
+    }
+
+    /// Returns the American Soundex code of each string, or the string itself if it does not start
+    /// with a letter.
+    pub fn soundex(&self) -> DaftResult<Self> {
+        self.unary_broadcasted_op(|val| match soundex(val) {
+            Some(code) => code.into(),
+            None => val.into(),
+        })
+    }
+
+    /// Returns the code point of the first character of each string, or 0 for empty strings.
+    pub fn ascii(&self) -> DaftResult<Int32Array> {
+        let self_arrow = self.as_arrow();
+        let arrow_result = self_arrow
+            .iter()
+            .map(|val| Some(val?.chars().next().map_or(0, |c| c as i32)))
+            .collect::<arrow2::array::Int32Array>();
+        Ok(Int32Array::from((self.name(), Box::new(arrow_result))))
+    }
+
+    /// Encodes each string in the `application/x-www-form-urlencoded` format, as Java's
+    /// `URLEncoder` does.
+    pub fn url_encode(&self) -> DaftResult<Self> {
+        self.unary_broadcasted_op(|val| {
+            let mut encoded = String::with_capacity(val.len());
+            for byte in val.bytes() {
+                match byte {
+                    b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'.' | b'-' | b'*' | b'_' => {
+                        encoded.push(byte as char);
+                    }
+                    b' ' => encoded.push('+'),
+                    _ => write!(encoded, "%{byte:02X}").unwrap(),
+                }
+            }
+            encoded.into()
+        })
+    }
+
+    /// Decodes `application/x-www-form-urlencoded` strings, returning null for invalid strings.
+    pub fn url_decode(&self) -> DaftResult<Self> {
+        let self_arrow = self.as_arrow();
+        let arrow_result = self_arrow
+            .iter()
+            .map(|val| url_decode(val?))
+            .collect::<arrow2::array::Utf8Array<i64>>();
+        Ok(Self::from((self.name(), Box::new(arrow_result))))
+    }
+
+    pub fn levenshtein(&self, other: &Self) -> DaftResult<UInt64Array> {
+        let (is_full_null, expected_size) = parse_inputs(self, &[other])
+            .map_err(|e| DaftError::ValueError(format!("Error in levenshtein: {e}")))?;
+        if is_full_null {
+            return Ok(UInt64Array::full_null(
+                self.name(),
+                &DataType::UInt64,
+                expected_size,
+            ));
+        }
+        if expected_size == 0 {
+            return Ok(UInt64Array::empty(self.name(), &DataType::UInt64));
+        }
+
+        let self_iter = create_broadcasted_str_iter(self, expected_size);
+        let other_iter = create_broadcasted_str_iter(other, expected_size);
+        let arrow_result = self_iter
+            .zip(other_iter)
+            .map(|(val, other)| match (val, other) {
+                (Some(val), Some(other)) => Some(levenshtein_distance(val, other) as u64),
+                _ => None,
+            })
+            .collect::<arrow2::array::UInt64Array>();
+
+        let result = UInt64Array::from((self.name(), Box::new(arrow_result)));
+        assert_eq!(result.len(), expected_size);
+        Ok(result)
+    }
+
+    /// Replaces each character in `matching` with the character at the same position in `replace`.
+    ///
+    /// Characters in `matching` without a counterpart in `replace` are removed.
+    pub fn translate(&self, matching: &Self, replace: &Self) -> DaftResult<Self> {
+        let (is_full_null, expected_size) = parse_inputs(self, &[matching, replace])
+            .map_err(|e| DaftError::ValueError(format!("Error in translate: {e}")))?;
+        if is_full_null {
+            return Ok(Self::full_null(self.name(), &DataType::Utf8, expected_size));
+        }
+        if expected_size == 0 {
+            return Ok(Self::empty(self.name(), &DataType::Utf8));
+        }
+
+        let self_iter = create_broadcasted_str_iter(self, expected_size);
+        let matching_iter = create_broadcasted_str_iter(matching, expected_size);
+        let replace_iter = create_broadcasted_str_iter(replace, expected_size);
+        let arrow_result = self_iter
+            .zip(matching_iter)
+            .zip(replace_iter)
+            .map(
+                |((val, matching), replace)| match (val, matching, replace) {
+                    (Some(val), Some(matching), Some(replace)) => {
+                        // only the first occurrence of a character in `matching` counts
+                        let mut replacements = HashMap::new();
+                        let mut replace = replace.chars();
+                        for c in matching.chars() {
+                            let replacement = replace.next();
+                            replacements.entry(c).or_insert(replacement);
+                        }
+                        Some(
+                            val.chars()
+                                .filter_map(|c| replacements.get(&c).copied().unwrap_or(Some(c)))
+                                .collect::<String>(),
+                        )
+                    }
+                    _ => None,
+                },
+            )
+            .collect::<arrow2::array::Utf8Array<i64>>();
+
+        let result = Self::from((self.name(), Box::new(arrow_result)));
+        assert_eq!(result.len(), expected_size);
+        Ok(result)
+    }
+
     pub fn find(&self, substr: &Self) -> DaftResult<Int64Array> {
         let (is_full_null, expected_size) = parse_inputs(self, &[substr])
             .map_err(|e| DaftError::ValueError(format!("Error in find: {e}")))?;
@@ -677,6 +856,39 @@ impl Utf8Array {
         Ok(result)
     }
 
+    /// Returns the one-based character position of the first occurrence of `substr`, or 0 if it does not occur.
+    pub fn position(&self, substr: &Self) -> DaftResult<Int64Array> {
+        let (is_full_null, expected_size) = parse_inputs(self, &[substr])
+            .map_err(|e| DaftError::ValueError(format!("Error in position: {e}")))?;
+        if is_full_null {
+            return Ok(Int64Array::full_null(
+                self.name(),
+                &DataType::Int64,
+                expected_size,
+            ));
+        }
+        if expected_size == 0 {
+            return Ok(Int64Array::empty(self.name(), &DataType::Int64));
+        }
+
+        let self_iter = create_broadcasted_str_iter(self, expected_size);
+        let substr_iter = create_broadcasted_str_iter(substr, expected_size);
+        let arrow_result = self_iter
+            .zip(substr_iter)
+            .map(|(val, substr)| match (val, substr) {
+                (Some(val), Some(substr)) => Some(
+                    val.find(substr)
+                        .map_or(0, |pos| val[..pos].chars().count() as i64 + 1),
+                ),
+                _ => None,
+            })
+            .collect::<arrow2::array::Int64Array>();
+
+        let result = Int64Array::from((self.name(), Box::new(arrow_result)));
+        assert_eq!(result.len(), expected_size);
+        Ok(result)
+    }
+
     pub fn like(&self, pattern: &Self) -> DaftResult<BooleanArray> {
         let (is_full_null, expected_size) = parse_inputs(self, &[pattern])
             .map_err(|e| DaftError::ValueError(format!("Error in like: {e}")))?;
@@ -889,21 +1101,29 @@ impl Utf8Array {
     }
 
     pub fn to_date(&self, format: &str) -> DaftResult<DateArray> {
+        self.parse_date(format, true)
+    }
+
+    /// Like [`Self::to_date`], but returns null for strings which don't match `format`.
+    pub fn try_to_date(&self, format: &str) -> DaftResult<DateArray> {
+        self.parse_date(format, false)
+    }
+
+    fn parse_date(&self, format: &str, raise_error_on_failure: bool) -> DaftResult<DateArray> {
         let len = self.len();
         let self_iter = self.as_arrow().iter();
 
         let arrow_result = self_iter
             .map(|val| match val {
-                Some(val) => {
-                    let date = chrono::NaiveDate::parse_from_str(val, format).map_err(|e| {
-                        DaftError::ComputeError(format!(
-                            "Error in to_date: failed to parse date {val} with format {format} : {e}"
-                        ))
-                    })?;
-                    Ok(Some(
+                Some(val) => match chrono::NaiveDate::parse_from_str(val, format) {
+                    Ok(date) => Ok(Some(
                         date.num_days_from_ce() - temporal_conversions::EPOCH_DAYS_FROM_CE,
-                    ))
-                }
+                    )),
+                    Err(_) if !raise_error_on_failure => Ok(None),
+                    Err(e) => Err(DaftError::ComputeError(format!(
+                        "Error in to_date: failed to parse date {val} with format {format} : {e}"
+                    ))),
+                },
                 _ => Ok(None),
             })
             .collect::<DaftResult<arrow2::array::Int32Array>>()?;
@@ -915,49 +1135,69 @@ impl Utf8Array {
     }
 
     pub fn to_datetime(&self, format: &str, timezone: Option<&str>) -> DaftResult<TimestampArray> {
+        self.parse_datetime(format, timezone, true)
+    }
+
+    /// Like [`Self::to_datetime`], but returns null for strings which don't match `format`.
+    pub fn try_to_datetime(
+        &self,
+        format: &str,
+        timezone: Option<&str>,
+    ) -> DaftResult<TimestampArray> {
+        self.parse_datetime(format, timezone, false)
+    }
+
+    fn parse_datetime(
+        &self,
+        format: &str,
+        timezone: Option<&str>,
+        raise_error_on_failure: bool,
+    ) -> DaftResult<TimestampArray> {
         let len = self.len();
         let self_iter = self.as_arrow().iter();
         let timeunit = daft_schema::time_unit::infer_timeunit_from_format_string(format);
+        let tz = timezone
+            .map(|tz| {
+                tz.parse::<chrono_tz::Tz>().map_err(|e| {
+                    DaftError::ComputeError(format!(
+                        "Error in to_datetime: failed to parse timezone {tz} : {e}"
+                    ))
+                })
+            })
+            .transpose()?;
+
+        let parse = |val: &str| -> DaftResult<i64> {
+            let parse_error = |e: chrono::ParseError| {
+                DaftError::ComputeError(format!(
+                    "Error in to_datetime: failed to parse datetime {val} with format {format} : {e}"
+                ))
+            };
+            let datetime = match tz {
+                Some(tz) => chrono::DateTime::parse_from_str(val, format)
+                    .map_err(parse_error)?
+                    .with_timezone(&tz)
+                    .naive_utc(),
+                None => chrono::NaiveDateTime::parse_from_str(val, format).map_err(parse_error)?,
+            }
+            .and_utc();
+            Ok(match timeunit {
+                TimeUnit::Seconds => datetime.timestamp(),
+                TimeUnit::Milliseconds => datetime.timestamp_millis(),
+                TimeUnit::Microseconds => datetime.timestamp_micros(),
+                TimeUnit::Nanoseconds => datetime.timestamp_nanos_opt().ok_or_else(|| {
+                    DaftError::ComputeError(format!(
+                        "Error in to_datetime: failed to get nanoseconds for {val}"
+                    ))
+                })?,
+            })
+        };
 
         let arrow_result = self_iter
-            .map(|val| match val {
-                Some(val) => {
-                    let timestamp = match timezone {
-                        Some(tz) => {
-                            let datetime = chrono::DateTime::parse_from_str(val, format).map_err(|e| {
-                                DaftError::ComputeError(format!(
-                                    "Error in to_datetime: failed to parse datetime {val} with format {format} : {e}"
-                                ))
-                            })?;
-                            let datetime_with_timezone = datetime.with_timezone(&tz.parse::<chrono_tz::Tz>().map_err(|e| {
-                                DaftError::ComputeError(format!(
-                                    "Error in to_datetime: failed to parse timezone {tz} : {e}"
-                                ))
-                            })?);
-                            match timeunit {
-                                TimeUnit::Seconds => datetime_with_timezone.timestamp(),
-                                TimeUnit::Milliseconds => datetime_with_timezone.timestamp_millis(),
-                                TimeUnit::Microseconds => datetime_with_timezone.timestamp_micros(),
-                                TimeUnit::Nanoseconds => datetime_with_timezone.timestamp_nanos_opt().ok_or_else(|| DaftError::ComputeError(format!("Error in to_datetime: failed to get nanoseconds for {val}")))?,
-                            }
-                        }
-                        None => {
-                            let naive_datetime = chrono::NaiveDateTime::parse_from_str(val, format).map_err(|e| {
-                                DaftError::ComputeError(format!(
-                                    "Error in to_datetime: failed to parse datetime {val} with format {format} : {e}"
-                                ))
-                            })?;
-                            match timeunit {
-                                TimeUnit::Seconds => naive_datetime.and_utc().timestamp(),
-                                TimeUnit::Milliseconds => naive_datetime.and_utc().timestamp_millis(),
-                                TimeUnit::Microseconds => naive_datetime.and_utc().timestamp_micros(),
-                                TimeUnit::Nanoseconds => naive_datetime.and_utc().timestamp_nanos_opt().ok_or_else(|| DaftError::ComputeError(format!("Error in to_datetime: failed to get nanoseconds for {val}")))?,
-                            }
-                        }
-                    };
-                    Ok(Some(timestamp))
-                }
-                _ => Ok(None),
+            .map(|val| match val.map(parse) {
+                Some(Ok(timestamp)) => Ok(Some(timestamp)),
+                Some(Err(_)) if !raise_error_on_failure => Ok(None),
+                Some(Err(e)) => Err(e),
+                None => Ok(None),
             })
             .collect::<DaftResult<arrow2::array::Int64Array>>()?;
 
@@ -1424,6 +1664,78 @@ impl Utf8Array {
     }
 }
 
+fn url_decode(val: &str) -> Option<String> {
+    let bytes = val.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                let hex = bytes.get(i + 1..i + 3)?;
+                if !hex.iter().all(u8::is_ascii_hexdigit) {
+                    return None;
+                }
+                decoded.push(u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok()?);
+                i += 2;
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8(decoded).ok()
+}
+
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    // the distances between the prefix of `a` seen so far and each prefix of `b`
+    let mut distances = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut substitution = distances[0];
+        distances[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let deletion = distances[j + 1];
+            distances[j + 1] = if a_char == *b_char {
+                substitution
+            } else {
+                1 + substitution.min(distances[j]).min(deletion)
+            };
+            substitution = deletion;
+        }
+    }
+    distances[b.len()]
+}
+
+fn soundex(val: &str) -> Option<String> {
+    // the codes of the letters A to Z, where vowels are 0 and H and W are 7
+    const CODES: &[u8; 26] = b"01230127022455012623017202";
+
+    let mut chars = val.chars().map(|c| c.to_ascii_uppercase());
+    let first = chars.next().filter(char::is_ascii_uppercase)?;
+
+    let mut code = String::with_capacity(4);
+    code.push(first);
+    let mut last = CODES[(first as u8 - b'A') as usize];
+    for c in chars.filter(char::is_ascii_uppercase) {
+        let current = CODES[(c as u8 - b'A') as usize];
+        // H and W do not separate letters with the same code
+        if current == b'7' {
+            continue;
+        }
+        if current != b'0' && current != last {
+            code.push(current as char);
+            if code.len() == 4 {
+                break;
+            }
+        }
+        last = current;
+    }
+    while code.len() < 4 {
+        code.push('0');
+    }
+    Some(code)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1475,4 +1787,57 @@ mod tests {
         assert!(result.as_arrow().value(2));
         Ok(())
     }
+
+    #[test]
+    fn check_strip_chars_utf_arrays_broadcast() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                "xxfooyx".into(),
+                None,
+                "xy".into(),
+            ])),
+        ));
+        let chars = Utf8Array::from((
+            "chars",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec!["xy".into()])),
+        ));
+        let both = data.strip_chars(&chars, true, true)?;
+        assert_eq!(both.get(0), Some("foo"));
+        assert_eq!(both.get(1), None);
+        assert_eq!(both.get(2), Some(""));
+        let leading = data.strip_chars(&chars, true, false)?;
+        assert_eq!(leading.get(0), Some("fooyx"));
+        let trailing = data.strip_chars(&chars, false, true)?;
+        assert_eq!(trailing.get(0), Some("xxfoo"));
+        Ok(())
+    }
+
+    #[test]
+    fn check_position_counts_characters() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                "éb".into(),
+                "日本語".into(),
+                "abc".into(),
+                None,
+            ])),
+        ));
+        let substr = Utf8Array::from((
+            "substr",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                "b".into(),
+                "語".into(),
+                "z".into(),
+                "a".into(),
+            ])),
+        ));
+        let result = data.position(&substr)?;
+        assert_eq!(result.get(0), Some(2));
+        assert_eq!(result.get(1), Some(3));
+        assert_eq!(result.get(2), Some(0));
+        assert_eq!(result.get(3), None);
+        Ok(())
+    }
 }
@@ -128,6 +128,64 @@ impl FromStr for JoinStrategy {
     }
 }
 
+/// Which right-side row an ASOF join matches to each left-side row, among the rows with equal `by` keys.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", eq, eq_int))]
+pub enum AsofJoinDirection {
+    /// The last row whose `on` key is less than or equal to the left `on` key.
+    Backward,
+    /// The first row whose `on` key is greater than or equal to the left `on` key.
+    Forward,
+    /// The row whose `on` key is closest to the left `on` key, preferring backward matches on ties.
+    Nearest,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl AsofJoinDirection {
+    /// Create an AsofJoinDirection from its string representation.
+    ///
+    /// Args:
+    ///     direction: String representation of the direction, i.e. "backward", "forward", or "nearest".
+    #[staticmethod]
+    pub fn from_asof_join_direction_str(direction: &str) -> PyResult<Self> {
+        Self::from_str(direction).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    pub fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+}
+impl_bincode_py_state_serialization!(AsofJoinDirection);
+
+impl AsofJoinDirection {
+    pub fn iterator() -> std::slice::Iter<'static, Self> {
+        static DIRECTIONS: [AsofJoinDirection; 3] = [
+            AsofJoinDirection::Backward,
+            AsofJoinDirection::Forward,
+            AsofJoinDirection::Nearest,
+        ];
+        DIRECTIONS.iter()
+    }
+}
+
+impl FromStr for AsofJoinDirection {
+    type Err = DaftError;
+
+    fn from_str(direction: &str) -> DaftResult<Self> {
+        match direction {
+            "backward" => Ok(Self::Backward),
+            "forward" => Ok(Self::Forward),
+            "nearest" => Ok(Self::Nearest),
+            _ => Err(DaftError::TypeError(format!(
+                "ASOF join direction {} is not supported; only the following directions are supported: {:?}",
+                direction,
+                Self::iterator().as_slice()
+            ))),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "python", pyclass(module = "daft.daft", eq, eq_int))]
 pub enum JoinSide {
@@ -54,6 +54,19 @@ impl Series {
         }
     }
 
+    pub fn list_contains(&self, item: &Self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::List(_) => Ok(self.list()?.contains(item)?.into_series()),
+            DataType::FixedSizeList(..) => {
+                Ok(self.fixed_size_list()?.contains(item)?.into_series())
+            }
+            dt => Err(DaftError::TypeError(format!(
+                "list_contains not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
     pub fn explode(&self) -> DaftResult<Self> {
         match self.data_type() {
             DataType::List(_) => self.list()?.explode(),
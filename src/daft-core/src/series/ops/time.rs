@@ -169,6 +169,57 @@ impl Series {
         }
     }
 
+    pub fn dt_day_of_year(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => {
+                let downcasted = self.date()?;
+                Ok(downcasted.day_of_year()?.into_series())
+            }
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.date()?.day_of_year()?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run dt_day_of_year() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_week_of_year(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => {
+                let downcasted = self.date()?;
+                Ok(downcasted.week_of_year()?.into_series())
+            }
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.date()?.week_of_year()?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run dt_week_of_year() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_strftime(&self, format: &str) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => {
+                let downcasted = self.date()?;
+                Ok(downcasted.strftime(format)?.into_series())
+            }
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.strftime(format)?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run strftime() operation on date and timestamp types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
     pub fn dt_truncate(&self, interval: &str, relative_to: &Self) -> DaftResult<Self> {
         match (self.data_type(), relative_to.data_type()) {
             (DataType::Timestamp(self_tu,self_tz), DataType::Timestamp(start_tu,start_tz)) if self_tu == start_tu && self_tz == start_tz => {
@@ -200,4 +251,30 @@ impl Series {
             ))),
         }
     }
+
+    pub fn dt_convert_timezone(&self, tz: &str) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.convert_timezone(tz)?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run convert_timezone() operation on timestamp types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_replace_timezone(&self, tz: Option<&str>) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.replace_timezone(tz)?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run replace_timezone() operation on timestamp types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
 }
@@ -104,6 +104,19 @@ impl Series {
         self.with_utf8_array(|arr| Ok(arr.rstrip()?.into_series()))
     }
 
+    pub fn utf8_strip_chars(
+        &self,
+        chars: &Self,
+        leading: bool,
+        trailing: bool,
+    ) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| {
+            chars.with_utf8_array(|chars_arr| {
+                Ok(arr.strip_chars(chars_arr, leading, trailing)?.into_series())
+            })
+        })
+    }
+
     pub fn utf8_reverse(&self) -> DaftResult<Self> {
         self.with_utf8_array(|arr| Ok(arr.reverse()?.into_series()))
     }
@@ -112,6 +125,42 @@ impl Series {
         self.with_utf8_array(|arr| Ok(arr.capitalize()?.into_series()))
     }
 
+    pub fn utf8_initcap(&self) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.initcap()?.into_series()))
+    }
+
+    pub fn utf8_soundex(&self) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.soundex()?.into_series()))
+    }
+
+    pub fn utf8_ascii(&self) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.ascii()?.into_series()))
+    }
+
+    pub fn utf8_url_encode(&self) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.url_encode()?.into_series()))
+    }
+
+    pub fn utf8_url_decode(&self) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.url_decode()?.into_series()))
+    }
+
+    pub fn utf8_levenshtein(&self, other: &Self) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| {
+            other.with_utf8_array(|other_arr| Ok(arr.levenshtein(other_arr)?.into_series()))
+        })
+    }
+
+    pub fn utf8_translate(&self, matching: &Self, replace: &Self) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| {
+            matching.with_utf8_array(|matching_arr| {
+                replace.with_utf8_array(|replace_arr| {
+                    Ok(arr.translate(matching_arr, replace_arr)?.into_series())
+                })
+            })
+        })
+    }
+
     pub fn utf8_left(&self, nchars: &Self) -> DaftResult<Self> {
         self.with_utf8_array(|arr| {
             if nchars.data_type().is_integer() {
@@ -152,6 +201,12 @@ impl Series {
         })
     }
 
+    pub fn utf8_position(&self, substr: &Self) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| {
+            substr.with_utf8_array(|substr_arr| Ok(arr.position(substr_arr)?.into_series()))
+        })
+    }
+
     pub fn utf8_lpad(&self, length: &Self, pad: &Self) -> DaftResult<Self> {
         self.with_utf8_array(|arr| {
             pad.with_utf8_array(|pad_arr| {
@@ -255,6 +310,14 @@ impl Series {
         self.with_utf8_array(|arr| Ok(arr.to_datetime(format, timezone)?.into_series()))
     }
 
+    pub fn utf8_try_to_date(&self, format: &str) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.try_to_date(format)?.into_series()))
+    }
+
+    pub fn utf8_try_to_datetime(&self, format: &str, timezone: Option<&str>) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.try_to_datetime(format, timezone)?.into_series()))
+    }
+
     pub fn utf8_normalize(&self, opts: Utf8NormalizeOptions) -> DaftResult<Self> {
         self.with_utf8_array(|arr| Ok(arr.normalize(opts)?.into_series()))
     }
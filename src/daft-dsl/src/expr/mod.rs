@@ -1,6 +1,7 @@
 mod display;
 #[cfg(test)]
 mod tests;
+pub mod window;
 
 use std::{
     any::Any,
@@ -26,6 +27,7 @@ use daft_core::{
 };
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
+pub use window::{WindowBoundary, WindowExpr, WindowFrame, WindowFrameType, WindowSpec};
 
 use super::functions::FunctionExpr;
 use crate::{
@@ -240,6 +242,9 @@ pub enum Expr {
 
     #[display("exists {_0}")]
     Exists(Subquery),
+
+    #[display("{_0} over ({_1})")]
+    Over(WindowExpr, WindowSpec),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Hash, Eq)]
@@ -330,6 +335,15 @@ pub fn resolved_col<S: Into<Arc<str>>>(name: S) -> ExprRef {
     Expr::Column(Column::Resolved(ResolvedColumn::Basic(name.into()))).into()
 }
 
+/// A column of the given side of a join, for use in join predicates.
+pub fn join_side_col<S: Into<Arc<str>>>(name: S, side: JoinSide) -> ExprRef {
+    Expr::Column(Column::Resolved(ResolvedColumn::JoinSide(
+        name.into(),
+        side,
+    )))
+    .into()
+}
+
 pub fn binary_op(op: Operator, left: ExprRef, right: ExprRef) -> ExprRef {
     Expr::BinaryOp { op, left, right }.into()
 }
@@ -805,6 +819,57 @@ impl Expr {
         Self::InSubquery(self, subquery).into()
     }
 
+    /// Evaluates an aggregation or window function over the given window.
+    pub fn over(self: ExprRef, window_spec: WindowSpec) -> DaftResult<ExprRef> {
+        match self.as_ref() {
+            Self::Agg(agg_expr) => {
+                Ok(Self::Over(WindowExpr::Agg(agg_expr.clone()), window_spec).into())
+            }
+            Self::Over(window_expr, _) => Ok(Self::Over(window_expr.clone(), window_spec).into()),
+            Self::Alias(expr, name) => Ok(expr.clone().over(window_spec)?.alias(name.clone())),
+            _ => Err(DaftError::ValueError(format!(
+                "Only aggregations and window functions can be evaluated over a window, but received {self}"
+            ))),
+        }
+    }
+
+    /// The value of this expression `offset` rows before the current row in its window partition.
+    pub fn lag(self: ExprRef, offset: u64, default: Option<ExprRef>) -> DaftResult<ExprRef> {
+        let offset = Self::window_offset("lag", offset)?;
+        Ok(Self::Over(
+            WindowExpr::Offset {
+                input: self,
+                offset: -offset,
+                default,
+            },
+            WindowSpec::default(),
+        )
+        .into())
+    }
+
+    /// The value of this expression `offset` rows after the current row in its window partition.
+    pub fn lead(self: ExprRef, offset: u64, default: Option<ExprRef>) -> DaftResult<ExprRef> {
+        let offset = Self::window_offset("lead", offset)?;
+        Ok(Self::Over(
+            WindowExpr::Offset {
+                input: self,
+                offset,
+                default,
+            },
+            WindowSpec::default(),
+        )
+        .into())
+    }
+
+    fn window_offset(name: &str, offset: u64) -> DaftResult<i64> {
+        i64::try_from(offset).map_err(|_| {
+            DaftError::ValueError(format!(
+                "{name} offset must be at most {}, got: {offset}",
+                i64::MAX
+            ))
+        })
+    }
+
     pub fn semantic_id(&self, schema: &Schema) -> FieldID {
         match self {
             // Base case - anonymous column reference.
@@ -910,6 +975,11 @@ impl Expr {
 
                 FieldID::new(format!("(EXISTS {subquery_id})"))
             }
+            Self::Over(window_expr, window_spec) => {
+                let child_id = window_expr.semantic_id(schema);
+                let window_spec_id = window_spec.semantic_id(schema);
+                FieldID::new(format!("{child_id}.over({window_spec_id})"))
+            }
         }
     }
 
@@ -948,6 +1018,12 @@ impl Expr {
             }
             Self::FillNull(expr, fill_value) => vec![expr.clone(), fill_value.clone()],
             Self::ScalarFunction(sf) => sf.inputs.clone(),
+            Self::Over(window_expr, window_spec) => window_expr
+                .children()
+                .into_iter()
+                .chain(window_spec.partition_by.iter().cloned())
+                .chain(window_spec.order_by.iter().cloned())
+                .collect(),
         }
     }
 
@@ -1046,6 +1122,26 @@ impl Expr {
                     inputs: children,
                 })
             }
+            Self::Over(window_expr, window_spec) => {
+                let num_window_expr_children = window_expr.children().len();
+                let num_partition_by = window_spec.partition_by.len();
+                assert_eq!(
+                    children.len(),
+                    num_window_expr_children + num_partition_by + window_spec.order_by.len(),
+                    "Should have same number of children"
+                );
+                let mut children = children;
+                let order_by = children.split_off(num_window_expr_children + num_partition_by);
+                let partition_by = children.split_off(num_window_expr_children);
+                Self::Over(
+                    window_expr.with_new_children(children),
+                    WindowSpec {
+                        partition_by,
+                        order_by,
+                        ..window_spec.clone()
+                    },
+                )
+            }
         }
     }
 
@@ -1239,6 +1335,16 @@ impl Expr {
             }
             Self::InSubquery(expr, _) => Ok(Field::new(expr.name(), DataType::Boolean)),
             Self::Exists(_) => Ok(Field::new("exists", DataType::Boolean)),
+            Self::Over(window_expr, window_spec) => {
+                for expr in window_spec
+                    .partition_by
+                    .iter()
+                    .chain(window_spec.order_by.iter())
+                {
+                    expr.to_field(schema)?;
+                }
+                window_expr.to_field(schema)
+            }
         }
     }
 
@@ -1278,6 +1384,7 @@ impl Expr {
             Self::Subquery(subquery) => subquery.name(),
             Self::InSubquery(expr, _) => expr.name(),
             Self::Exists(subquery) => subquery.name(),
+            Self::Over(window_expr, _) => window_expr.name(),
         }
     }
 
@@ -1358,6 +1465,7 @@ impl Expr {
                 | Expr::Subquery(..)
                 | Expr::InSubquery(..)
                 | Expr::Exists(..)
+                | Expr::Over(..)
                 | Expr::Column(..) => Err(io::Error::new(
                     io::ErrorKind::Other,
                     "Unsupported expression for SQL translation",
@@ -1396,6 +1504,7 @@ impl Expr {
             Self::Function { .. } => true,
             Self::ScalarFunction(..) => true,
             Self::Agg(_) => true,
+            Self::Over(..) => true,
             Self::IsIn(..) => true,
             Self::Between(..) => true,
             Self::BinaryOp { .. } => true,
@@ -1627,6 +1736,7 @@ pub fn estimated_selectivity(expr: &Expr, schema: &Schema) -> f64 {
         // Everything else doesn't filter
         Expr::Subquery(_) => 1.0,
         Expr::Agg(_) => panic!("Aggregates are not allowed in WHERE clauses"),
+        Expr::Over(..) => panic!("Window functions are not allowed in WHERE clauses"),
         Expr::List(_) => 1.0,
     };
 
@@ -81,3 +81,17 @@ fn check_arithmetic_type_with_columns() -> DaftResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn check_lag_lead_offset_bounds() -> DaftResult<()> {
+    let x = resolved_col("x");
+    let Expr::Over(WindowExpr::Offset { offset, .. }, _) =
+        x.clone().lag(i64::MAX as u64, None)?.as_ref().clone()
+    else {
+        panic!("expected a window offset expression");
+    };
+    assert_eq!(offset, -i64::MAX);
+    assert!(x.clone().lag(i64::MAX as u64 + 1, None).is_err());
+    assert!(x.lead(u64::MAX, None).is_err());
+    Ok(())
+}
@@ -0,0 +1,327 @@
+use std::fmt::{Display, Formatter};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{prelude::*, utils::supertype::try_get_supertype};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use super::{AggExpr, Expr, ExprRef};
+
+/// A function that is evaluated over a window of rows, rather than over a single row or a whole group.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum WindowExpr {
+    /// An aggregation over the rows in the window frame of each row.
+    Agg(AggExpr),
+    /// The 1-based position of each row within its partition.
+    RowNumber,
+    /// The rank of each row within its partition, with gaps after ties.
+    Rank,
+    /// The rank of each row within its partition, without gaps after ties.
+    DenseRank,
+    /// The value of `input` in the row that is `offset` rows after the current row in its partition,
+    /// or `default` if there is no such row. A negative offset refers to preceding rows, i.e. `lag`.
+    Offset {
+        input: ExprRef,
+        offset: i64,
+        default: Option<ExprRef>,
+    },
+}
+
+impl WindowExpr {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Agg(agg_expr) => agg_expr.name(),
+            Self::RowNumber => "row_number",
+            Self::Rank => "rank",
+            Self::DenseRank => "dense_rank",
+            Self::Offset { input, .. } => input.name(),
+        }
+    }
+
+    pub fn semantic_id(&self, schema: &Schema) -> FieldID {
+        match self {
+            Self::Agg(agg_expr) => agg_expr.semantic_id(schema),
+            Self::RowNumber => FieldID::new("row_number()"),
+            Self::Rank => FieldID::new("rank()"),
+            Self::DenseRank => FieldID::new("dense_rank()"),
+            Self::Offset {
+                input,
+                offset,
+                default,
+            } => {
+                let child_id = input.semantic_id(schema);
+                let default_id = default.as_ref().map(|d| d.semantic_id(schema).id);
+                FieldID::new(format!(
+                    "{child_id}.window_offset(offset={offset},default={default_id:?})"
+                ))
+            }
+        }
+    }
+
+    pub fn children(&self) -> Vec<ExprRef> {
+        match self {
+            Self::Agg(agg_expr) => agg_expr.children(),
+            Self::RowNumber | Self::Rank | Self::DenseRank => vec![],
+            Self::Offset { input, default, .. } => std::iter::once(input.clone())
+                .chain(default.iter().cloned())
+                .collect(),
+        }
+    }
+
+    pub fn with_new_children(&self, children: Vec<ExprRef>) -> Self {
+        match self {
+            Self::Agg(agg_expr) => Self::Agg(agg_expr.with_new_children(children)),
+            Self::RowNumber | Self::Rank | Self::DenseRank => {
+                assert!(children.is_empty(), "Should have no children");
+                self.clone()
+            }
+            Self::Offset {
+                offset, default, ..
+            } => {
+                let mut children = children.into_iter();
+                let input = children.next().expect("Should have 1 child");
+                let new_default = children.next();
+                assert_eq!(
+                    default.is_some(),
+                    new_default.is_some(),
+                    "Should have same number of children"
+                );
+                Self::Offset {
+                    input,
+                    offset: *offset,
+                    default: new_default,
+                }
+            }
+        }
+    }
+
+    pub fn to_field(&self, schema: &Schema) -> DaftResult<Field> {
+        match self {
+            Self::Agg(AggExpr::MapGroups { .. }) => Err(DaftError::ValueError(
+                "UDF aggregations cannot be used as window functions".to_string(),
+            )),
+            Self::Agg(agg_expr) => agg_expr.to_field(schema),
+            Self::RowNumber | Self::Rank | Self::DenseRank => {
+                Ok(Field::new(self.name(), DataType::UInt64))
+            }
+            Self::Offset { input, default, .. } => {
+                let field = input.to_field(schema)?;
+                match default {
+                    Some(default) => {
+                        let default_field = default.to_field(schema)?;
+                        match try_get_supertype(&field.dtype, &default_field.dtype) {
+                            Ok(supertype) => Ok(Field::new(field.name, supertype)),
+                            Err(_) => Err(DaftError::TypeError(format!(
+                                "Expected input and default arguments of window offset to be castable to the same supertype, but received {field} and {default_field}"
+                            ))),
+                        }
+                    }
+                    None => Ok(field),
+                }
+            }
+        }
+    }
+}
+
+impl Display for WindowExpr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Agg(agg_expr) => write!(f, "{agg_expr}"),
+            Self::RowNumber => write!(f, "row_number()"),
+            Self::Rank => write!(f, "rank()"),
+            Self::DenseRank => write!(f, "dense_rank()"),
+            Self::Offset {
+                input,
+                offset,
+                default,
+            } => {
+                let (name, offset) = if *offset < 0 {
+                    ("lag", -offset)
+                } else {
+                    ("lead", *offset)
+                };
+                match default {
+                    Some(default) => write!(f, "{name}({input}, {offset}, {default})"),
+                    None => write!(f, "{name}({input}, {offset})"),
+                }
+            }
+        }
+    }
+}
+
+/// Whether a window frame is defined in terms of row positions or in terms of the values of the order by key.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum WindowFrameType {
+    Rows,
+    Range,
+}
+
+/// One end of a window frame.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum WindowBoundary {
+    UnboundedPreceding,
+    /// An offset from the current row, where negative offsets precede the current row and 0 is the current row.
+    /// For range frames, the offset is in units of the order by key, which must then be a single numeric column.
+    Offset(i64),
+    UnboundedFollowing,
+}
+
+impl Display for WindowBoundary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnboundedPreceding => write!(f, "unbounded preceding"),
+            Self::Offset(0) => write!(f, "current row"),
+            Self::Offset(offset) if *offset < 0 => write!(f, "{} preceding", -offset),
+            Self::Offset(offset) => write!(f, "{offset} following"),
+            Self::UnboundedFollowing => write!(f, "unbounded following"),
+        }
+    }
+}
+
+/// The rows of a partition that a window aggregation is computed over, relative to the current row.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct WindowFrame {
+    pub frame_type: WindowFrameType,
+    pub start: WindowBoundary,
+    pub end: WindowBoundary,
+}
+
+impl WindowFrame {
+    pub fn try_new(
+        frame_type: WindowFrameType,
+        start: WindowBoundary,
+        end: WindowBoundary,
+    ) -> DaftResult<Self> {
+        match (start, end) {
+            (WindowBoundary::UnboundedFollowing, _) => Err(DaftError::ValueError(
+                "Window frame cannot start at unbounded following".to_string(),
+            )),
+            (_, WindowBoundary::UnboundedPreceding) => Err(DaftError::ValueError(
+                "Window frame cannot end at unbounded preceding".to_string(),
+            )),
+            (WindowBoundary::Offset(start), WindowBoundary::Offset(end)) if start > end => {
+                Err(DaftError::ValueError(format!(
+                    "Window frame start ({start}) must not be after its end ({end})"
+                )))
+            }
+            _ => Ok(Self {
+                frame_type,
+                start,
+                end,
+            }),
+        }
+    }
+
+    /// The frame spanning the entire partition.
+    pub fn unbounded() -> Self {
+        Self {
+            frame_type: WindowFrameType::Rows,
+            start: WindowBoundary::UnboundedPreceding,
+            end: WindowBoundary::UnboundedFollowing,
+        }
+    }
+}
+
+impl Display for WindowFrame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let frame_type = match self.frame_type {
+            WindowFrameType::Rows => "rows",
+            WindowFrameType::Range => "range",
+        };
+        write!(f, "{frame_type} between {} and {}", self.start, self.end)
+    }
+}
+
+/// The partitioning, ordering and frame of the window that a [`WindowExpr`] is evaluated over.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct WindowSpec {
+    pub partition_by: Vec<ExprRef>,
+    pub order_by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+    pub nulls_first: Vec<bool>,
+    pub frame: Option<WindowFrame>,
+}
+
+impl WindowSpec {
+    /// The frame that window aggregations are computed over.
+    ///
+    /// If no frame was specified, this follows the SQL standard: the whole partition if there is no ordering,
+    /// and otherwise all rows up to and including the peers of the current row.
+    pub fn effective_frame(&self) -> WindowFrame {
+        match self.frame {
+            Some(frame) => frame,
+            None if self.order_by.is_empty() => WindowFrame::unbounded(),
+            None => WindowFrame {
+                frame_type: WindowFrameType::Range,
+                start: WindowBoundary::UnboundedPreceding,
+                end: WindowBoundary::Offset(0),
+            },
+        }
+    }
+
+    pub(super) fn semantic_id(&self, schema: &Schema) -> String {
+        let partition_by = self
+            .partition_by
+            .iter()
+            .map(|e| e.semantic_id(schema).id)
+            .join(",");
+        let order_by = self
+            .order_by
+            .iter()
+            .zip(self.descending.iter())
+            .zip(self.nulls_first.iter())
+            .map(|((e, desc), nulls_first)| {
+                format!("{}:{desc}:{nulls_first}", e.semantic_id(schema).id)
+            })
+            .join(",");
+        let frame = self.frame.map(|frame| frame.to_string());
+        format!("partition_by=[{partition_by}],order_by=[{order_by}],frame={frame:?}")
+    }
+}
+
+impl Display for WindowSpec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut clauses = vec![];
+        if !self.partition_by.is_empty() {
+            clauses.push(format!(
+                "partition_by=[{}]",
+                self.partition_by.iter().join(", ")
+            ));
+        }
+        if !self.order_by.is_empty() {
+            clauses.push(format!(
+                "order_by=[{}]",
+                self.order_by
+                    .iter()
+                    .zip(self.descending.iter())
+                    .map(|(e, desc)| format!("{e} {}", if *desc { "desc" } else { "asc" }))
+                    .join(", ")
+            ));
+        }
+        if let Some(frame) = &self.frame {
+            clauses.push(frame.to_string());
+        }
+        write!(f, "{}", clauses.join(", "))
+    }
+}
+
+/// The 1-based position of each row within its window partition.
+pub fn row_number() -> ExprRef {
+    Expr::Over(WindowExpr::RowNumber, WindowSpec::default()).into()
+}
+
+/// The rank of each row within its window partition, with gaps after ties.
+pub fn rank() -> ExprRef {
+    Expr::Over(WindowExpr::Rank, WindowSpec::default()).into()
+}
+
+/// The rank of each row within its window partition, without gaps after ties.
+pub fn dense_rank() -> ExprRef {
+    Expr::Over(WindowExpr::DenseRank, WindowSpec::default()).into()
+}
+
+pub fn has_window(expr: &ExprRef) -> bool {
+    use common_treenode::TreeNode;
+
+    expr.exists(|e| matches!(e.as_ref(), Expr::Over(..)))
+}
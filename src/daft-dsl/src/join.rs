@@ -1,8 +1,11 @@
-use common_error::DaftResult;
-use daft_core::{prelude::*, utils::supertype::try_get_supertype};
+use std::sync::Arc;
+
+use common_error::{DaftError, DaftResult};
+use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
+use daft_core::{join::JoinSide, prelude::*, utils::supertype::try_get_supertype};
 use indexmap::IndexSet;
 
-use crate::{deduplicate_expr_names, ExprRef};
+use crate::{deduplicate_expr_names, resolved_col, Column, Expr, ExprRef, ResolvedColumn};
 
 pub fn get_common_join_cols<'a>(
     left_schema: &'a SchemaRef,
@@ -97,3 +100,90 @@ pub fn normalize_join_keys(
 
     Ok((left_on, right_on))
 }
+
+/// The columns of either side of a join that a join predicate refers to, in order of first reference.
+pub fn get_join_predicate_columns(predicate: &ExprRef) -> IndexSet<(Arc<str>, JoinSide)> {
+    let mut cols = IndexSet::new();
+    predicate
+        .apply(|e| {
+            if let Expr::Column(Column::Resolved(ResolvedColumn::JoinSide(name, side))) = e.as_ref()
+            {
+                cols.insert((name.clone(), *side));
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })
+        .expect("Error occurred when visiting for join predicate columns");
+    cols
+}
+
+/// The name of the column that holds the column `name` of the given side of a join in a table of row pairs,
+/// i.e. a table whose rows each pair a left row with a right row, over which a join predicate can be evaluated.
+pub fn join_pair_column_name(name: &str, side: JoinSide) -> String {
+    format!("{side}.{name}")
+}
+
+/// Rewrites a join predicate to refer to the columns of a table of row pairs, see [`join_pair_column_name`].
+pub fn join_predicate_to_pair_expr(predicate: ExprRef) -> ExprRef {
+    predicate
+        .transform(|e| match e.as_ref() {
+            Expr::Column(Column::Resolved(ResolvedColumn::JoinSide(name, side))) => Ok(
+                Transformed::yes(resolved_col(join_pair_column_name(name, *side))),
+            ),
+            _ => Ok(Transformed::no(e)),
+        })
+        .expect("Error occurred when rewriting join predicate")
+        .data
+}
+
+/// The schema of the table of row pairs that a join predicate is evaluated over,
+/// which holds the columns of either side that the predicate refers to.
+pub fn join_pair_schema(
+    predicate: &ExprRef,
+    left_schema: &Schema,
+    right_schema: &Schema,
+) -> DaftResult<SchemaRef> {
+    let fields = get_join_predicate_columns(predicate)
+        .into_iter()
+        .map(|(name, side)| {
+            let schema = match side {
+                JoinSide::Left => left_schema,
+                JoinSide::Right => right_schema,
+            };
+            Ok(schema
+                .get_field(&name)?
+                .rename(join_pair_column_name(&name, side)))
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    Ok(Schema::new(fields)?.into())
+}
+
+/// Checks that a join predicate is a boolean expression over the columns of both sides of a join.
+pub fn validate_join_predicate(
+    predicate: &ExprRef,
+    left_schema: &Schema,
+    right_schema: &Schema,
+) -> DaftResult<()> {
+    let pair_schema = join_pair_schema(predicate, left_schema, right_schema)?;
+    let field = join_predicate_to_pair_expr(predicate.clone()).to_field(&pair_schema)?;
+    if field.dtype != DataType::Boolean {
+        return Err(DaftError::ValueError(format!(
+            "Expected join predicate to be a boolean expression, received: {predicate} of type {}",
+            field.dtype
+        )));
+    }
+    Ok(())
+}
+
+/// Rewrites a join predicate to refer to the columns of the output of an inner join, in which each column of either side
+/// keeps its name and the columns common to both sides are taken from the left side.
+pub fn join_predicate_to_output_expr(predicate: ExprRef) -> ExprRef {
+    predicate
+        .transform(|e| match e.as_ref() {
+            Expr::Column(Column::Resolved(ResolvedColumn::JoinSide(name, _))) => {
+                Ok(Transformed::yes(resolved_col(name.clone())))
+            }
+            _ => Ok(Transformed::no(e)),
+        })
+        .expect("Error occurred when rewriting join predicate")
+        .data
+}
@@ -15,9 +15,12 @@ mod treenode;
 pub use common_treenode;
 pub use expr::{
     binary_op, count_actor_pool_udfs, deduplicate_expr_names, estimated_selectivity,
-    exprs_to_schema, has_agg, is_actor_pool_udf, is_partition_compatible, resolved_col,
-    unresolved_col, AggExpr, ApproxPercentileParams, Column, Expr, ExprRef, Operator, PlanRef,
-    ResolvedColumn, SketchType, Subquery, SubqueryPlan, UnresolvedColumn,
+    exprs_to_schema, has_agg, is_actor_pool_udf, is_partition_compatible, join_side_col,
+    resolved_col, unresolved_col,
+    window::{dense_rank, has_window, rank, row_number},
+    AggExpr, ApproxPercentileParams, Column, Expr, ExprRef, Operator, PlanRef, ResolvedColumn,
+    SketchType, Subquery, SubqueryPlan, UnresolvedColumn, WindowBoundary, WindowExpr, WindowFrame,
+    WindowFrameType, WindowSpec,
 };
 pub use lit::{lit, literal_value, literals_to_series, null_lit, Literal, LiteralValue};
 #[cfg(feature = "python")]
@@ -42,6 +45,9 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_function(wrap_pyfunction!(python::initialize_udfs, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::get_udf_names, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::eq, parent)?)?;
+    parent.add_function(wrap_pyfunction!(python::row_number, parent)?)?;
+    parent.add_function(wrap_pyfunction!(python::rank, parent)?)?;
+    parent.add_function(wrap_pyfunction!(python::dense_rank, parent)?)?;
 
     Ok(())
 }
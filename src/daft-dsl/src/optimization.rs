@@ -36,7 +36,8 @@ pub fn requires_computation(e: &Expr) -> bool {
         | Expr::IfElse { .. }
         | Expr::Subquery { .. }
         | Expr::InSubquery { .. }
-        | Expr::Exists(..) => true,
+        | Expr::Exists(..)
+        | Expr::Over(..) => true,
     }
 }
 
@@ -256,6 +256,21 @@ pub fn get_udf_names(expr: PyExpr) -> Vec<String> {
     get_udf_names(&expr.expr)
 }
 
+#[pyfunction]
+pub fn row_number() -> PyExpr {
+    crate::row_number().into()
+}
+
+#[pyfunction]
+pub fn rank() -> PyExpr {
+    crate::rank().into()
+}
+
+#[pyfunction]
+pub fn dense_rank() -> PyExpr {
+    crate::dense_rank().into()
+}
+
 #[pyclass(module = "daft.daft")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PyExpr {
@@ -373,6 +388,65 @@ impl PyExpr {
         Ok(self.expr.clone().agg_concat().into())
     }
 
+    /// Evaluates this aggregation or window function over a window.
+    ///
+    /// `frame` is `(frame_type, start, end)`, where `frame_type` is "rows" or "range" and
+    /// an offset of `None` is unbounded preceding for `start` and unbounded following for `end`.
+    #[pyo3(signature = (partition_by, order_by, descending, nulls_first, frame=None))]
+    pub fn over(
+        &self,
+        partition_by: Vec<Self>,
+        order_by: Vec<Self>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        frame: Option<(String, Option<i64>, Option<i64>)>,
+    ) -> PyResult<Self> {
+        use crate::{WindowBoundary, WindowFrame, WindowFrameType, WindowSpec};
+
+        let frame = frame
+            .map(|(frame_type, start, end)| {
+                let frame_type = match frame_type.as_str() {
+                    "rows" => WindowFrameType::Rows,
+                    "range" => WindowFrameType::Range,
+                    other => {
+                        return Err(PyValueError::new_err(format!(
+                            "Expected window frame type to be \"rows\" or \"range\", but received: {other}"
+                        )))
+                    }
+                };
+                let start = start.map_or(WindowBoundary::UnboundedPreceding, WindowBoundary::Offset);
+                let end = end.map_or(WindowBoundary::UnboundedFollowing, WindowBoundary::Offset);
+                Ok(WindowFrame::try_new(frame_type, start, end)?)
+            })
+            .transpose()?;
+        let window_spec = WindowSpec {
+            partition_by: partition_by.into_iter().map(|e| e.expr).collect(),
+            order_by: order_by.into_iter().map(|e| e.expr).collect(),
+            descending,
+            nulls_first,
+            frame,
+        };
+        Ok(self.expr.clone().over(window_spec)?.into())
+    }
+
+    #[pyo3(signature = (offset, default=None))]
+    pub fn lag(&self, offset: u64, default: Option<Self>) -> PyResult<Self> {
+        Ok(self
+            .expr
+            .clone()
+            .lag(offset, default.map(|d| d.expr))?
+            .into())
+    }
+
+    #[pyo3(signature = (offset, default=None))]
+    pub fn lead(&self, offset: u64, default: Option<Self>) -> PyResult<Self> {
+        Ok(self
+            .expr
+            .clone()
+            .lead(offset, default.map(|d| d.expr))?
+            .into())
+    }
+
     pub fn __add__(&self, other: &Self) -> PyResult<Self> {
         Ok(crate::binary_op(crate::Operator::Plus, self.into(), other.expr.clone()).into())
     }
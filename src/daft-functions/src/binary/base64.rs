@@ -0,0 +1,116 @@
+use base64::{engine::general_purpose, Engine};
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    datatypes::{BinaryArray, DataType, Field, Utf8Array},
+    prelude::Schema,
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{is_bytes_like, map_bytes};
+
+/// Encodes binary or utf8 values as base64 strings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Base64Encode {}
+
+#[typetag::serde]
+impl ScalarUDF for Base64Encode {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn name(&self) -> &'static str {
+        "base64_encode"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => {
+                let data_field = data.to_field(schema)?;
+                if !is_bytes_like(&data_field) {
+                    return Err(DaftError::TypeError(format!(
+                        "Expects input to base64_encode to be binary or utf8, but received {data_field}",
+                    )));
+                }
+                Ok(Field::new(data_field.name, DataType::Utf8))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data] => {
+                let encoded = map_bytes(data, |val| Some(general_purpose::STANDARD.encode(val)))?;
+                let arrow_result = arrow2::array::Utf8Array::<i64>::from(encoded);
+                Ok(Utf8Array::from((data.name(), Box::new(arrow_result))).into_series())
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+/// Decodes base64 strings into binary values, returning null for invalid strings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Base64Decode {}
+
+#[typetag::serde]
+impl ScalarUDF for Base64Decode {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn name(&self) -> &'static str {
+        "base64_decode"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => {
+                let data_field = data.to_field(schema)?;
+                if !is_bytes_like(&data_field) {
+                    return Err(DaftError::TypeError(format!(
+                        "Expects input to base64_decode to be binary or utf8, but received {data_field}",
+                    )));
+                }
+                Ok(Field::new(data_field.name, DataType::Binary))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data] => {
+                let decoded = map_bytes(data, |val| general_purpose::STANDARD.decode(val).ok())?;
+                let arrow_result = arrow2::array::BinaryArray::<i64>::from(decoded);
+                Ok(BinaryArray::from((data.name(), Box::new(arrow_result))).into_series())
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[must_use]
+pub fn base64_encode(input: ExprRef) -> ExprRef {
+    ScalarFunction::new(Base64Encode {}, vec![input]).into()
+}
+
+#[must_use]
+pub fn base64_decode(input: ExprRef) -> ExprRef {
+    ScalarFunction::new(Base64Decode {}, vec![input]).into()
+}
@@ -0,0 +1,155 @@
+use std::fmt::Write;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    array::ops::as_arrow::AsArrow,
+    datatypes::{BinaryArray, DataType, Field, Utf8Array},
+    prelude::Schema,
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{is_bytes_like, map_bytes};
+
+/// Formats integers as uppercase hexadecimal strings, where negative integers are formatted as
+/// their two's complement, and binary or utf8 values as the uppercase hexadecimal digits of their
+/// bytes.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Hex {}
+
+#[typetag::serde]
+impl ScalarUDF for Hex {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn name(&self) -> &'static str {
+        "hex"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => {
+                let data_field = data.to_field(schema)?;
+                if !is_bytes_like(&data_field) && !data_field.dtype.is_integer() {
+                    return Err(DaftError::TypeError(format!(
+                        "Expects input to hex to be an integer, binary or utf8, but received {data_field}",
+                    )));
+                }
+                Ok(Field::new(data_field.name, DataType::Utf8))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data] => {
+                let hexed = if data.data_type().is_integer() {
+                    let data = data.cast(&DataType::Int64)?;
+                    data.i64()?
+                        .as_arrow()
+                        .iter()
+                        .map(|val| val.map(|val| format!("{val:X}")))
+                        .collect::<Vec<_>>()
+                } else {
+                    map_bytes(data, |val| {
+                        let mut hexed = String::with_capacity(val.len() * 2);
+                        for byte in val {
+                            write!(hexed, "{byte:02X}").unwrap();
+                        }
+                        Some(hexed)
+                    })?
+                };
+                let arrow_result = arrow2::array::Utf8Array::<i64>::from(hexed);
+                Ok(Utf8Array::from((data.name(), Box::new(arrow_result))).into_series())
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+/// Decodes strings of hexadecimal digits into binary values, returning null for invalid strings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Unhex {}
+
+#[typetag::serde]
+impl ScalarUDF for Unhex {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn name(&self) -> &'static str {
+        "unhex"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => {
+                let data_field = data.to_field(schema)?;
+                if !is_bytes_like(&data_field) {
+                    return Err(DaftError::TypeError(format!(
+                        "Expects input to unhex to be binary or utf8, but received {data_field}",
+                    )));
+                }
+                Ok(Field::new(data_field.name, DataType::Binary))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data] => {
+                let unhexed = map_bytes(data, unhex_bytes)?;
+                let arrow_result = arrow2::array::BinaryArray::<i64>::from(unhexed);
+                Ok(BinaryArray::from((data.name(), Box::new(arrow_result))).into_series())
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+fn unhex_bytes(val: &[u8]) -> Option<Vec<u8>> {
+    let digit = |byte: &u8| (*byte as char).to_digit(16).map(|digit| digit as u8);
+
+    // an odd number of digits is treated as if it had a leading zero
+    let (first, rest) = if val.len() % 2 == 1 {
+        (Some(digit(&val[0])?), &val[1..])
+    } else {
+        (None, val)
+    };
+
+    first
+        .into_iter()
+        .map(Some)
+        .chain(
+            rest.chunks_exact(2)
+                .map(|pair| Some((digit(&pair[0])? << 4) | digit(&pair[1])?)),
+        )
+        .collect()
+}
+
+#[must_use]
+pub fn hex(input: ExprRef) -> ExprRef {
+    ScalarFunction::new(Hex {}, vec![input]).into()
+}
+
+#[must_use]
+pub fn unhex(input: ExprRef) -> ExprRef {
+    ScalarFunction::new(Unhex {}, vec![input]).into()
+}
@@ -1,7 +1,52 @@
+pub mod base64;
 pub mod concat;
+pub mod hex;
 pub mod length;
 pub mod slice;
 
+use common_error::{DaftError, DaftResult};
 pub use concat::{binary_concat, BinaryConcat};
+use daft_core::{
+    array::ops::as_arrow::AsArrow,
+    datatypes::{DataType, Field},
+    series::Series,
+};
+pub use hex::{hex, unhex, Hex, Unhex};
 pub use length::{binary_length, BinaryLength};
 pub use slice::{binary_slice, BinarySlice};
+
+pub use self::base64::{base64_decode, base64_encode, Base64Decode, Base64Encode};
+
+/// Whether a field holds values that [`map_bytes`] can be applied to.
+fn is_bytes_like(field: &Field) -> bool {
+    matches!(
+        field.dtype,
+        DataType::Binary | DataType::FixedSizeBinary(_) | DataType::Utf8 | DataType::Null
+    )
+}
+
+/// Applies `func` to the bytes of each value of a binary or utf8 series.
+fn map_bytes<T>(
+    input: &Series,
+    mut func: impl FnMut(&[u8]) -> Option<T>,
+) -> DaftResult<Vec<Option<T>>> {
+    match input.data_type() {
+        DataType::Binary => Ok(input
+            .binary()?
+            .as_arrow()
+            .iter()
+            .map(|val| func(val?))
+            .collect()),
+        DataType::Utf8 => Ok(input
+            .utf8()?
+            .as_arrow()
+            .iter()
+            .map(|val| func(val?.as_bytes()))
+            .collect()),
+        DataType::FixedSizeBinary(_) => map_bytes(&input.cast(&DataType::Binary)?, func),
+        DataType::Null => Ok((0..input.len()).map(|_| None).collect()),
+        other => Err(DaftError::TypeError(format!(
+            "Expected input to be binary or utf8, got {other}"
+        ))),
+    }
+}
@@ -0,0 +1,123 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{AsArrow, DataType, Field, Schema, Utf8Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+const DIGITS: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Converts numbers between bases, from strings of digits in `from_base` to strings of digits in
+/// `to_base`.
+///
+/// As in MySQL and Spark, a negative `to_base` formats the result as a signed number, while
+/// negative numbers are otherwise formatted as unsigned 64-bit integers. Parsing stops at the first
+/// invalid digit, and bases must be between 2 and 36.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Conv {
+    pub from_base: u32,
+    pub to_base: i32,
+}
+
+#[typetag::serde]
+impl ScalarUDF for Conv {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "conv"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => {
+                let field = data.to_field(schema)?;
+                if !field.dtype.is_string() && !field.dtype.is_integer() {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected input to conv to be utf8 or an integer, got {}",
+                        field.dtype
+                    )));
+                }
+                Ok(Field::new(field.name, DataType::Utf8))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data] => {
+                let data = data.cast(&DataType::Utf8)?;
+                let arrow_result = data
+                    .utf8()?
+                    .as_arrow()
+                    .iter()
+                    .map(|val| convert_base(val?, self.from_base, self.to_base))
+                    .collect::<arrow2::array::Utf8Array<i64>>();
+                Ok(Utf8Array::from((data.name(), Box::new(arrow_result))).into_series())
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+fn convert_base(val: &str, from_base: u32, to_base: i32) -> Option<String> {
+    let to_base_abs = to_base.unsigned_abs();
+    if !(2..=36).contains(&from_base) || !(2..=36).contains(&to_base_abs) {
+        return None;
+    }
+
+    let val = val.trim();
+    let (is_negative, digits) = match val.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, val),
+    };
+
+    // values which overflow saturate at the largest unsigned 64-bit integer
+    let mut value = 0u64;
+    for digit in digits.chars().map_while(|c| c.to_digit(from_base)) {
+        value = value
+            .checked_mul(u64::from(from_base))
+            .and_then(|value| value.checked_add(u64::from(digit)))
+            .unwrap_or(u64::MAX);
+    }
+    if is_negative {
+        value = value.wrapping_neg();
+    }
+
+    let (is_negative, mut value) = if to_base < 0 && (value as i64) < 0 {
+        (true, (value as i64).unsigned_abs())
+    } else {
+        (false, value)
+    };
+
+    let mut result = Vec::new();
+    loop {
+        result.push(DIGITS[(value % u64::from(to_base_abs)) as usize]);
+        value /= u64::from(to_base_abs);
+        if value == 0 {
+            break;
+        }
+    }
+    if is_negative {
+        result.push(b'-');
+    }
+    result.reverse();
+    Some(String::from_utf8(result).expect("digits are ascii"))
+}
+
+#[must_use]
+pub fn conv(input: ExprRef, from_base: u32, to_base: i32) -> ExprRef {
+    ScalarFunction::new(Conv { from_base, to_base }, vec![input]).into()
+}
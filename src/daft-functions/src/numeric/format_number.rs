@@ -0,0 +1,194 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{AsArrow, DataType, Field, Schema, Utf8Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+/// Formats numbers like `#,###,##0.00`, with commas between groups of thousands and `scale`
+/// decimal places, rounding half to even.
+///
+/// As in Spark's `format_number`, floats are formatted with their shortest representation, so
+/// that `1e20` is `100,000,000,000,000,000,000` rather than its exact binary value.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct FormatNumber {
+    pub scale: usize,
+}
+
+#[typetag::serde]
+impl ScalarUDF for FormatNumber {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "format_number"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => {
+                let field = data.to_field(schema)?;
+                if !field.dtype.is_numeric() && !matches!(field.dtype, DataType::Decimal128(..)) {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected input to format_number to be numeric, got {}",
+                        field.dtype
+                    )));
+                }
+                Ok(Field::new(field.name, DataType::Utf8))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        let [data] = inputs else {
+            return Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            )));
+        };
+        let arrow_result = match data.data_type() {
+            dtype if dtype.is_floating() => data
+                .cast(&DataType::Float64)?
+                .f64()?
+                .as_arrow()
+                .iter()
+                .map(|val| val.map(|val| format_float(*val, self.scale)))
+                .collect::<arrow2::array::Utf8Array<i64>>(),
+            DataType::Decimal128(_, scale) => data
+                .decimal128()?
+                .as_arrow()
+                .iter()
+                .map(|val| {
+                    val.map(|val| format_decimal(&decimal_to_string(*val, *scale), self.scale))
+                })
+                .collect(),
+            // integers are formatted from their exact digits
+            _ => data
+                .cast(&DataType::Utf8)?
+                .utf8()?
+                .as_arrow()
+                .iter()
+                .map(|val| val.map(|val| format_decimal(val, self.scale)))
+                .collect(),
+        };
+        Ok(Utf8Array::from((data.name(), Box::new(arrow_result))).into_series())
+    }
+}
+
+fn format_float(val: f64, scale: usize) -> String {
+    if val.is_nan() {
+        return "NaN".to_string();
+    }
+    if val.is_infinite() {
+        return if val > 0.0 { "∞" } else { "-∞" }.to_string();
+    }
+    let shortest = val.to_string();
+    let fraction_len = shortest.split_once('.').map_or(0, |(_, f)| f.len());
+    if fraction_len > scale {
+        // ties are broken by the exact binary value, which the shortest representation hides
+        format_decimal(&format!("{val:.scale$}"), scale)
+    } else {
+        format_decimal(&shortest, scale)
+    }
+}
+
+/// The digits of a decimal with `scale` digits after the decimal point, such as `-123.45`.
+fn decimal_to_string(val: i128, scale: usize) -> String {
+    let digits = format!("{:0>width$}", val.unsigned_abs(), width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    let sign = if val < 0 { "-" } else { "" };
+    format!("{sign}{integer}.{fraction}")
+}
+
+/// Formats a decimal string of the form `-123.456` with `scale` decimal places.
+fn format_decimal(val: &str, scale: usize) -> String {
+    let (is_negative, val) = match val.strip_prefix('-') {
+        Some(val) => (true, val),
+        None => (false, val),
+    };
+    let (integer, fraction) = val.split_once('.').unwrap_or((val, ""));
+
+    let mut digits = format!("{integer}{}", &fraction[..fraction.len().min(scale)]).into_bytes();
+    digits.resize(integer.len() + scale, b'0');
+    if let Some((&first, rest)) = fraction
+        .as_bytes()
+        .get(scale..)
+        .and_then(|d| d.split_first())
+    {
+        let is_odd = digits.last().is_some_and(|d| (d - b'0') % 2 == 1);
+        let round_up = match first {
+            b'6'..=b'9' => true,
+            b'5' => is_odd || rest.iter().any(|&d| d != b'0'),
+            _ => false,
+        };
+        if round_up && !increment(&mut digits) {
+            digits.insert(0, b'1');
+        }
+    }
+
+    let integer_len = digits.len() - scale;
+    let mut result = String::with_capacity(digits.len() + integer_len / 3 + 2);
+    if is_negative {
+        result.push('-');
+    }
+    for (i, &digit) in digits[..integer_len].iter().enumerate() {
+        if i > 0 && (integer_len - i) % 3 == 0 {
+            result.push(',');
+        }
+        result.push(digit as char);
+    }
+    if scale > 0 {
+        result.push('.');
+        result.extend(digits[integer_len..].iter().map(|&d| d as char));
+    }
+    result
+}
+
+/// Adds one to the last of the `digits`, returning false if it carries past the first digit.
+fn increment(digits: &mut [u8]) -> bool {
+    for digit in digits.iter_mut().rev() {
+        if *digit == b'9' {
+            *digit = b'0';
+        } else {
+            *digit += 1;
+            return true;
+        }
+    }
+    false
+}
+
+#[must_use]
+pub fn format_number(input: ExprRef, scale: usize) -> ExprRef {
+    ScalarFunction::new(FormatNumber { scale }, vec![input]).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decimal_to_string, format_decimal, format_float};
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_decimal("1234567", 0), "1,234,567");
+        assert_eq!(format_decimal("-1234.5", 2), "-1,234.50");
+        assert_eq!(format_decimal("999.995", 2), "1,000.00");
+        assert_eq!(format_decimal("0.125", 2), "0.12");
+        assert_eq!(format_decimal("0.135", 2), "0.14");
+        assert_eq!(format_decimal("2.5", 0), "2");
+        assert_eq!(format_decimal("-0.001", 2), "-0.00");
+        assert_eq!(format_float(2.675, 2), "2.67");
+        assert_eq!(format_float(1e20, 1), "100,000,000,000,000,000,000.0");
+        assert_eq!(format_float(-0.5, 0), "-0");
+        assert_eq!(format_float(f64::NAN, 2), "NaN");
+        assert_eq!(decimal_to_string(-5, 2), "-0.05");
+        assert_eq!(decimal_to_string(12345, 0), "12345.");
+    }
+}
@@ -2,9 +2,12 @@ pub mod abs;
 pub mod cbrt;
 pub mod ceil;
 pub mod clip;
+pub mod conv;
 pub mod exp;
 pub mod floor;
+pub mod format_number;
 pub mod log;
+pub mod pow;
 pub mod round;
 pub mod sign;
 pub mod sqrt;
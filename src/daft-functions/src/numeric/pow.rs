@@ -0,0 +1,66 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{DataType, Field, Schema},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Pow {}
+
+#[typetag::serde]
+impl ScalarUDF for Pow {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "pow"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [base, exponent] => {
+                let base = base.to_field(schema)?;
+                let exponent = exponent.to_field(schema)?;
+                if !base.dtype.is_numeric() || !exponent.dtype.is_numeric() {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected inputs to pow to be numeric, got {} and {}",
+                        base.dtype, exponent.dtype
+                    )));
+                }
+                Ok(Field::new(base.name, DataType::Float64))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [base, exponent] => {
+                let base = base.cast(&DataType::Float64)?;
+                let exponent = exponent.cast(&DataType::Float64)?;
+                Ok(base
+                    .f64()?
+                    .binary_apply(exponent.f64()?, f64::powf)?
+                    .into_series())
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[must_use]
+pub fn pow(base: ExprRef, exponent: ExprRef) -> ExprRef {
+    ScalarFunction::new(Pow {}, vec![base, exponent]).into()
+}
@@ -75,6 +75,9 @@ trigonometry!(degrees, Degrees);
 trigonometry!(arctanh, ArcTanh);
 trigonometry!(arccosh, ArcCosh);
 trigonometry!(arcsinh, ArcSinh);
+trigonometry!(sinh, Sinh);
+trigonometry!(cosh, Cosh);
+trigonometry!(tanh, Tanh);
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct Atan2 {}
@@ -113,6 +113,8 @@ pub fn register(parent: &Bound<PyModule>) -> PyResult<()> {
     add!(temporal::dt_time);
     add!(temporal::dt_year);
     add!(temporal::dt_truncate);
+    add!(temporal::dt_convert_timezone);
+    add!(temporal::dt_replace_timezone);
 
     add!(tokenize::tokenize_encode);
     add!(tokenize::tokenize_decode);
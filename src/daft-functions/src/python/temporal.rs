@@ -15,3 +15,13 @@ simple_python_wrapper!(dt_year, crate::temporal::dt_year, [expr: PyExpr]);
 pub fn dt_truncate(expr: PyExpr, interval: &str, relative_to: PyExpr) -> PyResult<PyExpr> {
     Ok(crate::temporal::truncate::dt_truncate(expr.into(), interval, relative_to.into()).into())
 }
+
+#[pyfunction]
+pub fn dt_convert_timezone(expr: PyExpr, tz: &str) -> PyResult<PyExpr> {
+    Ok(crate::temporal::timezone::dt_convert_timezone(expr.into(), tz).into())
+}
+
+#[pyfunction(signature = (expr, tz=None))]
+pub fn dt_replace_timezone(expr: PyExpr, tz: Option<&str>) -> PyResult<PyExpr> {
+    Ok(crate::temporal::timezone::dt_replace_timezone(expr.into(), tz).into())
+}
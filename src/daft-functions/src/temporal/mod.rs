@@ -1,3 +1,5 @@
+pub mod strftime;
+pub mod timezone;
 pub mod truncate;
 
 use common_error::{DaftError, DaftResult};
@@ -70,9 +72,11 @@ impl_temporal!(Date, dt_date, "dt_date", Date);
 impl_temporal!(Day, dt_day, "dt_day", UInt32);
 impl_temporal!(Hour, dt_hour, "dt_hour", UInt32);
 impl_temporal!(DayOfWeek, dt_day_of_week, "dt_day_of_week", UInt32);
+impl_temporal!(DayOfYear, dt_day_of_year, "dt_day_of_year", UInt32);
 impl_temporal!(Minute, dt_minute, "dt_minute", UInt32);
 impl_temporal!(Month, dt_month, "dt_month", UInt32);
 impl_temporal!(Second, dt_second, "dt_second", UInt32);
+impl_temporal!(WeekOfYear, dt_week_of_year, "dt_week_of_year", UInt32);
 impl_temporal!(Year, dt_year, "dt_year", Int32);
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -134,7 +138,11 @@ pub fn dt_time(input: ExprRef) -> ExprRef {
 mod test {
     use std::sync::Arc;
 
-    use super::truncate::Truncate;
+    use super::{
+        strftime::Strftime,
+        timezone::{ConvertTimezone, ReplaceTimezone},
+        truncate::Truncate,
+    };
 
     #[test]
     fn test_fn_name() {
@@ -144,11 +152,24 @@ mod test {
             (Arc::new(Day), "day"),
             (Arc::new(Hour), "hour"),
             (Arc::new(DayOfWeek), "day_of_week"),
+            (Arc::new(DayOfYear), "day_of_year"),
             (Arc::new(Minute), "minute"),
             (Arc::new(Month), "month"),
             (Arc::new(Second), "second"),
+            (Arc::new(WeekOfYear), "week_of_year"),
             (Arc::new(Time), "time"),
             (Arc::new(Year), "year"),
+            (
+                Arc::new(ConvertTimezone { tz: String::new() }),
+                "convert_timezone",
+            ),
+            (Arc::new(ReplaceTimezone { tz: None }), "replace_timezone"),
+            (
+                Arc::new(Strftime {
+                    format: String::new(),
+                }),
+                "strftime",
+            ),
             (
                 Arc::new(Truncate {
                     interval: String::new(),
@@ -0,0 +1,65 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+/// Formats a date or timestamp as a string using a chrono `strftime` format string.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Strftime {
+    pub(super) format: String,
+}
+
+#[typetag::serde]
+impl ScalarUDF for Strftime {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "strftime"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                match input_field.dtype {
+                    DataType::Date | DataType::Timestamp(..) => {
+                        Ok(Field::new(input_field.name, DataType::Utf8))
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to strftime to be date or timestamp, got {}",
+                        input_field.dtype
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input] => input.dt_strftime(&self.format),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+pub fn dt_strftime<S: Into<String>>(input: ExprRef, format: S) -> ExprRef {
+    ScalarFunction::new(
+        Strftime {
+            format: format.into(),
+        },
+        vec![input],
+    )
+    .into()
+}
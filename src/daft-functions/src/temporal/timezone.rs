@@ -0,0 +1,123 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{array::ops::ParsedTimezone, prelude::*};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+/// Converts a timestamp to another timezone, keeping the instant it represents.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ConvertTimezone {
+    pub(super) tz: String,
+}
+
+#[typetag::serde]
+impl ScalarUDF for ConvertTimezone {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "convert_timezone"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                ParsedTimezone::parse(&self.tz)?;
+                match input_field.dtype {
+                    DataType::Timestamp(tu, _) => Ok(Field::new(
+                        input_field.name,
+                        DataType::Timestamp(tu, Some(self.tz.clone())),
+                    )),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to convert_timezone to be timestamp, got {}",
+                        input_field.dtype
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input] => input.dt_convert_timezone(&self.tz),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+/// Replaces the timezone of a timestamp, keeping its wall clock time.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ReplaceTimezone {
+    pub(super) tz: Option<String>,
+}
+
+#[typetag::serde]
+impl ScalarUDF for ReplaceTimezone {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "replace_timezone"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                if let Some(tz) = &self.tz {
+                    ParsedTimezone::parse(tz)?;
+                }
+                match input_field.dtype {
+                    DataType::Timestamp(tu, _) => Ok(Field::new(
+                        input_field.name,
+                        DataType::Timestamp(tu, self.tz.clone()),
+                    )),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to replace_timezone to be timestamp, got {}",
+                        input_field.dtype
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input] => input.dt_replace_timezone(self.tz.as_deref()),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+pub fn dt_convert_timezone<S: Into<String>>(input: ExprRef, tz: S) -> ExprRef {
+    ScalarFunction::new(ConvertTimezone { tz: tz.into() }, vec![input]).into()
+}
+
+pub fn dt_replace_timezone<S: Into<String>>(input: ExprRef, tz: Option<S>) -> ExprRef {
+    ScalarFunction::new(
+        ReplaceTimezone {
+            tz: tz.map(Into::into),
+        },
+        vec![input],
+    )
+    .into()
+}
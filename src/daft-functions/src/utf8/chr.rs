@@ -0,0 +1,74 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{AsArrow, DataType, Field, Schema, Utf8Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+/// Returns the character with the code point of an integer modulo 256, or an empty string for
+/// negative integers.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Utf8Chr {}
+
+#[typetag::serde]
+impl ScalarUDF for Utf8Chr {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn name(&self) -> &'static str {
+        "chr"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => match data.to_field(schema) {
+                Ok(data_field) if data_field.dtype.is_integer() => {
+                    Ok(Field::new(data_field.name, DataType::Utf8))
+                }
+                Ok(data_field) => Err(DaftError::TypeError(format!(
+                    "Expects input to chr to be an integer, but received {data_field}",
+                ))),
+                Err(e) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data] => {
+                let data = data.cast(&DataType::Int64)?;
+                let arrow_result = data
+                    .i64()?
+                    .as_arrow()
+                    .iter()
+                    .map(|val| {
+                        let val = *val?;
+                        Some(if val < 0 {
+                            String::new()
+                        } else {
+                            char::from((val % 256) as u8).to_string()
+                        })
+                    })
+                    .collect::<arrow2::array::Utf8Array<i64>>();
+                Ok(Utf8Array::from((data.name(), Box::new(arrow_result))).into_series())
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[must_use]
+pub fn utf8_chr(input: ExprRef) -> ExprRef {
+    ScalarFunction::new(Utf8Chr {}, vec![input]).into()
+}
@@ -0,0 +1,62 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{DataType, Field, Schema},
+    series::Series,
+};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Utf8Levenshtein {}
+
+#[typetag::serde]
+impl ScalarUDF for Utf8Levenshtein {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn name(&self) -> &'static str {
+        "levenshtein"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data, other] => {
+                match (data.to_field(schema), other.to_field(schema)) {
+                    (Ok(data_field), Ok(other_field)) => {
+                        match (&data_field.dtype, &other_field.dtype) {
+                        (DataType::Utf8, DataType::Utf8) => {
+                            Ok(Field::new(data_field.name, DataType::UInt64))
+                        }
+                        _ => Err(DaftError::TypeError(format!(
+                            "Expects inputs to levenshtein to be utf8 and utf8, but received {data_field} and {other_field}",
+                        ))),
+                    }
+                    }
+                    (Err(e), _) | (_, Err(e)) => Err(e),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data, other] => data.utf8_levenshtein(other),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[must_use]
+pub fn utf8_levenshtein(input: ExprRef, other: ExprRef) -> ExprRef {
+    ScalarFunction::new(Utf8Levenshtein {}, vec![input, other]).into()
+}
@@ -1,57 +1,79 @@
+mod ascii;
 mod capitalize;
+mod chr;
 mod contains;
 mod endswith;
 mod extract;
 mod extract_all;
 mod find;
 mod ilike;
+mod initcap;
 mod left;
 mod length;
 mod length_bytes;
+mod levenshtein;
 mod like;
 mod lower;
 mod lpad;
 mod lstrip;
 mod match_;
 mod normalize;
+mod position;
 mod repeat;
 mod replace;
 mod reverse;
 mod right;
 mod rpad;
 mod rstrip;
+mod soundex;
 mod split;
 mod startswith;
 mod substr;
 mod to_date;
 mod to_datetime;
+mod translate;
+mod trim;
 mod upper;
+mod url_decode;
+mod url_encode;
 
+pub use ascii::{utf8_ascii as ascii, Utf8Ascii};
 pub use capitalize::{utf8_capitalize as capitalize, Utf8Capitalize};
+pub use chr::{utf8_chr as chr, Utf8Chr};
 pub use contains::{utf8_contains as contains, Utf8Contains};
 pub use endswith::{utf8_endswith as endswith, Utf8Endswith};
 pub use extract::{utf8_extract as extract, Utf8Extract};
 pub use extract_all::{utf8_extract_all as extract_all, Utf8ExtractAll};
 pub use find::{utf8_find as find, Utf8Find};
 pub use ilike::{utf8_ilike as ilike, Utf8Ilike};
+pub use initcap::{utf8_initcap as initcap, Utf8Initcap};
 pub use left::{utf8_left as left, Utf8Left};
 pub use length::{utf8_length as length, Utf8Length};
 pub use length_bytes::{utf8_length_bytes as length_bytes, Utf8LengthBytes};
+pub use levenshtein::{utf8_levenshtein as levenshtein, Utf8Levenshtein};
 pub use like::{utf8_like as like, Utf8Like};
 pub use lower::{utf8_lower as lower, Utf8Lower};
 pub use lpad::{utf8_lpad as lpad, Utf8Lpad};
 pub use lstrip::{utf8_lstrip as lstrip, Utf8Lstrip};
 pub use match_::{utf8_match as match_, Utf8Match};
 pub use normalize::{utf8_normalize as normalize, Utf8Normalize};
+pub use position::{utf8_position as position, Utf8Position};
 pub use repeat::{utf8_repeat as repeat, Utf8Repeat};
 pub use replace::{utf8_replace as replace, Utf8Replace};
 pub use reverse::{utf8_reverse as reverse, Utf8Reverse};
 pub use right::{utf8_right as right, Utf8Right};
 pub use rpad::{utf8_rpad as rpad, Utf8Rpad};
 pub use rstrip::{utf8_rstrip as rstrip, Utf8Rstrip};
+pub use soundex::{utf8_soundex as soundex, Utf8Soundex};
 pub use split::{utf8_split as split, Utf8Split};
 pub use startswith::{utf8_startswith as startswith, Utf8Startswith};
 pub use substr::{utf8_substr as substr, Utf8Substr};
-pub use to_date::{utf8_to_date as to_date, Utf8ToDate};
-pub use to_datetime::{utf8_to_datetime as to_datetime, Utf8ToDatetime};
+pub use to_date::{utf8_to_date as to_date, utf8_try_to_date as try_to_date, Utf8ToDate};
+pub use to_datetime::{
+    utf8_to_datetime as to_datetime, utf8_try_to_datetime as try_to_datetime, Utf8ToDatetime,
+};
+pub use translate::{utf8_translate as translate, Utf8Translate};
+pub use trim::{utf8_trim as trim, Utf8Trim};
 pub use upper::{utf8_upper as upper, Utf8Upper};
+pub use url_decode::{utf8_url_decode as url_decode, Utf8UrlDecode};
+pub use url_encode::{utf8_url_encode as url_encode, Utf8UrlEncode};
@@ -0,0 +1,62 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{DataType, Field, Schema},
+    series::Series,
+};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+/// Returns the one-based character position of the first occurrence of a substring, or 0 if it does
+/// not occur, like SQL's `POSITION(substr IN string)`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Utf8Position {}
+
+#[typetag::serde]
+impl ScalarUDF for Utf8Position {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn name(&self) -> &'static str {
+        "position"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data, substr] => match (data.to_field(schema), substr.to_field(schema)) {
+                (Ok(data_field), Ok(substr_field)) => {
+                    match (&data_field.dtype, &substr_field.dtype) {
+                        (DataType::Utf8, DataType::Utf8) => {
+                            Ok(Field::new(data_field.name, DataType::Int64))
+                        }
+                        _ => Err(DaftError::TypeError(format!(
+                            "Expects inputs to position to be utf8 and utf8, but received {data_field} and {substr_field}",
+                        ))),
+                    }
+                }
+                (Err(e), _) | (_, Err(e)) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data, substr] => data.utf8_position(substr),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[must_use]
+pub fn utf8_position(input: ExprRef, substr: ExprRef) -> ExprRef {
+    ScalarFunction::new(Utf8Position {}, vec![input, substr]).into()
+}
@@ -12,6 +12,8 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct Utf8ToDate {
     pub format: String,
+    /// Whether strings which don't match the format are an error, rather than null.
+    pub raise_error_on_failure: bool,
 }
 
 #[typetag::serde]
@@ -43,7 +45,8 @@ impl ScalarUDF for Utf8ToDate {
 
     fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
         match inputs {
-            [data] => data.utf8_to_date(&self.format),
+            [data] if self.raise_error_on_failure => data.utf8_to_date(&self.format),
+            [data] => data.utf8_try_to_date(&self.format),
             _ => Err(DaftError::ValueError(format!(
                 "Expected 1 input args, got {}",
                 inputs.len()
@@ -57,6 +60,20 @@ pub fn utf8_to_date<S: Into<String>>(input: ExprRef, format: S) -> ExprRef {
     ScalarFunction::new(
         Utf8ToDate {
             format: format.into(),
+            raise_error_on_failure: true,
+        },
+        vec![input],
+    )
+    .into()
+}
+
+/// Like [`utf8_to_date`], but strings which don't match the format are parsed as null.
+#[must_use]
+pub fn utf8_try_to_date<S: Into<String>>(input: ExprRef, format: S) -> ExprRef {
+    ScalarFunction::new(
+        Utf8ToDate {
+            format: format.into(),
+            raise_error_on_failure: false,
         },
         vec![input],
     )
@@ -14,6 +14,8 @@ use serde::{Deserialize, Serialize};
 pub struct Utf8ToDatetime {
     pub format: String,
     pub timezone: Option<String>,
+    /// Whether strings which don't match the format are an error, rather than null.
+    pub raise_error_on_failure: bool,
 }
 
 #[typetag::serde]
@@ -51,7 +53,10 @@ impl ScalarUDF for Utf8ToDatetime {
 
     fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
         match inputs {
-            [data] => data.utf8_to_datetime(&self.format, self.timezone.as_deref()),
+            [data] if self.raise_error_on_failure => {
+                data.utf8_to_datetime(&self.format, self.timezone.as_deref())
+            }
+            [data] => data.utf8_try_to_datetime(&self.format, self.timezone.as_deref()),
             _ => Err(DaftError::SchemaMismatch(format!(
                 "Expected 1 input args, got {}",
                 inputs.len()
@@ -70,6 +75,25 @@ pub fn utf8_to_datetime<S: Into<String>>(
         Utf8ToDatetime {
             format: format.into(),
             timezone: timezone.map(|s| s.into()),
+            raise_error_on_failure: true,
+        },
+        vec![input],
+    )
+    .into()
+}
+
+/// Like [`utf8_to_datetime`], but strings which don't match the format are parsed as null.
+#[must_use]
+pub fn utf8_try_to_datetime<S: Into<String>>(
+    input: ExprRef,
+    format: S,
+    timezone: Option<S>,
+) -> ExprRef {
+    ScalarFunction::new(
+        Utf8ToDatetime {
+            format: format.into(),
+            timezone: timezone.map(|s| s.into()),
+            raise_error_on_failure: false,
         },
         vec![input],
     )
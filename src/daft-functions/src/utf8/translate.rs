@@ -0,0 +1,62 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{DataType, Field, Schema},
+    series::Series,
+};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+/// Replaces each character in `matching` with the character at the same position in `replace`,
+/// removing the characters without a counterpart.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Utf8Translate {}
+
+#[typetag::serde]
+impl ScalarUDF for Utf8Translate {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn name(&self) -> &'static str {
+        "translate"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data, matching, replace] => {
+                let data_field = data.to_field(schema)?;
+                let matching_field = matching.to_field(schema)?;
+                let replace_field = replace.to_field(schema)?;
+                match (&data_field.dtype, &matching_field.dtype, &replace_field.dtype) {
+                    (DataType::Utf8, DataType::Utf8, DataType::Utf8) => {
+                        Ok(Field::new(data_field.name, DataType::Utf8))
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expects inputs to translate to be utf8, utf8 and utf8, but received {data_field}, {matching_field} and {replace_field}",
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 3 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data, matching, replace] => data.utf8_translate(matching, replace),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 3 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[must_use]
+pub fn utf8_translate(input: ExprRef, matching: ExprRef, replace: ExprRef) -> ExprRef {
+    ScalarFunction::new(Utf8Translate {}, vec![input, matching, replace]).into()
+}
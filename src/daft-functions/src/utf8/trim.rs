@@ -0,0 +1,67 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{DataType, Field, Schema},
+    series::Series,
+};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+/// Strips any of a set of characters from the start and/or end of a string, as in SQL's
+/// `TRIM([BOTH | LEADING | TRAILING] chars FROM input)`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Utf8Trim {
+    pub leading: bool,
+    pub trailing: bool,
+}
+
+#[typetag::serde]
+impl ScalarUDF for Utf8Trim {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn name(&self) -> &'static str {
+        "trim"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data, chars] => {
+                match (data.to_field(schema), chars.to_field(schema)) {
+                    (Ok(data_field), Ok(chars_field)) => {
+                        match (&data_field.dtype, &chars_field.dtype) {
+                        (DataType::Utf8, DataType::Utf8) => {
+                            Ok(Field::new(data_field.name, DataType::Utf8))
+                        }
+                        _ => Err(DaftError::TypeError(format!(
+                            "Expects inputs to trim to be utf8 and utf8, but received {data_field} and {chars_field}",
+                        ))),
+                    }
+                    }
+                    (Err(e), _) | (_, Err(e)) => Err(e),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data, chars] => data.utf8_strip_chars(chars, self.leading, self.trailing),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[must_use]
+pub fn utf8_trim(input: ExprRef, chars: ExprRef, leading: bool, trailing: bool) -> ExprRef {
+    ScalarFunction::new(Utf8Trim { leading, trailing }, vec![input, chars]).into()
+}
@@ -134,6 +134,7 @@ impl LocalSource {
 pub struct LocalFile {
     pub path: PathBuf,
     pub range: Option<Range<usize>>,
+    pub io_stats: Option<IOStatsRef>,
 }
 
 #[async_trait]
@@ -142,13 +143,14 @@ impl ObjectSource for LocalSource {
         &self,
         uri: &str,
         range: Option<Range<usize>>,
-        _io_stats: Option<IOStatsRef>,
+        io_stats: Option<IOStatsRef>,
     ) -> super::Result<GetResult> {
         const LOCAL_PROTOCOL: &str = "file://";
         if let Some(uri) = uri.strip_prefix(LOCAL_PROTOCOL) {
             Ok(GetResult::File(LocalFile {
                 path: uri.into(),
                 range,
+                io_stats,
             }))
         } else {
             Err(Error::InvalidFilePath { path: uri.into() }.into())
@@ -372,6 +374,10 @@ pub async fn collect_file(local_file: LocalFile) -> Result<Bytes> {
                 })?;
         }
     }
+    if let Some(io_stats) = &local_file.io_stats {
+        io_stats.mark_get_requests(1);
+        io_stats.mark_bytes_read(buf.len());
+    }
     Ok(Bytes::from(buf))
 }
 
@@ -0,0 +1,48 @@
+use common_error::DaftError;
+use snafu::Snafu;
+
+pub mod read;
+
+pub use read::{read_ipc, read_ipc_bulk, read_ipc_schema, stream_ipc};
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("{source}"))]
+    IOError { source: daft_io::Error },
+
+    #[snafu(display("Unable to read Arrow IPC data from {}: {}", path, source))]
+    UnableToReadIpc {
+        path: String,
+        source: arrow2::error::Error,
+    },
+
+    #[snafu(display(
+        "Arrow IPC file {} has an invalid footer length of {} for a file of {} bytes",
+        path,
+        footer_len,
+        file_size
+    ))]
+    InvalidIpcFooter {
+        path: String,
+        footer_len: i32,
+        file_size: usize,
+    },
+
+    #[snafu(display("Error joining spawned task: {}", source))]
+    JoinError { source: tokio::task::JoinError },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::IOError { source } => source.into(),
+            _ => Self::External(err.into()),
+        }
+    }
+}
+
+impl From<daft_io::Error> for Error {
+    fn from(err: daft_io::Error) -> Self {
+        Self::IOError { source: err }
+    }
+}
@@ -0,0 +1,685 @@
+use std::{
+    io::{Cursor, Read, Seek, SeekFrom},
+    ops::Range,
+    sync::Arc,
+};
+
+use arrow2::{
+    array::Array,
+    chunk::Chunk,
+    datatypes::Schema as ArrowSchema,
+    io::ipc::read::{
+        batch_message_range, batch_ranges, deserialize_footer, dictionary_ranges, read_batch,
+        read_file_dictionaries, read_stream_metadata, Dictionaries, FileMetadata, StreamReader,
+        StreamState,
+    },
+};
+use bytes::Bytes;
+use common_error::DaftResult;
+use common_runtime::get_io_runtime;
+use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
+use daft_dsl::{optimization::get_required_columns, ExprRef};
+use daft_io::{IOClient, IOStatsRef};
+use daft_recordbatch::RecordBatch;
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use snafu::{futures::TryFutureExt, ResultExt};
+
+use crate::{InvalidIpcFooterSnafu, JoinSnafu, UnableToReadIpcSnafu};
+
+/// Magic bytes at the start and end of an Arrow IPC file. IPC streams do not start with them.
+const ARROW_MAGIC: &[u8; 6] = b"ARROW1";
+
+/// An IPC file ends with its footer, followed by the footer length (4 bytes) and the magic bytes.
+const FOOTER_TRAILER_LEN: usize = 4 + ARROW_MAGIC.len();
+
+/// Ranges of an IPC file which are this close are fetched in a single request, as in the parquet reader.
+const MAX_HOLE_SIZE: u64 = 1024 * 1024;
+
+type ChunkStream = BoxStream<'static, DaftResult<Chunk<Box<dyn Array>>>>;
+
+type ChunkIter = Box<dyn Iterator<Item = arrow2::error::Result<Chunk<Box<dyn Array>>>> + Send>;
+
+/// Returns whether the object at `uri` is an Arrow IPC file, rather than an IPC stream.
+async fn is_ipc_file(
+    uri: &str,
+    size: usize,
+    io_client: &IOClient,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<bool> {
+    let head = io_client
+        .single_url_get(
+            uri.to_string(),
+            Some(0..ARROW_MAGIC.len().min(size)),
+            io_stats,
+        )
+        .await?
+        .bytes()
+        .await?;
+    Ok(head.starts_with(ARROW_MAGIC) && size >= FOOTER_TRAILER_LEN)
+}
+
+/// Reads the metadata in the footer of an Arrow IPC file of `size` bytes.
+async fn read_ipc_file_metadata(
+    uri: &str,
+    size: usize,
+    io_client: &IOClient,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<FileMetadata> {
+    let trailer = io_client
+        .single_url_get(
+            uri.to_string(),
+            Some(size - FOOTER_TRAILER_LEN..size),
+            io_stats.clone(),
+        )
+        .await?
+        .bytes()
+        .await?;
+    let footer_len = i32::from_le_bytes(trailer[..4].try_into().unwrap());
+    let footer_end = size - FOOTER_TRAILER_LEN;
+    let footer_start = usize::try_from(footer_len)
+        .ok()
+        .and_then(|footer_len| footer_end.checked_sub(footer_len))
+        .ok_or_else(|| {
+            InvalidIpcFooterSnafu {
+                path: uri.to_string(),
+                footer_len,
+                file_size: size,
+            }
+            .build()
+        })?;
+    let footer = io_client
+        .single_url_get(uri.to_string(), Some(footer_start..footer_end), io_stats)
+        .await?
+        .bytes()
+        .await?;
+    Ok(deserialize_footer(&footer, size as u64).context(UnableToReadIpcSnafu { path: uri })?)
+}
+
+/// Reads the schema of an Arrow IPC file or stream.
+///
+/// For IPC files only the footer is fetched. IPC streams hold their schema in their first message, and are fetched whole.
+pub async fn read_ipc_schema(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Schema> {
+    let size = io_client
+        .single_url_get_size(uri.to_string(), io_stats.clone())
+        .await?;
+
+    let arrow_schema = if is_ipc_file(uri, size, &io_client, io_stats.clone()).await? {
+        read_ipc_file_metadata(uri, size, &io_client, io_stats)
+            .await?
+            .schema
+    } else {
+        let data = io_client
+            .single_url_get(uri.to_string(), None, io_stats)
+            .await?
+            .bytes()
+            .await?;
+        read_stream_metadata(&mut Cursor::new(data.as_ref()))
+            .context(UnableToReadIpcSnafu { path: uri })?
+            .schema
+    };
+    Schema::try_from(&arrow_schema)
+}
+
+/// Returns the indices of the given columns in `schema`, in the order of the schema.
+fn projection_indices(schema: &ArrowSchema, columns: &[String]) -> Vec<usize> {
+    schema
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| columns.contains(&field.name))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+fn project_arrow_schema(schema: &ArrowSchema, projection: Option<&[usize]>) -> ArrowSchema {
+    match projection {
+        Some(projection) => ArrowSchema {
+            fields: projection
+                .iter()
+                .map(|&i| schema.fields[i].clone())
+                .collect(),
+            metadata: schema.metadata.clone(),
+        },
+        None => schema.clone(),
+    }
+}
+
+/// The fetched ranges of an IPC file, read as if they were the whole file.
+///
+/// This lets arrow2 decode a record batch of which only the message and the buffers of the
+/// projected columns were fetched. Reading outside of those ranges is an error.
+///
+/// The file's bytes are untrusted, so they are decoded with the validating reader rather than
+/// memory mapped, which copies each buffer out of the fetched ranges once.
+struct FetchedRanges {
+    /// The start of each range and its bytes, sorted by start and not overlapping.
+    ranges: Vec<(u64, Bytes)>,
+    size: u64,
+    position: u64,
+}
+
+impl FetchedRanges {
+    fn new(mut ranges: Vec<(u64, Bytes)>, size: u64) -> Self {
+        ranges.sort_by_key(|(start, _)| *start);
+        Self {
+            ranges,
+            size,
+            position: 0,
+        }
+    }
+}
+
+impl Read for FetchedRanges {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let fetched = self
+            .ranges
+            .partition_point(|(start, _)| *start <= self.position)
+            .checked_sub(1)
+            .and_then(|i| {
+                let (start, data) = &self.ranges[i];
+                data.get((self.position - start) as usize..)
+            })
+            .filter(|data| !data.is_empty())
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    format!("byte {} of the IPC file was not fetched", self.position),
+                )
+            })?;
+        let len = fetched.len().min(buf.len());
+        buf[..len].copy_from_slice(&fetched[..len]);
+        self.position += len as u64;
+        Ok(len)
+    }
+}
+
+impl Seek for FetchedRanges {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.position = match pos {
+            SeekFrom::Start(position) => Some(position),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+            SeekFrom::End(offset) => self.size.checked_add_signed(offset),
+        }
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "seek to a negative position in the IPC file",
+            )
+        })?;
+        Ok(self.position)
+    }
+}
+
+/// Fetches the given ranges of a file, coalescing the ranges which are close to each other into
+/// single requests.
+async fn fetch_ranges(
+    uri: &str,
+    mut ranges: Vec<Range<u64>>,
+    io_client: &IOClient,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Vec<(u64, Bytes)>> {
+    ranges.retain(|range| range.end > range.start);
+    ranges.sort_by_key(|range| range.start);
+    let mut coalesced: Vec<Range<u64>> = vec![];
+    for range in ranges {
+        match coalesced.last_mut() {
+            Some(last) if range.start <= last.end + MAX_HOLE_SIZE => {
+                last.end = last.end.max(range.end);
+            }
+            _ => coalesced.push(range),
+        }
+    }
+    futures::future::try_join_all(coalesced.into_iter().map(|range| {
+        let io_stats = io_stats.clone();
+        async move {
+            let data = io_client
+                .single_url_get(
+                    uri.to_string(),
+                    Some(range.start as usize..range.end as usize),
+                    io_stats,
+                )
+                .await?
+                .bytes()
+                .await?;
+            Ok((range.start, data))
+        }
+    }))
+    .await
+}
+
+/// Fetches and decodes the `projection` of the record batch at `index` of an IPC file.
+///
+/// Only the batch's message and the buffers of the projected columns are fetched.
+async fn read_ipc_file_batch(
+    uri: &str,
+    metadata: &FileMetadata,
+    dictionaries: &Dictionaries,
+    projection: Option<&[usize]>,
+    index: usize,
+    io_client: &IOClient,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Chunk<Box<dyn Array>>> {
+    let message_range =
+        batch_message_range(metadata, index).context(UnableToReadIpcSnafu { path: uri })?;
+    let message = io_client
+        .single_url_get(
+            uri.to_string(),
+            Some(message_range.start as usize..message_range.end as usize),
+            io_stats.clone(),
+        )
+        .await?
+        .bytes()
+        .await?;
+    let ranges = batch_ranges(metadata, projection, index, &message)
+        .context(UnableToReadIpcSnafu { path: uri })?;
+    let mut fetched = fetch_ranges(uri, ranges, io_client, io_stats).await?;
+    fetched.push((message_range.start, message));
+
+    let mut reader = FetchedRanges::new(fetched, metadata.size);
+    Ok(read_batch(
+        &mut reader,
+        dictionaries,
+        metadata,
+        projection,
+        None,
+        index,
+        &mut vec![],
+        &mut vec![],
+    )
+    .context(UnableToReadIpcSnafu { path: uri })?)
+}
+
+/// Streams the record batches of an Arrow IPC file, decoding only the requested columns.
+///
+/// Rather than the whole file, only its dictionaries and the message and projected buffers of each
+/// record batch are fetched, one record batch at a time.
+async fn stream_ipc_file(
+    uri: String,
+    metadata: FileMetadata,
+    columns: Option<&[String]>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<(ArrowSchema, ChunkStream)> {
+    let projection = columns.map(|columns| projection_indices(&metadata.schema, columns));
+    let schema = project_arrow_schema(&metadata.schema, projection.as_deref());
+
+    let ranges =
+        dictionary_ranges(&metadata).context(UnableToReadIpcSnafu { path: uri.clone() })?;
+    let fetched = fetch_ranges(&uri, ranges, &io_client, io_stats.clone()).await?;
+    let dictionaries = read_file_dictionaries(
+        &mut FetchedRanges::new(fetched, metadata.size),
+        &metadata,
+        &mut vec![],
+    )
+    .context(UnableToReadIpcSnafu { path: uri.clone() })?;
+
+    let (metadata, dictionaries) = (Arc::new(metadata), Arc::new(dictionaries));
+    let chunks = futures::stream::iter(0..metadata.blocks.len()).then(move |index| {
+        let (uri, metadata, dictionaries, projection, io_client, io_stats) = (
+            uri.clone(),
+            metadata.clone(),
+            dictionaries.clone(),
+            projection.clone(),
+            io_client.clone(),
+            io_stats.clone(),
+        );
+        async move {
+            read_ipc_file_batch(
+                &uri,
+                &metadata,
+                &dictionaries,
+                projection.as_deref(),
+                index,
+                &io_client,
+                io_stats,
+            )
+            .await
+        }
+    });
+    Ok((schema, chunks.boxed()))
+}
+
+/// Decodes the record batches of an Arrow IPC stream held in memory.
+fn decode_ipc_stream(
+    data: Bytes,
+    columns: Option<&[String]>,
+) -> arrow2::error::Result<(ArrowSchema, ChunkIter)> {
+    let mut reader = Cursor::new(data);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let projection = columns.map(|columns| projection_indices(&metadata.schema, columns));
+    let schema = project_arrow_schema(&metadata.schema, projection.as_deref());
+    let chunks = StreamReader::new(reader, metadata, projection).map_while(|state| match state {
+        Ok(StreamState::Some(chunk)) => Some(Ok(chunk)),
+        // The whole stream is in memory, so waiting for more data means that it has ended.
+        Ok(StreamState::Waiting) => None,
+        Err(err) => Some(Err(err)),
+    });
+    Ok((schema, Box::new(chunks)))
+}
+
+fn chunk_to_table(chunk: Chunk<Box<dyn Array>>, schema: &SchemaRef) -> DaftResult<RecordBatch> {
+    let num_rows = chunk.len();
+    let columns = schema
+        .fields
+        .values()
+        .zip(chunk.into_arrays())
+        .map(|(field, array)| {
+            Series::try_from_field_and_arrow_array(
+                field.clone(),
+                cast_array_for_daft_if_needed(array),
+            )
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    RecordBatch::new_with_size(schema.clone(), columns, num_rows)
+}
+
+/// Streams the tables of a single Arrow IPC file or stream, decoding only the requested columns.
+///
+/// Returns the stream along with the schema of its tables.
+async fn stream_ipc_single(
+    uri: String,
+    columns: Option<Vec<String>>,
+    limit: Option<usize>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<(BoxStream<'static, DaftResult<RecordBatch>>, SchemaRef)> {
+    // The columns required by the predicate are also read, and are dropped after filtering.
+    let read_columns = columns.clone().map(|mut columns| {
+        if let Some(predicate) = &predicate {
+            for column in get_required_columns(predicate) {
+                if !columns.contains(&column) {
+                    columns.push(column);
+                }
+            }
+        }
+        columns
+    });
+
+    let size = io_client
+        .single_url_get_size(uri.clone(), io_stats.clone())
+        .await?;
+    let (arrow_schema, chunks) = if is_ipc_file(&uri, size, &io_client, io_stats.clone()).await? {
+        let metadata = read_ipc_file_metadata(&uri, size, &io_client, io_stats.clone()).await?;
+        stream_ipc_file(
+            uri.clone(),
+            metadata,
+            read_columns.as_deref(),
+            io_client,
+            io_stats,
+        )
+        .await?
+    } else {
+        // IPC streams have no footer to locate their record batches, so they are fetched whole.
+        let data = io_client
+            .single_url_get(uri.clone(), None, io_stats)
+            .await?
+            .bytes()
+            .await?;
+        let (arrow_schema, chunks) = decode_ipc_stream(data, read_columns.as_deref())
+            .context(UnableToReadIpcSnafu { path: uri.clone() })?;
+        let path = uri.clone();
+        let chunks = futures::stream::iter(chunks)
+            .map(move |chunk| Ok(chunk.context(UnableToReadIpcSnafu { path: path.clone() })?));
+        (arrow_schema, chunks.boxed())
+    };
+
+    let read_schema = Arc::new(Schema::try_from(&arrow_schema)?);
+    let output_schema = match &columns {
+        Some(columns) => Arc::new(Schema::new(
+            read_schema
+                .fields
+                .values()
+                .filter(|field| columns.contains(&field.name))
+                .cloned()
+                .collect(),
+        )?),
+        None => read_schema.clone(),
+    };
+
+    let tables = chunks.map(move |chunk| chunk_to_table(chunk?, &read_schema));
+
+    let output_columns = columns.map(|_| output_schema.names());
+    let filtered = tables.map(move |table| {
+        let table = table?;
+        match (&predicate, &output_columns) {
+            (Some(predicate), Some(output_columns)) => table
+                .filter(&[predicate.clone()])?
+                .get_columns(output_columns.as_slice()),
+            (Some(predicate), None) => table.filter(&[predicate.clone()]),
+            (None, _) => Ok(table),
+        }
+    });
+
+    let limited = filtered.scan(limit, |remaining, table| {
+        let table = match (table, *remaining) {
+            // The limit has been met, so the stream is terminated.
+            (_, Some(0)) => None,
+            (Ok(table), Some(rows_left)) => {
+                let table = if table.len() > rows_left {
+                    table.head(rows_left)
+                } else {
+                    Ok(table)
+                };
+                if let Ok(table) = &table {
+                    *remaining = Some(rows_left - table.len());
+                }
+                Some(table)
+            }
+            (table, None) | (table @ Err(_), _) => Some(table),
+        };
+        futures::future::ready(table)
+    });
+
+    Ok((limited.boxed(), output_schema))
+}
+
+async fn read_ipc_single_into_table(
+    uri: &str,
+    columns: Option<Vec<String>>,
+    limit: Option<usize>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<RecordBatch> {
+    let (stream, schema) = stream_ipc_single(
+        uri.to_string(),
+        columns,
+        limit,
+        predicate,
+        io_client,
+        io_stats,
+    )
+    .await?;
+    let tables = stream.try_collect::<Vec<_>>().await?;
+    if tables.is_empty() {
+        RecordBatch::empty(Some(schema))
+    } else {
+        RecordBatch::concat(tables.as_slice())
+    }
+}
+
+pub fn read_ipc(
+    uri: &str,
+    columns: Option<&[&str]>,
+    limit: Option<usize>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+) -> DaftResult<RecordBatch> {
+    let runtime_handle = get_io_runtime(multithreaded_io);
+    let columns = columns.map(|s| s.iter().map(|v| (*v).to_string()).collect::<Vec<_>>());
+    runtime_handle.block_on_current_thread(async {
+        read_ipc_single_into_table(uri, columns, limit, predicate, io_client, io_stats).await
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn read_ipc_bulk(
+    uris: &[&str],
+    columns: Option<&[&str]>,
+    limit: Option<usize>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    num_parallel_tasks: usize,
+    multithreaded_io: bool,
+) -> DaftResult<Vec<RecordBatch>> {
+    let runtime_handle = get_io_runtime(multithreaded_io);
+    let columns = columns.map(|s| s.iter().map(|v| (*v).to_string()).collect::<Vec<_>>());
+    let tables = runtime_handle.block_on_current_thread(async move {
+        // Launch a read task per URI, throttling the number of concurrent file reads to num_parallel tasks.
+        let task_stream = futures::stream::iter(uris.iter().map(|uri| {
+            let (uri, columns, predicate, io_client, io_stats) = (
+                (*uri).to_string(),
+                columns.clone(),
+                predicate.clone(),
+                io_client.clone(),
+                io_stats.clone(),
+            );
+            tokio::task::spawn(async move {
+                read_ipc_single_into_table(
+                    uri.as_str(),
+                    columns,
+                    limit,
+                    predicate,
+                    io_client,
+                    io_stats,
+                )
+                .await
+            })
+            .context(JoinSnafu)
+        }));
+        let mut remaining_rows = limit.map(|limit| limit as i64);
+        task_stream
+            // Limit the number of file reads we have in flight at any given time.
+            .buffered(num_parallel_tasks)
+            // Terminate the stream if we have already reached the row limit. With the upstream buffering, we will still read up to
+            // num_parallel_tasks redundant files.
+            .try_take_while(|result| {
+                match (result, remaining_rows) {
+                    // Limit has been met, early-terminate.
+                    (_, Some(rows_left)) if rows_left <= 0 => futures::future::ready(Ok(false)),
+                    // Limit has not yet been met, update remaining limit slack and continue.
+                    (Ok(table), Some(rows_left)) => {
+                        remaining_rows = Some(rows_left - table.len() as i64);
+                        futures::future::ready(Ok(true))
+                    }
+                    // (1) No limit, never early-terminate.
+                    // (2) Encountered error, propagate error to try_collect to allow it to short-circuit.
+                    (_, None) | (Err(_), _) => futures::future::ready(Ok(true)),
+                }
+            })
+            .try_collect::<Vec<_>>()
+            .await
+    })?;
+    tables.into_iter().collect::<DaftResult<Vec<_>>>()
+}
+
+pub async fn stream_ipc(
+    uri: &str,
+    columns: Option<&[&str]>,
+    limit: Option<usize>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<BoxStream<'static, DaftResult<RecordBatch>>> {
+    let columns = columns.map(|s| s.iter().map(|v| (*v).to_string()).collect::<Vec<_>>());
+    let (stream, _) = stream_ipc_single(
+        uri.to_string(),
+        columns,
+        limit,
+        predicate,
+        io_client,
+        io_stats,
+    )
+    .await?;
+    Ok(stream)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow2::{
+        array::{Int64Array, Utf8Array},
+        chunk::Chunk,
+        datatypes::{DataType, Field, Schema},
+        io::ipc::write::{FileWriter, WriteOptions},
+    };
+    use common_error::DaftResult;
+    use daft_io::{IOClient, IOConfig, IOStatsContext};
+
+    use super::read_ipc;
+
+    const ROWS_PER_BATCH: i64 = 20_000;
+
+    /// Writes an IPC file of three record batches, whose string column is much larger than its
+    /// integer column.
+    fn write_ipc_file(path: &std::path::Path) {
+        let schema = Schema::from(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("text", DataType::LargeUtf8, false),
+        ]);
+        let file = std::fs::File::create(path).unwrap();
+        let mut writer =
+            FileWriter::try_new(file, schema, None, WriteOptions { compression: None }).unwrap();
+        for batch in 0..3 {
+            let ids = (batch * ROWS_PER_BATCH..(batch + 1) * ROWS_PER_BATCH).collect::<Vec<_>>();
+            let texts = ids
+                .iter()
+                .map(|id| format!("{id:0>64}"))
+                .collect::<Vec<_>>();
+            let chunk = Chunk::new(vec![
+                Int64Array::from_vec(ids).boxed(),
+                Utf8Array::<i64>::from_slice(texts).boxed(),
+            ]);
+            writer.write(&chunk, None).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    #[test]
+    fn test_read_ipc_only_fetches_projected_columns() -> DaftResult<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("batches.arrow");
+        write_ipc_file(&path);
+        let uri = path.to_str().unwrap();
+        let file_size = std::fs::metadata(&path).unwrap().len() as usize;
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let io_stats = IOStatsContext::new("read_ipc");
+        let table = read_ipc(
+            uri,
+            Some(&["id"]),
+            None,
+            None,
+            io_client.clone(),
+            Some(io_stats.clone()),
+            true,
+        )?;
+        assert_eq!(table.len(), 3 * ROWS_PER_BATCH as usize);
+        assert_eq!(table.column_names(), vec!["id"]);
+        // The ids take 8 of the 72 bytes of each row.
+        assert!(io_stats.load_bytes_read() < file_size / 4);
+
+        let table = read_ipc(uri, None, None, None, io_client, None, true)?;
+        assert_eq!(table.len(), 3 * ROWS_PER_BATCH as usize);
+        assert_eq!(
+            table
+                .get_column("text")?
+                .utf8()?
+                .get(ROWS_PER_BATCH as usize),
+            Some(format!("{ROWS_PER_BATCH:0>64}").as_str())
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::{join::AsofJoinDirection, prelude::SchemaRef};
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+use itertools::Itertools;
+use tracing::{instrument, Span};
+
+use super::intermediate_op::{
+    IntermediateOpExecuteResult, IntermediateOpState, IntermediateOperator,
+    IntermediateOperatorResult,
+};
+use crate::{state_bridge::BroadcastStateBridgeRef, ExecutionTaskSpawner};
+
+struct AsofJoinState {
+    bridge: BroadcastStateBridgeRef<RecordBatch>,
+}
+
+impl AsofJoinState {
+    fn new(bridge: BroadcastStateBridgeRef<RecordBatch>) -> Self {
+        Self { bridge }
+    }
+}
+
+impl IntermediateOpState for AsofJoinState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+struct AsofJoinParams {
+    left_by: Vec<ExprRef>,
+    right_by: Vec<ExprRef>,
+    left_on: ExprRef,
+    right_on: ExprRef,
+    direction: AsofJoinDirection,
+    tolerance: Option<ExprRef>,
+}
+
+/// Streams the left side of an ASOF join against the collected right side.
+pub struct AsofJoinOperator {
+    params: Arc<AsofJoinParams>,
+    right_schema: SchemaRef,
+    state_bridge: BroadcastStateBridgeRef<RecordBatch>,
+}
+
+impl AsofJoinOperator {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        left_by: Vec<ExprRef>,
+        right_by: Vec<ExprRef>,
+        left_on: ExprRef,
+        right_on: ExprRef,
+        direction: AsofJoinDirection,
+        tolerance: Option<ExprRef>,
+        right_schema: SchemaRef,
+        state_bridge: BroadcastStateBridgeRef<RecordBatch>,
+    ) -> Self {
+        Self {
+            params: Arc::new(AsofJoinParams {
+                left_by,
+                right_by,
+                left_on,
+                right_on,
+                direction,
+                tolerance,
+            }),
+            right_schema,
+            state_bridge,
+        }
+    }
+}
+
+impl IntermediateOperator for AsofJoinOperator {
+    #[instrument(skip_all, name = "AsofJoinOperator::execute")]
+    fn execute(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn IntermediateOpState>,
+        task_spawner: &ExecutionTaskSpawner,
+    ) -> IntermediateOpExecuteResult {
+        let params = self.params.clone();
+        let right_schema = self.right_schema.clone();
+
+        task_spawner
+            .spawn(
+                async move {
+                    let asof_join_state = state
+                        .as_any_mut()
+                        .downcast_mut::<AsofJoinState>()
+                        .expect("AsofJoinState should be used with AsofJoinOperator");
+
+                    // The right side was sorted by its keys when it was collected.
+                    let right = asof_join_state.bridge.get_state().await;
+                    let right = MicroPartition::new_loaded(
+                        right_schema,
+                        Arc::new(vec![right.as_ref().clone()]),
+                        None,
+                    );
+
+                    let output = input.asof_join(
+                        &right,
+                        &params.left_by,
+                        &params.right_by,
+                        &params.left_on,
+                        &params.right_on,
+                        params.direction,
+                        params.tolerance.as_ref(),
+                        true,
+                    )?;
+                    Ok((
+                        state,
+                        IntermediateOperatorResult::NeedMoreInput(Some(Arc::new(output))),
+                    ))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "AsofJoin"
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![format!("AsofJoin: Direction = {}", self.params.direction)];
+        if !self.params.left_by.is_empty() {
+            res.push(format!(
+                "Left by = {}",
+                self.params.left_by.iter().map(|e| e.to_string()).join(", ")
+            ));
+            res.push(format!(
+                "Right by = {}",
+                self.params
+                    .right_by
+                    .iter()
+                    .map(|e| e.to_string())
+                    .join(", ")
+            ));
+        }
+        res.push(format!("Left on = {}", self.params.left_on));
+        res.push(format!("Right on = {}", self.params.right_on));
+        if let Some(tolerance) = &self.params.tolerance {
+            res.push(format!("Tolerance = {tolerance}"));
+        }
+        res
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn IntermediateOpState>> {
+        Ok(Box::new(AsofJoinState::new(self.state_bridge.clone())))
+    }
+}
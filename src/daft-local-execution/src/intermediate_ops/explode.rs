@@ -14,12 +14,14 @@ use crate::ExecutionTaskSpawner;
 
 pub struct ExplodeOperator {
     to_explode: Arc<Vec<ExprRef>>,
+    index_column: Option<Arc<str>>,
 }
 
 impl ExplodeOperator {
-    pub fn new(to_explode: Vec<ExprRef>) -> Self {
+    pub fn new(to_explode: Vec<ExprRef>, index_column: Option<String>) -> Self {
         Self {
             to_explode: Arc::new(to_explode.into_iter().map(explode).collect()),
+            index_column: index_column.map(Into::into),
         }
     }
 }
@@ -33,10 +35,11 @@ impl IntermediateOperator for ExplodeOperator {
         task_spawner: &ExecutionTaskSpawner,
     ) -> IntermediateOpExecuteResult {
         let to_explode = self.to_explode.clone();
+        let index_column = self.index_column.clone();
         task_spawner
             .spawn(
                 async move {
-                    let out = input.explode(&to_explode)?;
+                    let out = input.explode(&to_explode, index_column.as_deref())?;
                     Ok((
                         state,
                         IntermediateOperatorResult::NeedMoreInput(Some(Arc::new(out))),
@@ -48,10 +51,14 @@ impl IntermediateOperator for ExplodeOperator {
     }
 
     fn multiline_display(&self) -> Vec<String> {
-        vec![format!(
+        let mut res = vec![format!(
             "Explode: {}",
             self.to_explode.iter().map(|e| e.to_string()).join(", ")
-        )]
+        )];
+        if let Some(index_column) = &self.index_column {
+            res.push(format!("Index column = {}", index_column));
+        }
+        res
     }
 
     fn name(&self) -> &'static str {
@@ -7,11 +7,13 @@ mod dispatcher;
 mod intermediate_ops;
 mod pipeline;
 mod progress_bar;
+mod recursive_union;
 mod resource_manager;
 mod run;
 mod runtime_stats;
 mod sinks;
 mod sources;
+mod spill;
 mod state_bridge;
 
 use std::{
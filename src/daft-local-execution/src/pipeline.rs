@@ -12,13 +12,14 @@ use common_file_formats::FileFormat;
 use daft_core::{join::JoinSide, prelude::Schema};
 use daft_dsl::{join::get_common_join_cols, resolved_col};
 use daft_local_plan::{
-    ActorPoolProject, Concat, CrossJoin, EmptyScan, Explode, Filter, HashAggregate, HashJoin,
-    InMemoryScan, Limit, LocalPhysicalPlan, MonotonicallyIncreasingId, PhysicalWrite, Pivot,
-    Project, Sample, Sort, UnGroupedAggregate, Unpivot,
+    ActorPoolProject, AsofJoin, Concat, CrossJoin, EmptyScan, Explode, Filter, HashAggregate,
+    HashJoin, InMemoryScan, Limit, LocalPhysicalPlan, MonotonicallyIncreasingId, NestedLoopJoin,
+    PhysicalWrite, Pivot, Project, RecursiveUnion, Sample, Sort, UnGroupedAggregate, Unpivot,
+    Window, WorkTableScan,
 };
 use daft_logical_plan::{stats::StatsState, JoinType};
 use daft_micropartition::{
-    partitioning::{MicroPartitionSet, PartitionSetCache},
+    partitioning::{MicroPartitionSet, PartitionCacheEntry, PartitionSetCache},
     MicroPartition, MicroPartitionRef,
 };
 use daft_scan::ScanTaskRef;
@@ -29,30 +30,37 @@ use snafu::ResultExt;
 use crate::{
     channel::Receiver,
     intermediate_ops::{
-        actor_pool_project::ActorPoolProjectOperator, cross_join::CrossJoinOperator,
-        explode::ExplodeOperator, filter::FilterOperator,
-        inner_hash_join_probe::InnerHashJoinProbeOperator, intermediate_op::IntermediateNode,
-        project::ProjectOperator, sample::SampleOperator, unpivot::UnpivotOperator,
+        actor_pool_project::ActorPoolProjectOperator, asof_join::AsofJoinOperator,
+        cross_join::CrossJoinOperator, explode::ExplodeOperator, filter::FilterOperator,
+        intermediate_op::IntermediateNode, project::ProjectOperator, sample::SampleOperator,
+        unpivot::UnpivotOperator,
     },
+    recursive_union::{CapturedPartitionSets, RecursiveUnionNode},
+    resource_manager::get_or_init_memory_manager,
     sinks::{
         aggregate::AggregateSink,
         anti_semi_hash_join_probe::AntiSemiProbeSink,
+        asof_join_build::AsofJoinBuildSink,
         blocking_sink::BlockingSinkNode,
         concat::ConcatSink,
         cross_join_collect::CrossJoinCollectSink,
         grouped_aggregate::GroupedAggregateSink,
         hash_join_build::HashJoinBuildSink,
+        inner_hash_join_probe::InnerHashJoinProbeSink,
         limit::LimitSink,
         monotonically_increasing_id::MonotonicallyIncreasingIdSink,
+        nested_loop_join::NestedLoopJoinSink,
+        nested_loop_join_collect::NestedLoopJoinCollectSink,
         outer_hash_join_probe::OuterHashJoinProbeSink,
         pivot::PivotSink,
         sort::SortSink,
         streaming_sink::StreamingSinkNode,
+        window::WindowSink,
         write::{WriteFormat, WriteSink},
     },
     sources::{empty_scan::EmptyScanSource, in_memory::InMemorySource, source::SourceNode},
     state_bridge::BroadcastStateBridge,
-    ExecutionRuntimeContext, PipelineCreationSnafu,
+    ExecutionRuntimeContext, PipelineCreationSnafu, NUM_CPUS,
 };
 
 pub(crate) trait PipelineNode: Sync + Send + TreeDisplay {
@@ -125,7 +133,17 @@ pub fn physical_plan_to_pipeline(
         LocalPhysicalPlan::InMemoryScan(InMemoryScan { info, stats_state }) => {
             let cache_key: Arc<str> = info.cache_key.clone().into();
 
-            let materialized_pset = psets.get_partition_set(&cache_key);
+            // Sources created in Rust, e.g. the results of SQL statements, hold their partition set in their cache entry.
+            let materialized_pset = psets.get_partition_set(&cache_key).or_else(|| {
+                let PartitionCacheEntry::Rust {
+                    value: Some(value), ..
+                } = &info.cache_entry
+                else {
+                    return None;
+                };
+                let pset = value.clone().downcast::<MicroPartitionSet>().ok()?;
+                Some(pset as _)
+            });
             let in_memory_source = InMemorySource::new(
                 materialized_pset,
                 info.source_schema.clone(),
@@ -134,6 +152,17 @@ pub fn physical_plan_to_pipeline(
             .arced();
             SourceNode::new(in_memory_source, stats_state.clone()).boxed()
         }
+        LocalPhysicalPlan::WorkTableScan(WorkTableScan {
+            work_table_id,
+            schema,
+            stats_state,
+        }) => {
+            // The work table is registered by the enclosing recursive union, and refilled before
+            // every iteration of its recursive term.
+            let work_table = psets.get_partition_set(&work_table_key(*work_table_id));
+            let in_memory_source = InMemorySource::new(work_table, schema.clone(), 0).arced();
+            SourceNode::new(in_memory_source, stats_state.clone()).boxed()
+        }
         LocalPhysicalPlan::Project(Project {
             input,
             projection,
@@ -185,10 +214,11 @@ pub fn physical_plan_to_pipeline(
         LocalPhysicalPlan::Explode(Explode {
             input,
             to_explode,
+            index_column,
             stats_state,
             ..
         }) => {
-            let explode_op = ExplodeOperator::new(to_explode.clone());
+            let explode_op = ExplodeOperator::new(to_explode.clone(), index_column.clone());
             let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
             IntermediateNode::new(Arc::new(explode_op), vec![child_node], stats_state.clone())
                 .boxed()
@@ -219,6 +249,35 @@ pub fn physical_plan_to_pipeline(
             )
             .boxed()
         }
+        LocalPhysicalPlan::RecursiveUnion(RecursiveUnion {
+            anchor,
+            recursive,
+            work_table_id,
+            is_all,
+            schema,
+            stats_state,
+        }) => {
+            let anchor_child = physical_plan_to_pipeline(anchor, psets, cfg)?;
+            let key = work_table_key(*work_table_id);
+            let work_table = Arc::new(MicroPartitionSet::empty());
+            psets.put_partition_set(&key, &work_table);
+            let recursive_child = CapturedPartitionSets::capture(recursive, psets, cfg);
+            psets.rm_partition_set(&key);
+            let (recursive_child, recursive_psets) = recursive_child?;
+            RecursiveUnionNode::new(
+                anchor_child,
+                recursive_child,
+                recursive.clone(),
+                recursive_psets,
+                cfg.clone(),
+                work_table,
+                *is_all,
+                cfg.recursive_cte_max_iterations,
+                schema.clone(),
+                stats_state.clone(),
+            )
+            .boxed()
+        }
         LocalPhysicalPlan::UnGroupedAggregate(UnGroupedAggregate {
             input,
             aggregations,
@@ -288,6 +347,16 @@ pub fn physical_plan_to_pipeline(
             );
             BlockingSinkNode::new(Arc::new(pivot_sink), child_node, stats_state.clone()).boxed()
         }
+        LocalPhysicalPlan::Window(Window {
+            input,
+            window_functions,
+            stats_state,
+            ..
+        }) => {
+            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let window_sink = WindowSink::new(window_functions.clone());
+            BlockingSinkNode::new(Arc::new(window_sink), child_node, stats_state.clone()).boxed()
+        }
         LocalPhysicalPlan::Sort(Sort {
             input,
             sort_by,
@@ -296,7 +365,12 @@ pub fn physical_plan_to_pipeline(
             stats_state,
             ..
         }) => {
-            let sort_sink = SortSink::new(sort_by.clone(), descending.clone(), nulls_first.clone());
+            let sort_sink = SortSink::new(
+                sort_by.clone(),
+                descending.clone(),
+                nulls_first.clone(),
+                get_or_init_memory_manager().spill_budget_bytes(*NUM_CPUS),
+            );
             let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
             BlockingSinkNode::new(Arc::new(sort_sink), child_node, stats_state.clone()).boxed()
         }
@@ -324,6 +398,7 @@ pub fn physical_plan_to_pipeline(
             right_on,
             null_equals_null,
             join_type,
+            build_on_left,
             schema,
             stats_state,
             ..
@@ -331,12 +406,13 @@ pub fn physical_plan_to_pipeline(
             let left_schema = left.schema();
             let right_schema = right.schema();
 
-            // To determine whether to use the left or right side of a join for building a probe table, we consider:
+            // Unless the plan fixes the build side, e.g. the broadcast side of a broadcast join,
+            // to determine whether to use the left or right side of a join for building a probe table, we consider:
             // 1. Cardinality of the sides. Probe tables should be built on the smaller side.
             // 2. Join type. Different join types have different requirements for which side can build the probe table.
             let left_stats_state = left.get_stats_state();
             let right_stats_state = right.get_stats_state();
-            let build_on_left = match join_type {
+            let build_on_left = build_on_left.unwrap_or_else(|| match join_type {
                 // Inner and outer joins can build on either side. If stats are available, choose the smaller side.
                 // Else, default to building on the left.
                 JoinType::Inner | JoinType::Outer => match (left_stats_state, right_stats_state) {
@@ -414,7 +490,7 @@ pub fn physical_plan_to_pipeline(
                     // Else, default to building on the right
                     _ => false,
                 },
-            };
+            });
             let (build_on, probe_on, build_child, probe_child) = match build_on_left {
                 true => (left_on, right_on, left, right),
                 false => (right_on, left_on, right, left),
@@ -456,6 +532,7 @@ pub fn physical_plan_to_pipeline(
                     build_on.clone(),
                     null_equals_null.clone(),
                     track_indices,
+                    cfg.hash_join_spill_threshold,
                     probe_state_bridge.clone(),
                 )?;
                 let build_child_node = physical_plan_to_pipeline(build_child, psets, cfg)?;
@@ -467,6 +544,8 @@ pub fn physical_plan_to_pipeline(
                 .boxed();
 
                 let probe_child_node = physical_plan_to_pipeline(probe_child, psets, cfg)?;
+                // If the build side is spilled, each probe worker partitions its input within an equal share of the budget.
+                let probe_memory_budget = cfg.hash_join_spill_threshold / *NUM_CPUS;
 
                 match join_type {
                     JoinType::Anti | JoinType::Semi => Ok(StreamingSinkNode::new(
@@ -476,19 +555,21 @@ pub fn physical_plan_to_pipeline(
                             schema,
                             probe_state_bridge,
                             build_on_left,
+                            probe_memory_budget,
                         )),
                         vec![build_node, probe_child_node],
                         stats_state.clone(),
                     )
                     .boxed()),
-                    JoinType::Inner => Ok(IntermediateNode::new(
-                        Arc::new(InnerHashJoinProbeOperator::new(
+                    JoinType::Inner => Ok(StreamingSinkNode::new(
+                        Arc::new(InnerHashJoinProbeSink::new(
                             probe_on.clone(),
                             left_schema,
                             right_schema,
                             build_on_left,
                             common_join_cols,
                             schema,
+                            probe_memory_budget,
                             probe_state_bridge,
                         )),
                         vec![build_node, probe_child_node],
@@ -505,6 +586,7 @@ pub fn physical_plan_to_pipeline(
                                 build_on_left,
                                 common_join_cols,
                                 schema,
+                                probe_memory_budget,
                                 probe_state_bridge,
                             )?),
                             vec![build_node, probe_child_node],
@@ -518,6 +600,101 @@ pub fn physical_plan_to_pipeline(
                 plan_name: physical_plan.name(),
             })?
         }
+        LocalPhysicalPlan::AsofJoin(AsofJoin {
+            left,
+            right,
+            left_by,
+            right_by,
+            left_on,
+            right_on,
+            direction,
+            tolerance,
+            stats_state,
+            ..
+        }) => {
+            // Every left row is matched against the whole right side, so the right side is collected
+            // and the left side is streamed.
+            let left_node = physical_plan_to_pipeline(left, psets, cfg)?;
+            let right_node = physical_plan_to_pipeline(right, psets, cfg)?;
+
+            let state_bridge = BroadcastStateBridge::new();
+            let build_node = BlockingSinkNode::new(
+                Arc::new(AsofJoinBuildSink::new(
+                    right_by.clone(),
+                    right_on.clone(),
+                    right.schema().clone(),
+                    state_bridge.clone(),
+                )),
+                right_node,
+                right.get_stats_state().clone(),
+            )
+            .boxed();
+
+            IntermediateNode::new(
+                Arc::new(AsofJoinOperator::new(
+                    left_by.clone(),
+                    right_by.clone(),
+                    left_on.clone(),
+                    right_on.clone(),
+                    *direction,
+                    tolerance.clone(),
+                    right.schema().clone(),
+                    state_bridge,
+                )),
+                vec![build_node, left_node],
+                stats_state.clone(),
+            )
+            .boxed()
+        }
+        LocalPhysicalPlan::NestedLoopJoin(NestedLoopJoin {
+            left,
+            right,
+            left_on,
+            right_on,
+            null_equals_null,
+            predicate,
+            join_type,
+            schema,
+            stats_state,
+        }) => {
+            // The streamed side must be the one whose unmatched rows are kept, so the left side is streamed,
+            // except for right joins. For outer joins, the unmatched rows of the collected side are tracked.
+            let (stream_child, collect_child) = if *join_type == JoinType::Right {
+                (right, left)
+            } else {
+                (left, right)
+            };
+
+            let stream_child_node = physical_plan_to_pipeline(stream_child, psets, cfg)?;
+            let collect_child_node = physical_plan_to_pipeline(collect_child, psets, cfg)?;
+
+            let state_bridge = BroadcastStateBridge::new();
+            let collect_node = BlockingSinkNode::new(
+                Arc::new(NestedLoopJoinCollectSink::new(
+                    collect_child.schema().clone(),
+                    state_bridge.clone(),
+                )),
+                collect_child_node,
+                collect_child.get_stats_state().clone(),
+            )
+            .boxed();
+
+            StreamingSinkNode::new(
+                Arc::new(NestedLoopJoinSink::new(
+                    left_on.clone(),
+                    right_on.clone(),
+                    null_equals_null.clone(),
+                    predicate.clone(),
+                    *join_type,
+                    left.schema().clone(),
+                    schema.clone(),
+                    state_bridge,
+                )),
+                vec![collect_node, stream_child_node],
+                stats_state.clone(),
+            )
+            .boxed()
+        }
         LocalPhysicalPlan::CrossJoin(CrossJoin {
             left,
             right,
@@ -590,6 +767,10 @@ pub fn physical_plan_to_pipeline(
                 (FileFormat::Parquet, false) => WriteFormat::Parquet,
                 (FileFormat::Csv, true) => WriteFormat::PartitionedCsv,
                 (FileFormat::Csv, false) => WriteFormat::Csv,
+                (FileFormat::Json, true) => WriteFormat::PartitionedJson,
+                (FileFormat::Json, false) => WriteFormat::Json,
+                (FileFormat::Ipc, true) => WriteFormat::PartitionedIpc,
+                (FileFormat::Ipc, false) => WriteFormat::Ipc,
                 (_, _) => panic!("Unsupported file format"),
             };
             let write_sink = WriteSink::new(
@@ -665,7 +846,30 @@ pub fn physical_plan_to_pipeline(
             );
             BlockingSinkNode::new(Arc::new(write_sink), child_node, stats_state.clone()).boxed()
         }
+        #[cfg(feature = "python")]
+        LocalPhysicalPlan::CatalogTableWrite(daft_local_plan::CatalogTableWrite {
+            input,
+            table_info,
+            data_schema,
+            file_schema,
+            stats_state,
+        }) => {
+            use crate::sinks::catalog_table_write::CatalogTableWriteSink;
+
+            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let write_sink = CatalogTableWriteSink::new(
+                table_info.clone(),
+                data_schema.clone(),
+                file_schema.clone(),
+            );
+            BlockingSinkNode::new(Arc::new(write_sink), child_node, stats_state.clone()).boxed()
+        }
     };
 
     Ok(out)
 }
+
+/// The partition set cache key under which the work table of a recursive union is registered.
+fn work_table_key(work_table_id: usize) -> String {
+    format!("__work_table_{work_table_id}__")
+}
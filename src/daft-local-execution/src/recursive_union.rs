@@ -0,0 +1,370 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use common_daft_config::DaftExecutionConfig;
+use common_display::tree::TreeDisplay;
+use common_error::{DaftError, DaftResult};
+use common_runtime::get_compute_runtime;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::{resolved_col, ExprRef};
+use daft_local_plan::LocalPhysicalPlanRef;
+use daft_logical_plan::{stats::StatsState, JoinType};
+use daft_micropartition::{
+    partitioning::{MicroPartitionSet, PartitionSetCache, PartitionSetRef},
+    MicroPartition, MicroPartitionRef,
+};
+use snafu::ResultExt;
+
+use crate::{
+    channel::{create_channel, Receiver},
+    pipeline::{physical_plan_to_pipeline, PipelineNode},
+    progress_bar::ProgressBarColor,
+    runtime_stats::{CountingSender, RuntimeStatsContext},
+    ExecutionRuntimeContext, JoinSnafu,
+};
+
+/// The partition sets read by a pipeline, captured when it was first built so that it can be
+/// rebuilt from its plan after the cache it was built from is gone.
+#[derive(Debug, Default)]
+pub(crate) struct CapturedPartitionSets {
+    partition_sets: Mutex<HashMap<String, PartitionSetRef<MicroPartitionRef>>>,
+}
+
+impl CapturedPartitionSets {
+    /// Builds the pipeline of `plan`, capturing the partition sets it reads from `psets`.
+    pub(crate) fn capture(
+        plan: &LocalPhysicalPlanRef,
+        psets: &(impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> + ?Sized),
+        cfg: &Arc<DaftExecutionConfig>,
+    ) -> crate::Result<(Box<dyn PipelineNode>, Arc<Self>)> {
+        let recording = RecordingPartitionSets {
+            inner: psets,
+            captured: Self::default(),
+        };
+        // Built through a trait object, as the recursive plan may itself contain recursive unions
+        // whose captures would otherwise instantiate the pipeline for ever deeper wrapper types.
+        let pipeline = physical_plan_to_pipeline(
+            plan,
+            &recording as &dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+            cfg,
+        )?;
+        Ok((pipeline, Arc::new(recording.captured)))
+    }
+}
+
+impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> for CapturedPartitionSets {
+    fn get_partition_set(&self, key: &str) -> Option<PartitionSetRef<MicroPartitionRef>> {
+        self.partition_sets.lock().unwrap().get(key).cloned()
+    }
+
+    fn get_all_partition_sets(&self) -> Vec<PartitionSetRef<MicroPartitionRef>> {
+        self.partition_sets
+            .lock()
+            .unwrap()
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    fn put_partition_set(&self, key: &str, partition_set: &Arc<MicroPartitionSet>) {
+        self.partition_sets
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), partition_set.clone());
+    }
+
+    fn rm_partition_set(&self, key: &str) {
+        self.partition_sets.lock().unwrap().remove(key);
+    }
+
+    fn clear(&self) {
+        self.partition_sets.lock().unwrap().clear();
+    }
+}
+
+/// Wraps a partition set cache, capturing every partition set that is read through it.
+#[derive(Debug)]
+struct RecordingPartitionSets<'a, C: ?Sized> {
+    inner: &'a C,
+    captured: CapturedPartitionSets,
+}
+
+impl<C> PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>
+    for RecordingPartitionSets<'_, C>
+where
+    C: PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> + ?Sized,
+{
+    fn get_partition_set(&self, key: &str) -> Option<PartitionSetRef<MicroPartitionRef>> {
+        let partition_set = self.inner.get_partition_set(key)?;
+        self.captured
+            .partition_sets
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), partition_set.clone());
+        Some(partition_set)
+    }
+
+    fn get_all_partition_sets(&self) -> Vec<PartitionSetRef<MicroPartitionRef>> {
+        self.inner.get_all_partition_sets()
+    }
+
+    fn put_partition_set(&self, key: &str, partition_set: &Arc<MicroPartitionSet>) {
+        self.inner.put_partition_set(key, partition_set);
+    }
+
+    fn rm_partition_set(&self, key: &str) {
+        self.inner.rm_partition_set(key);
+    }
+
+    fn clear(&self) {
+        self.inner.clear();
+    }
+}
+
+/// Evaluates a recursive union to a fixpoint.
+///
+/// The anchor pipeline is run once, and its output becomes the first working table. The recursive
+/// pipeline reads the working table through a work table scan, and is re-run with its own output as
+/// the next working table until it produces no new rows, or `max_iterations` is exceeded.
+///
+/// Pipeline nodes can only be started once, e.g. a join's build side is handed to its probe side
+/// through a bridge that is set a single time, so the recursive pipeline is rebuilt from its plan
+/// for every iteration. `recursive` is the first of these, and is only kept to be displayed.
+pub(crate) struct RecursiveUnionNode {
+    anchor: Box<dyn PipelineNode>,
+    recursive: Box<dyn PipelineNode>,
+    recursive_plan: LocalPhysicalPlanRef,
+    recursive_psets: Arc<CapturedPartitionSets>,
+    cfg: Arc<DaftExecutionConfig>,
+    work_table: Arc<MicroPartitionSet>,
+    is_all: bool,
+    max_iterations: usize,
+    schema: SchemaRef,
+    runtime_stats: Arc<RuntimeStatsContext>,
+    plan_stats: StatsState,
+}
+
+impl RecursiveUnionNode {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        anchor: Box<dyn PipelineNode>,
+        recursive: Box<dyn PipelineNode>,
+        recursive_plan: LocalPhysicalPlanRef,
+        recursive_psets: Arc<CapturedPartitionSets>,
+        cfg: Arc<DaftExecutionConfig>,
+        work_table: Arc<MicroPartitionSet>,
+        is_all: bool,
+        max_iterations: usize,
+        schema: SchemaRef,
+        plan_stats: StatsState,
+    ) -> Self {
+        Self {
+            anchor,
+            recursive,
+            recursive_plan,
+            recursive_psets,
+            cfg,
+            work_table,
+            is_all,
+            max_iterations,
+            schema,
+            runtime_stats: RuntimeStatsContext::new(),
+            plan_stats,
+        }
+    }
+
+    pub(crate) fn boxed(self) -> Box<dyn PipelineNode> {
+        Box::new(self)
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        if self.is_all {
+            res.push("RecursiveUnion All:".to_string());
+        } else {
+            res.push("RecursiveUnion:".to_string());
+        }
+        res.push(format!("Max iterations = {}", self.max_iterations));
+        res
+    }
+}
+
+/// Concatenates all morsels from a receiver into a single micropartition.
+async fn collect(
+    receiver: Receiver<Arc<MicroPartition>>,
+    schema: &SchemaRef,
+) -> DaftResult<MicroPartition> {
+    let mut morsels = Vec::new();
+    while let Some(morsel) = receiver.recv().await {
+        morsels.push(morsel);
+    }
+    if morsels.is_empty() {
+        Ok(MicroPartition::empty(Some(schema.clone())))
+    } else {
+        MicroPartition::concat(morsels)
+    }
+}
+
+/// Removes the rows of `output` that are duplicates, or that were already produced, and adds the
+/// remaining rows to `seen`.
+fn dedup(
+    output: MicroPartition,
+    seen: &mut Option<MicroPartition>,
+    schema: &SchemaRef,
+) -> DaftResult<MicroPartition> {
+    let columns = schema
+        .names()
+        .into_iter()
+        .map(resolved_col)
+        .collect::<Vec<ExprRef>>();
+    let distinct = output.agg(&[], &columns)?;
+    let new_rows = match seen.as_ref() {
+        Some(seen) if !seen.is_empty() => distinct.hash_join(
+            seen,
+            &columns,
+            &columns,
+            Some(vec![true; columns.len()]),
+            JoinType::Anti,
+        )?,
+        _ => distinct,
+    };
+    *seen = Some(MicroPartition::concat(
+        seen.iter().chain(std::iter::once(&new_rows)),
+    )?);
+    Ok(new_rows)
+}
+
+impl TreeDisplay for RecursiveUnionNode {
+    fn display_as(&self, level: common_display::DisplayLevel) -> String {
+        use std::fmt::Write;
+        let mut display = String::new();
+
+        use common_display::DisplayLevel;
+        match level {
+            DisplayLevel::Compact => {
+                writeln!(display, "{}", self.name()).unwrap();
+            }
+            level => {
+                let multiline_display = self.multiline_display().join("\n");
+                writeln!(display, "{}", multiline_display).unwrap();
+                if let StatsState::Materialized(stats) = &self.plan_stats {
+                    writeln!(display, "Stats = {}", stats).unwrap();
+                }
+                if matches!(level, DisplayLevel::Verbose) {
+                    let rt_result = self.runtime_stats.result();
+                    rt_result.display(&mut display, true, true, true).unwrap();
+                }
+            }
+        }
+        display
+    }
+
+    fn get_children(&self) -> Vec<&dyn TreeDisplay> {
+        self.children()
+            .iter()
+            .map(|v| v.as_tree_display())
+            .collect()
+    }
+}
+
+impl PipelineNode for RecursiveUnionNode {
+    fn children(&self) -> Vec<&dyn PipelineNode> {
+        vec![self.anchor.as_ref(), self.recursive.as_ref()]
+    }
+
+    fn name(&self) -> &'static str {
+        "RecursiveUnion"
+    }
+
+    fn start(
+        &self,
+        _maintain_order: bool,
+        runtime_handle: &mut ExecutionRuntimeContext,
+    ) -> crate::Result<Receiver<Arc<MicroPartition>>> {
+        let progress_bar = runtime_handle.make_progress_bar(
+            self.name(),
+            ProgressBarColor::Cyan,
+            false,
+            self.runtime_stats.clone(),
+        );
+        let anchor_receiver = self.anchor.start(false, runtime_handle)?;
+
+        let (destination_sender, destination_receiver) = create_channel(0);
+        let counting_sender =
+            CountingSender::new(destination_sender, self.runtime_stats.clone(), progress_bar);
+
+        let recursive_plan = self.recursive_plan.clone();
+        let recursive_psets = self.recursive_psets.clone();
+        let cfg = self.cfg.clone();
+        let work_table = self.work_table.clone();
+        let is_all = self.is_all;
+        let max_iterations = self.max_iterations;
+        let schema = self.schema.clone();
+        let default_morsel_size = runtime_handle.default_morsel_size();
+        let memory_manager = runtime_handle.memory_manager();
+        runtime_handle.spawn(
+            async move {
+                let compute_runtime = get_compute_runtime();
+                let mut seen = None;
+                let mut output = collect(anchor_receiver, &schema).await?;
+                let mut iterations = 0;
+                loop {
+                    let new_rows = if is_all {
+                        output
+                    } else {
+                        let schema = schema.clone();
+                        let (new_rows, seen_rows) = compute_runtime
+                            .spawn(async move {
+                                let mut seen = seen;
+                                let new_rows = dedup(output, &mut seen, &schema)?;
+                                DaftResult::Ok((new_rows, seen))
+                            })
+                            .await??;
+                        seen = seen_rows;
+                        new_rows
+                    };
+                    if new_rows.is_empty() {
+                        break;
+                    }
+                    let new_rows = Arc::new(new_rows);
+                    if counting_sender.send(new_rows.clone()).await.is_err() {
+                        break;
+                    }
+
+                    if iterations == max_iterations {
+                        return Err(DaftError::ComputeError(format!(
+                            "Recursive CTE did not terminate after {max_iterations} iterations. \
+                            The limit can be raised with the `recursive_cte_max_iterations` execution config."
+                        )));
+                    }
+                    iterations += 1;
+
+                    // Re-run the recursive term over the rows produced by the previous iteration.
+                    work_table.partitions.clear();
+                    work_table.partitions.insert(0, new_rows);
+                    let mut recursive_handle = ExecutionRuntimeContext::new(
+                        default_morsel_size,
+                        memory_manager.clone(),
+                        None,
+                    );
+                    let recursive =
+                        physical_plan_to_pipeline(&recursive_plan, recursive_psets.as_ref(), &cfg)?;
+                    let recursive_receiver = recursive.start(false, &mut recursive_handle)?;
+                    output = collect(recursive_receiver, &schema).await?;
+                    while let Some(result) = recursive_handle.join_next().await {
+                        result.context(JoinSnafu)??;
+                    }
+                }
+                Ok(())
+            },
+            self.name(),
+        );
+        Ok(destination_receiver)
+    }
+
+    fn as_tree_display(&self) -> &dyn TreeDisplay {
+        self
+    }
+}
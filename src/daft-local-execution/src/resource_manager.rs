@@ -6,6 +6,9 @@ use tokio::sync::Notify;
 
 pub(crate) static MEMORY_MANAGER: OnceLock<Arc<MemoryManager>> = OnceLock::new();
 
+/// Fraction of the memory limit that a spilling operator may buffer in memory before spilling to disk.
+const SPILL_MEMORY_FRACTION: f64 = 0.5;
+
 fn custom_memory_limit() -> Option<u64> {
     let memory_limit_var_name = "DAFT_MEMORY_LIMIT";
     if let Ok(val) = std::env::var(memory_limit_var_name) {
@@ -76,6 +79,12 @@ impl MemoryManager {
         }
     }
 
+    /// Returns the number of bytes each of `num_workers` workers of a spilling operator may buffer in memory
+    /// before spilling to disk.
+    pub fn spill_budget_bytes(&self, num_workers: usize) -> usize {
+        ((self.total_bytes as f64 * SPILL_MEMORY_FRACTION) as usize) / num_workers.max(1)
+    }
+
     pub async fn request_bytes(&self, bytes: u64) -> DaftResult<MemoryPermit> {
         if bytes == 0 {
             return Ok(MemoryPermit {
@@ -130,6 +139,14 @@ mod tests {
         assert!(Arc::ptr_eq(manager1, manager2));
     }
 
+    #[test]
+    fn test_spill_budget_is_split_across_workers() {
+        let manager = MemoryManager::new();
+        let budget = manager.spill_budget_bytes(1);
+        assert!(budget > 0 && budget as u64 <= manager.total_bytes);
+        assert_eq!(manager.spill_budget_bytes(4), budget / 4);
+    }
+
     #[tokio::test]
     async fn test_zero_byte_request() {
         let manager = MemoryManager::new();
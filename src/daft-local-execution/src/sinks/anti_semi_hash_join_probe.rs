@@ -1,19 +1,22 @@
 use std::sync::Arc;
 
 use common_error::DaftResult;
-use daft_core::{prelude::SchemaRef, series::IntoSeries};
+use daft_core::prelude::SchemaRef;
 use daft_dsl::ExprRef;
 use daft_logical_plan::JoinType;
 use daft_micropartition::MicroPartition;
-use daft_recordbatch::{GrowableRecordBatch, ProbeState, Probeable, RecordBatch};
-use futures::{stream, StreamExt};
+use daft_recordbatch::{GrowableRecordBatch, ProbeState, Probeable};
 use itertools::Itertools;
 use tracing::{info_span, instrument, Span};
 
 use super::{
-    outer_hash_join_probe::IndexBitmapBuilder,
+    grace_hash_join::{GraceHashJoinStream, GraceJoinProbe, SpilledProbeSide},
+    hash_join_build::BuildSide,
+    outer_hash_join_probe::{IndexBitmap, IndexBitmapBuilder},
+    spill_partition::MemoryBudget,
     streaming_sink::{
-        StreamingSink, StreamingSinkExecuteResult, StreamingSinkOutput, StreamingSinkState,
+        StreamingSink, StreamingSinkExecuteResult, StreamingSinkFinalizeOutput,
+        StreamingSinkFinalizeResult, StreamingSinkOutput, StreamingSinkState,
     },
 };
 use crate::{
@@ -23,27 +26,30 @@ use crate::{
 };
 
 enum AntiSemiProbeState {
-    Building(BroadcastStateBridgeRef<ProbeState>),
+    Building(BroadcastStateBridgeRef<BuildSide>),
     Probing(Arc<ProbeState>, Option<IndexBitmapBuilder>),
+    Partitioning(SpilledProbeSide),
+    Done,
 }
 
 impl AntiSemiProbeState {
-    async fn get_or_await_probe_state(
-        &mut self,
-        needs_bitmap: bool,
-    ) -> (&mut Arc<ProbeState>, &mut Option<IndexBitmapBuilder>) {
+    async fn await_build_side(&mut self, params: &AntiSemiJoinParams) {
         if let Self::Building(bridge) = self {
-            let probe_state = bridge.get_state().await;
-            let builder = if needs_bitmap {
-                Some(IndexBitmapBuilder::new(probe_state.get_tables()))
-            } else {
-                None
+            *self = match bridge.get_state().await.as_ref() {
+                BuildSide::InMemory(probe_state) => {
+                    let builder = if params.build_on_left {
+                        Some(IndexBitmapBuilder::new(probe_state.get_tables()))
+                    } else {
+                        None
+                    };
+                    Self::Probing(probe_state.clone(), builder)
+                }
+                BuildSide::Spilled(build_side) => Self::Partitioning(SpilledProbeSide::new(
+                    build_side.clone(),
+                    params.probe_on.clone(),
+                    params.memory_budget,
+                )),
             };
-            *self = Self::Probing(probe_state, builder);
-        }
-        match self {
-            Self::Probing(probe_state, builder) => (probe_state, builder),
-            _ => unreachable!(),
         }
     }
 }
@@ -57,13 +63,70 @@ impl StreamingSinkState for AntiSemiProbeState {
 struct AntiSemiJoinParams {
     probe_on: Vec<ExprRef>,
     is_semi: bool,
+    build_on_left: bool,
+    memory_budget: MemoryBudget,
+}
+
+impl GraceJoinProbe for AntiSemiJoinParams {
+    type PartitionState = Option<IndexBitmapBuilder>;
+
+    fn make_partition_state(&self, probe_state: &ProbeState) -> Self::PartitionState {
+        self.build_on_left
+            .then(|| IndexBitmapBuilder::new(probe_state.get_tables()))
+    }
+
+    fn probe(
+        &self,
+        input: &Arc<MicroPartition>,
+        probe_state: &ProbeState,
+        state: &mut Self::PartitionState,
+    ) -> DaftResult<Option<Arc<MicroPartition>>> {
+        if let Some(bitmap_builder) = state {
+            AntiSemiProbeSink::probe_anti_semi_with_bitmap(
+                &self.probe_on,
+                probe_state.get_probeable(),
+                bitmap_builder,
+                input,
+            )?;
+            Ok(None)
+        } else {
+            AntiSemiProbeSink::probe_anti_semi(
+                &self.probe_on,
+                probe_state.get_probeable(),
+                input,
+                self.is_semi,
+            )
+            .map(Some)
+        }
+    }
+
+    fn finish_partition(
+        &self,
+        probe_state: &ProbeState,
+        state: Self::PartitionState,
+    ) -> DaftResult<Option<Arc<MicroPartition>>> {
+        match state {
+            Some(bitmap_builder) => {
+                let mut bitmap = bitmap_builder.build();
+                if self.is_semi {
+                    bitmap = bitmap.negate();
+                }
+                let build_side_table = bitmap.filter_tables(probe_state.get_tables())?;
+                Ok(Some(Arc::new(MicroPartition::new_loaded(
+                    build_side_table.schema.clone(),
+                    Arc::new(vec![build_side_table]),
+                    None,
+                ))))
+            }
+            None => Ok(None),
+        }
+    }
 }
 
 pub(crate) struct AntiSemiProbeSink {
     params: Arc<AntiSemiJoinParams>,
     output_schema: SchemaRef,
-    probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
-    build_on_left: bool,
+    probe_state_bridge: BroadcastStateBridgeRef<BuildSide>,
 }
 
 impl AntiSemiProbeSink {
@@ -73,17 +136,19 @@ impl AntiSemiProbeSink {
         probe_on: Vec<ExprRef>,
         join_type: &JoinType,
         output_schema: &SchemaRef,
-        probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
+        probe_state_bridge: BroadcastStateBridgeRef<BuildSide>,
         build_on_left: bool,
+        memory_budget: usize,
     ) -> Self {
         Self {
             params: Arc::new(AntiSemiJoinParams {
                 probe_on,
                 is_semi: *join_type == JoinType::Semi,
+                build_on_left,
+                memory_budget: MemoryBudget::new(memory_budget),
             }),
             output_schema: output_schema.clone(),
             probe_state_bridge,
-            build_on_left,
         }
     }
 
@@ -158,49 +223,29 @@ impl AntiSemiProbeSink {
     }
 
     // Finalize the anti/semi join where we have a bitmap index, i.e. left side builds.
-    async fn finalize_anti_semi(
+    fn finalize_anti_semi(
         mut states: Vec<Box<dyn StreamingSinkState>>,
         is_semi: bool,
     ) -> DaftResult<Option<Arc<MicroPartition>>> {
-        let mut states_iter = states.iter_mut();
-        let first_state = states_iter
-            .next()
-            .expect("at least one state should be present")
-            .as_any_mut()
-            .downcast_mut::<AntiSemiProbeState>()
-            .expect("state should be AntiSemiProbeState");
-        let (first_probe_state, first_bitmap_builder) =
-            first_state.get_or_await_probe_state(true).await;
-        let tables = first_probe_state.get_tables();
-        let first_bitmap = first_bitmap_builder
-            .take()
-            .expect("bitmap should be set")
-            .build();
-
-        let mut merged_bitmap = {
-            let bitmaps = stream::once(async move { first_bitmap })
-                .chain(stream::iter(states_iter).then(|s| async move {
-                    let state = s
-                        .as_any_mut()
-                        .downcast_mut::<AntiSemiProbeState>()
-                        .expect("state should be AntiSemiProbeState");
-                    state
-                        .get_or_await_probe_state(true)
-                        .await
-                        .1
-                        .take()
-                        .expect("bitmap should be set")
-                        .build()
-                }))
-                .collect::<Vec<_>>()
-                .await;
-
-            bitmaps.into_iter().fold(None, |acc, x| match acc {
-                None => Some(x),
-                Some(acc) => Some(acc.merge(&x)),
-            })
+        let mut tables = None;
+        let mut merged_bitmap: Option<IndexBitmap> = None;
+        for state in &mut states {
+            let AntiSemiProbeState::Probing(probe_state, bitmap_builder) = state
+                .as_any_mut()
+                .downcast_mut::<AntiSemiProbeState>()
+                .expect("state should be AntiSemiProbeState")
+            else {
+                panic!("AntiSemiProbeState should be in Probing state");
+            };
+            tables.get_or_insert_with(|| probe_state.get_tables().clone());
+            let bitmap = bitmap_builder.take().expect("bitmap should be set").build();
+            merged_bitmap = Some(match merged_bitmap {
+                None => bitmap,
+                Some(acc) => acc.merge(&bitmap),
+            });
         }
-        .expect("at least one bitmap should be present");
+        let tables = tables.expect("at least one state should be present");
+        let mut merged_bitmap = merged_bitmap.expect("at least one bitmap should be present");
 
         // The bitmap marks matched rows as 0, so we need to negate it if we are doing semi join, i.e. the matched rows become 1 so that
         // we can we can keep them in the final result.
@@ -208,13 +253,7 @@ impl AntiSemiProbeSink {
             merged_bitmap = merged_bitmap.negate();
         }
 
-        let leftovers = merged_bitmap
-            .convert_to_boolean_arrays()
-            .zip(tables.iter())
-            .map(|(bitmap, table)| table.mask_filter(&bitmap.into_series()))
-            .collect::<DaftResult<Vec<_>>>()?;
-
-        let build_side_table = RecordBatch::concat(&leftovers)?;
+        let build_side_table = merged_bitmap.filter_tables(&tables)?;
         Ok(Some(Arc::new(MicroPartition::new_loaded(
             build_side_table.schema.clone(),
             Arc::new(vec![build_side_table]),
@@ -237,7 +276,6 @@ impl StreamingSink for AntiSemiProbeSink {
         }
 
         let params = self.params.clone();
-        let build_on_left = self.build_on_left;
         task_spawner
             .spawn(
                 async move {
@@ -245,24 +283,31 @@ impl StreamingSink for AntiSemiProbeSink {
                         .as_any_mut()
                         .downcast_mut::<AntiSemiProbeState>()
                         .expect("AntiSemiProbeState should be used with AntiSemiProbeSink");
-                    let (ps, bitmap_builder) =
-                        probe_state.get_or_await_probe_state(build_on_left).await;
-                    if let Some(bm_builder) = bitmap_builder {
-                        Self::probe_anti_semi_with_bitmap(
-                            &params.probe_on,
-                            ps.get_probeable(),
-                            bm_builder,
-                            &input,
-                        )?;
-                        Ok((state, StreamingSinkOutput::NeedMoreInput(None)))
-                    } else {
-                        let res = Self::probe_anti_semi(
-                            &params.probe_on,
-                            ps.get_probeable(),
-                            &input,
-                            params.is_semi,
-                        );
-                        Ok((state, StreamingSinkOutput::NeedMoreInput(Some(res?))))
+                    probe_state.await_build_side(&params).await;
+                    match probe_state {
+                        AntiSemiProbeState::Probing(ps, Some(bm_builder)) => {
+                            Self::probe_anti_semi_with_bitmap(
+                                &params.probe_on,
+                                ps.get_probeable(),
+                                bm_builder,
+                                &input,
+                            )?;
+                            Ok((state, StreamingSinkOutput::NeedMoreInput(None)))
+                        }
+                        AntiSemiProbeState::Probing(ps, None) => {
+                            let res = Self::probe_anti_semi(
+                                &params.probe_on,
+                                ps.get_probeable(),
+                                &input,
+                                params.is_semi,
+                            );
+                            Ok((state, StreamingSinkOutput::NeedMoreInput(Some(res?))))
+                        }
+                        AntiSemiProbeState::Partitioning(probe_side) => {
+                            probe_side.push(&input)?;
+                            Ok((state, StreamingSinkOutput::NeedMoreInput(None)))
+                        }
+                        _ => unreachable!("build side should be available after awaiting it"),
                     }
                 },
                 Span::current(),
@@ -295,27 +340,49 @@ impl StreamingSink for AntiSemiProbeSink {
                     .join(", ")
             ));
         }
-        res.push(format!("Build on left: {}", self.build_on_left));
+        res.push(format!("Build on left: {}", self.params.build_on_left));
         res
     }
 
     #[instrument(skip_all, name = "AntiSemiProbeSink::finalize")]
     fn finalize(
         &self,
-        states: Vec<Box<dyn super::streaming_sink::StreamingSinkState>>,
+        mut states: Vec<Box<dyn StreamingSinkState>>,
         task_spawner: &ExecutionTaskSpawner,
-    ) -> super::streaming_sink::StreamingSinkFinalizeResult {
-        if self.build_on_left {
-            let is_semi = self.params.is_semi;
-            task_spawner
-                .spawn(
-                    async move { Self::finalize_anti_semi(states, is_semi).await },
-                    Span::current(),
-                )
-                .into()
-        } else {
-            Ok(None).into()
-        }
+    ) -> StreamingSinkFinalizeResult {
+        let params = self.params.clone();
+        task_spawner
+            .spawn(
+                async move {
+                    let mut probe_sides = Vec::with_capacity(states.len());
+                    for state in &mut states {
+                        let state = state
+                            .as_any_mut()
+                            .downcast_mut::<AntiSemiProbeState>()
+                            .expect("AntiSemiProbeState should be used with AntiSemiProbeSink");
+                        state.await_build_side(&params).await;
+                        if matches!(state, AntiSemiProbeState::Partitioning(..)) {
+                            let AntiSemiProbeState::Partitioning(probe_side) =
+                                std::mem::replace(state, AntiSemiProbeState::Done)
+                            else {
+                                unreachable!()
+                            };
+                            probe_sides.push(probe_side);
+                        }
+                    }
+                    if let Some(stream) = GraceHashJoinStream::try_new(probe_sides, params.clone())?
+                    {
+                        return Ok(StreamingSinkFinalizeOutput::Streaming(Box::new(stream)));
+                    }
+                    if !params.build_on_left {
+                        return Ok(StreamingSinkFinalizeOutput::Finished(None));
+                    }
+                    let res = Self::finalize_anti_semi(states, params.is_semi)?;
+                    Ok(StreamingSinkFinalizeOutput::Finished(res))
+                },
+                Span::current(),
+            )
+            .into()
     }
 
     fn make_state(&self) -> Box<dyn StreamingSinkState> {
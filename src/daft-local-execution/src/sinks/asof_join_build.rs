@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+use tracing::{info_span, instrument, Span};
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeOutput, BlockingSinkFinalizeResult, BlockingSinkSinkResult,
+    BlockingSinkState, BlockingSinkStatus,
+};
+use crate::{state_bridge::BroadcastStateBridgeRef, ExecutionTaskSpawner};
+
+struct AsofJoinBuildState(Option<Vec<RecordBatch>>);
+
+impl BlockingSinkState for AsofJoinBuildState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Collects the right side of an ASOF join into a single table sorted by its keys,
+/// which is shared with every worker of the [`crate::intermediate_ops::asof_join::AsofJoinOperator`].
+pub struct AsofJoinBuildSink {
+    sort_by: Vec<ExprRef>,
+    schema: SchemaRef,
+    state_bridge: BroadcastStateBridgeRef<RecordBatch>,
+}
+
+impl AsofJoinBuildSink {
+    pub(crate) fn new(
+        right_by: Vec<ExprRef>,
+        right_on: ExprRef,
+        schema: SchemaRef,
+        state_bridge: BroadcastStateBridgeRef<RecordBatch>,
+    ) -> Self {
+        let mut sort_by = right_by;
+        sort_by.push(right_on);
+        Self {
+            sort_by,
+            schema,
+            state_bridge,
+        }
+    }
+}
+
+impl BlockingSink for AsofJoinBuildSink {
+    fn name(&self) -> &'static str {
+        "AsofJoinBuild"
+    }
+
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn BlockingSinkState>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkSinkResult {
+        if input.is_empty() {
+            return Ok(BlockingSinkStatus::NeedMoreInput(state)).into();
+        }
+
+        spawner
+            .spawn(
+                async move {
+                    let collect_state = state
+                        .as_any_mut()
+                        .downcast_mut::<AsofJoinBuildState>()
+                        .expect("AsofJoinBuildSink should have AsofJoinBuildState");
+
+                    collect_state
+                        .0
+                        .as_mut()
+                        .expect("Collected tables should not be consumed before sink stage is done")
+                        .extend(input.get_tables()?.iter().cloned());
+
+                    Ok(BlockingSinkStatus::NeedMoreInput(state))
+                },
+                info_span!("AsofJoinBuildSink::sink"),
+            )
+            .into()
+    }
+
+    #[instrument(skip_all, name = "AsofJoinBuildSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn BlockingSinkState>>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkFinalizeResult {
+        let sort_by = self.sort_by.clone();
+        let schema = self.schema.clone();
+        let state_bridge = self.state_bridge.clone();
+        spawner
+            .spawn(
+                async move {
+                    let mut state = states.into_iter().next().unwrap();
+                    let tables = state
+                        .as_any_mut()
+                        .downcast_mut::<AsofJoinBuildState>()
+                        .expect("AsofJoinBuildSink should have AsofJoinBuildState")
+                        .0
+                        .take()
+                        .expect(
+                            "ASOF join build state should have tables before finalize is called",
+                        );
+
+                    // The right side is sorted once here, so that each left morsel only
+                    // needs to sort itself to be merged against it.
+                    let right = if tables.is_empty() {
+                        RecordBatch::empty(Some(schema))?
+                    } else {
+                        RecordBatch::concat(tables.as_slice())?.sort(
+                            &sort_by,
+                            &vec![false; sort_by.len()],
+                            &vec![false; sort_by.len()],
+                        )?
+                    };
+                    state_bridge.set_state(Arc::new(right));
+                    Ok(BlockingSinkFinalizeOutput::Finished(None))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
+        Ok(Box::new(AsofJoinBuildState(Some(Vec::new()))))
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        vec!["AsofJoinBuild".to_string()]
+    }
+
+    fn max_concurrency(&self) -> usize {
+        1
+    }
+}
@@ -28,8 +28,19 @@ pub enum BlockingSinkStatus {
 }
 
 pub(crate) type BlockingSinkSinkResult = OperatorOutput<DaftResult<BlockingSinkStatus>>;
-pub(crate) type BlockingSinkFinalizeResult =
-    OperatorOutput<DaftResult<Option<Arc<MicroPartition>>>>;
+/// Morsels produced lazily by a blocking sink's finalize step, one at a time.
+pub(crate) type BlockingSinkOutputStream =
+    Box<dyn Iterator<Item = DaftResult<Arc<MicroPartition>>> + Send + Sync>;
+
+pub enum BlockingSinkFinalizeOutput {
+    /// The entire output of the sink, materialized in memory.
+    Finished(Option<Arc<MicroPartition>>),
+    /// Output that is produced incrementally, for sinks whose output may not fit in memory,
+    /// e.g. a sort that spilled runs to disk.
+    Streaming(BlockingSinkOutputStream),
+}
+
+pub(crate) type BlockingSinkFinalizeResult = OperatorOutput<DaftResult<BlockingSinkFinalizeOutput>>;
 pub trait BlockingSink: Send + Sync {
     fn sink(
         &self,
@@ -229,8 +240,30 @@ impl PipelineNode for BlockingSinkNode {
                     info_span!("BlockingSink::Finalize"),
                 );
                 let finalized_result = op.finalize(finished_states, &spawner).await??;
-                if let Some(res) = finalized_result {
-                    let _ = counting_sender.send(res).await;
+                match finalized_result {
+                    BlockingSinkFinalizeOutput::Finished(Some(res)) => {
+                        let _ = counting_sender.send(res).await;
+                    }
+                    BlockingSinkFinalizeOutput::Finished(None) => {}
+                    BlockingSinkFinalizeOutput::Streaming(mut stream) => loop {
+                        // Each morsel may require reading from disk, so pull it on the compute runtime.
+                        let (next, returned_stream) = spawner
+                            .spawn(
+                                async move {
+                                    let next = stream.next().transpose()?;
+                                    Ok((next, stream))
+                                },
+                                info_span!("BlockingSink::FinalizeStream"),
+                            )
+                            .await??;
+                        stream = returned_stream;
+                        let Some(res) = next else {
+                            break;
+                        };
+                        if counting_sender.send(res).await.is_err() {
+                            break;
+                        }
+                    },
                 }
                 Ok(())
             },
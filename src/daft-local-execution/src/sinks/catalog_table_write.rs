@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::{
+    prelude::{SchemaRef, UInt64Array},
+    series::IntoSeries,
+};
+use daft_logical_plan::TableCatalogInfo;
+use daft_micropartition::{python::PyMicroPartition, MicroPartition};
+use daft_recordbatch::RecordBatch;
+use pyo3::{intern, prelude::*};
+use tracing::{info_span, instrument, Span};
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeOutput, BlockingSinkFinalizeResult, BlockingSinkSinkResult,
+    BlockingSinkState, BlockingSinkStatus,
+};
+use crate::ExecutionTaskSpawner;
+
+struct CatalogTableWriteState(Vec<Arc<MicroPartition>>);
+
+impl BlockingSinkState for CatalogTableWriteState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Collects the rows to write to a catalog table, and writes all of them at once through the table's
+/// `daft.catalog.Table` python object, so that an overwrite replaces the table's rows only once.
+/// Outputs the number of written rows.
+pub(crate) struct CatalogTableWriteSink {
+    table_info: TableCatalogInfo,
+    data_schema: SchemaRef,
+    file_schema: SchemaRef,
+}
+
+impl CatalogTableWriteSink {
+    pub(crate) fn new(
+        table_info: TableCatalogInfo,
+        data_schema: SchemaRef,
+        file_schema: SchemaRef,
+    ) -> Self {
+        Self {
+            table_info,
+            data_schema,
+            file_schema,
+        }
+    }
+}
+
+impl BlockingSink for CatalogTableWriteSink {
+    fn name(&self) -> &'static str {
+        "CatalogTableWrite"
+    }
+
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn BlockingSinkState>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkSinkResult {
+        if input.is_empty() {
+            return Ok(BlockingSinkStatus::NeedMoreInput(state)).into();
+        }
+
+        spawner
+            .spawn(
+                async move {
+                    state
+                        .as_any_mut()
+                        .downcast_mut::<CatalogTableWriteState>()
+                        .expect("CatalogTableWriteSink should have CatalogTableWriteState")
+                        .0
+                        .push(input);
+                    Ok(BlockingSinkStatus::NeedMoreInput(state))
+                },
+                info_span!("CatalogTableWriteSink::sink"),
+            )
+            .into()
+    }
+
+    #[instrument(skip_all, name = "CatalogTableWriteSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn BlockingSinkState>>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkFinalizeResult {
+        let table_info = self.table_info.clone();
+        let data_schema = self.data_schema.clone();
+        let file_schema = self.file_schema.clone();
+        spawner
+            .spawn(
+                async move {
+                    let parts = states
+                        .into_iter()
+                        .flat_map(|mut state| {
+                            std::mem::take(
+                                &mut state
+                                    .as_any_mut()
+                                    .downcast_mut::<CatalogTableWriteState>()
+                                    .expect(
+                                        "CatalogTableWriteSink should have CatalogTableWriteState",
+                                    )
+                                    .0,
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    let rows = if parts.is_empty() {
+                        MicroPartition::empty(Some(data_schema))
+                    } else {
+                        MicroPartition::concat(parts)?
+                    };
+                    let num_rows = rows.len() as u64;
+                    Python::with_gil(|py| -> PyResult<()> {
+                        // df = DataFrame._from_tables(MicroPartition._from_pymicropartition(rows))
+                        let rows = py
+                            .import(intern!(py, "daft.recordbatch"))?
+                            .getattr(intern!(py, "MicroPartition"))?
+                            .getattr(intern!(py, "_from_pymicropartition"))?
+                            .call1((PyMicroPartition::from(rows),))?;
+                        let df = py
+                            .import(intern!(py, "daft.dataframe"))?
+                            .getattr(intern!(py, "DataFrame"))?
+                            .getattr(intern!(py, "_from_tables"))?
+                            .call1((rows,))?;
+                        // table.write(df, mode)
+                        table_info.table.call_method1(
+                            py,
+                            intern!(py, "write"),
+                            (df, table_info.mode.as_str()),
+                        )?;
+                        Ok(())
+                    })?;
+
+                    let written = UInt64Array::from(("rows", vec![num_rows])).into_series();
+                    let written =
+                        RecordBatch::new_with_size(file_schema.clone(), vec![written], 1)?;
+                    Ok(BlockingSinkFinalizeOutput::Finished(Some(Arc::new(
+                        MicroPartition::new_loaded(file_schema, Arc::new(vec![written]), None),
+                    ))))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
+        Ok(Box::new(CatalogTableWriteState(Vec::new())))
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec!["CatalogTableWrite".to_string()];
+        res.extend(self.table_info.multiline_display());
+        res
+    }
+
+    fn max_concurrency(&self) -> usize {
+        1
+    }
+}
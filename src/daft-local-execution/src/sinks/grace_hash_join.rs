@@ -0,0 +1,268 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::ProbeState;
+
+use super::{
+    hash_join_build::SpilledBuildSide,
+    spill_partition::{
+        HashPartition, HashPartitionSpiller, MemoryBudget, PartitionChunk, MAX_REPARTITION_DEPTH,
+    },
+};
+
+/// Number of partitions that both sides of a hash join are split into once the build side exceeds its memory budget.
+pub(crate) const GRACE_HASH_JOIN_PARTITIONS: usize = 32;
+
+/// The probe logic of a hash join, applied to each partition of a grace hash join in turn.
+pub(crate) trait GraceJoinProbe: Send + Sync + 'static {
+    /// State carried across the probe side chunks of a single partition, e.g. a bitmap of the matched build side rows.
+    type PartitionState: Send + Sync;
+
+    fn make_partition_state(&self, probe_state: &ProbeState) -> Self::PartitionState;
+
+    /// Probes a chunk of the probe side of a partition against the probe table of the build side of the same partition.
+    fn probe(
+        &self,
+        input: &Arc<MicroPartition>,
+        probe_state: &ProbeState,
+        state: &mut Self::PartitionState,
+    ) -> DaftResult<Option<Arc<MicroPartition>>>;
+
+    /// Produces any remaining output of a partition once its entire probe side has been probed.
+    fn finish_partition(
+        &self,
+        probe_state: &ProbeState,
+        state: Self::PartitionState,
+    ) -> DaftResult<Option<Arc<MicroPartition>>>;
+}
+
+/// The input of a probe worker once the build side turned out to be spilled to disk. The input is partitioned the same
+/// way as the build side, within the memory budget of the worker, and joined by a [`GraceHashJoinStream`] on finalize.
+pub(crate) struct SpilledProbeSide {
+    build_side: Arc<SpilledBuildSide>,
+    partitions: HashPartitionSpiller,
+}
+
+impl SpilledProbeSide {
+    pub(crate) fn new(
+        build_side: Arc<SpilledBuildSide>,
+        probe_on: Vec<ExprRef>,
+        memory_budget: MemoryBudget,
+    ) -> Self {
+        Self {
+            build_side,
+            partitions: HashPartitionSpiller::new(
+                probe_on,
+                GRACE_HASH_JOIN_PARTITIONS,
+                memory_budget.bytes(),
+            ),
+        }
+    }
+
+    pub(crate) fn push(&mut self, input: &MicroPartition) -> DaftResult<()> {
+        self.partitions.push(&input.get_tables()?)
+    }
+}
+
+struct CurrentPartition<S> {
+    probe_state: ProbeState,
+    state: S,
+    chunks: std::vec::IntoIter<PartitionChunk>,
+}
+
+/// The build side of a partition that has yet to be joined.
+enum BuildPartition {
+    /// A partition of the spilled build side, by index.
+    Spilled(usize),
+    /// A sub-partition of a partition that was too large to be joined in memory.
+    Repartitioned(HashPartition),
+}
+
+/// A partition that has yet to be joined, along with the number of times it was partitioned again.
+struct PendingPartition {
+    build: BuildPartition,
+    probe: Vec<PartitionChunk>,
+    depth: u64,
+}
+
+/// Joins a spilled build side with the partitioned probe side, one partition at a time, so that only the probe table
+/// of a single build side partition needs to be in memory at once.
+///
+/// A partition whose build side still exceeds the memory budget is partitioned again on both sides with a different
+/// hash seed, and its sub-partitions are joined one at a time instead.
+pub(crate) struct GraceHashJoinStream<P: GraceJoinProbe> {
+    build_side: Arc<SpilledBuildSide>,
+    /// The expressions the probe side is partitioned by.
+    probe_on: Vec<ExprRef>,
+    /// Partitions that have yet to be joined, the next one last.
+    pending: Vec<PendingPartition>,
+    current: Option<CurrentPartition<P::PartitionState>>,
+    probe: Arc<P>,
+}
+
+impl<P: GraceJoinProbe> GraceHashJoinStream<P> {
+    /// Creates a stream over the join of the spilled build side with the input partitioned by each worker in
+    /// `probe_sides`, or returns `None` if the build side wasn't spilled and there are no probe sides.
+    pub(crate) fn try_new(
+        probe_sides: Vec<SpilledProbeSide>,
+        probe: Arc<P>,
+    ) -> DaftResult<Option<Self>> {
+        let Some(first) = probe_sides.first() else {
+            return Ok(None);
+        };
+        let build_side = first.build_side.clone();
+        let probe_on = first.partitions.partition_by().to_vec();
+        let mut probe_partitions = (0..build_side.num_partitions())
+            .map(|_| Vec::new())
+            .collect::<Vec<_>>();
+        for probe_side in probe_sides {
+            for (chunks, partition) in probe_partitions
+                .iter_mut()
+                .zip(probe_side.partitions.finish()?)
+            {
+                chunks.extend(partition.into_chunks());
+            }
+        }
+        let pending = probe_partitions
+            .into_iter()
+            .enumerate()
+            .map(|(idx, probe)| PendingPartition {
+                build: BuildPartition::Spilled(idx),
+                probe,
+                depth: 0,
+            })
+            .rev()
+            .collect();
+        Ok(Some(Self {
+            build_side,
+            probe_on,
+            pending,
+            current: None,
+            probe,
+        }))
+    }
+
+    /// Partitions both sides of an oversized partition again and queues the sub-partitions to be joined next.
+    fn repartition(&mut self, partition: PendingPartition) -> DaftResult<()> {
+        let PendingPartition {
+            build,
+            probe,
+            depth,
+        } = partition;
+        let seed = depth + 1;
+        let memory_budget = self.build_side.memory_budget();
+
+        let build_partitions = match &build {
+            BuildPartition::Spilled(idx) => self.build_side.partition(*idx),
+            BuildPartition::Repartitioned(partition) => partition,
+        }
+        .repartition(
+            self.build_side.partition_by(),
+            GRACE_HASH_JOIN_PARTITIONS,
+            seed,
+            memory_budget,
+        )?;
+        drop(build);
+
+        let mut probe_spiller = HashPartitionSpiller::new(
+            self.probe_on.clone(),
+            GRACE_HASH_JOIN_PARTITIONS,
+            memory_budget,
+        )
+        .with_seed(seed);
+        for chunk in probe {
+            chunk.push_to(&mut probe_spiller)?;
+        }
+
+        // if all rows ended up in the same partition again, e.g. because they share a key, another seed won't help
+        let non_empty = build_partitions
+            .iter()
+            .filter(|p| p.size_bytes() > 0)
+            .count();
+        let depth = if non_empty > 1 {
+            seed
+        } else {
+            MAX_REPARTITION_DEPTH
+        };
+        let sub_partitions = build_partitions
+            .into_iter()
+            .zip(probe_spiller.finish()?)
+            .map(|(build, probe)| PendingPartition {
+                build: BuildPartition::Repartitioned(build),
+                probe: probe.into_chunks().collect(),
+                depth,
+            });
+        self.pending.extend(sub_partitions.rev());
+        Ok(())
+    }
+
+    fn try_next(&mut self) -> DaftResult<Option<Arc<MicroPartition>>> {
+        loop {
+            if self.current.is_none() {
+                let Some(partition) = self.pending.pop() else {
+                    return Ok(None);
+                };
+                let build = match &partition.build {
+                    BuildPartition::Spilled(idx) => self.build_side.partition(*idx),
+                    BuildPartition::Repartitioned(build) => build,
+                };
+                // sub-partitions without any rows on either side produce no output for any join type
+                if matches!(partition.build, BuildPartition::Repartitioned(_))
+                    && build.size_bytes() == 0
+                    && partition.probe.is_empty()
+                {
+                    continue;
+                }
+                if build.size_bytes() > self.build_side.memory_budget()
+                    && partition.depth < MAX_REPARTITION_DEPTH
+                {
+                    self.repartition(partition)?;
+                    continue;
+                }
+                let probe_state = self.build_side.build_probe_state(build)?;
+                let state = self.probe.make_partition_state(&probe_state);
+                self.current = Some(CurrentPartition {
+                    probe_state,
+                    state,
+                    chunks: partition.probe.into_iter(),
+                });
+            }
+            let current = self
+                .current
+                .as_mut()
+                .expect("current partition should be set");
+            if let Some(chunk) = current.chunks.next() {
+                let input = chunk.load()?;
+                if input.is_empty() {
+                    continue;
+                }
+                if let Some(output) =
+                    self.probe
+                        .probe(&input, &current.probe_state, &mut current.state)?
+                {
+                    return Ok(Some(output));
+                }
+            } else {
+                let CurrentPartition {
+                    probe_state, state, ..
+                } = self
+                    .current
+                    .take()
+                    .expect("current partition should be set");
+                if let Some(output) = self.probe.finish_partition(&probe_state, state)? {
+                    return Ok(Some(output));
+                }
+            }
+        }
+    }
+}
+
+impl<P: GraceJoinProbe> Iterator for GraceHashJoinStream<P> {
+    type Item = DaftResult<Arc<MicroPartition>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.try_next().transpose()
+    }
+}
@@ -12,11 +12,17 @@ use daft_physical_plan::extract_agg_expr;
 use itertools::Itertools;
 use tracing::{instrument, Span};
 
-use super::blocking_sink::{
-    BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
-    BlockingSinkStatus,
+use super::{
+    blocking_sink::{
+        BlockingSink, BlockingSinkFinalizeOutput, BlockingSinkFinalizeResult,
+        BlockingSinkSinkResult, BlockingSinkState, BlockingSinkStatus,
+    },
+    spill_partition::{HashPartition, HashPartitionSpiller, MemoryBudget, MAX_REPARTITION_DEPTH},
+};
+use crate::{
+    spill::{can_spill, SpillFile},
+    ExecutionTaskSpawner, NUM_CPUS,
 };
-use crate::{ExecutionTaskSpawner, NUM_CPUS};
 
 #[derive(Clone)]
 enum AggStrategy {
@@ -108,6 +114,99 @@ struct SinglePartitionAggregateState {
     partially_aggregated: Vec<MicroPartition>,
     unaggregated: Vec<MicroPartition>,
     unaggregated_size: usize,
+    // State that was spilled to disk once the memory budget was exceeded
+    spilled_partially_aggregated: Vec<SpillFile>,
+    spilled_unaggregated: Vec<SpillFile>,
+    spilled_bytes: usize,
+}
+
+impl SinglePartitionAggregateState {
+    fn has_spilled(&self) -> bool {
+        !self.spilled_partially_aggregated.is_empty() || !self.spilled_unaggregated.is_empty()
+    }
+
+    /// Size of the state of this partition once it is read back into memory.
+    fn size_bytes(&self) -> DaftResult<usize> {
+        let mut size_bytes = self.spilled_bytes;
+        for partition in self.unaggregated.iter().chain(&self.partially_aggregated) {
+            size_bytes += partition.size_bytes()?.unwrap_or(0);
+        }
+        Ok(size_bytes)
+    }
+
+    /// Pushes the unaggregated and the partially aggregated state of this partition into their spillers, reading the
+    /// spilled state back one batch at a time.
+    fn push_to(
+        &self,
+        unaggregated: &mut HashPartitionSpiller,
+        partially_aggregated: &mut HashPartitionSpiller,
+    ) -> DaftResult<()> {
+        for partition in &self.unaggregated {
+            unaggregated.push(&partition.get_tables()?)?;
+        }
+        for partition in &self.partially_aggregated {
+            partially_aggregated.push(&partition.get_tables()?)?;
+        }
+        for (spill_files, spiller) in [
+            (&self.spilled_unaggregated, unaggregated),
+            (&self.spilled_partially_aggregated, partially_aggregated),
+        ] {
+            for spill_file in spill_files {
+                for batch in spill_file.read()? {
+                    spiller.push(&[batch?])?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Spills the in-memory state of this partition to disk.
+    /// Unless the strategy is `PartitionOnly`, the unaggregated state is partially aggregated first, so that
+    /// only partially aggregated state is spilled.
+    /// Returns false if the state cannot be spilled, e.g. because it contains Python objects, in which case it is kept in memory.
+    fn spill(&mut self, partition_only: bool, params: &GroupedAggregateParams) -> DaftResult<bool> {
+        if partition_only {
+            if self.unaggregated.is_empty() {
+                return Ok(true);
+            }
+            let concated = MicroPartition::concat(&self.unaggregated)?;
+            if !can_spill(&concated.schema()) {
+                return Ok(false);
+            }
+            if !concated.is_empty() {
+                self.spilled_bytes += concated.size_bytes()?.unwrap_or(0);
+                self.spilled_unaggregated
+                    .push(SpillFile::try_from_micropartition(&concated)?);
+            }
+            self.unaggregated.clear();
+            self.unaggregated_size = 0;
+        } else {
+            if !self.unaggregated.is_empty() {
+                let aggregated = MicroPartition::concat(&self.unaggregated)?.agg(
+                    params.partial_agg_exprs.as_slice(),
+                    params.group_by.as_slice(),
+                )?;
+                self.partially_aggregated.push(aggregated);
+                self.unaggregated.clear();
+                self.unaggregated_size = 0;
+            }
+            if self.partially_aggregated.is_empty() {
+                return Ok(true);
+            }
+            let concated = MicroPartition::concat(&self.partially_aggregated)?;
+            if !can_spill(&concated.schema()) {
+                self.partially_aggregated = vec![concated];
+                return Ok(false);
+            }
+            if !concated.is_empty() {
+                self.spilled_bytes += concated.size_bytes()?.unwrap_or(0);
+                self.spilled_partially_aggregated
+                    .push(SpillFile::try_from_micropartition(&concated)?);
+            }
+            self.partially_aggregated.clear();
+        }
+        Ok(true)
+    }
 }
 
 enum GroupedAggregateState {
@@ -116,6 +215,8 @@ enum GroupedAggregateState {
         strategy: Option<AggStrategy>,
         partial_agg_threshold: usize,
         high_cardinality_threshold_ratio: f64,
+        buffered_bytes: usize,
+        spill_disabled: bool,
     },
     Done,
 }
@@ -132,6 +233,8 @@ impl GroupedAggregateState {
             strategy: None,
             partial_agg_threshold,
             high_cardinality_threshold_ratio,
+            buffered_bytes: 0,
+            spill_disabled: false,
         }
     }
 
@@ -146,10 +249,13 @@ impl GroupedAggregateState {
             strategy,
             partial_agg_threshold,
             high_cardinality_threshold_ratio,
+            buffered_bytes,
+            ..
         } = self
         else {
             panic!("GroupedAggregateSink should be in Accumulating state");
         };
+        *buffered_bytes += input.size_bytes()?.unwrap_or(0);
 
         // If we have determined a strategy, execute it.
         if let Some(strategy) = strategy {
@@ -211,6 +317,43 @@ impl GroupedAggregateState {
         Ok(decided_strategy)
     }
 
+    fn should_spill(&self, memory_budget: MemoryBudget) -> bool {
+        if let Self::Accumulating {
+            buffered_bytes,
+            spill_disabled,
+            ..
+        } = self
+        {
+            !*spill_disabled && memory_budget.is_exceeded_by(*buffered_bytes)
+        } else {
+            panic!("GroupedAggregateSink should be in Accumulating state");
+        }
+    }
+
+    /// Spills the state of every partition to disk.
+    /// If the state cannot be spilled, it is kept in memory and spilling is disabled for this worker.
+    fn spill(&mut self, params: &GroupedAggregateParams) -> DaftResult<()> {
+        let Self::Accumulating {
+            inner_states,
+            strategy,
+            buffered_bytes,
+            spill_disabled,
+            ..
+        } = self
+        else {
+            panic!("GroupedAggregateSink should be in Accumulating state");
+        };
+        let partition_only = matches!(strategy, Some(AggStrategy::PartitionOnly));
+        for state in inner_states.iter_mut().flatten() {
+            if !state.spill(partition_only, params)? {
+                *spill_disabled = true;
+                return Ok(());
+            }
+        }
+        *buffered_bytes = 0;
+        Ok(())
+    }
+
     fn finalize(&mut self) -> Vec<Option<SinglePartitionAggregateState>> {
         let res = if let Self::Accumulating {
             ref mut inner_states,
@@ -242,6 +385,247 @@ struct GroupedAggregateParams {
     final_agg_exprs: Vec<ExprRef>,
     final_group_by: Vec<ExprRef>,
     final_projections: Vec<ExprRef>,
+    memory_budget: MemoryBudget,
+}
+
+/// Merges the states of a single partition across all workers, reading back any state that was spilled to disk.
+/// Returns the unaggregated and the partially aggregated micropartitions of the partition.
+fn collect_partition_state(
+    states: Vec<Option<SinglePartitionAggregateState>>,
+) -> DaftResult<(Vec<MicroPartition>, Vec<MicroPartition>)> {
+    let mut unaggregated = vec![];
+    let mut partially_aggregated = vec![];
+    for state in states.into_iter().flatten() {
+        unaggregated.extend(state.unaggregated);
+        partially_aggregated.extend(state.partially_aggregated);
+        for spill_file in &state.spilled_unaggregated {
+            unaggregated.push(spill_file.read_micropartition()?);
+        }
+        for spill_file in &state.spilled_partially_aggregated {
+            partially_aggregated.push(spill_file.read_micropartition()?);
+        }
+    }
+    Ok((unaggregated, partially_aggregated))
+}
+
+/// Most sub-partitions that a partition of spilled state which exceeds the memory budget is split into at once.
+const MAX_REPARTITION_FANOUT: usize = 32;
+
+/// A partition of aggregation state that has yet to be finalized.
+enum PendingAggregatePartition {
+    /// The state of a partition across all workers.
+    Accumulated(Vec<Option<SinglePartitionAggregateState>>),
+    /// A sub-partition of a partition that exceeded the memory budget, along with the number of times it was
+    /// partitioned again.
+    Repartitioned {
+        unaggregated: HashPartition,
+        partially_aggregated: HashPartition,
+        depth: u64,
+    },
+}
+
+impl PendingAggregatePartition {
+    fn size_bytes(&self) -> DaftResult<usize> {
+        match self {
+            Self::Accumulated(states) => states
+                .iter()
+                .flatten()
+                .map(SinglePartitionAggregateState::size_bytes)
+                .sum(),
+            Self::Repartitioned {
+                unaggregated,
+                partially_aggregated,
+                ..
+            } => Ok(unaggregated.size_bytes() + partially_aggregated.size_bytes()),
+        }
+    }
+
+    fn depth(&self) -> u64 {
+        match self {
+            Self::Accumulated(_) => 0,
+            Self::Repartitioned { depth, .. } => *depth,
+        }
+    }
+
+    /// Reads the entire state of the partition into memory.
+    /// Returns the unaggregated and the partially aggregated micropartitions of the partition.
+    fn load(self) -> DaftResult<(Vec<MicroPartition>, Vec<MicroPartition>)> {
+        match self {
+            Self::Accumulated(states) => collect_partition_state(states),
+            Self::Repartitioned {
+                unaggregated,
+                partially_aggregated,
+                ..
+            } => {
+                let load = |partition: HashPartition| -> DaftResult<Vec<MicroPartition>> {
+                    let tables = partition.read_tables()?;
+                    Ok(match tables.first() {
+                        Some(table) => vec![MicroPartition::new_loaded(
+                            table.schema.clone(),
+                            Arc::new(tables),
+                            None,
+                        )],
+                        None => vec![],
+                    })
+                };
+                Ok((load(unaggregated)?, load(partially_aggregated)?))
+            }
+        }
+    }
+}
+
+/// Finalizes aggregation state that was spilled to disk one partition at a time, so that only the state of a single
+/// partition has to be read back into memory at once.
+///
+/// A partition whose state exceeds the memory budget is partitioned again with a different hash seed, into as many
+/// sub-partitions as it takes for each to fit the budget, and its sub-partitions are finalized one at a time instead.
+struct SpilledAggregateStream {
+    /// Partitions that have yet to be finalized, the next one last.
+    pending: Vec<PendingAggregatePartition>,
+    params: Arc<GroupedAggregateParams>,
+}
+
+impl SpilledAggregateStream {
+    fn new(
+        per_partition_states: Vec<Vec<Option<SinglePartitionAggregateState>>>,
+        params: Arc<GroupedAggregateParams>,
+    ) -> Self {
+        let pending = per_partition_states
+            .into_iter()
+            .map(PendingAggregatePartition::Accumulated)
+            .rev()
+            .collect();
+        Self { pending, params }
+    }
+
+    /// Partitions the state of an oversized partition again and queues the sub-partitions to be finalized next.
+    fn repartition(
+        &mut self,
+        partition: PendingAggregatePartition,
+        size_bytes: usize,
+    ) -> DaftResult<()> {
+        let seed = partition.depth() + 1;
+        let memory_budget = self.params.memory_budget.bytes();
+        let num_partitions = size_bytes
+            .div_ceil(memory_budget.max(1))
+            .clamp(2, MAX_REPARTITION_FANOUT);
+        // the unaggregated and the partially aggregated rows of a group hash alike, as the partially aggregated
+        // group by columns hold the values of the group by expressions
+        let mut unaggregated_spiller =
+            HashPartitionSpiller::new(self.params.group_by.clone(), num_partitions, memory_budget)
+                .with_seed(seed);
+        let mut partially_aggregated_spiller = HashPartitionSpiller::new(
+            self.params.final_group_by.clone(),
+            num_partitions,
+            memory_budget,
+        )
+        .with_seed(seed);
+        match partition {
+            PendingAggregatePartition::Accumulated(states) => {
+                for state in states.into_iter().flatten() {
+                    state.push_to(&mut unaggregated_spiller, &mut partially_aggregated_spiller)?;
+                }
+            }
+            PendingAggregatePartition::Repartitioned {
+                unaggregated,
+                partially_aggregated,
+                ..
+            } => {
+                unaggregated.push_to(&mut unaggregated_spiller)?;
+                partially_aggregated.push_to(&mut partially_aggregated_spiller)?;
+            }
+        }
+        let sub_partitions = unaggregated_spiller
+            .finish()?
+            .into_iter()
+            .zip(partially_aggregated_spiller.finish()?)
+            .collect::<Vec<_>>();
+
+        // if all rows ended up in the same partition again, e.g. because they share a group, another seed won't help
+        let non_empty = sub_partitions
+            .iter()
+            .filter(|(u, p)| u.size_bytes() + p.size_bytes() > 0)
+            .count();
+        let depth = if non_empty > 1 {
+            seed
+        } else {
+            MAX_REPARTITION_DEPTH
+        };
+        self.pending.extend(
+            sub_partitions
+                .into_iter()
+                .map(|(unaggregated, partially_aggregated)| {
+                    PendingAggregatePartition::Repartitioned {
+                        unaggregated,
+                        partially_aggregated,
+                        depth,
+                    }
+                })
+                .rev(),
+        );
+        Ok(())
+    }
+
+    fn try_next(&mut self) -> DaftResult<Option<Arc<MicroPartition>>> {
+        while let Some(partition) = self.pending.pop() {
+            let size_bytes = partition.size_bytes()?;
+            if self.params.memory_budget.is_exceeded_by(size_bytes)
+                && partition.depth() < MAX_REPARTITION_DEPTH
+            {
+                self.repartition(partition, size_bytes)?;
+                continue;
+            }
+            let (unaggregated, partially_aggregated) = partition.load()?;
+            if unaggregated.is_empty() && partially_aggregated.is_empty() {
+                continue;
+            }
+            let finalized = finalize_partition(&unaggregated, &partially_aggregated, &self.params)?;
+            return Ok(Some(Arc::new(finalized)));
+        }
+        Ok(None)
+    }
+}
+
+impl Iterator for SpilledAggregateStream {
+    type Item = DaftResult<Arc<MicroPartition>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.try_next().transpose()
+    }
+}
+
+/// Computes the final aggregation result of a single partition.
+fn finalize_partition(
+    unaggregated: &[MicroPartition],
+    partially_aggregated: &[MicroPartition],
+    params: &GroupedAggregateParams,
+) -> DaftResult<MicroPartition> {
+    // If we have no partially aggregated partitions, aggregate the unaggregated partitions using the original aggregations
+    if partially_aggregated.is_empty() {
+        let concated = MicroPartition::concat(unaggregated)?;
+        let agged = concated.agg(&params.original_aggregations, &params.group_by)?;
+        Ok(agged)
+    }
+    // If we have no unaggregated partitions, finalize the partially aggregated partitions
+    else if unaggregated.is_empty() {
+        let concated = MicroPartition::concat(partially_aggregated)?;
+        let agged = concated.agg(&params.final_agg_exprs, &params.final_group_by)?;
+        let projected = agged.eval_expression_list(&params.final_projections)?;
+        Ok(projected)
+    }
+    // Otherwise, partially aggregate the unaggregated partitions, concatenate them with the partially aggregated partitions, and finalize the result.
+    else {
+        let leftover_partial_agg = MicroPartition::concat(unaggregated)?
+            .agg(&params.partial_agg_exprs, &params.group_by)?;
+        let concated = MicroPartition::concat(
+            partially_aggregated
+                .iter()
+                .chain(std::iter::once(&leftover_partial_agg)),
+        )?;
+        let agged = concated.agg(&params.final_agg_exprs, &params.final_group_by)?;
+        let projected = agged.eval_expression_list(&params.final_projections)?;
+        Ok(projected)
+    }
 }
 
 pub struct GroupedAggregateSink {
@@ -296,6 +680,7 @@ impl GroupedAggregateSink {
                 final_agg_exprs,
                 final_group_by,
                 final_projections,
+                memory_budget: MemoryBudget::new(cfg.grouped_aggregate_spill_threshold / *NUM_CPUS),
             }),
             partial_agg_threshold: cfg.partial_aggregation_threshold,
             high_cardinality_threshold_ratio: cfg.high_cardinality_aggregation_threshold,
@@ -327,6 +712,9 @@ impl BlockingSink for GroupedAggregateSink {
                         .expect("GroupedAggregateSink should have GroupedAggregateState");
 
                     agg_state.push(input, &params, &strategy_lock)?;
+                    if agg_state.should_spill(params.memory_budget) {
+                        agg_state.spill(&params)?;
+                    }
                     Ok(BlockingSinkStatus::NeedMoreInput(state))
                 },
                 Span::current(),
@@ -357,57 +745,36 @@ impl BlockingSink for GroupedAggregateSink {
                         })
                         .collect::<Vec<_>>();
 
-                    let mut per_partition_finalize_tasks = tokio::task::JoinSet::new();
-                    for _ in 0..num_partitions {
-                        let per_partition_state = state_iters
-                            .iter_mut()
-                            .map(|state| {
-                                state.next().expect(
+                    let per_partition_states = (0..num_partitions)
+                        .map(|_| {
+                            state_iters
+                                .iter_mut()
+                                .map(|state| {
+                                    state.next().expect(
                                 "GroupedAggregateState should have SinglePartitionAggregateState",
                             )
-                            })
-                            .collect::<Vec<_>>();
+                                })
+                                .collect::<Vec<_>>()
+                        })
+                        .collect::<Vec<_>>();
+
+                    let has_spilled = per_partition_states
+                        .iter()
+                        .flatten()
+                        .flatten()
+                        .any(SinglePartitionAggregateState::has_spilled);
+                    if has_spilled {
+                        let stream = SpilledAggregateStream::new(per_partition_states, params);
+                        return Ok(BlockingSinkFinalizeOutput::Streaming(Box::new(stream)));
+                    }
+
+                    let mut per_partition_finalize_tasks = tokio::task::JoinSet::new();
+                    for per_partition_state in per_partition_states {
                         let params = params.clone();
                         per_partition_finalize_tasks.spawn(async move {
-                            let mut unaggregated = vec![];
-                            let mut partially_aggregated = vec![];
-                            for state in per_partition_state.into_iter().flatten() {
-                                unaggregated.extend(state.unaggregated);
-                                partially_aggregated.extend(state.partially_aggregated);
-                            }
-
-                            // If we have no partially aggregated partitions, aggregate the unaggregated partitions using the original aggregations
-                            if partially_aggregated.is_empty() {
-                                let concated = MicroPartition::concat(&unaggregated)?;
-                                let agged = concated
-                                    .agg(&params.original_aggregations, &params.group_by)?;
-                                Ok(agged)
-                            }
-                            // If we have no unaggregated partitions, finalize the partially aggregated partitions
-                            else if unaggregated.is_empty() {
-                                let concated = MicroPartition::concat(&partially_aggregated)?;
-                                let agged = concated
-                                    .agg(&params.final_agg_exprs, &params.final_group_by)?;
-                                let projected =
-                                    agged.eval_expression_list(&params.final_projections)?;
-                                Ok(projected)
-                            }
-                            // Otherwise, partially aggregate the unaggregated partitions, concatenate them with the partially aggregated partitions, and finalize the result.
-                            else {
-                                let leftover_partial_agg =
-                                    MicroPartition::concat(&unaggregated)?
-                                        .agg(&params.partial_agg_exprs, &params.group_by)?;
-                                let concated = MicroPartition::concat(
-                                    partially_aggregated
-                                        .iter()
-                                        .chain(std::iter::once(&leftover_partial_agg)),
-                                )?;
-                                let agged = concated
-                                    .agg(&params.final_agg_exprs, &params.final_group_by)?;
-                                let projected =
-                                    agged.eval_expression_list(&params.final_projections)?;
-                                Ok(projected)
-                            }
+                            let (unaggregated, partially_aggregated) =
+                                collect_partition_state(per_partition_state)?;
+                            finalize_partition(&unaggregated, &partially_aggregated, &params)
                         });
                     }
                     let results = per_partition_finalize_tasks
@@ -416,7 +783,9 @@ impl BlockingSink for GroupedAggregateSink {
                         .into_iter()
                         .collect::<DaftResult<Vec<_>>>()?;
                     let concated = MicroPartition::concat(&results)?;
-                    Ok(Some(Arc::new(concated)))
+                    Ok(BlockingSinkFinalizeOutput::Finished(Some(Arc::new(
+                        concated,
+                    ))))
                 },
                 Span::current(),
             )
@@ -460,3 +829,100 @@ impl BlockingSink for GroupedAggregateSink {
         )))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use common_daft_config::DaftExecutionConfig;
+    use common_error::DaftResult;
+    use daft_core::{
+        prelude::{DataType, Field, Int64Array, Schema},
+        series::IntoSeries,
+    };
+    use daft_dsl::resolved_col;
+    use daft_io::IOStatsContext;
+    use daft_micropartition::MicroPartition;
+    use daft_recordbatch::RecordBatch;
+
+    use super::{
+        GroupedAggregateSink, GroupedAggregateState, MemoryBudget, SpilledAggregateStream,
+    };
+    use crate::NUM_CPUS;
+
+    fn make_part(keys: Vec<i64>) -> DaftResult<Arc<MicroPartition>> {
+        let len = keys.len();
+        let keys = Int64Array::from_values("k", keys.into_iter()).into_series();
+        let values = Int64Array::from_values("v", vec![1; len].into_iter()).into_series();
+        let schema = Arc::new(Schema::new(vec![
+            keys.field().clone(),
+            values.field().clone(),
+        ])?);
+        let table = RecordBatch::new_with_size(schema.clone(), vec![keys, values], len)?;
+        Ok(Arc::new(MicroPartition::new_loaded(
+            schema,
+            Arc::new(vec![table]),
+            None,
+        )))
+    }
+
+    #[test]
+    fn test_repartitions_spilled_partition_over_budget() -> DaftResult<()> {
+        const NUM_GROUPS: i64 = 4_000;
+        const NUM_INPUTS: i64 = 4;
+        let memory_budget = 16 * 1024;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("k", DataType::Int64),
+            Field::new("v", DataType::Int64),
+        ])?);
+        let cfg = DaftExecutionConfig {
+            grouped_aggregate_spill_threshold: memory_budget * *NUM_CPUS,
+            ..Default::default()
+        };
+        let sink = GroupedAggregateSink::new(
+            &[resolved_col("v").sum()],
+            &[resolved_col("k")],
+            &schema,
+            &cfg,
+        )?;
+        let params = sink.grouped_aggregate_params.clone();
+        assert_eq!(params.memory_budget, MemoryBudget::new(memory_budget));
+
+        // Accumulate all state into a single partition, so that its spilled state exceeds the budget.
+        let mut state = GroupedAggregateState::new(
+            1,
+            sink.partial_agg_threshold,
+            sink.high_cardinality_threshold_ratio,
+        );
+        let strategy_lock = Arc::new(Mutex::new(None));
+        for _ in 0..NUM_INPUTS {
+            state.push(
+                make_part((0..NUM_GROUPS).collect())?,
+                &params,
+                &strategy_lock,
+            )?;
+            assert!(state.should_spill(params.memory_budget));
+            state.spill(&params)?;
+        }
+        let partition_states = state.finalize();
+        let spilled_bytes = partition_states[0].as_ref().unwrap().size_bytes()?;
+        assert!(spilled_bytes > memory_budget);
+
+        let outputs = SpilledAggregateStream::new(vec![partition_states], params)
+            .collect::<DaftResult<Vec<_>>>()?;
+        assert!(outputs.len() > 1);
+
+        let output =
+            MicroPartition::concat(outputs)?.sort(&[resolved_col("k")], &[false], &[false])?;
+        let output = output.concat_or_get(IOStatsContext::new("test"))?;
+        let keys = output[0].get_column("k")?.i64()?;
+        let sums = output[0].get_column("v")?.i64()?;
+        assert_eq!(keys.len(), NUM_GROUPS as usize);
+        for (i, (key, sum)) in keys.into_iter().zip(sums).enumerate() {
+            assert_eq!(key, Some(&(i as i64)));
+            assert_eq!(sum, Some(&NUM_INPUTS));
+        }
+        Ok(())
+    }
+}
@@ -8,63 +8,183 @@ use daft_recordbatch::{make_probeable_builder, ProbeState, ProbeableBuilder, Rec
 use itertools::Itertools;
 use tracing::{info_span, instrument};
 
-use super::blocking_sink::{
-    BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
-    BlockingSinkStatus,
+use super::{
+    blocking_sink::{
+        BlockingSink, BlockingSinkFinalizeOutput, BlockingSinkFinalizeResult,
+        BlockingSinkSinkResult, BlockingSinkState, BlockingSinkStatus,
+    },
+    grace_hash_join::GRACE_HASH_JOIN_PARTITIONS,
+    spill_partition::{HashPartition, HashPartitionSpiller},
 };
-use crate::{state_bridge::BroadcastStateBridgeRef, ExecutionTaskSpawner};
+use crate::{spill::can_spill, state_bridge::BroadcastStateBridgeRef, ExecutionTaskSpawner};
+
+/// The build side of a hash join, handed to the probe side through a [`crate::state_bridge::BroadcastStateBridge`].
+pub(crate) enum BuildSide {
+    /// The build side fit within the memory budget and was built into a single probe table.
+    InMemory(Arc<ProbeState>),
+    /// The build side exceeded the memory budget and was hash partitioned to disk by its join keys.
+    /// The probe side has to be partitioned the same way, after which the join is performed partition by partition.
+    Spilled(Arc<SpilledBuildSide>),
+}
+
+/// The hash partitions of a build side that exceeded its memory budget.
+pub(crate) struct SpilledBuildSide {
+    partitions: Vec<HashPartition>,
+    schema: SchemaRef,
+    params: Arc<HashJoinBuildParams>,
+}
+
+impl SpilledBuildSide {
+    pub(crate) fn num_partitions(&self) -> usize {
+        self.partitions.len()
+    }
+
+    pub(crate) fn partition(&self, partition_idx: usize) -> &HashPartition {
+        &self.partitions[partition_idx]
+    }
+
+    /// The expressions the build side is partitioned by.
+    pub(crate) fn partition_by(&self) -> Vec<ExprRef> {
+        self.params.projection.clone()
+    }
+
+    /// Number of bytes of a partition that may be read back into memory to build its probe table.
+    pub(crate) fn memory_budget(&self) -> usize {
+        self.params.memory_budget
+    }
+
+    /// Reads a partition of the build side back into memory and builds its probe table.
+    pub(crate) fn build_probe_state(&self, partition: &HashPartition) -> DaftResult<ProbeState> {
+        let mut state = ProbeTableState::new(&self.params)?;
+        let tables = partition.read_tables()?;
+        if tables.is_empty() {
+            state.add_table(RecordBatch::empty(Some(self.schema.clone()))?)?;
+        }
+        for table in tables {
+            state.add_table(table)?;
+        }
+        Ok(state.finalize())
+    }
+}
+
+struct HashJoinBuildParams {
+    key_schema: SchemaRef,
+    projection: Vec<ExprRef>,
+    nulls_equal_aware: Option<Vec<bool>>,
+    track_indices: bool,
+    /// Number of bytes the build side may buffer before it is partitioned and spilled to disk.
+    memory_budget: usize,
+}
 
 enum ProbeTableState {
     Building {
         probe_table_builder: Option<Box<dyn ProbeableBuilder>>,
         projection: Vec<ExprRef>,
         tables: Vec<RecordBatch>,
+        buffered_bytes: usize,
+        spill_disabled: bool,
+    },
+    /// The build side exceeded the memory budget, so its tables are hash partitioned by their join keys and spilled to disk.
+    Spilling {
+        spiller: HashPartitionSpiller,
+        schema: SchemaRef,
     },
     Done,
 }
 
 impl ProbeTableState {
-    fn new(
-        key_schema: &SchemaRef,
-        projection: Vec<ExprRef>,
-        nulls_equal_aware: Option<&Vec<bool>>,
-        track_indices: bool,
-    ) -> DaftResult<Self> {
+    fn new(params: &HashJoinBuildParams) -> DaftResult<Self> {
         Ok(Self::Building {
             probe_table_builder: Some(make_probeable_builder(
-                key_schema.clone(),
-                nulls_equal_aware,
-                track_indices,
+                params.key_schema.clone(),
+                params.nulls_equal_aware.as_ref(),
+                params.track_indices,
             )?),
-            projection,
+            projection: params.projection.clone(),
             tables: Vec::new(),
+            buffered_bytes: 0,
+            spill_disabled: false,
         })
     }
 
-    fn add_tables(&mut self, input: &Arc<MicroPartition>) -> DaftResult<()> {
+    fn add_table(&mut self, table: RecordBatch) -> DaftResult<()> {
         if let Self::Building {
             ref mut probe_table_builder,
             projection,
             tables,
+            ..
         } = self
         {
-            let probe_table_builder = probe_table_builder.as_mut().unwrap();
-            let input_tables = input.get_tables()?;
-            if input_tables.is_empty() {
-                tables.push(RecordBatch::empty(Some(input.schema()))?);
-                return Ok(());
-            }
-            for table in input_tables.iter() {
-                tables.push(table.clone());
-                let join_keys = table.eval_expression_list(projection)?;
-
-                probe_table_builder.add_table(&join_keys)?;
-            }
+            let join_keys = table.eval_expression_list(projection)?;
+            probe_table_builder
+                .as_mut()
+                .unwrap()
+                .add_table(&join_keys)?;
+            tables.push(table);
             Ok(())
         } else {
-            panic!("add_tables can only be used during the Building Phase")
+            panic!("add_table can only be used during the Building Phase")
+        }
+    }
+
+    fn add_tables(
+        &mut self,
+        input: &Arc<MicroPartition>,
+        params: &HashJoinBuildParams,
+    ) -> DaftResult<()> {
+        match self {
+            Self::Building {
+                tables,
+                buffered_bytes,
+                spill_disabled,
+                ..
+            } => {
+                let input_tables = input.get_tables()?;
+                if input_tables.is_empty() {
+                    tables.push(RecordBatch::empty(Some(input.schema()))?);
+                    return Ok(());
+                }
+                *buffered_bytes += input.size_bytes()?.unwrap_or(0);
+                if *buffered_bytes > params.memory_budget && !*spill_disabled {
+                    if can_spill(&input.schema()) {
+                        return self.start_spilling(input, params);
+                    }
+                    // The build side cannot be spilled, e.g. because it contains Python objects, so keep it in memory.
+                    *spill_disabled = true;
+                }
+                for table in input_tables.iter() {
+                    self.add_table(table.clone())?;
+                }
+                Ok(())
+            }
+            Self::Spilling { spiller, .. } => spiller.push(&input.get_tables()?),
+            Self::Done => panic!("add_tables can only be used during the Building Phase"),
         }
     }
+
+    /// Switches to partitioning the build side to disk, starting with the tables buffered so far.
+    fn start_spilling(
+        &mut self,
+        input: &Arc<MicroPartition>,
+        params: &HashJoinBuildParams,
+    ) -> DaftResult<()> {
+        let Self::Building { tables, .. } = self else {
+            panic!("start_spilling can only be used during the Building Phase")
+        };
+        let mut spiller = HashPartitionSpiller::new(
+            params.projection.clone(),
+            GRACE_HASH_JOIN_PARTITIONS,
+            params.memory_budget,
+        );
+        spiller.push(&std::mem::take(tables))?;
+        spiller.push(&input.get_tables()?)?;
+        *self = Self::Spilling {
+            spiller,
+            schema: input.schema(),
+        };
+        Ok(())
+    }
+
     fn finalize(&mut self) -> ProbeState {
         if let Self::Building {
             probe_table_builder,
@@ -75,13 +195,29 @@ impl ProbeTableState {
             let ptb = std::mem::take(probe_table_builder).expect("should be set in building mode");
             let pt = ptb.build();
 
-            let ps = ProbeState::new(pt, tables.clone().into());
+            let ps = ProbeState::new(pt, std::mem::take(tables).into());
             *self = Self::Done;
             ps
         } else {
             panic!("finalize can only be used during the Building Phase")
         }
     }
+
+    fn finalize_build_side(&mut self, params: &Arc<HashJoinBuildParams>) -> DaftResult<BuildSide> {
+        match std::mem::replace(self, Self::Done) {
+            Self::Spilling { spiller, schema } => {
+                Ok(BuildSide::Spilled(Arc::new(SpilledBuildSide {
+                    partitions: spiller.finish()?,
+                    schema,
+                    params: params.clone(),
+                })))
+            }
+            mut building @ Self::Building { .. } => {
+                Ok(BuildSide::InMemory(Arc::new(building.finalize())))
+            }
+            Self::Done => panic!("finalize can only be used during the Building Phase"),
+        }
+    }
 }
 
 impl BlockingSinkState for ProbeTableState {
@@ -91,11 +227,8 @@ impl BlockingSinkState for ProbeTableState {
 }
 
 pub struct HashJoinBuildSink {
-    key_schema: SchemaRef,
-    projection: Vec<ExprRef>,
-    nulls_equal_aware: Option<Vec<bool>>,
-    track_indices: bool,
-    probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
+    params: Arc<HashJoinBuildParams>,
+    probe_state_bridge: BroadcastStateBridgeRef<BuildSide>,
 }
 
 impl HashJoinBuildSink {
@@ -104,13 +237,17 @@ impl HashJoinBuildSink {
         projection: Vec<ExprRef>,
         nulls_equal_aware: Option<Vec<bool>>,
         track_indices: bool,
-        probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
+        memory_budget: usize,
+        probe_state_bridge: BroadcastStateBridgeRef<BuildSide>,
     ) -> DaftResult<Self> {
         Ok(Self {
-            key_schema,
-            projection,
-            nulls_equal_aware,
-            track_indices,
+            params: Arc::new(HashJoinBuildParams {
+                key_schema,
+                projection,
+                nulls_equal_aware,
+                track_indices,
+                memory_budget,
+            }),
             probe_state_bridge,
         })
     }
@@ -124,9 +261,12 @@ impl BlockingSink for HashJoinBuildSink {
     fn multiline_display(&self) -> Vec<String> {
         let mut display = vec![];
         display.push("HashJoinBuild:".to_string());
-        display.push(format!("Track Indices: {}", self.track_indices));
-        display.push(format!("Key Schema: {}", self.key_schema.short_string()));
-        if let Some(null_equals_nulls) = &self.nulls_equal_aware {
+        display.push(format!("Track Indices: {}", self.params.track_indices));
+        display.push(format!(
+            "Key Schema: {}",
+            self.params.key_schema.short_string()
+        ));
+        if let Some(null_equals_nulls) = &self.params.nulls_equal_aware {
             display.push(format!(
                 "Null equals Nulls = [{}]",
                 null_equals_nulls.iter().map(|b| b.to_string()).join(", ")
@@ -141,6 +281,7 @@ impl BlockingSink for HashJoinBuildSink {
         mut state: Box<dyn BlockingSinkState>,
         spawner: &ExecutionTaskSpawner,
     ) -> BlockingSinkSinkResult {
+        let params = self.params.clone();
         spawner
             .spawn(
                 async move {
@@ -148,7 +289,7 @@ impl BlockingSink for HashJoinBuildSink {
                         .as_any_mut()
                         .downcast_mut::<ProbeTableState>()
                         .expect("HashJoinBuildSink should have ProbeTableState");
-                    probe_table_state.add_tables(&input)?;
+                    probe_table_state.add_tables(&input, &params)?;
                     Ok(BlockingSinkStatus::NeedMoreInput(state))
                 },
                 info_span!("HashJoinBuildSink::sink"),
@@ -168,10 +309,12 @@ impl BlockingSink for HashJoinBuildSink {
             .as_any_mut()
             .downcast_mut::<ProbeTableState>()
             .expect("State type mismatch");
-        let finalized_probe_state = probe_table_state.finalize();
-        self.probe_state_bridge
-            .set_state(finalized_probe_state.into());
-        Ok(None).into()
+        let build_side = match probe_table_state.finalize_build_side(&self.params) {
+            Ok(build_side) => build_side,
+            Err(e) => return Err(e).into(),
+        };
+        self.probe_state_bridge.set_state(build_side.into());
+        Ok(BlockingSinkFinalizeOutput::Finished(None)).into()
     }
 
     fn max_concurrency(&self) -> usize {
@@ -179,11 +322,6 @@ impl BlockingSink for HashJoinBuildSink {
     }
 
     fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
-        Ok(Box::new(ProbeTableState::new(
-            &self.key_schema,
-            self.projection.clone(),
-            self.nulls_equal_aware.as_ref(),
-            self.track_indices,
-        )?))
+        Ok(Box::new(ProbeTableState::new(&self.params)?))
     }
 }
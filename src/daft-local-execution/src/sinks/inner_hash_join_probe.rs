@@ -0,0 +1,320 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::{GrowableRecordBatch, ProbeState};
+use indexmap::IndexSet;
+use itertools::Itertools;
+use tracing::{info_span, instrument, Span};
+
+use super::{
+    grace_hash_join::{GraceHashJoinStream, GraceJoinProbe, SpilledProbeSide},
+    hash_join_build::BuildSide,
+    spill_partition::MemoryBudget,
+    streaming_sink::{
+        StreamingSink, StreamingSinkExecuteResult, StreamingSinkFinalizeOutput,
+        StreamingSinkFinalizeResult, StreamingSinkOutput, StreamingSinkState,
+    },
+};
+use crate::{
+    dispatcher::{DispatchSpawner, RoundRobinDispatcher, UnorderedDispatcher},
+    state_bridge::BroadcastStateBridgeRef,
+    ExecutionRuntimeContext, ExecutionTaskSpawner,
+};
+
+enum InnerHashJoinProbeState {
+    Building(BroadcastStateBridgeRef<BuildSide>),
+    Probing(Arc<ProbeState>),
+    Partitioning(SpilledProbeSide),
+    Done,
+}
+
+impl InnerHashJoinProbeState {
+    async fn await_build_side(&mut self, params: &InnerHashJoinParams) {
+        if let Self::Building(bridge) = self {
+            *self = match bridge.get_state().await.as_ref() {
+                BuildSide::InMemory(probe_state) => Self::Probing(probe_state.clone()),
+                BuildSide::Spilled(build_side) => Self::Partitioning(SpilledProbeSide::new(
+                    build_side.clone(),
+                    params.probe_on.clone(),
+                    params.memory_budget,
+                )),
+            };
+        }
+    }
+}
+
+impl StreamingSinkState for InnerHashJoinProbeState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+struct InnerHashJoinParams {
+    probe_on: Vec<ExprRef>,
+    common_join_keys: Vec<String>,
+    left_non_join_columns: Vec<String>,
+    right_non_join_columns: Vec<String>,
+    build_on_left: bool,
+    memory_budget: MemoryBudget,
+}
+
+impl GraceJoinProbe for InnerHashJoinParams {
+    type PartitionState = ();
+
+    fn make_partition_state(&self, _probe_state: &ProbeState) -> Self::PartitionState {}
+
+    fn probe(
+        &self,
+        input: &Arc<MicroPartition>,
+        probe_state: &ProbeState,
+        _state: &mut Self::PartitionState,
+    ) -> DaftResult<Option<Arc<MicroPartition>>> {
+        InnerHashJoinProbeSink::probe_inner(input, probe_state, self).map(Some)
+    }
+
+    fn finish_partition(
+        &self,
+        _probe_state: &ProbeState,
+        _state: Self::PartitionState,
+    ) -> DaftResult<Option<Arc<MicroPartition>>> {
+        Ok(None)
+    }
+}
+
+pub(crate) struct InnerHashJoinProbeSink {
+    params: Arc<InnerHashJoinParams>,
+    output_schema: SchemaRef,
+    probe_state_bridge: BroadcastStateBridgeRef<BuildSide>,
+}
+
+impl InnerHashJoinProbeSink {
+    const DEFAULT_GROWABLE_SIZE: usize = 20;
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        probe_on: Vec<ExprRef>,
+        left_schema: &SchemaRef,
+        right_schema: &SchemaRef,
+        build_on_left: bool,
+        common_join_keys: IndexSet<String>,
+        output_schema: &SchemaRef,
+        memory_budget: usize,
+        probe_state_bridge: BroadcastStateBridgeRef<BuildSide>,
+    ) -> Self {
+        let left_non_join_columns = left_schema
+            .fields
+            .keys()
+            .filter(|c| !common_join_keys.contains(*c))
+            .cloned()
+            .collect();
+        let right_non_join_columns = right_schema
+            .fields
+            .keys()
+            .filter(|c| !common_join_keys.contains(*c))
+            .cloned()
+            .collect();
+        let common_join_keys = common_join_keys.into_iter().collect();
+        Self {
+            params: Arc::new(InnerHashJoinParams {
+                probe_on,
+                common_join_keys,
+                left_non_join_columns,
+                right_non_join_columns,
+                build_on_left,
+                memory_budget: MemoryBudget::new(memory_budget),
+            }),
+            output_schema: output_schema.clone(),
+            probe_state_bridge,
+        }
+    }
+
+    fn probe_inner(
+        input: &Arc<MicroPartition>,
+        probe_state: &ProbeState,
+        params: &InnerHashJoinParams,
+    ) -> DaftResult<Arc<MicroPartition>> {
+        let probe_table = probe_state.get_probeable();
+        let tables = probe_state.get_tables();
+
+        let _growables = info_span!("InnerHashJoinProbeSink::build_growables").entered();
+
+        let mut build_side_growable = GrowableRecordBatch::new(
+            &tables.iter().collect::<Vec<_>>(),
+            false,
+            Self::DEFAULT_GROWABLE_SIZE,
+        )?;
+
+        let input_tables = input.get_tables()?;
+
+        let mut probe_side_growable = GrowableRecordBatch::new(
+            &input_tables.iter().collect::<Vec<_>>(),
+            false,
+            Self::DEFAULT_GROWABLE_SIZE,
+        )?;
+
+        drop(_growables);
+        {
+            let _loop = info_span!("InnerHashJoinProbeSink::eval_and_probe").entered();
+            for (probe_side_table_idx, table) in input_tables.iter().enumerate() {
+                // we should emit one table at a time when this is streaming
+                let join_keys = table.eval_expression_list(&params.probe_on)?;
+                let idx_mapper = probe_table.probe_indices(&join_keys)?;
+
+                for (probe_row_idx, inner_iter) in idx_mapper.make_iter().enumerate() {
+                    if let Some(inner_iter) = inner_iter {
+                        for (build_side_table_idx, build_row_idx) in inner_iter {
+                            build_side_growable.extend(
+                                build_side_table_idx as usize,
+                                build_row_idx as usize,
+                                1,
+                            );
+                            // we can perform run length compression for this to make this more efficient
+                            probe_side_growable.extend(probe_side_table_idx, probe_row_idx, 1);
+                        }
+                    }
+                }
+            }
+        }
+        let build_side_table = build_side_growable.build()?;
+        let probe_side_table = probe_side_growable.build()?;
+
+        let (left_table, right_table) = if params.build_on_left {
+            (build_side_table, probe_side_table)
+        } else {
+            (probe_side_table, build_side_table)
+        };
+
+        let join_keys_table = left_table.get_columns(&params.common_join_keys)?;
+        let left_non_join_columns = left_table.get_columns(&params.left_non_join_columns)?;
+        let right_non_join_columns = right_table.get_columns(&params.right_non_join_columns)?;
+        let final_table = join_keys_table
+            .union(&left_non_join_columns)?
+            .union(&right_non_join_columns)?;
+
+        Ok(Arc::new(MicroPartition::new_loaded(
+            final_table.schema.clone(),
+            Arc::new(vec![final_table]),
+            None,
+        )))
+    }
+}
+
+impl StreamingSink for InnerHashJoinProbeSink {
+    #[instrument(skip_all, name = "InnerHashJoinProbeSink::execute")]
+    fn execute(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn StreamingSinkState>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> StreamingSinkExecuteResult {
+        if input.is_empty() {
+            let empty = Arc::new(MicroPartition::empty(Some(self.output_schema.clone())));
+            return Ok((state, StreamingSinkOutput::NeedMoreInput(Some(empty)))).into();
+        }
+
+        let params = self.params.clone();
+        spawner
+            .spawn(
+                async move {
+                    let inner_join_state = state
+                        .as_any_mut()
+                        .downcast_mut::<InnerHashJoinProbeState>()
+                        .expect(
+                            "InnerHashJoinProbeState should be used with InnerHashJoinProbeSink",
+                        );
+                    inner_join_state.await_build_side(&params).await;
+                    let res = match inner_join_state {
+                        InnerHashJoinProbeState::Probing(probe_state) => {
+                            Some(Self::probe_inner(&input, probe_state, &params)?)
+                        }
+                        InnerHashJoinProbeState::Partitioning(probe_side) => {
+                            probe_side.push(&input)?;
+                            None
+                        }
+                        _ => unreachable!("build side should be available after awaiting it"),
+                    };
+                    Ok((state, StreamingSinkOutput::NeedMoreInput(res)))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "InnerHashJoinProbe"
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push("InnerHashJoinProbe:".to_string());
+        res.push(format!(
+            "Probe on: [{}]",
+            self.params
+                .probe_on
+                .iter()
+                .map(|e| e.to_string())
+                .join(", ")
+        ));
+        res.push(format!("Build on left: {}", self.params.build_on_left));
+        res
+    }
+
+    fn make_state(&self) -> Box<dyn StreamingSinkState> {
+        Box::new(InnerHashJoinProbeState::Building(
+            self.probe_state_bridge.clone(),
+        ))
+    }
+
+    #[instrument(skip_all, name = "InnerHashJoinProbeSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn StreamingSinkState>>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> StreamingSinkFinalizeResult {
+        let params = self.params.clone();
+        spawner
+            .spawn(
+                async move {
+                    let mut probe_sides = Vec::with_capacity(states.len());
+                    for mut state in states {
+                        let state = state
+                            .as_any_mut()
+                            .downcast_mut::<InnerHashJoinProbeState>()
+                            .expect("InnerHashJoinProbeState should be used with InnerHashJoinProbeSink");
+                        state.await_build_side(&params).await;
+                        if let InnerHashJoinProbeState::Partitioning(probe_side) =
+                            std::mem::replace(state, InnerHashJoinProbeState::Done)
+                        {
+                            probe_sides.push(probe_side);
+                        }
+                    }
+                    // Only a spilled build side leaves any work for finalize.
+                    let Some(stream) = GraceHashJoinStream::try_new(probe_sides, params)? else {
+                        return Ok(StreamingSinkFinalizeOutput::Finished(None));
+                    };
+                    Ok(StreamingSinkFinalizeOutput::Streaming(Box::new(stream)))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn dispatch_spawner(
+        &self,
+        runtime_handle: &ExecutionRuntimeContext,
+        maintain_order: bool,
+    ) -> Arc<dyn DispatchSpawner> {
+        if maintain_order {
+            Arc::new(RoundRobinDispatcher::new(Some(
+                runtime_handle.default_morsel_size(),
+            )))
+        } else {
+            Arc::new(UnorderedDispatcher::new(Some(
+                runtime_handle.default_morsel_size(),
+            )))
+        }
+    }
+}
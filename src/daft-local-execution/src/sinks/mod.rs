@@ -1,14 +1,23 @@
 pub mod aggregate;
 pub mod anti_semi_hash_join_probe;
+pub mod asof_join_build;
 pub mod blocking_sink;
+#[cfg(feature = "python")]
+pub mod catalog_table_write;
 pub mod concat;
 pub mod cross_join_collect;
+pub mod grace_hash_join;
 pub mod grouped_aggregate;
 pub mod hash_join_build;
+pub mod inner_hash_join_probe;
 pub mod limit;
 pub mod monotonically_increasing_id;
+pub mod nested_loop_join;
+pub mod nested_loop_join_collect;
 pub mod outer_hash_join_probe;
 pub mod pivot;
 pub mod sort;
+pub mod spill_partition;
 pub mod streaming_sink;
+pub mod window;
 pub mod write;
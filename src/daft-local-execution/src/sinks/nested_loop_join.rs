@@ -0,0 +1,297 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::{
+    array::ops::as_arrow::AsArrow,
+    prelude::{
+        bitmap::{or, Bitmap, MutableBitmap},
+        *,
+    },
+};
+use daft_dsl::ExprRef;
+use daft_logical_plan::JoinType;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+use itertools::Itertools;
+use tracing::{instrument, Span};
+
+use super::streaming_sink::{
+    StreamingSink, StreamingSinkExecuteResult, StreamingSinkFinalizeOutput,
+    StreamingSinkFinalizeResult, StreamingSinkOutput, StreamingSinkState,
+};
+use crate::{
+    dispatcher::{DispatchSpawner, RoundRobinDispatcher, UnorderedDispatcher},
+    state_bridge::BroadcastStateBridgeRef,
+    ExecutionRuntimeContext, ExecutionTaskSpawner,
+};
+
+struct NestedLoopJoinState {
+    bridge: BroadcastStateBridgeRef<RecordBatch>,
+    // For outer joins, the rows of the collected right side that were matched by this worker.
+    right_matched: Option<MutableBitmap>,
+}
+
+impl NestedLoopJoinState {
+    fn new(bridge: BroadcastStateBridgeRef<RecordBatch>) -> Self {
+        Self {
+            bridge,
+            right_matched: None,
+        }
+    }
+}
+
+impl StreamingSinkState for NestedLoopJoinState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+struct NestedLoopJoinParams {
+    left_on: Vec<ExprRef>,
+    right_on: Vec<ExprRef>,
+    null_equals_nulls: Vec<bool>,
+    predicate: ExprRef,
+    join_type: JoinType,
+    left_schema: SchemaRef,
+    output_schema: SchemaRef,
+}
+
+/// Joins each morsel of the streamed side against the whole collected side, keeping the pairs of rows
+/// whose join keys are equal and for which the join predicate holds.
+///
+/// The left side is streamed, except for right joins, for which the right side is streamed.
+/// For outer joins, the matched rows of the collected right side are tracked, and the unmatched ones are emitted on finalize.
+pub struct NestedLoopJoinSink {
+    params: Arc<NestedLoopJoinParams>,
+    state_bridge: BroadcastStateBridgeRef<RecordBatch>,
+}
+
+impl NestedLoopJoinSink {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        left_on: Vec<ExprRef>,
+        right_on: Vec<ExprRef>,
+        null_equals_nulls: Option<Vec<bool>>,
+        predicate: ExprRef,
+        join_type: JoinType,
+        left_schema: SchemaRef,
+        output_schema: SchemaRef,
+        state_bridge: BroadcastStateBridgeRef<RecordBatch>,
+    ) -> Self {
+        let null_equals_nulls = null_equals_nulls.unwrap_or_else(|| vec![false; left_on.len()]);
+        Self {
+            params: Arc::new(NestedLoopJoinParams {
+                left_on,
+                right_on,
+                null_equals_nulls,
+                predicate,
+                join_type,
+                left_schema,
+                output_schema,
+            }),
+            state_bridge,
+        }
+    }
+
+    fn join(
+        input: &MicroPartition,
+        state: &mut NestedLoopJoinState,
+        collected: &RecordBatch,
+        params: &NestedLoopJoinParams,
+    ) -> DaftResult<RecordBatch> {
+        let input_tables = input.get_tables()?;
+        let streamed = if input_tables.is_empty() {
+            RecordBatch::empty(Some(input.schema()))?
+        } else {
+            RecordBatch::concat(input_tables.as_slice())?
+        };
+
+        match params.join_type {
+            JoinType::Right => collected.nested_loop_join(
+                &streamed,
+                &params.left_on,
+                &params.right_on,
+                &params.null_equals_nulls,
+                &params.predicate,
+                JoinType::Right,
+            ),
+            // The unmatched rows of the collected right side are only known once every left row has been joined,
+            // so each morsel is left joined and the matched right rows are tracked.
+            JoinType::Outer => {
+                let (joined, right_matches) = streamed.nested_loop_join_with_right_matches(
+                    collected,
+                    &params.left_on,
+                    &params.right_on,
+                    &params.null_equals_nulls,
+                    &params.predicate,
+                    JoinType::Left,
+                )?;
+                let right_matched = state
+                    .right_matched
+                    .get_or_insert_with(|| MutableBitmap::from_len_zeroed(collected.len()));
+                for r in right_matches.u64()?.as_arrow().values_iter() {
+                    right_matched.set(*r as usize, true);
+                }
+                joined.cast_to_schema(&params.output_schema)
+            }
+            how => streamed.nested_loop_join(
+                collected,
+                &params.left_on,
+                &params.right_on,
+                &params.null_equals_nulls,
+                &params.predicate,
+                how,
+            ),
+        }
+    }
+
+    /// Emits the rows of the collected right side that were not matched by any left row, padded with nulls.
+    fn finalize_outer(
+        collected: &RecordBatch,
+        right_matched: Option<Bitmap>,
+        params: &NestedLoopJoinParams,
+    ) -> DaftResult<RecordBatch> {
+        let unmatched = match right_matched {
+            Some(matched) => collected
+                .mask_filter(&BooleanArray::from(("unmatched", !&matched)).into_series())?,
+            None => collected.clone(),
+        };
+        // Right joining the unmatched rows to an empty left side pads them with nulls.
+        RecordBatch::empty(Some(params.left_schema.clone()))?
+            .nested_loop_join(
+                &unmatched,
+                &params.left_on,
+                &params.right_on,
+                &params.null_equals_nulls,
+                &params.predicate,
+                JoinType::Right,
+            )?
+            .cast_to_schema(&params.output_schema)
+    }
+}
+
+impl StreamingSink for NestedLoopJoinSink {
+    #[instrument(skip_all, name = "NestedLoopJoinSink::execute")]
+    fn execute(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn StreamingSinkState>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> StreamingSinkExecuteResult {
+        if input.is_empty() {
+            let empty = Arc::new(MicroPartition::empty(Some(
+                self.params.output_schema.clone(),
+            )));
+            return Ok((state, StreamingSinkOutput::NeedMoreInput(Some(empty)))).into();
+        }
+
+        let params = self.params.clone();
+        spawner
+            .spawn(
+                async move {
+                    let nested_loop_join_state = state
+                        .as_any_mut()
+                        .downcast_mut::<NestedLoopJoinState>()
+                        .expect("NestedLoopJoinSink should have NestedLoopJoinState");
+                    let collected = nested_loop_join_state.bridge.get_state().await;
+                    let output = Self::join(&input, nested_loop_join_state, &collected, &params)?;
+                    let output = Arc::new(MicroPartition::new_loaded(
+                        params.output_schema.clone(),
+                        Arc::new(vec![output]),
+                        None,
+                    ));
+                    Ok((state, StreamingSinkOutput::NeedMoreInput(Some(output))))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "NestedLoopJoin"
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![format!("NestedLoopJoin: Type = {}", self.params.join_type)];
+        if !self.params.left_on.is_empty() {
+            res.push(format!(
+                "Left on: [{}]",
+                self.params.left_on.iter().map(|e| e.to_string()).join(", ")
+            ));
+            res.push(format!(
+                "Right on: [{}]",
+                self.params
+                    .right_on
+                    .iter()
+                    .map(|e| e.to_string())
+                    .join(", ")
+            ));
+        }
+        res.push(format!("Predicate = {}", self.params.predicate));
+        res
+    }
+
+    fn make_state(&self) -> Box<dyn StreamingSinkState> {
+        Box::new(NestedLoopJoinState::new(self.state_bridge.clone()))
+    }
+
+    #[instrument(skip_all, name = "NestedLoopJoinSink::finalize")]
+    fn finalize(
+        &self,
+        mut states: Vec<Box<dyn StreamingSinkState>>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> StreamingSinkFinalizeResult {
+        if self.params.join_type != JoinType::Outer {
+            return Ok(StreamingSinkFinalizeOutput::Finished(None)).into();
+        }
+
+        let params = self.params.clone();
+        let state_bridge = self.state_bridge.clone();
+        spawner
+            .spawn(
+                async move {
+                    let collected = state_bridge.get_state().await;
+                    let mut right_matched: Option<Bitmap> = None;
+                    for state in &mut states {
+                        let state = state
+                            .as_any_mut()
+                            .downcast_mut::<NestedLoopJoinState>()
+                            .expect("NestedLoopJoinSink should have NestedLoopJoinState");
+                        if let Some(matched) = state.right_matched.take() {
+                            let matched: Bitmap = matched.into();
+                            right_matched = Some(match right_matched {
+                                Some(acc) => or(&acc, &matched),
+                                None => matched,
+                            });
+                        }
+                    }
+                    let leftovers = Self::finalize_outer(&collected, right_matched, &params)?;
+                    Ok(StreamingSinkFinalizeOutput::Finished(Some(Arc::new(
+                        MicroPartition::new_loaded(
+                            params.output_schema.clone(),
+                            Arc::new(vec![leftovers]),
+                            None,
+                        ),
+                    ))))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn dispatch_spawner(
+        &self,
+        runtime_handle: &ExecutionRuntimeContext,
+        maintain_order: bool,
+    ) -> Arc<dyn DispatchSpawner> {
+        if maintain_order {
+            Arc::new(RoundRobinDispatcher::new(Some(
+                runtime_handle.default_morsel_size(),
+            )))
+        } else {
+            Arc::new(UnorderedDispatcher::new(Some(
+                runtime_handle.default_morsel_size(),
+            )))
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::prelude::SchemaRef;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+use tracing::{info_span, instrument, Span};
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeOutput, BlockingSinkFinalizeResult, BlockingSinkSinkResult,
+    BlockingSinkState, BlockingSinkStatus,
+};
+use crate::{state_bridge::BroadcastStateBridgeRef, ExecutionTaskSpawner};
+
+struct NestedLoopJoinCollectState(Option<Vec<RecordBatch>>);
+
+impl BlockingSinkState for NestedLoopJoinCollectState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Collects the non-streamed side of a nested loop join and concatenates it into a single table,
+/// which is shared with every worker of the [`super::nested_loop_join::NestedLoopJoinSink`].
+pub struct NestedLoopJoinCollectSink {
+    schema: SchemaRef,
+    state_bridge: BroadcastStateBridgeRef<RecordBatch>,
+}
+
+impl NestedLoopJoinCollectSink {
+    pub(crate) fn new(
+        schema: SchemaRef,
+        state_bridge: BroadcastStateBridgeRef<RecordBatch>,
+    ) -> Self {
+        Self {
+            schema,
+            state_bridge,
+        }
+    }
+}
+
+impl BlockingSink for NestedLoopJoinCollectSink {
+    fn name(&self) -> &'static str {
+        "NestedLoopJoinCollect"
+    }
+
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn BlockingSinkState>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkSinkResult {
+        if input.is_empty() {
+            return Ok(BlockingSinkStatus::NeedMoreInput(state)).into();
+        }
+
+        spawner
+            .spawn(
+                async move {
+                    let collect_state = state
+                        .as_any_mut()
+                        .downcast_mut::<NestedLoopJoinCollectState>()
+                        .expect("NestedLoopJoinCollectSink should have NestedLoopJoinCollectState");
+
+                    collect_state
+                        .0
+                        .as_mut()
+                        .expect("Collected tables should not be consumed before sink stage is done")
+                        .extend(input.get_tables()?.iter().cloned());
+
+                    Ok(BlockingSinkStatus::NeedMoreInput(state))
+                },
+                info_span!("NestedLoopJoinCollectSink::sink"),
+            )
+            .into()
+    }
+
+    #[instrument(skip_all, name = "NestedLoopJoinCollectSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn BlockingSinkState>>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkFinalizeResult {
+        let schema = self.schema.clone();
+        let state_bridge = self.state_bridge.clone();
+        spawner
+            .spawn(
+                async move {
+                    let mut state = states.into_iter().next().unwrap();
+                    let tables = state
+                        .as_any_mut()
+                        .downcast_mut::<NestedLoopJoinCollectState>()
+                        .expect("NestedLoopJoinCollectSink should have NestedLoopJoinCollectState")
+                        .0
+                        .take()
+                        .expect("Nested loop join collect state should have tables before finalize is called");
+
+                    // Concatenated once here, so that the streaming workers share a single table.
+                    let collected = if tables.is_empty() {
+                        RecordBatch::empty(Some(schema))?
+                    } else {
+                        RecordBatch::concat(tables.as_slice())?
+                    };
+                    state_bridge.set_state(Arc::new(collected));
+                    Ok(BlockingSinkFinalizeOutput::Finished(None))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
+        Ok(Box::new(NestedLoopJoinCollectState(Some(Vec::new()))))
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        vec!["NestedLoopJoinCollect".to_string()]
+    }
+
+    fn max_concurrency(&self) -> usize {
+        1
+    }
+}
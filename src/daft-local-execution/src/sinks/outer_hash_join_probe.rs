@@ -11,14 +11,18 @@ use daft_dsl::ExprRef;
 use daft_logical_plan::JoinType;
 use daft_micropartition::MicroPartition;
 use daft_recordbatch::{GrowableRecordBatch, ProbeState, RecordBatch};
-use futures::{stream, StreamExt};
 use indexmap::IndexSet;
 use itertools::Itertools;
 use tracing::{info_span, instrument, Span};
 
-use super::streaming_sink::{
-    StreamingSink, StreamingSinkExecuteResult, StreamingSinkFinalizeResult, StreamingSinkOutput,
-    StreamingSinkState,
+use super::{
+    grace_hash_join::{GraceHashJoinStream, GraceJoinProbe, SpilledProbeSide},
+    hash_join_build::BuildSide,
+    spill_partition::MemoryBudget,
+    streaming_sink::{
+        StreamingSink, StreamingSinkExecuteResult, StreamingSinkFinalizeOutput,
+        StreamingSinkFinalizeResult, StreamingSinkOutput, StreamingSinkState,
+    },
 };
 use crate::{
     dispatcher::{DispatchSpawner, RoundRobinDispatcher, UnorderedDispatcher},
@@ -79,39 +83,55 @@ impl IndexBitmap {
             .into_iter()
             .map(|b| BooleanArray::from(("bitmap", b)))
     }
+
+    /// Returns the rows of `tables` whose bits are set.
+    pub fn filter_tables(self, tables: &[RecordBatch]) -> DaftResult<RecordBatch> {
+        let filtered = self
+            .convert_to_boolean_arrays()
+            .zip(tables.iter())
+            .map(|(bitmap, table)| table.mask_filter(&bitmap.into_series()))
+            .collect::<DaftResult<Vec<_>>>()?;
+        RecordBatch::concat(&filtered)
+    }
 }
 
 enum OuterHashJoinState {
-    Building(BroadcastStateBridgeRef<ProbeState>, bool),
+    Building(BroadcastStateBridgeRef<BuildSide>, bool),
     Probing(Arc<ProbeState>, Option<IndexBitmapBuilder>),
+    Partitioning(SpilledProbeSide),
+    Done,
 }
 
 impl OuterHashJoinState {
-    async fn get_or_build_probe_state(&mut self) -> Arc<ProbeState> {
+    async fn await_build_side(&mut self, params: &OuterHashJoinParams) {
+        if let Self::Building(bridge, needs_bitmap) = self {
+            let needs_bitmap = *needs_bitmap;
+            *self = match bridge.get_state().await.as_ref() {
+                BuildSide::InMemory(probe_state) => {
+                    let builder =
+                        needs_bitmap.then(|| IndexBitmapBuilder::new(probe_state.get_tables()));
+                    Self::Probing(probe_state.clone(), builder)
+                }
+                BuildSide::Spilled(build_side) => Self::Partitioning(SpilledProbeSide::new(
+                    build_side.clone(),
+                    params.probe_on.clone(),
+                    params.memory_budget,
+                )),
+            };
+        }
+    }
+
+    fn get_probe_state(&self) -> Arc<ProbeState> {
         match self {
-            Self::Building(bridge, needs_bitmap) => {
-                let probe_state = bridge.get_state().await;
-                let builder =
-                    needs_bitmap.then(|| IndexBitmapBuilder::new(probe_state.get_tables()));
-                *self = Self::Probing(probe_state.clone(), builder);
-                probe_state
-            }
             Self::Probing(probe_state, _) => probe_state.clone(),
+            _ => panic!("OuterHashJoinState should be in Probing state"),
         }
     }
 
-    async fn get_or_build_bitmap(&mut self) -> &mut Option<IndexBitmapBuilder> {
+    fn get_bitmap(&mut self) -> &mut Option<IndexBitmapBuilder> {
         match self {
-            Self::Building(bridge, _) => {
-                let probe_state = bridge.get_state().await;
-                let builder = IndexBitmapBuilder::new(probe_state.get_tables());
-                *self = Self::Probing(probe_state, Some(builder));
-                match self {
-                    Self::Probing(_, builder) => builder,
-                    _ => unreachable!(),
-                }
-            }
             Self::Probing(_, builder) => builder,
+            _ => panic!("OuterHashJoinState should be in Probing state"),
         }
     }
 }
@@ -132,13 +152,48 @@ struct OuterHashJoinParams {
     right_non_join_schema: SchemaRef,
     join_type: JoinType,
     build_on_left: bool,
+    needs_bitmap: bool,
+    memory_budget: MemoryBudget,
+}
+
+impl GraceJoinProbe for OuterHashJoinParams {
+    type PartitionState = Option<IndexBitmapBuilder>;
+
+    fn make_partition_state(&self, probe_state: &ProbeState) -> Self::PartitionState {
+        self.needs_bitmap
+            .then(|| IndexBitmapBuilder::new(probe_state.get_tables()))
+    }
+
+    fn probe(
+        &self,
+        input: &Arc<MicroPartition>,
+        probe_state: &ProbeState,
+        state: &mut Self::PartitionState,
+    ) -> DaftResult<Option<Arc<MicroPartition>>> {
+        OuterHashJoinProbeSink::probe(input, probe_state, state.as_mut(), self).map(Some)
+    }
+
+    fn finish_partition(
+        &self,
+        probe_state: &ProbeState,
+        state: Self::PartitionState,
+    ) -> DaftResult<Option<Arc<MicroPartition>>> {
+        match state {
+            Some(bitmap_builder) => {
+                let build_side_table = bitmap_builder
+                    .build()
+                    .filter_tables(probe_state.get_tables())?;
+                OuterHashJoinProbeSink::finalize_leftovers(build_side_table, self)
+            }
+            None => Ok(None),
+        }
+    }
 }
 
 pub(crate) struct OuterHashJoinProbeSink {
     params: Arc<OuterHashJoinParams>,
-    needs_bitmap: bool,
     output_schema: SchemaRef,
-    probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
+    probe_state_bridge: BroadcastStateBridgeRef<BuildSide>,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -151,7 +206,8 @@ impl OuterHashJoinProbeSink {
         build_on_left: bool,
         common_join_cols: IndexSet<String>,
         output_schema: &SchemaRef,
-        probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
+        memory_budget: usize,
+        probe_state_bridge: BroadcastStateBridgeRef<BuildSide>,
     ) -> DaftResult<Self> {
         let needs_bitmap = join_type == JoinType::Outer
             || join_type == JoinType::Right && !build_on_left
@@ -201,8 +257,9 @@ impl OuterHashJoinProbeSink {
                 right_non_join_schema,
                 join_type,
                 build_on_left,
+                needs_bitmap,
+                memory_budget: MemoryBudget::new(memory_budget),
             }),
-            needs_bitmap,
             output_schema: output_schema.clone(),
             probe_state_bridge,
         })
@@ -418,70 +475,122 @@ impl OuterHashJoinProbeSink {
         )))
     }
 
-    async fn merge_bitmaps_and_construct_null_table(
+    /// Probes the input against the probe table, using the strategy for the join type.
+    fn probe(
+        input: &Arc<MicroPartition>,
+        probe_state: &ProbeState,
+        bitmap_builder: Option<&mut IndexBitmapBuilder>,
+        params: &OuterHashJoinParams,
+    ) -> DaftResult<Arc<MicroPartition>> {
+        match params.join_type {
+            JoinType::Left | JoinType::Right if params.needs_bitmap => {
+                Self::probe_left_right_with_bitmap(
+                    input,
+                    bitmap_builder.expect("bitmap should be set"),
+                    probe_state,
+                    params.join_type,
+                    &params.probe_on,
+                    &params.common_join_cols,
+                    &params.left_non_join_columns,
+                    &params.right_non_join_columns,
+                )
+            }
+            JoinType::Left | JoinType::Right => Self::probe_left_right(
+                input,
+                probe_state,
+                params.join_type,
+                &params.probe_on,
+                &params.common_join_cols,
+                &params.left_non_join_columns,
+                &params.right_non_join_columns,
+            ),
+            JoinType::Outer => Self::probe_outer(
+                input,
+                probe_state,
+                bitmap_builder.expect("bitmap should be set"),
+                &params.probe_on,
+                &params.common_join_cols,
+                &params.outer_common_col_schema,
+                &params.left_non_join_columns,
+                &params.right_non_join_columns,
+                params.build_on_left,
+            ),
+            _ => unreachable!(
+                "Only Left, Right, and Outer joins are supported in OuterHashJoinProbeSink"
+            ),
+        }
+    }
+
+    fn merge_bitmaps_and_construct_null_table(
         mut states: Vec<Box<dyn StreamingSinkState>>,
     ) -> DaftResult<RecordBatch> {
-        let mut states_iter = states.iter_mut();
+        let mut states_iter = states.iter_mut().map(|s| {
+            s.as_any_mut()
+                .downcast_mut::<OuterHashJoinState>()
+                .expect("OuterHashJoinProbeSink state should be OuterHashJoinProbeState")
+        });
         let first_state = states_iter
             .next()
-            .expect("at least one state should be present")
-            .as_any_mut()
-            .downcast_mut::<OuterHashJoinState>()
-            .expect("OuterHashJoinProbeSink state should be OuterHashJoinProbeState");
-        let tables = first_state
-            .get_or_build_probe_state()
-            .await
-            .get_tables()
-            .clone();
+            .expect("at least one state should be present");
+        let tables = first_state.get_probe_state().get_tables().clone();
         let first_bitmap = first_state
-            .get_or_build_bitmap()
-            .await
+            .get_bitmap()
             .take()
             .expect("bitmap should be set")
             .build();
 
-        let merged_bitmap = {
-            let bitmaps = stream::once(async move { first_bitmap })
-                .chain(stream::iter(states_iter).then(|s| async move {
-                    let state = s
-                        .as_any_mut()
-                        .downcast_mut::<OuterHashJoinState>()
-                        .expect("OuterHashJoinProbeSink state should be OuterHashJoinProbeState");
-                    state
-                        .get_or_build_bitmap()
-                        .await
-                        .take()
-                        .expect("bitmap should be set")
-                        .build()
-                }))
-                .collect::<Vec<_>>()
-                .await;
-
-            bitmaps.into_iter().fold(None, |acc, x| match acc {
-                None => Some(x),
-                Some(acc) => Some(acc.merge(&x)),
-            })
-        }
-        .expect("at least one bitmap should be present");
+        let merged_bitmap = states_iter.fold(first_bitmap, |acc, state| {
+            let bitmap = state
+                .get_bitmap()
+                .take()
+                .expect("bitmap should be set")
+                .build();
+            acc.merge(&bitmap)
+        });
 
-        let leftovers = merged_bitmap
-            .convert_to_boolean_arrays()
-            .zip(tables.iter())
-            .map(|(bitmap, table)| table.mask_filter(&bitmap.into_series()))
-            .collect::<DaftResult<Vec<_>>>()?;
+        merged_bitmap.filter_tables(&tables)
+    }
 
-        RecordBatch::concat(&leftovers)
+    /// Emits the build side rows that were not matched by any probe side row, padded with nulls.
+    fn finalize_leftovers(
+        build_side_table: RecordBatch,
+        params: &OuterHashJoinParams,
+    ) -> DaftResult<Option<Arc<MicroPartition>>> {
+        match params.join_type {
+            JoinType::Left => Self::finalize_left(
+                build_side_table,
+                &params.common_join_cols,
+                &params.left_non_join_columns,
+                &params.right_non_join_schema,
+            ),
+            JoinType::Right => Self::finalize_right(
+                build_side_table,
+                &params.common_join_cols,
+                &params.right_non_join_columns,
+                &params.left_non_join_schema,
+            ),
+            JoinType::Outer => Self::finalize_outer(
+                build_side_table,
+                &params.common_join_cols,
+                &params.outer_common_col_schema,
+                &params.left_non_join_columns,
+                &params.right_non_join_schema,
+                params.build_on_left,
+            ),
+            _ => unreachable!(
+                "Only Left, Right, and Outer joins are supported in OuterHashJoinProbeSink"
+            ),
+        }
     }
 
-    async fn finalize_outer(
-        states: Vec<Box<dyn StreamingSinkState>>,
+    fn finalize_outer(
+        build_side_table: RecordBatch,
         common_join_cols: &[String],
         outer_common_col_schema: &SchemaRef,
         left_non_join_columns: &[String],
         right_non_join_schema: &SchemaRef,
         build_on_left: bool,
     ) -> DaftResult<Option<Arc<MicroPartition>>> {
-        let build_side_table = Self::merge_bitmaps_and_construct_null_table(states).await?;
         let join_table = build_side_table
             .get_columns(common_join_cols)?
             .cast_to_schema(outer_common_col_schema)?;
@@ -508,13 +617,12 @@ impl OuterHashJoinProbeSink {
         ))))
     }
 
-    async fn finalize_left(
-        states: Vec<Box<dyn StreamingSinkState>>,
+    fn finalize_left(
+        build_side_table: RecordBatch,
         common_join_cols: &[String],
         left_non_join_columns: &[String],
         right_non_join_schema: &SchemaRef,
     ) -> DaftResult<Option<Arc<MicroPartition>>> {
-        let build_side_table = Self::merge_bitmaps_and_construct_null_table(states).await?;
         let join_table = build_side_table.get_columns(common_join_cols)?;
         let left = build_side_table.get_columns(left_non_join_columns)?;
         let right = {
@@ -533,13 +641,12 @@ impl OuterHashJoinProbeSink {
         ))))
     }
 
-    async fn finalize_right(
-        states: Vec<Box<dyn StreamingSinkState>>,
+    fn finalize_right(
+        build_side_table: RecordBatch,
         common_join_cols: &[String],
         right_non_join_columns: &[String],
         left_non_join_schema: &SchemaRef,
     ) -> DaftResult<Option<Arc<MicroPartition>>> {
-        let build_side_table = Self::merge_bitmaps_and_construct_null_table(states).await?;
         let join_table = build_side_table.get_columns(common_join_cols)?;
         let left = {
             let columns = left_non_join_schema
@@ -576,7 +683,6 @@ impl StreamingSink for OuterHashJoinProbeSink {
             return Ok((state, StreamingSinkOutput::NeedMoreInput(Some(empty)))).into();
         }
 
-        let needs_bitmap = self.needs_bitmap;
         let params = self.params.clone();
         spawner
             .spawn(
@@ -585,56 +691,18 @@ impl StreamingSink for OuterHashJoinProbeSink {
                         .as_any_mut()
                         .downcast_mut::<OuterHashJoinState>()
                         .expect("OuterHashJoinProbeSink should have OuterHashJoinProbeState");
-                    let probe_state = outer_join_state.get_or_build_probe_state().await;
-                    let out = match params.join_type {
-                        JoinType::Left | JoinType::Right if needs_bitmap => {
-                            Self::probe_left_right_with_bitmap(
-                                &input,
-                                outer_join_state
-                                    .get_or_build_bitmap()
-                                    .await
-                                    .as_mut()
-                                    .expect("bitmap should be set"),
-                                &probe_state,
-                                params.join_type,
-                                &params.probe_on,
-                                &params.common_join_cols,
-                                &params.left_non_join_columns,
-                                &params.right_non_join_columns,
-                            )
-                        }
-                        JoinType::Left | JoinType::Right => Self::probe_left_right(
-                            &input,
-                            &probe_state,
-                            params.join_type,
-                            &params.probe_on,
-                            &params.common_join_cols,
-                            &params.left_non_join_columns,
-                            &params.right_non_join_columns,
+                    outer_join_state.await_build_side(&params).await;
+                    let out = match outer_join_state {
+                        OuterHashJoinState::Probing(probe_state, bitmap_builder) => Some(
+                            Self::probe(&input, probe_state, bitmap_builder.as_mut(), &params)?,
                         ),
-                        JoinType::Outer => {
-                            let bitmap_builder = outer_join_state
-                                .get_or_build_bitmap()
-                                .await
-                                .as_mut()
-                                .expect("bitmap should be set");
-                            Self::probe_outer(
-                                &input,
-                                &probe_state,
-                                bitmap_builder,
-                                &params.probe_on,
-                                &params.common_join_cols,
-                                &params.outer_common_col_schema,
-                                &params.left_non_join_columns,
-                                &params.right_non_join_columns,
-                                params.build_on_left,
-                            )
+                        OuterHashJoinState::Partitioning(probe_side) => {
+                            probe_side.push(&input)?;
+                            None
                         }
-                        _ => unreachable!(
-                        "Only Left, Right, and Outer joins are supported in OuterHashJoinProbeSink"
-                    ),
-                    }?;
-                    Ok((state, StreamingSinkOutput::NeedMoreInput(Some(out))))
+                        _ => unreachable!("build side should be available after awaiting it"),
+                    };
+                    Ok((state, StreamingSinkOutput::NeedMoreInput(out)))
                 },
                 Span::current(),
             )
@@ -670,56 +738,52 @@ impl StreamingSink for OuterHashJoinProbeSink {
     fn make_state(&self) -> Box<dyn StreamingSinkState> {
         Box::new(OuterHashJoinState::Building(
             self.probe_state_bridge.clone(),
-            self.needs_bitmap,
+            self.params.needs_bitmap,
         ))
     }
 
     #[instrument(skip_all, name = "OuterHashJoinProbeSink::finalize")]
     fn finalize(
         &self,
-        states: Vec<Box<dyn StreamingSinkState>>,
+        mut states: Vec<Box<dyn StreamingSinkState>>,
         spawner: &ExecutionTaskSpawner,
     ) -> StreamingSinkFinalizeResult {
-        if self.needs_bitmap {
-            let params = self.params.clone();
-            spawner
-                .spawn(
-                    async move {
-                        match params.join_type {
-                        JoinType::Left => Self::finalize_left(
-                            states,
-                            &params.common_join_cols,
-                            &params.left_non_join_columns,
-                            &params.right_non_join_schema,
-                        )
-                        .await,
-                        JoinType::Right => Self::finalize_right(
-                            states,
-                            &params.common_join_cols,
-                            &params.right_non_join_columns,
-                            &params.left_non_join_schema,
-                        )
-                        .await,
-                        JoinType::Outer => Self::finalize_outer(
-                                states,
-                                &params.common_join_cols,
-                                &params.outer_common_col_schema,
-                                &params.left_non_join_columns,
-                                &params.right_non_join_schema,
-                                params.build_on_left,
-                            )
-                            .await,
-                        _ => unreachable!(
-                            "Only Left, Right, and Outer joins are supported in OuterHashJoinProbeSink"
-                        ),
+        let params = self.params.clone();
+        spawner
+            .spawn(
+                async move {
+                    let mut probe_sides = Vec::with_capacity(states.len());
+                    for state in &mut states {
+                        let state = state
+                            .as_any_mut()
+                            .downcast_mut::<OuterHashJoinState>()
+                            .expect("OuterHashJoinProbeSink should have OuterHashJoinProbeState");
+                        state.await_build_side(&params).await;
+                        if matches!(state, OuterHashJoinState::Partitioning(..)) {
+                            let OuterHashJoinState::Partitioning(probe_side) =
+                                std::mem::replace(state, OuterHashJoinState::Done)
+                            else {
+                                unreachable!()
+                            };
+                            probe_sides.push(probe_side);
+                        }
                     }
-                    },
-                    Span::current(),
-                )
-                .into()
-        } else {
-            Ok(None).into()
-        }
+                    // If the build side was spilled, join the partitions one at a time. The unmatched build side rows
+                    // of each partition are emitted along with it.
+                    if let Some(stream) = GraceHashJoinStream::try_new(probe_sides, params.clone())?
+                    {
+                        return Ok(StreamingSinkFinalizeOutput::Streaming(Box::new(stream)));
+                    }
+                    if !params.needs_bitmap {
+                        return Ok(StreamingSinkFinalizeOutput::Finished(None));
+                    }
+                    let build_side_table = Self::merge_bitmaps_and_construct_null_table(states)?;
+                    let leftovers = Self::finalize_leftovers(build_side_table, &params)?;
+                    Ok(StreamingSinkFinalizeOutput::Finished(leftovers))
+                },
+                Span::current(),
+            )
+            .into()
     }
 
     fn dispatch_spawner(
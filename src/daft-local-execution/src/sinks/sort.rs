@@ -1,34 +1,108 @@
-use std::sync::Arc;
+use std::{cmp::Ordering, sync::Arc};
 
 use common_error::DaftResult;
+use daft_core::{kernels::search_sorted::build_compare_with_nan, prelude::SchemaRef};
 use daft_dsl::ExprRef;
+use daft_io::IOStatsContext;
 use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
 use itertools::Itertools;
 use tracing::{instrument, Span};
 
-use super::blocking_sink::{
-    BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
-    BlockingSinkStatus,
+use super::{
+    blocking_sink::{
+        BlockingSink, BlockingSinkFinalizeOutput, BlockingSinkFinalizeResult,
+        BlockingSinkSinkResult, BlockingSinkState, BlockingSinkStatus,
+    },
+    spill_partition::MemoryBudget,
+};
+use crate::{
+    spill::{can_spill, SpillFile},
+    ExecutionTaskSpawner, NUM_CPUS,
 };
-use crate::{ExecutionTaskSpawner, NUM_CPUS};
 
 enum SortState {
-    Building(Vec<Arc<MicroPartition>>),
+    Building {
+        parts: Vec<Arc<MicroPartition>>,
+        buffered_bytes: usize,
+        runs: Vec<SpillFile>,
+        spill_disabled: bool,
+    },
     Done,
 }
 
 impl SortState {
-    fn push(&mut self, part: Arc<MicroPartition>) {
-        if let Self::Building(ref mut parts) = self {
+    fn new() -> Self {
+        Self::Building {
+            parts: Vec::new(),
+            buffered_bytes: 0,
+            runs: Vec::new(),
+            spill_disabled: false,
+        }
+    }
+
+    fn push(&mut self, part: Arc<MicroPartition>) -> DaftResult<()> {
+        if let Self::Building {
+            parts,
+            buffered_bytes,
+            ..
+        } = self
+        {
+            *buffered_bytes += part.size_bytes()?.unwrap_or(0);
             parts.push(part);
+            Ok(())
         } else {
             panic!("SortSink should be in Building state");
         }
     }
 
-    fn finalize(&mut self) -> Vec<Arc<MicroPartition>> {
-        let res = if let Self::Building(ref mut parts) = self {
-            std::mem::take(parts)
+    fn should_spill(&self, memory_budget: MemoryBudget) -> bool {
+        if let Self::Building {
+            buffered_bytes,
+            spill_disabled,
+            ..
+        } = self
+        {
+            !*spill_disabled && memory_budget.is_exceeded_by(*buffered_bytes)
+        } else {
+            panic!("SortSink should be in Building state");
+        }
+    }
+
+    /// Sorts the buffered partitions into a run and spills it to disk.
+    /// If the data cannot be spilled, e.g. because it contains Python objects, it is kept in memory instead.
+    fn spill(&mut self, params: &SortParams) -> DaftResult<()> {
+        let Self::Building {
+            parts,
+            buffered_bytes,
+            runs,
+            spill_disabled,
+        } = self
+        else {
+            panic!("SortSink should be in Building state");
+        };
+        let sorted = sort_parts(std::mem::take(parts), params)?;
+        let tables = sorted.concat_or_get(IOStatsContext::new("SortSink::spill"))?;
+        let mergeable = match tables.first() {
+            Some(table) => {
+                let keys = table.eval_expression_list(&params.sort_by)?;
+                build_key_comparator(&keys, &keys, params).is_ok()
+            }
+            None => true,
+        };
+        if !can_spill(&sorted.schema()) || !mergeable {
+            *spill_disabled = true;
+            parts.push(Arc::new(sorted));
+            return Ok(());
+        }
+        runs.push(SpillFile::try_new(sorted.schema(), tables.iter())?);
+        *buffered_bytes = 0;
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> (Vec<Arc<MicroPartition>>, Vec<SpillFile>) {
+        let res = if let Self::Building { parts, runs, .. } = self {
+            (std::mem::take(parts), std::mem::take(runs))
         } else {
             panic!("SortSink should be in Building state");
         };
@@ -47,37 +121,255 @@ struct SortParams {
     sort_by: Vec<ExprRef>,
     descending: Vec<bool>,
     nulls_first: Vec<bool>,
+    memory_budget: MemoryBudget,
 }
 pub struct SortSink {
     params: Arc<SortParams>,
 }
 
 impl SortSink {
-    pub fn new(sort_by: Vec<ExprRef>, descending: Vec<bool>, nulls_first: Vec<bool>) -> Self {
+    pub fn new(
+        sort_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        memory_budget: usize,
+    ) -> Self {
         Self {
             params: Arc::new(SortParams {
                 sort_by,
                 descending,
                 nulls_first,
+                memory_budget: MemoryBudget::new(memory_budget),
             }),
         }
     }
 }
 
+fn sort_parts(parts: Vec<Arc<MicroPartition>>, params: &SortParams) -> DaftResult<MicroPartition> {
+    let concated = MicroPartition::concat(parts)?;
+    concated.sort(&params.sort_by, &params.descending, &params.nulls_first)
+}
+
+type RowComparator = Box<dyn Fn(usize, usize) -> Ordering + Send + Sync>;
+
+/// Builds a comparator between rows of the `left` and `right` sort key tables, ordering rows the same way as
+/// `RecordBatch::sort`, including the placement of nulls.
+fn build_key_comparator(
+    left: &RecordBatch,
+    right: &RecordBatch,
+    params: &SortParams,
+) -> DaftResult<RowComparator> {
+    let mut comparators = Vec::with_capacity(left.num_columns());
+    for (idx, (descending, nulls_first)) in params
+        .descending
+        .iter()
+        .zip(params.nulls_first.iter())
+        .enumerate()
+    {
+        let left = left.get_column_by_index(idx)?.to_arrow();
+        let right = right.get_column_by_index(idx)?.to_arrow();
+        let compare_values = build_compare_with_nan(left.as_ref(), right.as_ref())?;
+        let (descending, nulls_first) = (*descending, *nulls_first);
+        let comparator: RowComparator =
+            Box::new(move |i, j| match (left.is_valid(i), right.is_valid(j)) {
+                (true, true) if descending => compare_values(i, j).reverse(),
+                (true, true) => compare_values(i, j),
+                (false, false) => Ordering::Equal,
+                (false, true) if nulls_first => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (true, false) if nulls_first => Ordering::Greater,
+                (true, false) => Ordering::Less,
+            });
+        comparators.push(comparator);
+    }
+    Ok(Box::new(move |i, j| {
+        comparators
+            .iter()
+            .map(|compare| compare(i, j))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }))
+}
+
+/// Returns the number of leading rows for which `pred` holds, assuming it holds for a prefix of the `len` rows.
+fn partition_point(len: usize, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// A sorted run being merged: either a run spilled to disk, or the sorted in-memory remainder.
+struct MergeRun {
+    batches: Box<dyn Iterator<Item = DaftResult<RecordBatch>> + Send + Sync>,
+    /// The rows of the current batch that have not been emitted yet, along with their sort keys.
+    current: Option<(RecordBatch, RecordBatch)>,
+}
+
+impl MergeRun {
+    /// Loads the next non-empty batch if the current one has been fully emitted.
+    /// Returns false once the run is exhausted.
+    fn fill(&mut self, sort_by: &[ExprRef]) -> DaftResult<bool> {
+        while self.current.is_none() {
+            let Some(batch) = self.batches.next().transpose()? else {
+                return Ok(false);
+            };
+            if !batch.is_empty() {
+                let keys = batch.eval_expression_list(sort_by)?;
+                self.current = Some((batch, keys));
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// K-way merge of sorted runs, streaming the merged output one batch at a time.
+///
+/// Each step takes the smallest of the last sort keys of the runs' current batches. Rows that have not been
+/// loaded yet sort at or after it, so every loaded row that sorts at or before it can be emitted. The emitted
+/// prefixes are sorted together, and runs whose current batch is used up load their next batch. At most one
+/// batch per run is held in memory at a time.
+struct ExternalMerge {
+    runs: Vec<MergeRun>,
+    params: Arc<SortParams>,
+    schema: SchemaRef,
+    /// Kept alive so the spill files are only deleted once the merge is dropped.
+    _spill_files: Vec<SpillFile>,
+}
+
+impl ExternalMerge {
+    fn new(
+        spill_files: Vec<SpillFile>,
+        in_memory: Option<MicroPartition>,
+        schema: SchemaRef,
+        params: Arc<SortParams>,
+    ) -> DaftResult<Self> {
+        let mut runs = Vec::with_capacity(spill_files.len() + 1);
+        for spill_file in &spill_files {
+            runs.push(MergeRun {
+                batches: Box::new(spill_file.read()?),
+                current: None,
+            });
+        }
+        if let Some(in_memory) = in_memory {
+            let tables = in_memory.concat_or_get(IOStatsContext::new("SortSink::merge"))?;
+            runs.push(MergeRun {
+                batches: Box::new(tables.as_ref().clone().into_iter().map(Ok)),
+                current: None,
+            });
+        }
+        Ok(Self {
+            runs,
+            params,
+            schema,
+            _spill_files: spill_files,
+        })
+    }
+
+    fn next_batch(&mut self) -> DaftResult<Option<RecordBatch>> {
+        let mut active = Vec::with_capacity(self.runs.len());
+        for mut run in std::mem::take(&mut self.runs) {
+            if run.fill(&self.params.sort_by)? {
+                active.push(run);
+            }
+        }
+        self.runs = active;
+        if self.runs.is_empty() {
+            return Ok(None);
+        }
+
+        let last_keys = self
+            .runs
+            .iter()
+            .map(|run| {
+                let (_, keys) = run.current.as_ref().unwrap();
+                keys.slice(keys.len() - 1, keys.len())
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let last_keys = RecordBatch::concat(&last_keys)?;
+        let compare = build_key_comparator(&last_keys, &last_keys, &self.params)?;
+        let bound = (0..last_keys.len()).min_by(|a, b| compare(*a, *b)).unwrap();
+        let bound_key = last_keys.slice(bound, bound + 1)?;
+
+        let mut emitted = Vec::with_capacity(self.runs.len());
+        for run in &mut self.runs {
+            let (batch, keys) = run.current.take().unwrap();
+            let compare = build_key_comparator(&keys, &bound_key, &self.params)?;
+            let cutoff = partition_point(keys.len(), |i| compare(i, 0) != Ordering::Greater);
+            if cutoff > 0 {
+                emitted.push(batch.slice(0, cutoff)?);
+            }
+            if cutoff < batch.len() {
+                run.current = Some((
+                    batch.slice(cutoff, batch.len())?,
+                    keys.slice(cutoff, keys.len())?,
+                ));
+            }
+        }
+        let emitted = RecordBatch::concat(&emitted)?;
+        Ok(Some(emitted.sort(
+            &self.params.sort_by,
+            &self.params.descending,
+            &self.params.nulls_first,
+        )?))
+    }
+}
+
+impl Iterator for ExternalMerge {
+    type Item = DaftResult<Arc<MicroPartition>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_batch().transpose().map(|batch| {
+            batch.map(|batch| {
+                Arc::new(MicroPartition::new_loaded(
+                    self.schema.clone(),
+                    Arc::new(vec![batch]),
+                    None,
+                ))
+            })
+        })
+    }
+}
+
 impl BlockingSink for SortSink {
     #[instrument(skip_all, name = "SortSink::sink")]
     fn sink(
         &self,
         input: Arc<MicroPartition>,
         mut state: Box<dyn BlockingSinkState>,
-        _spawner: &ExecutionTaskSpawner,
+        spawner: &ExecutionTaskSpawner,
     ) -> BlockingSinkSinkResult {
-        state
+        let sort_state = state
             .as_any_mut()
             .downcast_mut::<SortState>()
-            .expect("SortSink should have sort state")
-            .push(input);
-        Ok(BlockingSinkStatus::NeedMoreInput(state)).into()
+            .expect("SortSink should have sort state");
+        if let Err(e) = sort_state.push(input) {
+            return Err(e).into();
+        }
+        if !sort_state.should_spill(self.params.memory_budget) {
+            return Ok(BlockingSinkStatus::NeedMoreInput(state)).into();
+        }
+
+        let params = self.params.clone();
+        spawner
+            .spawn(
+                async move {
+                    state
+                        .as_any_mut()
+                        .downcast_mut::<SortState>()
+                        .expect("SortSink should have sort state")
+                        .spill(&params)?;
+                    Ok(BlockingSinkStatus::NeedMoreInput(state))
+                },
+                Span::current(),
+            )
+            .into()
     }
 
     #[instrument(skip_all, name = "SortSink::finalize")]
@@ -90,20 +382,32 @@ impl BlockingSink for SortSink {
         spawner
             .spawn(
                 async move {
-                    let parts = states.into_iter().flat_map(|mut state| {
-                        let state = state
-                            .as_any_mut()
-                            .downcast_mut::<SortState>()
-                            .expect("State type mismatch");
-                        state.finalize()
-                    });
-                    let concated = MicroPartition::concat(parts)?;
-                    let sorted = Arc::new(concated.sort(
-                        &params.sort_by,
-                        &params.descending,
-                        &params.nulls_first,
-                    )?);
-                    Ok(Some(sorted))
+                    let (parts, runs): (Vec<_>, Vec<_>) = states
+                        .into_iter()
+                        .map(|mut state| {
+                            let state = state
+                                .as_any_mut()
+                                .downcast_mut::<SortState>()
+                                .expect("State type mismatch");
+                            state.finalize()
+                        })
+                        .unzip();
+                    let parts = parts.into_iter().flatten().collect::<Vec<_>>();
+                    let runs = runs.into_iter().flatten().collect::<Vec<_>>();
+
+                    if runs.is_empty() {
+                        let sorted = Arc::new(sort_parts(parts, &params)?);
+                        return Ok(BlockingSinkFinalizeOutput::Finished(Some(sorted)));
+                    }
+
+                    let in_memory = if parts.is_empty() {
+                        None
+                    } else {
+                        Some(sort_parts(parts, &params)?)
+                    };
+                    let schema = runs[0].schema();
+                    let merge = ExternalMerge::new(runs, in_memory, schema, params)?;
+                    Ok(BlockingSinkFinalizeOutput::Streaming(Box::new(merge)))
                 },
                 Span::current(),
             )
@@ -137,10 +441,89 @@ impl BlockingSink for SortSink {
     }
 
     fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
-        Ok(Box::new(SortState::Building(Vec::new())))
+        Ok(Box::new(SortState::new()))
     }
 
     fn max_concurrency(&self) -> usize {
         *NUM_CPUS
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::{
+        prelude::{DataType, Field, Int64Array, Schema},
+        series::IntoSeries,
+    };
+    use daft_dsl::resolved_col;
+    use daft_io::IOStatsContext;
+    use daft_micropartition::MicroPartition;
+    use daft_recordbatch::RecordBatch;
+
+    use super::{ExternalMerge, MemoryBudget, SortParams, SortState};
+
+    fn make_part(values: Vec<Option<i64>>) -> DaftResult<Arc<MicroPartition>> {
+        let series =
+            Int64Array::from_regular_iter(Field::new("a", DataType::Int64), values.into_iter())?
+                .into_series();
+        let schema = Arc::new(Schema::new(vec![series.field().clone()])?);
+        let len = series.len();
+        let table = RecordBatch::new_with_size(schema.clone(), vec![series], len)?;
+        Ok(Arc::new(MicroPartition::new_loaded(
+            schema,
+            Arc::new(vec![table]),
+            None,
+        )))
+    }
+
+    #[test]
+    fn test_external_merge_of_spilled_runs() -> DaftResult<()> {
+        for (descending, nulls_first) in
+            [(false, false), (false, true), (true, false), (true, true)]
+        {
+            let params = Arc::new(SortParams {
+                sort_by: vec![resolved_col("a")],
+                descending: vec![descending],
+                nulls_first: vec![nulls_first],
+                memory_budget: MemoryBudget::new(0),
+            });
+
+            // Spill three overlapping runs, and keep a fourth one in memory.
+            let mut state = SortState::new();
+            for run in 0..3i64 {
+                let values = (0..20_000)
+                    .map(|i| (i % 7 != 0).then_some(i * 3 + run))
+                    .collect();
+                state.push(make_part(values)?)?;
+                assert!(state.should_spill(params.memory_budget));
+                state.spill(&params)?;
+            }
+            let (parts, runs) = state.finalize();
+            assert!(parts.is_empty());
+            assert_eq!(runs.len(), 3);
+            let in_memory = make_part((0..100).map(Some).collect())?.sort(
+                &params.sort_by,
+                &params.descending,
+                &params.nulls_first,
+            )?;
+
+            let schema = runs[0].schema();
+            let merge = ExternalMerge::new(runs, Some(in_memory), schema, params.clone())?;
+            let merged = MicroPartition::concat(merge.collect::<DaftResult<Vec<_>>>()?)?;
+            let expected = merged.sort(&params.sort_by, &params.descending, &params.nulls_first)?;
+
+            assert_eq!(merged.len(), 3 * 20_000 + 100);
+            let merged = merged.concat_or_get(IOStatsContext::new("test"))?;
+            let expected = expected.concat_or_get(IOStatsContext::new("test"))?;
+            // compared as arrow arrays, as series never compare equal if they contain nulls
+            assert_eq!(
+                merged[0].get_column("a")?.to_arrow(),
+                expected[0].get_column("a")?.to_arrow()
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,235 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+
+use crate::spill::{can_spill, SpillFile};
+
+/// Maximum number of times a partition that still exceeds the memory budget is partitioned again.
+/// Partitions that are still too large after that, e.g. because most rows share a key, are processed in memory.
+pub(crate) const MAX_REPARTITION_DEPTH: u64 = 3;
+
+/// Number of bytes a single worker may buffer before it spills to disk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct MemoryBudget(usize);
+
+impl MemoryBudget {
+    pub(crate) fn new(bytes: usize) -> Self {
+        Self(bytes)
+    }
+
+    pub(crate) fn bytes(self) -> usize {
+        self.0
+    }
+
+    pub(crate) fn is_exceeded_by(self, bytes: usize) -> bool {
+        bytes > self.0
+    }
+}
+
+/// Hash partitions record batches by a set of expressions, spilling the partitions to disk whenever more than
+/// `memory_budget` bytes are buffered. Data that cannot be spilled, e.g. because it contains Python objects, is kept in memory.
+pub(crate) struct HashPartitionSpiller {
+    partition_by: Vec<ExprRef>,
+    /// Seed of the row hashes, set when an oversized partition is partitioned again.
+    seed: Option<u64>,
+    memory_budget: usize,
+    buffers: Vec<Vec<RecordBatch>>,
+    buffered_bytes: usize,
+    spill_files: Vec<Vec<SpillFile>>,
+    partition_bytes: Vec<usize>,
+    /// Set once the data turns out not to be spillable, after which everything is kept in memory.
+    spill_disabled: bool,
+}
+
+impl HashPartitionSpiller {
+    pub(crate) fn new(
+        partition_by: Vec<ExprRef>,
+        num_partitions: usize,
+        memory_budget: usize,
+    ) -> Self {
+        Self {
+            partition_by,
+            seed: None,
+            memory_budget,
+            buffers: (0..num_partitions).map(|_| Vec::new()).collect(),
+            buffered_bytes: 0,
+            spill_files: (0..num_partitions).map(|_| Vec::new()).collect(),
+            partition_bytes: vec![0; num_partitions],
+            spill_disabled: false,
+        }
+    }
+
+    /// Hashes the rows with `seed`, so that rows which shared a partition without it are spread over the partitions.
+    pub(crate) fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub(crate) fn partition_by(&self) -> &[ExprRef] {
+        &self.partition_by
+    }
+
+    pub(crate) fn push(&mut self, tables: &[RecordBatch]) -> DaftResult<()> {
+        for table in tables {
+            let num_partitions = self.buffers.len();
+            let partitioned = match self.seed {
+                Some(seed) => {
+                    table.partition_by_hash_with_seed(&self.partition_by, num_partitions, seed)?
+                }
+                None => table.partition_by_hash(&self.partition_by, num_partitions)?,
+            };
+            for ((partition, buffer), bytes) in partitioned
+                .into_iter()
+                .zip(self.buffers.iter_mut())
+                .zip(self.partition_bytes.iter_mut())
+            {
+                if !partition.is_empty() {
+                    *bytes += partition.size_bytes()?;
+                    buffer.push(partition);
+                }
+            }
+            self.buffered_bytes += table.size_bytes()?;
+        }
+        if !self.spill_disabled && self.buffered_bytes > self.memory_budget {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    fn spill(&mut self) -> DaftResult<()> {
+        if self.spill_disabled {
+            return Ok(());
+        }
+        let Some(schema) = self
+            .buffers
+            .iter()
+            .flatten()
+            .next()
+            .map(|t| t.schema.clone())
+        else {
+            return Ok(());
+        };
+        if !can_spill(&schema) {
+            self.spill_disabled = true;
+            return Ok(());
+        }
+        for (buffer, spill_files) in self.buffers.iter_mut().zip(self.spill_files.iter_mut()) {
+            if !buffer.is_empty() {
+                spill_files.push(SpillFile::try_new(schema.clone(), buffer.iter())?);
+                buffer.clear();
+            }
+        }
+        self.buffered_bytes = 0;
+        Ok(())
+    }
+
+    /// Spills whatever is still buffered and returns the resulting partitions.
+    pub(crate) fn finish(mut self) -> DaftResult<Vec<HashPartition>> {
+        self.spill()?;
+        Ok(self
+            .buffers
+            .into_iter()
+            .zip(self.spill_files)
+            .zip(self.partition_bytes)
+            .map(|((tables, spill_files), size_bytes)| HashPartition {
+                tables,
+                spill_files,
+                size_bytes,
+            })
+            .collect())
+    }
+}
+
+/// A single partition produced by a [`HashPartitionSpiller`].
+pub(crate) struct HashPartition {
+    tables: Vec<RecordBatch>,
+    spill_files: Vec<SpillFile>,
+    size_bytes: usize,
+}
+
+impl HashPartition {
+    /// Size of the partition once it is read into memory.
+    pub(crate) fn size_bytes(&self) -> usize {
+        self.size_bytes
+    }
+
+    /// Partitions the rows of this partition again with a [`HashPartitionSpiller`] that hashes them with `seed`,
+    /// reading the spilled rows back one batch at a time.
+    pub(crate) fn repartition(
+        &self,
+        partition_by: Vec<ExprRef>,
+        num_partitions: usize,
+        seed: u64,
+        memory_budget: usize,
+    ) -> DaftResult<Vec<Self>> {
+        let mut spiller =
+            HashPartitionSpiller::new(partition_by, num_partitions, memory_budget).with_seed(seed);
+        self.push_to(&mut spiller)?;
+        spiller.finish()
+    }
+
+    /// Pushes the rows of this partition into `spiller`, reading the spilled rows back one batch at a time.
+    pub(crate) fn push_to(&self, spiller: &mut HashPartitionSpiller) -> DaftResult<()> {
+        spiller.push(&self.tables)?;
+        for spill_file in &self.spill_files {
+            for batch in spill_file.read()? {
+                spiller.push(&[batch?])?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the entire partition into memory.
+    pub(crate) fn read_tables(&self) -> DaftResult<Vec<RecordBatch>> {
+        let mut tables = self.tables.clone();
+        for spill_file in &self.spill_files {
+            for batch in spill_file.read()? {
+                tables.push(batch?);
+            }
+        }
+        Ok(tables)
+    }
+
+    /// Splits the partition into chunks that can be loaded into memory one at a time.
+    pub(crate) fn into_chunks(self) -> impl Iterator<Item = PartitionChunk> {
+        let in_memory = (!self.tables.is_empty()).then_some(PartitionChunk::InMemory(self.tables));
+        in_memory
+            .into_iter()
+            .chain(self.spill_files.into_iter().map(PartitionChunk::Spilled))
+    }
+}
+
+/// Part of a [`HashPartition`] that is loaded into memory as a whole.
+pub(crate) enum PartitionChunk {
+    InMemory(Vec<RecordBatch>),
+    Spilled(SpillFile),
+}
+
+impl PartitionChunk {
+    /// Pushes the rows of this chunk into `spiller`, reading a spilled chunk back one batch at a time.
+    pub(crate) fn push_to(self, spiller: &mut HashPartitionSpiller) -> DaftResult<()> {
+        match self {
+            Self::InMemory(tables) => spiller.push(&tables),
+            Self::Spilled(spill_file) => {
+                for batch in spill_file.read()? {
+                    spiller.push(&[batch?])?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) fn load(self) -> DaftResult<Arc<MicroPartition>> {
+        match self {
+            Self::InMemory(tables) => Ok(Arc::new(MicroPartition::new_loaded(
+                tables[0].schema.clone(),
+                Arc::new(tables),
+                None,
+            ))),
+            Self::Spilled(spill_file) => Ok(Arc::new(spill_file.read_micropartition()?)),
+        }
+    }
+}
@@ -34,8 +34,20 @@ pub enum StreamingSinkOutput {
 
 pub(crate) type StreamingSinkExecuteResult =
     OperatorOutput<DaftResult<(Box<dyn StreamingSinkState>, StreamingSinkOutput)>>;
+/// Morsels produced lazily by a streaming sink's finalize step, one at a time.
+pub(crate) type StreamingSinkOutputStream =
+    Box<dyn Iterator<Item = DaftResult<Arc<MicroPartition>>> + Send + Sync>;
+
+pub enum StreamingSinkFinalizeOutput {
+    /// The entire output of the finalize step, materialized in memory.
+    Finished(Option<Arc<MicroPartition>>),
+    /// Output that is produced incrementally, for sinks whose final output may not fit in memory,
+    /// e.g. a hash join whose inputs were spilled to disk.
+    Streaming(StreamingSinkOutputStream),
+}
+
 pub(crate) type StreamingSinkFinalizeResult =
-    OperatorOutput<DaftResult<Option<Arc<MicroPartition>>>>;
+    OperatorOutput<DaftResult<StreamingSinkFinalizeOutput>>;
 pub trait StreamingSink: Send + Sync {
     /// Execute the StreamingSink operator on the morsel of input data,
     /// received from the child with the given index,
@@ -286,8 +298,30 @@ impl PipelineNode for StreamingSinkNode {
                     info_span!("StreamingSink::Finalize"),
                 );
                 let finalized_result = op.finalize(finished_states, &spawner).await??;
-                if let Some(res) = finalized_result {
-                    let _ = counting_sender.send(res).await;
+                match finalized_result {
+                    StreamingSinkFinalizeOutput::Finished(Some(res)) => {
+                        let _ = counting_sender.send(res).await;
+                    }
+                    StreamingSinkFinalizeOutput::Finished(None) => {}
+                    StreamingSinkFinalizeOutput::Streaming(mut stream) => loop {
+                        // Each morsel may require reading from disk, so pull it on the compute runtime.
+                        let (next, returned_stream) = spawner
+                            .spawn(
+                                async move {
+                                    let next = stream.next().transpose()?;
+                                    Ok((next, stream))
+                                },
+                                info_span!("StreamingSink::FinalizeStream"),
+                            )
+                            .await??;
+                        stream = returned_stream;
+                        let Some(res) = next else {
+                            break;
+                        };
+                        if counting_sender.send(res).await.is_err() {
+                            break;
+                        }
+                    },
                 }
                 Ok(())
             },
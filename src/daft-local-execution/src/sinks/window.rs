@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use itertools::Itertools;
+use tracing::{instrument, Span};
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeOutput, BlockingSinkFinalizeResult, BlockingSinkSinkResult,
+    BlockingSinkState, BlockingSinkStatus,
+};
+use crate::ExecutionTaskSpawner;
+
+enum WindowState {
+    Accumulating(Vec<Arc<MicroPartition>>),
+    Done,
+}
+
+impl WindowState {
+    fn push(&mut self, part: Arc<MicroPartition>) {
+        if let Self::Accumulating(ref mut parts) = self {
+            parts.push(part);
+        } else {
+            panic!("WindowSink should be in Accumulating state");
+        }
+    }
+
+    fn finalize(&mut self) -> Vec<Arc<MicroPartition>> {
+        let res = if let Self::Accumulating(ref mut parts) = self {
+            std::mem::take(parts)
+        } else {
+            panic!("WindowSink should be in Accumulating state");
+        };
+        *self = Self::Done;
+        res
+    }
+}
+
+impl BlockingSinkState for WindowState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+pub struct WindowSink {
+    window_functions: Arc<Vec<ExprRef>>,
+}
+
+impl WindowSink {
+    pub fn new(window_functions: Vec<ExprRef>) -> Self {
+        Self {
+            window_functions: Arc::new(window_functions),
+        }
+    }
+}
+
+impl BlockingSink for WindowSink {
+    #[instrument(skip_all, name = "WindowSink::sink")]
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn BlockingSinkState>,
+        _spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkSinkResult {
+        state
+            .as_any_mut()
+            .downcast_mut::<WindowState>()
+            .expect("WindowSink should have WindowState")
+            .push(input);
+        Ok(BlockingSinkStatus::NeedMoreInput(state)).into()
+    }
+
+    #[instrument(skip_all, name = "WindowSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn BlockingSinkState>>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkFinalizeResult {
+        let window_functions = self.window_functions.clone();
+        spawner
+            .spawn(
+                async move {
+                    let all_parts = states.into_iter().flat_map(|mut state| {
+                        state
+                            .as_any_mut()
+                            .downcast_mut::<WindowState>()
+                            .expect("WindowSink should have WindowState")
+                            .finalize()
+                    });
+                    let concated = MicroPartition::concat(all_parts)?;
+                    let windowed = Arc::new(concated.window(&window_functions)?);
+                    Ok(BlockingSinkFinalizeOutput::Finished(Some(windowed)))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "Window"
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        vec![format!(
+            "Window: {}",
+            self.window_functions
+                .iter()
+                .map(|e| e.to_string())
+                .join(", ")
+        )]
+    }
+
+    fn max_concurrency(&self) -> usize {
+        // A single state receives all of the input in order, so that the output rows are in the same order as the input rows.
+        1
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
+        Ok(Box::new(WindowState::Accumulating(vec![])))
+    }
+}
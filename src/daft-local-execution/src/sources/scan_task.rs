@@ -453,6 +453,35 @@ async fn stream_scan_task(
             )
             .await?
         }
+        FileFormatConfig::Orc(cfg) => {
+            let stripes = if let Some(ChunkSpec::Orc(stripes)) = source.get_chunk_spec() {
+                Some(stripes.clone())
+            } else {
+                None
+            };
+            daft_orc::stream_orc(
+                url,
+                file_column_names.as_deref(),
+                scan_task.pushdowns.limit,
+                stripes,
+                scan_task.pushdowns.filters.clone(),
+                io_client,
+                Some(io_stats),
+                cfg.chunk_size,
+            )
+            .await?
+        }
+        FileFormatConfig::Ipc(_) => {
+            daft_ipc::stream_ipc(
+                url,
+                file_column_names.as_deref(),
+                scan_task.pushdowns.limit,
+                scan_task.pushdowns.filters.clone(),
+                io_client,
+                Some(io_stats),
+            )
+            .await?
+        }
         #[cfg(feature = "python")]
         FileFormatConfig::Database(common_file_formats::DatabaseSourceConfig { sql, conn }) => {
             use pyo3::Python;
@@ -0,0 +1,162 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Seek, SeekFrom, Write},
+    sync::Arc,
+};
+
+use arrow2::{
+    chunk::Chunk,
+    io::ipc::{
+        read::{read_file_metadata, FileReader},
+        write::{FileWriter, WriteOptions},
+    },
+};
+use common_error::DaftResult;
+use daft_core::{prelude::SchemaRef, series::Series};
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+use tempfile::NamedTempFile;
+
+/// Maximum number of rows per record batch written to a spill file.
+/// Spill files are read back one batch at a time, so this bounds the memory needed to read a file back.
+const SPILL_BATCH_ROWS: usize = 8192;
+
+/// Returns whether data with the given schema can be spilled to disk, i.e. whether it can be represented in Arrow.
+pub(crate) fn can_spill(schema: &SchemaRef) -> bool {
+    schema.to_arrow().is_ok()
+}
+
+/// Record batches spilled to a temporary Arrow IPC file on local disk.
+///
+/// The file is created in the system temporary directory (`TMPDIR`) and deleted when the `SpillFile` is dropped.
+pub(crate) struct SpillFile {
+    file: NamedTempFile,
+    schema: SchemaRef,
+}
+
+impl SpillFile {
+    /// Writes `batches` to a new spill file, in order, splitting them into batches of at most `SPILL_BATCH_ROWS` rows.
+    pub(crate) fn try_new<'a>(
+        schema: SchemaRef,
+        batches: impl IntoIterator<Item = &'a RecordBatch>,
+    ) -> DaftResult<Self> {
+        let file = tempfile::Builder::new()
+            .prefix("daft-spill-")
+            .suffix(".arrow")
+            .tempfile()?;
+        let mut writer = FileWriter::try_new(
+            BufWriter::new(file.as_file().try_clone()?),
+            schema.to_arrow()?,
+            None,
+            WriteOptions { compression: None },
+        )?;
+
+        for batch in batches {
+            for start in (0..batch.len()).step_by(SPILL_BATCH_ROWS) {
+                let end = (start + SPILL_BATCH_ROWS).min(batch.len());
+                let slice = batch.slice(start, end)?;
+                let chunk = Chunk::try_new(slice.get_inner_arrow_arrays().collect::<Vec<_>>())?;
+                writer.write(&chunk, None)?;
+            }
+        }
+        writer.finish()?;
+        writer.into_inner().flush()?;
+
+        Ok(Self { file, schema })
+    }
+
+    /// Writes the tables of `partition` to a new spill file.
+    pub(crate) fn try_from_micropartition(partition: &MicroPartition) -> DaftResult<Self> {
+        let tables = partition.get_tables()?;
+        Self::try_new(partition.schema(), tables.iter())
+    }
+
+    pub(crate) fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Returns an iterator over the batches of this file, in the order they were written.
+    pub(crate) fn read(&self) -> DaftResult<SpillFileReader> {
+        let mut file = self.file.reopen()?;
+        file.seek(SeekFrom::Start(0))?;
+        let mut reader = BufReader::new(file);
+        let metadata = read_file_metadata(&mut reader)?;
+        Ok(SpillFileReader {
+            reader: FileReader::new(reader, metadata, None, None),
+            schema: self.schema.clone(),
+        })
+    }
+
+    /// Reads the whole file back into a single loaded micropartition.
+    pub(crate) fn read_micropartition(&self) -> DaftResult<MicroPartition> {
+        let batches = self.read()?.collect::<DaftResult<Vec<_>>>()?;
+        Ok(MicroPartition::new_loaded(
+            self.schema.clone(),
+            Arc::new(batches),
+            None,
+        ))
+    }
+}
+
+/// Streams the record batches of a [`SpillFile`] back into memory, one batch at a time.
+pub(crate) struct SpillFileReader {
+    reader: FileReader<BufReader<File>>,
+    schema: SchemaRef,
+}
+
+impl Iterator for SpillFileReader {
+    type Item = DaftResult<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = match self.reader.next()? {
+            Ok(chunk) => chunk,
+            Err(err) => return Some(Err(err.into())),
+        };
+        let num_rows = chunk.len();
+        let columns = self
+            .schema
+            .fields
+            .values()
+            .zip(chunk.into_arrays())
+            .map(|(field, array)| Series::from_arrow(field.clone().into(), array))
+            .collect::<DaftResult<Vec<_>>>();
+        Some(
+            columns.and_then(|columns| {
+                RecordBatch::new_with_size(self.schema.clone(), columns, num_rows)
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::{
+        prelude::{Int64Array, Schema, Utf8Array},
+        series::IntoSeries,
+    };
+    use daft_recordbatch::RecordBatch;
+
+    use super::SpillFile;
+
+    #[test]
+    fn test_spill_file_roundtrip() -> DaftResult<()> {
+        let ints = Int64Array::from(("ints", (0..20_000).collect::<Vec<i64>>())).into_series();
+        let values = (0..20_000).map(|i| i.to_string()).collect::<Vec<_>>();
+        let strs = Utf8Array::from_values("strs", values.iter()).into_series();
+        let schema = Arc::new(Schema::new(vec![
+            ints.field().clone(),
+            strs.field().clone(),
+        ])?);
+        let batch = RecordBatch::new_with_size(schema.clone(), vec![ints, strs], 20_000)?;
+
+        let spill_file = SpillFile::try_new(schema, [&batch])?;
+        let batches = spill_file.read()?.collect::<DaftResult<Vec<_>>>()?;
+        // The batch is split into batches of at most `SPILL_BATCH_ROWS` rows.
+        assert_eq!(batches.len(), 3);
+        assert_eq!(RecordBatch::concat(&batches)?, batch);
+        Ok(())
+    }
+}
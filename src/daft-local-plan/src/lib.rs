@@ -2,13 +2,16 @@
 mod plan;
 mod translate;
 
+#[cfg(feature = "python")]
+pub use plan::CatalogTableWrite;
 #[cfg(feature = "python")]
 pub use plan::CatalogWrite;
 #[cfg(feature = "python")]
 pub use plan::LanceWrite;
 pub use plan::{
-    ActorPoolProject, Concat, CrossJoin, EmptyScan, Explode, Filter, HashAggregate, HashJoin,
-    InMemoryScan, Limit, LocalPhysicalPlan, LocalPhysicalPlanRef, MonotonicallyIncreasingId,
-    PhysicalScan, PhysicalWrite, Pivot, Project, Sample, Sort, UnGroupedAggregate, Unpivot,
+    ActorPoolProject, AsofJoin, Concat, CrossJoin, EmptyScan, Explode, Filter, HashAggregate,
+    HashJoin, InMemoryScan, Limit, LocalPhysicalPlan, LocalPhysicalPlanRef,
+    MonotonicallyIncreasingId, NestedLoopJoin, PhysicalScan, PhysicalWrite, Pivot, Project,
+    RecursiveUnion, Sample, Sort, UnGroupedAggregate, Unpivot, Window, WorkTableScan,
 };
 pub use translate::translate;
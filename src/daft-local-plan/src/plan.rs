@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use common_resource_request::ResourceRequest;
 use common_scan_info::{Pushdowns, ScanTaskLikeRef};
-use daft_core::prelude::*;
+use daft_core::{join::AsofJoinDirection, prelude::*};
 use daft_dsl::{AggExpr, ExprRef};
 use daft_logical_plan::{
     stats::{PlanStats, StatsState},
@@ -15,6 +15,7 @@ pub enum LocalPhysicalPlan {
     InMemoryScan(InMemoryScan),
     PhysicalScan(PhysicalScan),
     EmptyScan(EmptyScan),
+    WorkTableScan(WorkTableScan),
     Project(Project),
     ActorPoolProject(ActorPoolProject),
     Filter(Filter),
@@ -25,6 +26,7 @@ pub enum LocalPhysicalPlan {
     // Split(Split),
     Sample(Sample),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
+    Window(Window),
     // Coalesce(Coalesce),
     // Flatten(Flatten),
     // FanoutRandom(FanoutRandom),
@@ -35,8 +37,11 @@ pub enum LocalPhysicalPlan {
     HashAggregate(HashAggregate),
     Pivot(Pivot),
     Concat(Concat),
+    RecursiveUnion(RecursiveUnion),
     HashJoin(HashJoin),
     CrossJoin(CrossJoin),
+    AsofJoin(AsofJoin),
+    NestedLoopJoin(NestedLoopJoin),
     // SortMergeJoin(SortMergeJoin),
     // BroadcastJoin(BroadcastJoin),
     PhysicalWrite(PhysicalWrite),
@@ -46,6 +51,8 @@ pub enum LocalPhysicalPlan {
     CatalogWrite(CatalogWrite),
     #[cfg(feature = "python")]
     LanceWrite(LanceWrite),
+    #[cfg(feature = "python")]
+    CatalogTableWrite(CatalogTableWrite),
 }
 
 impl LocalPhysicalPlan {
@@ -65,6 +72,7 @@ impl LocalPhysicalPlan {
             Self::InMemoryScan(InMemoryScan { stats_state, .. })
             | Self::PhysicalScan(PhysicalScan { stats_state, .. })
             | Self::EmptyScan(EmptyScan { stats_state, .. })
+            | Self::WorkTableScan(WorkTableScan { stats_state, .. })
             | Self::Project(Project { stats_state, .. })
             | Self::ActorPoolProject(ActorPoolProject { stats_state, .. })
             | Self::Filter(Filter { stats_state, .. })
@@ -74,16 +82,21 @@ impl LocalPhysicalPlan {
             | Self::Sort(Sort { stats_state, .. })
             | Self::Sample(Sample { stats_state, .. })
             | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { stats_state, .. })
+            | Self::Window(Window { stats_state, .. })
             | Self::UnGroupedAggregate(UnGroupedAggregate { stats_state, .. })
             | Self::HashAggregate(HashAggregate { stats_state, .. })
             | Self::Pivot(Pivot { stats_state, .. })
             | Self::Concat(Concat { stats_state, .. })
+            | Self::RecursiveUnion(RecursiveUnion { stats_state, .. })
             | Self::HashJoin(HashJoin { stats_state, .. })
             | Self::CrossJoin(CrossJoin { stats_state, .. })
+            | Self::AsofJoin(AsofJoin { stats_state, .. })
+            | Self::NestedLoopJoin(NestedLoopJoin { stats_state, .. })
             | Self::PhysicalWrite(PhysicalWrite { stats_state, .. }) => stats_state,
             #[cfg(feature = "python")]
             Self::CatalogWrite(CatalogWrite { stats_state, .. })
-            | Self::LanceWrite(LanceWrite { stats_state, .. }) => stats_state,
+            | Self::LanceWrite(LanceWrite { stats_state, .. })
+            | Self::CatalogTableWrite(CatalogTableWrite { stats_state, .. }) => stats_state,
         }
     }
 
@@ -121,6 +134,19 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    pub(crate) fn work_table_scan(
+        work_table_id: usize,
+        schema: SchemaRef,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::WorkTableScan(WorkTableScan {
+            work_table_id,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     pub(crate) fn filter(
         input: LocalPhysicalPlanRef,
         predicate: ExprRef,
@@ -154,12 +180,14 @@ impl LocalPhysicalPlan {
     pub(crate) fn explode(
         input: LocalPhysicalPlanRef,
         to_explode: Vec<ExprRef>,
+        index_column: Option<String>,
         schema: SchemaRef,
         stats_state: StatsState,
     ) -> LocalPhysicalPlanRef {
         Self::Explode(Explode {
             input,
             to_explode,
+            index_column,
             schema,
             stats_state,
         })
@@ -326,6 +354,21 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    pub(crate) fn window(
+        input: LocalPhysicalPlanRef,
+        window_functions: Vec<ExprRef>,
+        schema: SchemaRef,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::Window(Window {
+            input,
+            window_functions,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn hash_join(
         left: LocalPhysicalPlanRef,
@@ -334,6 +377,7 @@ impl LocalPhysicalPlan {
         right_on: Vec<ExprRef>,
         null_equals_null: Option<Vec<bool>>,
         join_type: JoinType,
+        build_on_left: Option<bool>,
         schema: SchemaRef,
         stats_state: StatsState,
     ) -> LocalPhysicalPlanRef {
@@ -344,6 +388,7 @@ impl LocalPhysicalPlan {
             right_on,
             null_equals_null,
             join_type,
+            build_on_left,
             schema,
             stats_state,
         })
@@ -365,6 +410,60 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn asof_join(
+        left: LocalPhysicalPlanRef,
+        right: LocalPhysicalPlanRef,
+        left_by: Vec<ExprRef>,
+        right_by: Vec<ExprRef>,
+        left_on: ExprRef,
+        right_on: ExprRef,
+        direction: AsofJoinDirection,
+        tolerance: Option<ExprRef>,
+        schema: SchemaRef,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::AsofJoin(AsofJoin {
+            left,
+            right,
+            left_by,
+            right_by,
+            left_on,
+            right_on,
+            direction,
+            tolerance,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn nested_loop_join(
+        left: LocalPhysicalPlanRef,
+        right: LocalPhysicalPlanRef,
+        left_on: Vec<ExprRef>,
+        right_on: Vec<ExprRef>,
+        null_equals_null: Option<Vec<bool>>,
+        predicate: ExprRef,
+        join_type: JoinType,
+        schema: SchemaRef,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::NestedLoopJoin(NestedLoopJoin {
+            left,
+            right,
+            left_on,
+            right_on,
+            null_equals_null,
+            predicate,
+            join_type,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     pub(crate) fn concat(
         input: LocalPhysicalPlanRef,
         other: LocalPhysicalPlanRef,
@@ -380,6 +479,25 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    pub(crate) fn recursive_union(
+        anchor: LocalPhysicalPlanRef,
+        recursive: LocalPhysicalPlanRef,
+        work_table_id: usize,
+        is_all: bool,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        let schema = anchor.schema().clone();
+        Self::RecursiveUnion(RecursiveUnion {
+            anchor,
+            recursive,
+            work_table_id,
+            is_all,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     pub(crate) fn physical_write(
         input: LocalPhysicalPlanRef,
         data_schema: SchemaRef,
@@ -433,10 +551,29 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    #[cfg(feature = "python")]
+    pub(crate) fn catalog_table_write(
+        input: LocalPhysicalPlanRef,
+        table_info: daft_logical_plan::TableCatalogInfo,
+        data_schema: SchemaRef,
+        file_schema: SchemaRef,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::CatalogTableWrite(CatalogTableWrite {
+            input,
+            table_info,
+            data_schema,
+            file_schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     pub fn schema(&self) -> &SchemaRef {
         match self {
             Self::PhysicalScan(PhysicalScan { schema, .. })
             | Self::EmptyScan(EmptyScan { schema, .. })
+            | Self::WorkTableScan(WorkTableScan { schema, .. })
             | Self::Filter(Filter { schema, .. })
             | Self::Limit(Limit { schema, .. })
             | Self::Project(Project { schema, .. })
@@ -448,16 +585,22 @@ impl LocalPhysicalPlan {
             | Self::Sample(Sample { schema, .. })
             | Self::HashJoin(HashJoin { schema, .. })
             | Self::CrossJoin(CrossJoin { schema, .. })
+            | Self::AsofJoin(AsofJoin { schema, .. })
+            | Self::NestedLoopJoin(NestedLoopJoin { schema, .. })
             | Self::Explode(Explode { schema, .. })
             | Self::Unpivot(Unpivot { schema, .. })
             | Self::Concat(Concat { schema, .. })
-            | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { schema, .. }) => schema,
+            | Self::RecursiveUnion(RecursiveUnion { schema, .. })
+            | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { schema, .. })
+            | Self::Window(Window { schema, .. }) => schema,
             Self::PhysicalWrite(PhysicalWrite { file_schema, .. }) => file_schema,
             Self::InMemoryScan(InMemoryScan { info, .. }) => &info.source_schema,
             #[cfg(feature = "python")]
             Self::CatalogWrite(CatalogWrite { file_schema, .. }) => file_schema,
             #[cfg(feature = "python")]
             Self::LanceWrite(LanceWrite { file_schema, .. }) => file_schema,
+            #[cfg(feature = "python")]
+            Self::CatalogTableWrite(CatalogTableWrite { file_schema, .. }) => file_schema,
         }
     }
 }
@@ -482,6 +625,13 @@ pub struct EmptyScan {
     pub stats_state: StatsState,
 }
 
+#[derive(Debug)]
+pub struct WorkTableScan {
+    pub work_table_id: usize,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug)]
 pub struct Project {
     pub input: LocalPhysicalPlanRef,
@@ -518,6 +668,7 @@ pub struct Limit {
 pub struct Explode {
     pub input: LocalPhysicalPlanRef,
     pub to_explode: Vec<ExprRef>,
+    pub index_column: Option<String>,
     pub schema: SchemaRef,
     pub stats_state: StatsState,
 }
@@ -550,6 +701,14 @@ pub struct MonotonicallyIncreasingId {
     pub stats_state: StatsState,
 }
 
+#[derive(Debug)]
+pub struct Window {
+    pub input: LocalPhysicalPlanRef,
+    pub window_functions: Vec<ExprRef>,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug)]
 pub struct UnGroupedAggregate {
     pub input: LocalPhysicalPlanRef,
@@ -598,6 +757,8 @@ pub struct HashJoin {
     pub right_on: Vec<ExprRef>,
     pub null_equals_null: Option<Vec<bool>>,
     pub join_type: JoinType,
+    /// Builds the probe table on this side (`true` for the left) instead of choosing the side by size.
+    pub build_on_left: Option<bool>,
     pub schema: SchemaRef,
     pub stats_state: StatsState,
 }
@@ -610,6 +771,34 @@ pub struct CrossJoin {
     pub stats_state: StatsState,
 }
 
+#[derive(Debug)]
+pub struct AsofJoin {
+    pub left: LocalPhysicalPlanRef,
+    pub right: LocalPhysicalPlanRef,
+    pub left_by: Vec<ExprRef>,
+    pub right_by: Vec<ExprRef>,
+    pub left_on: ExprRef,
+    pub right_on: ExprRef,
+    pub direction: AsofJoinDirection,
+    pub tolerance: Option<ExprRef>,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
+/// A join whose rows must satisfy an arbitrary predicate over both sides, in addition to the equality of the join keys.
+#[derive(Debug)]
+pub struct NestedLoopJoin {
+    pub left: LocalPhysicalPlanRef,
+    pub right: LocalPhysicalPlanRef,
+    pub left_on: Vec<ExprRef>,
+    pub right_on: Vec<ExprRef>,
+    pub null_equals_null: Option<Vec<bool>>,
+    pub predicate: ExprRef,
+    pub join_type: JoinType,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug)]
 pub struct Concat {
     pub input: LocalPhysicalPlanRef,
@@ -618,6 +807,16 @@ pub struct Concat {
     pub stats_state: StatsState,
 }
 
+#[derive(Debug)]
+pub struct RecursiveUnion {
+    pub anchor: LocalPhysicalPlanRef,
+    pub recursive: LocalPhysicalPlanRef,
+    pub work_table_id: usize,
+    pub is_all: bool,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug)]
 pub struct PhysicalWrite {
     pub input: LocalPhysicalPlanRef,
@@ -646,3 +845,13 @@ pub struct LanceWrite {
     pub file_schema: SchemaRef,
     pub stats_state: StatsState,
 }
+
+#[cfg(feature = "python")]
+#[derive(Debug)]
+pub struct CatalogTableWrite {
+    pub input: LocalPhysicalPlanRef,
+    pub table_info: daft_logical_plan::TableCatalogInfo,
+    pub data_schema: SchemaRef,
+    pub file_schema: SchemaRef,
+    pub stats_state: StatsState,
+}
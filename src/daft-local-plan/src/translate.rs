@@ -3,7 +3,10 @@ use std::sync::Arc;
 use common_error::{DaftError, DaftResult};
 use common_scan_info::ScanState;
 use daft_core::join::JoinStrategy;
-use daft_dsl::{join::normalize_join_keys, ExprRef};
+use daft_dsl::{
+    join::{join_predicate_to_output_expr, normalize_join_keys},
+    ExprRef,
+};
 use daft_logical_plan::{JoinType, LogicalPlan, LogicalPlanRef, SourceInfo};
 
 use super::plan::{LocalPhysicalPlan, LocalPhysicalPlanRef};
@@ -38,6 +41,11 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 SourceInfo::PlaceHolder(_) => {
                     panic!("We should not encounter a PlaceHolder during translation")
                 }
+                SourceInfo::WorkTable(info) => Ok(LocalPhysicalPlan::work_table_scan(
+                    info.id,
+                    source.output_schema.clone(),
+                    source.stats_state.clone(),
+                )),
             }
         }
         LogicalPlan::Filter(filter) => {
@@ -139,9 +147,14 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::Join(join) => {
-            if join.join_strategy.is_some_and(|x| x != JoinStrategy::Hash) {
+            // A broadcast join is executed as a hash join, whose build side is likewise shared by all
+            // of the probing workers.
+            if join
+                .join_strategy
+                .is_some_and(|x| !matches!(x, JoinStrategy::Hash | JoinStrategy::Broadcast))
+            {
                 return Err(DaftError::not_implemented(
-                    "Only hash join is supported for now",
+                    "Only hash and broadcast joins are supported for now",
                 ));
             }
             let left = translate(&join.left)?;
@@ -154,7 +167,51 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 join.right.schema(),
             )?;
 
-            if left_on.is_empty() && right_on.is_empty() && join.join_type == JoinType::Inner {
+            // A broadcast join builds on the side that a distributed broadcast join would broadcast.
+            // Inner joins can broadcast either side, so they still build on the smaller side.
+            let build_on_left = match (join.join_strategy, join.join_type) {
+                (Some(JoinStrategy::Broadcast), JoinType::Right) => Some(true),
+                (
+                    Some(JoinStrategy::Broadcast),
+                    JoinType::Left | JoinType::Anti | JoinType::Semi,
+                ) => Some(false),
+                _ => None,
+            };
+
+            if let Some(predicate) = &join.predicate {
+                if join.join_type == JoinType::Inner && !left_on.is_empty() {
+                    // The predicate of an inner join can be applied as a filter on the rows joined on the keys.
+                    let hash_join = LocalPhysicalPlan::hash_join(
+                        left,
+                        right,
+                        left_on,
+                        right_on,
+                        join.null_equals_nulls.clone(),
+                        join.join_type,
+                        build_on_left,
+                        join.output_schema.clone(),
+                        join.stats_state.clone(),
+                    );
+                    Ok(LocalPhysicalPlan::filter(
+                        hash_join,
+                        join_predicate_to_output_expr(predicate.clone()),
+                        join.stats_state.clone(),
+                    ))
+                } else {
+                    Ok(LocalPhysicalPlan::nested_loop_join(
+                        left,
+                        right,
+                        left_on,
+                        right_on,
+                        join.null_equals_nulls.clone(),
+                        predicate.clone(),
+                        join.join_type,
+                        join.output_schema.clone(),
+                        join.stats_state.clone(),
+                    ))
+                }
+            } else if left_on.is_empty() && right_on.is_empty() && join.join_type == JoinType::Inner
+            {
                 Ok(LocalPhysicalPlan::cross_join(
                     left,
                     right,
@@ -169,11 +226,28 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                     right_on,
                     join.null_equals_nulls.clone(),
                     join.join_type,
+                    build_on_left,
                     join.output_schema.clone(),
                     join.stats_state.clone(),
                 ))
             }
         }
+        LogicalPlan::AsofJoin(asof_join) => {
+            let left = translate(&asof_join.left)?;
+            let right = translate(&asof_join.right)?;
+            Ok(LocalPhysicalPlan::asof_join(
+                left,
+                right,
+                asof_join.left_by.clone(),
+                asof_join.right_by.clone(),
+                asof_join.left_on.clone(),
+                asof_join.right_on.clone(),
+                asof_join.direction,
+                asof_join.tolerance.clone(),
+                asof_join.output_schema.clone(),
+                asof_join.stats_state.clone(),
+            ))
+        }
         LogicalPlan::Distinct(distinct) => {
             let schema = distinct.input.schema();
             let input = translate(&distinct.input)?;
@@ -200,6 +274,17 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 concat.stats_state.clone(),
             ))
         }
+        LogicalPlan::RecursiveUnion(recursive_union) => {
+            let anchor = translate(&recursive_union.anchor)?;
+            let recursive = translate(&recursive_union.recursive)?;
+            Ok(LocalPhysicalPlan::recursive_union(
+                anchor,
+                recursive,
+                recursive_union.work_table_id,
+                recursive_union.is_all,
+                recursive_union.stats_state.clone(),
+            ))
+        }
         LogicalPlan::Repartition(repartition) => {
             log::warn!("Repartition not supported on the NativeRunner. This will be a no-op. Please use the RayRunner instead if you need to repartition");
             translate(&repartition.input)
@@ -213,6 +298,15 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 monotonically_increasing_id.stats_state.clone(),
             ))
         }
+        LogicalPlan::Window(window) => {
+            let input = translate(&window.input)?;
+            Ok(LocalPhysicalPlan::window(
+                input,
+                window.window_functions.clone(),
+                window.output_schema.clone(),
+                window.stats_state.clone(),
+            ))
+        }
         LogicalPlan::Sink(sink) => {
             use daft_logical_plan::SinkInfo;
             let input = translate(&sink.input)?;
@@ -246,6 +340,15 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                             sink.stats_state.clone(),
                         ))
                     }
+                    daft_logical_plan::CatalogType::Table(info) => {
+                        Ok(LocalPhysicalPlan::catalog_table_write(
+                            input,
+                            info.clone(),
+                            data_schema,
+                            sink.schema.clone(),
+                            sink.stats_state.clone(),
+                        ))
+                    }
                 },
             }
         }
@@ -254,6 +357,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             Ok(LocalPhysicalPlan::explode(
                 input,
                 explode.to_explode.clone(),
+                explode.index_column.clone(),
                 explode.exploded_schema.clone(),
                 explode.stats_state.clone(),
             ))
@@ -0,0 +1,276 @@
+use common_error::{DaftError, DaftResult};
+use daft_dsl::{lit, null_lit, resolved_col, Expr, ExprRef};
+use indexmap::IndexSet;
+use itertools::Itertools;
+
+use crate::LogicalPlanBuilder;
+
+/// Expands `ROLLUP(e1, ..., en)` into its grouping sets `(e1, ..., en), (e1, ..., en-1), ..., ()`.
+///
+/// Each element may be a composite of several expressions that are rolled up together.
+pub fn rollup(elements: Vec<Vec<ExprRef>>) -> Vec<Vec<ExprRef>> {
+    (0..=elements.len())
+        .rev()
+        .map(|len| elements[..len].concat())
+        .collect()
+}
+
+/// Expands `CUBE(e1, ..., en)` into all of the `2^n` subsets of its elements as grouping sets,
+/// from the full set down to the empty set.
+///
+/// Each element may be a composite of several expressions that are grouped together.
+pub fn cube(elements: Vec<Vec<ExprRef>>) -> DaftResult<Vec<Vec<ExprRef>>> {
+    let n = elements.len();
+    check_grouping_keys(n)?;
+    check_grouping_sets(1 << n)?;
+    Ok((0..1usize << n)
+        .rev()
+        .map(|mask| {
+            elements
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << (n - 1 - i)) != 0)
+                .flat_map(|(_, element)| element.iter().cloned())
+                .collect()
+        })
+        .collect())
+}
+
+/// Grouping ids are Int64 bitmasks over the grouping keys, so there can be at most 63 keys.
+fn check_grouping_keys(num_keys: usize) -> DaftResult<()> {
+    if num_keys >= i64::BITS as usize {
+        return Err(DaftError::ValueError(format!(
+            "Aggregating over grouping sets supports at most {} distinct grouping keys, but got {num_keys}",
+            i64::BITS - 1,
+        )));
+    }
+    Ok(())
+}
+
+/// Most grouping sets that a single aggregation may have, as every input row is duplicated once per
+/// grouping set. This is the same limit as in Spark.
+const MAX_GROUPING_SETS: usize = 4096;
+
+fn check_grouping_sets(num_sets: usize) -> DaftResult<()> {
+    if num_sets > MAX_GROUPING_SETS {
+        return Err(DaftError::ValueError(format!(
+            "Aggregating over grouping sets supports at most {MAX_GROUPING_SETS} grouping sets, but got {num_sets}"
+        )));
+    }
+    Ok(())
+}
+
+/// The union of the grouping keys of a list of grouping sets, in order of first appearance.
+pub fn grouping_keys(grouping_sets: &[Vec<ExprRef>]) -> Vec<ExprRef> {
+    grouping_sets
+        .iter()
+        .flatten()
+        .cloned()
+        .collect::<IndexSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Computes the grouping id of a grouping set, i.e. a bitmask over the grouping keys where
+/// the bit of a key is set if the key is *not* part of the grouping set.
+///
+/// The first key gets the most significant bit, matching the `GROUPING_ID` of other engines.
+pub fn grouping_id(keys: &[ExprRef], grouping_set: &[ExprRef]) -> i64 {
+    keys.iter().fold(0, |id, key| {
+        (id << 1) | i64::from(!grouping_set.contains(key))
+    })
+}
+
+/// Builds the `GROUPING(e1, ..., ek)` expression over the grouping id column of an aggregation
+/// over `grouping_sets`. Its value for a row is a bitmask over the arguments where the bit of
+/// an argument is set if the argument is aggregated over, i.e. not grouped by, in that row.
+pub fn grouping_expr(
+    grouping_sets: &[Vec<ExprRef>],
+    grouping_id_column: &str,
+    args: &[ExprRef],
+) -> DaftResult<ExprRef> {
+    let keys = grouping_keys(grouping_sets);
+    if let Some(arg) = args.iter().find(|arg| !keys.contains(arg)) {
+        return Err(DaftError::ValueError(format!(
+            "Arguments to GROUPING must be grouping expressions, but got: {arg}"
+        )));
+    }
+    let ids_by_value = grouping_sets
+        .iter()
+        .map(|set| (grouping_id(args, set), grouping_id(&keys, set)))
+        .into_group_map();
+
+    let id_column = resolved_col(grouping_id_column);
+    Ok(ids_by_value
+        .into_iter()
+        .filter(|(value, _)| *value != 0)
+        .sorted()
+        .fold(lit(0i64), |expr, (value, ids)| {
+            id_column
+                .clone()
+                .is_in(ids.into_iter().map(lit).collect())
+                .if_else(lit(value), expr)
+        }))
+}
+
+/// Aggregates the input once for every grouping set, in a single pass over the input.
+///
+/// This is a rewrite into existing operators rather than an operator of its own: a projection,
+/// an explode and a regular aggregation. Every input row is duplicated once per grouping set,
+/// tagged with the index of the set, and the keys that are not part of the set are nulled out.
+/// The aggregation grouped by all keys and the set index then computes every grouping set at once.
+/// Like in SQL, a grouping set that is listed more than once produces its groups more than once.
+///
+/// The explode multiplies the rows fed into the aggregation by the number of grouping sets, which
+/// is at most `2^n` for a `CUBE` over `n` keys. This is accepted because the expansion is streamed
+/// and the partial aggregation collapses it before anything is shuffled, so only the pre-aggregated
+/// groups of every set are exchanged; and unlike a union of one aggregation per set, the input is
+/// only scanned and computed once, and every runner and optimization that supports aggregations
+/// supports grouping sets as well.
+///
+/// The output has the union of the grouping keys, followed by the aggregations and an Int64
+/// column named `grouping_id_column` that holds the grouping id (see [`grouping_id`]) of each row.
+pub fn grouping_sets_aggregate(
+    input: &LogicalPlanBuilder,
+    agg_exprs: Vec<ExprRef>,
+    grouping_sets: Vec<Vec<ExprRef>>,
+    grouping_id_column: &str,
+) -> DaftResult<LogicalPlanBuilder> {
+    if grouping_sets.is_empty() {
+        return Err(DaftError::ValueError(
+            "Aggregating over grouping sets requires at least one grouping set".to_string(),
+        ));
+    }
+    check_grouping_sets(grouping_sets.len())?;
+    let keys = grouping_keys(&grouping_sets);
+    check_grouping_keys(keys.len())?;
+
+    // The keys are grouped on as hidden columns, so that aggregations over the same columns
+    // still see the values that a grouping set nulls out.
+    let key_column = |i: usize| format!("__grouping_key_{i}__");
+    let set_column = "__grouping_set__";
+    let mut columns = keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| key.alias(key_column(i)))
+        .collect::<Vec<_>>();
+    columns.push(
+        Expr::List((0..grouping_sets.len() as i64).map(lit).collect())
+            .arced()
+            .alias(set_column),
+    );
+    let expanded = input
+        .with_columns(columns)?
+        .explode(vec![resolved_col(set_column)], None)?;
+
+    let schema = expanded.schema();
+    let mut nulled_keys = Vec::new();
+    for (i, key) in keys.iter().enumerate() {
+        let present_sets = grouping_sets
+            .iter()
+            .positions(|set| set.contains(key))
+            .map(|set| lit(set as i64))
+            .collect::<Vec<_>>();
+        if present_sets.len() == grouping_sets.len() {
+            continue;
+        }
+        let name = key_column(i);
+        let dtype = &schema.get_field(&name)?.dtype;
+        nulled_keys.push(
+            resolved_col(set_column)
+                .is_in(present_sets)
+                .if_else(resolved_col(name.as_str()), null_lit().cast(dtype))
+                .alias(name),
+        );
+    }
+
+    let mut groupby = (0..keys.len())
+        .map(|i| resolved_col(key_column(i)))
+        .collect::<Vec<_>>();
+    groupby.push(resolved_col(set_column));
+
+    let sets_by_id = grouping_sets
+        .iter()
+        .enumerate()
+        .map(|(i, set)| (grouping_id(&keys, set), i as i64))
+        .into_group_map();
+    let grouping_id_expr = sets_by_id
+        .into_iter()
+        .filter(|(id, _)| *id != 0)
+        .sorted()
+        .fold(lit(0i64), |expr, (id, sets)| {
+            resolved_col(set_column)
+                .is_in(sets.into_iter().map(lit).collect())
+                .if_else(lit(id), expr)
+        });
+
+    let mut output = keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| resolved_col(key_column(i)).alias(key.name()))
+        .collect::<Vec<_>>();
+    output.extend(agg_exprs.iter().map(|agg| resolved_col(agg.name())));
+    output.push(grouping_id_expr.alias(grouping_id_column));
+
+    expanded
+        .with_columns(nulled_keys)?
+        .aggregate(agg_exprs, groupby)?
+        .select(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::{count_mode::CountMode, prelude::*};
+    use daft_dsl::unresolved_col;
+
+    use super::*;
+    use crate::test::{dummy_scan_node, dummy_scan_operator};
+
+    #[test]
+    fn rollup_and_cube_expand_to_grouping_sets() {
+        let (a, b) = (unresolved_col("a"), unresolved_col("b"));
+        assert_eq!(
+            rollup(vec![vec![a.clone()], vec![b.clone()]]),
+            vec![vec![a.clone(), b.clone()], vec![a.clone()], vec![]]
+        );
+        assert_eq!(
+            cube(vec![vec![a.clone()], vec![b.clone()]]).unwrap(),
+            vec![vec![a.clone(), b.clone()], vec![a.clone()], vec![b], vec![]]
+        );
+        assert!(cube(vec![vec![a]; 64]).is_err());
+    }
+
+    #[test]
+    fn grouping_sets_are_limited() {
+        let keys = (0..13)
+            .map(|i| vec![unresolved_col(format!("k{i}"))])
+            .collect::<Vec<_>>();
+        assert_eq!(cube(keys[..12].to_vec()).unwrap().len(), 4096);
+        assert!(matches!(cube(keys), Err(DaftError::ValueError(_))));
+
+        let input = dummy_scan_node(dummy_scan_operator(vec![Field::new("a", DataType::Int64)]));
+        let err = grouping_sets_aggregate(
+            &input,
+            vec![unresolved_col("a").count(CountMode::Valid)],
+            vec![vec![unresolved_col("a")]; 4097],
+            "grouping_id",
+        )
+        .unwrap_err();
+        assert!(matches!(err, DaftError::ValueError(_)), "{err}");
+    }
+
+    #[test]
+    fn grouping_ids_set_bits_of_missing_keys() {
+        let (a, b, c) = (
+            unresolved_col("a"),
+            unresolved_col("b"),
+            unresolved_col("c"),
+        );
+        let keys = [a.clone(), b.clone(), c.clone()];
+        assert_eq!(grouping_id(&keys, &keys), 0);
+        assert_eq!(grouping_id(&keys, &[a.clone(), b]), 0b001);
+        assert_eq!(grouping_id(&keys, &[c]), 0b110);
+        assert_eq!(grouping_id(&keys, &[]), 0b111);
+        assert_eq!(grouping_id(&keys, &[a]), 0b011);
+    }
+}
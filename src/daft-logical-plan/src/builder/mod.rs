@@ -1,3 +1,4 @@
+pub mod grouping_sets;
 mod resolve_expr;
 
 use std::{
@@ -9,10 +10,10 @@ use std::{
 use common_daft_config::DaftPlanningConfig;
 use common_display::mermaid::MermaidDisplayOptions;
 use common_error::{DaftError, DaftResult};
-use common_file_formats::FileFormat;
+use common_file_formats::{CsvWriteOptions, FileFormat};
 use common_io_config::IOConfig;
 use common_scan_info::{PhysicalScanInfo, Pushdowns, ScanOperatorRef};
-use daft_core::join::{JoinStrategy, JoinType};
+use daft_core::join::{AsofJoinDirection, JoinStrategy, JoinType};
 use daft_dsl::{resolved_col, ExprRef};
 use daft_schema::schema::{Schema, SchemaRef};
 use indexmap::IndexSet;
@@ -31,12 +32,12 @@ use {
 use crate::{
     logical_plan::{LogicalPlan, SubqueryAlias},
     ops::{self, join::JoinOptions},
-    optimization::OptimizerBuilder,
+    optimization::{self, OptimizerBuilder},
     partitioning::{
         HashRepartitionConfig, IntoPartitionsConfig, RandomShuffleConfig, RepartitionSpec,
     },
     sink_info::{OutputFileInfo, SinkInfo},
-    source_info::{InMemoryInfo, SourceInfo},
+    source_info::{InMemoryInfo, SourceInfo, WorkTableInfo},
     LogicalPlanRef,
 };
 
@@ -148,6 +149,15 @@ impl LogicalPlanBuilder {
         Ok(Self::from(Arc::new(logical_plan)))
     }
 
+    /// Creates a `LogicalPlan::Source` which reads the working table of a recursive union.
+    pub fn work_table(work_table: WorkTableInfo) -> Self {
+        let schema = work_table.source_schema.clone();
+        let source_info = SourceInfo::WorkTable(work_table);
+        let logical_plan: LogicalPlan = ops::Source::new(schema, source_info.into()).into();
+
+        Self::from(Arc::new(logical_plan))
+    }
+
     /// Creates a `LogicalPlan::Source` from a scan handle.
     pub fn table_scan(
         scan_operator: ScanOperatorRef,
@@ -202,16 +212,24 @@ impl LogicalPlanBuilder {
     }
 
     pub fn select(&self, to_select: Vec<ExprRef>) -> DaftResult<Self> {
-        let expr_resolver = ExprResolver::builder().allow_actor_pool_udf(true).build();
+        let expr_resolver = ExprResolver::builder()
+            .allow_actor_pool_udf(true)
+            .allow_window(true)
+            .build();
 
         let to_select = expr_resolver.resolve(to_select, self.plan.clone())?;
 
-        let logical_plan: LogicalPlan = ops::Project::try_new(self.plan.clone(), to_select)?.into();
+        let (input, to_select) =
+            ops::Window::extract_from_projection(self.plan.clone(), to_select)?;
+        let logical_plan: LogicalPlan = ops::Project::try_new(input, to_select)?.into();
         Ok(self.with_new_plan(logical_plan))
     }
 
     pub fn with_columns(&self, columns: Vec<ExprRef>) -> DaftResult<Self> {
-        let expr_resolver = ExprResolver::builder().allow_actor_pool_udf(true).build();
+        let expr_resolver = ExprResolver::builder()
+            .allow_actor_pool_udf(true)
+            .allow_window(true)
+            .build();
 
         let columns = expr_resolver.resolve(columns, self.plan.clone())?;
 
@@ -242,7 +260,8 @@ impl LogicalPlanBuilder {
                 .cloned(),
         );
 
-        let logical_plan: LogicalPlan = ops::Project::try_new(self.plan.clone(), exprs)?.into();
+        let (input, exprs) = ops::Window::extract_from_projection(self.plan.clone(), exprs)?;
+        let logical_plan: LogicalPlan = ops::Project::try_new(input, exprs)?.into();
         Ok(self.with_new_plan(logical_plan))
     }
 
@@ -309,13 +328,21 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
-    pub fn explode(&self, to_explode: Vec<ExprRef>) -> DaftResult<Self> {
+    pub fn explode(
+        &self,
+        to_explode: Vec<ExprRef>,
+        index_column: Option<&str>,
+    ) -> DaftResult<Self> {
         let expr_resolver = ExprResolver::default();
 
         let to_explode = expr_resolver.resolve(to_explode, self.plan.clone())?;
 
-        let logical_plan: LogicalPlan =
-            ops::Explode::try_new(self.plan.clone(), to_explode)?.into();
+        let logical_plan: LogicalPlan = ops::Explode::try_new(
+            self.plan.clone(),
+            to_explode,
+            index_column.map(ToString::to_string),
+        )?
+        .into();
         Ok(self.with_new_plan(logical_plan))
     }
 
@@ -440,6 +467,15 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(ops::summarize(self)?))
     }
 
+    /// Creates a summary of the given statistics of each column, with a row per statistic.
+    pub fn summarize_statistics(
+        &self,
+        columns: &[String],
+        statistics: &[ops::SummaryStatistic],
+    ) -> DaftResult<Self> {
+        Ok(self.with_new_plan(ops::summarize_statistics(self, columns, statistics)?))
+    }
+
     pub fn distinct(&self) -> DaftResult<Self> {
         let logical_plan: LogicalPlan = ops::Distinct::new(self.plan.clone()).into();
         Ok(self.with_new_plan(logical_plan))
@@ -472,6 +508,19 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    /// Aggregates over multiple grouping sets at once, e.g. for `ROLLUP` and `CUBE`.
+    ///
+    /// Keys that are not part of a row's grouping set are null, and the grouping id of each row
+    /// is added as an Int64 column named `grouping_id_column`.
+    pub fn aggregate_grouping_sets(
+        &self,
+        agg_exprs: Vec<ExprRef>,
+        grouping_sets: Vec<Vec<ExprRef>>,
+        grouping_id_column: &str,
+    ) -> DaftResult<Self> {
+        grouping_sets::grouping_sets_aggregate(self, agg_exprs, grouping_sets, grouping_id_column)
+    }
+
     pub fn pivot(
         &self,
         group_by: Vec<ExprRef>,
@@ -548,6 +597,32 @@ impl LogicalPlanBuilder {
         join_type: JoinType,
         join_strategy: Option<JoinStrategy>,
         options: JoinOptions,
+    ) -> DaftResult<Self> {
+        self.join_with_predicate(
+            right,
+            left_on,
+            right_on,
+            null_equals_nulls,
+            None,
+            join_type,
+            join_strategy,
+            options,
+        )
+    }
+
+    /// Join on the given equality keys along with an arbitrary predicate over both sides,
+    /// whose columns are referenced with [`daft_dsl::join_side_col`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn join_with_predicate<Right: Into<LogicalPlanRef>>(
+        &self,
+        right: Right,
+        left_on: Vec<ExprRef>,
+        right_on: Vec<ExprRef>,
+        null_equals_nulls: Option<Vec<bool>>,
+        predicate: Option<ExprRef>,
+        join_type: JoinType,
+        join_strategy: Option<JoinStrategy>,
+        options: JoinOptions,
     ) -> DaftResult<Self> {
         let left_plan = self.plan.clone();
         let right_plan = right.into();
@@ -557,9 +632,10 @@ impl LogicalPlanBuilder {
         let left_on = expr_resolver.resolve(left_on, left_plan.clone())?;
         let right_on = expr_resolver.resolve(right_on, right_plan.clone())?;
 
-        let (left_plan, right_plan, left_on, right_on) = ops::join::Join::deduplicate_join_columns(
-            left_plan, right_plan, left_on, right_on, join_type, options,
-        )?;
+        let (left_plan, right_plan, left_on, right_on, predicate) =
+            ops::join::Join::deduplicate_join_columns(
+                left_plan, right_plan, left_on, right_on, predicate, join_type, options,
+            )?;
 
         let logical_plan: LogicalPlan = ops::Join::try_new(
             left_plan,
@@ -567,6 +643,7 @@ impl LogicalPlanBuilder {
             left_on,
             right_on,
             null_equals_nulls,
+            predicate,
             join_type,
             join_strategy,
         )?
@@ -574,6 +651,57 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn asof_join<Right: Into<LogicalPlanRef>>(
+        &self,
+        right: Right,
+        left_by: Vec<ExprRef>,
+        right_by: Vec<ExprRef>,
+        left_on: ExprRef,
+        right_on: ExprRef,
+        direction: AsofJoinDirection,
+        tolerance: Option<ExprRef>,
+        options: JoinOptions,
+    ) -> DaftResult<Self> {
+        let left_plan = self.plan.clone();
+        let right_plan = right.into();
+
+        let expr_resolver = ExprResolver::default();
+
+        let left_by = expr_resolver.resolve(left_by, left_plan.clone())?;
+        let right_by = expr_resolver.resolve(right_by, right_plan.clone())?;
+        let left_on = expr_resolver.resolve_single(left_on, left_plan.clone())?;
+        let right_on = expr_resolver.resolve_single(right_on, right_plan.clone())?;
+
+        // The `on` keys are deduplicated along with the `by` keys, as the last join key.
+        let left_keys = left_by
+            .into_iter()
+            .chain(std::iter::once(left_on))
+            .collect();
+        let right_keys = right_by
+            .into_iter()
+            .chain(std::iter::once(right_on))
+            .collect();
+        let (left_plan, right_plan, mut left_keys, mut right_keys, _) =
+            ops::join::Join::deduplicate_join_columns(
+                left_plan,
+                right_plan,
+                left_keys,
+                right_keys,
+                None,
+                JoinType::Left,
+                options,
+            )?;
+        let left_on = left_keys.pop().expect("ASOF join should have an on key");
+        let right_on = right_keys.pop().expect("ASOF join should have an on key");
+
+        let logical_plan: LogicalPlan = ops::AsofJoin::try_new(
+            left_plan, right_plan, left_keys, right_keys, left_on, right_on, direction, tolerance,
+        )?
+        .into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
     pub fn cross_join<Right: Into<LogicalPlanRef>>(
         &self,
         right: Right,
@@ -582,6 +710,56 @@ impl LogicalPlanBuilder {
         self.join(right, vec![], vec![], JoinType::Inner, None, options)
     }
 
+    /// Joins each row of this plan with the rows of a lateral subquery, i.e. a subquery whose
+    /// correlated columns are outer references to this plan.
+    ///
+    /// The subquery is decorrelated into an equi-join on its correlated columns, and the output has
+    /// the columns of this plan followed by the columns of the subquery.
+    pub fn lateral_join<Right: Into<LogicalPlanRef>>(
+        &self,
+        subquery: Right,
+        join_type: JoinType,
+        options: JoinOptions,
+    ) -> DaftResult<Self> {
+        let subquery: LogicalPlanRef = subquery.into();
+        let subquery_names = subquery.schema().names();
+
+        let (subquery, subquery_on, outer_on) = optimization::pull_up_correlated_cols(subquery)?;
+        if subquery_on.is_empty() && join_type != JoinType::Inner {
+            return Err(DaftError::NotImplemented(format!(
+                "{join_type} join with an uncorrelated lateral subquery"
+            )));
+        }
+
+        let decorrelated_names = subquery.schema().names();
+        let num_outer_columns = self.schema().len();
+        let joined = self.join(
+            subquery,
+            outer_on,
+            subquery_on,
+            join_type,
+            None,
+            options.merge_matching_join_keys(false),
+        )?;
+
+        // drop the correlated columns that were pulled up to the output of the subquery,
+        // whose columns may have been renamed by the join.
+        let joined_names = joined.schema().names();
+        let (outer_names, right_names) = joined_names.split_at(num_outer_columns);
+        let columns = outer_names
+            .iter()
+            .chain(
+                right_names
+                    .iter()
+                    .zip(&decorrelated_names)
+                    .filter(|(_, name)| subquery_names.contains(name))
+                    .map(|(joined_name, _)| joined_name),
+            )
+            .map(|name| resolved_col(name.as_str()))
+            .collect();
+        joined.select(columns)
+    }
+
     pub fn concat(&self, other: &Self) -> DaftResult<Self> {
         let logical_plan: LogicalPlan =
             ops::Concat::try_new(self.plan.clone(), other.plan.clone())?.into();
@@ -609,6 +787,24 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    /// Unions this plan with `recursive`, which is re-evaluated over the rows of its previous
+    /// iteration, read through the work table with id `work_table_id`, until no new rows appear.
+    pub fn recursive_union(
+        &self,
+        recursive: &Self,
+        work_table_id: usize,
+        is_all: bool,
+    ) -> DaftResult<Self> {
+        let logical_plan: LogicalPlan = ops::RecursiveUnion::try_new(
+            self.plan.clone(),
+            recursive.plan.clone(),
+            work_table_id,
+            is_all,
+        )?
+        .into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
     pub fn add_monotonically_increasing_id(&self, column_name: Option<&str>) -> DaftResult<Self> {
         let logical_plan: LogicalPlan =
             ops::MonotonicallyIncreasingId::try_new(self.plan.clone(), column_name)?.into();
@@ -622,6 +818,7 @@ impl LogicalPlanBuilder {
         partition_cols: Option<Vec<ExprRef>>,
         compression: Option<String>,
         io_config: Option<IOConfig>,
+        csv_options: Option<CsvWriteOptions>,
     ) -> DaftResult<Self> {
         let expr_resolver = ExprResolver::default();
 
@@ -635,6 +832,7 @@ impl LogicalPlanBuilder {
             partition_cols,
             compression,
             io_config,
+            csv_options,
         ));
 
         let logical_plan: LogicalPlan =
@@ -730,6 +928,31 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    /// Writes the rows of this plan to a table of a daft catalog through its `daft.catalog.Table`
+    /// python object, once all of them have been computed.
+    #[cfg(feature = "python")]
+    pub fn catalog_table_write(
+        &self,
+        table_name: String,
+        mode: String,
+        table: Arc<PyObject>,
+    ) -> DaftResult<Self> {
+        use crate::sink_info::TableCatalogInfo;
+
+        let sink_info = SinkInfo::CatalogInfo(CatalogInfo {
+            catalog: crate::sink_info::CatalogType::Table(TableCatalogInfo {
+                table_name,
+                mode,
+                table,
+            }),
+            catalog_columns: self.schema().names(),
+        });
+
+        let logical_plan: LogicalPlan =
+            ops::Sink::try_new(self.plan.clone(), sink_info.into())?.into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
     /// Async equivalent of `optimize`
     /// This is safe to call from a tokio runtime
     pub fn optimize_async(&self) -> impl Future<Output = DaftResult<Self>> {
@@ -924,8 +1147,12 @@ impl PyLogicalPlanBuilder {
         Ok(self.builder.limit(limit, eager)?.into())
     }
 
-    pub fn explode(&self, to_explode: Vec<PyExpr>) -> PyResult<Self> {
-        Ok(self.builder.explode(pyexprs_to_exprs(to_explode))?.into())
+    #[pyo3(signature = (to_explode, index_column=None))]
+    pub fn explode(&self, to_explode: Vec<PyExpr>, index_column: Option<&str>) -> PyResult<Self> {
+        Ok(self
+            .builder
+            .explode(pyexprs_to_exprs(to_explode), index_column)?
+            .into())
     }
 
     pub fn unpivot(
@@ -1014,6 +1241,23 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
+    pub fn aggregate_grouping_sets(
+        &self,
+        agg_exprs: Vec<PyExpr>,
+        grouping_sets: Vec<Vec<PyExpr>>,
+        grouping_id_column: &str,
+    ) -> PyResult<Self> {
+        let grouping_sets = grouping_sets.into_iter().map(pyexprs_to_exprs).collect();
+        Ok(self
+            .builder
+            .aggregate_grouping_sets(
+                pyexprs_to_exprs(agg_exprs),
+                grouping_sets,
+                grouping_id_column,
+            )?
+            .into())
+    }
+
     pub fn pivot(
         &self,
         group_by: Vec<PyExpr>,
@@ -1070,6 +1314,49 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        right,
+        left_by,
+        right_by,
+        left_on,
+        right_on,
+        direction,
+        tolerance=None,
+        prefix=None,
+        suffix=None,
+    ))]
+    pub fn asof_join(
+        &self,
+        right: &Self,
+        left_by: Vec<PyExpr>,
+        right_by: Vec<PyExpr>,
+        left_on: PyExpr,
+        right_on: PyExpr,
+        direction: AsofJoinDirection,
+        tolerance: Option<PyExpr>,
+        prefix: Option<String>,
+        suffix: Option<String>,
+    ) -> PyResult<Self> {
+        Ok(self
+            .builder
+            .asof_join(
+                &right.builder,
+                pyexprs_to_exprs(left_by),
+                pyexprs_to_exprs(right_by),
+                left_on.into(),
+                right_on.into(),
+                direction,
+                tolerance.map(|t| t.into()),
+                JoinOptions {
+                    prefix,
+                    suffix,
+                    merge_matching_join_keys: true,
+                },
+            )?
+            .into())
+    }
+
     pub fn concat(&self, other: &Self) -> DaftResult<Self> {
         Ok(self.builder.concat(&other.builder)?.into())
     }
@@ -1096,7 +1383,8 @@ impl PyLogicalPlanBuilder {
         file_format,
         partition_cols=None,
         compression=None,
-        io_config=None
+        io_config=None,
+        csv_options=None
     ))]
     pub fn table_write(
         &self,
@@ -1105,6 +1393,7 @@ impl PyLogicalPlanBuilder {
         partition_cols: Option<Vec<PyExpr>>,
         compression: Option<String>,
         io_config: Option<common_io_config::python::IOConfig>,
+        csv_options: Option<CsvWriteOptions>,
     ) -> PyResult<Self> {
         Ok(self
             .builder
@@ -1114,6 +1403,7 @@ impl PyLogicalPlanBuilder {
                 partition_cols.map(pyexprs_to_exprs),
                 compression,
                 io_config.map(|cfg| cfg.config),
+                csv_options,
             )?
             .into())
     }
@@ -5,7 +5,7 @@ use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
 use daft_core::prelude::*;
 use daft_dsl::{
     functions::{struct_::StructExpr, FunctionExpr},
-    has_agg, is_actor_pool_udf, resolved_col, AggExpr, Column, Expr, ExprRef, PlanRef,
+    has_agg, has_window, is_actor_pool_udf, resolved_col, AggExpr, Column, Expr, ExprRef, PlanRef,
     ResolvedColumn, UnresolvedColumn,
 };
 use typed_builder::TypedBuilder;
@@ -162,12 +162,14 @@ fn convert_udfs_to_map_groups(expr: &ExprRef) -> ExprRef {
 }
 
 /// Used for resolving and validating expressions.
-/// Specifically, makes sure the expression does not contain aggregations or actor pool UDFs
+/// Specifically, makes sure the expression does not contain aggregations, window functions or actor pool UDFs
 /// where they are not allowed, and resolves struct accessors and wildcards.
 #[derive(Default, TypedBuilder)]
 pub struct ExprResolver<'a> {
     #[builder(default)]
     allow_actor_pool_udf: bool,
+    #[builder(default)]
+    allow_window: bool,
     #[builder(via_mutators, mutators(
         pub fn in_agg_context(&mut self, in_agg_context: bool) {
             // workaround since typed_builder can't have defaults for mutator requirements
@@ -192,6 +194,21 @@ impl<'a> ExprResolver<'a> {
             )));
         }
 
+        if has_window(&expr) {
+            if !self.allow_window {
+                return Err(DaftError::ValueError(format!(
+                    "Window functions are currently only allowed in select and with_columns: {expr}"
+                )));
+            }
+            if expr.exists(|e| {
+                matches!(e.as_ref(), Expr::Over(..)) && e.children().iter().any(has_window)
+            }) {
+                return Err(DaftError::ValueError(format!(
+                    "Window functions cannot be nested in other window functions: {expr}"
+                )));
+            }
+        }
+
         expand_wildcard(expr, plan.clone())?
             .into_iter()
             .map(|e| resolve_unresolved_columns(e, plan.clone()))
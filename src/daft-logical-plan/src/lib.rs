@@ -18,8 +18,8 @@ mod treenode;
 pub use builder::{LogicalPlanBuilder, PyLogicalPlanBuilder};
 #[cfg(feature = "python")]
 use common_file_formats::{
-    python::PyFileFormatConfig, CsvSourceConfig, DatabaseSourceConfig, JsonSourceConfig,
-    ParquetSourceConfig,
+    python::PyFileFormatConfig, CsvSourceConfig, CsvWriteOptions, DatabaseSourceConfig,
+    IpcSourceConfig, JsonSourceConfig, OrcSourceConfig, ParquetSourceConfig,
 };
 pub use daft_core::join::{JoinStrategy, JoinType};
 pub use logical_plan::{LogicalPlan, LogicalPlanRef};
@@ -28,9 +28,11 @@ pub use partitioning::ClusteringSpec;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 #[cfg(feature = "python")]
-pub use sink_info::{CatalogType, DeltaLakeCatalogInfo, IcebergCatalogInfo, LanceCatalogInfo};
+pub use sink_info::{
+    CatalogType, DeltaLakeCatalogInfo, IcebergCatalogInfo, LanceCatalogInfo, TableCatalogInfo,
+};
 pub use sink_info::{OutputFileInfo, SinkInfo};
-pub use source_info::{FileInfo, FileInfos, InMemoryInfo, SourceInfo};
+pub use source_info::{FileInfo, FileInfos, InMemoryInfo, SourceInfo, WorkTableInfo};
 
 #[cfg(feature = "python")]
 pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
@@ -38,7 +40,10 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_class::<PyFileFormatConfig>()?;
     parent.add_class::<ParquetSourceConfig>()?;
     parent.add_class::<JsonSourceConfig>()?;
+    parent.add_class::<OrcSourceConfig>()?;
+    parent.add_class::<IpcSourceConfig>()?;
     parent.add_class::<CsvSourceConfig>()?;
+    parent.add_class::<CsvWriteOptions>()?;
     parent.add_class::<DatabaseSourceConfig>()?;
     parent.add_class::<FileInfos>()?;
     parent.add_class::<FileInfo>()?;
@@ -8,7 +8,10 @@ use std::{
 use common_display::ascii::AsciiTreeDisplay;
 use common_error::{DaftError, DaftResult};
 use common_treenode::TreeNodeRecursion;
-use daft_dsl::{optimization::get_required_columns, Subquery, SubqueryPlan};
+use daft_core::join::JoinSide;
+use daft_dsl::{
+    join::get_join_predicate_columns, optimization::get_required_columns, Subquery, SubqueryPlan,
+};
 use daft_schema::schema::SchemaRef;
 use indexmap::IndexSet;
 use snafu::Snafu;
@@ -34,10 +37,13 @@ pub enum LogicalPlan {
     Concat(Concat),
     Intersect(Intersect),
     Union(Union),
+    RecursiveUnion(RecursiveUnion),
     Join(Join),
+    AsofJoin(AsofJoin),
     Sink(Sink),
     Sample(Sample),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
+    Window(Window),
     SubqueryAlias(SubqueryAlias),
 }
 
@@ -90,12 +96,15 @@ impl LogicalPlan {
             Self::Concat(Concat { input, .. }) => input.schema(),
             Self::Intersect(Intersect { lhs, .. }) => lhs.schema(),
             Self::Union(Union { lhs, .. }) => lhs.schema(),
+            Self::RecursiveUnion(recursive_union) => recursive_union.schema(),
             Self::Join(Join { output_schema, .. }) => output_schema.clone(),
+            Self::AsofJoin(AsofJoin { output_schema, .. }) => output_schema.clone(),
             Self::Sink(Sink { schema, .. }) => schema.clone(),
             Self::Sample(Sample { input, .. }) => input.schema(),
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { schema, .. }) => {
                 schema.clone()
             }
+            Self::Window(Window { output_schema, .. }) => output_schema.clone(),
             Self::SubqueryAlias(SubqueryAlias { input, .. }) => input.schema(),
         }
     }
@@ -176,6 +185,14 @@ impl LogicalPlan {
                     .collect();
                 vec![res]
             }
+            Self::Window(window) => {
+                let res = window
+                    .window_functions
+                    .iter()
+                    .flat_map(get_required_columns)
+                    .collect();
+                vec![res]
+            }
             Self::Pivot(pivot) => {
                 let res = pivot
                     .group_by
@@ -187,16 +204,56 @@ impl LogicalPlan {
                 vec![res]
             }
             Self::Join(join) => {
-                let left = join.left_on.iter().flat_map(get_required_columns).collect();
-                let right = join
+                let mut left: IndexSet<_> =
+                    join.left_on.iter().flat_map(get_required_columns).collect();
+                let mut right: IndexSet<_> = join
                     .right_on
                     .iter()
                     .flat_map(get_required_columns)
                     .collect();
+                if let Some(predicate) = &join.predicate {
+                    for (name, side) in get_join_predicate_columns(predicate) {
+                        match side {
+                            JoinSide::Left => left.insert(name.to_string()),
+                            JoinSide::Right => right.insert(name.to_string()),
+                        };
+                    }
+                }
+                vec![left, right]
+            }
+            Self::AsofJoin(asof_join) => {
+                let left = asof_join
+                    .left_by
+                    .iter()
+                    .chain(std::iter::once(&asof_join.left_on))
+                    .flat_map(get_required_columns)
+                    .collect();
+                let right = asof_join
+                    .right_by
+                    .iter()
+                    .chain(std::iter::once(&asof_join.right_on))
+                    .flat_map(get_required_columns)
+                    .collect();
                 vec![left, right]
             }
             Self::Intersect(_) => vec![IndexSet::new(), IndexSet::new()],
             Self::Union(_) => vec![IndexSet::new(), IndexSet::new()],
+            Self::RecursiveUnion(recursive_union) => {
+                // Every column of both terms is needed, since the rows are fed back into the recursive term.
+                let anchor = recursive_union
+                    .anchor
+                    .schema()
+                    .names()
+                    .into_iter()
+                    .collect();
+                let recursive = recursive_union
+                    .recursive
+                    .schema()
+                    .names()
+                    .into_iter()
+                    .collect();
+                vec![anchor, recursive]
+            }
             Self::Source(_) => todo!(),
             Self::Sink(_) => todo!(),
             Self::SubqueryAlias(SubqueryAlias { input, .. }) => input.required_columns(),
@@ -219,11 +276,14 @@ impl LogicalPlan {
             Self::Pivot(..) => "Pivot",
             Self::Concat(..) => "Concat",
             Self::Join(..) => "Join",
+            Self::AsofJoin(..) => "AsofJoin",
             Self::Intersect(..) => "Intersect",
             Self::Union(..) => "Union",
+            Self::RecursiveUnion(..) => "RecursiveUnion",
             Self::Sink(..) => "Sink",
             Self::Sample(..) => "Sample",
             Self::MonotonicallyIncreasingId(..) => "MonotonicallyIncreasingId",
+            Self::Window(..) => "Window",
             Self::SubqueryAlias(..) => "Alias",
         }
     }
@@ -243,9 +303,12 @@ impl LogicalPlan {
             | Self::Aggregate(Aggregate { stats_state, .. })
             | Self::Pivot(Pivot { stats_state, .. })
             | Self::Concat(Concat { stats_state, .. })
+            | Self::RecursiveUnion(RecursiveUnion { stats_state, .. })
             | Self::Join(Join { stats_state, .. })
+            | Self::AsofJoin(AsofJoin { stats_state, .. })
             | Self::Sink(Sink { stats_state, .. })
             | Self::Sample(Sample { stats_state, .. })
+            | Self::Window(Window { stats_state, .. })
             | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { stats_state, .. }) => {
                 stats_state
             }
@@ -282,6 +345,7 @@ impl LogicalPlan {
             Self::Aggregate(plan) => Self::Aggregate(plan.with_materialized_stats()),
             Self::Pivot(plan) => Self::Pivot(plan.with_materialized_stats()),
             Self::Concat(plan) => Self::Concat(plan.with_materialized_stats()),
+            Self::RecursiveUnion(plan) => Self::RecursiveUnion(plan.with_materialized_stats()),
             Self::Intersect(_) => {
                 panic!("Intersect should be optimized away before stats are derived")
             }
@@ -292,11 +356,13 @@ impl LogicalPlan {
                 panic!("Alias should be optimized away before stats are derived")
             }
             Self::Join(plan) => Self::Join(plan.with_materialized_stats()),
+            Self::AsofJoin(plan) => Self::AsofJoin(plan.with_materialized_stats()),
             Self::Sink(plan) => Self::Sink(plan.with_materialized_stats()),
             Self::Sample(plan) => Self::Sample(plan.with_materialized_stats()),
             Self::MonotonicallyIncreasingId(plan) => {
                 Self::MonotonicallyIncreasingId(plan.with_materialized_stats())
             }
+            Self::Window(plan) => Self::Window(plan.with_materialized_stats()),
         }
     }
 
@@ -317,12 +383,15 @@ impl LogicalPlan {
             Self::Concat(concat) => concat.multiline_display(),
             Self::Intersect(inner) => inner.multiline_display(),
             Self::Union(inner) => inner.multiline_display(),
+            Self::RecursiveUnion(recursive_union) => recursive_union.multiline_display(),
             Self::Join(join) => join.multiline_display(),
+            Self::AsofJoin(asof_join) => asof_join.multiline_display(),
             Self::Sink(sink) => sink.multiline_display(),
             Self::Sample(sample) => sample.multiline_display(),
             Self::MonotonicallyIncreasingId(monotonically_increasing_id) => {
                 monotonically_increasing_id.multiline_display()
             }
+            Self::Window(window) => window.multiline_display(),
             Self::SubqueryAlias(alias) => alias.multiline_display(),
         }
     }
@@ -343,13 +412,18 @@ impl LogicalPlan {
             Self::Pivot(Pivot { input, .. }) => vec![input],
             Self::Concat(Concat { input, other, .. }) => vec![input, other],
             Self::Join(Join { left, right, .. }) => vec![left, right],
+            Self::AsofJoin(AsofJoin { left, right, .. }) => vec![left, right],
             Self::Sink(Sink { input, .. }) => vec![input],
             Self::Intersect(Intersect { lhs, rhs, .. }) => vec![lhs, rhs],
             Self::Union(Union { lhs, rhs, .. }) => vec![lhs, rhs],
+            Self::RecursiveUnion(RecursiveUnion {
+                anchor, recursive, ..
+            }) => vec![anchor, recursive],
             Self::Sample(Sample { input, .. }) => vec![input],
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. }) => {
                 vec![input]
             }
+            Self::Window(Window { input, .. }) => vec![input],
             Self::SubqueryAlias(SubqueryAlias { input, .. }) => vec![input],
         }
     }
@@ -364,7 +438,7 @@ impl LogicalPlan {
                 Self::ActorPoolProject(ActorPoolProject {projection, ..}) => Self::ActorPoolProject(ActorPoolProject::try_new(input.clone(), projection.clone()).unwrap()),
                 Self::Filter(Filter { predicate, .. }) => Self::Filter(Filter::try_new(input.clone(), predicate.clone()).unwrap()),
                 Self::Limit(Limit { limit, eager, .. }) => Self::Limit(Limit::new(input.clone(), *limit, *eager)),
-                Self::Explode(Explode { to_explode, .. }) => Self::Explode(Explode::try_new(input.clone(), to_explode.clone()).unwrap()),
+                Self::Explode(Explode { to_explode, index_column, .. }) => Self::Explode(Explode::try_new(input.clone(), to_explode.clone(), index_column.clone()).unwrap()),
                 Self::Sort(Sort { sort_by, descending, nulls_first, .. }) => Self::Sort(Sort::try_new(input.clone(), sort_by.clone(), descending.clone(), nulls_first.clone()).unwrap()),
                 Self::Repartition(Repartition {  repartition_spec: scheme_config, .. }) => Self::Repartition(Repartition::new(input.clone(), scheme_config.clone())),
                 Self::Distinct(_) => Self::Distinct(Distinct::new(input.clone())),
@@ -372,6 +446,7 @@ impl LogicalPlan {
                 Self::Pivot(Pivot { group_by, pivot_column, value_column, aggregation, names, ..}) => Self::Pivot(Pivot::try_new(input.clone(), group_by.clone(), pivot_column.clone(), value_column.clone(), aggregation.into(), names.clone()).unwrap()),
                 Self::Sink(Sink { sink_info, .. }) => Self::Sink(Sink::try_new(input.clone(), sink_info.clone()).unwrap()),
                 Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId {column_name, .. }) => Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId::try_new(input.clone(), Some(column_name)).unwrap()),
+                Self::Window(Window { window_functions, .. }) => Self::Window(Window::try_new(input.clone(), window_functions.clone()).unwrap()),
                 Self::Unpivot(Unpivot {ids, values, variable_name, value_name, output_schema, ..}) =>
                     Self::Unpivot(Unpivot::new(input.clone(), ids.clone(), values.clone(), variable_name.clone(), value_name.clone(), output_schema.clone())),
                 Self::Sample(Sample {fraction, with_replacement, seed, ..}) => Self::Sample(Sample::new(input.clone(), *fraction, *with_replacement, *seed)),
@@ -379,22 +454,36 @@ impl LogicalPlan {
                 Self::Concat(_) => panic!("Concat ops should never have only one input, but got one"),
                 Self::Intersect(_) => panic!("Intersect ops should never have only one input, but got one"),
                 Self::Union(_) => panic!("Union ops should never have only one input, but got one"),
+                Self::RecursiveUnion(_) => panic!("RecursiveUnion ops should never have only one input, but got one"),
                 Self::Join(_) => panic!("Join ops should never have only one input, but got one"),
+                Self::AsofJoin(_) => panic!("AsofJoin ops should never have only one input, but got one"),
             },
             [input1, input2] => match self {
                 Self::Source(_) => panic!("Source nodes don't have children, with_new_children() should never be called for Source ops"),
                 Self::Concat(_) => Self::Concat(Concat::try_new(input1.clone(), input2.clone()).unwrap()),
                 Self::Intersect(inner) => Self::Intersect(Intersect::try_new(input1.clone(), input2.clone(), inner.is_all).unwrap()),
                 Self::Union(inner) => Self::Union(Union::try_new(input1.clone(), input2.clone(), inner.is_all).unwrap()),
-                Self::Join(Join { left_on, right_on, null_equals_nulls, join_type, join_strategy, .. }) => Self::Join(Join::try_new(
+                Self::RecursiveUnion(RecursiveUnion { work_table_id, is_all, .. }) => Self::RecursiveUnion(RecursiveUnion::try_new(input1.clone(), input2.clone(), *work_table_id, *is_all).unwrap()),
+                Self::Join(Join { left_on, right_on, null_equals_nulls, predicate, join_type, join_strategy, .. }) => Self::Join(Join::try_new(
                     input1.clone(),
                     input2.clone(),
                     left_on.clone(),
                     right_on.clone(),
                     null_equals_nulls.clone(),
+                    predicate.clone(),
                     *join_type,
                     *join_strategy,
                 ).unwrap()),
+                Self::AsofJoin(AsofJoin { left_by, right_by, left_on, right_on, direction, tolerance, .. }) => Self::AsofJoin(AsofJoin::try_new(
+                    input1.clone(),
+                    input2.clone(),
+                    left_by.clone(),
+                    right_by.clone(),
+                    left_on.clone(),
+                    right_on.clone(),
+                    *direction,
+                    tolerance.clone(),
+                ).unwrap()),
                 _ => panic!("Logical op {} has one input, but got two", self),
             },
             _ => panic!("Logical ops should never have more than 2 inputs, but got: {}", children.len())
@@ -569,7 +658,10 @@ impl_from_data_struct_for_logical_plan!(Pivot);
 impl_from_data_struct_for_logical_plan!(Concat);
 impl_from_data_struct_for_logical_plan!(Intersect);
 impl_from_data_struct_for_logical_plan!(Union);
+impl_from_data_struct_for_logical_plan!(RecursiveUnion);
 impl_from_data_struct_for_logical_plan!(Join);
+impl_from_data_struct_for_logical_plan!(AsofJoin);
 impl_from_data_struct_for_logical_plan!(Sink);
 impl_from_data_struct_for_logical_plan!(Sample);
 impl_from_data_struct_for_logical_plan!(MonotonicallyIncreasingId);
+impl_from_data_struct_for_logical_plan!(Window);
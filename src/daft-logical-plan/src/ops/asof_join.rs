@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use common_error::DaftError;
+use daft_core::{join::AsofJoinDirection, prelude::*, utils::supertype::try_get_supertype};
+use daft_dsl::{join::infer_join_schema, ExprRef};
+use itertools::Itertools;
+use snafu::ResultExt;
+
+use crate::{
+    logical_plan::{self, CreationSnafu},
+    stats::{PlanStats, StatsState},
+    LogicalPlan,
+};
+
+/// Joins each left row to the right row with equal `by` keys whose `on` key is nearest to that of the left row,
+/// in the given direction. Like a left join, every left row is kept, with nulls if it has no match.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AsofJoin {
+    // Upstream nodes.
+    pub left: Arc<LogicalPlan>,
+    pub right: Arc<LogicalPlan>,
+
+    pub left_by: Vec<ExprRef>,
+    pub right_by: Vec<ExprRef>,
+    pub left_on: ExprRef,
+    pub right_on: ExprRef,
+    pub direction: AsofJoinDirection,
+    /// The maximum distance between the `on` keys of matched rows, as a literal.
+    pub tolerance: Option<ExprRef>,
+    pub output_schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
+impl AsofJoin {
+    /// Create a new ASOF join node, checking the validity of the inputs and deriving the output schema.
+    ///
+    /// As with [`crate::ops::Join::try_new`], columns that have the same name between left and right are assumed to be merged.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn try_new(
+        left: Arc<LogicalPlan>,
+        right: Arc<LogicalPlan>,
+        left_by: Vec<ExprRef>,
+        right_by: Vec<ExprRef>,
+        left_on: ExprRef,
+        right_on: ExprRef,
+        direction: AsofJoinDirection,
+        tolerance: Option<ExprRef>,
+    ) -> logical_plan::Result<Self> {
+        if left_by.len() != right_by.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected length of left_by to match length of right_by for ASOF join, received: {} vs {}",
+                left_by.len(),
+                right_by.len()
+            )))
+            .context(CreationSnafu);
+        }
+
+        let left_schema = left.schema();
+        let right_schema = right.schema();
+        for (l, r) in left_by
+            .iter()
+            .chain(std::iter::once(&left_on))
+            .zip(right_by.iter().chain(std::iter::once(&right_on)))
+        {
+            let l_dtype = l.to_field(&left_schema)?.dtype;
+            let r_dtype = r.to_field(&right_schema)?.dtype;
+
+            try_get_supertype(&l_dtype, &r_dtype).map_err(|_| {
+                DaftError::TypeError(
+                    format!("Expected dtypes of left and right join keys for ASOF join to have a valid supertype, received: {l_dtype} vs {r_dtype}")
+                )
+            })?;
+        }
+
+        if let Some(tolerance) = &tolerance {
+            if tolerance.as_literal().is_none() {
+                return Err(DaftError::ValueError(format!(
+                    "Expected the tolerance of an ASOF join to be a literal, received: {tolerance}"
+                )))
+                .context(CreationSnafu);
+            }
+        }
+
+        let output_schema = infer_join_schema(&left_schema, &right_schema, JoinType::Left)?;
+
+        Ok(Self {
+            left,
+            right,
+            left_by,
+            right_by,
+            left_on,
+            right_on,
+            direction,
+            tolerance,
+            output_schema,
+            stats_state: StatsState::NotMaterialized,
+        })
+    }
+
+    pub(crate) fn with_materialized_stats(mut self) -> Self {
+        // Each left row is joined to at most one right row, so the output has as many rows as the left side.
+        let left_stats = self.left.materialized_stats();
+        let right_stats = self.right.materialized_stats();
+        let mut approx_stats = left_stats.approx_stats.clone();
+        if left_stats.approx_stats.num_rows > 0 {
+            let right_row_size = right_stats.approx_stats.size_bytes as f64
+                / right_stats.approx_stats.num_rows.max(1) as f64;
+            approx_stats.size_bytes +=
+                (right_row_size * left_stats.approx_stats.num_rows as f64).ceil() as usize;
+        }
+        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!("AsofJoin: Direction = {}", self.direction));
+        if !self.left_by.is_empty() {
+            res.push(format!(
+                "Left by = {}",
+                self.left_by.iter().map(|e| e.to_string()).join(", ")
+            ));
+            res.push(format!(
+                "Right by = {}",
+                self.right_by.iter().map(|e| e.to_string()).join(", ")
+            ));
+        }
+        res.push(format!("Left on = {}", self.left_on));
+        res.push(format!("Right on = {}", self.right_on));
+        if let Some(tolerance) = &self.tolerance {
+            res.push(format!("Tolerance = {tolerance}"));
+        }
+        res.push(format!(
+            "Output schema = {}",
+            self.output_schema.short_string()
+        ));
+        if let StatsState::Materialized(stats) = &self.stats_state {
+            res.push(format!("Stats = {}", stats));
+        }
+        res
+    }
+}
@@ -1,7 +1,11 @@
 use std::sync::Arc;
 
 use daft_dsl::{exprs_to_schema, ExprRef};
-use daft_schema::schema::{Schema, SchemaRef};
+use daft_schema::{
+    dtype::DataType,
+    field::Field,
+    schema::{Schema, SchemaRef},
+};
 use itertools::Itertools;
 
 use crate::{
@@ -16,6 +20,8 @@ pub struct Explode {
     pub input: Arc<LogicalPlan>,
     // Expressions to explode. e.g. col("a")
     pub to_explode: Vec<ExprRef>,
+    // Optional column that holds the position of each element within its list.
+    pub index_column: Option<String>,
     pub exploded_schema: SchemaRef,
     pub stats_state: StatsState,
 }
@@ -24,6 +30,7 @@ impl Explode {
     pub(crate) fn try_new(
         input: Arc<LogicalPlan>,
         to_explode: Vec<ExprRef>,
+        index_column: Option<String>,
     ) -> logical_plan::Result<Self> {
         let exploded_schema = {
             let explode_exprs = to_explode
@@ -34,19 +41,23 @@ impl Explode {
 
             let explode_schema = exprs_to_schema(&explode_exprs, input.schema())?;
 
-            let fields = input
+            let mut fields = input
                 .schema()
                 .fields
                 .iter()
                 .map(|(name, field)| explode_schema.fields.get(name).unwrap_or(field))
                 .cloned()
                 .collect::<Vec<_>>();
+            if let Some(index_column) = &index_column {
+                fields.push(Field::new(index_column.as_str(), DataType::Int64));
+            }
             Schema::new(fields)?.into()
         };
 
         Ok(Self {
             input,
             to_explode,
+            index_column,
             exploded_schema,
             stats_state: StatsState::NotMaterialized,
         })
@@ -76,6 +87,9 @@ impl Explode {
             "Explode: {}",
             self.to_explode.iter().map(|e| e.to_string()).join(", ")
         ));
+        if let Some(index_column) = &self.index_column {
+            res.push(format!("Index column = {}", index_column));
+        }
         res.push(format!("Schema = {}", self.exploded_schema.short_string()));
         if let StatsState::Materialized(stats) = &self.stats_state {
             res.push(format!("Stats = {}", stats));
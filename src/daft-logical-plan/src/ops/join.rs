@@ -4,10 +4,13 @@ use std::{
 };
 
 use common_error::{DaftError, DaftResult};
-use daft_core::{prelude::*, utils::supertype::try_get_supertype};
+use common_treenode::{Transformed, TreeNode};
+use daft_core::{join::JoinSide, prelude::*, utils::supertype::try_get_supertype};
 use daft_dsl::{
-    join::infer_join_schema, optimization::replace_columns_with_expressions, resolved_col, Column,
-    Expr, ExprRef, ResolvedColumn,
+    join::{infer_join_schema, validate_join_predicate},
+    join_side_col,
+    optimization::replace_columns_with_expressions,
+    resolved_col, Column, Expr, ExprRef, ResolvedColumn,
 };
 use indexmap::IndexSet;
 use itertools::Itertools;
@@ -31,6 +34,9 @@ pub struct Join {
     pub left_on: Vec<ExprRef>,
     pub right_on: Vec<ExprRef>,
     pub null_equals_nulls: Option<Vec<bool>>,
+    /// A condition that joined rows must satisfy in addition to the equality of the join keys,
+    /// e.g. a range condition. Its columns refer to either side of the join, see [`daft_dsl::join_side_col`].
+    pub predicate: Option<ExprRef>,
     pub join_type: JoinType,
     pub join_strategy: Option<JoinStrategy>,
     pub output_schema: SchemaRef,
@@ -42,12 +48,14 @@ impl Join {
     ///
     /// Columns that have the same name between left and right are assumed to be merged.
     /// If that is not the desired behavior, call `Join::deduplicate_join_keys` before initializing the join node.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn try_new(
         left: Arc<LogicalPlan>,
         right: Arc<LogicalPlan>,
         left_on: Vec<ExprRef>,
         right_on: Vec<ExprRef>,
         null_equals_nulls: Option<Vec<bool>>,
+        predicate: Option<ExprRef>,
         join_type: JoinType,
         join_strategy: Option<JoinStrategy>,
     ) -> logical_plan::Result<Self> {
@@ -82,6 +90,10 @@ impl Join {
             }
         }
 
+        if let Some(predicate) = &predicate {
+            validate_join_predicate(predicate, &left.schema(), &right.schema())?;
+        }
+
         let output_schema = infer_join_schema(&left.schema(), &right.schema(), join_type)?;
 
         Ok(Self {
@@ -90,6 +102,7 @@ impl Join {
             left_on,
             right_on,
             null_equals_nulls,
+            predicate,
             join_type,
             join_strategy,
             output_schema,
@@ -105,16 +118,25 @@ impl Join {
     /// - updated right
     /// - left_on (unchanged)
     /// - updated right_on
+    /// - updated predicate
+    #[allow(clippy::type_complexity)]
     pub(crate) fn deduplicate_join_columns(
         left: LogicalPlanRef,
         right: LogicalPlanRef,
         left_on: Vec<ExprRef>,
         right_on: Vec<ExprRef>,
+        predicate: Option<ExprRef>,
         join_type: JoinType,
         options: JoinOptions,
-    ) -> DaftResult<(LogicalPlanRef, LogicalPlanRef, Vec<ExprRef>, Vec<ExprRef>)> {
+    ) -> DaftResult<(
+        LogicalPlanRef,
+        LogicalPlanRef,
+        Vec<ExprRef>,
+        Vec<ExprRef>,
+        Option<ExprRef>,
+    )> {
         if matches!(join_type, JoinType::Anti | JoinType::Semi) {
-            Ok((left, right, left_on, right_on))
+            Ok((left, right, left_on, right_on, predicate))
         } else {
             let merged_cols = if options.merge_matching_join_keys {
                 left_on
@@ -171,7 +193,7 @@ impl Join {
                 .collect();
 
             if right_rename_mapping.is_empty() {
-                Ok((left, right, left_on, right_on))
+                Ok((left, right, left_on, right_on, predicate))
             } else {
                 // projection to update the right side with the new column names
                 let new_right_projection: Vec<_> = right_names
@@ -198,7 +220,26 @@ impl Join {
                     .map(|expr| replace_columns_with_expressions(expr, &right_on_replace_map))
                     .collect::<Vec<_>>();
 
-                Ok((left, new_right.into(), left_on, new_right_on))
+                // likewise for the references to the right side in the predicate
+                let new_predicate = predicate
+                    .map(|predicate| {
+                        predicate.transform(|e| match e.as_ref() {
+                            Expr::Column(Column::Resolved(ResolvedColumn::JoinSide(
+                                name,
+                                JoinSide::Right,
+                            ))) if let Some(new_name) = right_rename_mapping.get(name.as_ref()) => {
+                                Ok(Transformed::yes(join_side_col(
+                                    new_name.clone(),
+                                    JoinSide::Right,
+                                )))
+                            }
+                            _ => Ok(Transformed::no(e)),
+                        })
+                    })
+                    .transpose()?
+                    .map(|t| t.data);
+
+                Ok((left, new_right.into(), left_on, new_right_on, new_predicate))
             }
         }
     }
@@ -263,6 +304,9 @@ impl Join {
                 null_equals_nulls.iter().map(|b| b.to_string()).join(", ")
             ));
         }
+        if let Some(predicate) = &self.predicate {
+            res.push(format!("Predicate = {predicate}"));
+        }
         res.push(format!(
             "Output schema = {}",
             self.output_schema.short_string()
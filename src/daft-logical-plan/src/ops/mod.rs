@@ -1,5 +1,6 @@
 mod actor_pool_project;
 mod agg;
+mod asof_join;
 mod concat;
 mod distinct;
 mod explode;
@@ -9,6 +10,7 @@ mod limit;
 mod monotonically_increasing_id;
 mod pivot;
 mod project;
+mod recursive_union;
 mod repartition;
 mod sample;
 mod set_operations;
@@ -17,9 +19,11 @@ mod sort;
 mod source;
 mod summarize;
 mod unpivot;
+mod window;
 
 pub use actor_pool_project::ActorPoolProject;
 pub use agg::Aggregate;
+pub use asof_join::AsofJoin;
 pub use concat::Concat;
 pub use distinct::Distinct;
 pub use explode::Explode;
@@ -29,11 +33,13 @@ pub use limit::Limit;
 pub use monotonically_increasing_id::MonotonicallyIncreasingId;
 pub use pivot::Pivot;
 pub use project::Project;
+pub use recursive_union::RecursiveUnion;
 pub use repartition::Repartition;
 pub use sample::Sample;
 pub use set_operations::{Except, Intersect, Union};
 pub use sink::Sink;
 pub use sort::Sort;
 pub use source::Source;
-pub use summarize::summarize;
+pub use summarize::{summarize, summarize_statistics, SummaryStatistic};
 pub use unpivot::Unpivot;
+pub use window::Window;
@@ -219,9 +219,12 @@ fn replace_column_with_semantic_id(
         Transformed::yes(new_expr)
     } else {
         match e.as_ref() {
-            Expr::Column(_) | Expr::Literal(_) | Expr::Subquery(_) | Expr::Exists(_) => {
-                Transformed::no(e)
-            }
+            // Window functions are evaluated by a separate window node before any projection.
+            Expr::Column(_)
+            | Expr::Literal(_)
+            | Expr::Subquery(_)
+            | Expr::Exists(_)
+            | Expr::Over(..) => Transformed::no(e),
             Expr::Agg(agg_expr) => replace_column_with_semantic_id_aggexpr(
                 agg_expr.clone(),
                 subexprs_to_replace,
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use common_error::DaftError;
+use daft_dsl::resolved_col;
+use daft_schema::schema::SchemaRef;
+use snafu::ResultExt;
+
+use super::Project;
+use crate::{
+    logical_plan::{self, CreationSnafu},
+    stats::{PlanStats, StatsState},
+    LogicalPlan,
+};
+
+/// The union of an anchor term and a recursive term which is evaluated to a fixpoint, as in a
+/// `WITH RECURSIVE` CTE.
+///
+/// The recursive term reads the rows produced by its previous iteration through a `WorkTable`
+/// source with id `work_table_id`, and is re-evaluated until it produces no new rows.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecursiveUnion {
+    // Upstream nodes.
+    pub anchor: Arc<LogicalPlan>,
+    pub recursive: Arc<LogicalPlan>,
+    pub work_table_id: usize,
+    pub is_all: bool,
+    pub stats_state: StatsState,
+}
+
+impl RecursiveUnion {
+    pub(crate) fn try_new(
+        anchor: Arc<LogicalPlan>,
+        recursive: Arc<LogicalPlan>,
+        work_table_id: usize,
+        is_all: bool,
+    ) -> logical_plan::Result<Self> {
+        let anchor_schema = anchor.schema();
+        let recursive_schema = recursive.schema();
+        if anchor_schema.len() != recursive_schema.len() {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Both terms of a recursive union must have the same num of fields, \
+                but got[anchor: {} v.s recursive: {}], anchor schema: {}, recursive schema: {}",
+                anchor_schema.len(),
+                recursive_schema.len(),
+                anchor_schema,
+                recursive_schema
+            )))
+            .context(CreationSnafu);
+        }
+
+        // The output of the recursive term is fed back into itself, so it must have exactly the
+        // schema of the anchor term.
+        let recursive = if anchor_schema == recursive_schema {
+            recursive
+        } else {
+            let projection = anchor_schema
+                .fields
+                .values()
+                .zip(recursive_schema.fields.values())
+                .map(|(anchor_field, recursive_field)| {
+                    resolved_col(recursive_field.name.clone())
+                        .cast(&anchor_field.dtype)
+                        .alias(anchor_field.name.clone())
+                })
+                .collect();
+            Project::try_new(recursive, projection)?.into()
+        };
+
+        Ok(Self {
+            anchor,
+            recursive,
+            work_table_id,
+            is_all,
+            stats_state: StatsState::NotMaterialized,
+        })
+    }
+
+    pub fn schema(&self) -> SchemaRef {
+        self.anchor.schema()
+    }
+
+    pub(crate) fn with_materialized_stats(mut self) -> Self {
+        // We can't know how many iterations the recursive term will run for, so assume one.
+        let anchor_stats = self.anchor.materialized_stats();
+        let recursive_stats = self.recursive.materialized_stats();
+        let approx_stats = &anchor_stats.approx_stats + &recursive_stats.approx_stats;
+        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        if self.is_all {
+            res.push("Recursive Union All:".to_string());
+        } else {
+            res.push("Recursive Union:".to_string());
+        }
+        res.push(format!("Work table ID = {}", self.work_table_id));
+        if let StatsState::Materialized(stats) = &self.stats_state {
+            res.push(format!("Stats = {}", stats));
+        }
+        res
+    }
+}
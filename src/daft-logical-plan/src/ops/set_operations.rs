@@ -45,6 +45,7 @@ fn intersect_or_except_plan(
         left_on,
         right_on,
         Some(vec![true; left_on_size]),
+        None,
         join_type,
         None,
     );
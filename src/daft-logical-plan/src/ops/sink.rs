@@ -49,6 +49,7 @@ impl Sink {
                     }
                     CatalogType::DeltaLake(_) => vec![Field::new("add_action", DataType::Python)],
                     CatalogType::Lance(_) => vec![Field::new("fragments", DataType::Python)],
+                    CatalogType::Table(_) => vec![Field::new("rows", DataType::UInt64)],
                 }
             }
         };
@@ -90,6 +91,10 @@ impl Sink {
                     res.push(format!("Sink: Lance({})", lance_info.path));
                     res.extend(lance_info.multiline_display());
                 }
+                CatalogType::Table(table_info) => {
+                    res.push(format!("Sink: Table({})", table_info.table_name));
+                    res.extend(table_info.multiline_display());
+                }
             },
         }
         res.push(format!("Output schema = {}", self.schema.short_string()));
@@ -5,7 +5,7 @@ use common_scan_info::{PhysicalScanInfo, ScanState};
 use daft_schema::schema::SchemaRef;
 
 use crate::{
-    source_info::{InMemoryInfo, PlaceHolderInfo, SourceInfo},
+    source_info::{InMemoryInfo, PlaceHolderInfo, SourceInfo, WorkTableInfo},
     stats::{ApproxStats, PlanStats, StatsState},
 };
 
@@ -85,7 +85,7 @@ impl Source {
                     approx_stats
                 }
             },
-            SourceInfo::PlaceHolder(_) => ApproxStats::empty(),
+            SourceInfo::PlaceHolder(_) | SourceInfo::WorkTable(_) => ApproxStats::empty(),
         };
         self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
         self
@@ -124,6 +124,11 @@ impl Source {
                 res.push(format!("Source ID = {}", source_id));
                 res.extend(clustering_spec.multiline_display());
             }
+            SourceInfo::WorkTable(WorkTableInfo { name, id, .. }) => {
+                res.push("WorkTable:".to_string());
+                res.push(format!("Name = {}", name));
+                res.push(format!("Work table ID = {}", id));
+            }
         }
         res.push(format!(
             "Output schema = {}",
@@ -1,6 +1,9 @@
+use std::fmt::{Display, Formatter};
+
 use common_error::DaftResult;
 use daft_core::prelude::CountMode;
-use daft_dsl::{Expr, ExprRef, Literal};
+use daft_dsl::{lit, null_lit, Expr, ExprRef, Literal};
+use daft_functions::numeric::sqrt::sqrt;
 use daft_schema::dtype::DataType;
 
 use crate::LogicalPlanBuilder;
@@ -17,16 +20,18 @@ pub fn summarize(input: &LogicalPlanBuilder) -> DaftResult<LogicalPlanBuilder> {
     let mut unqs: Vec<ExprRef> = vec![]; // approx_distinct    :: int64
     for (_, field) in &input.schema().fields {
         let col = daft_dsl::resolved_col(field.name.as_str());
+        let stat = |statistic: SummaryStatistic| statistic.to_expr(col.clone(), &field.dtype);
         cols.push(field.name.to_string().lit());
         typs.push(field.dtype.to_string().lit());
-        mins.push(col.clone().min().cast(&DataType::Utf8));
-        maxs.push(col.clone().max().cast(&DataType::Utf8));
-        cnts.push(col.clone().count(CountMode::Valid));
+        mins.push(stat(SummaryStatistic::Min).cast(&DataType::Utf8));
+        maxs.push(stat(SummaryStatistic::Max).cast(&DataType::Utf8));
+        cnts.push(stat(SummaryStatistic::Count));
         nuls.push(col.clone().count(CountMode::Null));
-        unqs.push(col.clone().approx_count_distinct());
+        unqs.push(stat(SummaryStatistic::ApproxCountDistinct));
     }
     // apply aggregations lists
-    let input = input.aggregate(
+    explode_lists(
+        input,
         vec![
             list_(cols, "column"),
             list_(typs, "type"),
@@ -36,10 +41,100 @@ pub fn summarize(input: &LogicalPlanBuilder) -> DaftResult<LogicalPlanBuilder> {
             list_(nuls, "count_nulls"),
             list_(unqs, "approx_count_distinct"),
         ],
-        vec![],
-    )?;
-    // apply explode for all columns
-    input.explode(input.columns())
+    )
+}
+
+/// A statistic of a column computed by [`summarize_statistics`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SummaryStatistic {
+    Count,
+    CountDistinct,
+    ApproxCountDistinct,
+    Min,
+    Max,
+    Mean,
+    /// The sample standard deviation.
+    Stddev,
+    /// The approximate percentile, given as a percentage between 0 and 100.
+    Percentile(f64),
+}
+
+impl SummaryStatistic {
+    /// Creates the aggregation of this statistic, which is null if it is only defined for numeric
+    /// columns and the column is not numeric.
+    fn to_expr(self, col: ExprRef, dtype: &DataType) -> ExprRef {
+        let numeric = dtype.is_numeric();
+        match self {
+            Self::Count => col.count(CountMode::Valid),
+            Self::CountDistinct => col.count_distinct(),
+            Self::ApproxCountDistinct => col.approx_count_distinct(),
+            Self::Min => col.min(),
+            Self::Max => col.max(),
+            Self::Mean if numeric => col.mean(),
+            Self::Stddev if numeric => {
+                // daft computes the population standard deviation, so it is corrected to the
+                // sample standard deviation
+                let n = col.clone().count(CountMode::Valid).cast(&DataType::Float64);
+                col.stddev().mul(sqrt(n.clone().div(n.sub(lit(1.0)))))
+            }
+            Self::Percentile(percentage) if numeric => col
+                .cast(&DataType::Float64)
+                .approx_percentiles(&[percentage / 100.0], false),
+            Self::Mean | Self::Stddev | Self::Percentile(_) => null_lit(),
+        }
+    }
+}
+
+impl Display for SummaryStatistic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Count => write!(f, "count"),
+            Self::CountDistinct => write!(f, "count_distinct"),
+            Self::ApproxCountDistinct => write!(f, "approx_count_distinct"),
+            Self::Min => write!(f, "min"),
+            Self::Max => write!(f, "max"),
+            Self::Mean => write!(f, "mean"),
+            Self::Stddev => write!(f, "stddev"),
+            Self::Percentile(percentage) => write!(f, "{percentage}%"),
+        }
+    }
+}
+
+/// Computes the given statistics of each of the given columns.
+///
+/// Unlike [`summarize`], the result has a row per statistic: a `summary` column with the name of
+/// each statistic, followed by a column with the statistics of each input column as strings.
+pub fn summarize_statistics(
+    input: &LogicalPlanBuilder,
+    columns: &[String],
+    statistics: &[SummaryStatistic],
+) -> DaftResult<LogicalPlanBuilder> {
+    let schema = input.schema();
+
+    let names = statistics
+        .iter()
+        .map(|statistic| statistic.to_string().lit())
+        .collect();
+    let mut lists = vec![list_(names, "summary")];
+    for name in columns {
+        let dtype = &schema.get_field(name)?.dtype;
+        let col = daft_dsl::resolved_col(name.as_str());
+        let values = statistics
+            .iter()
+            .map(|statistic| statistic.to_expr(col.clone(), dtype).cast(&DataType::Utf8))
+            .collect();
+        lists.push(list_(values, name));
+    }
+    explode_lists(input, lists)
+}
+
+/// Aggregates the given lists into a single row, then explodes them into a row per item.
+fn explode_lists(
+    input: &LogicalPlanBuilder,
+    lists: Vec<ExprRef>,
+) -> DaftResult<LogicalPlanBuilder> {
+    let input = input.aggregate(lists, vec![])?;
+    input.explode(input.columns(), None)
 }
 
 /// Creates a list constructor for the given items.
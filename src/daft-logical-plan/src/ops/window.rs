@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use common_error::{DaftError, DaftResult};
+use common_treenode::{Transformed, TreeNode};
+use daft_core::prelude::*;
+use daft_dsl::{resolved_col, Expr, ExprRef};
+use indexmap::IndexMap;
+use itertools::Itertools;
+use snafu::ResultExt;
+
+use crate::{
+    logical_plan::{self, CreationSnafu},
+    stats::StatsState,
+    LogicalPlan,
+};
+
+/// Evaluates window functions over the input, appending one column per window function to the input columns.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Window {
+    // Upstream node.
+    pub input: Arc<LogicalPlan>,
+    /// Window function expressions, each of the form `Expr::Over(..)` with an optional alias.
+    pub window_functions: Vec<ExprRef>,
+    pub output_schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
+impl Window {
+    pub(crate) fn try_new(
+        input: Arc<LogicalPlan>,
+        window_functions: Vec<ExprRef>,
+    ) -> logical_plan::Result<Self> {
+        let input_schema = input.schema();
+        for expr in &window_functions {
+            let window_expr = match expr.as_ref() {
+                Expr::Alias(inner, _) => inner.as_ref(),
+                other => other,
+            };
+            if !matches!(window_expr, Expr::Over(..)) {
+                return Err(DaftError::ValueError(format!(
+                    "Expected a window function, but received {expr}"
+                )))
+                .context(CreationSnafu);
+            }
+        }
+
+        let fields = input_schema
+            .fields
+            .values()
+            .cloned()
+            .map(Ok)
+            .chain(window_functions.iter().map(|e| e.to_field(&input_schema)))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let output_schema = Schema::new(fields)?.into();
+
+        Ok(Self {
+            input,
+            window_functions,
+            output_schema,
+            stats_state: StatsState::NotMaterialized,
+        })
+    }
+
+    /// Moves the window functions in a projection into a window node below the projection.
+    ///
+    /// Returns the (maybe new) input of the projection, along with the projection in which
+    /// each window function is replaced by a reference to the corresponding output column of the window node.
+    pub(crate) fn extract_from_projection(
+        input: Arc<LogicalPlan>,
+        projection: Vec<ExprRef>,
+    ) -> logical_plan::Result<(Arc<LogicalPlan>, Vec<ExprRef>)> {
+        let input_schema = input.schema();
+        let mut window_functions = IndexMap::new();
+        let projection = projection
+            .into_iter()
+            .map(|e| {
+                let new_expr = e
+                    .clone()
+                    .transform_down(|expr| {
+                        if matches!(expr.as_ref(), Expr::Over(..)) {
+                            let id = expr.semantic_id(&input_schema).id;
+                            window_functions
+                                .entry(id.clone())
+                                .or_insert_with(|| expr.alias(id.clone()));
+                            Ok(Transformed::yes(resolved_col(id)))
+                        } else {
+                            Ok(Transformed::no(expr))
+                        }
+                    })?
+                    .data;
+                // Replacing a window function with a column can change the name of the expression, so re-alias it.
+                if new_expr.name() != e.name() {
+                    Ok(new_expr.alias(e.name()))
+                } else {
+                    Ok(new_expr)
+                }
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        if window_functions.is_empty() {
+            return Ok((input, projection));
+        }
+        let window: LogicalPlan =
+            Self::try_new(input, window_functions.into_values().collect())?.into();
+        Ok((window.into(), projection))
+    }
+
+    pub(crate) fn with_materialized_stats(mut self) -> Self {
+        // Window functions do not change the number of rows.
+        let input_stats = self.input.materialized_stats();
+        self.stats_state = StatsState::Materialized(input_stats.clone().into());
+        self
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![format!(
+            "Window: {}",
+            self.window_functions
+                .iter()
+                .map(|e| e.to_string())
+                .join(", ")
+        )];
+        res.push(format!(
+            "Output schema = {}",
+            self.output_schema.short_string()
+        ));
+        if let StatsState::Materialized(stats) = &self.stats_state {
+            res.push(format!("Stats = {}", stats));
+        }
+        res
+    }
+}
@@ -43,6 +43,7 @@ impl OptimizerRule for EliminateCrossJoin {
                     join_strategy: None,
                     // TODO: consider support eliminate cross join with null_equals_nulls
                     null_equals_nulls: None,
+                    predicate: None,
                     ..
                 })
             );
@@ -69,6 +70,7 @@ impl OptimizerRule for EliminateCrossJoin {
                 join_strategy: None,
                 // TODO: consider support eliminate cross join with null_equals_nulls
                 null_equals_nulls: None,
+                predicate: None,
                 ..
             })
         ) {
@@ -137,6 +139,7 @@ fn flatten_join_inputs(
         join @ Join {
             join_type: JoinType::Inner,
             join_strategy: None,
+            predicate: None,
             ..
         },
     ) = plan
@@ -167,7 +170,8 @@ fn flatten_join_inputs(
 fn can_flatten_join_inputs(plan: &LogicalPlan) -> bool {
     // can only flatten inner / cross joins
     match plan {
-        LogicalPlan::Join(join) if join.join_type == JoinType::Inner => {}
+        LogicalPlan::Join(join)
+            if join.join_type == JoinType::Inner && join.predicate.is_none() => {}
         _ => return false,
     };
 
@@ -177,6 +181,7 @@ fn can_flatten_join_inputs(plan: &LogicalPlan) -> bool {
             LogicalPlan::Join(Join {
                 join_strategy: None,
                 join_type: JoinType::Inner,
+                predicate: None,
                 ..
             })
         ) && !can_flatten_join_inputs(child)
@@ -312,6 +317,7 @@ fn find_inner_join(
                 left_keys,
                 right_keys,
                 None,
+                None,
                 JoinType::Inner,
                 None,
             )?)
@@ -329,6 +335,7 @@ fn find_inner_join(
         vec![],
         vec![],
         None,
+        None,
         JoinType::Inner,
         None,
     )?)
@@ -28,4 +28,5 @@ pub use reorder_joins::ReorderJoins;
 pub use rule::OptimizerRule;
 pub use simplify_expressions::SimplifyExpressionsRule;
 pub use split_actor_pool_projects::SplitActorPoolProjects;
+pub(crate) use unnest_subquery::pull_up_correlated_cols;
 pub use unnest_subquery::{UnnestPredicateSubquery, UnnestScalarSubquery};
@@ -58,7 +58,9 @@ impl PushDownLimit {
                     LogicalPlan::Source(source) => {
                         match source.source_info.as_ref() {
                             // Limit pushdown is not supported for in-memory sources.
-                            SourceInfo::InMemory(_) => Ok(Transformed::no(plan)),
+                            SourceInfo::InMemory(_) | SourceInfo::WorkTable(_) => {
+                                Ok(Transformed::no(plan))
+                            }
                             // Do not pushdown if Source node is already more limited than `limit`
                             SourceInfo::Physical(external_info)
                                 if let Some(existing_limit) = external_info.pushdowns.limit
@@ -13,7 +13,7 @@ use itertools::Itertools;
 
 use super::OptimizerRule;
 use crate::{
-    ops::{ActorPoolProject, Aggregate, Join, Pivot, Project, Source},
+    ops::{ActorPoolProject, Aggregate, AsofJoin, Join, Pivot, Project, Source},
     source_info::SourceInfo,
     LogicalPlan, LogicalPlanRef,
 };
@@ -173,7 +173,7 @@ impl PushDownProjection {
                             Ok(Transformed::no(plan))
                         }
                     }
-                    SourceInfo::InMemory(_) => Ok(Transformed::no(plan)),
+                    SourceInfo::InMemory(_) | SourceInfo::WorkTable(_) => Ok(Transformed::no(plan)),
                     SourceInfo::PlaceHolder(..) => {
                         panic!("PlaceHolderInfo should not exist for optimization!");
                     }
@@ -350,18 +350,21 @@ impl PushDownProjection {
             | LogicalPlan::Filter(..)
             | LogicalPlan::Sample(..)
             | LogicalPlan::Explode(..) => {
-                // Get required columns from projection and upstream.
+                // Get required columns from projection and upstream. The index column of an
+                // explode is created by the explode, so it is not required from its input.
+                let grand_upstream_plan = &upstream_plan.arc_children()[0];
+                let grand_upstream_schema = grand_upstream_plan.schema();
                 let combined_dependencies = plan
                     .required_columns()
                     .iter()
                     .flatten()
                     .chain(upstream_plan.required_columns().iter().flatten())
+                    .filter(|name| grand_upstream_schema.has_field(name))
                     .cloned()
                     .collect::<IndexSet<_>>();
 
                 // Skip optimization if no columns would be pruned.
-                let grand_upstream_plan = &upstream_plan.arc_children()[0];
-                let grand_upstream_columns = grand_upstream_plan.schema().names();
+                let grand_upstream_columns = grand_upstream_schema.names();
 
                 if grand_upstream_columns.len() == combined_dependencies.len() {
                     return Ok(Transformed::no(plan));
@@ -421,6 +424,39 @@ impl PushDownProjection {
                     .or(Transformed::yes(new_plan));
                 Ok(new_plan)
             }
+            LogicalPlan::Window(_) => {
+                // Only the input columns that are needed by the projection or by the window functions
+                // themselves need to be passed into the window.
+                let combined_dependencies = plan
+                    .required_columns()
+                    .iter()
+                    .flatten()
+                    .chain(upstream_plan.required_columns().iter().flatten())
+                    .cloned()
+                    .collect::<IndexSet<_>>();
+
+                let grand_upstream_plan = &upstream_plan.arc_children()[0];
+                let grand_upstream_columns = grand_upstream_plan.schema().names();
+                let can_be_pushed_down = grand_upstream_columns
+                    .iter()
+                    .filter(|name| combined_dependencies.contains(name.as_str()))
+                    .map(|name| resolved_col(name.as_str()))
+                    .collect::<Vec<_>>();
+
+                if grand_upstream_columns.len() == can_be_pushed_down.len() {
+                    return Ok(Transformed::no(plan));
+                }
+
+                let new_subprojection: LogicalPlan =
+                    Project::try_new(grand_upstream_plan.clone(), can_be_pushed_down)?.into();
+                let new_upstream = upstream_plan.with_new_children(&[new_subprojection.into()]);
+                let new_plan = Arc::new(plan.with_new_children(&[new_upstream.into()]));
+                // Retry optimization now that the upstream node is different.
+                let new_plan = self
+                    .try_optimize_node(new_plan.clone())?
+                    .or(Transformed::yes(new_plan));
+                Ok(new_plan)
+            }
             LogicalPlan::Concat(concat) => {
                 // Get required columns from projection and upstream.
                 let combined_dependencies = plan
@@ -460,7 +496,8 @@ impl PushDownProjection {
                 Ok(new_plan)
             }
             LogicalPlan::Union(_) => unreachable!("Union should have been optimized away"),
-            LogicalPlan::Join(join) => {
+            LogicalPlan::Join(Join { left, right, .. })
+            | LogicalPlan::AsofJoin(AsofJoin { left, right, .. }) => {
                 // Get required columns from projection and both upstreams.
                 let [projection_dependencies] = &plan.required_columns()[..] else {
                     panic!()
@@ -502,13 +539,10 @@ impl PushDownProjection {
                     }
                 }
 
-                let new_left_upstream = maybe_project_upstream_input(
-                    &join.left,
-                    left_dependencies,
-                    projection_dependencies,
-                )?;
+                let new_left_upstream =
+                    maybe_project_upstream_input(left, left_dependencies, projection_dependencies)?;
                 let new_right_upstream = maybe_project_upstream_input(
-                    &join.right,
+                    right,
                     right_dependencies,
                     projection_dependencies,
                 )?;
@@ -540,6 +574,11 @@ impl PushDownProjection {
                 // since Intersect implicitly requires all parent columns.
                 Ok(Transformed::no(plan))
             }
+            LogicalPlan::RecursiveUnion(_) => {
+                // Cannot push down past a RecursiveUnion,
+                // since its output is fed back into the recursive term.
+                Ok(Transformed::no(plan))
+            }
             LogicalPlan::Pivot(_) | LogicalPlan::MonotonicallyIncreasingId(_) => {
                 // Cannot push down past a Pivot/MonotonicallyIncreasingId because it changes the schema.
                 Ok(Transformed::no(plan))
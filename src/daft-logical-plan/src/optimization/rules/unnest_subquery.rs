@@ -1,18 +1,24 @@
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use common_error::{DaftError, DaftResult};
 use common_treenode::{DynTreeNode, Transformed, TreeNode};
 use daft_algebra::boolean::{combine_conjunction, split_conjunction};
 use daft_core::{join::JoinType, prelude::SchemaRef};
-use daft_dsl::{resolved_col, Column, Expr, ExprRef, Operator, ResolvedColumn, Subquery};
+use daft_dsl::{
+    lit, optimization::replace_columns_with_expressions, resolved_col, row_number, Column, Expr,
+    ExprRef, Operator, ResolvedColumn, Subquery, WindowSpec,
+};
 use itertools::multiunzip;
 use uuid::Uuid;
 
 use super::OptimizerRule;
 use crate::{
     logical_plan::downcast_subquery,
-    ops::{Aggregate, Filter, Join, Project},
-    LogicalPlan, LogicalPlanRef,
+    ops::{Aggregate, Filter, Join, Limit, Project, Sort},
+    LogicalPlan, LogicalPlanBuilder, LogicalPlanRef,
 };
 
 /// Rewriter rule to convert scalar subqueries into joins.
@@ -126,12 +132,13 @@ impl UnnestScalarSubquery {
                     JoinType::Left
                 };
 
-                let (curr_input, decorrelated_subquery, input_on, subquery_on) =
+                let (curr_input, decorrelated_subquery, input_on, subquery_on, _) =
                     Join::deduplicate_join_columns(
                         curr_input,
                         decorrelated_subquery,
                         input_on,
                         subquery_on,
+                        None,
                         join_type,
                         Default::default(),
                     )?;
@@ -142,6 +149,7 @@ impl UnnestScalarSubquery {
                     input_on,
                     subquery_on,
                     None,
+                    None,
                     join_type,
                     None,
                 )?)))
@@ -330,6 +338,7 @@ impl OptimizerRule for UnnestPredicateSubquery {
                         input_on,
                         subquery_on,
                         None,
+                        None,
                         join_type,
                         None,
                     )?)))
@@ -352,7 +361,7 @@ impl OptimizerRule for UnnestPredicateSubquery {
     }
 }
 
-fn pull_up_correlated_cols(
+pub(crate) fn pull_up_correlated_cols(
     plan: LogicalPlanRef,
 ) -> DaftResult<(LogicalPlanRef, Vec<ExprRef>, Vec<ExprRef>)> {
     let (new_inputs, subquery_on, outer_on): (Vec<_>, Vec<_>, Vec<_>) = multiunzip(
@@ -488,6 +497,90 @@ fn pull_up_correlated_cols(
             }
         }
 
+        LogicalPlan::Limit(Limit { input, limit, .. }) if !subquery_on.is_empty() => {
+            // a correlated limit applies to the rows of each outer row, so it becomes a filter on the
+            // row number within each group of correlated columns, ordered by the sort below it if any.
+            // the row number is computed below the projections between the limit and the sort,
+            // so the correlated columns are rewritten in terms of the input of those projections.
+
+            let mut projects = Vec::new();
+            let mut partition_by = subquery_on.clone();
+            let mut node = input.clone();
+            while let LogicalPlan::Project(Project {
+                input, projection, ..
+            }) = node.as_ref()
+            {
+                let projection_mapping = projection
+                    .iter()
+                    .map(|e| {
+                        let expr = match e.as_ref() {
+                            Expr::Alias(expr, _) => expr.clone(),
+                            _ => e.clone(),
+                        };
+                        (e.name().to_string(), expr)
+                    })
+                    .collect::<HashMap<_, _>>();
+                partition_by = partition_by
+                    .into_iter()
+                    .map(|e| replace_columns_with_expressions(e, &projection_mapping))
+                    .collect();
+
+                projects.push(node.clone());
+                node = input.clone();
+            }
+
+            let (input, order_by, descending, nulls_first) = match node.as_ref() {
+                LogicalPlan::Sort(Sort {
+                    input,
+                    sort_by,
+                    descending,
+                    nulls_first,
+                    ..
+                }) => (
+                    input.clone(),
+                    sort_by.clone(),
+                    descending.clone(),
+                    nulls_first.clone(),
+                ),
+                _ => (
+                    node.clone(),
+                    partition_by.clone(),
+                    vec![false; partition_by.len()],
+                    vec![false; partition_by.len()],
+                ),
+            };
+
+            let row_number_col = format!("row_number-{}", Uuid::new_v4());
+            let window_spec = WindowSpec {
+                partition_by,
+                order_by,
+                descending,
+                nulls_first,
+                ..Default::default()
+            };
+
+            let filtered = LogicalPlanBuilder::from(input.clone())
+                .with_columns(vec![row_number()
+                    .over(window_spec)?
+                    .alias(row_number_col.as_str())])?
+                .filter(resolved_col(row_number_col.as_str()).lt_eq(lit(*limit)))?
+                .select(
+                    input
+                        .schema()
+                        .names()
+                        .into_iter()
+                        .map(resolved_col)
+                        .collect(),
+                )?
+                .build();
+
+            let new_plan = projects.into_iter().rev().fold(filtered, |child, project| {
+                Arc::new(project.with_new_children(&[child]))
+            });
+
+            Ok((new_plan, subquery_on, outer_on))
+        }
+
         // ops that can trivially pull up correlated cols
         LogicalPlan::Distinct(..)
         | LogicalPlan::MonotonicallyIncreasingId(..)
@@ -505,8 +598,11 @@ fn pull_up_correlated_cols(
         | LogicalPlan::Explode(..)
         | LogicalPlan::Unpivot(..)
         | LogicalPlan::Pivot(..)
+        | LogicalPlan::Window(..)
         | LogicalPlan::Concat(..)
+        | LogicalPlan::RecursiveUnion(..)
         | LogicalPlan::Join(..)
+        | LogicalPlan::AsofJoin(..)
         | LogicalPlan::Sink(..) => {
             if subquery_on.is_empty() {
                 Ok((plan.clone(), vec![], vec![]))
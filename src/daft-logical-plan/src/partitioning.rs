@@ -328,8 +328,8 @@ fn translate_clustering_spec_expr(
 
             Ok(expr.in_subquery(subquery.clone()))
         }
-        // Cannot have agg exprs or references to other tables in clustering specs.
-        Expr::Agg(_) | Expr::Column(..) => Err(()),
+        // Cannot have agg exprs, window functions or references to other tables in clustering specs.
+        Expr::Agg(_) | Expr::Over(..) | Expr::Column(..) => Err(()),
     }
 }
 
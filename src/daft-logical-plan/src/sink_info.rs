@@ -1,6 +1,6 @@
 use std::{hash::Hash, sync::Arc};
 
-use common_file_formats::FileFormat;
+use common_file_formats::{CsvWriteOptions, FileFormat};
 use common_io_config::IOConfig;
 #[cfg(feature = "python")]
 use common_py_serde::{deserialize_py_object, serialize_py_object};
@@ -26,6 +26,7 @@ pub struct OutputFileInfo {
     pub partition_cols: Option<Vec<ExprRef>>,
     pub compression: Option<String>,
     pub io_config: Option<IOConfig>,
+    pub csv_options: Option<CsvWriteOptions>,
 }
 
 #[cfg(feature = "python")]
@@ -41,6 +42,7 @@ pub enum CatalogType {
     Iceberg(IcebergCatalogInfo),
     DeltaLake(DeltaLakeCatalogInfo),
     Lance(LanceCatalogInfo),
+    Table(TableCatalogInfo),
 }
 
 #[cfg(feature = "python")]
@@ -145,6 +147,32 @@ impl LanceCatalogInfo {
     }
 }
 
+/// A table of a daft catalog, which is written through its `daft.catalog.Table` python object.
+#[cfg(feature = "python")]
+#[derive(Derivative, Debug, Clone, Serialize, Deserialize)]
+#[derivative(PartialEq, Eq, Hash)]
+pub struct TableCatalogInfo {
+    pub table_name: String,
+    pub mode: String,
+    #[serde(
+        serialize_with = "serialize_py_object",
+        deserialize_with = "deserialize_py_object"
+    )]
+    #[derivative(PartialEq = "ignore")]
+    #[derivative(Hash = "ignore")]
+    pub table: Arc<PyObject>,
+}
+
+#[cfg(feature = "python")]
+impl TableCatalogInfo {
+    pub fn multiline_display(&self) -> Vec<String> {
+        vec![
+            format!("Table Name = {}", self.table_name),
+            format!("Mode = {}", self.mode),
+        ]
+    }
+}
+
 impl OutputFileInfo {
     pub fn new(
         root_dir: String,
@@ -152,6 +180,7 @@ impl OutputFileInfo {
         partition_cols: Option<Vec<ExprRef>>,
         compression: Option<String>,
         io_config: Option<IOConfig>,
+        csv_options: Option<CsvWriteOptions>,
     ) -> Self {
         Self {
             root_dir,
@@ -159,6 +188,7 @@ impl OutputFileInfo {
             partition_cols,
             compression,
             io_config,
+            csv_options,
         }
     }
 
@@ -173,6 +203,9 @@ impl OutputFileInfo {
         if let Some(ref compression) = self.compression {
             res.push(format!("Compression = {}", compression));
         }
+        if let Some(ref csv_options) = self.csv_options {
+            res.extend(csv_options.multiline_display());
+        }
         res.push(format!("Root dir = {}", self.root_dir));
         match &self.io_config {
             None => res.push("IOConfig = None".to_string()),
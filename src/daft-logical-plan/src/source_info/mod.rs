@@ -17,6 +17,7 @@ pub enum SourceInfo {
     InMemory(InMemoryInfo),
     Physical(PhysicalScanInfo),
     PlaceHolder(PlaceHolderInfo),
+    WorkTable(WorkTableInfo),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,3 +85,24 @@ impl PlaceHolderInfo {
         }
     }
 }
+
+static WORK_TABLE_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// The working table of a recursive union, i.e. the rows produced by the previous iteration of
+/// its recursive term.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WorkTableInfo {
+    pub source_schema: SchemaRef,
+    pub name: String,
+    pub id: usize,
+}
+
+impl WorkTableInfo {
+    pub fn new(source_schema: SchemaRef, name: String) -> Self {
+        Self {
+            source_schema,
+            name,
+            id: WORK_TABLE_ID_COUNTER.fetch_add(1, std::sync::atomic::Ordering::SeqCst),
+        }
+    }
+}
@@ -138,6 +138,7 @@ impl LogicalPlan {
             Self::Explode(Explode {
                 input,
                 to_explode,
+                index_column,
                 exploded_schema,
                 stats_state,
             }) => to_explode
@@ -148,6 +149,7 @@ impl LogicalPlan {
                     Self::Explode(Explode {
                         input: input.clone(),
                         to_explode: new_to_explode,
+                        index_column: index_column.clone(),
                         exploded_schema: exploded_schema.clone(),
                         stats_state: stats_state.clone(),
                     })
@@ -159,6 +161,7 @@ impl LogicalPlan {
                 left_on,
                 right_on,
                 null_equals_nulls,
+                predicate,
                 join_type,
                 join_strategy,
                 output_schema,
@@ -181,6 +184,7 @@ impl LogicalPlan {
                             left_on: new_left_on.data,
                             right_on: new_right_on.data,
                             null_equals_nulls: null_equals_nulls.clone(),
+                            predicate: predicate.clone(),
                             join_type: *join_type,
                             join_strategy: *join_strategy,
                             output_schema: output_schema.clone(),
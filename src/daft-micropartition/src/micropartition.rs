@@ -269,6 +269,45 @@ fn materialize_scan_task(
             )
             .context(DaftCoreComputeSnafu)?
         }
+
+        // ****************
+        // Native ORC Reads
+        // ****************
+        FileFormatConfig::Orc(cfg) => {
+            let uris = urls.collect::<Vec<_>>();
+            let stripes = orc_sources_to_stripes(scan_task.sources.as_slice());
+            daft_orc::read_orc_bulk(
+                uris.as_slice(),
+                file_column_names.as_deref(),
+                scan_task.pushdowns.limit,
+                stripes,
+                scan_task.pushdowns.filters.clone(),
+                io_client,
+                io_stats,
+                8,
+                multithreaded_io,
+                cfg.chunk_size,
+            )
+            .context(DaftCoreComputeSnafu)?
+        }
+
+        // **********************
+        // Native Arrow IPC Reads
+        // **********************
+        FileFormatConfig::Ipc(_) => {
+            let uris = urls.collect::<Vec<_>>();
+            daft_ipc::read_ipc_bulk(
+                uris.as_slice(),
+                file_column_names.as_deref(),
+                scan_task.pushdowns.limit,
+                scan_task.pushdowns.filters.clone(),
+                io_client,
+                io_stats,
+                8,
+                multithreaded_io,
+            )
+            .context(DaftCoreComputeSnafu)?
+        }
         #[cfg(feature = "python")]
         FileFormatConfig::Database(DatabaseSourceConfig { sql, conn }) => {
             let predicate = scan_task
@@ -669,6 +708,24 @@ fn parquet_sources_to_row_groups(sources: &[DataSource]) -> Option<Vec<Option<Ve
     }
 }
 
+fn orc_sources_to_stripes(sources: &[DataSource]) -> Option<Vec<Option<Vec<i64>>>> {
+    let stripes = sources
+        .iter()
+        .map(|s| {
+            if let Some(ChunkSpec::Orc(stripes)) = s.get_chunk_spec() {
+                Some(stripes.clone())
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    if stripes.iter().any(std::option::Option::is_some) {
+        Some(stripes)
+    } else {
+        None
+    }
+}
+
 pub fn read_csv_into_micropartition(
     uris: &[&str],
     convert_options: Option<CsvConvertOptions>,
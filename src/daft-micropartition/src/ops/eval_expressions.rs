@@ -1,7 +1,7 @@
 use std::{collections::HashSet, sync::Arc};
 
 use common_error::{DaftError, DaftResult};
-use daft_core::prelude::Schema;
+use daft_core::prelude::{DataType, Field, Schema};
 use daft_dsl::ExprRef;
 use daft_io::IOStatsContext;
 use daft_stats::{ColumnRangeStatistics, TableStatistics};
@@ -84,15 +84,21 @@ impl MicroPartition {
         ))
     }
 
-    pub fn explode(&self, exprs: &[ExprRef]) -> DaftResult<Self> {
+    pub fn explode(&self, exprs: &[ExprRef], index_column: Option<&str>) -> DaftResult<Self> {
         let io_stats = IOStatsContext::new("MicroPartition::explode");
 
         let tables = self.tables_or_read(io_stats)?;
         let evaluated_tables = tables
             .iter()
-            .map(|t| t.explode(exprs))
+            .map(|t| t.explode(exprs, index_column))
             .collect::<DaftResult<Vec<_>>>()?;
-        let expected_new_columns = infer_schema(exprs, &self.schema)?;
+        let mut expected_new_columns = infer_schema(exprs, &self.schema)?;
+        if let Some(index_column) = index_column {
+            expected_new_columns.fields.insert(
+                index_column.to_string(),
+                Field::new(index_column, DataType::Int64),
+            );
+        }
         let eval_stats = if let Some(stats) = &self.statistics {
             let mut new_stats = stats.columns.clone();
             for (name, _) in &expected_new_columns.fields {
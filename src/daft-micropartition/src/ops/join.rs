@@ -3,7 +3,7 @@ use std::sync::Arc;
 use common_error::DaftResult;
 use daft_core::{
     array::ops::DaftCompare,
-    join::{JoinSide, JoinType},
+    join::{AsofJoinDirection, JoinSide, JoinType},
 };
 use daft_dsl::{join::infer_join_schema, ExprRef};
 use daft_io::IOStatsContext;
@@ -137,4 +137,51 @@ impl MicroPartition {
 
         self.join(right, io_stats, &[], &[], JoinType::Inner, table_join)
     }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn asof_join(
+        &self,
+        right: &Self,
+        left_by: &[ExprRef],
+        right_by: &[ExprRef],
+        left_on: &ExprRef,
+        right_on: &ExprRef,
+        direction: AsofJoinDirection,
+        tolerance: Option<&ExprRef>,
+        right_sorted: bool,
+    ) -> DaftResult<Self> {
+        let io_stats = IOStatsContext::new("MicroPartition::asof_join");
+        let join_schema = infer_join_schema(&self.schema, &right.schema, JoinType::Left)?;
+
+        // Every left row is kept, so unlike the other joins, an empty right side still produces output.
+        let lt = self.concat_or_get(io_stats.clone())?;
+        let rt = right.concat_or_get(io_stats)?;
+        let rt = match rt.as_slice() {
+            [] => RecordBatch::empty(Some(right.schema.clone()))?,
+            [rt] => rt.clone(),
+            _ => unreachable!(),
+        };
+
+        match lt.as_slice() {
+            [] => Ok(Self::empty(Some(join_schema))),
+            [lt] => {
+                let joined_table = lt.asof_join(
+                    &rt,
+                    left_by,
+                    right_by,
+                    left_on,
+                    right_on,
+                    direction,
+                    tolerance,
+                    right_sorted,
+                )?;
+                Ok(Self::new_loaded(
+                    join_schema,
+                    vec![joined_table].into(),
+                    None,
+                ))
+            }
+            _ => unreachable!(),
+        }
+    }
 }
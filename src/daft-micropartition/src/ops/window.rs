@@ -0,0 +1,33 @@
+use common_error::{DaftError, DaftResult};
+use daft_dsl::ExprRef;
+use daft_io::IOStatsContext;
+use daft_recordbatch::RecordBatch;
+
+use crate::micropartition::MicroPartition;
+
+impl MicroPartition {
+    pub fn window(&self, window_functions: &[ExprRef]) -> DaftResult<Self> {
+        let io_stats = IOStatsContext::new("MicroPartition::window");
+
+        let tables = self.concat_or_get(io_stats)?;
+
+        match tables.as_slice() {
+            [] => {
+                let empty_table = RecordBatch::empty(Some(self.schema.clone()))?;
+                let windowed = empty_table.window(window_functions)?;
+                Ok(Self::empty(Some(windowed.schema)))
+            }
+            [t] => {
+                let windowed = t.window(window_functions)?;
+                Ok(Self::new_loaded(
+                    windowed.schema.clone(),
+                    vec![windowed].into(),
+                    None,
+                ))
+            }
+            _ => Err(DaftError::ComputeError(
+                "Window operation is not supported on multiple tables".to_string(),
+            )),
+        }
+    }
+}
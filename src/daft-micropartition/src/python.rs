@@ -336,11 +336,22 @@ impl PyMicroPartition {
         py.allow_threads(|| Ok(self.inner.cross_join(&right.inner, outer_loop_side)?.into()))
     }
 
-    pub fn explode(&self, py: Python, to_explode: Vec<PyExpr>) -> PyResult<Self> {
+    #[pyo3(signature = (to_explode, index_column=None))]
+    pub fn explode(
+        &self,
+        py: Python,
+        to_explode: Vec<PyExpr>,
+        index_column: Option<&str>,
+    ) -> PyResult<Self> {
         let converted_to_explode: Vec<daft_dsl::ExprRef> =
             to_explode.into_iter().map(|e| e.expr).collect();
 
-        py.allow_threads(|| Ok(self.inner.explode(converted_to_explode.as_slice())?.into()))
+        py.allow_threads(|| {
+            Ok(self
+                .inner
+                .explode(converted_to_explode.as_slice(), index_column)?
+                .into())
+        })
     }
 
     pub fn unpivot(
@@ -0,0 +1,84 @@
+//! Conversions from the arrow-rs types produced by orc-rust to arrow2, through the Arrow C data interface.
+//!
+//! orc-rust builds against a different arrow-rs release than arrow2's own interop, so the arrays are handed over
+//! as C structs rather than as `ArrayData`.
+
+use arrow_array::{
+    ffi::{to_ffi, FFI_ArrowArray},
+    Array,
+};
+use arrow_schema::{ffi::FFI_ArrowSchema, ArrowError, Field};
+
+/// Converts an arrow-rs field to an arrow2 field.
+pub(crate) fn field_to_arrow2(field: &Field) -> Result<arrow2::datatypes::Field, ArrowError> {
+    let schema = FFI_ArrowSchema::try_from(field)?;
+    // SAFETY: both structs are the `ArrowSchema` of the C data interface, and `schema` is moved into the arrow2
+    // struct, which releases it on drop.
+    let schema: arrow2::ffi::ArrowSchema = unsafe { std::mem::transmute(schema) };
+    unsafe { arrow2::ffi::import_field_from_c(&schema) }
+        .map_err(|err| ArrowError::CDataInterface(err.to_string()))
+}
+
+/// Converts an arrow-rs array to an arrow2 array without copying its buffers.
+pub(crate) fn array_to_arrow2(
+    array: &dyn Array,
+) -> Result<Box<dyn arrow2::array::Array>, ArrowError> {
+    let (array, schema) = to_ffi(&array.to_data())?;
+    // SAFETY: both pairs of structs are the `ArrowArray` and `ArrowSchema` of the C data interface, exported by
+    // arrow-rs just above, and ownership of both is moved into the arrow2 structs, which release them on drop.
+    let (array, schema) = unsafe {
+        (
+            std::mem::transmute::<FFI_ArrowArray, arrow2::ffi::ArrowArray>(array),
+            std::mem::transmute::<FFI_ArrowSchema, arrow2::ffi::ArrowSchema>(schema),
+        )
+    };
+    unsafe {
+        arrow2::ffi::import_field_from_c(&schema)
+            .and_then(|field| arrow2::ffi::import_array_from_c(array, field.data_type))
+    }
+    .map_err(|err| ArrowError::CDataInterface(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{Int64Array, StringArray};
+    use arrow_schema::{DataType, Field};
+
+    use super::{array_to_arrow2, field_to_arrow2};
+
+    #[test]
+    fn test_array_to_arrow2() {
+        let ints = Int64Array::from(vec![Some(1), None, Some(3)]);
+        let converted = array_to_arrow2(&ints).unwrap();
+        let expected = arrow2::array::Int64Array::from(vec![Some(1), None, Some(3)]);
+        assert_eq!(converted.as_ref(), &expected as &dyn arrow2::array::Array);
+
+        // sliced arrays keep their offset across the boundary
+        let strings = StringArray::from(vec!["a", "bc", "def"]).slice(1, 2);
+        let converted = array_to_arrow2(&strings).unwrap();
+        let expected = arrow2::array::Utf8Array::<i32>::from_slice(["bc", "def"]);
+        assert_eq!(converted.as_ref(), &expected as &dyn arrow2::array::Array);
+    }
+
+    #[test]
+    fn test_field_to_arrow2() {
+        let field = Field::new(
+            "xs",
+            DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+            false,
+        );
+        let converted = field_to_arrow2(&field).unwrap();
+        assert_eq!(converted.name, "xs");
+        assert!(!converted.is_nullable);
+        assert_eq!(
+            converted.data_type,
+            arrow2::datatypes::DataType::List(Box::new(arrow2::datatypes::Field::new(
+                "item",
+                arrow2::datatypes::DataType::Utf8,
+                true
+            )))
+        );
+    }
+}
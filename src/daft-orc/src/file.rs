@@ -0,0 +1,87 @@
+use std::sync::{Arc, Mutex};
+
+use bytes::Bytes;
+use daft_io::{IOClient, IOStatsRef};
+use futures::{future::BoxFuture, FutureExt};
+use orc_rust::reader::AsyncChunkReader;
+
+/// The size of a file and the bytes fetched from its tail, which hold the footer.
+#[derive(Default)]
+struct FileTail {
+    size: Option<u64>,
+    tail: Option<(u64, Bytes)>,
+}
+
+/// Reads byte ranges of an ORC file through Daft's IO layer, so that only the footer and the
+/// selected stripes of a file are fetched from object storage.
+///
+/// Clones of a reader share the size and tail of the file, so that the footer is fetched once even
+/// though every run of selected stripes is read through its own reader.
+#[derive(Clone)]
+pub(crate) struct OrcFileReader {
+    uri: String,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    file_tail: Arc<Mutex<FileTail>>,
+}
+
+impl OrcFileReader {
+    pub(crate) fn new(uri: &str, io_client: Arc<IOClient>, io_stats: Option<IOStatsRef>) -> Self {
+        Self {
+            uri: uri.to_string(),
+            io_client,
+            io_stats,
+            file_tail: Arc::default(),
+        }
+    }
+}
+
+impl AsyncChunkReader for OrcFileReader {
+    fn len(&mut self) -> BoxFuture<'_, std::io::Result<u64>> {
+        async move {
+            if let Some(size) = self.file_tail.lock().unwrap().size {
+                return Ok(size);
+            }
+            let size = self
+                .io_client
+                .single_url_get_size(self.uri.clone(), self.io_stats.clone())
+                .await
+                .map_err(std::io::Error::other)? as u64;
+            self.file_tail.lock().unwrap().size = Some(size);
+            Ok(size)
+        }
+        .boxed()
+    }
+
+    fn get_bytes(
+        &mut self,
+        offset_from_start: u64,
+        length: u64,
+    ) -> BoxFuture<'_, std::io::Result<Bytes>> {
+        async move {
+            let end = offset_from_start + length;
+            if let Some((tail_start, tail)) = &self.file_tail.lock().unwrap().tail
+                && *tail_start <= offset_from_start
+                && end <= tail_start + tail.len() as u64
+            {
+                let start = (offset_from_start - tail_start) as usize;
+                return Ok(tail.slice(start..start + length as usize));
+            }
+            let range = offset_from_start as usize..end as usize;
+            let bytes = self
+                .io_client
+                .single_url_get(self.uri.clone(), Some(range), self.io_stats.clone())
+                .await
+                .map_err(std::io::Error::other)?
+                .bytes()
+                .await
+                .map_err(std::io::Error::other)?;
+            let mut file_tail = self.file_tail.lock().unwrap();
+            if file_tail.size == Some(end) {
+                file_tail.tail = Some((offset_from_start, bytes.clone()));
+            }
+            Ok(bytes)
+        }
+        .boxed()
+    }
+}
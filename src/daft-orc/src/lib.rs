@@ -0,0 +1,74 @@
+#![feature(let_chains)]
+use common_error::DaftError;
+use snafu::Snafu;
+
+mod ffi;
+mod file;
+pub mod metadata;
+pub mod read;
+mod statistics;
+
+pub use metadata::{read_orc_metadata, read_orc_schema};
+pub use orc_rust::reader::metadata::FileMetadata;
+pub use read::{read_orc, read_orc_bulk, stream_orc};
+pub use statistics::stripe_metadata_to_table_stats;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("{source}"))]
+    IOError { source: daft_io::Error },
+
+    #[snafu(display("Unable to read ORC file {}: {}", path, source))]
+    UnableToReadOrc {
+        path: String,
+        source: orc_rust::error::OrcError,
+    },
+
+    #[snafu(display("Unable to decode ORC stripes of {} into Arrow: {}", path, source))]
+    UnableToDecodeOrc {
+        path: String,
+        source: arrow_schema::ArrowError,
+    },
+
+    #[snafu(display(
+        "ORC file: {} has stripe index {} out of bounds, the file has {} stripes",
+        path,
+        stripe,
+        total_stripes
+    ))]
+    OrcStripeOutOfIndex {
+        path: String,
+        stripe: i64,
+        total_stripes: i64,
+    },
+
+    #[snafu(display(
+        "Unable to run expression on ORC stripe statistics of {}: {}",
+        path,
+        source
+    ))]
+    UnableToRunExpressionOnStats {
+        path: String,
+        source: daft_stats::Error,
+    },
+
+    #[snafu(display("Error joining spawned task: {}", source))]
+    JoinError { source: tokio::task::JoinError },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::IOError { source } => source.into(),
+            _ => Self::External(err.into()),
+        }
+    }
+}
+
+impl From<daft_io::Error> for Error {
+    fn from(err: daft_io::Error) -> Self {
+        Self::IOError { source: err }
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
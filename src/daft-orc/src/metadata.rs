@@ -0,0 +1,51 @@
+use std::{collections::HashMap, sync::Arc};
+
+use common_error::DaftResult;
+use daft_core::prelude::*;
+use daft_io::{IOClient, IOStatsRef};
+use orc_rust::reader::metadata::{read_metadata_async, FileMetadata};
+use snafu::ResultExt;
+
+use crate::{
+    ffi::field_to_arrow2, file::OrcFileReader, UnableToDecodeOrcSnafu, UnableToReadOrcSnafu,
+};
+
+/// Reads the footer of an ORC file, which holds its schema and the location and statistics of its stripes.
+pub async fn read_orc_metadata(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Arc<FileMetadata>> {
+    let mut reader = OrcFileReader::new(uri, io_client, io_stats);
+    let metadata = read_metadata_async(&mut reader)
+        .await
+        .context(UnableToReadOrcSnafu { path: uri })?;
+    Ok(Arc::new(metadata))
+}
+
+/// Infers the Daft schema of an ORC file from its metadata.
+pub fn infer_schema_from_metadata(uri: &str, metadata: &FileMetadata) -> DaftResult<Schema> {
+    let arrow_schema = metadata
+        .root_data_type()
+        .create_arrow_schema(&HashMap::new());
+    let fields = arrow_schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let field = field_to_arrow2(field).context(UnableToDecodeOrcSnafu { path: uri })?;
+            Ok(Field::from(&field))
+        })
+        .collect::<DaftResult<_>>()?;
+    Schema::new(fields)
+}
+
+/// Reads the schema of an ORC file, along with its metadata.
+pub async fn read_orc_schema(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<(Schema, Arc<FileMetadata>)> {
+    let metadata = read_orc_metadata(uri, io_client, io_stats).await?;
+    let schema = infer_schema_from_metadata(uri, &metadata)?;
+    Ok((schema, metadata))
+}
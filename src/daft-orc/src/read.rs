@@ -0,0 +1,474 @@
+use std::{ops::Range, sync::Arc};
+
+use common_error::DaftResult;
+use common_runtime::get_io_runtime;
+use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
+use daft_dsl::{optimization::get_required_columns, ExprRef};
+use daft_io::{IOClient, IOStatsRef};
+use daft_recordbatch::RecordBatch;
+use daft_stats::TruthValue;
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use orc_rust::{
+    arrow_reader::ArrowReaderBuilder, projection::ProjectionMask, reader::metadata::FileMetadata,
+};
+use snafu::{futures::TryFutureExt, ResultExt};
+
+use crate::{
+    ffi::array_to_arrow2, file::OrcFileReader, metadata::infer_schema_from_metadata,
+    statistics::stripe_metadata_to_table_stats, JoinSnafu, OrcStripeOutOfIndexSnafu,
+    UnableToDecodeOrcSnafu, UnableToReadOrcSnafu, UnableToRunExpressionOnStatsSnafu,
+};
+
+/// Number of rows decoded into each table when no chunk size is specified.
+const DEFAULT_CHUNK_SIZE: usize = 8192;
+
+/// Selects the stripes of an ORC file to read.
+///
+/// These are the requested stripes, or all of them, less those whose statistics show that no row satisfies the predicate.
+/// Without a predicate, no more stripes are selected once they hold enough rows to satisfy the limit.
+fn select_stripes(
+    uri: &str,
+    metadata: &FileMetadata,
+    schema: &Schema,
+    stripes: Option<&[i64]>,
+    predicate: Option<&ExprRef>,
+    limit: Option<usize>,
+) -> super::Result<Vec<usize>> {
+    let stripe_metadatas = metadata.stripe_metadatas();
+    let candidates = match stripes {
+        Some(stripes) => stripes
+            .iter()
+            .map(|&stripe| {
+                if stripe < 0 || stripe as usize >= stripe_metadatas.len() {
+                    OrcStripeOutOfIndexSnafu {
+                        path: uri.to_string(),
+                        stripe,
+                        total_stripes: stripe_metadatas.len() as i64,
+                    }
+                    .fail()
+                } else {
+                    Ok(stripe as usize)
+                }
+            })
+            .collect::<super::Result<Vec<_>>>()?,
+        None => (0..stripe_metadatas.len()).collect(),
+    };
+
+    let mut rows_to_add = limit.map(|limit| limit as u64);
+    let mut selected = Vec::with_capacity(candidates.len());
+    for i in candidates {
+        if rows_to_add == Some(0) {
+            break;
+        }
+        let stripe = &stripe_metadatas[i];
+        if let Some(predicate) = predicate {
+            let stats = stripe_metadata_to_table_stats(metadata, stripe, schema);
+            let evaled = stats.eval_expression(predicate).with_context(|_| {
+                UnableToRunExpressionOnStatsSnafu {
+                    path: uri.to_string(),
+                }
+            })?;
+            if evaled.to_truth_value() == TruthValue::False {
+                continue;
+            }
+        } else if let Some(rows) = rows_to_add.as_mut() {
+            *rows = rows.saturating_sub(stripe.number_of_rows());
+        }
+        selected.push(i);
+    }
+    Ok(selected)
+}
+
+/// Gives the byte ranges of the file that hold each run of consecutive selected stripes, in file order.
+fn stripe_byte_ranges(metadata: &FileMetadata, stripes: &[usize]) -> Vec<Range<usize>> {
+    let stripe_metadatas = metadata.stripe_metadatas();
+    let mut stripes = stripes.to_vec();
+    stripes.sort_unstable();
+    stripes.dedup();
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for i in stripes {
+        let stripe = &stripe_metadatas[i];
+        let start = stripe.offset() as usize;
+        let end = (stripe.footer_offset() + stripe.footer_length()) as usize;
+        match ranges.last_mut() {
+            Some(range) if range.end == start => range.end = end,
+            _ => ranges.push(start..end),
+        }
+    }
+    ranges
+}
+
+fn arrow_batch_to_table(
+    uri: &str,
+    batch: &arrow_array::RecordBatch,
+    schema: &Schema,
+) -> DaftResult<RecordBatch> {
+    let columns = batch
+        .schema()
+        .fields()
+        .iter()
+        .zip(batch.columns())
+        .map(|(field, array)| {
+            let daft_field = schema.get_field(field.name())?.clone();
+            let array =
+                array_to_arrow2(array.as_ref()).context(UnableToDecodeOrcSnafu { path: uri })?;
+            Series::try_from_field_and_arrow_array(daft_field, cast_array_for_daft_if_needed(array))
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    let schema = Schema::new(columns.iter().map(|s| s.field().clone()).collect())?;
+    RecordBatch::new_with_size(schema, columns, batch.num_rows())
+}
+
+/// Streams the tables of a single ORC file, reading only the selected stripes and columns.
+///
+/// Returns the stream along with the schema of its tables.
+#[allow(clippy::too_many_arguments)]
+async fn stream_orc_single(
+    uri: String,
+    columns: Option<Vec<String>>,
+    limit: Option<usize>,
+    stripes: Option<Vec<i64>>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    chunk_size: Option<usize>,
+) -> DaftResult<(BoxStream<'static, DaftResult<RecordBatch>>, SchemaRef)> {
+    let reader = OrcFileReader::new(&uri, io_client, io_stats);
+    let builder = ArrowReaderBuilder::try_new_async(reader.clone())
+        .await
+        .context(UnableToReadOrcSnafu { path: uri.clone() })?;
+    let metadata = builder.file_metadata();
+    let file_schema = Arc::new(infer_schema_from_metadata(&uri, metadata)?);
+
+    // The columns required by the predicate are also read, and are dropped after filtering.
+    let read_columns = columns.clone().map(|mut columns| {
+        if let Some(predicate) = &predicate {
+            for column in get_required_columns(predicate) {
+                if !columns.contains(&column) {
+                    columns.push(column);
+                }
+            }
+        }
+        columns
+    });
+    let (read_schema, output_schema) = match (&read_columns, &columns) {
+        (Some(read_columns), Some(columns)) => {
+            let project = |names: &[String]| {
+                Schema::new(
+                    file_schema
+                        .fields
+                        .values()
+                        .filter(|field| names.contains(&field.name))
+                        .cloned()
+                        .collect(),
+                )
+            };
+            (
+                Arc::new(project(read_columns)?),
+                Arc::new(project(columns)?),
+            )
+        }
+        _ => (file_schema.clone(), file_schema.clone()),
+    };
+
+    let selected = select_stripes(
+        &uri,
+        metadata,
+        &file_schema,
+        stripes.as_deref(),
+        predicate.as_ref(),
+        limit,
+    )?;
+    let byte_ranges = stripe_byte_ranges(metadata, &selected);
+    let projection = read_columns
+        .map(|columns| ProjectionMask::named_roots(metadata.root_data_type(), columns.as_slice()));
+    let batch_size = chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE);
+
+    // Each run of consecutive selected stripes is read by its own reader, which only fetches the stripes within its
+    // byte range. The readers share the footer that was already fetched, and a run's reader is only built once the
+    // stream reaches it, so the runs that follow are never fetched once the limit is met.
+    let tables = futures::stream::iter(byte_ranges)
+        .then(move |byte_range| {
+            let (uri, reader, projection, read_schema) = (
+                uri.clone(),
+                reader.clone(),
+                projection.clone(),
+                read_schema.clone(),
+            );
+            async move {
+                let mut builder = ArrowReaderBuilder::try_new_async(reader)
+                    .await
+                    .context(UnableToReadOrcSnafu { path: uri.clone() })?
+                    .with_batch_size(batch_size)
+                    .with_file_byte_range(byte_range);
+                if let Some(projection) = projection {
+                    builder = builder.with_projection(projection);
+                }
+                DaftResult::Ok(builder.build_async().map(move |batch| {
+                    let batch = batch.context(UnableToDecodeOrcSnafu { path: uri.clone() })?;
+                    arrow_batch_to_table(&uri, &batch, &read_schema)
+                }))
+            }
+        })
+        .try_flatten();
+
+    let output_columns = columns.map(|_| output_schema.names());
+    let filtered = tables.map(move |table| {
+        let table = table?;
+        match (&predicate, &output_columns) {
+            (Some(predicate), Some(output_columns)) => table
+                .filter(&[predicate.clone()])?
+                .get_columns(output_columns.as_slice()),
+            (Some(predicate), None) => table.filter(&[predicate.clone()]),
+            (None, _) => Ok(table),
+        }
+    });
+
+    let limited = filtered.scan(limit, |remaining, table| {
+        let table = match (table, *remaining) {
+            // The limit has been met, so the stream is terminated.
+            (_, Some(0)) => None,
+            (Ok(table), Some(rows_left)) => {
+                let table = if table.len() > rows_left {
+                    table.head(rows_left)
+                } else {
+                    Ok(table)
+                };
+                if let Ok(table) = &table {
+                    *remaining = Some(rows_left - table.len());
+                }
+                Some(table)
+            }
+            (table, _) => Some(table),
+        };
+        futures::future::ready(table)
+    });
+
+    Ok((limited.boxed(), output_schema))
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn read_orc_single_into_table(
+    uri: &str,
+    columns: Option<Vec<String>>,
+    limit: Option<usize>,
+    stripes: Option<Vec<i64>>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    chunk_size: Option<usize>,
+) -> DaftResult<RecordBatch> {
+    let (stream, schema) = stream_orc_single(
+        uri.to_string(),
+        columns,
+        limit,
+        stripes,
+        predicate,
+        io_client,
+        io_stats,
+        chunk_size,
+    )
+    .await?;
+    let tables = stream.try_collect::<Vec<_>>().await?;
+    if tables.is_empty() {
+        RecordBatch::empty(Some(schema))
+    } else {
+        RecordBatch::concat(tables.as_slice())
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn read_orc(
+    uri: &str,
+    columns: Option<&[&str]>,
+    limit: Option<usize>,
+    stripes: Option<Vec<i64>>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+    chunk_size: Option<usize>,
+) -> DaftResult<RecordBatch> {
+    let runtime_handle = get_io_runtime(multithreaded_io);
+    let columns = columns.map(|s| s.iter().map(|v| (*v).to_string()).collect::<Vec<_>>());
+    runtime_handle.block_on_current_thread(async {
+        read_orc_single_into_table(
+            uri, columns, limit, stripes, predicate, io_client, io_stats, chunk_size,
+        )
+        .await
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn read_orc_bulk(
+    uris: &[&str],
+    columns: Option<&[&str]>,
+    limit: Option<usize>,
+    stripes: Option<Vec<Option<Vec<i64>>>>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    num_parallel_tasks: usize,
+    multithreaded_io: bool,
+    chunk_size: Option<usize>,
+) -> DaftResult<Vec<RecordBatch>> {
+    let runtime_handle = get_io_runtime(multithreaded_io);
+    let columns = columns.map(|s| s.iter().map(|v| (*v).to_string()).collect::<Vec<_>>());
+    let tables = runtime_handle.block_on_current_thread(async move {
+        // Launch a read task per URI, throttling the number of concurrent file reads to num_parallel tasks.
+        let task_stream = futures::stream::iter(uris.iter().enumerate().map(|(i, uri)| {
+            let (uri, columns, stripes, predicate, io_client, io_stats) = (
+                (*uri).to_string(),
+                columns.clone(),
+                stripes.as_ref().and_then(|stripes| stripes[i].clone()),
+                predicate.clone(),
+                io_client.clone(),
+                io_stats.clone(),
+            );
+            tokio::task::spawn(async move {
+                read_orc_single_into_table(
+                    uri.as_str(),
+                    columns,
+                    limit,
+                    stripes,
+                    predicate,
+                    io_client,
+                    io_stats,
+                    chunk_size,
+                )
+                .await
+            })
+            .context(JoinSnafu)
+        }));
+        let mut remaining_rows = limit.map(|limit| limit as i64);
+        task_stream
+            // Limit the number of file reads we have in flight at any given time.
+            .buffered(num_parallel_tasks)
+            // Terminate the stream if we have already reached the row limit. With the upstream buffering, we will still read up to
+            // num_parallel_tasks redundant files.
+            .try_take_while(|result| {
+                match (result, remaining_rows) {
+                    // Limit has been met, early-terminate.
+                    (_, Some(rows_left)) if rows_left <= 0 => futures::future::ready(Ok(false)),
+                    // Limit has not yet been met, update remaining limit slack and continue.
+                    (Ok(table), Some(rows_left)) => {
+                        remaining_rows = Some(rows_left - table.len() as i64);
+                        futures::future::ready(Ok(true))
+                    }
+                    // (1) No limit, never early-terminate.
+                    // (2) Encountered error, propagate error to try_collect to allow it to short-circuit.
+                    (_, None) | (Err(_), _) => futures::future::ready(Ok(true)),
+                }
+            })
+            .try_collect::<Vec<_>>()
+            .await
+    })?;
+    tables.into_iter().collect::<DaftResult<Vec<_>>>()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn stream_orc(
+    uri: &str,
+    columns: Option<&[&str]>,
+    limit: Option<usize>,
+    stripes: Option<Vec<i64>>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    chunk_size: Option<usize>,
+) -> DaftResult<BoxStream<'static, DaftResult<RecordBatch>>> {
+    let columns = columns.map(|s| s.iter().map(|v| (*v).to_string()).collect::<Vec<_>>());
+    let (stream, _) = stream_orc_single(
+        uri.to_string(),
+        columns,
+        limit,
+        stripes,
+        predicate,
+        io_client,
+        io_stats,
+        chunk_size,
+    )
+    .await?;
+    Ok(stream)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::Int64Array;
+    use arrow_schema::{DataType, Field, Schema};
+    use common_error::DaftResult;
+    use daft_io::{IOClient, IOConfig, IOStatsContext};
+    use orc_rust::ArrowWriterBuilder;
+
+    use super::read_orc;
+    use crate::read_orc_metadata;
+
+    const ROWS_PER_STRIPE: i64 = 20_000;
+
+    /// Writes an ORC file of three stripes, whose values don't compress so that each stripe is
+    /// much larger than the tail of the file that is fetched along with the footer.
+    fn write_orc_file(path: &std::path::Path) {
+        let schema = Arc::new(Schema::new(vec![Field::new("x", DataType::Int64, false)]));
+        let file = std::fs::File::create(path).unwrap();
+        let mut writer = ArrowWriterBuilder::new(file, schema.clone())
+            .try_build()
+            .unwrap();
+        for stripe in 0..3 {
+            let values = (0..ROWS_PER_STRIPE)
+                .map(|i| {
+                    (stripe * ROWS_PER_STRIPE + i).wrapping_mul(0x9E37_79B9_7F4A_7C15_u64 as i64)
+                })
+                .collect::<Vec<_>>();
+            let batch = arrow_array::RecordBatch::try_new(
+                schema.clone(),
+                vec![Arc::new(Int64Array::from(values))],
+            )
+            .unwrap();
+            writer.write(&batch).unwrap();
+            writer.flush_stripe().unwrap();
+        }
+        writer.close().unwrap();
+    }
+
+    #[test]
+    fn test_read_orc_only_fetches_selected_stripes() -> DaftResult<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stripes.orc");
+        write_orc_file(&path);
+        let uri = path.to_str().unwrap();
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let metadata_stats = IOStatsContext::new("read_orc_metadata");
+        let metadata = common_runtime::get_io_runtime(true).block_on_current_thread(
+            read_orc_metadata(uri, io_client.clone(), Some(metadata_stats.clone())),
+        )?;
+        let stripe_metadatas = metadata.stripe_metadatas();
+        assert_eq!(stripe_metadatas.len(), 3);
+        let stripe_len = |i: usize| {
+            let stripe = &stripe_metadatas[i];
+            (stripe.index_length() + stripe.data_length() + stripe.footer_length()) as usize
+        };
+
+        // The footer is fetched once for both runs of stripes, and the middle stripe is never fetched. Parts of the
+        // last stripe may already be within the fetched tail of the file.
+        let read_stats = IOStatsContext::new("read_orc");
+        let table = read_orc(
+            uri,
+            None,
+            None,
+            Some(vec![0, 2]),
+            None,
+            io_client,
+            Some(read_stats.clone()),
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 2 * ROWS_PER_STRIPE as usize);
+        let bytes_read = read_stats.load_bytes_read();
+        assert!(bytes_read <= metadata_stats.load_bytes_read() + stripe_len(0) + stripe_len(2));
+        assert!(bytes_read > metadata_stats.load_bytes_read() + stripe_len(0));
+        Ok(())
+    }
+}
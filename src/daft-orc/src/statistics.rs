@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use daft_core::prelude::*;
+use daft_stats::{ColumnRangeStatistics, TableStatistics};
+use orc_rust::{
+    reader::metadata::FileMetadata,
+    statistics::{ColumnStatistics, TypeStatistics},
+    stripe::StripeMetadata,
+};
+
+/// Converts the statistics of an ORC column to Daft column range statistics of the given type.
+///
+/// Returns `None` if the statistics are absent or of a kind that we do not convert.
+fn orc_statistics_to_column_range_statistics(
+    statistics: &ColumnStatistics,
+    dtype: &DataType,
+) -> Option<ColumnRangeStatistics> {
+    let (lower, upper) = match statistics.type_statistics()? {
+        TypeStatistics::Integer { min, max, .. } => (
+            Int64Array::from(("lower", [*min].as_slice())).into_series(),
+            Int64Array::from(("upper", [*max].as_slice())).into_series(),
+        ),
+        TypeStatistics::Double { min, max, .. } => (
+            Float64Array::from(("lower", [*min].as_slice())).into_series(),
+            Float64Array::from(("upper", [*max].as_slice())).into_series(),
+        ),
+        TypeStatistics::String { min, max, .. } => (
+            Utf8Array::from(("lower", [min.as_str()].as_slice())).into_series(),
+            Utf8Array::from(("upper", [max.as_str()].as_slice())).into_series(),
+        ),
+        TypeStatistics::Date { min, max } => (
+            Int32Array::from(("lower", [*min].as_slice()))
+                .into_series()
+                .cast(&DataType::Date)
+                .ok()?,
+            Int32Array::from(("upper", [*max].as_slice()))
+                .into_series()
+                .cast(&DataType::Date)
+                .ok()?,
+        ),
+        _ => return None,
+    };
+    ColumnRangeStatistics::new(Some(lower), Some(upper))
+        .ok()?
+        .cast(dtype)
+        .ok()
+}
+
+/// Builds the statistics of an ORC stripe for the top-level columns of the given schema.
+/// Columns without usable statistics in the stripe are marked as missing.
+pub fn stripe_metadata_to_table_stats(
+    metadata: &FileMetadata,
+    stripe: &StripeMetadata,
+    schema: &Schema,
+) -> TableStatistics {
+    let column_statistics = stripe.column_statistics();
+    let orc_column_statistics: HashMap<_, _> = metadata
+        .root_data_type()
+        .children()
+        .iter()
+        .filter_map(|column| {
+            column_statistics
+                .get(column.data_type().column_index())
+                .map(|statistics| (column.name(), statistics))
+        })
+        .collect();
+
+    let columns = schema
+        .fields
+        .iter()
+        .map(|(field_name, field)| {
+            let stats = orc_column_statistics
+                .get(field_name.as_str())
+                .filter(|_| ColumnRangeStatistics::supports_dtype(&field.dtype))
+                .and_then(|statistics| {
+                    orc_statistics_to_column_range_statistics(statistics, &field.dtype)
+                })
+                .unwrap_or(ColumnRangeStatistics::Missing);
+            (field_name.clone(), stats)
+        })
+        .collect();
+
+    TableStatistics { columns }
+}
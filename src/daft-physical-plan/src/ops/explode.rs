@@ -15,15 +15,21 @@ pub struct Explode {
     // Upstream node.
     pub input: PhysicalPlanRef,
     pub to_explode: Vec<ExprRef>,
+    pub index_column: Option<String>,
     pub clustering_spec: Arc<ClusteringSpec>,
 }
 
 impl Explode {
-    pub(crate) fn try_new(input: PhysicalPlanRef, to_explode: Vec<ExprRef>) -> DaftResult<Self> {
+    pub(crate) fn try_new(
+        input: PhysicalPlanRef,
+        to_explode: Vec<ExprRef>,
+        index_column: Option<String>,
+    ) -> DaftResult<Self> {
         let clustering_spec = Self::translate_clustering_spec(input.clustering_spec(), &to_explode);
         Ok(Self {
             input,
             to_explode,
+            index_column,
             clustering_spec,
         })
     }
@@ -63,6 +69,9 @@ impl Explode {
             "Explode: {}",
             self.to_explode.iter().map(|e| e.to_string()).join(", ")
         ));
+        if let Some(index_column) = &self.index_column {
+            res.push(format!("Index column = {}", index_column));
+        }
         res.push(format!(
             "Clustering spec = {{ {} }}",
             self.clustering_spec.multiline_display().join(", ")
@@ -99,7 +108,7 @@ mod tests {
             Field::new("c", DataType::Int64),
         ]))
         .hash_repartition(Some(3), vec![unresolved_col("a")])?
-        .explode(vec![unresolved_col("b")])?
+        .explode(vec![unresolved_col("b")], None)?
         .build();
 
         let physical_plan = logical_to_physical(logical_plan, cfg)?;
@@ -126,7 +135,7 @@ mod tests {
             Field::new("c", DataType::Int64),
         ]))
         .hash_repartition(Some(3), vec![unresolved_col("a"), unresolved_col("b")])?
-        .explode(vec![unresolved_col("b")])?
+        .explode(vec![unresolved_col("b")], None)?
         .build();
 
         let physical_plan = logical_to_physical(logical_plan, cfg)?;
@@ -96,11 +96,12 @@ impl PhysicalOptimizerRule for ReorderPartitionKeys {
                     });
                     Ok(Transformed::yes(c.with_plan(new_plan.into()).propagate()))
                 }
-                PhysicalPlan::Explode(Explode { input, to_explode, .. }) => {
+                PhysicalPlan::Explode(Explode { input, to_explode, index_column, .. }) => {
                     // can't use try_new because we are setting the clustering spec ourselves
                     let new_plan = PhysicalPlan::Explode(Explode {
                         input: input.clone(),
                         to_explode: to_explode.clone(),
+                        index_column: index_column.clone(),
                         clustering_spec: new_spec.into(),
                     });
                     Ok(Transformed::yes(c.with_plan(new_plan.into()).propagate()))
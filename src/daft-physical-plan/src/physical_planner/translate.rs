@@ -10,9 +10,11 @@ use common_file_formats::FileFormat;
 use common_scan_info::{PhysicalScanInfo, ScanState, SPLIT_AND_MERGE_PASS};
 use daft_core::{join::JoinSide, prelude::*};
 use daft_dsl::{
-    estimated_selectivity, functions::agg::merge_mean, is_partition_compatible,
-    join::normalize_join_keys, resolved_col, AggExpr, ApproxPercentileParams, Expr, ExprRef,
-    SketchType,
+    estimated_selectivity,
+    functions::agg::merge_mean,
+    is_partition_compatible,
+    join::{join_predicate_to_output_expr, normalize_join_keys},
+    resolved_col, AggExpr, ApproxPercentileParams, Expr, ExprRef, SketchType,
 };
 use daft_functions::{
     list::{count_distinct, distinct},
@@ -104,6 +106,9 @@ pub(super) fn translate_single_logical_node(
             SourceInfo::PlaceHolder(PlaceHolderInfo { source_id, .. }) => {
                 panic!("Placeholder {source_id} should not get to translation. This should have been optimized away");
             }
+            SourceInfo::WorkTable(_) => Err(DaftError::NotImplemented(
+                "Recursive CTEs are currently only supported on the native runner".to_string(),
+            )),
         },
         LogicalPlan::Project(LogicalProject { projection, .. }) => {
             let input_physical = physical_children.pop().expect("requires 1 input");
@@ -140,12 +145,18 @@ pub(super) fn translate_single_logical_node(
                     .arced(),
             )
         }
-        LogicalPlan::Explode(LogicalExplode { to_explode, .. }) => {
+        LogicalPlan::Explode(LogicalExplode {
+            to_explode,
+            index_column,
+            ..
+        }) => {
             let input_physical = physical_children.pop().expect("requires 1 input");
-            Ok(
-                PhysicalPlan::Explode(Explode::try_new(input_physical, to_explode.clone())?)
-                    .arced(),
-            )
+            Ok(PhysicalPlan::Explode(Explode::try_new(
+                input_physical,
+                to_explode.clone(),
+                index_column.clone(),
+            )?)
+            .arced())
         }
         LogicalPlan::Unpivot(LogicalUnpivot {
             ids,
@@ -432,10 +443,20 @@ pub(super) fn translate_single_logical_node(
             left_on,
             right_on,
             null_equals_nulls,
+            predicate,
             join_type,
             join_strategy,
+            output_schema,
             ..
         }) => {
+            // The predicate of an inner join can be applied as a filter on the joined rows,
+            // whereas other joins must also keep the rows for which it does not hold.
+            if predicate.is_some() && *join_type != JoinType::Inner {
+                return Err(DaftError::NotImplemented(format!(
+                    "Join predicates are not yet supported for {join_type} joins on the Ray runner"
+                )));
+            }
+
             let mut right_physical = physical_children.pop().expect("requires 1 inputs");
             let mut left_physical = physical_children.pop().expect("requires 2 inputs");
 
@@ -555,7 +576,7 @@ pub(super) fn translate_single_logical_node(
                     JoinStrategy::Hash
                 }
             });
-            match join_strategy {
+            let join_physical: DaftResult<PhysicalPlanRef> = match join_strategy {
                 JoinStrategy::Broadcast => {
                     let is_swapped = match (join_type, left_is_larger) {
                         (JoinType::Left, _) => true,
@@ -725,6 +746,20 @@ pub(super) fn translate_single_logical_node(
                     ))
                     .arced())
                 }
+            };
+            let join_physical = join_physical?;
+            match predicate {
+                Some(predicate) => {
+                    let predicate = join_predicate_to_output_expr(predicate.clone());
+                    let estimated_selectivity = estimated_selectivity(&predicate, output_schema);
+                    Ok(PhysicalPlan::Filter(Filter::new(
+                        join_physical,
+                        predicate,
+                        estimated_selectivity,
+                    ))
+                    .arced())
+                }
+                None => Ok(join_physical),
             }
         }
         LogicalPlan::Sink(LogicalSink {
@@ -756,6 +791,13 @@ pub(super) fn translate_single_logical_node(
                             ))
                             .arced())
                         }
+                        FileFormat::Orc => Err(common_error::DaftError::ValueError(
+                            "ORC sink not yet implemented".to_string(),
+                        )),
+                        FileFormat::Ipc => Err(common_error::DaftError::ValueError(
+                            "Writing Arrow IPC files is only supported on the native runner"
+                                .to_string(),
+                        )),
                         FileFormat::Database => Err(common_error::DaftError::ValueError(
                             "Database sink not yet implemented".to_string(),
                         )),
@@ -784,6 +826,10 @@ pub(super) fn translate_single_logical_node(
                             LanceWrite::new(schema.clone(), lance_info.clone(), input_physical),
                         )
                         .arced()),
+                        CatalogType::Table(_) => Err(DaftError::NotImplemented(
+                            "Writing to catalog tables is currently only supported on the native runner"
+                                .to_string(),
+                        )),
                     }
                 }
             }
@@ -801,6 +847,15 @@ pub(super) fn translate_single_logical_node(
                 .arced(),
             )
         }
+        LogicalPlan::Window(_) => Err(DaftError::NotImplemented(
+            "Window functions are currently only supported on the native runner".to_string(),
+        )),
+        LogicalPlan::AsofJoin(_) => Err(DaftError::NotImplemented(
+            "ASOF joins are currently only supported on the native runner".to_string(),
+        )),
+        LogicalPlan::RecursiveUnion(_) => Err(DaftError::NotImplemented(
+            "Recursive CTEs are currently only supported on the native runner".to_string(),
+        )),
         LogicalPlan::Intersect(_) => Err(DaftError::InternalError(
             "Intersect should already be optimized away".to_string(),
         )),
@@ -648,6 +648,9 @@ impl RecordBatch {
             Expr::Exists(_subquery) => Err(DaftError::ComputeError(
                 "EXISTS <SUBQUERY> should be optimized away before evaluation. This indicates a bug in the query optimizer.".to_string(),
             )),
+            Expr::Over(..) => Err(DaftError::ComputeError(
+                "Window functions should be evaluated by a window operator, not as a projection. This indicates a bug in the query planner.".to_string(),
+            )),
             Expr::Column(Column::Resolved(ResolvedColumn::OuterRef(Field { name, .. }))) => Err(DaftError::ComputeError(
                 format!("Outer reference columns should be eliminated before evaluation. This indicates either that column {name} does not exist in the table, or there is a bug in the query optimizer."),
             )),
@@ -4,7 +4,7 @@ use common_error::{DaftError, DaftResult};
 use daft_core::{
     array::ops::as_arrow::AsArrow,
     count_mode::CountMode,
-    datatypes::{DataType, UInt64Array},
+    datatypes::{DataType, Field, Int64Array, UInt64Array},
     series::{IntoSeries, Series},
 };
 use daft_dsl::Expr;
@@ -20,8 +20,27 @@ fn lengths_to_indices(lengths: &UInt64Array, capacity: usize) -> DaftResult<UInt
     Ok(UInt64Array::from(("indices", indices)))
 }
 
+/// The position of every exploded element within its list, or null for the single row that an
+/// empty or null list is exploded into.
+fn lengths_to_positions(lengths: &UInt64Array, name: &str, capacity: usize) -> Int64Array {
+    let mut positions = Vec::with_capacity(capacity);
+    for l in lengths.as_arrow() {
+        match l.copied().unwrap_or(0) {
+            0 => positions.push(None),
+            l => positions.extend((0..l as i64).map(Some)),
+        }
+    }
+    Int64Array::from_iter(Field::new(name, DataType::Int64), positions.into_iter())
+}
+
 impl RecordBatch {
-    pub fn explode<E: AsRef<Expr>>(&self, exprs: &[E]) -> DaftResult<Self> {
+    /// Explodes the lists of `exprs` into one row per element, where `index_column` names an
+    /// optional Int64 column that is appended with the zero-based position of each element.
+    pub fn explode<E: AsRef<Expr>>(
+        &self,
+        exprs: &[E],
+        index_column: Option<&str>,
+    ) -> DaftResult<Self> {
         if exprs.is_empty() {
             return Err(DaftError::ValueError(format!(
                 "Explode needs at least 1 expression, received: {}",
@@ -96,6 +115,11 @@ impl RecordBatch {
             }
         }
         new_series.extend_from_slice(exploded_columns.as_slice());
+        if let Some(index_column) = index_column {
+            new_series.push(
+                lengths_to_positions(&first_len, index_column, capacity_expected).into_series(),
+            );
+        }
         Self::from_nonempty_columns(new_series)
     }
 }
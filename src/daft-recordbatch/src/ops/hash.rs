@@ -11,12 +11,22 @@ use crate::RecordBatch;
 
 impl RecordBatch {
     pub fn hash_rows(&self) -> DaftResult<UInt64Array> {
+        self.hash_rows_seeded(None)
+    }
+
+    /// Like [`Self::hash_rows`], but seeds the hash of every row with `seed`.
+    pub fn hash_rows_with_seed(&self, seed: u64) -> DaftResult<UInt64Array> {
+        let seeds = UInt64Array::from(("seed", vec![seed; self.len()]));
+        self.hash_rows_seeded(Some(&seeds))
+    }
+
+    fn hash_rows_seeded(&self, seeds: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
         if self.num_columns() == 0 {
             return Err(DaftError::ValueError(
                 "Attempting to Hash Table with no columns".to_string(),
             ));
         }
-        let mut hash_so_far = self.columns.first().unwrap().hash(None)?;
+        let mut hash_so_far = self.columns.first().unwrap().hash(seeds)?;
         for c in self.columns.iter().skip(1) {
             hash_so_far = c.hash(Some(&hash_so_far))?;
         }
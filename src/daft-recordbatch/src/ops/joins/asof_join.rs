@@ -0,0 +1,218 @@
+use std::cmp::Ordering;
+
+use common_error::DaftResult;
+use daft_core::{
+    array::ops::{full::FullNull, DaftCompare},
+    join::AsofJoinDirection,
+    kernels::search_sorted::build_partial_compare_with_nulls,
+    prelude::*,
+};
+
+use crate::RecordBatch;
+
+/// Computes the right-side take indices of an ASOF join, i.e. for each row of the left table the index of the matched
+/// row of the right table, or null if there is none.
+///
+/// `left` and `right` hold the `by` keys followed by the `on` key, with matching types.
+/// If `tolerance` is set, a right row is only matched if its distance to the left row, in the direction of the match,
+/// is at most `tolerance`.
+/// If `right_sorted` is set, `right` is already sorted by its keys, so only `left` needs to be sorted.
+pub(super) fn asof_join_indices(
+    left: &RecordBatch,
+    right: &RecordBatch,
+    direction: AsofJoinDirection,
+    tolerance: Option<&Series>,
+    right_sorted: bool,
+) -> DaftResult<Series> {
+    let has_null_type = left.columns.iter().any(|s| s.data_type().is_null())
+        || right.columns.iter().any(|s| s.data_type().is_null());
+    if left.is_empty() || right.is_empty() || has_null_type {
+        return Ok(
+            UInt64Array::full_null("right_indices", &DataType::UInt64, left.len()).into_series(),
+        );
+    }
+
+    // Sort both sides by the `by` keys and then the `on` key, so that the matches can be found in a single merge pass.
+    let num_keys = left.num_columns();
+    let ascending = vec![false; num_keys];
+    let nulls_last = vec![false; num_keys];
+    let left_argsort = Series::argsort_multikey(left.columns.as_slice(), &ascending, &nulls_last)?;
+    let left_sorted = left.take(&left_argsort)?;
+    let left_argsort = left_argsort.u64()?;
+    let right_argsort = if right_sorted {
+        None
+    } else {
+        Some(Series::argsort_multikey(
+            right.columns.as_slice(),
+            &ascending,
+            &nulls_last,
+        )?)
+    };
+    let right_taken;
+    let right_sorted = match &right_argsort {
+        Some(right_argsort) => {
+            right_taken = right.take(right_argsort)?;
+            &right_taken
+        }
+        None => right,
+    };
+    let right_argsort = right_argsort.as_ref().map(|s| s.u64()).transpose()?;
+    // The index of a row of `right_sorted` in `right`.
+    let right_index = |r: usize| match right_argsort {
+        Some(right_argsort) => right_argsort.get(r),
+        None => Some(r as u64),
+    };
+
+    let mut comparators = Vec::with_capacity(num_keys);
+    for (l, r) in left_sorted.columns.iter().zip(right_sorted.columns.iter()) {
+        comparators.push(build_partial_compare_with_nulls(
+            l.as_physical()?.to_arrow().as_ref(),
+            r.as_physical()?.to_arrow().as_ref(),
+            false,
+        )?);
+    }
+    let (on_comparator, by_comparators) = comparators.split_last().unwrap();
+    // Nulls sort last, so a right row with a null key compares greater than any left row with the same prefix of keys.
+    let compare_by = |l: usize, r: usize| -> Ordering {
+        for comparator in by_comparators {
+            match comparator(l, r) {
+                Some(Ordering::Equal) => continue,
+                Some(ordering) => return ordering,
+                None => return Ordering::Less,
+            }
+        }
+        Ordering::Equal
+    };
+    // Whether the right row is in the same `by` group as the left row and has an `on` key for which `pred` holds,
+    // where `pred` receives the ordering of the left `on` key relative to the right `on` key.
+    let in_group_and = |l: usize, r: usize, pred: fn(Ordering) -> bool| -> bool {
+        compare_by(l, r) == Ordering::Equal && on_comparator(l, r).is_some_and(pred)
+    };
+
+    let mut left_valid = vec![true; left_sorted.len()];
+    for column in left_sorted.columns.iter() {
+        let not_null = column.not_null()?;
+        for (valid, not_null) in left_valid.iter_mut().zip(not_null.bool()?) {
+            *valid &= not_null == Some(true);
+        }
+    }
+
+    let num_right = right_sorted.len();
+    let mut backward = vec![None; left.len()];
+    let mut forward = vec![None; left.len()];
+    // Start of the run of right rows whose `by` keys are not less than those of the current left row.
+    let mut group_start = 0;
+    // End of the run of right rows in the current group whose `on` key is at most that of the current left row.
+    let mut backward_end = 0;
+    // Start of the run of right rows in the current group whose `on` key is at least that of the current left row.
+    let mut forward_start = 0;
+    for (l, valid) in left_valid.into_iter().enumerate() {
+        if !valid {
+            continue;
+        }
+        while group_start < num_right && compare_by(l, group_start) == Ordering::Greater {
+            group_start += 1;
+        }
+        backward_end = backward_end.max(group_start);
+        forward_start = forward_start.max(group_start);
+
+        let original_idx = left_argsort.get(l).unwrap() as usize;
+        if matches!(
+            direction,
+            AsofJoinDirection::Backward | AsofJoinDirection::Nearest
+        ) {
+            while backward_end < num_right && in_group_and(l, backward_end, |o| o != Ordering::Less)
+            {
+                backward_end += 1;
+            }
+            if backward_end > group_start {
+                backward[original_idx] = right_index(backward_end - 1);
+            }
+        }
+        if matches!(
+            direction,
+            AsofJoinDirection::Forward | AsofJoinDirection::Nearest
+        ) {
+            while forward_start < num_right
+                && in_group_and(l, forward_start, |o| o == Ordering::Greater)
+            {
+                forward_start += 1;
+            }
+            if forward_start < num_right && in_group_and(l, forward_start, |_| true) {
+                forward[original_idx] = right_index(forward_start);
+            }
+        }
+    }
+
+    let left_on = &left.columns[num_keys - 1];
+    let right_on = &right.columns[num_keys - 1];
+    // The distance between each left row and its candidate match, which is non-negative in the direction of the match.
+    let distances = |candidates: &[Option<u64>], is_backward: bool| -> DaftResult<Series> {
+        let indices = UInt64Array::from_iter(
+            Field::new("right_indices", DataType::UInt64),
+            candidates.iter().copied(),
+        )
+        .into_series();
+        let matched_on = right_on.take(&indices)?;
+        if is_backward {
+            left_on - &matched_on
+        } else {
+            &matched_on - left_on
+        }
+    };
+    let mut backward_distances = None;
+    let mut forward_distances = None;
+    if direction != AsofJoinDirection::Forward {
+        backward_distances = Some(distances(&backward, true)?);
+    }
+    if direction != AsofJoinDirection::Backward {
+        forward_distances = Some(distances(&forward, false)?);
+    }
+
+    if let Some(tolerance) = tolerance {
+        for (candidates, distances) in [
+            (&mut backward, &backward_distances),
+            (&mut forward, &forward_distances),
+        ] {
+            if let Some(distances) = distances {
+                let within_tolerance = distances.lte(tolerance)?;
+                for (candidate, within) in candidates.iter_mut().zip(&within_tolerance) {
+                    if within != Some(true) {
+                        *candidate = None;
+                    }
+                }
+            }
+        }
+    }
+
+    let right_indices: Vec<Option<u64>> = match direction {
+        AsofJoinDirection::Backward => backward,
+        AsofJoinDirection::Forward => forward,
+        AsofJoinDirection::Nearest => {
+            let backward_is_nearer = backward_distances
+                .as_ref()
+                .unwrap()
+                .lte(forward_distances.as_ref().unwrap())?;
+            backward
+                .into_iter()
+                .zip(forward)
+                .zip(&backward_is_nearer)
+                .map(
+                    |((backward, forward), backward_is_nearer)| match (backward, forward) {
+                        (Some(b), Some(f)) => Some(if backward_is_nearer == Some(false) {
+                            f
+                        } else {
+                            b
+                        }),
+                        (b, f) => b.or(f),
+                    },
+                )
+                .collect()
+        }
+    };
+    Ok(UInt64Array::from_iter(
+        Field::new("right_indices", DataType::UInt64),
+        right_indices.into_iter(),
+    )
+    .into_series())
+}
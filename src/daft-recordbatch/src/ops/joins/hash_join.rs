@@ -13,6 +13,71 @@ use daft_dsl::{
 
 use super::{add_non_join_key_columns, match_types_for_tables};
 use crate::RecordBatch;
+
+/// Computes the left and right take indices of the pairs of rows whose join keys are equal.
+///
+/// `lkeys` and `rkeys` hold the join keys of either side, with matching types.
+pub(super) fn hash_inner_join_indices(
+    lkeys: &RecordBatch,
+    rkeys: &RecordBatch,
+    null_equals_nulls: &[bool],
+) -> DaftResult<(Series, Series)> {
+    if lkeys.columns.iter().any(|s| s.data_type().is_null())
+        || rkeys.columns.iter().any(|s| s.data_type().is_null())
+    {
+        return Ok((
+            UInt64Array::empty("left_indices", &DataType::UInt64).into_series(),
+            UInt64Array::empty("right_indices", &DataType::UInt64).into_series(),
+        ));
+    }
+
+    // probe on the smaller table
+    let probe_left = lkeys.len() <= rkeys.len();
+
+    let (lkeys, rkeys) = if probe_left {
+        (lkeys, rkeys)
+    } else {
+        (rkeys, lkeys)
+    };
+
+    let probe_table = lkeys.to_probe_hash_table()?;
+
+    let r_hashes = rkeys.hash_rows()?;
+    use daft_core::array::ops::arrow2::comparison::build_multi_array_is_equal;
+    let is_equal = build_multi_array_is_equal(
+        lkeys.columns.as_slice(),
+        rkeys.columns.as_slice(),
+        null_equals_nulls,
+        vec![false; lkeys.columns.len()].as_slice(),
+    )?;
+
+    let mut left_idx = vec![];
+    let mut right_idx = vec![];
+
+    for (r_idx, h) in r_hashes.as_arrow().values_iter().enumerate() {
+        if let Some((_, indices)) = probe_table.raw_entry().from_hash(*h, |other| {
+            *h == other.hash && {
+                let l_idx = other.idx;
+                is_equal(l_idx as usize, r_idx)
+            }
+        }) {
+            for l_idx in indices {
+                left_idx.push(*l_idx);
+                right_idx.push(r_idx as u64);
+            }
+        }
+    }
+
+    let lseries = UInt64Array::from(("left_indices", left_idx)).into_series();
+    let rseries = UInt64Array::from(("right_indices", right_idx)).into_series();
+
+    Ok(if probe_left {
+        (lseries, rseries)
+    } else {
+        (rseries, lseries)
+    })
+}
+
 pub(super) fn hash_inner_join(
     left: &RecordBatch,
     right: &RecordBatch,
@@ -26,60 +91,7 @@ pub(super) fn hash_inner_join(
 
     let (lkeys, rkeys) = match_types_for_tables(&lkeys, &rkeys)?;
 
-    let (lidx, ridx) = if lkeys.columns.iter().any(|s| s.data_type().is_null())
-        || rkeys.columns.iter().any(|s| s.data_type().is_null())
-    {
-        (
-            UInt64Array::empty("left_indices", &DataType::UInt64).into_series(),
-            UInt64Array::empty("right_indices", &DataType::UInt64).into_series(),
-        )
-    } else {
-        // probe on the smaller table
-        let probe_left = lkeys.len() <= rkeys.len();
-
-        let (lkeys, rkeys) = if probe_left {
-            (&lkeys, &rkeys)
-        } else {
-            (&rkeys, &lkeys)
-        };
-
-        let probe_table = lkeys.to_probe_hash_table()?;
-
-        let r_hashes = rkeys.hash_rows()?;
-        use daft_core::array::ops::arrow2::comparison::build_multi_array_is_equal;
-        let is_equal = build_multi_array_is_equal(
-            lkeys.columns.as_slice(),
-            rkeys.columns.as_slice(),
-            null_equals_nulls,
-            vec![false; lkeys.columns.len()].as_slice(),
-        )?;
-
-        let mut left_idx = vec![];
-        let mut right_idx = vec![];
-
-        for (r_idx, h) in r_hashes.as_arrow().values_iter().enumerate() {
-            if let Some((_, indices)) = probe_table.raw_entry().from_hash(*h, |other| {
-                *h == other.hash && {
-                    let l_idx = other.idx;
-                    is_equal(l_idx as usize, r_idx)
-                }
-            }) {
-                for l_idx in indices {
-                    left_idx.push(*l_idx);
-                    right_idx.push(r_idx as u64);
-                }
-            }
-        }
-
-        let lseries = UInt64Array::from(("left_indices", left_idx)).into_series();
-        let rseries = UInt64Array::from(("right_indices", right_idx)).into_series();
-
-        if probe_left {
-            (lseries, rseries)
-        } else {
-            (rseries, lseries)
-        }
-    };
+    let (lidx, ridx) = hash_inner_join_indices(&lkeys, &rkeys, null_equals_nulls)?;
 
     let common_cols: Vec<_> = get_common_join_cols(&left.schema, &right.schema).collect();
 
@@ -2,7 +2,10 @@ use std::{collections::HashSet, sync::Arc};
 
 use common_error::{DaftError, DaftResult};
 use daft_core::{
-    array::growable::make_growable, join::JoinSide, prelude::*, utils::supertype::try_get_supertype,
+    array::growable::make_growable,
+    join::{AsofJoinDirection, JoinSide},
+    prelude::*,
+    utils::supertype::try_get_supertype,
 };
 use daft_dsl::{
     join::{get_common_join_cols, infer_join_schema},
@@ -12,8 +15,10 @@ use hash_join::hash_semi_anti_join;
 
 use self::hash_join::{hash_inner_join, hash_left_right_join, hash_outer_join};
 use crate::RecordBatch;
+mod asof_join;
 mod hash_join;
 mod merge_join;
+mod nested_loop_join;
 
 fn match_types_for_tables(
     left: &RecordBatch,
@@ -196,6 +201,167 @@ impl RecordBatch {
         Self::new_with_size(join_schema, join_series, num_rows)
     }
 
+    /// Left joins each row to at most one right row: among the right rows whose `by` keys equal those of the left row,
+    /// the one whose `on` key is nearest to that of the left row in the given direction, and within `tolerance` of it.
+    ///
+    /// If `right_sorted` is set, `right` must already be sorted by its `by` keys and then its `on` key, ascending with
+    /// nulls last, which saves sorting it again when it is joined against many left tables.
+    #[allow(clippy::too_many_arguments)]
+    pub fn asof_join(
+        &self,
+        right: &Self,
+        left_by: &[ExprRef],
+        right_by: &[ExprRef],
+        left_on: &ExprRef,
+        right_on: &ExprRef,
+        direction: AsofJoinDirection,
+        tolerance: Option<&ExprRef>,
+        right_sorted: bool,
+    ) -> DaftResult<Self> {
+        if left_by.len() != right_by.len() {
+            return Err(DaftError::ValueError(format!(
+                "Mismatch of join by clauses: left: {:?} vs right: {:?}",
+                left_by.len(),
+                right_by.len()
+            )));
+        }
+
+        let join_schema = infer_join_schema(&self.schema, &right.schema, JoinType::Left)?;
+        let lkeys = self.eval_expression_list(
+            &left_by
+                .iter()
+                .chain(std::iter::once(left_on))
+                .cloned()
+                .collect::<Vec<_>>(),
+        )?;
+        let rkeys = right.eval_expression_list(
+            &right_by
+                .iter()
+                .chain(std::iter::once(right_on))
+                .cloned()
+                .collect::<Vec<_>>(),
+        )?;
+        let (lkeys, cast_rkeys) = match_types_for_tables(&lkeys, &rkeys)?;
+        // Casting the keys to a common type may not preserve their order, e.g. from integers to strings.
+        let right_sorted = right_sorted
+            && rkeys
+                .columns
+                .iter()
+                .zip(cast_rkeys.columns.iter())
+                .all(|(before, after)| before.data_type() == after.data_type());
+        let rkeys = cast_rkeys;
+        let tolerance = tolerance
+            .map(|tolerance| {
+                let tolerance = self.eval_expression_list(std::slice::from_ref(tolerance))?;
+                Ok::<_, DaftError>(tolerance.get_column_by_index(0)?.clone())
+            })
+            .transpose()?;
+
+        let ridx = asof_join::asof_join_indices(
+            &lkeys,
+            &rkeys,
+            direction,
+            tolerance.as_ref(),
+            right_sorted,
+        )?;
+        let lidx =
+            UInt64Array::from(("left_indices", (0..(self.len() as u64)).collect::<Vec<_>>()))
+                .into_series();
+
+        drop(lkeys);
+        drop(rkeys);
+
+        // The join keys that are common to both sides are taken from the left side, since every left row is kept.
+        let join_series = get_common_join_cols(&self.schema, &right.schema)
+            .map(|name| self.get_column(name).cloned())
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        let num_rows = self.len();
+        let join_series = if right.is_empty() {
+            // There are no right rows to take, so all of the right columns are null.
+            let join_keys = join_series
+                .iter()
+                .map(|s| s.name().to_string())
+                .collect::<HashSet<_>>();
+            let mut join_series = join_series;
+            for field in self.schema.fields.values() {
+                if !join_keys.contains(&field.name) {
+                    join_series.push(self.get_column(&field.name)?.clone());
+                }
+            }
+            for field in right.schema.fields.values() {
+                if !join_keys.contains(&field.name) {
+                    join_series.push(Series::full_null(&field.name, &field.dtype, num_rows));
+                }
+            }
+            join_series
+        } else {
+            add_non_join_key_columns(self, right, lidx, ridx, join_series)?
+        };
+
+        Self::new_with_size(join_schema, join_series, num_rows)
+    }
+
+    /// Joins the pairs of rows whose join keys are equal and for which `predicate` holds,
+    /// where the columns of the predicate refer to either side of the join.
+    ///
+    /// Without join keys, if the predicate has a conjunct that compares an expression over the left side with one over the right side,
+    /// e.g. `left.a < right.b`, the right side is sorted on its expression so that each left row is only paired with the right rows
+    /// for which the comparison holds. Otherwise, the predicate is evaluated over every pair of rows.
+    #[allow(clippy::too_many_arguments)]
+    pub fn nested_loop_join(
+        &self,
+        right: &Self,
+        left_on: &[ExprRef],
+        right_on: &[ExprRef],
+        null_equals_nulls: &[bool],
+        predicate: &ExprRef,
+        how: JoinType,
+    ) -> DaftResult<Self> {
+        self.nested_loop_join_with_right_matches(
+            right,
+            left_on,
+            right_on,
+            null_equals_nulls,
+            predicate,
+            how,
+        )
+        .map(|(joined, _)| joined)
+    }
+
+    /// Like [`Self::nested_loop_join`], but also returns the indices of the rows of `right` that were matched,
+    /// so that the unmatched rows of `right` can be found across multiple left tables.
+    #[allow(clippy::too_many_arguments)]
+    pub fn nested_loop_join_with_right_matches(
+        &self,
+        right: &Self,
+        left_on: &[ExprRef],
+        right_on: &[ExprRef],
+        null_equals_nulls: &[bool],
+        predicate: &ExprRef,
+        how: JoinType,
+    ) -> DaftResult<(Self, Series)> {
+        if left_on.len() != right_on.len() {
+            return Err(DaftError::ValueError(format!(
+                "Mismatch of join on clauses: left: {:?} vs right: {:?}",
+                left_on.len(),
+                right_on.len()
+            )));
+        }
+
+        let (lidx, ridx) = nested_loop_join::nested_loop_join_indices(
+            self,
+            right,
+            left_on,
+            right_on,
+            null_equals_nulls,
+            predicate,
+        )?;
+        let right_matches = UInt64Array::from(("right_indices", ridx.clone())).into_series();
+        let joined = nested_loop_join::join_from_matches(self, right, lidx, ridx, how)?;
+        Ok((joined, right_matches))
+    }
+
     pub fn cross_join(&self, right: &Self, outer_loop_side: JoinSide) -> DaftResult<Self> {
         /// Create a new table by repeating each column of the input table `inner_len` times in a row, thus preserving sort order.
         fn create_outer_loop_table(
@@ -0,0 +1,510 @@
+use std::{cmp::Ordering, ops::Range, sync::Arc};
+
+use common_error::DaftResult;
+use daft_core::{
+    array::{growable::make_growable, ops::as_arrow::AsArrow},
+    join::JoinSide,
+    kernels::search_sorted::build_partial_compare_with_nulls,
+    prelude::*,
+};
+use daft_dsl::{
+    join::{
+        get_common_join_cols, get_join_predicate_columns, infer_join_schema, join_pair_column_name,
+        join_predicate_to_output_expr, join_predicate_to_pair_expr,
+    },
+    Expr, ExprRef, Operator,
+};
+
+use super::{add_non_join_key_columns, hash_join::hash_inner_join_indices, match_types_for_tables};
+use crate::RecordBatch;
+
+/// The maximum number of candidate pairs of rows that the join predicate is evaluated over at a time.
+const CANDIDATE_BATCH_SIZE: usize = 64 * 1024;
+
+/// A conjunct of a join predicate that compares an expression over the left side with one over the right side,
+/// oriented so that it reads `left <op> right`. Its expressions refer to the columns of their own side.
+struct RangeCondition {
+    left: ExprRef,
+    right: ExprRef,
+    op: Operator,
+}
+
+fn split_conjunction(expr: &ExprRef, conjuncts: &mut Vec<ExprRef>) {
+    match expr.as_ref() {
+        Expr::BinaryOp {
+            op: Operator::And,
+            left,
+            right,
+        } => {
+            split_conjunction(left, conjuncts);
+            split_conjunction(right, conjuncts);
+        }
+        _ => conjuncts.push(expr.clone()),
+    }
+}
+
+/// The side of the join whose columns an expression refers to, if it refers to the columns of only one side.
+fn expr_side(expr: &ExprRef) -> Option<JoinSide> {
+    let mut sides = get_join_predicate_columns(expr)
+        .into_iter()
+        .map(|(_, side)| side);
+    let side = sides.next()?;
+    sides.all(|s| s == side).then_some(side)
+}
+
+impl RangeCondition {
+    /// Whether the condition bounds the right expression from below, i.e. holds for a suffix of the right rows in sorted order.
+    fn is_lower_bound(&self) -> bool {
+        matches!(self.op, Operator::Lt | Operator::LtEq)
+    }
+}
+
+/// Finds the range conditions of a join predicate to narrow the candidate pairs of rows with.
+///
+/// If the predicate bounds a right expression from both sides, such as `r.y BETWEEN l.x - 5 AND l.x + 5`,
+/// both bounds are returned so that each left row only pairs with the right rows within the band.
+/// Otherwise, the first range condition is returned, if there is one.
+fn find_range_conditions(predicate: &ExprRef) -> Vec<RangeCondition> {
+    let mut conjuncts = vec![];
+    split_conjunction(predicate, &mut conjuncts);
+    let conditions = conjuncts
+        .iter()
+        .filter_map(to_range_condition)
+        .collect::<Vec<_>>();
+    let band = conditions.iter().enumerate().find_map(|(i, lower)| {
+        let upper = conditions.iter().position(|upper| {
+            lower.is_lower_bound() && !upper.is_lower_bound() && lower.right == upper.right
+        })?;
+        Some((i, upper))
+    });
+    match band {
+        Some((lower, upper)) => conditions
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| *i == lower || *i == upper)
+            .map(|(_, condition)| condition)
+            .collect(),
+        None => conditions.into_iter().take(1).collect(),
+    }
+}
+
+fn to_range_condition(conjunct: &ExprRef) -> Option<RangeCondition> {
+    let Expr::BinaryOp { op, left, right } = conjunct.as_ref() else {
+        return None;
+    };
+    let flipped_op = match op {
+        Operator::Lt => Operator::Gt,
+        Operator::LtEq => Operator::GtEq,
+        Operator::Gt => Operator::Lt,
+        Operator::GtEq => Operator::LtEq,
+        _ => return None,
+    };
+    match (expr_side(left)?, expr_side(right)?) {
+        (JoinSide::Left, JoinSide::Right) => Some(RangeCondition {
+            left: join_predicate_to_output_expr(left.clone()),
+            right: join_predicate_to_output_expr(right.clone()),
+            op: *op,
+        }),
+        (JoinSide::Right, JoinSide::Left) => Some(RangeCondition {
+            left: join_predicate_to_output_expr(right.clone()),
+            right: join_predicate_to_output_expr(left.clone()),
+            op: flipped_op,
+        }),
+        _ => None,
+    }
+}
+
+/// The first index in `0..len` for which `pred` does not hold, where `pred` holds for a prefix of the indices.
+fn partition_point(len: usize, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut lo, mut hi) = (0, len);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Sorts the right side by the right expression of the range conditions, which they share, and finds for each left row
+/// the range of positions in the sorted order of the right rows for which all of the conditions hold.
+///
+/// Returns the argsort of the right side along with the range for each left row.
+fn range_condition_candidates(
+    left: &RecordBatch,
+    right: &RecordBatch,
+    conditions: &[RangeCondition],
+) -> DaftResult<(Series, Vec<Range<usize>>)> {
+    let mut right_argsort: Option<(Series, DataType)> = None;
+    let mut ranges = vec![0..right.len(); left.len()];
+    for condition in conditions {
+        let lkeys = left.eval_expression_list(std::slice::from_ref(&condition.left))?;
+        let rkeys = right.eval_expression_list(std::slice::from_ref(&condition.right))?;
+        let (lkeys, rkeys) = match_types_for_tables(&lkeys, &rkeys)?;
+        let lkey = lkeys.get_column_by_index(0)?;
+        let rkey = rkeys.get_column_by_index(0)?;
+
+        // Nulls sort last, so the right rows with a non-null key are a prefix of the sorted order. The conditions share
+        // the right expression, but casting it to a common type with a left key can change its order, e.g. integers
+        // cast to strings sort as strings. So only the conditions whose right key has the type that the right side was
+        // sorted by narrow the candidates. The join predicate is evaluated over every candidate pair either way.
+        let right_argsort = match &right_argsort {
+            Some((argsort, dtype)) if dtype == rkey.data_type() => argsort,
+            Some(_) => continue,
+            None => {
+                &right_argsort
+                    .insert((rkey.argsort(false, false)?, rkey.data_type().clone()))
+                    .0
+            }
+        };
+        if lkey.data_type().is_null() {
+            return Ok((right_argsort.clone(), vec![0..0; left.len()]));
+        }
+        let rkey_sorted = rkey.take(right_argsort)?;
+        let num_valid = rkey_sorted.len() - rkey_sorted.validity().map_or(0, |v| v.unset_bits());
+
+        let compare = build_partial_compare_with_nulls(
+            lkey.as_physical()?.to_arrow().as_ref(),
+            rkey_sorted.as_physical()?.to_arrow().as_ref(),
+            false,
+        )?;
+        for (l, range) in ranges.iter_mut().enumerate() {
+            if range.start >= range.end {
+                continue;
+            }
+            if !lkey.is_valid(l) {
+                *range = 0..0;
+                continue;
+            }
+            // The ordering of the left key relative to the right keys goes from greater to less along the sorted order.
+            let ordering = |r: usize| compare(l, r).expect("keys should be non-null");
+            let bound = match condition.op {
+                Operator::Lt => {
+                    partition_point(num_valid, |r| ordering(r) != Ordering::Less)..num_valid
+                }
+                Operator::LtEq => {
+                    partition_point(num_valid, |r| ordering(r) == Ordering::Greater)..num_valid
+                }
+                Operator::Gt => 0..partition_point(num_valid, |r| ordering(r) == Ordering::Greater),
+                Operator::GtEq => 0..partition_point(num_valid, |r| ordering(r) != Ordering::Less),
+                _ => unreachable!("range conditions only use comparison operators"),
+            };
+            *range = range.start.max(bound.start)..range.end.min(bound.end);
+        }
+    }
+    let (right_argsort, _) = right_argsort.expect("there should be at least one range condition");
+    Ok((right_argsort, ranges))
+}
+
+/// Evaluates a join predicate over candidate pairs of rows in batches, keeping the pairs for which it holds.
+struct PairFilter<'a> {
+    left: &'a RecordBatch,
+    right: &'a RecordBatch,
+    columns: Vec<(Arc<str>, JoinSide)>,
+    predicate: ExprRef,
+    candidates: (Vec<u64>, Vec<u64>),
+    matches: (Vec<u64>, Vec<u64>),
+}
+
+impl<'a> PairFilter<'a> {
+    fn new(left: &'a RecordBatch, right: &'a RecordBatch, predicate: &ExprRef) -> Self {
+        Self {
+            left,
+            right,
+            columns: get_join_predicate_columns(predicate).into_iter().collect(),
+            predicate: join_predicate_to_pair_expr(predicate.clone()),
+            candidates: Default::default(),
+            matches: Default::default(),
+        }
+    }
+
+    fn push(&mut self, l: u64, r: u64) -> DaftResult<()> {
+        self.candidates.0.push(l);
+        self.candidates.1.push(r);
+        if self.candidates.0.len() >= CANDIDATE_BATCH_SIZE {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> DaftResult<()> {
+        if self.candidates.0.is_empty() {
+            return Ok(());
+        }
+        let lidx = UInt64Array::from(("left_indices", std::mem::take(&mut self.candidates.0)))
+            .into_series();
+        let ridx = UInt64Array::from(("right_indices", std::mem::take(&mut self.candidates.1)))
+            .into_series();
+        let num_pairs = lidx.len();
+
+        let pair_columns = self
+            .columns
+            .iter()
+            .map(|(name, side)| {
+                let (table, idx) = match side {
+                    JoinSide::Left => (self.left, &lidx),
+                    JoinSide::Right => (self.right, &ridx),
+                };
+                Ok(table
+                    .get_column(name)?
+                    .take(idx)?
+                    .rename(join_pair_column_name(name, *side)))
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let pair_schema = Schema::new(pair_columns.iter().map(|s| s.field().clone()).collect())?;
+        let pairs = RecordBatch::new_with_size(pair_schema, pair_columns, num_pairs)?;
+        let mask = pairs.eval_expression(&self.predicate)?;
+
+        let matched = RecordBatch::from_nonempty_columns(vec![lidx, ridx])?.mask_filter(&mask)?;
+        self.matches.0.extend(
+            matched
+                .get_column_by_index(0)?
+                .u64()?
+                .as_arrow()
+                .values_iter(),
+        );
+        self.matches.1.extend(
+            matched
+                .get_column_by_index(1)?
+                .u64()?
+                .as_arrow()
+                .values_iter(),
+        );
+        Ok(())
+    }
+
+    fn finish(mut self) -> DaftResult<(Vec<u64>, Vec<u64>)> {
+        self.flush()?;
+        Ok(self.matches)
+    }
+}
+
+/// Computes the left and right take indices of the pairs of rows whose join keys are equal and for which the predicate holds.
+///
+/// The predicate is evaluated over candidate pairs of rows, which are the pairs with equal join keys if there are any,
+/// or else the pairs for which the range conditions of the predicate hold if it has any. Otherwise, every pair of rows is a candidate.
+pub(super) fn nested_loop_join_indices(
+    left: &RecordBatch,
+    right: &RecordBatch,
+    left_on: &[ExprRef],
+    right_on: &[ExprRef],
+    null_equals_nulls: &[bool],
+    predicate: &ExprRef,
+) -> DaftResult<(Vec<u64>, Vec<u64>)> {
+    let mut filter = PairFilter::new(left, right, predicate);
+    if left.is_empty() || right.is_empty() {
+        return filter.finish();
+    }
+
+    let range_conditions = find_range_conditions(predicate);
+    if !left_on.is_empty() {
+        let lkeys = left.eval_expression_list(left_on)?;
+        let rkeys = right.eval_expression_list(right_on)?;
+        let (lkeys, rkeys) = match_types_for_tables(&lkeys, &rkeys)?;
+        let (lidx, ridx) = hash_inner_join_indices(&lkeys, &rkeys, null_equals_nulls)?;
+        for (l, r) in lidx
+            .u64()?
+            .as_arrow()
+            .values_iter()
+            .zip(ridx.u64()?.as_arrow().values_iter())
+        {
+            filter.push(*l, *r)?;
+        }
+    } else if !range_conditions.is_empty() {
+        let (right_argsort, ranges) = range_condition_candidates(left, right, &range_conditions)?;
+        let right_argsort = right_argsort.u64()?.as_arrow();
+        for (l, range) in ranges.into_iter().enumerate() {
+            for pos in range {
+                filter.push(l as u64, right_argsort.value(pos))?;
+            }
+        }
+    } else {
+        for l in 0..left.len() as u64 {
+            for r in 0..right.len() as u64 {
+                filter.push(l, r)?;
+            }
+        }
+    }
+    filter.finish()
+}
+
+/// Assembles the output of a join from the take indices of its matched pairs of rows,
+/// adding the unmatched rows of either side that the join type keeps.
+pub(super) fn join_from_matches(
+    left: &RecordBatch,
+    right: &RecordBatch,
+    left_matches: Vec<u64>,
+    right_matches: Vec<u64>,
+    how: JoinType,
+) -> DaftResult<RecordBatch> {
+    let mut left_matched = vec![false; left.len()];
+    for l in &left_matches {
+        left_matched[*l as usize] = true;
+    }
+
+    if matches!(how, JoinType::Semi | JoinType::Anti) {
+        let keep_matched = how == JoinType::Semi;
+        let mask = left_matched
+            .into_iter()
+            .map(|matched| matched == keep_matched)
+            .collect::<Vec<_>>();
+        return left.mask_filter(&BooleanArray::from(("mask", mask.as_slice())).into_series());
+    }
+
+    let mut lidx = left_matches.into_iter().map(Some).collect::<Vec<_>>();
+    let mut ridx = right_matches.into_iter().map(Some).collect::<Vec<_>>();
+    if matches!(how, JoinType::Right | JoinType::Outer) {
+        let mut right_matched = vec![false; right.len()];
+        for r in ridx.iter().flatten() {
+            right_matched[*r as usize] = true;
+        }
+        for (r, _) in right_matched.iter().enumerate().filter(|(_, m)| !**m) {
+            lidx.push(None);
+            ridx.push(Some(r as u64));
+        }
+    }
+    if matches!(how, JoinType::Left | JoinType::Outer) {
+        for (l, _) in left_matched.iter().enumerate().filter(|(_, m)| !**m) {
+            lidx.push(Some(l as u64));
+            ridx.push(None);
+        }
+    }
+    let lidx = UInt64Array::from_iter(
+        Field::new("left_indices", DataType::UInt64),
+        lidx.into_iter(),
+    )
+    .into_series();
+    let ridx = UInt64Array::from_iter(
+        Field::new("right_indices", DataType::UInt64),
+        ridx.into_iter(),
+    )
+    .into_series();
+
+    let join_schema = infer_join_schema(&left.schema, &right.schema, how)?;
+    // The columns common to both sides are join keys, so they are taken from whichever side of each row is present.
+    let join_series = get_common_join_cols(&left.schema, &right.schema)
+        .map(|name| {
+            let dtype = &join_schema.get_field(name)?.dtype;
+            let lcol = left.get_column(name)?.cast(dtype)?;
+            let rcol = right.get_column(name)?.cast(dtype)?;
+
+            let mut growable = make_growable(name, dtype, vec![&lcol, &rcol], false, lidx.len());
+            for (li, ri) in lidx.u64()?.into_iter().zip(ridx.u64()?) {
+                match (li, ri) {
+                    (Some(i), _) => growable.extend(0, *i as usize, 1),
+                    (None, Some(i)) => growable.extend(1, *i as usize, 1),
+                    (None, None) => unreachable!("Join should not have None for both sides"),
+                }
+            }
+
+            growable.build()
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+
+    let num_rows = lidx.len();
+    let join_series = add_non_join_key_columns(left, right, lidx, ridx, join_series)?;
+
+    RecordBatch::new_with_size(join_schema, join_series, num_rows)
+}
+
+#[cfg(test)]
+mod test {
+    use common_error::DaftResult;
+    use daft_core::{join::JoinSide, prelude::*};
+    use daft_dsl::{binary_op, join_side_col, lit, Operator};
+
+    use super::{find_range_conditions, nested_loop_join_indices, range_condition_candidates};
+    use crate::RecordBatch;
+
+    #[test]
+    fn test_band_predicate_bounds_candidates() -> DaftResult<()> {
+        const NUM_ROWS: i64 = 1000;
+        const WIDTH: i64 = 5;
+        let left = RecordBatch::from_nonempty_columns(vec![Int64Array::from((
+            "x",
+            (0..NUM_ROWS).collect::<Vec<_>>(),
+        ))
+        .into_series()])?;
+        // The right keys are in reverse order so that the candidates depend on the sort.
+        let right = RecordBatch::from_nonempty_columns(vec![Int64Array::from((
+            "y",
+            (0..NUM_ROWS).rev().collect::<Vec<_>>(),
+        ))
+        .into_series()])?;
+
+        // right.y BETWEEN left.x - 5 AND left.x + 5
+        let x = join_side_col("x", JoinSide::Left);
+        let y = join_side_col("y", JoinSide::Right);
+        let predicate = y
+            .clone()
+            .gt_eq(binary_op(Operator::Minus, x.clone(), lit(WIDTH)))
+            .and(y.lt_eq(binary_op(Operator::Plus, x, lit(WIDTH))));
+
+        let conditions = find_range_conditions(&predicate);
+        assert_eq!(conditions.len(), 2);
+        let (_, ranges) = range_condition_candidates(&left, &right, &conditions)?;
+        let num_candidates = ranges.iter().map(|range| range.len()).sum::<usize>();
+        assert!(num_candidates <= ((2 * WIDTH + 1) * NUM_ROWS) as usize);
+
+        let (lidx, ridx) = nested_loop_join_indices(&left, &right, &[], &[], &[], &predicate)?;
+        let mut pairs = lidx
+            .into_iter()
+            .zip(ridx)
+            .map(|(l, r)| (l as i64, NUM_ROWS - 1 - r as i64))
+            .collect::<Vec<_>>();
+        pairs.sort_unstable();
+        let expected = (0..NUM_ROWS)
+            .flat_map(|x| ((x - WIDTH).max(0)..=(x + WIDTH).min(NUM_ROWS - 1)).map(move |y| (x, y)))
+            .collect::<Vec<_>>();
+        assert_eq!(pairs, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_conditions_with_different_cast_types() -> DaftResult<()> {
+        const NUM_LEFT: i32 = 200;
+        const NUM_RIGHT: i32 = 300;
+        let left = RecordBatch::from_nonempty_columns(vec![
+            Int64Array::from(("x", (0..NUM_LEFT as i64).collect::<Vec<_>>())).into_series(),
+            Float64Array::from((
+                "f",
+                (0..NUM_LEFT).map(|x| x as f64 / 2.0).collect::<Vec<_>>(),
+            ))
+            .into_series(),
+        ])?;
+        let right = RecordBatch::from_nonempty_columns(vec![Int32Array::from((
+            "y",
+            (0..NUM_RIGHT).rev().collect::<Vec<_>>(),
+        ))
+        .into_series()])?;
+
+        // right.y >= left.f AND right.y <= left.x + 5, which cast the right key to different types
+        let x = join_side_col("x", JoinSide::Left);
+        let f = join_side_col("f", JoinSide::Left);
+        let y = join_side_col("y", JoinSide::Right);
+        let predicate = y
+            .clone()
+            .gt_eq(f)
+            .and(y.lt_eq(binary_op(Operator::Plus, x, lit(5))));
+        assert_eq!(find_range_conditions(&predicate).len(), 2);
+
+        let (lidx, ridx) = nested_loop_join_indices(&left, &right, &[], &[], &[], &predicate)?;
+        let mut pairs = lidx
+            .into_iter()
+            .zip(ridx)
+            .map(|(l, r)| (l as i32, NUM_RIGHT - 1 - r as i32))
+            .collect::<Vec<_>>();
+        pairs.sort_unstable();
+        let expected = (0..NUM_LEFT)
+            .flat_map(|x| {
+                (0..NUM_RIGHT)
+                    .filter(move |y| f64::from(*y) >= f64::from(x) / 2.0 && *y <= x + 5)
+                    .map(move |y| (x, y))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(pairs, expected);
+        Ok(())
+    }
+}
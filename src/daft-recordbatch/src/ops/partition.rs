@@ -54,6 +54,26 @@ impl RecordBatch {
         &self,
         exprs: &[ExprRef],
         num_partitions: usize,
+    ) -> DaftResult<Vec<Self>> {
+        self.partition_by_seeded_hash(exprs, num_partitions, None)
+    }
+
+    /// Like [`Self::partition_by_hash`], but hashes the rows with `seed`, so that rows that share a
+    /// partition under one seed are spread over the partitions under another.
+    pub fn partition_by_hash_with_seed(
+        &self,
+        exprs: &[ExprRef],
+        num_partitions: usize,
+        seed: u64,
+    ) -> DaftResult<Vec<Self>> {
+        self.partition_by_seeded_hash(exprs, num_partitions, Some(seed))
+    }
+
+    fn partition_by_seeded_hash(
+        &self,
+        exprs: &[ExprRef],
+        num_partitions: usize,
+        seed: Option<u64>,
     ) -> DaftResult<Vec<Self>> {
         if num_partitions == 0 {
             return Err(DaftError::ValueError(
@@ -61,13 +81,15 @@ impl RecordBatch {
             ));
         }
 
-        let targets = self
-            .eval_expression_list(exprs)?
-            .hash_rows()?
-            .rem(&UInt64Array::from((
-                "num_partitions",
-                [num_partitions as u64].as_slice(),
-            )))?;
+        let keys = self.eval_expression_list(exprs)?;
+        let hashes = match seed {
+            Some(seed) => keys.hash_rows_with_seed(seed)?,
+            None => keys.hash_rows()?,
+        };
+        let targets = hashes.rem(&UInt64Array::from((
+            "num_partitions",
+            [num_partitions as u64].as_slice(),
+        )))?;
         self.partition_by_index(&targets, num_partitions)
     }
 
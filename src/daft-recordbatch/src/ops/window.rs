@@ -0,0 +1,1065 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    ops::Range,
+};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    array::ops::IntoGroups,
+    datatypes::{try_sum_supertype, DaftPrimitiveType},
+    prelude::*,
+};
+use daft_dsl::{
+    AggExpr, Expr, ExprRef, WindowBoundary, WindowExpr, WindowFrame, WindowFrameType, WindowSpec,
+};
+
+use crate::RecordBatch;
+
+/// Maximum number of row indices gathered at once when evaluating aggregations over arbitrary window frames.
+const MAX_FRAME_INDICES_PER_CHUNK: usize = 1 << 20;
+
+fn as_window_function(expr: &Expr) -> DaftResult<(&WindowExpr, &WindowSpec)> {
+    match expr {
+        Expr::Over(window_expr, window_spec) => Ok((window_expr, window_spec)),
+        Expr::Alias(inner, _) => as_window_function(inner),
+        _ => Err(DaftError::ValueError(format!(
+            "Expected a window function, but received {expr}"
+        ))),
+    }
+}
+
+/// Splits a table into runs of consecutive rows with equal values of `keys`.
+/// Returns the runs, along with the index of the run that each row belongs to.
+fn runs_of_equal_keys(
+    table: &RecordBatch,
+    keys: &[ExprRef],
+) -> DaftResult<(Vec<Range<usize>>, Vec<usize>)> {
+    let num_rows = table.len();
+    if keys.is_empty() {
+        let all_rows = 0..num_rows;
+        return Ok((vec![all_rows], vec![0; num_rows]));
+    }
+    let (_, groups) = table.eval_expression_list(keys)?.make_groups()?;
+    let mut group_of_row = vec![0; num_rows];
+    for (group_idx, indices) in groups.iter().enumerate() {
+        for &row in indices {
+            group_of_row[row as usize] = group_idx;
+        }
+    }
+
+    let mut runs = vec![];
+    let mut run_of_row = Vec::with_capacity(num_rows);
+    let mut start = 0;
+    for row in 0..num_rows {
+        if row > 0 && group_of_row[row] != group_of_row[row - 1] {
+            runs.push(start..row);
+            start = row;
+        }
+        run_of_row.push(runs.len());
+    }
+    runs.push(start..num_rows);
+    Ok((runs, run_of_row))
+}
+
+/// Returns the first index in `range` for which `pred` is false, assuming `pred` is true for a prefix of `range`.
+fn partition_point(range: Range<usize>, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut lo, mut hi) = (range.start, range.end);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// A table sorted by the partition by and order by keys of a window, along with its partitions and peer groups.
+struct SortedWindow {
+    table: RecordBatch,
+    /// Indices of the rows of the sorted table in the original table, or `None` if the table did not need sorting.
+    argsort: Option<Series>,
+    partitions: Vec<Range<usize>>,
+    partition_of_row: Vec<usize>,
+    /// Runs of rows with equal partition by and order by keys.
+    peers: Vec<Range<usize>>,
+    peers_of_row: Vec<usize>,
+}
+
+impl SortedWindow {
+    fn try_new(table: &RecordBatch, window_spec: &WindowSpec) -> DaftResult<Self> {
+        let sort_keys = window_spec
+            .partition_by
+            .iter()
+            .chain(window_spec.order_by.iter())
+            .cloned()
+            .collect::<Vec<_>>();
+        let (table, argsort) = if sort_keys.is_empty() {
+            (table.clone(), None)
+        } else {
+            let num_partition_by = window_spec.partition_by.len();
+            let descending = std::iter::repeat(false)
+                .take(num_partition_by)
+                .chain(window_spec.descending.iter().copied())
+                .collect::<Vec<_>>();
+            let nulls_first = std::iter::repeat(false)
+                .take(num_partition_by)
+                .chain(window_spec.nulls_first.iter().copied())
+                .collect::<Vec<_>>();
+            let argsort = table.argsort(&sort_keys, &descending, &nulls_first)?;
+            (table.take(&argsort)?, Some(argsort))
+        };
+        let (partitions, partition_of_row) = runs_of_equal_keys(&table, &window_spec.partition_by)?;
+        let (peers, peers_of_row) = runs_of_equal_keys(&table, &sort_keys)?;
+        Ok(Self {
+            table,
+            argsort,
+            partitions,
+            partition_of_row,
+            peers,
+            peers_of_row,
+        })
+    }
+
+    fn partition(&self, row: usize) -> Range<usize> {
+        self.partitions[self.partition_of_row[row]].clone()
+    }
+
+    fn peers(&self, row: usize) -> Range<usize> {
+        self.peers[self.peers_of_row[row]].clone()
+    }
+
+    /// Restores the original row order of a column computed over the sorted table.
+    fn unsort(&self, series: Series) -> DaftResult<Series> {
+        let Some(argsort) = &self.argsort else {
+            return Ok(series);
+        };
+        let mut inverse = vec![0; argsort.len()];
+        for (sorted_idx, original_idx) in argsort.u64()?.as_arrow().values_iter().enumerate() {
+            inverse[*original_idx as usize] = sorted_idx as u64;
+        }
+        series.take(&UInt64Array::from(("", inverse)).into_series())
+    }
+
+    fn eval(&self, window_expr: &WindowExpr, window_spec: &WindowSpec) -> DaftResult<Series> {
+        let num_rows = self.table.len();
+        match window_expr {
+            WindowExpr::RowNumber => {
+                let row_numbers = (0..num_rows)
+                    .map(|row| (row - self.partition(row).start + 1) as u64)
+                    .collect::<Vec<_>>();
+                Ok(UInt64Array::from(("row_number", row_numbers)).into_series())
+            }
+            WindowExpr::Rank => {
+                let ranks = (0..num_rows)
+                    .map(|row| (self.peers(row).start - self.partition(row).start + 1) as u64)
+                    .collect::<Vec<_>>();
+                Ok(UInt64Array::from(("rank", ranks)).into_series())
+            }
+            WindowExpr::DenseRank => {
+                let mut dense_ranks = Vec::with_capacity(num_rows);
+                let mut dense_rank = 0;
+                for row in 0..num_rows {
+                    if row == self.partition(row).start {
+                        dense_rank = 1;
+                    } else if row == self.peers(row).start {
+                        dense_rank += 1;
+                    }
+                    dense_ranks.push(dense_rank);
+                }
+                Ok(UInt64Array::from(("dense_rank", dense_ranks)).into_series())
+            }
+            WindowExpr::Offset {
+                input,
+                offset,
+                default,
+            } => {
+                let values = self.table.eval_expression(input)?;
+                let mut indices = Vec::with_capacity(num_rows);
+                let mut in_partition = Vec::with_capacity(num_rows);
+                for row in 0..num_rows {
+                    let partition = self.partition(row);
+                    // computed in i128 so that offsets near the ends of the i64 range can't overflow
+                    let target = row as i128 + i128::from(*offset);
+                    if target >= partition.start as i128 && target < partition.end as i128 {
+                        indices.push(target as u64);
+                        in_partition.push(true);
+                    } else {
+                        indices.push(row as u64);
+                        in_partition.push(false);
+                    }
+                }
+                let shifted = values.take(&UInt64Array::from(("", indices)).into_series())?;
+                let default = match default {
+                    Some(default) => self.table.eval_expression(default)?,
+                    None => Series::full_null(values.name(), values.data_type(), 1),
+                };
+                let predicate = BooleanArray::from(("", in_partition.as_slice())).into_series();
+                Ok(shifted.if_else(&default, &predicate)?.rename(values.name()))
+            }
+            WindowExpr::Agg(AggExpr::MapGroups { .. }) => Err(DaftError::ValueError(
+                "UDF aggregations cannot be used as window functions".to_string(),
+            )),
+            WindowExpr::Agg(agg_expr) => {
+                let frame = window_spec.effective_frame();
+                if frame.start == WindowBoundary::UnboundedPreceding
+                    && frame.end == WindowBoundary::UnboundedFollowing
+                {
+                    // Aggregate each partition once and broadcast the result to all of its rows.
+                    let groups = self
+                        .partitions
+                        .iter()
+                        .map(|partition| {
+                            (partition.start as u64..partition.end as u64).collect::<Vec<_>>()
+                        })
+                        .collect::<Vec<_>>();
+                    let aggregated = self.table.eval_agg_expression(agg_expr, Some(&groups))?;
+                    let partition_indices = self
+                        .partition_of_row
+                        .iter()
+                        .map(|idx| *idx as u64)
+                        .collect::<Vec<_>>();
+                    return aggregated
+                        .take(&UInt64Array::from(("", partition_indices)).into_series());
+                }
+
+                let frames = self.frames(&frame, window_spec)?;
+                let incremental = if frame.start == WindowBoundary::UnboundedPreceding {
+                    self.eval_running_agg(agg_expr, &frames)?
+                } else {
+                    self.eval_sliding_agg(agg_expr, &frames)?
+                };
+                match incremental {
+                    Some(result) => Ok(result),
+                    None => self.eval_agg_over_frames(agg_expr, &frames),
+                }
+            }
+        }
+    }
+
+    /// Computes the range of rows in the window frame of each row.
+    fn frames(
+        &self,
+        frame: &WindowFrame,
+        window_spec: &WindowSpec,
+    ) -> DaftResult<Vec<Range<usize>>> {
+        let num_rows = self.table.len();
+        let frames = match frame.frame_type {
+            WindowFrameType::Rows => (0..num_rows)
+                .map(|row| {
+                    let partition = self.partition(row);
+                    // positions are computed in i128 so that offsets near the ends of the i64
+                    // range can't overflow
+                    let position = |offset: i64| row as i128 + i128::from(offset);
+                    let clamp = |pos: i128| {
+                        pos.clamp(partition.start as i128, partition.end as i128) as usize
+                    };
+                    let start = match frame.start {
+                        WindowBoundary::UnboundedPreceding => partition.start,
+                        WindowBoundary::Offset(offset) => clamp(position(offset)),
+                        WindowBoundary::UnboundedFollowing => partition.end,
+                    };
+                    let end = match frame.end {
+                        WindowBoundary::UnboundedPreceding => partition.start,
+                        WindowBoundary::Offset(offset) => clamp(position(offset) + 1),
+                        WindowBoundary::UnboundedFollowing => partition.end,
+                    };
+                    start..end.max(start)
+                })
+                .collect(),
+            WindowFrameType::Range => {
+                let has_value_offset = [frame.start, frame.end]
+                    .iter()
+                    .any(|bound| matches!(bound, WindowBoundary::Offset(offset) if *offset != 0));
+                let descending = window_spec.descending.first().copied().unwrap_or(false);
+                if !has_value_offset {
+                    return Ok(self.range_frames::<i128>(frame, None, descending, |key, _| key));
+                }
+                let [order_by] = window_spec.order_by.as_slice() else {
+                    return Err(DaftError::ValueError(format!(
+                        "Range window frames with offsets require exactly one order by expression, but received {}",
+                        window_spec.order_by.len()
+                    )));
+                };
+                // The order by values are compared in their own type, where integers are widened
+                // losslessly so that adding an offset can't overflow.
+                let values = self.table.eval_expression(order_by)?;
+                match values.data_type() {
+                    DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+                        let values = values.cast(&DataType::Int64)?;
+                        let values = values.i64()?;
+                        let keys = (0..num_rows)
+                            .map(|row| values.get(row).map(i128::from))
+                            .collect::<Vec<_>>();
+                        self.range_frames(frame, Some(&keys), descending, |key, offset| {
+                            key + offset
+                        })
+                    }
+                    DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+                        let values = values.cast(&DataType::UInt64)?;
+                        let values = values.u64()?;
+                        let keys = (0..num_rows)
+                            .map(|row| values.get(row).map(i128::from))
+                            .collect::<Vec<_>>();
+                        self.range_frames(frame, Some(&keys), descending, |key, offset| {
+                            key + offset
+                        })
+                    }
+                    DataType::Float32 => {
+                        let values = values.f32()?;
+                        let keys = (0..num_rows).map(|row| values.get(row)).collect::<Vec<_>>();
+                        self.range_frames(frame, Some(&keys), descending, |key, offset| {
+                            key + offset as f32
+                        })
+                    }
+                    DataType::Float64 => {
+                        let values = values.f64()?;
+                        let keys = (0..num_rows).map(|row| values.get(row)).collect::<Vec<_>>();
+                        self.range_frames(frame, Some(&keys), descending, |key, offset| {
+                            key + offset as f64
+                        })
+                    }
+                    _ => {
+                        return Err(DaftError::TypeError(format!(
+                            "Range window frames with offsets require a numeric order by expression, but received {}",
+                            values.field()
+                        )));
+                    }
+                }
+            }
+        };
+        Ok(frames)
+    }
+
+    /// Computes the range frame of each row from the order by values in `keys`, which are only
+    /// needed if the frame has offsets. `shift` adds a signed offset to a key.
+    fn range_frames<K: Copy + PartialOrd>(
+        &self,
+        frame: &WindowFrame,
+        keys: Option<&[Option<K>]>,
+        descending: bool,
+        shift: impl Fn(K, i128) -> K,
+    ) -> Vec<Range<usize>> {
+        let key = |row: usize| keys.and_then(|keys| keys[row]);
+        // Whether a key is sorted before the bound, and whether it is sorted at or before it.
+        let before = |key: K, bound: K| if descending { key > bound } else { key < bound };
+        let not_after = |key: K, bound: K| {
+            if descending {
+                key >= bound
+            } else {
+                key <= bound
+            }
+        };
+        // Following rows have greater keys in ascending order, and smaller keys in descending order.
+        let bound = |key: K, offset: i64| {
+            let offset = i128::from(offset);
+            shift(key, if descending { -offset } else { offset })
+        };
+
+        (0..self.table.len())
+            .map(|row| {
+                let partition = self.partition(row);
+                let peers = self.peers(row);
+                // Null keys are sorted to one end of the partition and are only peers of each other.
+                let row_key = key(row);
+                let non_null = || {
+                    let start = partition_point(partition.clone(), |j| key(j).is_none());
+                    let end = partition_point(start..partition.end, |j| key(j).is_some());
+                    start..end
+                };
+                let start = match (frame.start, row_key) {
+                    (WindowBoundary::UnboundedPreceding, _) => partition.start,
+                    (WindowBoundary::Offset(0), _) | (WindowBoundary::Offset(_), None) => {
+                        peers.start
+                    }
+                    (WindowBoundary::Offset(offset), Some(row_key)) => {
+                        let bound = bound(row_key, offset);
+                        partition_point(non_null(), |j| before(key(j).unwrap(), bound))
+                    }
+                    (WindowBoundary::UnboundedFollowing, _) => partition.end,
+                };
+                let end = match (frame.end, row_key) {
+                    (WindowBoundary::UnboundedPreceding, _) => partition.start,
+                    (WindowBoundary::Offset(0), _) | (WindowBoundary::Offset(_), None) => peers.end,
+                    (WindowBoundary::Offset(offset), Some(row_key)) => {
+                        let bound = bound(row_key, offset);
+                        partition_point(non_null(), |j| not_after(key(j).unwrap(), bound))
+                    }
+                    (WindowBoundary::UnboundedFollowing, _) => partition.end,
+                };
+                start..end.max(start)
+            })
+            .collect()
+    }
+
+    /// Computes sums, counts, means, minimums and maximums over frames that start at the start of their partition by keeping
+    /// a running total per partition. Returns `None` for aggregations that are not supported by this fast path.
+    fn eval_running_agg(
+        &self,
+        agg_expr: &AggExpr,
+        frames: &[Range<usize>],
+    ) -> DaftResult<Option<Series>> {
+        match agg_expr {
+            AggExpr::Count(input, mode) => {
+                let values = self.table.eval_expression(input)?;
+                let is_valid = values.not_null()?.cast(&DataType::UInt64)?;
+                let (valid_counts, _) =
+                    self.running_sums(is_valid.u64()?, frames, u64::wrapping_add);
+                let counts = frames
+                    .iter()
+                    .zip(valid_counts)
+                    .map(|(frame, valid_count)| (frame, valid_count.unwrap_or(0)))
+                    .map(|(frame, valid_count)| match mode {
+                        CountMode::All => frame.len() as u64,
+                        CountMode::Valid => valid_count,
+                        CountMode::Null => frame.len() as u64 - valid_count,
+                    })
+                    .collect::<Vec<_>>();
+                Ok(Some(
+                    UInt64Array::from((values.name(), counts)).into_series(),
+                ))
+            }
+            AggExpr::Sum(input) => {
+                let values = self.table.eval_expression(input)?;
+                let name = values.name().to_string();
+                let series = match try_sum_supertype(values.data_type())? {
+                    DataType::Int64 => {
+                        let values = values.cast(&DataType::Int64)?;
+                        let (sums, _) = self.running_sums(values.i64()?, frames, i64::wrapping_add);
+                        Int64Array::from_regular_iter(
+                            Field::new(name, DataType::Int64),
+                            sums.into_iter(),
+                        )?
+                        .into_series()
+                    }
+                    DataType::UInt64 => {
+                        let values = values.cast(&DataType::UInt64)?;
+                        let (sums, _) = self.running_sums(values.u64()?, frames, u64::wrapping_add);
+                        UInt64Array::from_regular_iter(
+                            Field::new(name, DataType::UInt64),
+                            sums.into_iter(),
+                        )?
+                        .into_series()
+                    }
+                    DataType::Float64 => {
+                        let values = values.cast(&DataType::Float64)?;
+                        let (sums, _) = self.running_sums(values.f64()?, frames, |a, b| a + b);
+                        Float64Array::from_regular_iter(
+                            Field::new(name, DataType::Float64),
+                            sums.into_iter(),
+                        )?
+                        .into_series()
+                    }
+                    _ => return Ok(None),
+                };
+                Ok(Some(series))
+            }
+            AggExpr::Mean(input) => {
+                let values = self.table.eval_expression(input)?;
+                if !values.data_type().is_numeric() {
+                    return Ok(None);
+                }
+                let name = values.name().to_string();
+                let values = values.cast(&DataType::Float64)?;
+                let (sums, counts) = self.running_sums(values.f64()?, frames, |a, b| a + b);
+                let means = sums
+                    .into_iter()
+                    .zip(counts)
+                    .map(|(sum, count)| sum.map(|sum| sum / count as f64));
+                Ok(Some(
+                    Float64Array::from_regular_iter(Field::new(name, DataType::Float64), means)?
+                        .into_series(),
+                ))
+            }
+            AggExpr::Min(input) | AggExpr::Max(input) => {
+                let values = self.table.eval_expression(input)?;
+                let name = values.name().to_string();
+                // Folds the values in the same order and with the same comparison as the grouped
+                // MIN and MAX kernels, so that NaNs are handled the same way.
+                let is_min = matches!(agg_expr, AggExpr::Min(_));
+                let series = match widen_numeric(&values)? {
+                    Some(values) if values.data_type() == &DataType::Int64 => {
+                        let pick = |acc: i64, value: i64| pick_extremum(acc, value, is_min);
+                        let (extrema, _) = self.running_sums(values.i64()?, frames, pick);
+                        Int64Array::from_regular_iter(
+                            Field::new(name, DataType::Int64),
+                            extrema.into_iter(),
+                        )?
+                        .into_series()
+                    }
+                    Some(values) if values.data_type() == &DataType::UInt64 => {
+                        let pick = |acc: u64, value: u64| pick_extremum(acc, value, is_min);
+                        let (extrema, _) = self.running_sums(values.u64()?, frames, pick);
+                        UInt64Array::from_regular_iter(
+                            Field::new(name, DataType::UInt64),
+                            extrema.into_iter(),
+                        )?
+                        .into_series()
+                    }
+                    Some(values) => {
+                        let pick = |acc: f64, value: f64| pick_extremum(acc, value, is_min);
+                        let (extrema, _) = self.running_sums(values.f64()?, frames, pick);
+                        Float64Array::from_regular_iter(
+                            Field::new(name, DataType::Float64),
+                            extrema.into_iter(),
+                        )?
+                        .into_series()
+                    }
+                    None => return Ok(None),
+                };
+                Ok(Some(series))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Computes counts and integer sums over arbitrary frames as the difference of the running totals at their ends,
+    /// and integer minimums and maximums with a sliding window. Returns `None` for aggregations that are not supported
+    /// by this fast path, and for float sums and means, where subtracting running totals would lose precision.
+    fn eval_sliding_agg(
+        &self,
+        agg_expr: &AggExpr,
+        frames: &[Range<usize>],
+    ) -> DaftResult<Option<Series>> {
+        match agg_expr {
+            AggExpr::Count(input, mode) => {
+                let values = self.table.eval_expression(input)?;
+                let is_valid = values.not_null()?.cast(&DataType::UInt64)?;
+                let (valid_counts, _) = self.sliding_sums(
+                    is_valid.u64()?,
+                    frames,
+                    u64::wrapping_add,
+                    u64::wrapping_sub,
+                );
+                let counts = frames
+                    .iter()
+                    .zip(valid_counts)
+                    .map(|(frame, valid_count)| (frame, valid_count.unwrap_or(0)))
+                    .map(|(frame, valid_count)| match mode {
+                        CountMode::All => frame.len() as u64,
+                        CountMode::Valid => valid_count,
+                        CountMode::Null => frame.len() as u64 - valid_count,
+                    })
+                    .collect::<Vec<_>>();
+                Ok(Some(
+                    UInt64Array::from((values.name(), counts)).into_series(),
+                ))
+            }
+            AggExpr::Sum(input) => {
+                let values = self.table.eval_expression(input)?;
+                let name = values.name().to_string();
+                let series = match try_sum_supertype(values.data_type())? {
+                    DataType::Int64 => {
+                        let values = values.cast(&DataType::Int64)?;
+                        let (sums, _) = self.sliding_sums(
+                            values.i64()?,
+                            frames,
+                            i64::wrapping_add,
+                            i64::wrapping_sub,
+                        );
+                        Int64Array::from_regular_iter(
+                            Field::new(name, DataType::Int64),
+                            sums.into_iter(),
+                        )?
+                        .into_series()
+                    }
+                    DataType::UInt64 => {
+                        let values = values.cast(&DataType::UInt64)?;
+                        let (sums, _) = self.sliding_sums(
+                            values.u64()?,
+                            frames,
+                            u64::wrapping_add,
+                            u64::wrapping_sub,
+                        );
+                        UInt64Array::from_regular_iter(
+                            Field::new(name, DataType::UInt64),
+                            sums.into_iter(),
+                        )?
+                        .into_series()
+                    }
+                    _ => return Ok(None),
+                };
+                Ok(Some(series))
+            }
+            AggExpr::Min(input) | AggExpr::Max(input) => {
+                let values = self.table.eval_expression(input)?;
+                let name = values.name().to_string();
+                let is_min = matches!(agg_expr, AggExpr::Min(_));
+                let series = match widen_numeric(&values)? {
+                    Some(values) if values.data_type() == &DataType::Int64 => {
+                        let Some(extrema) = self.sliding_extrema(values.i64()?, frames, is_min)
+                        else {
+                            return Ok(None);
+                        };
+                        Int64Array::from_regular_iter(
+                            Field::new(name, DataType::Int64),
+                            extrema.into_iter(),
+                        )?
+                        .into_series()
+                    }
+                    Some(values) if values.data_type() == &DataType::UInt64 => {
+                        let Some(extrema) = self.sliding_extrema(values.u64()?, frames, is_min)
+                        else {
+                            return Ok(None);
+                        };
+                        UInt64Array::from_regular_iter(
+                            Field::new(name, DataType::UInt64),
+                            extrema.into_iter(),
+                        )?
+                        .into_series()
+                    }
+                    // floats may hold NaNs, which have no place in the order the sliding window relies on
+                    _ => return Ok(None),
+                };
+                Ok(Some(series))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// For frames that start at the start of their partition, returns the valid values of each frame folded with `add`
+    /// (or `None` if there are none), along with the number of valid values.
+    fn running_sums<T: DaftPrimitiveType>(
+        &self,
+        values: &DataArray<T>,
+        frames: &[Range<usize>],
+        add: impl Fn(T::Native, T::Native) -> T::Native,
+    ) -> (Vec<Option<T::Native>>, Vec<u64>) {
+        let mut running = Vec::with_capacity(values.len());
+        for partition in &self.partitions {
+            let mut sum = None;
+            let mut count = 0;
+            for row in partition.clone() {
+                if let Some(value) = values.get(row) {
+                    sum = Some(sum.map_or(value, |sum| add(sum, value)));
+                    count += 1;
+                }
+                running.push((sum, count));
+            }
+        }
+        frames
+            .iter()
+            .map(|frame| {
+                if frame.is_empty() {
+                    (None, 0)
+                } else {
+                    running[frame.end - 1]
+                }
+            })
+            .unzip()
+    }
+
+    /// Returns the sum of the valid values of each frame (or `None` if there are none), along with the number of valid
+    /// values, as the difference of the running totals at the end and at the start of the frame.
+    fn sliding_sums<T: DaftPrimitiveType>(
+        &self,
+        values: &DataArray<T>,
+        frames: &[Range<usize>],
+        add: impl Fn(T::Native, T::Native) -> T::Native,
+        sub: impl Fn(T::Native, T::Native) -> T::Native,
+    ) -> (Vec<Option<T::Native>>, Vec<u64>) {
+        // The running totals before each row of its partition.
+        let mut running = Vec::with_capacity(values.len() + self.partitions.len());
+        let mut running_start = Vec::with_capacity(self.partitions.len());
+        for partition in &self.partitions {
+            running_start.push(running.len() - partition.start);
+            let mut sum = None;
+            let mut count = 0;
+            running.push((sum, count));
+            for row in partition.clone() {
+                if let Some(value) = values.get(row) {
+                    sum = Some(sum.map_or(value, |sum| add(sum, value)));
+                    count += 1;
+                }
+                running.push((sum, count));
+            }
+        }
+        frames
+            .iter()
+            .enumerate()
+            .map(|(row, frame)| {
+                let offset = running_start[self.partition_of_row[row]];
+                let (end_sum, end_count) = running[offset + frame.end];
+                let (start_sum, start_count) = running[offset + frame.start];
+                match (end_sum, start_sum) {
+                    _ if end_count == start_count => (None, 0),
+                    (Some(end_sum), Some(start_sum)) => {
+                        (Some(sub(end_sum, start_sum)), end_count - start_count)
+                    }
+                    (end_sum, _) => (end_sum, end_count - start_count),
+                }
+            })
+            .unzip()
+    }
+
+    /// Returns the minimum or maximum of the valid values of each frame (or `None` if there are none), by sliding a
+    /// window over each partition that keeps the candidates for the extremum in a monotonic queue.
+    /// Returns `None` if the starts and ends of the frames are not increasing within each partition.
+    fn sliding_extrema<T: DaftPrimitiveType>(
+        &self,
+        values: &DataArray<T>,
+        frames: &[Range<usize>],
+        is_min: bool,
+    ) -> Option<Vec<Option<T::Native>>>
+    where
+        T::Native: Ord,
+    {
+        let mut extrema = Vec::with_capacity(frames.len());
+        for partition in &self.partitions {
+            let frames = &frames[partition.clone()];
+            let is_increasing = frames
+                .windows(2)
+                .all(|w| w[0].start <= w[1].start && w[0].end <= w[1].end);
+            if !is_increasing {
+                return None;
+            }
+            // Rows in the window, whose values are strictly increasing for MIN and decreasing for MAX.
+            let mut candidates = std::collections::VecDeque::<(usize, T::Native)>::new();
+            let mut next = partition.start;
+            for frame in frames {
+                for row in next.max(frame.start)..frame.end {
+                    let Some(value) = values.get(row) else {
+                        continue;
+                    };
+                    while candidates.back().is_some_and(|(_, last)| {
+                        if is_min {
+                            *last >= value
+                        } else {
+                            *last <= value
+                        }
+                    }) {
+                        candidates.pop_back();
+                    }
+                    candidates.push_back((row, value));
+                }
+                next = next.max(frame.end);
+                while candidates
+                    .front()
+                    .is_some_and(|(row, _)| *row < frame.start)
+                {
+                    candidates.pop_front();
+                }
+                extrema.push(candidates.front().map(|(_, value)| *value));
+            }
+        }
+        Some(extrema)
+    }
+
+    /// Evaluates an aggregation over the frame of each row, by gathering the rows of the frames into groups.
+    fn eval_agg_over_frames(
+        &self,
+        agg_expr: &AggExpr,
+        frames: &[Range<usize>],
+    ) -> DaftResult<Series> {
+        let mut results = vec![];
+        let mut groups = vec![];
+        let mut num_indices = 0;
+        for frame in frames {
+            // Empty frames are aggregated over a placeholder row, and their results are replaced below.
+            let group = if frame.is_empty() {
+                vec![0]
+            } else {
+                (frame.start as u64..frame.end as u64).collect::<Vec<_>>()
+            };
+            num_indices += group.len();
+            groups.push(group);
+            if num_indices >= MAX_FRAME_INDICES_PER_CHUNK {
+                results.push(self.table.eval_agg_expression(agg_expr, Some(&groups))?);
+                groups.clear();
+                num_indices = 0;
+            }
+        }
+        if !groups.is_empty() {
+            results.push(self.table.eval_agg_expression(agg_expr, Some(&groups))?);
+        }
+        let result = Series::concat(&results.iter().collect::<Vec<_>>())?;
+
+        if !frames.iter().any(Range::is_empty) {
+            return Ok(result);
+        }
+        let empty_value = match agg_expr {
+            AggExpr::Count(..) | AggExpr::CountDistinct(..) | AggExpr::ApproxCountDistinct(..) => {
+                UInt64Array::from((result.name(), vec![0])).into_series()
+            }
+            _ => Series::full_null(result.name(), result.data_type(), 1),
+        };
+        let non_empty = frames
+            .iter()
+            .map(|frame| !frame.is_empty())
+            .collect::<Vec<_>>();
+        result.if_else(
+            &empty_value,
+            &BooleanArray::from(("", non_empty.as_slice())).into_series(),
+        )
+    }
+}
+
+/// Widens a numeric column losslessly to the 64 bit type of its kind, or returns `None` for other columns.
+fn widen_numeric(values: &Series) -> DaftResult<Option<Series>> {
+    let dtype = match values.data_type() {
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => DataType::Int64,
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+            DataType::UInt64
+        }
+        DataType::Float32 | DataType::Float64 => DataType::Float64,
+        _ => return Ok(None),
+    };
+    values.cast(&dtype).map(Some)
+}
+
+/// Picks the minimum or maximum of an accumulated value and the next value, like the grouped MIN and MAX kernels.
+fn pick_extremum<T: PartialOrd>(acc: T, value: T, is_min: bool) -> T {
+    if (is_min && acc < value) || (!is_min && acc > value) {
+        acc
+    } else {
+        value
+    }
+}
+
+impl RecordBatch {
+    /// Evaluates window functions over this table, appending one column per window function to the columns of the table.
+    /// The rows of the table are not reordered.
+    pub fn window(&self, window_functions: &[ExprRef]) -> DaftResult<Self> {
+        let mut fields = self.schema.fields.values().cloned().collect::<Vec<_>>();
+        let mut columns = self.columns.as_ref().clone();
+        // Window functions over the same partitioning and ordering share the same sort.
+        let mut sorted_windows: HashMap<WindowSpec, SortedWindow> = HashMap::new();
+        for expr in window_functions {
+            let field = expr.to_field(&self.schema)?;
+            let (window_expr, window_spec) = as_window_function(expr)?;
+            let column = if self.is_empty() {
+                Series::empty(&field.name, &field.dtype)
+            } else {
+                let key = WindowSpec {
+                    frame: None,
+                    ..window_spec.clone()
+                };
+                let sorted_window = match sorted_windows.entry(key) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => entry.insert(SortedWindow::try_new(self, window_spec)?),
+                };
+                let result = sorted_window.eval(window_expr, window_spec)?;
+                sorted_window
+                    .unsort(result)?
+                    .cast(&field.dtype)?
+                    .rename(&field.name)
+            };
+            fields.push(field);
+            columns.push(column);
+        }
+        Self::new_with_size(Schema::new(fields)?, columns, self.len())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{
+        resolved_col, AggExpr, Expr, WindowBoundary, WindowExpr, WindowFrame, WindowFrameType,
+        WindowSpec,
+    };
+
+    use super::SortedWindow;
+    use crate::RecordBatch;
+
+    fn frame(
+        frame_type: WindowFrameType,
+        start: WindowBoundary,
+        end: WindowBoundary,
+    ) -> WindowFrame {
+        WindowFrame {
+            frame_type,
+            start,
+            end,
+        }
+    }
+
+    #[test]
+    fn test_incremental_aggs_match_aggregating_each_frame() -> DaftResult<()> {
+        const NUM_ROWS: usize = 200;
+        let column = |name: &str, f: &dyn Fn(usize) -> Option<i64>| {
+            Int64Array::from_iter(
+                Field::new(name, DataType::Int64),
+                (0..NUM_ROWS).map(f).collect::<Vec<_>>().into_iter(),
+            )
+            .into_series()
+        };
+        let table = RecordBatch::from_nonempty_columns(vec![
+            column("p", &|i| Some((i % 3) as i64)),
+            column("t", &|i| (i % 11 != 0).then_some(((i * 7) % 23) as i64)),
+            column("v", &|i| {
+                (i % 5 != 0).then_some(((i * 37) % 101) as i64 - 50)
+            }),
+            column("u", &|i| Some(((i * 13) % 17) as i64))
+                .cast(&DataType::UInt8)?
+                .rename("u"),
+            column("f", &|i| (i % 7 != 0).then_some(((i * 29) % 31) as i64))
+                .cast(&DataType::Float32)?
+                .rename("f"),
+        ])?;
+
+        use WindowBoundary::{Offset, UnboundedFollowing, UnboundedPreceding};
+        let mut num_incremental = 0;
+        let frames = [
+            frame(WindowFrameType::Rows, UnboundedPreceding, Offset(0)),
+            frame(WindowFrameType::Rows, UnboundedPreceding, Offset(2)),
+            frame(WindowFrameType::Rows, Offset(-3), Offset(1)),
+            frame(WindowFrameType::Rows, Offset(2), Offset(4)),
+            frame(WindowFrameType::Rows, Offset(-2), UnboundedFollowing),
+            frame(WindowFrameType::Range, UnboundedPreceding, Offset(0)),
+            frame(WindowFrameType::Range, Offset(-5), Offset(3)),
+            frame(WindowFrameType::Range, Offset(1), Offset(6)),
+        ];
+        for descending in [false, true] {
+            for frame in frames {
+                let window_spec = WindowSpec {
+                    partition_by: vec![resolved_col("p")],
+                    order_by: vec![resolved_col("t")],
+                    descending: vec![descending],
+                    nulls_first: vec![false],
+                    frame: Some(frame),
+                };
+                let window = SortedWindow::try_new(&table, &window_spec)?;
+                let frame_ranges = window.frames(&frame, &window_spec)?;
+                for input in ["v", "u", "f"] {
+                    let input = resolved_col(input);
+                    for agg in [
+                        input.clone().min(),
+                        input.clone().max(),
+                        input.clone().sum(),
+                        input.clone().mean(),
+                        input.clone().count(CountMode::Valid),
+                        input.clone().count(CountMode::Null),
+                    ] {
+                        let Expr::Agg(agg_expr) = agg.as_ref() else {
+                            unreachable!()
+                        };
+                        let incremental = if frame.start == UnboundedPreceding {
+                            window.eval_running_agg(agg_expr, &frame_ranges)?
+                        } else {
+                            window.eval_sliding_agg(agg_expr, &frame_ranges)?
+                        };
+                        let Some(incremental) = incremental else {
+                            continue;
+                        };
+                        num_incremental += 1;
+                        let expected = window.eval_agg_over_frames(agg_expr, &frame_ranges)?;
+                        let incremental = incremental.cast(expected.data_type())?;
+                        assert_eq!(
+                            incremental.to_comfy_table().to_string(),
+                            expected.to_comfy_table().to_string(),
+                            "{agg} over {frame:?}, descending: {descending}"
+                        );
+                    }
+                }
+            }
+        }
+        // Every aggregation has a fast path here, except for sums of Float32, sliding means and sliding float
+        // minimums and maximums.
+        assert_eq!(num_incremental, 2 * (3 * (6 * 3 - 1) + 5 * (5 * 2 + 2)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_frames_compare_integers_exactly() -> DaftResult<()> {
+        // These keys are not representable as Float64, where they would all be peers.
+        let base = 1i64 << 53;
+        let table = RecordBatch::from_nonempty_columns(vec![Int64Array::from((
+            "t",
+            vec![base, base + 1, base + 2, base + 4],
+        ))
+        .into_series()])?;
+        let frame = frame(
+            WindowFrameType::Range,
+            WindowBoundary::Offset(-1),
+            WindowBoundary::Offset(0),
+        );
+        for (descending, expected) in [(false, [1, 2, 2, 1]), (true, [1, 1, 2, 2])] {
+            let window_spec = WindowSpec {
+                order_by: vec![resolved_col("t")],
+                descending: vec![descending],
+                nulls_first: vec![false],
+                frame: Some(frame),
+                ..Default::default()
+            };
+            let window = SortedWindow::try_new(&table, &window_spec)?;
+            let frames = window.frames(&frame, &window_spec)?;
+            let sizes = frames.iter().map(|frame| frame.len()).collect::<Vec<_>>();
+            assert_eq!(sizes, expected, "descending: {descending}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_extreme_offsets_do_not_overflow() -> DaftResult<()> {
+        let table = RecordBatch::from_nonempty_columns(vec![Int64Array::from((
+            "t",
+            vec![i64::MIN, -1, 0, i64::MAX],
+        ))
+        .into_series()])?;
+        use WindowBoundary::{Offset, UnboundedFollowing, UnboundedPreceding};
+        for (frame, expected) in [
+            (
+                frame(WindowFrameType::Rows, Offset(i64::MIN), Offset(i64::MAX)),
+                [4, 4, 4, 4],
+            ),
+            (
+                frame(WindowFrameType::Rows, Offset(i64::MAX), UnboundedFollowing),
+                [0, 0, 0, 0],
+            ),
+            (
+                frame(WindowFrameType::Rows, UnboundedPreceding, Offset(i64::MIN)),
+                [0, 0, 0, 0],
+            ),
+            (
+                frame(WindowFrameType::Range, Offset(i64::MIN), Offset(i64::MAX)),
+                [2, 3, 4, 3],
+            ),
+            (
+                // i64::MIN + i64::MAX = -1, so the frame of the smallest key ends at -1
+                frame(WindowFrameType::Range, Offset(0), Offset(i64::MAX)),
+                [2, 2, 2, 1],
+            ),
+        ] {
+            for descending in [false, true] {
+                let window_spec = WindowSpec {
+                    order_by: vec![resolved_col("t")],
+                    descending: vec![descending],
+                    nulls_first: vec![false],
+                    frame: Some(frame),
+                    ..Default::default()
+                };
+                let window = SortedWindow::try_new(&table, &window_spec)?;
+                let frames = window.frames(&frame, &window_spec)?;
+                let sizes = frames.iter().map(|frame| frame.len()).collect::<Vec<_>>();
+                assert_eq!(sizes, expected, "{frame:?}, descending: {descending}");
+            }
+        }
+
+        let window_spec = WindowSpec {
+            order_by: vec![resolved_col("t")],
+            descending: vec![false],
+            nulls_first: vec![false],
+            ..Default::default()
+        };
+        let window = SortedWindow::try_new(&table, &window_spec)?;
+        for offset in [i64::MIN, i64::MAX] {
+            let shifted = window.eval(
+                &WindowExpr::Offset {
+                    input: resolved_col("t"),
+                    offset,
+                    default: None,
+                },
+                &window_spec,
+            )?;
+            assert_eq!(
+                shifted.validity().map(|v| v.unset_bits()),
+                Some(4),
+                "offset: {offset}"
+            );
+        }
+        Ok(())
+    }
+}
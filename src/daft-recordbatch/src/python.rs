@@ -185,11 +185,22 @@ impl PyRecordBatch {
         })
     }
 
-    pub fn explode(&self, py: Python, to_explode: Vec<PyExpr>) -> PyResult<Self> {
+    #[pyo3(signature = (to_explode, index_column=None))]
+    pub fn explode(
+        &self,
+        py: Python,
+        to_explode: Vec<PyExpr>,
+        index_column: Option<&str>,
+    ) -> PyResult<Self> {
         let converted_to_explode: Vec<daft_dsl::ExprRef> =
             to_explode.into_iter().map(|e| e.expr).collect();
 
-        py.allow_threads(|| Ok(self.table.explode(converted_to_explode.as_slice())?.into()))
+        py.allow_threads(|| {
+            Ok(self
+                .table
+                .explode(converted_to_explode.as_slice(), index_column)?
+                .into())
+        })
     }
 
     pub fn __repr__(&self) -> PyResult<String> {
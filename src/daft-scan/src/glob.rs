@@ -271,6 +271,30 @@ impl GlobScanOperator {
                         .await?;
                         (schema, None)
                     }
+                    FileFormatConfig::Orc(_) => {
+                        let (schema, metadata) = daft_orc::read_orc_schema(
+                            first_filepath.as_str(),
+                            io_client,
+                            Some(io_stats),
+                        )
+                        .await?;
+                        let metadata = Some((
+                            first_filepath,
+                            TableMetadata {
+                                length: metadata.number_of_rows() as usize,
+                            },
+                        ));
+                        (schema, metadata)
+                    }
+                    FileFormatConfig::Ipc(_) => {
+                        let schema = daft_ipc::read_ipc_schema(
+                            first_filepath.as_str(),
+                            io_client,
+                            Some(io_stats),
+                        )
+                        .await?;
+                        (schema, None)
+                    }
                     #[cfg(feature = "python")]
                     FileFormatConfig::Database(_) => {
                         return Err(DaftError::ValueError(
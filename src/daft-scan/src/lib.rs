@@ -110,6 +110,8 @@ impl From<Error> for pyo3::PyErr {
 pub enum ChunkSpec {
     /// Selection of Parquet row groups.
     Parquet(Vec<i64>),
+    /// Selection of ORC stripes.
+    Orc(Vec<i64>),
 }
 
 impl ChunkSpec {
@@ -117,7 +119,7 @@ impl ChunkSpec {
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
         match self {
-            Self::Parquet(chunks) => {
+            Self::Parquet(chunks) | Self::Orc(chunks) => {
                 res.push(format!("Chunks = {chunks:?}"));
             }
         }
@@ -667,10 +669,14 @@ impl ScanTask {
                     let config = config
                         .map_or_else(|| Cow::Owned(DaftExecutionConfig::default()), Cow::Borrowed);
                     let inflation_factor = match self.file_format_config.as_ref() {
-                        FileFormatConfig::Parquet(_) => config.parquet_inflation_factor,
+                        FileFormatConfig::Parquet(_) | FileFormatConfig::Orc(_) => {
+                            config.parquet_inflation_factor
+                        }
                         FileFormatConfig::Csv(_) | FileFormatConfig::Json(_) => {
                             config.csv_inflation_factor
                         }
+                        // IPC files hold data in its in-memory Arrow layout.
+                        FileFormatConfig::Ipc(_) => 1.0,
                         #[cfg(feature = "python")]
                         FileFormatConfig::Database(_) => 1.0,
                         #[cfg(feature = "python")]
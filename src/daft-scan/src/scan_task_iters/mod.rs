@@ -7,6 +7,7 @@ use common_error::{DaftError, DaftResult};
 use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
 use common_scan_info::{ScanTaskLike, ScanTaskLikeRef, SPLIT_AND_MERGE_PASS};
 use daft_io::IOStatsContext;
+use daft_orc::read_orc_metadata;
 use daft_parquet::read::read_parquet_metadata;
 use parquet2::metadata::RowGroupList;
 
@@ -314,6 +315,115 @@ fn split_by_row_groups(
     }
 }
 
+#[must_use]
+fn split_by_stripes(
+    scan_tasks: BoxScanTaskIter,
+    max_tasks: usize,
+    min_size_bytes: usize,
+    max_size_bytes: usize,
+) -> BoxScanTaskIter {
+    let mut scan_tasks = itertools::peek_nth(scan_tasks);
+
+    // only split if we have a small amount of files
+    if scan_tasks.peek_nth(max_tasks).is_some() {
+        Box::new(scan_tasks)
+    } else {
+        Box::new(
+            scan_tasks
+                .map(move |t| -> DaftResult<BoxScanTaskIter> {
+                    let t = t?;
+
+                    /* Only split ORC tasks if they:
+                        - have one source
+                        - have no specified chunk spec or number of rows
+                        - have size past split threshold
+                    */
+                    if let (FileFormatConfig::Orc(_), [source], Some(None), None) = (
+                        t.file_format_config.as_ref(),
+                        &t.sources[..],
+                        t.sources.first().map(DataSource::get_chunk_spec),
+                        t.pushdowns.limit,
+                    ) && source
+                        .get_size_bytes()
+                        .map_or(true, |s| s > max_size_bytes as u64)
+                    {
+                        let (io_runtime, io_client) =
+                            t.storage_config.get_io_client_and_runtime()?;
+
+                        let path = source.get_path();
+
+                        let io_stats =
+                            IOStatsContext::new(format!("split_by_stripes for {path:#?}"));
+
+                        let metadata = io_runtime.block_on_current_thread(read_orc_metadata(
+                            path,
+                            io_client,
+                            Some(io_stats),
+                        ))?;
+
+                        let mut new_tasks: Vec<DaftResult<ScanTaskRef>> = Vec::new();
+                        let mut curr_stripe_indices = Vec::new();
+                        let mut curr_size_bytes = 0;
+                        let mut curr_num_rows = 0;
+
+                        let stripes = metadata.stripe_metadatas();
+                        for (i, stripe) in stripes.iter().enumerate() {
+                            curr_stripe_indices.push(i as i64);
+                            curr_size_bytes += (stripe.index_length()
+                                + stripe.data_length()
+                                + stripe.footer_length())
+                                as usize;
+                            curr_num_rows += stripe.number_of_rows() as usize;
+
+                            if curr_size_bytes >= min_size_bytes || i == stripes.len() - 1 {
+                                let mut new_source = source.clone();
+
+                                if let DataSource::File {
+                                    chunk_spec,
+                                    size_bytes,
+                                    metadata,
+                                    ..
+                                } = &mut new_source
+                                {
+                                    *chunk_spec = Some(ChunkSpec::Orc(std::mem::take(
+                                        &mut curr_stripe_indices,
+                                    )));
+                                    *size_bytes = Some(curr_size_bytes as u64);
+                                    if let Some(metadata) = metadata {
+                                        metadata.length = curr_num_rows;
+                                    }
+                                } else {
+                                    unreachable!(
+                                        "ORC file format should only be used with DataSource::File"
+                                    );
+                                }
+
+                                // Reset accumulators
+                                curr_size_bytes = 0;
+                                curr_num_rows = 0;
+
+                                new_tasks.push(Ok(ScanTask::new(
+                                    vec![new_source],
+                                    t.file_format_config.clone(),
+                                    t.schema.clone(),
+                                    t.storage_config.clone(),
+                                    t.pushdowns.clone(),
+                                    t.generated_fields.clone(),
+                                )
+                                .into()));
+                            }
+                        }
+
+                        Ok(Box::new(new_tasks.into_iter()))
+                    } else {
+                        Ok(Box::new(std::iter::once(Ok(t))))
+                    }
+                })
+                .flat_map(|t| t.unwrap_or_else(|e| Box::new(std::iter::once(Err(e))))),
+        )
+    }
+}
+
 fn split_and_merge_pass(
     scan_tasks: Arc<Vec<ScanTaskLikeRef>>,
     pushdowns: &Pushdowns,
@@ -333,6 +443,13 @@ fn split_and_merge_pass(
                 .downcast::<ScanTask>()
                 .map_err(|e| DaftError::TypeError(format!("Expected Arc<ScanTask>, found {:?}", e)))
         }));
+        // ORC files are split by stripes at every splitting level.
+        let iter = split_by_stripes(
+            iter,
+            cfg.parquet_split_row_groups_max_files,
+            cfg.scan_tasks_min_size_bytes,
+            cfg.scan_tasks_max_size_bytes,
+        );
         if cfg.scantask_splitting_level == 1 {
             let split_tasks = split_by_row_groups(
                 iter,
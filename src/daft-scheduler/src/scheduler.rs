@@ -24,6 +24,7 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "python")]
 use {
     common_daft_config::PyDaftExecutionConfig,
+    common_file_formats::CsvWriteOptions,
     common_io_config::IOConfig,
     daft_core::prelude::SchemaRef,
     daft_core::python::PySchema,
@@ -162,6 +163,7 @@ fn tabular_write(
     compression: &Option<String>,
     partition_cols: &Option<Vec<ExprRef>>,
     io_config: &Option<IOConfig>,
+    csv_options: &Option<CsvWriteOptions>,
 ) -> PyResult<PyObject> {
     let py_iter = py
         .import(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
@@ -178,6 +180,7 @@ fn tabular_write(
                 .map(|cfg| common_io_config::python::IOConfig {
                     config: cfg.clone(),
                 }),
+            csv_options.clone(),
         ))?;
     Ok(py_iter.into())
 }
@@ -396,7 +399,10 @@ fn physical_plan_to_partition_tasks(
             Ok(global_limit_iter.into())
         }
         PhysicalPlan::Explode(Explode {
-            input, to_explode, ..
+            input,
+            to_explode,
+            index_column,
+            ..
         }) => {
             let upstream_iter =
                 physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?;
@@ -407,7 +413,7 @@ fn physical_plan_to_partition_tasks(
             let py_iter = py
                 .import(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
                 .getattr(pyo3::intern!(py, "explode"))?
-                .call1((upstream_iter, explode_pyexprs))?;
+                .call1((upstream_iter, explode_pyexprs, index_column.clone()))?;
             Ok(py_iter.into())
         }
         PhysicalPlan::Unpivot(Unpivot {
@@ -809,6 +815,7 @@ fn physical_plan_to_partition_tasks(
                     partition_cols,
                     compression,
                     io_config,
+                    ..
                 },
             input,
         }) => tabular_write(
@@ -820,6 +827,7 @@ fn physical_plan_to_partition_tasks(
             compression,
             partition_cols,
             io_config,
+            &None,
         ),
         PhysicalPlan::TabularWriteCsv(TabularWriteCsv {
             schema,
@@ -830,6 +838,8 @@ fn physical_plan_to_partition_tasks(
                     partition_cols,
                     compression,
                     io_config,
+                    csv_options,
+                    ..
                 },
             input,
         }) => tabular_write(
@@ -841,6 +851,7 @@ fn physical_plan_to_partition_tasks(
             compression,
             partition_cols,
             io_config,
+            csv_options,
         ),
         PhysicalPlan::TabularWriteJson(TabularWriteJson {
             schema,
@@ -851,6 +862,7 @@ fn physical_plan_to_partition_tasks(
                     partition_cols,
                     compression,
                     io_config,
+                    ..
                 },
             input,
         }) => tabular_write(
@@ -862,6 +874,7 @@ fn physical_plan_to_partition_tasks(
             compression,
             partition_cols,
             io_config,
+            &None,
         ),
         #[cfg(feature = "python")]
         PhysicalPlan::IcebergWrite(IcebergWrite {
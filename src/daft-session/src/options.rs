@@ -1,3 +1,5 @@
+use daft_catalog::Namespace;
+
 // TODO make env variables
 pub(crate) const _DAFT_SESSION: &str = "default";
 pub(crate) const _DAFT_SESSION_USER: &str = "daft";
@@ -7,12 +9,14 @@ pub(crate) const DAFT_SESSION_DEFAULT_CATALOG: &str = "daft";
 #[derive(Debug)]
 pub(crate) struct Options {
     pub curr_catalog: String,
+    pub curr_namespace: Namespace,
 }
 
 impl Default for Options {
     fn default() -> Self {
         Self {
             curr_catalog: DAFT_SESSION_DEFAULT_CATALOG.to_string(),
+            curr_namespace: Namespace::new(),
         }
     }
 }
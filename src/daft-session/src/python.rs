@@ -8,6 +8,12 @@ use crate::Session;
 #[pyclass]
 pub struct PySession(Session);
 
+impl PySession {
+    pub fn session(&self) -> &Session {
+        &self.0
+    }
+}
+
 #[pymethods]
 impl PySession {
     #[staticmethod]
@@ -48,6 +54,10 @@ impl PySession {
         self.0.current_catalog()?.to_py(py)
     }
 
+    pub fn current_namespace(&self) -> PyResult<Vec<String>> {
+        Ok(self.0.current_namespace())
+    }
+
     pub fn get_catalog(&self, py: Python<'_>, name: &str) -> PyResult<PyObject> {
         self.0.get_catalog(name)?.to_py(py)
     }
@@ -77,6 +87,10 @@ impl PySession {
     pub fn set_catalog(&self, name: &str) -> PyResult<()> {
         Ok(self.0.set_catalog(name)?)
     }
+
+    pub fn set_namespace(&self, namespace: Vec<String>) -> PyResult<()> {
+        Ok(self.0.set_namespace(namespace)?)
+    }
 }
 
 pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
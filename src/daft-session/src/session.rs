@@ -1,6 +1,9 @@
 use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
-use daft_catalog::{Bindings, CatalogRef, Identifier, TableRef, TableSource, View};
+use daft_catalog::{
+    Bindings, CatalogRef, Identifier, MemTable, Namespace, TableRef, TableSource, View, WriteMode,
+};
+use daft_logical_plan::LogicalPlanBuilder;
 use uuid::Uuid;
 
 use crate::{
@@ -19,7 +22,7 @@ pub struct Session {
 struct SessionState {
     /// Session identifier
     _id: String,
-    /// Session options i.e. curr_catalog and curr_schema.
+    /// Session options i.e. curr_catalog and curr_namespace.
     options: Options,
     /// Bindings for the attached catalogs.
     catalogs: Bindings<CatalogRef>,
@@ -89,7 +92,7 @@ impl Session {
         if !replace && self.state().tables.exists(&name) {
             obj_already_exists_err!("Temporary table", &name.into())
         }
-        // we don't have mutable temporary tables, only immutable views over dataframes.
+        // tables created from a source are immutable views over dataframes, see `create_temp_table_as`.
         let table = match source {
             TableSource::Schema(_) => unsupported_err!("temporary table with schema"),
             TableSource::View(plan) => View::from(plan.clone()).arced(),
@@ -98,11 +101,62 @@ impl Session {
         Ok(table)
     }
 
+    /// Creates a temp table scoped to this session which holds the rows of the plan, so that
+    /// the table keeps its rows even if the tables that the plan reads from change. Unlike a
+    /// view, rows can be written to the table.
+    ///
+    /// ```sql
+    /// CREATE [OR REPLACE] TEMP TABLE <name> AS <query>;
+    /// ```
+    pub fn create_temp_table_as(
+        &self,
+        name: impl Into<String>,
+        plan: LogicalPlanBuilder,
+        replace: bool,
+    ) -> Result<TableRef> {
+        let name = name.into();
+        // check before running the plan, so that its rows aren't computed for nothing
+        if !replace && self.state().tables.exists(&name) {
+            obj_already_exists_err!("Temporary table", &name.into())
+        }
+        let table = MemTable::from(materialize(plan)?.build()).arced();
+        self.state_mut().tables.insert(name, table.clone());
+        Ok(table)
+    }
+
+    /// Writes the rows of the plan to a table, either appending to or overwriting its rows,
+    /// err if the table is read-only e.g. a view.
+    ///
+    /// Only the written rows of temp tables are materialized, so that an append doesn't
+    /// recompute the rows that the table already has. Catalog tables are written by their catalog.
+    pub fn write_table(
+        &self,
+        name: &Identifier,
+        plan: LogicalPlanBuilder,
+        mode: WriteMode,
+    ) -> Result<()> {
+        let table = self.get_table(name)?;
+        if name.has_namespace() || !self.has_temp_table(&name.name) {
+            return table.write(plan.build(), mode);
+        }
+        table.write(materialize(plan)?.build(), mode)
+    }
+
     /// Returns the session's current catalog.
     pub fn current_catalog(&self) -> Result<CatalogRef> {
         self.get_catalog(&self.state().options.curr_catalog)
     }
 
+    /// Returns the name of the session's current catalog.
+    pub fn current_catalog_name(&self) -> String {
+        self.state().options.curr_catalog.clone()
+    }
+
+    /// Returns the session's current namespace, which qualifies unqualified names in the current catalog.
+    pub fn current_namespace(&self) -> Namespace {
+        self.state().options.curr_namespace.clone()
+    }
+
     /// Detaches a table from this session, err if does not exist.
     pub fn detach_table(&self, alias: &str) -> Result<()> {
         if !self.state().tables.exists(alias) {
@@ -131,16 +185,45 @@ impl Session {
     }
 
     /// Returns the table or an object not found error.
+    ///
+    /// Unqualified names are first resolved against the session's temporary tables, then against the
+    /// current catalog in the current namespace. Qualified names whose first part is an attached
+    /// catalog are resolved in that catalog, and any other qualified name in the current catalog.
     pub fn get_table(&self, name: &Identifier) -> Result<TableRef> {
-        if name.has_namespace() {
-            unsupported_err!("Qualified identifiers are not yet supported")
+        if !name.has_namespace() {
+            if let Some(view) = self.state().tables.get(&name.name) {
+                return Ok(view.clone());
+            }
         }
-        if let Some(view) = self.state().tables.get(&name.name) {
-            return Ok(view.clone());
+        if let Some(table) = self.get_catalog_table(name)? {
+            return Ok(table);
         }
         obj_not_found_err!("Table", name)
     }
 
+    /// Resolves the table in the session's catalogs, if any catalog can resolve it.
+    fn get_catalog_table(&self, name: &Identifier) -> Result<Option<TableRef>> {
+        let (catalog, ident) = match name.namespace.split_first() {
+            Some((first, rest)) if self.has_catalog(first) => (
+                self.get_catalog(first)?,
+                Identifier::new(rest.to_vec(), name.name.clone()),
+            ),
+            _ => {
+                let state = self.state();
+                let Some(catalog) = state.catalogs.get(&state.options.curr_catalog) else {
+                    return Ok(None);
+                };
+                let ident = if name.has_namespace() {
+                    name.clone()
+                } else {
+                    Identifier::new(state.options.curr_namespace.clone(), name.name.clone())
+                };
+                (catalog.clone(), ident)
+            }
+        };
+        Ok(catalog.get_table(&ident)?.map(TableRef::from))
+    }
+
     /// Returns true iff the session has access to a matching catalog.
     pub fn has_catalog(&self, name: &str) -> bool {
         self.state().catalogs.exists(name)
@@ -148,10 +231,15 @@ impl Session {
 
     /// Returns true iff the session has access to a matching table.
     pub fn has_table(&self, name: &Identifier) -> bool {
-        if name.has_namespace() {
-            return false;
+        if !name.has_namespace() && self.state().tables.exists(&name.name) {
+            return true;
         }
-        return self.state().tables.exists(&name.name);
+        matches!(self.get_catalog_table(name), Ok(Some(_)))
+    }
+
+    /// Returns true iff the session has a matching temporary table.
+    pub fn has_temp_table(&self, name: &str) -> bool {
+        self.state().tables.exists(name)
     }
 
     /// Lists all catalogs matching the pattern.
@@ -169,9 +257,54 @@ impl Session {
         if !self.has_catalog(name) {
             obj_not_found_err!("Catalog", &name.into())
         }
-        self.state_mut().options.curr_catalog = name.to_string();
+        let mut state = self.state_mut();
+        state.options.curr_catalog = name.to_string();
+        state.options.curr_namespace = Namespace::new();
         Ok(())
     }
+
+    /// Sets the current_namespace within the current catalog.
+    pub fn set_namespace(&self, namespace: Namespace) -> Result<()> {
+        self.state_mut().options.curr_namespace = namespace;
+        Ok(())
+    }
+}
+
+/// Runs the plan and returns an in-memory source over its result.
+#[cfg(feature = "python")]
+fn materialize(plan: LogicalPlanBuilder) -> Result<LogicalPlanBuilder> {
+    use daft_logical_plan::PyLogicalPlanBuilder;
+    use pyo3::{intern, prelude::*};
+
+    use crate::error::Error;
+
+    Python::with_gil(|py| -> PyResult<LogicalPlanBuilder> {
+        // df = DataFrame(LogicalPlanBuilder(builder)).collect()
+        let builder = py
+            .import(intern!(py, "daft.logical.builder"))?
+            .getattr(intern!(py, "LogicalPlanBuilder"))?
+            .call1((PyLogicalPlanBuilder::from(plan),))?;
+        let df = py
+            .import(intern!(py, "daft.dataframe.dataframe"))?
+            .getattr(intern!(py, "DataFrame"))?
+            .call1((builder,))?
+            .call_method0(intern!(py, "collect"))?;
+        // builder = df._builder._builder
+        let builder: PyLogicalPlanBuilder = df
+            .getattr(intern!(py, "_builder"))?
+            .getattr(intern!(py, "_builder"))?
+            .extract()?;
+        Ok(builder.builder)
+    })
+    .map_err(|source| Error::PythonError {
+        source,
+        context: "materialize".to_string(),
+    })
+}
+
+#[cfg(not(feature = "python"))]
+fn materialize(_plan: LogicalPlanBuilder) -> Result<LogicalPlanBuilder> {
+    unsupported_err!("materializing a table requires the 'python' feature")
 }
 
 impl Default for Session {
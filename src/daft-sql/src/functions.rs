@@ -4,7 +4,7 @@ use daft_dsl::{Expr, ExprRef};
 use once_cell::sync::Lazy;
 use sqlparser::ast::{
     DuplicateTreatment, Function, FunctionArg, FunctionArgExpr, FunctionArgOperator,
-    FunctionArguments,
+    FunctionArguments, WindowType,
 };
 
 use crate::{
@@ -13,7 +13,7 @@ use crate::{
         coalesce::SQLCoalesce, hashing::SQLModuleHashing, SQLModule, SQLModuleAggs,
         SQLModuleConfig, SQLModuleFloat, SQLModuleImage, SQLModuleJson, SQLModuleList,
         SQLModuleMap, SQLModuleNumeric, SQLModulePartitioning, SQLModulePython, SQLModuleSketch,
-        SQLModuleStructs, SQLModuleTemporal, SQLModuleUri, SQLModuleUtf8,
+        SQLModuleStructs, SQLModuleTemporal, SQLModuleUri, SQLModuleUtf8, SQLModuleWindow,
     },
     planner::SQLPlanner,
     unsupported_sql_err,
@@ -37,6 +37,7 @@ pub(crate) static SQL_FUNCTIONS: Lazy<SQLFunctions> = Lazy::new(|| {
     functions.register::<SQLModuleTemporal>();
     functions.register::<SQLModuleUri>();
     functions.register::<SQLModuleUtf8>();
+    functions.register::<SQLModuleWindow>();
     functions.register::<SQLModuleConfig>();
     functions.add_fn("coalesce", SQLCoalesce {});
     functions
@@ -48,10 +49,6 @@ fn check_features(func: &Function) -> SQLPlannerResult<()> {
         // <agg>(..) FILTER (WHERE ..)
         unsupported_sql_err!("Aggregation `FILTER`");
     }
-    if func.over.is_some() {
-        // WINDOW functions
-        unsupported_sql_err!("Window functions `OVER`");
-    }
     if !func.within_group.is_empty() {
         // <agg>(...) WITHIN GROUP
         unsupported_sql_err!("Aggregation `WITHIN GROUP`");
@@ -285,6 +282,23 @@ impl<'a> SQLPlanner<'a> {
         // lookup function variant(s) by name
         // SQL function names are case-insensitive
         let fn_name = func.name.to_string().to_lowercase();
+
+        // GROUPING(..) is planned against the grouping sets of the query, not as a scalar function.
+        if fn_name == "grouping" {
+            if func.over.is_some() {
+                unsupported_sql_err!("GROUPING with a window");
+            }
+            let args = match &func.args {
+                FunctionArguments::List(args) => args
+                    .args
+                    .iter()
+                    .map(|arg| self.plan_function_arg(arg))
+                    .collect::<SQLPlannerResult<Vec<_>>>()?,
+                _ => vec![],
+            };
+            return self.plan_grouping(&args);
+        }
+
         let mut fn_match = get_func_from_sqlfunctions_registry(fn_name.as_str())?;
 
         // TODO: Filter the variants for correct arity.
@@ -332,7 +346,16 @@ impl<'a> SQLPlanner<'a> {
         };
 
         // validate input argument arity and return the validated expression.
-        fn_match.to_expr(&args, self)
+        let expr = fn_match.to_expr(&args, self)?;
+
+        // <func>(..) OVER (..)
+        match &func.over {
+            None => Ok(expr),
+            Some(WindowType::WindowSpec(spec)) => Ok(expr.over(self.plan_window_spec(spec)?)?),
+            Some(WindowType::NamedWindow(name)) => {
+                unsupported_sql_err!("Named window `{name}`")
+            }
+        }
     }
 
     pub(crate) fn plan_function_args<T>(
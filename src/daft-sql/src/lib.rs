@@ -440,6 +440,42 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case::groupby("select i32 as a, max(i64) from tbl1 where a > 0 group by a")]
+    #[case::groupby_and_orderby(
+        "select i32 as a, max(i64) from tbl1 where a > 0 group by a order by a"
+    )]
+    fn test_groupby_alias_matches_column(
+        mut planner: SQLPlanner,
+        #[case] query: &str,
+    ) -> SQLPlannerResult<()> {
+        // plain GROUP BY is planned after the projections, so it resolves select aliases to the
+        // same plan as grouping by the underlying column
+        let aliased = planner.plan_sql(query)?;
+        let unaliased = planner.plan_sql(
+            &query
+                .replace("where a", "where i32")
+                .replace("group by a", "group by i32"),
+        )?;
+
+        assert_eq!(aliased, unaliased);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::rollup("select i32 as a, max(i64) from tbl1 where a > 0 group by rollup(i32)")]
+    #[case::cube("select i32 as a, grouping(i32) from tbl1 group by cube(i32, utf8) order by a")]
+    #[case::mixed_with_plain("select i32 as a, count(i64) from tbl1 group by i32, rollup(utf8)")]
+    fn test_compiles_grouping_sets_with_alias(
+        mut planner: SQLPlanner,
+        #[case] query: &str,
+    ) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql(query);
+        assert!(plan.is_ok(), "query: {query}\nerror: {plan:?}");
+
+        Ok(())
+    }
+
     #[rstest]
     #[case::basic("select utf8 from tbl1 where i64 > (select max(id) from tbl2 where id = i32)")]
     #[case::compound(
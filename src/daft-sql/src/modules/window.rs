@@ -0,0 +1,122 @@
+use daft_dsl::{dense_rank, rank, row_number, ExprRef, LiteralValue};
+use sqlparser::ast::FunctionArg;
+
+use super::SQLModule;
+use crate::{
+    ensure,
+    error::{PlannerError, SQLPlannerResult},
+    functions::{SQLFunction, SQLFunctions},
+    planner::SQLPlanner,
+};
+
+pub struct SQLModuleWindow;
+
+impl SQLModule for SQLModuleWindow {
+    fn register(parent: &mut SQLFunctions) {
+        parent.add_fn("row_number", SQLRowNumber);
+        parent.add_fn("rank", SQLRank);
+        parent.add_fn("dense_rank", SQLDenseRank);
+        parent.add_fn("lag", SQLOffset { lag: true });
+        parent.add_fn("lead", SQLOffset { lag: false });
+    }
+}
+
+pub struct SQLRowNumber;
+
+impl SQLFunction for SQLRowNumber {
+    fn to_expr(&self, inputs: &[FunctionArg], _planner: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
+        ensure!(inputs.is_empty(), "row_number takes no arguments");
+        Ok(row_number())
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Returns the 1-based position of each row within its window partition.".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+pub struct SQLRank;
+
+impl SQLFunction for SQLRank {
+    fn to_expr(&self, inputs: &[FunctionArg], _planner: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
+        ensure!(inputs.is_empty(), "rank takes no arguments");
+        Ok(rank())
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Returns the rank of each row within its window partition, with gaps after ties."
+            .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+pub struct SQLDenseRank;
+
+impl SQLFunction for SQLDenseRank {
+    fn to_expr(&self, inputs: &[FunctionArg], _planner: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
+        ensure!(inputs.is_empty(), "dense_rank takes no arguments");
+        Ok(dense_rank())
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Returns the rank of each row within its window partition, without gaps after ties."
+            .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+/// `lag(input[, offset[, default]])` and `lead(input[, offset[, default]])`.
+pub struct SQLOffset {
+    lag: bool,
+}
+
+impl SQLFunction for SQLOffset {
+    fn to_expr(&self, inputs: &[FunctionArg], planner: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
+        let name = if self.lag { "lag" } else { "lead" };
+        ensure!(
+            (1..=3).contains(&inputs.len()),
+            "{name} takes between 1 and 3 arguments"
+        );
+        let args = self.args_to_expr_unnamed(inputs, planner)?;
+        let input = args[0].clone();
+        let offset = match args.get(1) {
+            Some(offset) => offset
+                .as_literal()
+                .and_then(LiteralValue::as_i64)
+                .and_then(|n| u64::try_from(n).ok())
+                .ok_or_else(|| {
+                    PlannerError::invalid_operation(format!(
+                        "{name} offset must be a non-negative integer literal, instead got: {offset}"
+                    ))
+                })?,
+            None => 1,
+        };
+        let default = args.get(2).cloned();
+        if self.lag {
+            Ok(input.lag(offset, default)?)
+        } else {
+            Ok(input.lead(offset, default)?)
+        }
+    }
+
+    fn docstrings(&self, alias: &str) -> String {
+        if self.lag {
+            format!("{alias}(input, offset=1, default=NULL): Returns the value of input `offset` rows before the current row in its window partition, or `default` if there is no such row.")
+        } else {
+            format!("{alias}(input, offset=1, default=NULL): Returns the value of input `offset` rows after the current row in its window partition, or `default` if there is no such row.")
+        }
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input", "offset", "default"]
+    }
+}
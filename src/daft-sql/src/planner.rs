@@ -7,26 +7,36 @@ use std::{
 };
 
 use common_error::DaftResult;
+use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
 use daft_algebra::boolean::combine_conjunction;
 use daft_catalog::Identifier;
-use daft_core::prelude::*;
+use daft_core::{
+    join::{AsofJoinDirection, JoinSide},
+    prelude::*,
+};
 use daft_dsl::{
-    has_agg, lit, literals_to_series, null_lit, resolved_col, unresolved_col, Column, Expr,
-    ExprRef, LiteralValue, Operator, PlanRef, Subquery, UnresolvedColumn,
+    has_agg, join_side_col, lit, literals_to_series, null_lit, resolved_col, unresolved_col,
+    Column, Expr, ExprRef, LiteralValue, Operator, PlanRef, Subquery, UnresolvedColumn,
+    WindowBoundary, WindowFrame, WindowFrameType, WindowSpec,
 };
 use daft_functions::{
+    coalesce::coalesce,
     numeric::{ceil::ceil, floor::floor},
-    utf8::{ilike, like, to_date, to_datetime},
+    utf8::{self, ilike, like, match_, to_date, to_datetime},
+};
+use daft_logical_plan::{
+    builder::grouping_sets, JoinOptions, LogicalPlan, LogicalPlanBuilder, LogicalPlanRef,
+    SourceInfo, WorkTableInfo,
 };
-use daft_logical_plan::{JoinOptions, LogicalPlanBuilder, LogicalPlanRef};
 use daft_session::Session;
 use itertools::Itertools;
 use sqlparser::{
     ast::{
-        self, BinaryOperator, CastKind, ColumnDef, DateTimeField, Distinct, ExcludeSelectItem,
-        FunctionArg, FunctionArgExpr, GroupByExpr, Ident, ObjectName, Query, SelectItem, SetExpr,
-        Subscript, TableAlias, TableFunctionArgs, TableWithJoins, TimezoneInfo, UnaryOperator,
-        Value, WildcardAdditionalOptions, With,
+        self, BinaryOperator, CastKind, ColumnDef, Cte, DateTimeField, Distinct, ExcludeSelectItem,
+        ExprWithAlias, FunctionArg, FunctionArgExpr, FunctionArguments, GroupByExpr,
+        GroupByWithModifier, Ident, ObjectName, PivotValueSource, Query, SelectItem, SetExpr,
+        SetOperator, SetQuantifier, Subscript, TableAlias, TableFunctionArgs, TableWithJoins,
+        TimezoneInfo, TrimWhereField, UnaryOperator, Value, WildcardAdditionalOptions, With,
     },
     dialect::GenericDialect,
     parser::{Parser, ParserOptions},
@@ -35,7 +45,7 @@ use sqlparser::{
 
 use crate::{
     column_not_found_err, error::*, invalid_operation_err, schema::sql_dtype_to_dtype,
-    table_not_found_err, unsupported_sql_err,
+    table_not_found_err, table_provider::SQLTableFunction, unsupported_sql_err,
 };
 
 /// Bindings are used to lookup in-scope tables, views, and columns (targets T).
@@ -55,6 +65,11 @@ impl<T> Bindings<T> {
         self.0.insert(name, target)
     }
 
+    /// Removes a binding by name
+    pub fn remove(&mut self, ident: &str) -> Option<T> {
+        self.0.remove(ident)
+    }
+
     /// Clears all bound targets
     pub fn clear(&mut self) {
         self.0.clear();
@@ -84,6 +99,8 @@ struct PlannerContext {
     session: Rc<Session>,
     /// Bindings for common table expressions (cte).
     bound_ctes: Bindings<LogicalPlanBuilder>,
+    /// Bindings for table functions, e.g. those registered by the user, which shadow the builtins.
+    bound_table_functions: Bindings<Arc<dyn SQLTableFunction>>,
 }
 
 impl PlannerContext {
@@ -92,12 +109,14 @@ impl PlannerContext {
         Self {
             session,
             bound_ctes: Bindings::default(),
+            bound_table_functions: Bindings::default(),
         }
     }
 
     /// Clears the entire statement context
     fn clear(&mut self) {
         self.bound_ctes.clear();
+        self.bound_table_functions.clear();
     }
 }
 
@@ -116,8 +135,17 @@ pub struct SQLPlanner<'a> {
     /// Aliases from selection that can be used in other clauses
     /// but may not yet be in the schema of `current_relation`.
     bound_columns: Bindings<ExprRef>,
+    /// The left and right sides of the join whose condition is being planned, if any.
+    /// Identifiers are then bound to the side that has them, as join side columns.
+    join_sides: Option<(LogicalPlanBuilder, LogicalPlanBuilder)>,
+    /// The grouping sets of the current query, if it groups by `GROUPING SETS`, `ROLLUP` or `CUBE`.
+    /// `GROUPING(...)` calls are planned against these.
+    pub(crate) grouping_sets: Option<Vec<Vec<ExprRef>>>,
 }
 
+/// Name of the hidden column that holds the grouping id of a grouping sets aggregation.
+pub(crate) const GROUPING_ID_COLUMN: &str = "__grouping_id__";
+
 impl<'a> SQLPlanner<'a> {
     /// Create a new query planner for the session.
     pub fn new(session: Rc<Session>) -> Self {
@@ -185,10 +213,31 @@ impl<'a> SQLPlanner<'a> {
     }
 
     /// Borrow the planning session
-    fn session(&self) -> Ref<'_, Rc<Session>> {
+    pub(crate) fn session(&self) -> Ref<'_, Rc<Session>> {
         Ref::map(self.context.borrow(), |i| &i.session)
     }
 
+    /// Binds a table to the name for the next statement, shadowing any session table of that name.
+    pub fn bind_table(&self, name: String, plan: LogicalPlanBuilder) {
+        let plan = plan.alias(name.clone());
+        self.context_mut().bound_ctes.insert(name, plan);
+    }
+
+    /// Binds a table function to the name for the next statement, shadowing any builtin of that name.
+    #[cfg(feature = "python")]
+    pub(crate) fn bind_table_function(&self, name: String, func: Arc<dyn SQLTableFunction>) {
+        self.context_mut().bound_table_functions.insert(name, func);
+    }
+
+    /// Gets the table function bound to the name, if any.
+    pub(crate) fn get_bound_table_function(&self, name: &str) -> Option<Arc<dyn SQLTableFunction>> {
+        self.context
+            .borrow()
+            .bound_table_functions
+            .get(name)
+            .cloned()
+    }
+
     /// Clears the current context used for planning a SQL query
     fn clear_context(&mut self) {
         self.current_plan = None;
@@ -196,22 +245,24 @@ impl<'a> SQLPlanner<'a> {
     }
 
     fn plan_ctes(&self, with: &With) -> SQLPlannerResult<()> {
-        if with.recursive {
-            unsupported_sql_err!("Recursive CTEs are not supported");
-        }
-
         for cte in &with.cte_tables {
             if cte.materialized.is_some() {
                 unsupported_sql_err!("MATERIALIZED is not supported");
             }
 
             if cte.from.is_some() {
+                if with.recursive {
+                    unsupported_sql_err!("FROM in recursive CTEs is not supported");
+                }
                 invalid_operation_err!("FROM should only exist in recursive CTEs");
             }
 
-            let plan = self.new_with_context().plan_query(&cte.query)?;
-
-            let plan = apply_table_alias(plan, &cte.alias)?;
+            let plan = if with.recursive {
+                self.plan_recursive_cte(cte)?
+            } else {
+                let plan = self.new_with_context().plan_query(&cte.query)?;
+                apply_table_alias(plan, &cte.alias)?
+            };
 
             self.context_mut()
                 .bound_ctes
@@ -220,6 +271,66 @@ impl<'a> SQLPlanner<'a> {
         Ok(())
     }
 
+    /// Plans a CTE of a `WITH RECURSIVE` clause.
+    ///
+    /// A recursive CTE is a UNION of an anchor term and a recursive term which references the CTE
+    /// itself. While the recursive term is planned, the CTE is bound to a work table which holds the
+    /// rows produced by the previous iteration. CTEs which don't reference themselves are planned
+    /// as usual.
+    fn plan_recursive_cte(&self, cte: &Cte) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let query = &cte.query;
+        let SetExpr::SetOperation {
+            op: SetOperator::Union,
+            set_quantifier,
+            left,
+            right,
+        } = query.body.as_ref()
+        else {
+            let plan = self.new_with_context().plan_query(query)?;
+            return apply_table_alias(plan, &cte.alias);
+        };
+        let is_all = match set_quantifier {
+            SetQuantifier::All => true,
+            SetQuantifier::None | SetQuantifier::Distinct => false,
+            set_quantifier => {
+                unsupported_sql_err!("UNION {set_quantifier} is not supported in recursive CTEs")
+            }
+        };
+        if query.order_by.is_some() || query.limit.is_some() || query.offset.is_some() {
+            unsupported_sql_err!("ORDER BY, LIMIT and OFFSET are not supported in recursive CTEs");
+        }
+
+        let name = cte.alias.name.value.clone();
+        let anchor = self.new_with_context().plan_query(&make_query(left))?;
+        let anchor = apply_table_alias(anchor, &cte.alias)?;
+
+        // bind the CTE to its work table while planning the recursive term
+        let work_table = WorkTableInfo::new(anchor.schema(), name.clone());
+        let work_table_id = work_table.id;
+        self.context_mut().bound_ctes.insert(
+            name.clone(),
+            LogicalPlanBuilder::work_table(work_table).alias(name.clone()),
+        );
+        let recursive = self.new_with_context().plan_query(&make_query(right));
+        self.context_mut().bound_ctes.remove(&name);
+        let recursive = recursive?;
+
+        let is_recursive = recursive.build().exists(|node| match node.as_ref() {
+            LogicalPlan::Source(source) => matches!(
+                source.source_info.as_ref(),
+                SourceInfo::WorkTable(info) if info.id == work_table_id
+            ),
+            _ => false,
+        });
+        let plan = if is_recursive {
+            anchor.recursive_union(&recursive, work_table_id, is_all)?
+        } else {
+            anchor.union(&recursive, is_all)?
+        };
+
+        Ok(plan.alias(name))
+    }
+
     pub fn plan_sql(&mut self, sql: &str) -> SQLPlannerResult<LogicalPlanRef> {
         let tokens = Tokenizer::new(&GenericDialect {}, sql).tokenize()?;
 
@@ -259,26 +370,7 @@ impl<'a> SQLPlanner<'a> {
                 left,
                 right,
             } => {
-                use sqlparser::ast::{
-                    SetOperator::{Intersect, Union},
-                    SetQuantifier,
-                };
-                fn make_query(expr: &SetExpr) -> Query {
-                    Query {
-                        with: None,
-                        body: Box::new(expr.clone()),
-                        order_by: None,
-                        limit: None,
-                        limit_by: vec![],
-                        offset: None,
-                        fetch: None,
-                        locks: vec![],
-                        for_clause: None,
-                        settings: None,
-                        format_clause: None,
-                    }
-                }
-
+                use sqlparser::ast::SetOperator::{Intersect, Union};
                 let left = self.new_with_context().plan_query(&make_query(left))?;
                 let right = self.new_with_context().plan_query(&make_query(right))?;
 
@@ -300,7 +392,22 @@ impl<'a> SQLPlanner<'a> {
                     }
                 };
             }
-            SetExpr::Values(..) => unsupported_sql_err!("VALUES are not supported"),
+            SetExpr::Values(values) => {
+                let plan = self.plan_values(values)?;
+                self.set_plan(plan);
+                if let Some(order_by) = &query.order_by {
+                    let OrderByExprs {
+                        exprs,
+                        descending,
+                        nulls_first,
+                    } = self.plan_order_by_exprs(&order_by.exprs)?;
+                    self.update_plan(|plan| plan.sort(exprs, descending, nulls_first))?;
+                }
+                if let Some(limit) = &query.limit {
+                    self.plan_limit(limit)?;
+                }
+                return Ok(self.current_plan.clone().unwrap());
+            }
             SetExpr::Insert(..) => unsupported_sql_err!("INSERT is not supported"),
             SetExpr::Update(..) => unsupported_sql_err!("UPDATE is not supported"),
             SetExpr::Table(..) => unsupported_sql_err!("TABLE is not supported"),
@@ -315,6 +422,12 @@ impl<'a> SQLPlanner<'a> {
         let from = selection.clone().from;
         self.plan_from(&from)?;
 
+        // GROUPING SETS
+        // These are planned before the projections, since `GROUPING(...)` calls depend on them.
+        if let GroupByExpr::Expressions(expressions, modifiers) = &selection.group_by {
+            self.grouping_sets = self.plan_grouping_sets(expressions, modifiers)?;
+        }
+
         // SELECT
         let projections = selection
             .projection
@@ -339,10 +452,12 @@ impl<'a> SQLPlanner<'a> {
                 }
             }
             GroupByExpr::Expressions(expressions, _) => {
-                groupby_exprs = expressions
-                    .iter()
-                    .map(|expr| self.plan_expr(expr))
-                    .collect::<SQLPlannerResult<Vec<_>>>()?;
+                if self.grouping_sets.is_none() {
+                    groupby_exprs = expressions
+                        .iter()
+                        .map(|expr| self.plan_expr(expr))
+                        .collect::<SQLPlannerResult<Vec<_>>>()?;
+                }
             }
         }
 
@@ -359,7 +474,9 @@ impl<'a> SQLPlanner<'a> {
             })
             .transpose()?;
 
-        let has_aggs = projections.iter().any(has_agg) || !groupby_exprs.is_empty();
+        let has_aggs = projections.iter().any(has_agg)
+            || !groupby_exprs.is_empty()
+            || self.grouping_sets.is_some();
 
         if has_aggs {
             let having = selection
@@ -382,17 +499,460 @@ impl<'a> SQLPlanner<'a> {
         }
 
         if let Some(limit) = &query.limit {
-            let limit = self.plan_expr(limit)?;
-            if let Expr::Literal(LiteralValue::Int64(limit)) = limit.as_ref() {
-                self.update_plan(|plan| plan.limit(*limit, true))?; // TODO: Should this be eager or not?
-            } else {
+            self.plan_limit(limit)?;
+        }
+
+        Ok(self.current_plan.clone().unwrap())
+    }
+
+    fn plan_limit(&mut self, limit: &sqlparser::ast::Expr) -> SQLPlannerResult<()> {
+        let limit = self.plan_expr(limit)?;
+        if let Expr::Literal(LiteralValue::Int64(limit)) = limit.as_ref() {
+            self.update_plan(|plan| plan.limit(*limit, true))?; // TODO: Should this be eager or not?
+        } else {
+            invalid_operation_err!("LIMIT <n> must be a constant integer, instead got: {limit}");
+        }
+        Ok(())
+    }
+
+    /// Plans a `VALUES (...), (...)` list into an in-memory relation with columns `column1`, `column2`, ...
+    ///
+    /// The values of each column are collected into a list over the singleton relation, and then all
+    /// columns are exploded together so that each row of values becomes a row of the relation.
+    fn plan_values(&self, values: &sqlparser::ast::Values) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let width = values.rows.first().map_or(0, Vec::len);
+        if width == 0 {
+            invalid_operation_err!("VALUES must have at least one value");
+        }
+        if values.rows.iter().any(|row| row.len() != width) {
+            invalid_operation_err!(
+                "VALUES rows must all have the same number of values, expected {width}"
+            );
+        }
+
+        let mut planner = self.new_with_context();
+        planner.set_plan(singleton_plan()?);
+
+        let names = (1..=width)
+            .map(|i| format!("column{i}"))
+            .collect::<Vec<_>>();
+        let columns = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let items = values
+                    .rows
+                    .iter()
+                    .map(|row| planner.plan_expr(&row[i]))
+                    .collect::<SQLPlannerResult<Vec<_>>>()?;
+                Ok(Expr::List(items).arced().alias(name.as_str()))
+            })
+            .collect::<SQLPlannerResult<Vec<_>>>()?;
+
+        Ok(planner.current_plan_ref().select(columns)?.explode(
+            names
+                .iter()
+                .map(|name| resolved_col(name.as_str()))
+                .collect(),
+            None,
+        )?)
+    }
+
+    /// Plans `UNNEST(<list>, ...) [WITH ORDINALITY] [AS <alias>]` over the rows of `input`, which is
+    /// the left side of `CROSS JOIN UNNEST`, or the singleton relation for a standalone `UNNEST`.
+    ///
+    /// The lists of each input row are zipped into one row per element of the longest list, where
+    /// the shorter lists are padded with nulls, and input rows whose lists are all empty or null are
+    /// dropped. `WITH ORDINALITY` adds the 1-based position of each element, and BigQuery's
+    /// `WITH OFFSET` its 0-based position.
+    fn plan_unnest(
+        &self,
+        input: Option<LogicalPlanBuilder>,
+        relation: &sqlparser::ast::TableFactor,
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        const POSITION: &str = "__unnest_position__";
+        const POSITIONS: &str = "__unnest_positions__";
+        let list_column = |i: usize| format!("__unnest_list_{i}__");
+
+        let sqlparser::ast::TableFactor::UNNEST {
+            alias,
+            array_exprs,
+            with_offset,
+            with_offset_alias,
+            with_ordinality,
+        } = relation
+        else {
+            unreachable!("plan_unnest is only called on UNNEST table factors")
+        };
+        if array_exprs.is_empty() {
+            invalid_operation_err!("UNNEST requires at least one argument");
+        }
+
+        let is_lateral = input.is_some();
+        let input = match input {
+            Some(input) => input,
+            None => singleton_plan()?,
+        };
+        let mut planner = self.new_with_context();
+        planner.set_plan(input.clone());
+        let arrays = array_exprs
+            .iter()
+            .map(|expr| planner.plan_expr(expr))
+            .collect::<SQLPlannerResult<Vec<_>>>()?;
+
+        // name the unnested columns, followed by the ordinality and offset columns
+        let mut names = match arrays.len() {
+            1 => vec!["unnest".to_string()],
+            n => (1..=n).map(|i| format!("unnest_{i}")).collect(),
+        };
+        if *with_ordinality {
+            names.push("ordinality".to_string());
+        }
+        if *with_offset {
+            let offset = with_offset_alias
+                .as_ref()
+                .map_or("offset", |a| a.value.as_str());
+            names.push(offset.to_string());
+        }
+        if let Some(alias) = alias
+            && !alias.columns.is_empty()
+        {
+            if alias.columns.len() != names.len() {
                 invalid_operation_err!(
-                    "LIMIT <n> must be a constant integer, instead got: {limit}"
+                    "Column count mismatch: expected {} columns, found {}",
+                    names.len(),
+                    alias.columns.len()
                 );
             }
+            names = alias.columns.iter().map(|c| c.value.clone()).collect();
         }
+        let left_names = if is_lateral {
+            planner.current_plan_ref().schema().names()
+        } else {
+            vec![]
+        };
+        if let Some(name) = names.iter().find(|name| left_names.contains(name)) {
+            invalid_operation_err!(
+                "UNNEST column {name} conflicts with a column of the same name, rename it with AS <alias>(<columns>)"
+            );
+        }
+        let (array_names, position_names) = names.split_at(arrays.len());
+
+        // the lists are zipped by exploding the positions of the longest list, and taking the
+        // element at each position from every list, which is null past the end of a shorter list
+        let num_arrays = arrays.len();
+        let max_len = (0..num_arrays)
+            .map(|i| {
+                daft_functions::list::count(resolved_col(list_column(i)), CountMode::All)
+                    .fill_null(lit(0u64))
+            })
+            .reduce(|max, len| len.clone().gt(max.clone()).if_else(len, max))
+            .unwrap();
+        let mut columns = arrays
+            .into_iter()
+            .enumerate()
+            .map(|(i, array)| array.alias(list_column(i)))
+            .collect::<Vec<_>>();
+        columns.push(daft_functions::list::list_fill(max_len, lit(0i64)).alias(POSITIONS));
+        let mut plan = input
+            .with_columns(columns)?
+            .filter(
+                daft_functions::list::count(resolved_col(POSITIONS), CountMode::All).gt(lit(0)),
+            )?
+            .explode(vec![resolved_col(POSITIONS)], Some(POSITION))?;
+
+        // explode numbers the elements of each list from 0, which is the offset of an element.
+        let position = resolved_col(POSITION);
+        let mut columns = array_names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                daft_functions::list::get(
+                    resolved_col(list_column(i)),
+                    position.clone(),
+                    null_lit(),
+                )
+                .alias(name.as_str())
+            })
+            .collect::<Vec<_>>();
+        let mut positions = position_names.iter();
+        if *with_ordinality {
+            let name = positions.next().unwrap();
+            columns.push(position.clone().add(lit(1i64)).alias(name.as_str()));
+        }
+        if *with_offset {
+            let name = positions.next().unwrap();
+            columns.push(position.alias(name.as_str()));
+        }
+        plan = plan.with_columns(columns)?;
+
+        plan = plan.select(
+            left_names
+                .iter()
+                .chain(&names)
+                .map(|name| resolved_col(name.as_str()))
+                .collect(),
+        )?;
+
+        match alias {
+            Some(alias) if !is_lateral => Ok(plan.alias(alias.name.value.clone())),
+            _ => Ok(plan),
+        }
+    }
 
-        Ok(self.current_plan.clone().unwrap())
+    /// Plans `<table> PIVOT (<agg>(<value>) FOR <column> IN (...))` onto the pivot of `input`.
+    ///
+    /// Each pivot value becomes a column holding the aggregate of the rows with that value, and rows
+    /// are grouped by all input columns which are not referenced by the pivot. The values of
+    /// `IN (ANY [ORDER BY ...])` and `IN (<subquery>)` are discovered by running a distinct query.
+    fn plan_pivot(
+        &self,
+        input: LogicalPlanBuilder,
+        aggregate_functions: &[ExprWithAlias],
+        value_column: &[Ident],
+        value_source: &PivotValueSource,
+        default_on_null: Option<&sqlparser::ast::Expr>,
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let [aggregate] = aggregate_functions else {
+            unsupported_sql_err!("PIVOT with multiple aggregate functions");
+        };
+
+        let mut planner = self.new_with_context();
+        planner.set_plan(input.clone());
+
+        let agg_expr = planner.plan_expr(&aggregate.expr)?;
+        let Expr::Agg(agg) = agg_expr.as_ref() else {
+            invalid_operation_err!(
+                "PIVOT expects an aggregate function, found {}",
+                aggregate.expr
+            );
+        };
+        let value = match agg.children().as_slice() {
+            [value] => value.clone(),
+            _ => unsupported_sql_err!("PIVOT aggregate function {}", aggregate.expr),
+        };
+        let pivot_column = planner.plan_expr(&match value_column {
+            [ident] => sqlparser::ast::Expr::Identifier(ident.clone()),
+            idents => sqlparser::ast::Expr::CompoundIdentifier(idents.to_vec()),
+        })?;
+
+        // group by every column which is neither pivoted nor aggregated
+        let mut pivoted = HashSet::new();
+        for expr in [&agg_expr, &pivot_column] {
+            expr.apply(|e| {
+                if let Expr::Column(Column::Unresolved(UnresolvedColumn { name, .. })) = e.as_ref()
+                {
+                    pivoted.insert(name.to_string());
+                }
+                Ok(TreeNodeRecursion::Continue)
+            })?;
+        }
+        let group_by = input
+            .schema()
+            .names()
+            .into_iter()
+            .filter(|name| !pivoted.contains(name))
+            .map(unresolved_col)
+            .collect::<Vec<_>>();
+
+        let values = match value_source {
+            PivotValueSource::List(values) => values
+                .iter()
+                .map(|value| {
+                    let expr = planner.plan_expr(&value.expr)?;
+                    let Expr::Literal(literal) = expr.as_ref() else {
+                        invalid_operation_err!(
+                            "PIVOT values must be literals, found {}",
+                            value.expr
+                        );
+                    };
+                    let name = pivot_value_name(literal)?;
+                    Ok((name, value.alias.as_ref().map(|alias| alias.value.clone())))
+                })
+                .collect::<SQLPlannerResult<Vec<_>>>()?,
+            PivotValueSource::Any(order_by) => {
+                self.discover_pivot_values(input.select(vec![pivot_column.clone()])?, order_by)?
+            }
+            PivotValueSource::Subquery(query) => {
+                let plan = self.new_with_context().plan_query(query)?;
+                if plan.schema().len() != 1 {
+                    invalid_operation_err!("PIVOT subquery must return exactly one column");
+                }
+                self.discover_pivot_values(plan, &[])?
+            }
+        };
+
+        let names = values.iter().map(|(name, _)| name.clone()).collect();
+        let mut plan = input.pivot(group_by.clone(), pivot_column, value, agg_expr, names)?;
+
+        if default_on_null.is_some() || values.iter().any(|(_, alias)| alias.is_some()) {
+            let default = default_on_null.map(|e| planner.plan_expr(e)).transpose()?;
+            let pivoted = values.iter().map(|(name, alias)| {
+                let mut column = unresolved_col(name.as_str());
+                if let Some(default) = &default {
+                    column = column.fill_null(default.clone());
+                }
+                column.alias(alias.as_deref().unwrap_or(name))
+            });
+            plan = plan.select(group_by.into_iter().chain(pivoted).collect())?;
+        }
+
+        Ok(plan)
+    }
+
+    /// Discovers the distinct, non-null values of the single column of `plan` for a `PIVOT`,
+    /// ordered by `order_by` or else by the values themselves.
+    fn discover_pivot_values(
+        &self,
+        plan: LogicalPlanBuilder,
+        order_by: &[sqlparser::ast::OrderByExpr],
+    ) -> SQLPlannerResult<Vec<(String, Option<String>)>> {
+        let column = unresolved_col(plan.schema().names()[0].as_str());
+        let plan = plan.distinct()?.filter(column.clone().not_null())?;
+        let plan = if order_by.is_empty() {
+            plan.sort(vec![column.clone()], vec![false], vec![false])?
+        } else {
+            let mut planner = self.new_with_context();
+            planner.set_plan(plan);
+            let OrderByExprs {
+                exprs,
+                descending,
+                nulls_first,
+            } = planner.plan_order_by_exprs(order_by)?;
+            planner
+                .current_plan_ref()
+                .sort(exprs, descending, nulls_first)?
+        };
+        let plan = plan.select(vec![column.cast(&DataType::Utf8)])?;
+        Ok(collect_strings(plan)?
+            .into_iter()
+            .map(|name| (name, None))
+            .collect())
+    }
+
+    /// Plans `<table> UNPIVOT (<value> FOR <name> IN (<columns>))` onto the unpivot of `input`.
+    ///
+    /// The remaining input columns are kept as identifiers and, as in standard SQL, rows whose
+    /// unpivoted value is null are excluded.
+    fn plan_unpivot(
+        &self,
+        input: LogicalPlanBuilder,
+        value: &Ident,
+        name: &Ident,
+        columns: &[Ident],
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let mut planner = self.new_with_context();
+        planner.set_plan(input.clone());
+
+        let values = columns
+            .iter()
+            .map(|column| planner.plan_expr(&sqlparser::ast::Expr::Identifier(column.clone())))
+            .collect::<SQLPlannerResult<Vec<_>>>()?;
+        let unpivoted = values
+            .iter()
+            .map(|value| value.name().to_string())
+            .collect::<HashSet<_>>();
+        let ids = input
+            .schema()
+            .names()
+            .into_iter()
+            .filter(|name| !unpivoted.contains(name))
+            .map(unresolved_col)
+            .collect();
+
+        Ok(input
+            .unpivot(ids, values, name.value.clone(), value.value.clone())?
+            .filter(unresolved_col(value.value.as_str()).not_null())?)
+    }
+
+    /// Plans the grouping sets of a `GROUP BY` clause that uses `GROUPING SETS`, `ROLLUP` or `CUBE`,
+    /// either as grouping elements or as `WITH ROLLUP`/`WITH CUBE` modifiers.
+    /// Returns `None` for a plain `GROUP BY` over a list of expressions.
+    ///
+    /// Multiple grouping elements are combined by the cross product of their grouping sets, so
+    /// `GROUP BY a, ROLLUP(b, c)` groups by `(a, b, c), (a, b), (a)`.
+    fn plan_grouping_sets(
+        &self,
+        expressions: &[sqlparser::ast::Expr],
+        modifiers: &[GroupByWithModifier],
+    ) -> SQLPlannerResult<Option<Vec<Vec<ExprRef>>>> {
+        use sqlparser::ast::Expr as SQLExpr;
+
+        let is_grouping_element = |expr: &sqlparser::ast::Expr| {
+            matches!(
+                expr,
+                SQLExpr::GroupingSets(_) | SQLExpr::Rollup(_) | SQLExpr::Cube(_)
+            )
+        };
+        if modifiers.is_empty() && !expressions.iter().any(is_grouping_element) {
+            return Ok(None);
+        }
+
+        let plan_exprs = |exprs: &[sqlparser::ast::Expr]| {
+            exprs
+                .iter()
+                .map(|expr| self.plan_expr(expr))
+                .collect::<SQLPlannerResult<Vec<_>>>()
+        };
+        let plan_elements = |elements: &[Vec<sqlparser::ast::Expr>]| {
+            elements
+                .iter()
+                .map(|element| plan_exprs(element))
+                .collect::<SQLPlannerResult<Vec<_>>>()
+        };
+
+        let mut grouping_sets = vec![vec![]];
+        for expr in expressions {
+            let element_sets = match expr {
+                SQLExpr::GroupingSets(sets) => plan_elements(sets)?,
+                SQLExpr::Rollup(elements) => grouping_sets::rollup(plan_elements(elements)?),
+                SQLExpr::Cube(elements) => grouping_sets::cube(plan_elements(elements)?)?,
+                expr => vec![vec![self.plan_expr(expr)?]],
+            };
+            grouping_sets = grouping_sets
+                .iter()
+                .cartesian_product(&element_sets)
+                .map(|(left, right)| [left.as_slice(), right.as_slice()].concat())
+                .collect();
+        }
+
+        match modifiers {
+            [] => Ok(Some(grouping_sets)),
+            [GroupByWithModifier::Rollup] | [GroupByWithModifier::Cube]
+                if expressions.iter().any(is_grouping_element) =>
+            {
+                unsupported_sql_err!("WITH ROLLUP or WITH CUBE combined with grouping sets")
+            }
+            [GroupByWithModifier::Rollup] => Ok(Some(grouping_sets::rollup(
+                plan_exprs(expressions)?
+                    .into_iter()
+                    .map(|e| vec![e])
+                    .collect(),
+            ))),
+            [GroupByWithModifier::Cube] => Ok(Some(grouping_sets::cube(
+                plan_exprs(expressions)?
+                    .into_iter()
+                    .map(|e| vec![e])
+                    .collect(),
+            )?)),
+            modifiers => unsupported_sql_err!("GROUP BY {}", modifiers.iter().join(" ")),
+        }
+    }
+
+    /// Plans `GROUPING(e1, ..., ek)` against the grouping sets of the current query.
+    pub(crate) fn plan_grouping(&self, args: &[ExprRef]) -> SQLPlannerResult<ExprRef> {
+        if args.is_empty() {
+            invalid_operation_err!("GROUPING requires at least one argument");
+        }
+        let Some(grouping_sets) = &self.grouping_sets else {
+            // A plain GROUP BY groups by every key in every row.
+            return Ok(lit(0i64));
+        };
+        Ok(grouping_sets::grouping_expr(
+            grouping_sets,
+            GROUPING_ID_COLUMN,
+            args,
+        )?)
     }
 
     fn plan_non_agg_query(
@@ -483,7 +1043,17 @@ impl<'a> SQLPlanner<'a> {
             },
         );
 
-        self.update_plan(|plan| plan.aggregate(aggs.into_iter().collect(), groupby_exprs))?;
+        if let Some(grouping_sets) = self.grouping_sets.clone() {
+            self.update_plan(|plan| {
+                plan.aggregate_grouping_sets(
+                    aggs.into_iter().collect(),
+                    grouping_sets,
+                    GROUPING_ID_COLUMN,
+                )
+            })?;
+        } else {
+            self.update_plan(|plan| plan.aggregate(aggs.into_iter().collect(), groupby_exprs))?;
+        }
 
         if let Some(having) = having {
             self.update_plan(|plan| plan.filter(having))?;
@@ -571,6 +1141,82 @@ impl<'a> SQLPlanner<'a> {
         })
     }
 
+    /// Plans the `PARTITION BY`, `ORDER BY` and frame clauses of an `OVER (..)` window specification.
+    pub(crate) fn plan_window_spec(&self, spec: &ast::WindowSpec) -> SQLPlannerResult<WindowSpec> {
+        if let Some(window_name) = &spec.window_name {
+            unsupported_sql_err!("Named window `{window_name}`");
+        }
+        let partition_by = spec
+            .partition_by
+            .iter()
+            .map(|expr| self.plan_expr(expr))
+            .collect::<SQLPlannerResult<Vec<_>>>()?;
+        let (order_by, descending, nulls_first) = if spec.order_by.is_empty() {
+            (vec![], vec![], vec![])
+        } else {
+            let OrderByExprs {
+                exprs,
+                descending,
+                nulls_first,
+            } = self.plan_order_by_exprs(&spec.order_by)?;
+            (exprs, descending, nulls_first)
+        };
+        let frame = spec
+            .window_frame
+            .as_ref()
+            .map(|frame| self.plan_window_frame(frame))
+            .transpose()?;
+
+        Ok(WindowSpec {
+            partition_by,
+            order_by,
+            descending,
+            nulls_first,
+            frame,
+        })
+    }
+
+    fn plan_window_frame(&self, frame: &ast::WindowFrame) -> SQLPlannerResult<WindowFrame> {
+        let frame_type = match frame.units {
+            ast::WindowFrameUnits::Rows => WindowFrameType::Rows,
+            ast::WindowFrameUnits::Range => WindowFrameType::Range,
+            ast::WindowFrameUnits::Groups => unsupported_sql_err!("GROUPS window frames"),
+        };
+        let start = self.plan_window_frame_bound(&frame.start_bound)?;
+        // `ROWS <start>` is shorthand for `ROWS BETWEEN <start> AND CURRENT ROW`.
+        let end = match &frame.end_bound {
+            Some(bound) => self.plan_window_frame_bound(bound)?,
+            None => WindowBoundary::Offset(0),
+        };
+        Ok(WindowFrame::try_new(frame_type, start, end)?)
+    }
+
+    fn plan_window_frame_bound(
+        &self,
+        bound: &ast::WindowFrameBound,
+    ) -> SQLPlannerResult<WindowBoundary> {
+        let plan_offset = |expr: &ast::Expr| -> SQLPlannerResult<i64> {
+            let offset = self.plan_expr(expr)?;
+            match offset.as_literal().and_then(LiteralValue::as_i64) {
+                Some(offset) if offset >= 0 => Ok(offset),
+                _ => invalid_operation_err!(
+                    "Window frame offsets must be non-negative integer literals, instead got: {offset}"
+                ),
+            }
+        };
+        Ok(match bound {
+            ast::WindowFrameBound::CurrentRow => WindowBoundary::Offset(0),
+            ast::WindowFrameBound::Preceding(None) => WindowBoundary::UnboundedPreceding,
+            ast::WindowFrameBound::Preceding(Some(expr)) => {
+                WindowBoundary::Offset(-plan_offset(expr)?)
+            }
+            ast::WindowFrameBound::Following(None) => WindowBoundary::UnboundedFollowing,
+            ast::WindowFrameBound::Following(Some(expr)) => {
+                WindowBoundary::Offset(plan_offset(expr)?)
+            }
+        })
+    }
+
     /// Plans a single set of table and joins in a FROM clause.
     fn plan_single_from(&self, from: &TableWithJoins) -> SQLPlannerResult<LogicalPlanBuilder> {
         macro_rules! return_non_ident_errors {
@@ -594,7 +1240,19 @@ impl<'a> SQLPlanner<'a> {
             null_eq_nulls: &mut Vec<bool>,
             left_filters: &mut Vec<ExprRef>,
             right_filters: &mut Vec<ExprRef>,
+            predicates: &mut Vec<ExprRef>,
         ) -> SQLPlannerResult<()> {
+            // plans an expression over both tables as a join predicate
+            let plan_predicate = |predicates: &mut Vec<ExprRef>| -> SQLPlannerResult<()> {
+                let mut planner = left_planner.new_with_context();
+                planner.join_sides = Some((
+                    left_planner.current_plan_ref().clone(),
+                    right_planner.current_plan_ref().clone(),
+                ));
+                predicates.push(planner.plan_expr(sql_expr)?);
+                Ok(())
+            };
+
             // check if join expression is actually a filter on one of the tables
             match (
                 left_planner.plan_expr(sql_expr),
@@ -625,39 +1283,46 @@ impl<'a> SQLPlanner<'a> {
                 sqlparser::ast::Expr::BinaryOp {
                     left,
                     right,
-                    op: op @ BinaryOperator::Eq,
+                    op: BinaryOperator::Eq,
                 }
                 | sqlparser::ast::Expr::BinaryOp {
                     left,
                     right,
-                    op: op @ BinaryOperator::Spaceship,
-                } => {
-                    let null_equals_null = *op == BinaryOperator::Spaceship;
-
-                    let mut last_error = None;
+                    op: BinaryOperator::Spaceship,
+                }
+                | sqlparser::ast::Expr::IsNotDistinctFrom(left, right) => {
+                    // `<=>` and `IS NOT DISTINCT FROM` are null-safe join keys
+                    let null_equals_null = !matches!(
+                        sql_expr,
+                        sqlparser::ast::Expr::BinaryOp {
+                            op: BinaryOperator::Eq,
+                            ..
+                        }
+                    );
 
                     for (left, right) in [(left, right), (right, left)] {
                         let left_expr = left_planner.plan_expr(left);
                         let right_expr = right_planner.plan_expr(right);
 
-                        if let Ok(left_expr) = &left_expr && let Ok(right_expr) = &right_expr {
+                        if let Ok(left_expr) = &left_expr
+                            && let Ok(right_expr) = &right_expr
+                        {
                             left_on.push(left_expr.clone());
                             right_on.push(right_expr.clone());
                             null_eq_nulls.push(null_equals_null);
 
-                            return Ok(())
+                            return Ok(());
                         }
 
                         for expr_result in [left_expr, right_expr] {
                             if let Err(e) = expr_result {
                                 return_non_ident_errors!(e);
-
-                                last_error = Some(e);
                             }
                         }
                     }
 
-                    Err(last_error.unwrap())
+                    // neither side of the equality is over a single table, e.g. `a.x = a.y + b.y`
+                    plan_predicate(predicates)
                 }
                 // multiple expressions
                 sqlparser::ast::Expr::BinaryOp {
@@ -665,8 +1330,28 @@ impl<'a> SQLPlanner<'a> {
                     right,
                     op: BinaryOperator::And,
                 } => {
-                    process_join_on(left, left_planner, right_planner, left_on, right_on, null_eq_nulls, left_filters, right_filters)?;
-                    process_join_on(right, left_planner, right_planner, left_on, right_on, null_eq_nulls, left_filters, right_filters)?;
+                    process_join_on(
+                        left,
+                        left_planner,
+                        right_planner,
+                        left_on,
+                        right_on,
+                        null_eq_nulls,
+                        left_filters,
+                        right_filters,
+                        predicates,
+                    )?;
+                    process_join_on(
+                        right,
+                        left_planner,
+                        right_planner,
+                        left_on,
+                        right_on,
+                        null_eq_nulls,
+                        left_filters,
+                        right_filters,
+                        predicates,
+                    )?;
 
                     Ok(())
                 }
@@ -680,9 +1365,56 @@ impl<'a> SQLPlanner<'a> {
                     null_eq_nulls,
                     left_filters,
                     right_filters,
+                    predicates,
                 ),
-                _ => unsupported_sql_err!("JOIN clauses support '=' constraints and filter predicates combined with 'AND'; found expression = {:?}", sql_expr)
+                // any other condition over both tables, e.g. `a.x < b.y`
+                _ => plan_predicate(predicates),
+            }
+        }
+
+        /// Plans the MATCH_CONDITION of an ASOF JOIN into the left and right keys to match on,
+        /// and the direction in which to match.
+        fn process_asof_match_condition(
+            sql_expr: &sqlparser::ast::Expr,
+            left_planner: &SQLPlanner,
+            right_planner: &SQLPlanner,
+        ) -> SQLPlannerResult<(ExprRef, ExprRef, AsofJoinDirection)> {
+            let sqlparser::ast::Expr::BinaryOp { left, op, right } = sql_expr else {
+                if let sqlparser::ast::Expr::Nested(expr) = sql_expr {
+                    return process_asof_match_condition(expr, left_planner, right_planner);
+                }
+                unsupported_sql_err!("ASOF JOIN MATCH_CONDITION must be a comparison between the two tables; found expression = {:?}", sql_expr)
+            };
+            // The direction is that of the right key relative to the left key, e.g. `left >= right` matches backward.
+            let (direction, flipped_direction) = match op {
+                BinaryOperator::GtEq => (AsofJoinDirection::Backward, AsofJoinDirection::Forward),
+                BinaryOperator::LtEq => (AsofJoinDirection::Forward, AsofJoinDirection::Backward),
+                _ => unsupported_sql_err!("ASOF JOIN MATCH_CONDITION only supports '>=' and '<=' comparisons; found operator = {}", op),
+            };
+
+            let mut last_error = None;
+            for (left, right, direction) in
+                [(left, right, direction), (right, left, flipped_direction)]
+            {
+                let left_expr = left_planner.plan_expr(left);
+                let right_expr = right_planner.plan_expr(right);
+
+                if let Ok(left_expr) = &left_expr
+                    && let Ok(right_expr) = &right_expr
+                {
+                    return Ok((left_expr.clone(), right_expr.clone(), direction));
+                }
+
+                for expr_result in [left_expr, right_expr] {
+                    if let Err(e) = expr_result {
+                        return_non_ident_errors!(e);
+
+                        last_error = Some(e);
+                    }
+                }
             }
+
+            Err(last_error.unwrap())
         }
 
         let relation = from.relation.clone();
@@ -693,9 +1425,49 @@ impl<'a> SQLPlanner<'a> {
         for join in &from.joins {
             use sqlparser::ast::{
                 JoinConstraint,
-                JoinOperator::{FullOuter, Inner, LeftAnti, LeftOuter, LeftSemi, RightOuter},
+                JoinOperator::{
+                    AsOf, CrossJoin, FullOuter, Inner, LeftAnti, LeftOuter, LeftSemi, RightOuter,
+                },
             };
 
+            // `CROSS JOIN UNNEST(t.list)` unnests the lists of each row of the left side
+            if matches!(join.join_operator, CrossJoin)
+                && matches!(join.relation, sqlparser::ast::TableFactor::UNNEST { .. })
+            {
+                left_planner
+                    .update_plan(|plan| self.plan_unnest(Some(plan.clone()), &join.relation))?;
+                continue;
+            }
+
+            if is_lateral(&join.relation) {
+                let join_type = match &join.join_operator {
+                    CrossJoin => Some(JoinType::Inner),
+                    Inner(constraint) if is_trivial_join_constraint(constraint) => {
+                        Some(JoinType::Inner)
+                    }
+                    LeftOuter(constraint) if is_trivial_join_constraint(constraint) => {
+                        Some(JoinType::Left)
+                    }
+                    _ if matches!(
+                        join.relation,
+                        sqlparser::ast::TableFactor::Derived { lateral: true, .. }
+                            | sqlparser::ast::TableFactor::Function { .. }
+                    ) =>
+                    {
+                        unsupported_sql_err!(
+                            "LATERAL joins only support CROSS JOIN, and INNER or LEFT JOIN with ON TRUE"
+                        )
+                    }
+                    // other table functions are joined on their join constraint as usual
+                    _ => None,
+                };
+                if let Some(join_type) = join_type {
+                    left_planner
+                        .update_plan(|plan| self.plan_lateral(plan, &join.relation, join_type))?;
+                    continue;
+                }
+            }
+
             let right_plan = self.plan_relation(&join.relation)?;
 
             let mut join_options = JoinOptions::default();
@@ -707,6 +1479,75 @@ impl<'a> SQLPlanner<'a> {
             let mut right_planner = self.new_with_context();
             right_planner.set_plan(right_plan);
 
+            if let AsOf {
+                match_condition,
+                constraint,
+            } = &join.join_operator
+            {
+                let (left_on, right_on, direction) =
+                    process_asof_match_condition(match_condition, &left_planner, &right_planner)?;
+
+                let mut left_by = Vec::new();
+                let mut right_by = Vec::new();
+                let mut left_filters = Vec::new();
+                let mut right_filters = Vec::new();
+
+                let merge_matching_join_keys = match constraint {
+                    JoinConstraint::On(expr) => {
+                        let mut predicates = Vec::new();
+                        process_join_on(
+                            expr,
+                            &left_planner,
+                            &right_planner,
+                            &mut left_by,
+                            &mut right_by,
+                            &mut Vec::new(),
+                            &mut left_filters,
+                            &mut right_filters,
+                            &mut predicates,
+                        )?;
+                        if !predicates.is_empty() {
+                            unsupported_sql_err!("ASOF JOIN ON clauses support '=' constraints and filter predicates combined with 'AND'; found expression = {}", expr)
+                        }
+                        false
+                    }
+                    JoinConstraint::Using(idents) => {
+                        left_by = idents
+                            .iter()
+                            .map(|i| unresolved_col(i.value.clone()))
+                            .collect::<Vec<_>>();
+                        right_by.clone_from(&left_by);
+                        true
+                    }
+                    JoinConstraint::None => false,
+                    JoinConstraint::Natural => {
+                        unsupported_sql_err!("NATURAL ASOF JOIN not supported")
+                    }
+                };
+
+                if let Some(left_predicate) = combine_conjunction(left_filters) {
+                    left_planner.update_plan(|plan| plan.filter(left_predicate))?;
+                }
+
+                if let Some(right_predicate) = combine_conjunction(right_filters) {
+                    right_planner.update_plan(|plan| plan.filter(right_predicate))?;
+                }
+
+                left_planner.update_plan(|plan| {
+                    plan.asof_join(
+                        right_planner.current_plan.unwrap(),
+                        left_by,
+                        right_by,
+                        left_on,
+                        right_on,
+                        direction,
+                        None,
+                        join_options.merge_matching_join_keys(merge_matching_join_keys),
+                    )
+                })?;
+                continue;
+            }
+
             let (join_type, constraint) = match &join.join_operator {
                 Inner(constraint) => (JoinType::Inner, constraint),
                 LeftOuter(constraint) => (JoinType::Left, constraint),
@@ -722,6 +1563,7 @@ impl<'a> SQLPlanner<'a> {
             let mut right_on = Vec::new();
             let mut left_filters = Vec::new();
             let mut right_filters = Vec::new();
+            let mut predicates = Vec::new();
 
             let (merge_matching_join_keys, null_eq_nulls) = match &constraint {
                 JoinConstraint::On(expr) => {
@@ -736,6 +1578,7 @@ impl<'a> SQLPlanner<'a> {
                         &mut null_eq_nulls,
                         &mut left_filters,
                         &mut right_filters,
+                        &mut predicates,
                     )?;
 
                     (false, Some(null_eq_nulls))
@@ -762,11 +1605,12 @@ impl<'a> SQLPlanner<'a> {
             }
 
             left_planner.update_plan(|plan| {
-                plan.join_with_null_safe_equal(
+                plan.join_with_predicate(
                     right_planner.current_plan.unwrap(),
                     left_on,
                     right_on,
                     null_eq_nulls,
+                    combine_conjunction(predicates),
                     join_type,
                     None,
                     join_options.merge_matching_join_keys(merge_matching_join_keys),
@@ -780,26 +1624,38 @@ impl<'a> SQLPlanner<'a> {
     /// Plans the FROM clause of a query and populates `self.current_relation`.
     /// Should only be called once per query.
     fn plan_from(&mut self, from: &[TableWithJoins]) -> SQLPlannerResult<()> {
-        let plan = if let Some(plan) = from
-            .iter()
-            .map(|f| self.plan_single_from(f))
-            .reduce(|left, right| {
-                let left = left?;
-                let right = right?;
-
-                let mut join_options = JoinOptions::default();
-                if let [id] = right.plan.clone().get_aliases().as_slice() {
-                    join_options = join_options.prefix(format!("{id}."));
+        let mut plan: Option<LogicalPlanBuilder> = None;
+        for table in from {
+            plan = Some(match plan {
+                // `FROM t, UNNEST(t.list)` unnests the lists of each row of the tables to its left
+                Some(left)
+                    if table.joins.is_empty()
+                        && matches!(table.relation, sqlparser::ast::TableFactor::UNNEST { .. }) =>
+                {
+                    self.plan_unnest(Some(left), &table.relation)?
+                }
+                // `FROM t, LATERAL (...)` evaluates the subquery or table function for each row of
+                // the tables to its left
+                Some(left) if table.joins.is_empty() && is_lateral(&table.relation) => {
+                    self.plan_lateral(&left, &table.relation, JoinType::Inner)?
                 }
+                Some(left) => {
+                    let right = self.plan_single_from(table)?;
 
-                Ok(left.cross_join(right, join_options)?)
-            })
-            .transpose()?
-        {
-            plan
-        } else {
-            // singleton plan for SELECT without FROM
-            singleton_plan()?
+                    let mut join_options = JoinOptions::default();
+                    if let [id] = right.plan.clone().get_aliases().as_slice() {
+                        join_options = join_options.prefix(format!("{id}."));
+                    }
+
+                    left.cross_join(right, join_options)?
+                }
+                None => self.plan_single_from(table)?,
+            });
+        }
+        // singleton plan for SELECT without FROM
+        let plan = match plan {
+            Some(plan) => plan,
+            None => singleton_plan()?,
         };
 
         self.set_plan(plan);
@@ -807,6 +1663,26 @@ impl<'a> SQLPlanner<'a> {
         Ok(())
     }
 
+    /// Plans a lateral join of `left` with a lateral subquery or table function, whose references
+    /// to the columns of `left` are decorrelated into the join keys.
+    fn plan_lateral(
+        &self,
+        left: &LogicalPlanBuilder,
+        relation: &sqlparser::ast::TableFactor,
+        join_type: JoinType,
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let mut outer_planner = self.new_with_context();
+        outer_planner.set_plan(left.clone());
+        let right = outer_planner.plan_relation(relation)?;
+
+        let mut join_options = JoinOptions::default();
+        if let [id] = right.plan.clone().get_aliases().as_slice() {
+            join_options = join_options.prefix(format!("{id}."));
+        }
+
+        Ok(left.lateral_join(right.build(), join_type, join_options)?)
+    }
+
     fn plan_relation(
         &self,
         rel: &sqlparser::ast::TableFactor,
@@ -840,32 +1716,77 @@ impl<'a> SQLPlanner<'a> {
                 subquery,
                 alias,
             } => {
-                if *lateral {
-                    unsupported_sql_err!("LATERAL");
-                }
-                let subquery = self.new_with_context().plan_query(subquery)?;
+                // a lateral subquery may reference the columns of the relations to its left,
+                // which are the current plan of this planner when planning a lateral join.
+                let subquery = if *lateral {
+                    self.new_child().plan_query(subquery)?
+                } else {
+                    self.new_with_context().plan_query(subquery)?
+                };
                 (subquery, alias)
             }
-            sqlparser::ast::TableFactor::TableFunction { .. } => {
-                unsupported_sql_err!("Unsupported table factor: TableFunction")
-            }
-            sqlparser::ast::TableFactor::Function { .. } => {
-                unsupported_sql_err!("Unsupported table factor: Function")
+            sqlparser::ast::TableFactor::TableFunction { expr, alias } => {
+                let sqlparser::ast::Expr::Function(func) = expr else {
+                    invalid_operation_err!("TABLE(...) expects a table function, found {}", expr);
+                };
+                let args = match &func.args {
+                    FunctionArguments::None => vec![],
+                    FunctionArguments::List(list) => list.args.clone(),
+                    FunctionArguments::Subquery(_) => {
+                        unsupported_sql_err!("Table function with a subquery argument")
+                    }
+                };
+                let tbl_fn = func.name.0.first().unwrap().value.as_str();
+                let args = TableFunctionArgs {
+                    args,
+                    settings: None,
+                };
+                (self.plan_table_function(tbl_fn, &args)?, alias)
             }
-            sqlparser::ast::TableFactor::UNNEST { .. } => {
-                unsupported_sql_err!("Unsupported table factor: UNNEST")
+            sqlparser::ast::TableFactor::Function {
+                name, args, alias, ..
+            } => {
+                let tbl_fn = name.0.first().unwrap().value.as_str();
+                let args = TableFunctionArgs {
+                    args: args.clone(),
+                    settings: None,
+                };
+                (self.plan_table_function(tbl_fn, &args)?, alias)
             }
+            sqlparser::ast::TableFactor::UNNEST { .. } => return self.plan_unnest(None, rel),
             sqlparser::ast::TableFactor::JsonTable { .. } => {
                 unsupported_sql_err!("Unsupported table factor: JsonTable")
             }
             sqlparser::ast::TableFactor::NestedJoin { .. } => {
                 unsupported_sql_err!("Unsupported table factor: NestedJoin")
             }
-            sqlparser::ast::TableFactor::Pivot { .. } => {
-                unsupported_sql_err!("Unsupported table factor: Pivot")
+            sqlparser::ast::TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            } => {
+                let input = self.plan_relation(table)?;
+                let plan = self.plan_pivot(
+                    input,
+                    aggregate_functions,
+                    value_column,
+                    value_source,
+                    default_on_null.as_ref(),
+                )?;
+                (plan, alias)
             }
-            sqlparser::ast::TableFactor::Unpivot { .. } => {
-                unsupported_sql_err!("Unsupported table factor: Unpivot")
+            sqlparser::ast::TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                alias,
+            } => {
+                let input = self.plan_relation(table)?;
+                (self.plan_unpivot(input, value, name, columns)?, alias)
             }
             sqlparser::ast::TableFactor::MatchRecognize { .. } => {
                 unsupported_sql_err!("Unsupported table factor: MatchRecognize")
@@ -963,6 +1884,33 @@ impl<'a> SQLPlanner<'a> {
 
         let full_name = compound_ident_to_str(idents);
 
+        if let Some((left, right)) = &self.join_sides {
+            let plan_side = |plan: &LogicalPlanBuilder| {
+                let mut planner = self.new_with_context();
+                planner.set_plan(plan.clone());
+                planner.plan_identifier(idents)
+            };
+            let to_join_side = |expr: ExprRef, side: JoinSide| -> SQLPlannerResult<ExprRef> {
+                Ok(expr
+                    .transform(|e| match e.as_ref() {
+                        Expr::Column(Column::Unresolved(UnresolvedColumn { name, .. })) => {
+                            Ok(Transformed::yes(join_side_col(name.clone(), side)))
+                        }
+                        _ => Ok(Transformed::no(e)),
+                    })?
+                    .data)
+            };
+            return match (plan_side(left), plan_side(right)) {
+                (Ok(_), Ok(_)) => invalid_operation_err!(
+                    "Ambiguous reference to column name in join: {}",
+                    full_name
+                ),
+                (Ok(expr), Err(_)) => to_join_side(expr, JoinSide::Left),
+                (Err(_), Ok(expr)) => to_join_side(expr, JoinSide::Right),
+                (Err(e), Err(_)) => Err(e),
+            };
+        }
+
         // if the current relation is not resolved (e.g. in a `sql_expr` call, simply wrap identifier in a unresolved_col)
         let Some(current_plan) = &self.current_plan else {
             return Ok(unresolved_col(full_name));
@@ -1178,11 +2126,12 @@ impl<'a> SQLPlanner<'a> {
             SQLExpr::IsNotUnknown(_) => {
                 unsupported_sql_err!("IS NOT UNKNOWN")
             }
-            SQLExpr::IsDistinctFrom(_, _) => {
-                unsupported_sql_err!("IS DISTINCT FROM")
-            }
-            SQLExpr::IsNotDistinctFrom(_, _) => {
-                unsupported_sql_err!("IS NOT DISTINCT FROM")
+            SQLExpr::IsDistinctFrom(left, right) => Ok(self
+                .plan_expr(left)?
+                .eq_null_safe(self.plan_expr(right)?)
+                .not()),
+            SQLExpr::IsNotDistinctFrom(left, right) => {
+                Ok(self.plan_expr(left)?.eq_null_safe(self.plan_expr(right)?))
             }
             SQLExpr::InList {
                 expr,
@@ -1241,12 +2190,19 @@ impl<'a> SQLPlanner<'a> {
                 pattern,
                 escape_char,
             } => {
-                if escape_char.is_some() {
-                    unsupported_sql_err!("LIKE with escape char")
-                }
                 let expr = self.plan_expr(expr)?;
                 let pattern = self.plan_expr(pattern)?;
-                let expr = like(expr, pattern);
+                let expr = match escape_char {
+                    Some(escape_char) => {
+                        let regex = like_to_regex(
+                            pattern_literal(&pattern, "LIKE ... ESCAPE")?,
+                            single_escape_char(escape_char)?,
+                            false,
+                        )?;
+                        match_(expr, lit(regex))
+                    }
+                    None => like(expr, pattern),
+                };
                 if *negated {
                     Ok(expr.not())
                 } else {
@@ -1259,25 +2215,101 @@ impl<'a> SQLPlanner<'a> {
                 pattern,
                 escape_char,
             } => {
-                if escape_char.is_some() {
-                    unsupported_sql_err!("ILIKE with escape char")
+                let expr = self.plan_expr(expr)?;
+                let pattern = self.plan_expr(pattern)?;
+                let expr = match escape_char {
+                    Some(escape_char) => {
+                        let regex = like_to_regex(
+                            pattern_literal(&pattern, "LIKE ... ESCAPE")?,
+                            single_escape_char(escape_char)?,
+                            true,
+                        )?;
+                        match_(expr, lit(regex))
+                    }
+                    None => ilike(expr, pattern),
+                };
+                if *negated {
+                    Ok(expr.not())
+                } else {
+                    Ok(expr)
                 }
+            }
+            SQLExpr::SimilarTo {
+                negated,
+                expr,
+                pattern,
+                escape_char,
+            } => {
                 let expr = self.plan_expr(expr)?;
                 let pattern = self.plan_expr(pattern)?;
-                let expr = ilike(expr, pattern);
+                let regex = similar_to_regex(&pattern, escape_char.as_deref())?;
+                let expr = match_(expr, lit(regex));
                 if *negated {
                     Ok(expr.not())
                 } else {
                     Ok(expr)
                 }
             }
-            SQLExpr::SimilarTo { .. } => unsupported_sql_err!("SIMILAR TO"),
-            SQLExpr::RLike { .. } => unsupported_sql_err!("RLIKE"),
-            SQLExpr::AnyOp { .. } => unsupported_sql_err!("ANY"),
-            SQLExpr::AllOp { .. } => unsupported_sql_err!("ALL"),
+            SQLExpr::RLike {
+                negated,
+                expr,
+                pattern,
+                ..
+            } => {
+                let expr = self.plan_expr(expr)?;
+                let pattern = self.plan_expr(pattern)?;
+                let expr = match_(expr, pattern);
+                if *negated {
+                    Ok(expr.not())
+                } else {
+                    Ok(expr)
+                }
+            }
+            SQLExpr::AnyOp {
+                left,
+                compare_op,
+                right,
+            } => self.plan_quantified_comparison(left, compare_op, right, false),
+            SQLExpr::AllOp {
+                left,
+                compare_op,
+                right,
+            } => self.plan_quantified_comparison(left, compare_op, right, true),
             SQLExpr::Convert { .. } => unsupported_sql_err!("CONVERT"),
             SQLExpr::Cast { .. } => unsupported_sql_err!("CAST"),
-            SQLExpr::AtTimeZone { .. } => unsupported_sql_err!("AT TIME ZONE"),
+            SQLExpr::AtTimeZone {
+                timestamp,
+                time_zone,
+            } => {
+                use daft_functions::temporal::timezone::{
+                    dt_convert_timezone, dt_replace_timezone,
+                };
+
+                let timestamp = self.plan_expr(timestamp)?;
+                let time_zone = self.plan_expr(time_zone)?;
+                let Some(time_zone) = time_zone.as_literal().and_then(|l| l.as_str()) else {
+                    invalid_operation_err!("AT TIME ZONE expects a string literal time zone")
+                };
+                let schema = self
+                    .current_plan
+                    .as_ref()
+                    .map_or_else(|| Arc::new(Schema::empty()), |plan| plan.schema());
+
+                match timestamp.to_field(schema.as_ref())?.dtype {
+                    // the wall clock time in `time_zone` at the given instant
+                    DataType::Timestamp(_, Some(_)) => Ok(dt_replace_timezone(
+                        dt_convert_timezone(timestamp, time_zone),
+                        None::<String>,
+                    )),
+                    // the instant at which the wall clock in `time_zone` shows the given time
+                    DataType::Timestamp(_, None) => {
+                        Ok(dt_replace_timezone(timestamp, Some(time_zone)))
+                    }
+                    dtype => {
+                        invalid_operation_err!("AT TIME ZONE expects a timestamp, got {dtype}")
+                    }
+                }
+            }
             SQLExpr::Extract {
                 field,
                 syntax: _,
@@ -1300,32 +2332,85 @@ impl<'a> SQLPlanner<'a> {
             }
             SQLExpr::Ceil { expr, .. } => Ok(ceil(self.plan_expr(expr)?)),
             SQLExpr::Floor { expr, .. } => Ok(floor(self.plan_expr(expr)?)),
-            SQLExpr::Position { .. } => unsupported_sql_err!("POSITION"),
+            SQLExpr::Position { expr, r#in } => {
+                let substr = self.plan_expr(expr)?;
+                let expr = self.plan_expr(r#in)?;
+
+                Ok(utf8::position(expr, substr))
+            }
             SQLExpr::Substring {
                 expr,
                 substring_from,
                 substring_for,
-                special: true, // We only support SUBSTRING(expr, start, length) syntax
+                ..
             } => {
-                let (Some(substring_from), Some(substring_for)) = (substring_from, substring_for)
-                else {
-                    unsupported_sql_err!("SUBSTRING")
-                };
-
                 let expr = self.plan_expr(expr)?;
-                let start = self.plan_expr(substring_from)?;
-                let length = self.plan_expr(substring_for)?;
 
                 // SQL substring is one indexed
-                let start = start.sub(lit(1));
+                let start = match substring_from {
+                    Some(start) => self.plan_expr(start)?.sub(lit(1)),
+                    None => lit(0),
+                };
+                let length = match substring_for {
+                    Some(length) => self.plan_expr(length)?,
+                    None => null_lit(),
+                };
 
-                Ok(daft_functions::utf8::substr(expr, start, length))
+                Ok(utf8::substr(expr, start, length))
             }
-            SQLExpr::Substring { special: false, .. } => {
-                unsupported_sql_err!("`SUBSTRING(expr [FROM start] [FOR len])` syntax")
+            SQLExpr::Trim {
+                expr,
+                trim_where,
+                trim_what,
+                trim_characters,
+            } => {
+                let expr = self.plan_expr(expr)?;
+                let (leading, trailing) = match trim_where {
+                    Some(TrimWhereField::Leading) => (true, false),
+                    Some(TrimWhereField::Trailing) => (false, true),
+                    Some(TrimWhereField::Both) | None => (true, true),
+                };
+                let chars = match (trim_what, trim_characters.as_deref()) {
+                    (Some(chars), _) => Some(self.plan_expr(chars)?),
+                    (None, Some([chars])) => Some(self.plan_expr(chars)?),
+                    (None, None) => None,
+                    (None, Some(_)) => {
+                        invalid_operation_err!("TRIM expects a single string of characters")
+                    }
+                };
+
+                match chars {
+                    Some(chars) => Ok(utf8::trim(expr, chars, leading, trailing)),
+                    None => {
+                        let expr = if leading { utf8::lstrip(expr) } else { expr };
+                        let expr = if trailing { utf8::rstrip(expr) } else { expr };
+                        Ok(expr)
+                    }
+                }
+            }
+            SQLExpr::Overlay {
+                expr,
+                overlay_what,
+                overlay_from,
+                overlay_for,
+            } => {
+                let expr = self.plan_expr(expr)?;
+                let overlay_what = self.plan_expr(overlay_what)?;
+                let overlay_from = self.plan_expr(overlay_from)?;
+                let overlay_for = match overlay_for {
+                    Some(overlay_for) => self.plan_expr(overlay_for)?,
+                    None => utf8::length(overlay_what.clone()).cast(&DataType::Int64),
+                };
+
+                // OVERLAY(s PLACING w FROM a FOR n) is the first a - 1 characters of s, followed
+                // by w, followed by the rest of s after skipping n characters
+                let head = utf8::left(expr.clone(), overlay_from.clone().sub(lit(1)));
+                let tail = coalesce(vec![
+                    utf8::substr(expr, overlay_from.sub(lit(1)).add(overlay_for), null_lit()),
+                    lit(""),
+                ]);
+                Ok(head.add(overlay_what).add(tail))
             }
-            SQLExpr::Trim { .. } => unsupported_sql_err!("TRIM"),
-            SQLExpr::Overlay { .. } => unsupported_sql_err!("OVERLAY"),
             SQLExpr::Collate { .. } => unsupported_sql_err!("COLLATE"),
             SQLExpr::Nested(e) => self.plan_expr(e),
             SQLExpr::IntroducedString { .. } => unsupported_sql_err!("INTRODUCED STRING"),
@@ -1348,24 +2433,31 @@ impl<'a> SQLPlanner<'a> {
                 results,
                 else_result,
             } => {
-                if operand.is_some() {
-                    unsupported_sql_err!("CASE with operand not yet supported");
-                }
                 if results.len() != conditions.len() {
                     unsupported_sql_err!("CASE with different number of conditions and results");
                 }
 
+                // a CASE without an ELSE evaluates to NULL when no branch matches
                 let else_expr = match else_result {
                     Some(expr) => self.plan_expr(expr)?,
-                    None => unsupported_sql_err!("CASE with no else result"),
+                    None => null_lit(),
                 };
 
+                // a simple CASE (`CASE x WHEN 1 THEN ...`) compares the operand against each branch
+                let operand = operand
+                    .as_ref()
+                    .map(|operand| self.plan_expr(operand))
+                    .transpose()?;
+
                 // we need to traverse from back to front to build the if else chain
                 // because we need to start with the else expression
                 conditions.iter().zip(results.iter()).rev().try_fold(
                     else_expr,
                     |else_expr, (condition, result)| {
-                        let cond = self.plan_expr(condition)?;
+                        let cond = match &operand {
+                            Some(operand) => operand.clone().eq(self.plan_expr(condition)?),
+                            None => self.plan_expr(condition)?,
+                        };
                         let res = self.plan_expr(result)?;
                         Ok(cond.if_else(res, else_expr))
                     },
@@ -1391,9 +2483,9 @@ impl<'a> SQLPlanner<'a> {
                 };
                 Ok(Expr::Subquery(subquery).arced())
             }
-            SQLExpr::GroupingSets(_) => unsupported_sql_err!("GROUPING SETS"),
-            SQLExpr::Cube(_) => unsupported_sql_err!("CUBE"),
-            SQLExpr::Rollup(_) => unsupported_sql_err!("ROLLUP"),
+            SQLExpr::GroupingSets(_) => unsupported_sql_err!("GROUPING SETS outside of GROUP BY"),
+            SQLExpr::Cube(_) => unsupported_sql_err!("CUBE outside of GROUP BY"),
+            SQLExpr::Rollup(_) => unsupported_sql_err!("ROLLUP outside of GROUP BY"),
             // Similar to rust and python conventions, tuples always have a fixed size,
             // so we convert them to a fixed size list.
             SQLExpr::Tuple(exprs) => {
@@ -1651,6 +2743,98 @@ impl<'a> SQLPlanner<'a> {
         }
     }
 
+    /// Visit a quantified comparison over a subquery or a list expression.
+    ///
+    /// e.g. `x = ANY(SELECT y FROM t)` or `x > ALL(list_col)`
+    ///
+    /// `= ANY` and `<> ALL` are planned as (negated) membership tests, the remaining
+    /// comparisons are planned against the min or max of the right-hand side. An `ALL`
+    /// comparison over an empty right-hand side is always true.
+    fn plan_quantified_comparison(
+        &self,
+        left: &sqlparser::ast::Expr,
+        op: &BinaryOperator,
+        right: &sqlparser::ast::Expr,
+        all: bool,
+    ) -> SQLPlannerResult<ExprRef> {
+        let quantifier = if all { "ALL" } else { "ANY" };
+        let operator = self.sql_operator_to_operator(op)?;
+        let left = self.plan_expr(left)?;
+
+        // a subquery is collected into a list by a scalar subquery, so both forms go through the
+        // list kernels and share the same NULL semantics
+        let (list, is_subquery) = match right {
+            sqlparser::ast::Expr::Subquery(query) => {
+                let mut child_planner = self.new_child();
+                let plan = child_planner.plan_query(query)?;
+                let names = plan.schema().names();
+                let [name] = names.as_slice() else {
+                    invalid_operation_err!(
+                        "Subquery in {quantifier} must return exactly one column, got {}",
+                        names.len()
+                    );
+                };
+                let list = Expr::Subquery(Subquery {
+                    plan: plan
+                        .aggregate(vec![resolved_col(name.as_str()).agg_list()], vec![])?
+                        .build(),
+                })
+                .arced();
+                (list, true)
+            }
+            other => (self.plan_expr(other)?, false),
+        };
+        let contains = daft_functions::list::contains(list.clone(), left.clone());
+        let min = daft_functions::list::min(list.clone());
+        let max = daft_functions::list::max(list.clone());
+        let count = daft_functions::list::count(list.clone(), CountMode::All);
+        let null_count = daft_functions::list::count(list.clone(), CountMode::Null);
+
+        let compare = |op: Operator, right: ExprRef| {
+            Expr::BinaryOp {
+                left: left.clone(),
+                op,
+                right,
+            }
+            .arced()
+        };
+        // `min` and `max` skip nulls, so these only decide the comparison against the non-null
+        // elements: ANY holds if some element matches, ALL fails if some element doesn't
+        let decided = match (operator, all) {
+            (Operator::Eq, false) => contains,
+            (Operator::NotEq, false) => {
+                compare(Operator::NotEq, min).or(compare(Operator::NotEq, max))
+            }
+            (Operator::Gt | Operator::GtEq, false) => compare(operator, min),
+            (Operator::Lt | Operator::LtEq, false) => compare(operator, max),
+            (Operator::NotEq, true) => contains,
+            (Operator::Eq, true) => compare(Operator::Eq, min)
+                .and(compare(Operator::Eq, max))
+                .not(),
+            (Operator::Gt | Operator::GtEq, true) => compare(operator, max).not(),
+            (Operator::Lt | Operator::LtEq, true) => compare(operator, min).not(),
+            _ => unsupported_sql_err!("{op} {quantifier}"),
+        };
+        // otherwise the result is unknown if the list has a null or the left side is null, and
+        // FALSE for ANY or TRUE for ALL when it doesn't (including an empty list)
+        let unknown = null_count
+            .gt(lit(0))
+            .or(left.clone().is_null().and(count.gt(lit(0))));
+        let undecided = unknown.if_else(null_lit().cast(&DataType::Boolean), lit(all));
+        // a correlated subquery without rows for the outer row is unnested into a left join that
+        // yields a NULL list, which stands for an empty subquery rather than an unknown list
+        let null_list = if is_subquery {
+            lit(all)
+        } else {
+            null_lit().cast(&DataType::Boolean)
+        };
+        let expr = list.is_null().if_else(
+            null_list,
+            decided.fill_null(lit(false)).if_else(lit(!all), undecided),
+        );
+        Ok(expr)
+    }
+
     /// Visit a SQL unary operator.
     ///
     /// e.g. +column or -column
@@ -1904,6 +3088,27 @@ pub(crate) fn normalize(name: &ObjectName) -> Identifier {
     Identifier::new(namespace, name)
 }
 
+/// Returns true iff the table factor may reference the relations to its left, i.e. it is a
+/// `LATERAL` subquery or, as in PostgreSQL, any table function.
+fn is_lateral(relation: &sqlparser::ast::TableFactor) -> bool {
+    matches!(
+        relation,
+        sqlparser::ast::TableFactor::Derived { lateral: true, .. }
+            | sqlparser::ast::TableFactor::Table { args: Some(_), .. }
+            | sqlparser::ast::TableFactor::TableFunction { .. }
+            | sqlparser::ast::TableFactor::Function { .. }
+    )
+}
+
+/// Returns true iff the join constraint is absent or `ON TRUE`, the only constraints of a lateral join.
+fn is_trivial_join_constraint(constraint: &sqlparser::ast::JoinConstraint) -> bool {
+    matches!(
+        constraint,
+        sqlparser::ast::JoinConstraint::None
+            | sqlparser::ast::JoinConstraint::On(sqlparser::ast::Expr::Value(Value::Boolean(true)))
+    )
+}
+
 /// Returns true iff the ObjectName is a string literal (single-quoted identifier e.g. 'path/to/file.extension').
 /// Example:
 /// ```text
@@ -1949,6 +3154,23 @@ fn apply_table_alias(
     Ok(plan)
 }
 
+/// Wraps a set expression, e.g. one side of a set operation, in a query of its own.
+fn make_query(expr: &SetExpr) -> Query {
+    Query {
+        with: None,
+        body: Box::new(expr.clone()),
+        order_by: None,
+        limit: None,
+        limit_by: vec![],
+        offset: None,
+        fetch: None,
+        locks: vec![],
+        for_clause: None,
+        settings: None,
+        format_clause: None,
+    }
+}
+
 /// Helper to do create a singleton plan for SELECT without FROM.
 #[cfg(feature = "python")]
 fn singleton_plan() -> DaftResult<LogicalPlanBuilder> {
@@ -1985,12 +3207,146 @@ fn singleton_plan() -> DaftResult<LogicalPlanBuilder> {
     ))
 }
 
+/// Helper to run a plan with a single string column and collect its values, e.g. for PIVOT.
+#[cfg(feature = "python")]
+fn collect_strings(plan: LogicalPlanBuilder) -> DaftResult<Vec<String>> {
+    use daft_logical_plan::PyLogicalPlanBuilder;
+    use pyo3::{intern, prelude::*};
+    let name = plan.schema().names()[0].clone();
+    Python::with_gil(|py| {
+        // df = DataFrame(LogicalPlanBuilder(builder))
+        let builder = py
+            .import(intern!(py, "daft.logical.builder"))?
+            .getattr(intern!(py, "LogicalPlanBuilder"))?
+            .call1((PyLogicalPlanBuilder::from(plan),))?;
+        let df = py
+            .import(intern!(py, "daft.dataframe.dataframe"))?
+            .getattr(intern!(py, "DataFrame"))?
+            .call1((builder,))?;
+        // values = df.to_pydict()[name]
+        let values = df
+            .call_method0(intern!(py, "to_pydict"))?
+            .get_item(name)?
+            .extract()?;
+        // done.
+        Ok(values)
+    })
+}
+
+/// Helper to run a plan with a single string column and collect its values, e.g. for PIVOT.
+#[cfg(not(feature = "python"))]
+fn collect_strings(_plan: LogicalPlanBuilder) -> DaftResult<Vec<String>> {
+    Err(common_error::DaftError::InternalError(
+        "PIVOT over ANY or a subquery requires 'python' feature".to_string(),
+    ))
+}
+
+/// Returns the name of the PIVOT column for a value, which is its string representation.
+fn pivot_value_name(value: &LiteralValue) -> SQLPlannerResult<String> {
+    let series = value.to_series().cast(&DataType::Utf8)?;
+    match series.utf8()?.get(0) {
+        Some(name) => Ok(name.to_string()),
+        None => invalid_operation_err!("PIVOT values must not be null"),
+    }
+}
+
+/// Returns the string value of a literal pattern, e.g. the pattern of a `LIKE ... ESCAPE`.
+fn pattern_literal<'p>(pattern: &'p ExprRef, kind: &str) -> SQLPlannerResult<&'p str> {
+    match pattern.as_ref() {
+        Expr::Literal(LiteralValue::Utf8(pattern)) => Ok(pattern.as_str()),
+        _ => unsupported_sql_err!("non-literal {kind} pattern"),
+    }
+}
+
+/// Returns the escape character of a `LIKE` or `SIMILAR TO` pattern, where an empty string means
+/// that there is none.
+fn single_escape_char(escape_char: &str) -> SQLPlannerResult<Option<char>> {
+    let mut chars = escape_char.chars();
+    match (chars.next(), chars.next()) {
+        (None, _) => Ok(None),
+        (Some(c), None) => Ok(Some(c)),
+        _ => invalid_operation_err!("ESCAPE must be a single character, got '{escape_char}'"),
+    }
+}
+
+/// Translates a `LIKE` pattern into an equivalent anchored regex, where `escape` makes the character
+/// that follows it match itself.
+pub(crate) fn like_to_regex(
+    pattern: &str,
+    escape: Option<char>,
+    case_insensitive: bool,
+) -> SQLPlannerResult<String> {
+    let mut regex = String::from(if case_insensitive { "(?is)^" } else { "(?s)^" });
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if Some(c) == escape => match chars.next() {
+                Some(escaped) => regex.push_str(&regex::escape(&escaped.to_string())),
+                None => {
+                    invalid_operation_err!("LIKE pattern must not end with the escape character")
+                }
+            },
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+    Ok(regex)
+}
+
+/// Translates a `SIMILAR TO` pattern into an equivalent anchored regex.
+///
+/// `%` and `_` are the `LIKE` wildcards, `|`, `*`, `+`, `?`, `{m,n}`, `(...)` and `[...]` have their
+/// regex meanings, and every other character, including `.`, matches itself.
+fn similar_to_regex(pattern: &ExprRef, escape: Option<&str>) -> SQLPlannerResult<String> {
+    let pattern = pattern_literal(pattern, "SIMILAR TO")?;
+    // the default escape character is a backslash
+    let escape = match escape {
+        Some(escape) => single_escape_char(escape)?,
+        None => Some('\\'),
+    };
+
+    let mut regex = String::from("(?s)^(?:");
+    let mut in_bracket = false;
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if Some(c) == escape => match chars.next() {
+                Some(escaped) => regex.push_str(&regex::escape(&escaped.to_string())),
+                None => invalid_operation_err!(
+                    "SIMILAR TO pattern must not end with the escape character"
+                ),
+            },
+            '[' if !in_bracket => {
+                in_bracket = true;
+                regex.push(c);
+            }
+            ']' if in_bracket => {
+                in_bracket = false;
+                regex.push(c);
+            }
+            c if in_bracket => regex.push(c),
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            '|' | '*' | '+' | '?' | '{' | '}' | '(' | ')' => regex.push(c),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push_str(")$");
+    Ok(regex)
+}
+
 #[cfg(test)]
 mod tests {
     use daft_core::prelude::*;
+    use daft_dsl::lit;
     use sqlparser::ast::{Ident, ObjectName};
 
-    use crate::{planner::is_table_path, sql_schema};
+    use crate::{
+        planner::{is_table_path, like_to_regex, similar_to_regex, single_escape_char},
+        sql_schema,
+    };
 
     #[test]
     fn test_sql_schema_creates_expected_schema() {
@@ -2056,4 +3412,89 @@ mod tests {
             "path/to/file.ext"
         )])));
     }
+
+    #[test]
+    fn test_like_to_regex() {
+        assert_eq!(
+            like_to_regex("a!%b_c.%", Some('!'), false).unwrap(),
+            "(?s)^a%b.c\\..*$"
+        );
+        assert_eq!(
+            like_to_regex("10#_%", Some('#'), true).unwrap(),
+            "(?is)^10_.*$"
+        );
+        assert_eq!(like_to_regex("t_%", None, false).unwrap(), "(?s)^t..*$");
+        assert!(like_to_regex("abc!", Some('!'), false).is_err());
+        assert!(single_escape_char("!!").is_err());
+    }
+
+    #[test]
+    fn test_similar_to_regex() {
+        assert_eq!(
+            similar_to_regex(&lit("(a|b)%.c"), None).unwrap(),
+            "(?s)^(?:(a|b).*\\.c)$"
+        );
+        assert_eq!(
+            similar_to_regex(&lit("[a-c]_{2}\\%"), None).unwrap(),
+            "(?s)^(?:[a-c].{2}%)$"
+        );
+        assert_eq!(
+            similar_to_regex(&lit("a#|b"), Some("#")).unwrap(),
+            "(?s)^(?:a\\|b)$"
+        );
+    }
+
+    #[test]
+    fn test_quantified_comparison_null_semantics() -> common_error::DaftResult<()> {
+        use common_treenode::{Transformed, TreeNode};
+        use daft_dsl::{resolved_col, Column, Expr};
+        use daft_recordbatch::RecordBatch;
+
+        use crate::planner::sql_expr;
+
+        let a = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(2), Some(5), Some(2), None, Some(3), Some(1)].into_iter(),
+        )
+        .into_series();
+        // [[1, NULL], [1, NULL], [2, NULL], [1], [], NULL]
+        let values = Int64Array::from_iter(
+            Field::new("item", DataType::Int64),
+            vec![Some(1), None, Some(1), None, Some(2), None, Some(1)].into_iter(),
+        )
+        .into_series();
+        let l = ListArray::new(
+            Field::new("l", DataType::List(Box::new(DataType::Int64))),
+            values,
+            arrow2::offset::OffsetsBuffer::try_from(vec![0i64, 2, 4, 6, 7, 7, 7])?,
+            Some(arrow2::bitmap::Bitmap::from([
+                true, true, true, true, true, false,
+            ])),
+        )
+        .into_series();
+        let batch = RecordBatch::from_nonempty_columns(vec![a, l])?;
+
+        let eval = |sql: &str| -> common_error::DaftResult<Vec<Option<bool>>> {
+            let expr = sql_expr(sql)
+                .unwrap()
+                .transform(|e| match e.as_ref() {
+                    Expr::Column(Column::Unresolved(c)) => {
+                        Ok(Transformed::yes(resolved_col(c.name.clone())))
+                    }
+                    _ => Ok(Transformed::no(e)),
+                })?
+                .data;
+            let result = batch.eval_expression_list(&[expr])?;
+            Ok(result.get_column_by_index(0)?.bool()?.into_iter().collect())
+        };
+
+        let (t, f, n) = (Some(true), Some(false), None);
+        assert_eq!(eval("a = ANY(l)")?, vec![n, n, t, n, f, n]);
+        assert_eq!(eval("a <> ALL(l)")?, vec![n, n, f, n, t, n]);
+        assert_eq!(eval("a > ALL(l)")?, vec![n, n, f, n, t, n]);
+        assert_eq!(eval("a > ANY(l)")?, vec![t, t, n, n, f, n]);
+        assert_eq!(eval("a < ALL(l)")?, vec![f, f, f, n, t, n]);
+        assert_eq!(eval("a = ALL(l)")?, vec![f, f, n, n, t, n]);
+        Ok(())
+    }
 }
@@ -4,10 +4,12 @@ use common_daft_config::PyDaftPlanningConfig;
 use daft_catalog::TableSource;
 use daft_dsl::python::PyExpr;
 use daft_logical_plan::{LogicalPlan, LogicalPlanBuilder, PyLogicalPlanBuilder};
-use daft_session::Session;
+use daft_session::{python::PySession, Session};
 use pyo3::prelude::*;
 
-use crate::{functions::SQL_FUNCTIONS, planner::SQLPlanner};
+use crate::{
+    functions::SQL_FUNCTIONS, planner::SQLPlanner, table_provider::python::PyTableFunction,
+};
 
 #[pyclass]
 pub struct SQLFunctionStub {
@@ -36,18 +38,36 @@ impl SQLFunctionStub {
 
 // TODO replace with session.exec to invert responsibilities
 #[pyfunction]
+#[pyo3(signature = (sql, catalog, daft_planning_config, session=None))]
 pub fn sql(
     sql: &str,
     catalog: PyCatalog,
     daft_planning_config: PyDaftPlanningConfig,
+    session: Option<&PySession>,
 ) -> PyResult<PyLogicalPlanBuilder> {
-    // TODO deprecated catalog APIs #3819
-    let session = Session::empty();
-    for (name, view) in catalog.tables {
-        session.create_temp_table(name, &TableSource::View(view), true)?;
-    }
-    let mut planner = SQLPlanner::new(session.into());
-    let plan = planner.plan_sql(sql)?;
+    let plan = if let Some(session) = session {
+        // plan against the session, so that DDL and DML statements update its state, with the
+        // catalog's tables bound for the statement only.
+        let mut planner = SQLPlanner::new(session.session().clone().into());
+        for (name, view) in catalog.tables {
+            planner.bind_table(name, LogicalPlanBuilder::from(view));
+        }
+        for (name, func) in catalog.table_functions {
+            planner.bind_table_function(name, Arc::new(PyTableFunction(func)));
+        }
+        planner.plan_sql(sql)?
+    } else {
+        // TODO deprecated catalog APIs #3819
+        let session = Session::empty();
+        for (name, view) in catalog.tables {
+            session.create_temp_table(name, &TableSource::View(view), true)?;
+        }
+        let mut planner = SQLPlanner::new(session.into());
+        for (name, func) in catalog.table_functions {
+            planner.bind_table_function(name, Arc::new(PyTableFunction(func)));
+        }
+        planner.plan_sql(sql)?
+    };
     Ok(LogicalPlanBuilder::new(plan, Some(daft_planning_config.config)).into())
 }
 
@@ -80,6 +100,7 @@ pub fn list_sql_functions() -> Vec<SQLFunctionStub> {
 #[derive(Debug, Clone)]
 pub struct PyCatalog {
     tables: HashMap<String, Arc<LogicalPlan>>,
+    table_functions: HashMap<String, Arc<PyObject>>,
 }
 
 #[pymethods]
@@ -89,6 +110,7 @@ impl PyCatalog {
     pub fn new() -> Self {
         Self {
             tables: HashMap::new(),
+            table_functions: HashMap::new(),
         }
     }
 
@@ -104,11 +126,20 @@ impl PyCatalog {
         Ok(())
     }
 
+    /// Register a table function with the catalog, which is a callable that returns a DataFrame.
+    pub fn register_table_function(&mut self, name: &str, func: PyObject) {
+        self.table_functions
+            .insert(name.to_string(), Arc::new(func));
+    }
+
     /// Copy from another catalog, using tables from other in case of conflict
     pub fn copy_from(&mut self, other: &Self) {
         for (name, plan) in &other.tables {
             self.tables.insert(name.clone(), plan.clone());
         }
+        for (name, func) in &other.table_functions {
+            self.table_functions.insert(name.clone(), func.clone());
+        }
     }
 
     /// __str__ to print the catalog's tables
@@ -1,7 +1,21 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_file_formats::FileFormat;
+use daft_catalog::{error::Error as CatalogError, Identifier, TableSource, WriteMode};
+use daft_core::prelude::*;
+use daft_dsl::{null_lit, resolved_col};
 use daft_logical_plan::LogicalPlanBuilder;
+use daft_micropartition::partitioning::{MicroPartitionSet, PartitionCacheEntry};
+use daft_recordbatch::RecordBatch;
 use sqlparser::ast;
 
-use crate::{error::SQLPlannerResult, unsupported_sql_err, SQLPlanner};
+use crate::{
+    error::{PlannerError, SQLPlannerResult},
+    invalid_operation_err,
+    planner::{like_to_regex, normalize},
+    table_not_found_err, unsupported_sql_err, SQLPlanner,
+};
 
 /// Daft-SQL statement planning.
 impl<'a> SQLPlanner<'a> {
@@ -30,6 +44,50 @@ impl<'a> SQLPlanner<'a> {
                 *has_table_keyword,
                 table_name,
             ),
+            ast::Statement::CreateTable(create_table) => self.plan_create_table(create_table),
+            ast::Statement::CreateView {
+                or_replace,
+                materialized,
+                name,
+                columns,
+                query,
+                if_not_exists,
+                ..
+            } => self.plan_create_view(
+                *or_replace,
+                *materialized,
+                name,
+                columns,
+                query,
+                *if_not_exists,
+            ),
+            ast::Statement::Insert(insert) => self.plan_insert(insert),
+            ast::Statement::Directory {
+                overwrite,
+                path,
+                file_format,
+                source,
+                ..
+            } => self.plan_insert_directory(*overwrite, path, file_format, source),
+            ast::Statement::Drop {
+                object_type,
+                if_exists,
+                names,
+                cascade,
+                purge,
+                ..
+            } => self.plan_drop(object_type, *if_exists, names, *cascade, *purge),
+            ast::Statement::Use(use_) => self.plan_use(use_),
+            ast::Statement::ShowTables {
+                extended,
+                full,
+                db_name,
+                filter,
+            } => self.plan_show_tables(*extended, *full, db_name, filter),
+            // sqlparser has no SHOW CATALOGS statement, so it parses as SHOW <variable>
+            ast::Statement::ShowVariable { variable } if matches!(variable.as_slice(), [ident] if ident.value.eq_ignore_ascii_case("catalogs")) => {
+                self.plan_show_catalogs()
+            }
             other => unsupported_sql_err!("unsupported statement, {}", other),
         }
     }
@@ -59,6 +117,18 @@ impl<'a> SQLPlanner<'a> {
         if analyze || verbose || format.is_some() {
             unsupported_sql_err!("DESCRIBE ( options.. ) is not supported")
         }
+        // err on DESCRIBE <ddl | dml>, as most of those are applied when they are planned
+        if matches!(
+            statement,
+            ast::Statement::CreateTable(_)
+                | ast::Statement::CreateView { .. }
+                | ast::Statement::Insert(_)
+                | ast::Statement::Directory { .. }
+                | ast::Statement::Drop { .. }
+                | ast::Statement::Use(_)
+        ) {
+            unsupported_sql_err!("DESCRIBE is not supported for DDL and DML statements")
+        }
         // plan statement and .describe()
         Ok(self.plan_statement(statement)?.describe()?)
     }
@@ -90,3 +160,339 @@ impl<'a> SQLPlanner<'a> {
         Ok(self.plan_relation_table(table_name)?.describe()?)
     }
 }
+
+/// Daft-SQL DDL and DML statement planning.
+///
+/// Tables and views are created in the session as temporary tables. Tables hold the materialized
+/// rows of their queries whereas views are over the plans of their queries. DDL and DML statements
+/// are applied when they are planned, so they return an empty result.
+impl<'a> SQLPlanner<'a> {
+    /// CREATE [OR REPLACE] [TEMP] TABLE [IF NOT EXISTS] <name> AS <query>
+    fn plan_create_table(
+        &mut self,
+        create_table: &ast::CreateTable,
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let Some(query) = &create_table.query else {
+            unsupported_sql_err!("CREATE TABLE without AS <query> is not supported")
+        };
+        if !create_table.columns.is_empty() {
+            unsupported_sql_err!("CREATE TABLE with column definitions is not supported")
+        }
+        if create_table.external || create_table.location.is_some() {
+            unsupported_sql_err!("CREATE EXTERNAL TABLE is not supported")
+        }
+        let plan = self.plan_query(query)?;
+        self.create_table(
+            &create_table.name,
+            plan,
+            true,
+            create_table.or_replace,
+            create_table.if_not_exists,
+        )
+    }
+
+    /// CREATE [OR REPLACE] [TEMP] VIEW [IF NOT EXISTS] <name> [(<columns>)] AS <query>
+    fn plan_create_view(
+        &mut self,
+        or_replace: bool,
+        materialized: bool,
+        name: &ast::ObjectName,
+        columns: &[ast::ViewColumnDef],
+        query: &ast::Query,
+        if_not_exists: bool,
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        if materialized {
+            unsupported_sql_err!("CREATE MATERIALIZED VIEW is not supported")
+        }
+        let mut plan = self.plan_query(query)?;
+        if !columns.is_empty() {
+            let names = plan.schema().names();
+            if names.len() != columns.len() {
+                invalid_operation_err!(
+                    "View {} has {} column names but its query returns {} columns",
+                    name,
+                    columns.len(),
+                    names.len()
+                )
+            }
+            let renamed = names
+                .iter()
+                .zip(columns)
+                .map(|(from, to)| resolved_col(from.as_str()).alias(to.name.value.as_str()))
+                .collect();
+            plan = plan.select(renamed)?;
+        }
+        self.create_table(name, plan, false, or_replace, if_not_exists)
+    }
+
+    /// Creates the session table for CREATE TABLE and CREATE VIEW, materializing the plan's result for a table.
+    fn create_table(
+        &self,
+        name: &ast::ObjectName,
+        plan: LogicalPlanBuilder,
+        materialized: bool,
+        replace: bool,
+        if_not_exists: bool,
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let ident = normalize(name);
+        if ident.has_namespace() {
+            unsupported_sql_err!(
+                "Creating a table with a qualified name is not supported, found {}",
+                ident
+            )
+        }
+        if if_not_exists && self.session().has_temp_table(&ident.name) {
+            return plan_empty();
+        }
+        if materialized {
+            self.session()
+                .create_temp_table_as(ident.name, plan, replace)?;
+        } else {
+            self.session().create_temp_table(
+                ident.name,
+                &TableSource::View(plan.build()),
+                replace,
+            )?;
+        }
+        plan_empty()
+    }
+
+    /// INSERT [INTO | OVERWRITE] <table> [(<columns>)] <query>
+    ///
+    /// The query's columns are matched to the table's columns by position, and columns of the
+    /// table that are not inserted into are null. Like INSERT INTO DIRECTORY, this plans a sink
+    /// which writes the rows to the table when it is executed, and views can't be inserted into.
+    fn plan_insert(&mut self, insert: &ast::Insert) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let Some(source) = &insert.source else {
+            unsupported_sql_err!("INSERT without a query is not supported")
+        };
+        if insert.or.is_some()
+            || insert.ignore
+            || insert.replace_into
+            || insert.priority.is_some()
+            || insert.table_alias.is_some()
+            || insert.partitioned.is_some()
+            || !insert.after_columns.is_empty()
+            || insert.on.is_some()
+            || insert.returning.is_some()
+            || insert.insert_alias.is_some()
+        {
+            unsupported_sql_err!("INSERT options are not supported")
+        }
+        let ident = normalize(&insert.table_name);
+        if !self.session().has_table(&ident) {
+            table_not_found_err!(ident.to_string())
+        }
+        let table = self.session().get_table(&ident)?;
+        let target = LogicalPlanBuilder::from(table.get_logical_plan()?);
+        let target_schema = target.schema();
+
+        let columns = if insert.columns.is_empty() {
+            target_schema.names()
+        } else {
+            insert.columns.iter().map(|c| c.value.clone()).collect()
+        };
+        if let Some(column) = columns.iter().find(|c| !target_schema.has_field(c)) {
+            return Err(PlannerError::column_not_found(column, ident.to_string()));
+        }
+        let rows = self.plan_query(source)?;
+        let source_names = rows.schema().names();
+        if source_names.len() != columns.len() {
+            invalid_operation_err!(
+                "INSERT into {} has {} target columns but its query returns {} columns",
+                ident,
+                columns.len(),
+                source_names.len()
+            )
+        }
+        let projection = target_schema
+            .fields
+            .values()
+            .map(|field| {
+                let value = match columns.iter().position(|c| *c == field.name) {
+                    Some(i) => resolved_col(source_names[i].as_str()),
+                    None => null_lit(),
+                };
+                value.cast(&field.dtype).alias(field.name.as_str())
+            })
+            .collect();
+        let rows = rows.select(projection)?;
+        let mode = if insert.overwrite {
+            WriteMode::Overwrite
+        } else {
+            WriteMode::Append
+        };
+        Ok(table.write_plan(&ident.to_string(), rows, mode)?)
+    }
+
+    /// INSERT INTO DIRECTORY '<path>' [STORED AS <format>] <query>
+    ///
+    /// This writes the query's result to files under the path, like `DataFrame.write_parquet`.
+    fn plan_insert_directory(
+        &mut self,
+        overwrite: bool,
+        path: &str,
+        file_format: &Option<ast::FileFormat>,
+        source: &ast::Query,
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        if overwrite {
+            unsupported_sql_err!(
+                "INSERT OVERWRITE DIRECTORY is not supported, did you mean INSERT INTO DIRECTORY?"
+            )
+        }
+        let file_format = match file_format {
+            None | Some(ast::FileFormat::PARQUET) => FileFormat::Parquet,
+            Some(ast::FileFormat::TEXTFILE) => FileFormat::Csv,
+            Some(ast::FileFormat::JSONFILE) => FileFormat::Json,
+            Some(other) => unsupported_sql_err!("STORED AS {} is not supported", other),
+        };
+        let plan = self.plan_query(source)?;
+        Ok(plan.table_write(path, file_format, None, None, None, None)?)
+    }
+
+    /// DROP {TABLE | VIEW} [IF EXISTS] <name> [, ...]
+    fn plan_drop(
+        &self,
+        object_type: &ast::ObjectType,
+        if_exists: bool,
+        names: &[ast::ObjectName],
+        cascade: bool,
+        purge: bool,
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        if !matches!(object_type, ast::ObjectType::Table | ast::ObjectType::View) {
+            unsupported_sql_err!("DROP {} is not supported", object_type)
+        }
+        if cascade || purge {
+            unsupported_sql_err!("DROP {} options are not supported", object_type)
+        }
+        for name in names {
+            let ident = normalize(name);
+            if ident.has_namespace() {
+                unsupported_sql_err!(
+                    "Dropping a table with a qualified name is not supported, found {}",
+                    ident
+                )
+            }
+            if !self.session().has_temp_table(&ident.name) {
+                if if_exists {
+                    continue;
+                }
+                table_not_found_err!(ident.to_string())
+            }
+            self.session().detach_table(&ident.name)?;
+        }
+        plan_empty()
+    }
+
+    /// USE [CATALOG | SCHEMA | DATABASE] <name>
+    ///
+    /// A plain `USE a.b` sets the current catalog to `a` and namespace to `b` if `a` is an attached
+    /// catalog, otherwise it sets the namespace to `a.b` within the current catalog.
+    fn plan_use(&self, use_: &ast::Use) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let parts = |name: &ast::ObjectName| -> Vec<String> {
+            name.0.iter().map(|ident| ident.value.clone()).collect()
+        };
+        let session = self.session();
+        match use_ {
+            ast::Use::Catalog(name) => match parts(name).as_slice() {
+                [catalog] => session.set_catalog(catalog)?,
+                _ => invalid_operation_err!("USE CATALOG expects a catalog name, found {}", name),
+            },
+            ast::Use::Schema(name) | ast::Use::Database(name) => {
+                session.set_namespace(parts(name))?;
+            }
+            ast::Use::Object(name) => {
+                let parts = parts(name);
+                match parts.split_first() {
+                    Some((catalog, namespace)) if session.has_catalog(catalog) => {
+                        session.set_catalog(catalog)?;
+                        session.set_namespace(namespace.to_vec())?;
+                    }
+                    // a namespace is only meaningful within an attached current catalog
+                    Some((first, _)) if session.current_catalog().is_err() => {
+                        return Err(CatalogError::obj_not_found(
+                            "Catalog",
+                            &Identifier::simple(first.as_str()),
+                        )
+                        .into());
+                    }
+                    _ => session.set_namespace(parts)?,
+                }
+            }
+            other => unsupported_sql_err!("{} is not supported", other),
+        }
+        plan_empty()
+    }
+
+    /// SHOW TABLES [LIKE '<pattern>']
+    fn plan_show_tables(
+        &self,
+        extended: bool,
+        full: bool,
+        db_name: &Option<ast::Ident>,
+        filter: &Option<ast::ShowStatementFilter>,
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        if extended || full {
+            unsupported_sql_err!("SHOW [EXTENDED | FULL] TABLES is not supported")
+        }
+        if db_name.is_some() {
+            unsupported_sql_err!("SHOW TABLES FROM <name> is not supported")
+        }
+        let mut tables = self.session().list_tables(None)?;
+        if let Some(filter) = filter {
+            let ast::ShowStatementFilter::Like(pattern) = filter else {
+                unsupported_sql_err!("SHOW TABLES {} is not supported", filter)
+            };
+            let pattern =
+                regex::Regex::new(&like_to_regex(pattern, None, false)?).map_err(|e| {
+                    PlannerError::invalid_operation(format!("invalid LIKE pattern: {e}"))
+                })?;
+            tables.retain(|table| pattern.is_match(table));
+        }
+        tables.sort();
+        plan_strings("table", tables)
+    }
+
+    /// SHOW CATALOGS
+    fn plan_show_catalogs(&self) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let mut catalogs = self.session().list_catalogs(None)?;
+        catalogs.sort();
+        plan_strings("catalog", catalogs)
+    }
+}
+
+/// Returns the empty result of a DDL or DML statement.
+fn plan_empty() -> SQLPlannerResult<LogicalPlanBuilder> {
+    Ok(plan_batches(Arc::new(Schema::empty()), vec![])?)
+}
+
+/// Returns a result with a single string column, e.g. for SHOW statements.
+fn plan_strings(column: &str, values: Vec<String>) -> SQLPlannerResult<LogicalPlanBuilder> {
+    let values = Utf8Array::from_values(column, values.iter()).into_series();
+    let batch = RecordBatch::from_nonempty_columns(vec![values])?;
+    Ok(plan_batches(batch.schema.clone(), vec![batch])?)
+}
+
+/// Creates an in-memory source over the record batches.
+///
+/// The source's cache entry holds the partition set itself rather than a key into a runner's
+/// partition cache, so it is read by the native runner.
+fn plan_batches(schema: SchemaRef, batches: Vec<RecordBatch>) -> DaftResult<LogicalPlanBuilder> {
+    let num_rows = batches.iter().map(RecordBatch::len).sum();
+    let size_bytes = batches
+        .iter()
+        .map(RecordBatch::size_bytes)
+        .sum::<DaftResult<usize>>()?;
+    let pset = Arc::new(MicroPartitionSet::from_tables(0, batches)?);
+    let num_partitions = pset.partitions.len();
+    let cache_key = uuid::Uuid::new_v4().to_string();
+    let cache_entry = PartitionCacheEntry::new_rust(cache_key.clone(), pset);
+    LogicalPlanBuilder::in_memory_scan(
+        &cache_key,
+        cache_entry,
+        schema,
+        num_partitions,
+        size_bytes,
+        num_rows,
+    )
+}
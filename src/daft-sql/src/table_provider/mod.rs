@@ -4,6 +4,9 @@ mod read_iceberg;
 mod read_json;
 mod read_parquet;
 
+#[cfg(feature = "python")]
+pub(crate) mod python;
+
 use std::{collections::HashMap, sync::Arc};
 
 use daft_dsl::{Expr, ExprRef};
@@ -67,7 +70,10 @@ impl<'a> SQLPlanner<'a> {
     ) -> SQLPlannerResult<LogicalPlanBuilder> {
         let fns = &SQL_TABLE_FUNCTIONS;
 
-        let Some(func) = fns.get(fn_name) else {
+        let Some(func) = self
+            .get_bound_table_function(fn_name)
+            .or_else(|| fns.get(fn_name).cloned())
+        else {
             unsupported_sql_err!("Function `{}` not found", fn_name);
         };
 
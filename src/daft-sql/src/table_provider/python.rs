@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use common_error::DaftError;
+use daft_core::python::PySeries;
+use daft_dsl::{python::PyExpr, Expr, ExprRef};
+use daft_logical_plan::{LogicalPlanBuilder, PyLogicalPlanBuilder};
+use pyo3::{
+    intern,
+    prelude::*,
+    types::{PyDict, PyTuple},
+};
+use sqlparser::ast::{FunctionArg, TableFunctionArgs};
+
+use super::SQLTableFunction;
+use crate::{error::SQLPlannerResult, planner::SQLPlanner};
+
+/// A table function implemented by a Python callable which returns a DataFrame, e.g. one that is
+/// registered by the user.
+///
+/// Constant arguments are passed as Python values, and all other arguments as expressions. When the
+/// function is joined laterally, its arguments may reference the columns of the tables to its left,
+/// and the DataFrame it returns is then decorrelated into the join.
+pub(crate) struct PyTableFunction(pub(crate) Arc<PyObject>);
+
+impl SQLTableFunction for PyTableFunction {
+    fn plan(
+        &self,
+        planner: &SQLPlanner,
+        args: &TableFunctionArgs,
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let mut positional = Vec::new();
+        let mut named = Vec::new();
+        for arg in &args.args {
+            match arg {
+                FunctionArg::Unnamed(arg) => {
+                    positional.push(planner.try_unwrap_function_arg_expr(arg)?);
+                }
+                FunctionArg::Named { name, arg, .. } => {
+                    named.push((
+                        name.value.clone(),
+                        planner.try_unwrap_function_arg_expr(arg)?,
+                    ));
+                }
+            }
+        }
+
+        let builder = Python::with_gil(|py| -> PyResult<LogicalPlanBuilder> {
+            let args = PyTuple::new(
+                py,
+                positional
+                    .into_iter()
+                    .map(|expr| to_py(py, expr))
+                    .collect::<PyResult<Vec<_>>>()?,
+            )?;
+            let kwargs = PyDict::new(py);
+            for (name, expr) in named {
+                kwargs.set_item(name, to_py(py, expr)?)?;
+            }
+            // df = func(*args, **kwargs)
+            let df = self.0.bind(py).call(args, Some(&kwargs))?;
+            // builder = df._builder._builder
+            let builder: PyLogicalPlanBuilder = df
+                .getattr(intern!(py, "_builder"))?
+                .getattr(intern!(py, "_builder"))?
+                .extract()?;
+            Ok(builder.builder)
+        })
+        .map_err(DaftError::from)?;
+
+        Ok(builder)
+    }
+}
+
+/// Converts an argument to a Python value if it is constant, or else to an expression.
+fn to_py(py: Python<'_>, expr: ExprRef) -> PyResult<Bound<'_, PyAny>> {
+    if let Expr::Literal(value) = expr.as_ref() {
+        // value = Series.to_pylist()[0]
+        PySeries::from(value.to_series()).to_pylist(py)?.get_item(0)
+    } else {
+        // expr = Expression._from_pyexpr(expr)
+        py.import(intern!(py, "daft.expressions"))?
+            .getattr(intern!(py, "Expression"))?
+            .call_method1(intern!(py, "_from_pyexpr"), (PyExpr::from(expr),))
+    }
+}
@@ -0,0 +1,233 @@
+use std::{io::Write, sync::Arc};
+
+use arrow2::io::csv::write::{new_serializer, SerializeOptions};
+use common_error::DaftResult;
+use common_file_formats::CsvWriteOptions;
+use daft_io::{IOConfig, IOStatsContext};
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+
+use crate::{
+    storage::{make_write_result, OutputFilePath, StorageBackend},
+    FileWriter,
+};
+
+/// Native CSV writer built on the arrow2 CSV serializers, without going through PyArrow.
+///
+/// The underlying file is lazily created on the first write, at which point the header row is written
+/// using the column names of the first micropartition.
+pub(crate) struct CsvWriter {
+    output_path: OutputFilePath,
+    partition_values: Option<RecordBatch>,
+    io_config: Option<IOConfig>,
+    options: CsvWriteOptions,
+    serialize_options: SerializeOptions,
+    backend: Option<StorageBackend>,
+    is_closed: bool,
+}
+
+impl CsvWriter {
+    pub(crate) fn try_new(
+        root_dir: &str,
+        file_idx: usize,
+        options: CsvWriteOptions,
+        io_config: &Option<IOConfig>,
+        partition_values: Option<&RecordBatch>,
+    ) -> DaftResult<Self> {
+        let output_path = OutputFilePath::new(root_dir, file_idx, "csv", partition_values)?;
+        let serialize_options = SerializeOptions {
+            delimiter: options.delimiter as u8,
+            quote: options.quote as u8,
+            ..Default::default()
+        };
+        Ok(Self {
+            output_path,
+            partition_values: partition_values.cloned(),
+            io_config: io_config.clone(),
+            options,
+            serialize_options,
+            backend: None,
+            is_closed: false,
+        })
+    }
+
+    fn create_backend(&mut self, data: &MicroPartition) -> DaftResult<()> {
+        if self.backend.is_none() {
+            let mut backend = StorageBackend::try_new(&self.output_path, &self.io_config)?;
+            if self.options.header {
+                let header = data
+                    .schema()
+                    .fields
+                    .keys()
+                    .map(|name| quote_field(name.as_bytes(), &self.serialize_options))
+                    .collect::<Vec<_>>()
+                    .join(&[self.serialize_options.delimiter][..]);
+                backend.write_all(&header)?;
+                backend.write_all(b"\n")?;
+            }
+            self.backend = Some(backend);
+        }
+        Ok(())
+    }
+
+    fn current_bytes_written(&self) -> usize {
+        self.backend
+            .as_ref()
+            .map_or(0, StorageBackend::bytes_written)
+    }
+}
+
+/// Quotes a header field if it contains the delimiter, the quote character or a newline,
+/// escaping embedded quote characters by doubling them.
+fn quote_field(field: &[u8], options: &SerializeOptions) -> Vec<u8> {
+    let needs_quoting = field
+        .iter()
+        .any(|b| *b == options.delimiter || *b == options.quote || *b == b'\n' || *b == b'\r');
+    if !needs_quoting {
+        return field.to_vec();
+    }
+    let mut quoted = Vec::with_capacity(field.len() + 2);
+    quoted.push(options.quote);
+    for b in field {
+        if *b == options.quote {
+            quoted.push(options.quote);
+        }
+        quoted.push(*b);
+    }
+    quoted.push(options.quote);
+    quoted
+}
+
+/// Serializes every row of `table` into `writer`, writing `null_value` for null cells.
+fn write_rows<W: Write>(
+    writer: &mut W,
+    table: &RecordBatch,
+    options: &SerializeOptions,
+    null_value: &[u8],
+) -> DaftResult<()> {
+    let arrays = table.get_inner_arrow_arrays().collect::<Vec<_>>();
+    let mut serializers = arrays
+        .iter()
+        .map(|array| new_serializer(array.as_ref(), options))
+        .collect::<arrow2::error::Result<Vec<_>>>()?;
+
+    let mut row = Vec::new();
+    for row_idx in 0..table.len() {
+        for (col_idx, (array, serializer)) in arrays.iter().zip(serializers.iter_mut()).enumerate()
+        {
+            if col_idx > 0 {
+                row.push(options.delimiter);
+            }
+            // The serializer has to be advanced for every row, including nulls.
+            let field = serializer.next().unwrap_or_default();
+            if array.is_null(row_idx) {
+                row.extend_from_slice(null_value);
+            } else {
+                row.extend_from_slice(field);
+            }
+        }
+        row.push(b'\n');
+        writer.write_all(&row)?;
+        row.clear();
+    }
+    Ok(())
+}
+
+impl FileWriter for CsvWriter {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<RecordBatch>;
+
+    fn write(&mut self, data: Self::Input) -> DaftResult<usize> {
+        assert!(!self.is_closed, "Cannot write to a closed CsvWriter");
+        let tables = data.concat_or_get(IOStatsContext::new("CsvWriter::write"))?;
+        let Some(table) = tables.first() else {
+            return Ok(0);
+        };
+        let start_position = self.current_bytes_written();
+
+        self.create_backend(&data)?;
+        write_rows(
+            self.backend.as_mut().unwrap(),
+            table,
+            &self.serialize_options,
+            self.options.null_value.as_bytes(),
+        )?;
+
+        Ok(self.current_bytes_written() - start_position)
+    }
+
+    fn bytes_written(&self) -> usize {
+        self.current_bytes_written()
+    }
+
+    fn close(&mut self) -> DaftResult<Self::Result> {
+        self.is_closed = true;
+        // the file is only created on the first write, so there's nothing to report if it never happened.
+        let Some(backend) = self.backend.take() else {
+            return Ok(None);
+        };
+        backend.finish()?;
+        Ok(Some(make_write_result(
+            &self.output_path.path,
+            self.partition_values.as_ref(),
+        )?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use common_file_formats::CsvWriteOptions;
+
+    use super::CsvWriter;
+    use crate::{test::make_dummy_mp, FileWriter};
+
+    #[test]
+    fn test_native_csv_writer_header_and_options() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let options = CsvWriteOptions {
+            delimiter: '|',
+            ..Default::default()
+        };
+        let mut writer = CsvWriter::try_new(dir.path().to_str().unwrap(), 0, options, &None, None)?;
+
+        let mut bytes_written = 0;
+        for _ in 0..2 {
+            bytes_written += writer.write(make_dummy_mp(10))?;
+        }
+        assert_eq!(bytes_written, writer.bytes_written());
+
+        let result = writer.close()?.unwrap();
+        let path = result
+            .get_column("path")?
+            .utf8()?
+            .get(0)
+            .expect("writer should return the path it wrote to")
+            .to_string();
+
+        let contents = std::fs::read_to_string(&path)?;
+        let lines = contents.lines().collect::<Vec<_>>();
+        // One header row followed by every written row.
+        assert_eq!(lines.len(), 21);
+        assert_eq!(lines[0], "ints");
+        assert_eq!(lines[1], "42");
+        assert_eq!(contents.len(), bytes_written);
+        Ok(())
+    }
+
+    #[test]
+    fn test_native_csv_writer_no_writes() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let mut writer = CsvWriter::try_new(
+            dir.path().to_str().unwrap(),
+            0,
+            CsvWriteOptions::default(),
+            &None,
+            None,
+        )?;
+
+        assert!(writer.close()?.is_none());
+        assert_eq!(std::fs::read_dir(dir.path())?.count(), 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use arrow2::{
+    chunk::Chunk,
+    io::ipc::write::{Compression, FileWriter as ArrowIpcFileWriter, WriteOptions},
+};
+use common_error::{DaftError, DaftResult};
+use daft_io::IOConfig;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+
+use crate::{
+    storage::{make_write_result, OutputFilePath, StorageBackend},
+    FileWriter,
+};
+
+/// Parses the user-facing compression codec name into Arrow IPC buffer compression.
+/// IPC buffers can only be compressed with `lz4` or `zstd`.
+fn parse_ipc_compression(compression: Option<&str>) -> DaftResult<Option<Compression>> {
+    let Some(compression) = compression else {
+        return Ok(None);
+    };
+    match compression.to_lowercase().as_str() {
+        "none" | "uncompressed" => Ok(None),
+        "lz4" => Ok(Some(Compression::LZ4)),
+        "zstd" => Ok(Some(Compression::ZSTD)),
+        other => Err(DaftError::ValueError(format!(
+            "Unsupported Arrow IPC compression codec: {other}"
+        ))),
+    }
+}
+
+/// Native Arrow IPC file (Feather v2) writer built on the arrow2 IPC writer.
+///
+/// Each call to `write` produces one record batch per table of the micropartition. The underlying file is
+/// lazily created on the first write, using the schema of the first micropartition.
+pub(crate) struct IpcWriter {
+    output_path: OutputFilePath,
+    partition_values: Option<RecordBatch>,
+    io_config: Option<IOConfig>,
+    options: WriteOptions,
+    file_writer: Option<ArrowIpcFileWriter<StorageBackend>>,
+    bytes_written: Option<Arc<AtomicUsize>>,
+    is_closed: bool,
+}
+
+impl IpcWriter {
+    pub(crate) fn try_new(
+        root_dir: &str,
+        file_idx: usize,
+        compression: &Option<String>,
+        io_config: &Option<IOConfig>,
+        partition_values: Option<&RecordBatch>,
+    ) -> DaftResult<Self> {
+        let output_path = OutputFilePath::new(root_dir, file_idx, "arrow", partition_values)?;
+        let options = WriteOptions {
+            compression: parse_ipc_compression(compression.as_deref())?,
+        };
+        Ok(Self {
+            output_path,
+            partition_values: partition_values.cloned(),
+            io_config: io_config.clone(),
+            options,
+            file_writer: None,
+            bytes_written: None,
+            is_closed: false,
+        })
+    }
+
+    fn create_file_writer(
+        &mut self,
+        data: &MicroPartition,
+    ) -> DaftResult<&mut ArrowIpcFileWriter<StorageBackend>> {
+        if self.file_writer.is_none() {
+            let backend = StorageBackend::try_new(&self.output_path, &self.io_config)?;
+            self.bytes_written = Some(backend.bytes_written_handle());
+            let arrow_schema = data.schema().to_arrow()?;
+            self.file_writer = Some(ArrowIpcFileWriter::try_new(
+                backend,
+                arrow_schema,
+                None,
+                self.options,
+            )?);
+        }
+        Ok(self.file_writer.as_mut().unwrap())
+    }
+
+    fn current_bytes_written(&self) -> usize {
+        self.bytes_written
+            .as_ref()
+            .map_or(0, |bytes_written| bytes_written.load(Ordering::Relaxed))
+    }
+}
+
+impl FileWriter for IpcWriter {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<RecordBatch>;
+
+    fn write(&mut self, data: Self::Input) -> DaftResult<usize> {
+        assert!(!self.is_closed, "Cannot write to a closed IpcWriter");
+        let tables = data.get_tables()?;
+        if tables.is_empty() {
+            return Ok(0);
+        }
+        let start_position = self.current_bytes_written();
+
+        let file_writer = self.create_file_writer(&data)?;
+        for table in tables.iter() {
+            let chunk = Chunk::try_new(table.get_inner_arrow_arrays().collect::<Vec<_>>())?;
+            file_writer.write(&chunk, None)?;
+        }
+
+        Ok(self.current_bytes_written() - start_position)
+    }
+
+    fn bytes_written(&self) -> usize {
+        self.current_bytes_written()
+    }
+
+    fn close(&mut self) -> DaftResult<Self::Result> {
+        self.is_closed = true;
+        // the file is only created on the first write, so there's nothing to report if it never happened.
+        let Some(mut file_writer) = self.file_writer.take() else {
+            return Ok(None);
+        };
+        file_writer.finish()?;
+        file_writer.into_inner().finish()?;
+        Ok(Some(make_write_result(
+            &self.output_path.path,
+            self.partition_values.as_ref(),
+        )?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use arrow2::io::ipc::read::{read_file_metadata, FileReader};
+    use common_error::DaftResult;
+
+    use super::IpcWriter;
+    use crate::{test::make_dummy_mp, FileWriter};
+
+    #[test]
+    fn test_native_ipc_writer_roundtrip() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let mut writer = IpcWriter::try_new(
+            dir.path().to_str().unwrap(),
+            0,
+            &Some("zstd".to_string()),
+            &None,
+            None,
+        )?;
+
+        let mut bytes_written = 0;
+        for _ in 0..3 {
+            bytes_written += writer.write(make_dummy_mp(100))?;
+        }
+        assert_eq!(bytes_written, writer.bytes_written());
+
+        let result = writer.close()?.unwrap();
+        let path = result
+            .get_column("path")?
+            .utf8()?
+            .get(0)
+            .expect("writer should return the path it wrote to")
+            .to_string();
+        assert_eq!(
+            std::path::Path::new(&path).extension(),
+            Some("arrow".as_ref())
+        );
+
+        let mut file = File::open(&path)?;
+        let metadata = read_file_metadata(&mut file)?;
+        assert_eq!(metadata.blocks.len(), 3);
+        let num_rows = FileReader::new(file, metadata, None, None)
+            .map(|chunk| chunk.map(|chunk| chunk.len()))
+            .sum::<arrow2::error::Result<usize>>()?;
+        assert_eq!(num_rows, 300);
+        Ok(())
+    }
+
+    #[test]
+    fn test_native_ipc_writer_no_writes() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let mut writer = IpcWriter::try_new(dir.path().to_str().unwrap(), 0, &None, &None, None)?;
+
+        assert!(writer.close()?.is_none());
+        assert_eq!(std::fs::read_dir(dir.path())?.count(), 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,194 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::{Array, StructArray},
+    datatypes::{DataType, Field},
+    io::ndjson::write::{FileWriter as NdjsonFileWriter, Serializer},
+};
+use common_error::DaftResult;
+use daft_io::{IOConfig, IOStatsContext};
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+
+use crate::{
+    storage::{make_write_result, OutputFilePath, StorageBackend},
+    FileWriter,
+};
+
+/// Native newline-delimited JSON writer built on the arrow2 ndjson serializer.
+///
+/// Each row is written as a single JSON object keyed by column name. The underlying file is lazily
+/// created on the first write.
+pub(crate) struct JsonWriter {
+    output_path: OutputFilePath,
+    partition_values: Option<RecordBatch>,
+    io_config: Option<IOConfig>,
+    backend: Option<StorageBackend>,
+    is_closed: bool,
+}
+
+impl JsonWriter {
+    pub(crate) fn try_new(
+        root_dir: &str,
+        file_idx: usize,
+        io_config: &Option<IOConfig>,
+        partition_values: Option<&RecordBatch>,
+    ) -> DaftResult<Self> {
+        let output_path = OutputFilePath::new(root_dir, file_idx, "json", partition_values)?;
+        Ok(Self {
+            output_path,
+            partition_values: partition_values.cloned(),
+            io_config: io_config.clone(),
+            backend: None,
+            is_closed: false,
+        })
+    }
+
+    fn current_bytes_written(&self) -> usize {
+        self.backend
+            .as_ref()
+            .map_or(0, StorageBackend::bytes_written)
+    }
+}
+
+/// Packs the columns of `table` into a single struct array, so that each row serializes as one JSON object.
+fn table_to_struct_array(table: &RecordBatch) -> arrow2::error::Result<Box<dyn Array>> {
+    let arrays = table.get_inner_arrow_arrays().collect::<Vec<_>>();
+    let fields = table
+        .schema
+        .fields
+        .keys()
+        .zip(arrays.iter())
+        .map(|(name, array)| Field::new(name, array.data_type().clone(), true))
+        .collect::<Vec<_>>();
+    Ok(StructArray::try_new(DataType::Struct(fields), arrays, None)?.boxed())
+}
+
+impl FileWriter for JsonWriter {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<RecordBatch>;
+
+    fn write(&mut self, data: Self::Input) -> DaftResult<usize> {
+        assert!(!self.is_closed, "Cannot write to a closed JsonWriter");
+        let tables = data.concat_or_get(IOStatsContext::new("JsonWriter::write"))?;
+        let Some(table) = tables.first() else {
+            return Ok(0);
+        };
+        let start_position = self.current_bytes_written();
+
+        if self.backend.is_none() {
+            self.backend = Some(StorageBackend::try_new(&self.output_path, &self.io_config)?);
+        }
+        let backend = self.backend.as_mut().unwrap();
+        let serializer = Serializer::new(std::iter::once(table_to_struct_array(table)), Vec::new());
+        for result in NdjsonFileWriter::new(backend, serializer) {
+            result?;
+        }
+
+        Ok(self.current_bytes_written() - start_position)
+    }
+
+    fn bytes_written(&self) -> usize {
+        self.current_bytes_written()
+    }
+
+    fn close(&mut self) -> DaftResult<Self::Result> {
+        self.is_closed = true;
+        // the file is only created on the first write, so there's nothing to report if it never happened.
+        let Some(backend) = self.backend.take() else {
+            return Ok(None);
+        };
+        backend.finish()?;
+        Ok(Some(make_write_result(
+            &self.output_path.path,
+            self.partition_values.as_ref(),
+        )?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::{
+        prelude::{DataType, Field, Int64Array, Schema, Utf8Array},
+        series::IntoSeries,
+    };
+    use daft_micropartition::MicroPartition;
+    use daft_recordbatch::RecordBatch;
+
+    use super::JsonWriter;
+    use crate::{test::make_dummy_mp, FileWriter};
+
+    fn written_path(result: Option<RecordBatch>) -> DaftResult<String> {
+        Ok(result
+            .unwrap()
+            .get_column("path")?
+            .utf8()?
+            .get(0)
+            .expect("writer should return the path it wrote to")
+            .to_string())
+    }
+
+    #[test]
+    fn test_native_json_writer_one_object_per_row() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let mut writer = JsonWriter::try_new(dir.path().to_str().unwrap(), 0, &None, None)?;
+
+        let mut bytes_written = 0;
+        for _ in 0..2 {
+            bytes_written += writer.write(make_dummy_mp(10))?;
+        }
+        assert_eq!(bytes_written, writer.bytes_written());
+
+        let path = written_path(writer.close()?)?;
+        assert!(path.ends_with(".json"));
+        let contents = std::fs::read_to_string(&path)?;
+        let lines = contents.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 20);
+        assert!(lines.iter().all(|line| *line == r#"{"ints":42}"#));
+        assert_eq!(contents.len(), bytes_written);
+        Ok(())
+    }
+
+    #[test]
+    fn test_native_json_writer_multiple_columns_and_nulls() -> DaftResult<()> {
+        let ints = Int64Array::from_regular_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(1), None].into_iter(),
+        )?
+        .into_series();
+        let strs = Utf8Array::from_iter("b", vec![None, Some("x")].into_iter()).into_series();
+        let schema = Arc::new(Schema::new(vec![
+            ints.field().clone(),
+            strs.field().clone(),
+        ])?);
+        let table = RecordBatch::new_with_size(schema.clone(), vec![ints, strs], 2)?;
+        let mp = Arc::new(MicroPartition::new_loaded(
+            schema,
+            Arc::new(vec![table]),
+            None,
+        ));
+
+        let dir = tempfile::tempdir()?;
+        let mut writer = JsonWriter::try_new(dir.path().to_str().unwrap(), 0, &None, None)?;
+        writer.write(mp)?;
+        let contents = std::fs::read_to_string(written_path(writer.close()?)?)?;
+        assert_eq!(
+            contents.lines().collect::<Vec<_>>(),
+            [r#"{"a":1,"b":null}"#, r#"{"a":null,"b":"x"}"#]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_native_json_writer_no_writes() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let mut writer = JsonWriter::try_new(dir.path().to_str().unwrap(), 0, &None, None)?;
+
+        assert!(writer.close()?.is_none());
+        assert_eq!(std::fs::read_dir(dir.path())?.count(), 0);
+        Ok(())
+    }
+}
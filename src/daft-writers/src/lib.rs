@@ -1,9 +1,14 @@
 #![feature(hash_raw_entry)]
 #![feature(let_chains)]
 mod batch;
+mod csv_writer;
 mod file;
+mod ipc_writer;
+mod json_writer;
+mod parquet_writer;
 mod partition;
 mod physical;
+mod storage;
 
 #[cfg(test)]
 mod test;
@@ -68,7 +73,7 @@ pub fn make_physical_writer_factory(
     file_info: &OutputFileInfo,
     cfg: &DaftExecutionConfig,
 ) -> Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<RecordBatch>>> {
-    let base_writer_factory = PhysicalWriterFactory::new(file_info.clone());
+    let base_writer_factory = PhysicalWriterFactory::new(file_info.clone(), cfg);
     match file_info.file_format {
         FileFormat::Parquet => {
             let file_size_calculator = TargetInMemorySizeBytesCalculator::new(
@@ -101,7 +106,8 @@ pub fn make_physical_writer_factory(
                 Arc::new(file_writer_factory)
             }
         }
-        FileFormat::Csv => {
+        // JSON is a row-oriented text format like CSV, so it shares the CSV file sizing settings.
+        FileFormat::Csv | FileFormat::Json => {
             let file_size_calculator = TargetInMemorySizeBytesCalculator::new(
                 cfg.csv_target_filesize,
                 cfg.csv_inflation_factor,
@@ -122,7 +128,27 @@ pub fn make_physical_writer_factory(
                 Arc::new(file_writer_factory)
             }
         }
-        _ => unreachable!("Physical write should only support Parquet and CSV"),
+        // IPC files hold data in its in-memory Arrow layout, so they are sized without an inflation factor.
+        FileFormat::Ipc => {
+            let file_size_calculator =
+                TargetInMemorySizeBytesCalculator::new(cfg.parquet_target_filesize, 1.0);
+
+            let file_writer_factory = TargetFileSizeWriterFactory::new(
+                Arc::new(base_writer_factory),
+                Arc::new(file_size_calculator),
+            );
+
+            if let Some(partition_cols) = &file_info.partition_cols {
+                let partitioned_writer_factory = PartitionedWriterFactory::new(
+                    Arc::new(file_writer_factory),
+                    partition_cols.clone(),
+                );
+                Arc::new(partitioned_writer_factory)
+            } else {
+                Arc::new(file_writer_factory)
+            }
+        }
+        _ => unreachable!("Physical write should only support Parquet, CSV, JSON and Arrow IPC"),
     }
 }
 
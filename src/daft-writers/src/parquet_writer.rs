@@ -0,0 +1,222 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use arrow2::{
+    chunk::Chunk,
+    io::parquet::write::{
+        row_group_iter, transverse, CompressionOptions, Encoding,
+        FileWriter as ArrowParquetFileWriter, Version, WriteOptions,
+    },
+};
+use common_error::{DaftError, DaftResult};
+use daft_io::{IOConfig, IOStatsContext};
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+
+use crate::{
+    storage::{make_write_result, OutputFilePath, StorageBackend},
+    FileWriter,
+};
+
+/// Parses the user-facing compression codec name into parquet compression options.
+/// Accepts the same names as the pyarrow writer, e.g. `snappy`, `gzip`, `zstd`, `lz4`, `brotli` and `none`,
+/// and defaults to snappy like the pyarrow writer.
+pub(crate) fn parse_parquet_compression(
+    compression: Option<&str>,
+) -> DaftResult<CompressionOptions> {
+    let Some(compression) = compression else {
+        return Ok(CompressionOptions::Snappy);
+    };
+    match compression.to_lowercase().as_str() {
+        "none" | "uncompressed" => Ok(CompressionOptions::Uncompressed),
+        "snappy" => Ok(CompressionOptions::Snappy),
+        "gzip" => Ok(CompressionOptions::Gzip(None)),
+        "zstd" => Ok(CompressionOptions::Zstd(None)),
+        "lz4" => Ok(CompressionOptions::Lz4Raw),
+        "brotli" => Ok(CompressionOptions::Brotli(None)),
+        other => Err(DaftError::ValueError(format!(
+            "Unsupported parquet compression codec: {other}"
+        ))),
+    }
+}
+
+/// Native Parquet writer built on the arrow2 / parquet2 writer, without going through PyArrow.
+///
+/// Each call to `write` produces exactly one row group, so the row group size is controlled by the
+/// `TargetBatchWriter` wrapping this writer. The underlying file is lazily created on the first write,
+/// using the schema of the first micropartition.
+pub(crate) struct ParquetWriter {
+    output_path: OutputFilePath,
+    partition_values: Option<RecordBatch>,
+    io_config: Option<IOConfig>,
+    options: WriteOptions,
+    file_writer: Option<ArrowParquetFileWriter<StorageBackend>>,
+    bytes_written: Option<Arc<AtomicUsize>>,
+    is_closed: bool,
+}
+
+impl ParquetWriter {
+    pub(crate) fn try_new(
+        root_dir: &str,
+        file_idx: usize,
+        compression: &Option<String>,
+        io_config: &Option<IOConfig>,
+        partition_values: Option<&RecordBatch>,
+    ) -> DaftResult<Self> {
+        let output_path = OutputFilePath::new(root_dir, file_idx, "parquet", partition_values)?;
+        let options = WriteOptions {
+            write_statistics: true,
+            compression: parse_parquet_compression(compression.as_deref())?,
+            version: Version::V1,
+            data_pagesize_limit: None,
+        };
+        Ok(Self {
+            output_path,
+            partition_values: partition_values.cloned(),
+            io_config: io_config.clone(),
+            options,
+            file_writer: None,
+            bytes_written: None,
+            is_closed: false,
+        })
+    }
+
+    fn create_file_writer(
+        &mut self,
+        data: &MicroPartition,
+    ) -> DaftResult<&mut ArrowParquetFileWriter<StorageBackend>> {
+        if self.file_writer.is_none() {
+            let backend = StorageBackend::try_new(&self.output_path, &self.io_config)?;
+            self.bytes_written = Some(backend.bytes_written_handle());
+            let arrow_schema = data.schema().to_arrow()?;
+            self.file_writer = Some(ArrowParquetFileWriter::try_new(
+                backend,
+                arrow_schema,
+                self.options,
+            )?);
+        }
+        Ok(self.file_writer.as_mut().unwrap())
+    }
+
+    fn current_bytes_written(&self) -> usize {
+        self.bytes_written
+            .as_ref()
+            .map_or(0, |bytes_written| bytes_written.load(Ordering::Relaxed))
+    }
+}
+
+impl FileWriter for ParquetWriter {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<RecordBatch>;
+
+    fn write(&mut self, data: Self::Input) -> DaftResult<usize> {
+        assert!(!self.is_closed, "Cannot write to a closed ParquetWriter");
+        let tables = data.concat_or_get(IOStatsContext::new("ParquetWriter::write"))?;
+        let Some(table) = tables.first() else {
+            return Ok(0);
+        };
+        let start_position = self.current_bytes_written();
+
+        let file_writer = self.create_file_writer(&data)?;
+        let encodings = file_writer
+            .schema()
+            .fields
+            .iter()
+            .map(|field| transverse(&field.data_type, |_| Encoding::Plain))
+            .collect::<Vec<_>>();
+        let chunk = Chunk::try_new(table.get_inner_arrow_arrays().collect::<Vec<_>>())?;
+        let row_group = row_group_iter(
+            chunk,
+            encodings,
+            file_writer.parquet_schema().fields().to_vec(),
+            file_writer.options(),
+        );
+        file_writer.write(row_group)?;
+
+        Ok(self.current_bytes_written() - start_position)
+    }
+
+    fn bytes_written(&self) -> usize {
+        self.current_bytes_written()
+    }
+
+    fn close(&mut self) -> DaftResult<Self::Result> {
+        self.is_closed = true;
+        // the file is only created on the first write, so there's nothing to report if it never happened.
+        let Some(mut file_writer) = self.file_writer.take() else {
+            return Ok(None);
+        };
+        file_writer.end(None)?;
+        file_writer.into_inner().finish()?;
+        Ok(Some(make_write_result(
+            &self.output_path.path,
+            self.partition_values.as_ref(),
+        )?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use arrow2::io::parquet::{read::read_metadata, write::CompressionOptions};
+    use common_error::DaftResult;
+
+    use super::{parse_parquet_compression, ParquetWriter};
+    use crate::{test::make_dummy_mp, FileWriter};
+
+    #[test]
+    fn test_native_parquet_writer_one_row_group_per_write() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let mut writer = ParquetWriter::try_new(
+            dir.path().to_str().unwrap(),
+            0,
+            &Some("snappy".to_string()),
+            &None,
+            None,
+        )?;
+
+        let mut bytes_written = 0;
+        for _ in 0..3 {
+            bytes_written += writer.write(make_dummy_mp(100))?;
+        }
+        assert_eq!(bytes_written, writer.bytes_written());
+
+        let result = writer.close()?.unwrap();
+        let path = result
+            .get_column("path")?
+            .utf8()?
+            .get(0)
+            .expect("writer should return the path it wrote to")
+            .to_string();
+
+        let metadata = read_metadata(&mut File::open(&path)?)?;
+        assert_eq!(metadata.row_groups.len(), 3);
+        assert_eq!(metadata.num_rows, 300);
+        assert!(metadata.row_groups[0].columns()[0].statistics().is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_native_parquet_writer_no_writes() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let mut writer =
+            ParquetWriter::try_new(dir.path().to_str().unwrap(), 0, &None, &None, None)?;
+
+        assert!(writer.close()?.is_none());
+        assert_eq!(std::fs::read_dir(dir.path())?.count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_compression_defaults_to_snappy() -> DaftResult<()> {
+        assert_eq!(parse_parquet_compression(None)?, CompressionOptions::Snappy);
+        assert_eq!(
+            parse_parquet_compression(Some("NONE"))?,
+            CompressionOptions::Uncompressed
+        );
+        Ok(())
+    }
+}
@@ -1,28 +1,49 @@
 use std::sync::Arc;
 
+use common_daft_config::DaftExecutionConfig;
 use common_error::{DaftError, DaftResult};
-use common_file_formats::FileFormat;
+use common_file_formats::{CsvWriteOptions, FileFormat};
 use daft_logical_plan::OutputFileInfo;
 use daft_micropartition::MicroPartition;
 use daft_recordbatch::RecordBatch;
 
-use crate::{FileWriter, WriterFactory};
+use crate::{
+    csv_writer::CsvWriter, ipc_writer::IpcWriter, json_writer::JsonWriter,
+    parquet_writer::ParquetWriter, FileWriter, WriterFactory,
+};
 
-/// PhysicalWriterFactory is a factory for creating physical writers, i.e. parquet, csv writers.
+/// PhysicalWriterFactory is a factory for creating physical writers, i.e. parquet, csv, json and arrow ipc writers.
 pub struct PhysicalWriterFactory {
     output_file_info: OutputFileInfo,
-    native: bool, // TODO: Implement native writer
+    native: bool,
 }
 
 impl PhysicalWriterFactory {
-    pub fn new(output_file_info: OutputFileInfo) -> Self {
+    pub fn new(output_file_info: OutputFileInfo, cfg: &DaftExecutionConfig) -> Self {
+        let native = native_writer_supported(&output_file_info, cfg);
         Self {
             output_file_info,
-            native: false,
+            native,
         }
     }
 }
 
+/// Whether the given output should be written with a native Rust writer rather than through PyArrow.
+/// Without the `python` feature, the native writers are the only option. JSON and Arrow IPC have no PyArrow writer,
+/// and the PyArrow CSV writer can't honor a custom quote character or null value, so explicit CSV options go native.
+fn native_writer_supported(output_file_info: &OutputFileInfo, cfg: &DaftExecutionConfig) -> bool {
+    match output_file_info.file_format {
+        FileFormat::Parquet => cfg.native_parquet_writer || cfg!(not(feature = "python")),
+        FileFormat::Csv => {
+            cfg.native_csv_writer
+                || output_file_info.csv_options.is_some()
+                || cfg!(not(feature = "python"))
+        }
+        FileFormat::Json | FileFormat::Ipc => true,
+        _ => false,
+    }
+}
+
 impl WriterFactory for PhysicalWriterFactory {
     type Input = Arc<MicroPartition>;
     type Result = Option<RecordBatch>;
@@ -33,7 +54,18 @@ impl WriterFactory for PhysicalWriterFactory {
         partition_values: Option<&RecordBatch>,
     ) -> DaftResult<Box<dyn FileWriter<Input = Self::Input, Result = Self::Result>>> {
         match self.native {
-            true => unimplemented!(),
+            true => {
+                let writer = create_native_file_writer(
+                    &self.output_file_info.root_dir,
+                    file_idx,
+                    &self.output_file_info.compression,
+                    &self.output_file_info.csv_options,
+                    &self.output_file_info.io_config,
+                    self.output_file_info.file_format,
+                    partition_values,
+                )?;
+                Ok(writer)
+            }
             false => {
                 let writer = create_pyarrow_file_writer(
                     &self.output_file_info.root_dir,
@@ -49,6 +81,46 @@ impl WriterFactory for PhysicalWriterFactory {
     }
 }
 
+pub fn create_native_file_writer(
+    root_dir: &str,
+    file_idx: usize,
+    compression: &Option<String>,
+    csv_options: &Option<CsvWriteOptions>,
+    io_config: &Option<daft_io::IOConfig>,
+    format: FileFormat,
+    partition: Option<&RecordBatch>,
+) -> DaftResult<Box<dyn FileWriter<Input = Arc<MicroPartition>, Result = Option<RecordBatch>>>> {
+    match format {
+        FileFormat::Parquet => Ok(Box::new(ParquetWriter::try_new(
+            root_dir,
+            file_idx,
+            compression,
+            io_config,
+            partition,
+        )?)),
+        FileFormat::Csv => Ok(Box::new(CsvWriter::try_new(
+            root_dir,
+            file_idx,
+            csv_options.clone().unwrap_or_default(),
+            io_config,
+            partition,
+        )?)),
+        FileFormat::Json => Ok(Box::new(JsonWriter::try_new(
+            root_dir, file_idx, io_config, partition,
+        )?)),
+        FileFormat::Ipc => Ok(Box::new(IpcWriter::try_new(
+            root_dir,
+            file_idx,
+            compression,
+            io_config,
+            partition,
+        )?)),
+        _ => Err(DaftError::ComputeError(
+            "Unsupported file format for native physical write".to_string(),
+        )),
+    }
+}
+
 pub fn create_pyarrow_file_writer(
     root_dir: &str,
     file_idx: usize,
@@ -0,0 +1,196 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use common_error::DaftResult;
+use common_runtime::get_io_runtime;
+use daft_core::{
+    prelude::{Schema, Utf8Array},
+    series::IntoSeries,
+};
+use daft_io::{get_io_client, parse_url, IOConfig, SourceType};
+use daft_recordbatch::RecordBatch;
+
+const DEFAULT_PARTITION_FALLBACK: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Location of a single file produced by one of the native writers.
+///
+/// Mirrors the layout of the python `FileWriterBase`: files are named `{uuid}-{file_idx}.{ext}` and live
+/// under a hive-style `key=value` directory when partition values are provided.
+pub(crate) struct OutputFilePath {
+    pub(crate) path: String,
+    pub(crate) source_type: SourceType,
+}
+
+impl OutputFilePath {
+    pub(crate) fn new(
+        root_dir: &str,
+        file_idx: usize,
+        extension: &str,
+        partition_values: Option<&RecordBatch>,
+    ) -> DaftResult<Self> {
+        let (source_type, root_dir) = parse_url(root_dir)?;
+        let root_dir = match source_type {
+            SourceType::File => root_dir
+                .strip_prefix("file://")
+                .unwrap_or_else(|| root_dir.as_ref())
+                .to_string(),
+            _ => root_dir.to_string(),
+        };
+        let root_dir = root_dir.trim_end_matches('/');
+
+        let dir_path = match partition_values {
+            Some(partition_values) => {
+                format!(
+                    "{}/{}",
+                    root_dir,
+                    partition_values_to_path(partition_values)?
+                )
+            }
+            None => root_dir.to_string(),
+        };
+        let file_name = format!("{}-{}.{}", uuid::Uuid::new_v4(), file_idx, extension);
+        Ok(Self {
+            path: format!("{}/{}", dir_path, file_name),
+            source_type,
+        })
+    }
+}
+
+/// Converts a single row of partition values into a hive-style path, e.g. `year=2024/month=1`.
+/// Null values are written as `__HIVE_DEFAULT_PARTITION__`.
+fn partition_values_to_path(partition_values: &RecordBatch) -> DaftResult<String> {
+    let mut parts = Vec::with_capacity(partition_values.num_columns());
+    for idx in 0..partition_values.num_columns() {
+        let column = partition_values.get_column_by_index(idx)?;
+        let value = if column.is_valid(0) {
+            let str_values = column.to_str_values()?;
+            str_values
+                .utf8()?
+                .get(0)
+                .unwrap_or(DEFAULT_PARTITION_FALLBACK)
+                .to_string()
+        } else {
+            DEFAULT_PARTITION_FALLBACK.to_string()
+        };
+        parts.push(format!("{}={}", column.name(), value));
+    }
+    Ok(parts.join("/"))
+}
+
+/// Builds the result table returned by a native writer on close: a `path` column, plus the partition values if any.
+pub(crate) fn make_write_result(
+    path: &str,
+    partition_values: Option<&RecordBatch>,
+) -> DaftResult<RecordBatch> {
+    let path_series = Utf8Array::from_values("path", std::iter::once(path)).into_series();
+    let path_table = RecordBatch::new_unchecked(
+        Schema::new(vec![path_series.field().clone()])?,
+        vec![path_series],
+        1,
+    );
+    match partition_values {
+        Some(partition_values) => path_table.union(partition_values),
+        None => Ok(path_table),
+    }
+}
+
+/// Byte sink that the native writers serialize into.
+///
+/// Local files are streamed straight to disk. Files destined for object storage are buffered in memory
+/// and uploaded through the [`daft_io::IOClient`] when the writer is closed.
+pub(crate) struct StorageBackend {
+    inner: StorageBackendInner,
+    path: String,
+    bytes_written: Arc<AtomicUsize>,
+}
+
+enum StorageBackendInner {
+    Local(BufWriter<File>),
+    Remote {
+        buffer: Vec<u8>,
+        io_config: Arc<IOConfig>,
+    },
+}
+
+impl StorageBackend {
+    pub(crate) fn try_new(
+        output_path: &OutputFilePath,
+        io_config: &Option<IOConfig>,
+    ) -> DaftResult<Self> {
+        let inner = match output_path.source_type {
+            SourceType::File => {
+                if let Some(parent) = Path::new(&output_path.path).parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                StorageBackendInner::Local(BufWriter::new(File::create(&output_path.path)?))
+            }
+            _ => StorageBackendInner::Remote {
+                buffer: Vec::new(),
+                io_config: Arc::new(io_config.clone().unwrap_or_default()),
+            },
+        };
+        Ok(Self {
+            inner,
+            path: output_path.path.clone(),
+            bytes_written: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    /// Returns a handle to the running count of bytes written to this backend.
+    /// Useful when the backend itself is owned by a format-specific encoder.
+    pub(crate) fn bytes_written_handle(&self) -> Arc<AtomicUsize> {
+        self.bytes_written.clone()
+    }
+
+    pub(crate) fn bytes_written(&self) -> usize {
+        self.bytes_written.load(Ordering::Relaxed)
+    }
+
+    /// Flushes the backend. For remote destinations this uploads the buffered file.
+    pub(crate) fn finish(self) -> DaftResult<()> {
+        match self.inner {
+            StorageBackendInner::Local(mut writer) => {
+                writer.flush()?;
+                Ok(())
+            }
+            StorageBackendInner::Remote { buffer, io_config } => {
+                let io_client = get_io_client(true, io_config)?;
+                let path = self.path;
+                get_io_runtime(true).block_on(async move {
+                    io_client
+                        .single_url_put(&path, bytes::Bytes::from(buffer), None)
+                        .await
+                })??;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Write for StorageBackend {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = match &mut self.inner {
+            StorageBackendInner::Local(writer) => writer.write(buf)?,
+            StorageBackendInner::Remote { buffer, .. } => {
+                buffer.extend_from_slice(buf);
+                buf.len()
+            }
+        };
+        self.bytes_written.fetch_add(written, Ordering::Relaxed);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match &mut self.inner {
+            StorageBackendInner::Local(writer) => writer.flush(),
+            StorageBackendInner::Remote { .. } => Ok(()),
+        }
+    }
+}
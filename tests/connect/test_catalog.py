@@ -0,0 +1,127 @@
+from __future__ import annotations
+
+import pytest
+from pyspark.sql import SparkSession
+
+import daft
+from daft.catalog import Catalog, Table
+from daft.session import Session
+
+
+def test_temp_view(spark_session):
+    df = spark_session.createDataFrame([(1, "foo")], ["id", "name"])
+    df.createOrReplaceTempView("catalog_view")
+
+    assert spark_session.catalog.tableExists("catalog_view")
+    assert "catalog_view" in [table.name for table in spark_session.catalog.listTables()]
+    assert [column.name for column in spark_session.catalog.listColumns("catalog_view")] == ["id", "name"]
+    assert spark_session.catalog.getTable("catalog_view").isTemporary
+    assert spark_session.table("catalog_view").collect() == [(1, "foo")]
+
+    with pytest.raises(Exception, match="read-only"):
+        df.write.insertInto("catalog_view")
+
+    assert spark_session.catalog.dropTempView("catalog_view")
+    assert not spark_session.catalog.tableExists("catalog_view")
+    assert not spark_session.catalog.dropTempView("catalog_view")
+
+
+def test_current_database(spark_session):
+    assert spark_session.catalog.currentDatabase() == "default"
+    assert spark_session.catalog.databaseExists("default")
+    assert "default" in [db.name for db in spark_session.catalog.listDatabases()]
+
+
+def test_global_temp_view(spark_session):
+    df = spark_session.createDataFrame([(1, "foo")], ["id", "name"])
+    df.createOrReplaceGlobalTempView("global_view")
+
+    assert spark_session.table("global_temp.global_view").collect() == [(1, "foo")]
+    assert spark_session.sql("SELECT * FROM global_temp.global_view").collect() == [(1, "foo")]
+    assert "global_view" in [table.name for table in spark_session.catalog.listTables("global_temp")]
+
+    assert spark_session.catalog.dropGlobalTempView("global_view")
+    assert not spark_session.catalog.tableExists("global_view", "global_temp")
+
+
+def test_save_as_table(spark_session):
+    with pytest.raises(Exception, match="does not exist"):
+        spark_session.range(3).write.insertInto("saved_table")
+
+    # saveAsTable creates a missing table as a session table
+    spark_session.range(3).write.saveAsTable("saved_table")
+    assert spark_session.catalog.tableExists("saved_table")
+    assert sorted(row.id for row in spark_session.table("saved_table").collect()) == [0, 1, 2]
+
+    with pytest.raises(Exception, match="catalogs can't create tables"):
+        spark_session.range(3).write.saveAsTable("missing_catalog.saved_table")
+
+    spark_session.range(3, 5).write.mode("append").saveAsTable("saved_table")
+    assert sorted(row.id for row in spark_session.table("saved_table").collect()) == [0, 1, 2, 3, 4]
+
+    spark_session.range(1).write.insertInto("saved_table", overwrite=True)
+    assert [row.id for row in spark_session.table("saved_table").collect()] == [0]
+
+    with pytest.raises(Exception, match="already exists"):
+        spark_session.range(1).write.saveAsTable("saved_table")
+
+    spark_session.catalog.dropTempView("saved_table")
+
+
+def test_insert_into_casts_by_position(spark_session):
+    spark_session.createDataFrame([(1, "a")], "id long, name string").write.saveAsTable("insert_table")
+
+    # insertInto matches columns by position and casts them to the table's types
+    spark_session.createDataFrame([(2, "b")], "other int, label string").write.insertInto("insert_table")
+    df = spark_session.table("insert_table")
+    assert df.schema.simpleString() == "struct<id:bigint,name:string>"
+    assert sorted(tuple(row) for row in df.collect()) == [(1, "a"), (2, "b")]
+
+    with pytest.raises(Exception, match="Cannot write 1 columns"):
+        spark_session.range(1).write.insertInto("insert_table")
+
+    # saveAsTable matches columns by name
+    spark_session.createDataFrame([("c", 3)], "name string, id int").write.mode("append").saveAsTable("insert_table")
+    assert sorted(tuple(row) for row in spark_session.table("insert_table").collect()) == [(1, "a"), (2, "b"), (3, "c")]
+
+    with pytest.raises(Exception, match="not found in the data to write"):
+        spark_session.createDataFrame([(4, "d")], "id long, other string").write.mode("append").saveAsTable(
+            "insert_table"
+        )
+
+    spark_session.catalog.dropTempView("insert_table")
+
+
+def test_shared_session():
+    from daft.daft import connect_start
+
+    sess = Session()
+    sess.attach_table(Table.from_df("attached", daft.from_pydict({"x": [1, 2]})), alias="attached")
+    sess.attach_catalog(
+        Catalog.from_pydict({"ns.tbl": Table.from_df("tbl", daft.from_pydict({"x": [1, 2]}))}),
+        alias="cat",
+    )
+    sess.set_catalog("cat")
+
+    server = connect_start(session=sess._session)
+    spark = SparkSession.builder.remote(f"sc://localhost:{server.port()}").create()
+    try:
+        # session tables are temporary views
+        assert sorted(row.x for row in spark.table("attached").collect()) == [1, 2]
+        assert spark.catalog.getTable("attached").isTemporary
+
+        # catalog namespaces are databases
+        assert spark.catalog.currentCatalog() == "cat"
+        assert "ns" in [db.name for db in spark.catalog.listDatabases()]
+        assert [table.name for table in spark.catalog.listTables("ns") if not table.isTemporary] == ["tbl"]
+
+        # catalog tables are written by their catalog
+        spark.createDataFrame([(3,)], ["x"]).write.insertInto("ns.tbl")
+        assert sorted(row.x for row in spark.table("ns.tbl").collect()) == [1, 2, 3]
+
+        # spark views are visible to the daft session
+        spark.range(1).createOrReplaceTempView("from_spark")
+        assert sess.has_table("from_spark")
+    finally:
+        spark.stop()
+        server.shutdown()
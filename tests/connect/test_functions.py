@@ -0,0 +1,519 @@
+from __future__ import annotations
+
+import datetime
+import math
+
+import pytest
+from pyspark.sql import functions as F
+
+
+def evaluate(spark_session, column, rows, schema):
+    df = spark_session.createDataFrame(rows, schema)
+    return [row[0] for row in df.select(column).collect()]
+
+
+STRINGS = ([("Spark SQL",), ("hello world",), (None,)], "s string")
+
+
+@pytest.mark.parametrize(
+    ["column", "expected"],
+    [
+        (F.ascii("s"), [83, 104, None]),
+        (F.base64(F.col("s").cast("binary")), ["U3BhcmsgU1FM", "aGVsbG8gd29ybGQ=", None]),
+        (F.bit_length("s"), [72, 88, None]),
+        (F.concat_ws("-", "s", F.lit("x")), ["Spark SQL-x", "hello world-x", "x"]),
+        (F.contains("s", F.lit("SQL")), [True, False, None]),
+        (F.endswith("s", F.lit("SQL")), [True, False, None]),
+        (F.find_in_set(F.lit("b"), F.lit("a,b,c")), [2, 2, 2]),
+        (F.format_string("%s!", "s"), ["Spark SQL!", "hello world!", "null!"]),
+        (F.initcap("s"), ["Spark Sql", "Hello World", None]),
+        (F.instr("s", "o"), [0, 5, None]),
+        (F.lcase("s"), ["spark sql", "hello world", None]),
+        (F.levenshtein("s", F.lit("Spark")), [4, 10, None]),
+        (F.locate("o", "s"), [0, 5, None]),
+        (F.locate("o", "s", 6), [0, 8, None]),
+        (F.mask("s"), ["Xxxxx XXX", "xxxxx xxxxx", None]),
+        (F.octet_length("s"), [9, 11, None]),
+        (F.overlay("s", F.lit("__"), 2, 3), ["S__k SQL", "h__o world", None]),
+        (F.position(F.lit("o"), "s"), [0, 5, None]),
+        (F.printf(F.lit("%s!"), "s"), ["Spark SQL!", "hello world!", "null!"]),
+        (F.regexp("s", F.lit("^S")), [True, False, None]),
+        (F.regexp_count("s", F.lit("[a-z]")), [4, 10, None]),
+        (F.regexp_instr("s", F.lit("o")), [0, 5, None]),
+        (F.regexp_like("s", F.lit("^S")), [True, False, None]),
+        (F.regexp_substr("s", F.lit("[a-z]+")), ["park", "hello", None]),
+        (F.repeat("s", 2), ["Spark SQLSpark SQL", "hello worldhello world", None]),
+        (F.rlike("s", F.lit("^S")), [True, False, None]),
+        (F.split_part("s", F.lit(" "), F.lit(2)), ["SQL", "world", None]),
+        (F.split_part("s", F.lit(" "), F.lit(-1)), ["SQL", "world", None]),
+        (F.substring_index("s", " ", 1), ["Spark", "hello", None]),
+        (F.substring_index("s", " ", -1), ["SQL", "world", None]),
+        (F.translate("s", "ol", "01"), ["Spark SQL", "he110 w0r1d", None]),
+        (F.ucase("s"), ["SPARK SQL", "HELLO WORLD", None]),
+        (F.url_encode("s"), ["Spark+SQL", "hello+world", None]),
+    ],
+)
+def test_string_functions(spark_session, column, expected):
+    assert evaluate(spark_session, column, *STRINGS) == expected
+
+
+def test_string_trim_functions(spark_session):
+    rows, schema = [("  ab  ",), ("xxabxx",), (None,)], "s string"
+
+    assert evaluate(spark_session, F.btrim("s"), rows, schema) == ["ab", "xxabxx", None]
+    assert evaluate(spark_session, F.ltrim("s"), rows, schema) == ["ab  ", "xxabxx", None]
+    assert evaluate(spark_session, F.rtrim("s"), rows, schema) == ["  ab", "xxabxx", None]
+    assert evaluate(spark_session, F.trim("s"), rows, schema) == ["ab", "xxabxx", None]
+    assert evaluate(spark_session, F.btrim("s", F.lit("x")), rows, schema) == ["  ab  ", "ab", None]
+
+
+def test_string_encoding_functions(spark_session):
+    rows, schema = [("U3Bhcms=", "%E2%82%AC+1"), ("not base64!", "%zz"), (None, None)], "b string, u string"
+
+    assert evaluate(spark_session, F.unbase64("b"), rows, schema) == [b"Spark", None, None]
+    assert evaluate(spark_session, F.url_decode("u"), rows, schema) == ["€ 1", None, None]
+    assert evaluate(spark_session, F.decode(F.encode(F.col("u"), "UTF-8"), "UTF-8"), rows, schema) == [
+        "%E2%82%AC+1",
+        "%zz",
+        None,
+    ]
+
+
+def test_soundex(spark_session):
+    rows, schema = [("Robert",), ("Tymczak",), ("",), (None,)], "s string"
+
+    assert evaluate(spark_session, F.soundex("s"), rows, schema) == ["R163", "T522", "", None]
+
+
+def test_string_from_integer_functions(spark_session):
+    rows, schema = [(65,), (321,), (-1,), (None,)], "i long"
+
+    assert evaluate(spark_session, F.chr("i"), rows, schema) == ["A", "A", "", None]
+    assert evaluate(spark_session, F.char("i"), rows, schema) == ["A", "A", "", None]
+    assert evaluate(spark_session, F.elt(F.lit(2), F.lit("a"), F.lit("b")), rows, schema) == ["b"] * 4
+
+
+def test_format_number(spark_session):
+    rows, schema = [(1234567.891, 1234567), (0.125, -1000), (-2.5, 0), (None, None)], "x double, i long"
+
+    assert evaluate(spark_session, F.format_number("x", 2), rows, schema) == ["1,234,567.89", "0.12", "-2.50", None]
+    assert evaluate(spark_session, F.format_number("x", 0), rows, schema) == ["1,234,568", "0", "-2", None]
+    assert evaluate(spark_session, F.format_number("i", 1), rows, schema) == ["1,234,567.0", "-1,000.0", "0.0", None]
+    assert evaluate(spark_session, F.format_number("i", -1), rows, schema) == [None] * 4
+
+
+def test_parse_url(spark_session):
+    rows, schema = [("https://spark.apache.org/path?query=1&x=2#ref",), (None,)], "url string"
+
+    assert evaluate(spark_session, F.parse_url("url", F.lit("HOST")), rows, schema) == ["spark.apache.org", None]
+    assert evaluate(spark_session, F.parse_url("url", F.lit("PATH")), rows, schema) == ["/path", None]
+    assert evaluate(spark_session, F.parse_url("url", F.lit("QUERY"), F.lit("x")), rows, schema) == ["2", None]
+
+
+NUMBERS = ([(1.0,), (-2.5,), (None,)], "x double")
+
+
+@pytest.mark.parametrize(
+    ["column", "expected"],
+    [
+        (F.bround("x"), [1.0, -2.0, None]),
+        (F.cosh("x"), [math.cosh(1.0), math.cosh(-2.5), None]),
+        (F.csc("x"), [1 / math.sin(1.0), 1 / math.sin(-2.5), None]),
+        (F.e() + F.col("x") * 0, [math.e, math.e, None]),
+        (F.expm1("x"), [math.expm1(1.0), math.expm1(-2.5), None]),
+        (F.hypot("x", F.lit(0.0)), [1.0, 2.5, None]),
+        (F.log1p("x"), [math.log1p(1.0), None, None]),
+        (F.negate("x"), [-1.0, 2.5, None]),
+        (F.negative("x"), [-1.0, 2.5, None]),
+        (F.pi() + F.col("x") * 0, [math.pi, math.pi, None]),
+        (F.pmod("x", F.lit(2.0)), [1.0, 1.5, None]),
+        (F.positive("x"), [1.0, -2.5, None]),
+        (F.pow("x", F.lit(2)), [1.0, 6.25, None]),
+        (F.power("x", F.lit(2)), [1.0, 6.25, None]),
+        (F.rint("x"), [1.0, -2.0, None]),
+        (F.sec("x"), [1 / math.cos(1.0), 1 / math.cos(-2.5), None]),
+        (F.sign("x"), [1.0, -1.0, None]),
+        (F.signum("x"), [1.0, -1.0, None]),
+        (F.sinh("x"), [math.sinh(1.0), math.sinh(-2.5), None]),
+        (F.tanh("x"), [math.tanh(1.0), math.tanh(-2.5), None]),
+        (F.toDegrees("x"), [math.degrees(1.0), math.degrees(-2.5), None]),
+        (F.toRadians("x"), [math.radians(1.0), math.radians(-2.5), None]),
+        (F.try_divide("x", F.lit(0)), [None, None, None]),
+        (F.try_multiply("x", F.lit(2.0)), [2.0, -5.0, None]),
+        (F.try_subtract("x", F.lit(1.0)), [0.0, -3.5, None]),
+        (F.width_bucket("x", F.lit(-5.0), F.lit(5.0), F.lit(10)), [7, 3, None]),
+    ],
+)
+def test_math_functions(spark_session, column, expected):
+    actual = evaluate(spark_session, column, *NUMBERS)
+
+    assert len(actual) == len(expected)
+    for a, e in zip(actual, expected):
+        if e is None:
+            assert a is None
+        elif math.isnan(e):
+            assert math.isnan(a)
+        else:
+            assert a == pytest.approx(e)
+
+
+def test_try_aggregations(spark_session):
+    assert evaluate(spark_session, F.try_sum("x"), *NUMBERS) == [-1.5]
+    assert evaluate(spark_session, F.try_avg("x"), *NUMBERS) == [-0.75]
+
+
+INTEGERS = ([(5,), (-1,), (None,)], "i long")
+
+
+@pytest.mark.parametrize(
+    ["column", "expected"],
+    [
+        (F.bin("i"), ["101", "1111111111111111111111111111111111111111111111111111111111111111", None]),
+        (F.bitwise_not("i"), [-6, 0, None]),
+        (F.bitwiseNOT("i"), [-6, 0, None]),
+        (F.call_function("~", F.col("i")), [-6, 0, None]),
+        (F.conv(F.col("i").cast("string"), 10, 16), ["5", "FFFFFFFFFFFFFFFF", None]),
+        (F.factorial("i"), [120, None, None]),
+        (F.hex("i"), ["5", "FFFFFFFFFFFFFFFF", None]),
+        (F.shiftleft("i", 1), [10, -2, None]),
+        (F.shiftright("i", 1), [2, -1, None]),
+        (F.try_add("i", F.lit(1)), [6, 0, None]),
+    ],
+)
+def test_integer_functions(spark_session, column, expected):
+    assert evaluate(spark_session, column, *INTEGERS) == expected
+
+
+def test_unhex(spark_session):
+    rows, schema = [("537061726B",), ("zz",), (None,)], "s string"
+
+    assert evaluate(spark_session, F.unhex("s"), rows, schema) == [b"Spark", None, None]
+    assert evaluate(spark_session, F.to_binary(F.lit("537061726B")), rows, schema) == [b"Spark"] * 3
+    assert evaluate(spark_session, F.try_to_binary("s"), rows, schema) == [b"Spark", None, None]
+
+
+def test_greatest_and_least(spark_session):
+    rows, schema = [(1, 3, 2), (None, 1, None), (None, None, None)], "a long, b long, c long"
+
+    assert evaluate(spark_session, F.greatest("a", "b", "c"), rows, schema) == [3, 1, None]
+    assert evaluate(spark_session, F.least("a", "b", "c"), rows, schema) == [1, 1, None]
+
+
+def test_when(spark_session):
+    rows, schema = [(1,), (5,), (None,)], "i long"
+
+    column = F.when(F.col("i") < 3, "small").when(F.col("i") < 10, "medium")
+    assert evaluate(spark_session, column, rows, schema) == ["small", "medium", None]
+    assert evaluate(spark_session, column.otherwise("unknown"), rows, schema) == ["small", "medium", "unknown"]
+
+
+def test_nanvl_and_named_struct(spark_session):
+    rows, schema = [(float("nan"), 1.0), (2.0, 1.0), (None, 1.0)], "a double, b double"
+
+    assert evaluate(spark_session, F.nanvl("a", "b"), rows, schema) == [1.0, 2.0, None]
+    assert evaluate(spark_session, F.named_struct(F.lit("x"), F.col("b")), rows, schema) == [(1.0,)] * 3
+
+
+DATES = (
+    [(datetime.date(2024, 1, 31),), (datetime.date(2023, 11, 15),), (None,)],
+    "d date",
+)
+
+
+@pytest.mark.parametrize(
+    ["column", "expected"],
+    [
+        (F.add_months("d", 1), [datetime.date(2024, 2, 29), datetime.date(2023, 12, 15), None]),
+        (F.date_add("d", 1), [datetime.date(2024, 2, 1), datetime.date(2023, 11, 16), None]),
+        (F.date_diff(F.lit("2024-02-01").cast("date"), "d"), [1, 78, None]),
+        (F.date_sub("d", 1), [datetime.date(2024, 1, 30), datetime.date(2023, 11, 14), None]),
+        (F.datediff(F.lit("2024-02-01").cast("date"), "d"), [1, 78, None]),
+        (F.date_format("d", "yyyy/MM/dd"), ["2024/01/31", "2023/11/15", None]),
+        (F.dateadd("d", 1), [datetime.date(2024, 2, 1), datetime.date(2023, 11, 16), None]),
+        (F.datepart(F.lit("QUARTER"), "d"), [1, 4, None]),
+        (F.dayofmonth("d"), [31, 15, None]),
+        (F.dayofweek("d"), [4, 4, None]),
+        (F.dayofyear("d"), [31, 319, None]),
+        (F.extract(F.lit("QUARTER"), "d"), [1, 4, None]),
+        (F.last_day("d"), [datetime.date(2024, 1, 31), datetime.date(2023, 11, 30), None]),
+        (
+            F.col("d") + F.make_ym_interval(F.lit(1), F.lit(1)),
+            [datetime.date(2025, 2, 28), datetime.date(2024, 12, 15), None],
+        ),
+        (F.months_between(F.lit("2024-03-31").cast("date"), "d"), [2.0, 4.51612903, None]),
+        (F.next_day("d", "Mon"), [datetime.date(2024, 2, 5), datetime.date(2023, 11, 20), None]),
+        (F.quarter("d"), [1, 4, None]),
+        (F.trunc("d", "month"), [datetime.date(2024, 1, 1), datetime.date(2023, 11, 1), None]),
+        (F.trunc("d", "quarter"), [datetime.date(2024, 1, 1), datetime.date(2023, 10, 1), None]),
+        (F.trunc("d", "year"), [datetime.date(2024, 1, 1), datetime.date(2023, 1, 1), None]),
+        (F.unix_date("d"), [19753, 19676, None]),
+        (F.weekday("d"), [2, 2, None]),
+        (F.weekofyear("d"), [5, 46, None]),
+    ],
+)
+def test_date_functions(spark_session, column, expected):
+    assert evaluate(spark_session, column, *DATES) == expected
+
+
+TIMESTAMPS = (
+    [(datetime.datetime(2024, 1, 31, 12, 34, 56),), (None,)],
+    "ts timestamp",
+)
+
+
+@pytest.mark.parametrize(
+    ["column", "expected"],
+    [
+        (F.date_trunc("hour", "ts"), [datetime.datetime(2024, 1, 31, 12), None]),
+        (F.date_trunc("month", "ts"), [datetime.datetime(2024, 1, 1), None]),
+        (F.date_trunc("week", "ts"), [datetime.datetime(2024, 1, 29), None]),
+        (F.date_part(F.lit("MINUTE"), "ts"), [34, None]),
+        (
+            F.col("ts") + F.make_dt_interval(F.lit(1), F.lit(2), F.lit(3), F.lit(4.5)),
+            [datetime.datetime(2024, 2, 1, 14, 38, 0, 500000), None],
+        ),
+        (
+            F.col("ts") + F.make_interval(F.lit(0), F.lit(1), F.lit(1)),
+            [datetime.datetime(2024, 3, 7, 12, 34, 56), None],
+        ),
+        (F.to_char("ts", F.lit("HH:mm:ss")), ["12:34:56", None]),
+        (F.to_varchar("ts", F.lit("HH:mm:ss")), ["12:34:56", None]),
+        (F.unix_seconds("ts"), [1706704496, None]),
+        (F.unix_millis("ts"), [1706704496000, None]),
+        (F.unix_micros("ts"), [1706704496000000, None]),
+    ],
+)
+def test_timestamp_functions(spark_session, column, expected):
+    assert evaluate(spark_session, column, *TIMESTAMPS) == expected
+
+
+def test_epoch_functions(spark_session):
+    rows, schema = [(1706704496,), (None,)], "s long"
+
+    assert evaluate(spark_session, F.from_unixtime("s"), rows, schema) == ["2024-01-31 12:34:56", None]
+    assert evaluate(spark_session, F.timestamp_seconds("s"), rows, schema) == [
+        datetime.datetime(2024, 1, 31, 12, 34, 56),
+        None,
+    ]
+    assert evaluate(spark_session, F.timestamp_millis(F.col("s") * 1000), rows, schema) == [
+        datetime.datetime(2024, 1, 31, 12, 34, 56),
+        None,
+    ]
+    assert evaluate(spark_session, F.date_from_unix_date(F.lit(19753)), rows, schema) == [
+        datetime.date(2024, 1, 31)
+    ] * 2
+
+
+def test_parsing_functions(spark_session):
+    rows, schema = [("31/01/2024 12:34",), (None,)], "s string"
+
+    assert evaluate(spark_session, F.to_date("s", "dd/MM/yyyy HH:mm"), rows, schema) == [
+        datetime.date(2024, 1, 31),
+        None,
+    ]
+    assert evaluate(spark_session, F.to_timestamp("s", "dd/MM/yyyy HH:mm"), rows, schema) == [
+        datetime.datetime(2024, 1, 31, 12, 34),
+        None,
+    ]
+    assert evaluate(spark_session, F.unix_timestamp("s", "dd/MM/yyyy HH:mm"), rows, schema) == [1706704440, None]
+    assert evaluate(spark_session, F.to_unix_timestamp("s", F.lit("dd/MM/yyyy HH:mm")), rows, schema) == [
+        1706704440,
+        None,
+    ]
+    for to_timestamp in [F.to_timestamp_ltz, F.to_timestamp_ntz]:
+        assert evaluate(spark_session, to_timestamp("s", F.lit("dd/MM/yyyy HH:mm")), rows, schema) == [
+            datetime.datetime(2024, 1, 31, 12, 34),
+            None,
+        ]
+
+
+def test_try_to_timestamp(spark_session):
+    rows, schema = [("31/01/2024 12:34",), ("not a timestamp",), (None,)], "s string"
+
+    assert evaluate(spark_session, F.try_to_timestamp("s", F.lit("dd/MM/yyyy HH:mm")), rows, schema) == [
+        datetime.datetime(2024, 1, 31, 12, 34),
+        None,
+        None,
+    ]
+    assert evaluate(spark_session, F.try_to_timestamp("s", F.lit("dd/MM/yyyy")), rows, schema) == [None] * 3
+
+
+def test_make_date_and_timestamp(spark_session):
+    rows, schema = [(2024, 2, 29), (None, 1, 1), (2023, 2, 30)], "y int, m int, d int"
+
+    assert evaluate(spark_session, F.make_date("y", "m", "d"), rows, schema) == [
+        datetime.date(2024, 2, 29),
+        None,
+        None,
+    ]
+    for make_timestamp in [F.make_timestamp, F.make_timestamp_ltz, F.make_timestamp_ntz]:
+        assert evaluate(
+            spark_session,
+            make_timestamp("y", "m", "d", F.lit(1), F.lit(2), F.lit(3.5)),
+            rows,
+            schema,
+        ) == [datetime.datetime(2024, 2, 29, 1, 2, 3, 500000), None, None]
+
+
+def test_current_time_functions(spark_session):
+    before = datetime.datetime.now(datetime.timezone.utc).replace(tzinfo=None)
+    df = spark_session.range(1).select(
+        F.current_date(), F.curdate(), F.current_timestamp(), F.localtimestamp(), F.current_timezone()
+    )
+    (today, curdate, now, local_now, timezone) = df.collect()[0]
+
+    assert today in (before.date(), before.date() + datetime.timedelta(days=1))
+    assert curdate == today
+    assert now >= before - datetime.timedelta(seconds=1)
+    assert local_now >= before - datetime.timedelta(seconds=1)
+    assert timezone == "UTC"
+
+
+def test_monotonically_increasing_id(spark_session):
+    ids = [row[0] for row in spark_session.range(10).select(F.monotonically_increasing_id()).collect()]
+
+    assert ids == list(range(10))
+
+
+def test_spark_partition_id(spark_session):
+    ids = [row[0] for row in spark_session.range(3).select(F.spark_partition_id()).collect()]
+
+    assert ids == [0, 0, 0]
+
+
+def test_rand(spark_session):
+    df = spark_session.range(1000)
+    values = [row[0] for row in df.select(F.rand(42)).collect()]
+
+    assert all(0.0 <= v < 1.0 for v in values)
+    assert len(set(values)) == len(values)
+    assert sum(values) / len(values) == pytest.approx(0.5, abs=0.05)
+    assert [row[0] for row in df.select(F.rand(42)).collect()] == values
+    assert [row[0] for row in df.select(F.rand(7)).collect()] != values
+
+
+def test_randn(spark_session):
+    values = [row[0] for row in spark_session.range(1000).select(F.randn(42)).collect()]
+    mean = sum(values) / len(values)
+    variance = sum((v - mean) ** 2 for v in values) / len(values)
+
+    assert mean == pytest.approx(0.0, abs=0.15)
+    assert variance == pytest.approx(1.0, abs=0.2)
+
+
+@pytest.mark.parametrize(
+    ["column", "name"],
+    [
+        (F.input_file_name(), "input_file_name"),
+        (F.sentences(F.lit("Hello there.")), "sentences"),
+        (F.session_window(F.lit("2024-01-31").cast("timestamp"), "10 minutes"), "session_window"),
+        (F.to_number(F.lit("$99.99"), F.lit("$99.99")), "to_number"),
+        (F.try_to_number(F.lit("$99.99"), F.lit("$99.99")), "try_to_number"),
+        (F.window(F.lit("2024-01-31").cast("timestamp"), "10 minutes"), "window"),
+        (F.window_time(F.col("id")), "window_time"),
+    ],
+)
+def test_unsupported_functions(spark_session, column, name):
+    with pytest.raises(Exception, match=f"{name} \\("):
+        spark_session.range(1).select(column).collect()
+
+
+NULLS = ([(None, None, None, None, None)], "s string, x double, i long, d date, ts timestamp")
+
+
+@pytest.mark.parametrize(
+    ["column", "expected"],
+    [
+        (F.add_months("d", 1), None),
+        (F.ascii("s"), None),
+        (F.base64(F.col("s").cast("binary")), None),
+        (F.bin("i"), None),
+        (F.bit_length("s"), None),
+        (F.bitwise_not("i"), None),
+        (F.bround("x"), None),
+        (F.btrim("s"), None),
+        (F.chr("i"), None),
+        (F.concat_ws("-", "s", "s"), ""),
+        (F.contains("s", "s"), None),
+        (F.conv("s", 10, 16), None),
+        (F.cosh("x"), None),
+        (F.csc("x"), None),
+        (F.date_add("d", "i"), None),
+        (F.date_diff("d", "d"), None),
+        (F.date_format("d", "yyyy"), None),
+        (F.date_from_unix_date("i"), None),
+        (F.date_part(F.lit("YEAR"), "d"), None),
+        (F.date_sub("d", "i"), None),
+        (F.date_trunc("month", "ts"), None),
+        (F.dayofweek("d"), None),
+        (F.dayofyear("d"), None),
+        (F.decode(F.encode("s", "UTF-8"), "UTF-8"), None),
+        (F.elt("i", F.lit("a")), None),
+        (F.endswith("s", "s"), None),
+        (F.expm1("x"), None),
+        (F.factorial("i"), None),
+        (F.find_in_set("s", F.lit("a,b")), None),
+        (F.find_in_set(F.lit("a"), "s"), None),
+        (F.format_number("x", 2), None),
+        (F.format_string("%s-%d", "s", "i"), "null-null"),
+        (F.from_unixtime("i"), None),
+        (F.greatest("x", "x"), None),
+        (F.hex("i"), None),
+        (F.hypot("x", "x"), None),
+        (F.initcap("s"), None),
+        (F.instr("s", "a"), None),
+        (F.last_day("d"), None),
+        (F.least("x", "x"), None),
+        (F.levenshtein("s", "s"), None),
+        (F.locate("a", "s"), None),
+        (F.log1p("x"), None),
+        (F.make_date("i", "i", "i"), None),
+        (F.make_timestamp("i", "i", "i", "i", "i", "x"), None),
+        (F.mask("s"), None),
+        (F.months_between("d", "d"), None),
+        (F.named_struct(F.lit("a"), F.col("s")), (None,)),
+        (F.nanvl("x", F.lit(1.0)), None),
+        (F.negative("x"), None),
+        (F.next_day("d", "Mon"), None),
+        (F.octet_length("s"), None),
+        (F.overlay("s", F.lit("a"), 1), None),
+        (F.parse_url("s", F.lit("HOST")), None),
+        (F.pmod("x", F.lit(2.0)), None),
+        (F.pow("x", "x"), None),
+        (F.quarter("d"), None),
+        (F.regexp_count("s", F.lit("a")), None),
+        (F.regexp_instr("s", F.lit("a")), None),
+        (F.regexp_like("s", F.lit("a")), None),
+        (F.regexp_substr("s", F.lit("a")), None),
+        (F.repeat("s", 2), None),
+        (F.rint("x"), None),
+        (F.sec("x"), None),
+        (F.shiftleft("i", 1), None),
+        (F.signum("x"), None),
+        (F.soundex("s"), None),
+        (F.split_part("s", F.lit(" "), F.lit(1)), None),
+        (F.substring_index("s", " ", 1), None),
+        (F.timestamp_seconds("i"), None),
+        (F.to_binary("s"), None),
+        (F.to_char("ts", F.lit("yyyy")), None),
+        (F.to_date("s", "yyyy-MM-dd"), None),
+        (F.to_timestamp("s", "yyyy-MM-dd"), None),
+        (F.translate("s", "a", "b"), None),
+        (F.trunc("d", "month"), None),
+        (F.try_add("i", "i"), None),
+        (F.try_divide("x", F.lit(2.0)), None),
+        (F.try_to_binary("s"), None),
+        (F.try_to_timestamp("s", F.lit("yyyy-MM-dd")), None),
+        (F.unbase64("s"), None),
+        (F.unix_date("d"), None),
+        (F.unix_seconds("ts"), None),
+        (F.unix_timestamp("s", "yyyy-MM-dd"), None),
+        (F.url_decode("s"), None),
+        (F.url_encode("s"), None),
+        (F.weekofyear("d"), None),
+        (F.when(F.col("i") > 0, "positive"), None),
+        (F.width_bucket("x", F.lit(0.0), F.lit(1.0), F.lit(2)), None),
+    ],
+)
+def test_null_handling(spark_session, column, expected):
+    assert evaluate(spark_session, column, *NULLS) == [expected]
@@ -17,26 +17,43 @@ def test_csv_basic_roundtrip(make_spark_df, assert_spark_equals, spark_session,
     assert_spark_equals(df_read, spark_df_read)
 
 
-@pytest.mark.skip(reason="https://github.com/Eventual-Inc/Daft/issues/3775")
-def test_write_csv_without_header(make_df, make_spark_df, spark_session, tmp_path):
-    pass
+def test_write_csv_without_header(make_spark_df, spark_session, tmp_path):
+    df = make_spark_df({"id": [1, 2, 3]})
+    csv_dir = os.path.join(tmp_path, "csv")
+    df.write.option("header", False).csv(csv_dir)
 
+    df_read = daft.read_csv(csv_dir, has_headers=False).sort("column_1")
+    assert df_read.to_pydict() == {"column_1": [1, 2, 3]}
 
-@pytest.mark.skip(reason="https://github.com/Eventual-Inc/Daft/issues/3786")
-def test_write_csv_with_delimiter(make_df, make_spark_df, spark_session, tmp_path):
-    pass
 
+def test_write_csv_with_delimiter(make_spark_df, spark_session, tmp_path):
+    df = make_spark_df({"id": [1, 2, 3], "name": ["a", "b", "c"]})
+    csv_dir = os.path.join(tmp_path, "csv")
+    df.write.option("sep", "|").csv(csv_dir)
 
-@pytest.mark.skip(reason="https://github.com/Eventual-Inc/Daft/issues/3787")
-def test_write_csv_with_quote(spark_session, tmp_path):
-    pass
+    df_read = daft.read_csv(csv_dir, delimiter="|").sort("id")
+    assert df_read.to_pydict() == {"id": [1, 2, 3], "name": ["a", "b", "c"]}
 
 
-@pytest.mark.skip(
-    reason="https://github.com/Eventual-Inc/Daft/issues/3609: CSV null value handling not yet implemented"
-)
-def test_write_csv_with_null_value(spark_session, tmp_path):
-    pass
+def test_write_csv_with_quote(make_spark_df, spark_session, tmp_path):
+    df = make_spark_df({"id": [1, 2], "name": ["a,b", "c"]})
+    csv_dir = os.path.join(tmp_path, "csv")
+    df.write.option("quote", "'").csv(csv_dir)
+
+    df_read = daft.read_csv(csv_dir, quote="'").sort("id")
+    assert df_read.to_pydict() == {"id": [1, 2], "name": ["a,b", "c"]}
+
+
+def test_write_csv_with_null_value(make_spark_df, spark_session, tmp_path):
+    df = make_spark_df({"id": [1, 2], "name": ["a", None]})
+    csv_dir = os.path.join(tmp_path, "csv")
+    df.write.option("nullValue", "NULL").csv(csv_dir)
+
+    lines = []
+    for file in os.listdir(csv_dir):
+        with open(os.path.join(csv_dir, file)) as f:
+            lines.extend(f.read().splitlines())
+    assert "2,NULL" in lines
 
 
 def test_write_csv_with_compression(spark_session, tmp_path):
@@ -0,0 +1,80 @@
+from __future__ import annotations
+
+import pytest
+from pyspark.sql import Row
+
+
+@pytest.fixture
+def left_df(spark_session):
+    return spark_session.createDataFrame([(1, "a"), (2, "b"), (3, "c")], ["id", "x"])
+
+
+@pytest.fixture
+def right_df(spark_session):
+    return spark_session.createDataFrame([(2, "B"), (3, "C"), (4, "D")], ["id", "y"])
+
+
+@pytest.mark.parametrize(
+    "how, expected",
+    [
+        ("inner", [Row(id=2, x="b", y="B"), Row(id=3, x="c", y="C")]),
+        ("left", [Row(id=1, x="a", y=None), Row(id=2, x="b", y="B"), Row(id=3, x="c", y="C")]),
+        ("right", [Row(id=2, x="b", y="B"), Row(id=3, x="c", y="C"), Row(id=4, x=None, y="D")]),
+        (
+            "outer",
+            [
+                Row(id=1, x="a", y=None),
+                Row(id=2, x="b", y="B"),
+                Row(id=3, x="c", y="C"),
+                Row(id=4, x=None, y="D"),
+            ],
+        ),
+        ("left_semi", [Row(id=2, x="b"), Row(id=3, x="c")]),
+        ("left_anti", [Row(id=1, x="a")]),
+    ],
+)
+def test_join_using_columns(left_df, right_df, how, expected):
+    df = left_df.join(right_df, on="id", how=how)
+
+    assert sorted(df.collect(), key=lambda row: row.id) == expected
+
+
+def test_join_on_condition(left_df, right_df):
+    df = left_df.join(right_df, left_df.id == right_df.id)
+
+    # like Spark, the columns of both sides keep their names
+    assert df.columns == ["id", "x", "id", "y"]
+    assert sorted(tuple(row) for row in df.collect()) == [(2, "b", 2, "B"), (3, "c", 3, "C")]
+
+
+def test_join_select_duplicate_columns(left_df, right_df):
+    df = left_df.join(right_df, left_df.id == right_df.id, how="right")
+
+    assert sorted(df.select(right_df.id).collect(), key=lambda row: row.id) == [Row(id=2), Row(id=3), Row(id=4)]
+    assert sorted(df.select(left_df.id, right_df.id, "y").collect(), key=lambda row: row.y) == [
+        (2, 2, "B"),
+        (3, 3, "C"),
+        (None, 4, "D"),
+    ]
+
+
+def test_join_on_condition_with_predicate(left_df, right_df):
+    df = left_df.join(right_df, (left_df.id == right_df.id) & (right_df.y != "B"), how="inner")
+
+    assert [tuple(row) for row in df.collect()] == [(3, "c", 3, "C")]
+
+
+def test_join_on_null_safe_condition(spark_session):
+    left = spark_session.createDataFrame([(1,), (None,)], "a int")
+    right = spark_session.createDataFrame([(1,), (None,)], "b int")
+
+    df = left.join(right, left.a.eqNullSafe(right.b))
+
+    assert df.count() == 2
+
+
+def test_cross_join(left_df, right_df):
+    df = left_df.crossJoin(right_df.select("y"))
+
+    assert df.count() == 9
+    assert df.columns == ["id", "x", "y"]
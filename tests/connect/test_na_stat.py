@@ -0,0 +1,114 @@
+from __future__ import annotations
+
+import math
+
+import pytest
+
+
+@pytest.fixture
+def df(spark_session):
+    return spark_session.createDataFrame(
+        [(1, 1.0, "a"), (2, None, "b"), (None, 3.0, None), (4, float("nan"), "a")],
+        ["i", "f", "s"],
+    )
+
+
+def test_na_fill(df):
+    filled = df.na.fill(0).collect()
+    assert sorted(row.i for row in filled) == [0, 1, 2, 4]
+    assert sorted(row.f for row in filled if not math.isnan(row.f)) == [0.0, 1.0, 3.0]
+
+    filled = df.na.fill("z").collect()
+    assert sorted(row.s for row in filled) == ["a", "a", "b", "z"]
+
+    filled = df.na.fill({"i": -1, "s": "z"}).collect()
+    assert sorted(row.i for row in filled) == [-1, 1, 2, 4]
+    assert sorted(row.s for row in filled) == ["a", "a", "b", "z"]
+
+
+def test_na_drop(df):
+    # NaN counts as a missing value
+    assert df.na.drop().count() == 1
+    assert df.na.drop(how="all").count() == 4
+    assert df.na.drop(subset=["i"]).count() == 3
+    assert df.na.drop(thresh=2).count() == 3
+
+
+def test_na_replace(df):
+    replaced = df.na.replace("a", "x").collect()
+    assert sorted(row.s for row in replaced if row.s is not None) == ["b", "x", "x"]
+
+    replaced = df.na.replace(1, 10, subset=["i"]).collect()
+    assert sorted(row.i for row in replaced if row.i is not None) == [2, 4, 10]
+
+
+def test_describe(spark_session):
+    df = spark_session.createDataFrame([(1, "a"), (2, "b"), (3, "c")], ["x", "s"])
+
+    rows = {row.summary: row for row in df.describe().collect()}
+    assert set(rows) == {"count", "mean", "stddev", "min", "max"}
+    assert rows["count"].x == "3"
+    assert float(rows["mean"].x) == 2.0
+    assert float(rows["stddev"].x) == 1.0
+    assert rows["min"].s == "a"
+    assert rows["max"].s == "c"
+    assert rows["mean"].s is None
+
+
+def test_summary(spark_session):
+    df = spark_session.createDataFrame([(float(i),) for i in range(1, 101)], ["x"])
+
+    rows = {row.summary: row for row in df.summary("count", "50%").collect()}
+    assert rows["count"].x == "100"
+    assert float(rows["50%"].x) == pytest.approx(50.0, rel=0.05)
+
+
+def test_approx_quantile(spark_session):
+    df = spark_session.createDataFrame([(float(i), float(-i)) for i in range(1, 101)], ["x", "y"])
+
+    [[x_median], [y_median]] = df.approxQuantile(["x", "y"], [0.5], 0.01)
+    assert x_median == pytest.approx(50.0, rel=0.05)
+    assert y_median == pytest.approx(-50.0, rel=0.05)
+
+
+def test_cov_corr(spark_session):
+    df = spark_session.createDataFrame([(1.0, 2.0), (2.0, 4.0), (3.0, 6.0), (None, 1.0)], ["x", "y"])
+
+    assert df.cov("x", "y") == pytest.approx(2.0)
+    assert df.corr("x", "y") == pytest.approx(1.0)
+
+
+def test_cov_corr_large_values(spark_session):
+    # the sums of squares of these values exceed the precision of a double by far more than their variance
+    rows = [(1e9 + i, 1e9 - 2 * i + (i % 2)) for i in range(100)]
+    df = spark_session.createDataFrame(rows, ["x", "y"])
+
+    xs, ys = zip(*rows)
+    mean_x, mean_y = sum(xs) / len(xs), sum(ys) / len(ys)
+    sum_xy = sum((x - mean_x) * (y - mean_y) for x, y in rows)
+    sum_xx = sum((x - mean_x) ** 2 for x in xs)
+    sum_yy = sum((y - mean_y) ** 2 for y in ys)
+
+    assert df.cov("x", "y") == pytest.approx(sum_xy / (len(rows) - 1))
+    assert df.corr("x", "y") == pytest.approx(sum_xy / math.sqrt(sum_xx * sum_yy))
+
+
+def test_crosstab(spark_session):
+    df = spark_session.createDataFrame([(1, "a"), (1, "b"), (2, "a"), (1, "a")], ["x", "y"])
+
+    rows = sorted(df.crosstab("x", "y").collect(), key=lambda row: row.x_y)
+    assert [tuple(row) for row in rows] == [("1", 2, 1), ("2", 1, 0)]
+
+
+def test_freq_items(spark_session):
+    df = spark_session.createDataFrame([(1,), (1,), (1,), (2,)], ["x"])
+
+    [row] = df.freqItems(["x"], support=0.5).collect()
+    assert row.x_freqItems == [1]
+
+
+def test_sample_by(spark_session):
+    df = spark_session.createDataFrame([(i % 3, i) for i in range(30)], ["key", "value"])
+
+    sampled = df.sampleBy("key", fractions={0: 1.0, 1: 0.0}, seed=0).collect()
+    assert sorted(row.value for row in sampled) == list(range(0, 30, 3))
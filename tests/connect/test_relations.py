@@ -0,0 +1,101 @@
+from __future__ import annotations
+
+import pytest
+from pyspark.sql import Row
+from pyspark.sql.functions import broadcast
+from pyspark.sql.types import LongType, StringType, StructField, StructType
+
+
+def test_repartition(spark_session):
+    df = spark_session.range(10)
+
+    assert sorted(row.id for row in df.repartition(3).collect()) == list(range(10))
+    assert sorted(row.id for row in df.repartition(3, "id").collect()) == list(range(10))
+    assert sorted(row.id for row in df.coalesce(1).collect()) == list(range(10))
+
+
+def test_offset(spark_session):
+    df = spark_session.range(10).offset(7)
+
+    assert [row.id for row in df.collect()] == [7, 8, 9]
+
+
+def test_tail(spark_session):
+    df = spark_session.range(10)
+
+    assert df.tail(3) == [Row(id=7), Row(id=8), Row(id=9)]
+
+
+def test_offset_and_tail_across_partitions(spark_session):
+    df = spark_session.range(0, 100, 1, 4)
+
+    assert [row.id for row in df.offset(90).collect()] == list(range(90, 100))
+    assert [row.id for row in df.offset(10).limit(5).collect()] == list(range(10, 15))
+    assert df.tail(3) == [Row(id=97), Row(id=98), Row(id=99)]
+    assert [row.id for row in df.filter(df.id % 7 == 0).tail(2)] == [91, 98]
+
+
+def test_partitioning_hints(spark_session):
+    df = spark_session.range(10)
+
+    assert sorted(row.id for row in df.hint("coalesce", 1).collect()) == list(range(10))
+    assert sorted(row.id for row in df.hint("repartition", 2, "id").collect()) == list(range(10))
+    # unknown hints are ignored
+    assert sorted(row.id for row in df.hint("unknown").collect()) == list(range(10))
+
+
+def test_broadcast_join(spark_session):
+    left = spark_session.createDataFrame([(1, "a"), (2, "b"), (3, "c")], ["id", "x"])
+    right = spark_session.createDataFrame([(2, "B"), (3, "C")], ["id", "y"])
+
+    df = left.join(broadcast(right), on="id")
+
+    assert sorted(df.collect()) == [Row(id=2, x="b", y="B"), Row(id=3, x="c", y="C")]
+
+
+@pytest.mark.parametrize("how", ["inner", "left", "right", "outer", "left_semi", "left_anti"])
+@pytest.mark.parametrize("hinted", ["left", "right"])
+def test_broadcast_join_types(spark_session, how, hinted):
+    left = spark_session.createDataFrame([(1, "a"), (2, "b"), (3, "c")], ["id", "x"])
+    right = spark_session.createDataFrame([(2, "B"), (3, "C"), (4, "D")], ["id", "y"])
+
+    expected = sorted(left.join(right, on="id", how=how).collect(), key=str)
+
+    # the hint only changes how the join is executed, and is ignored where the hinted side can't be broadcast
+    if hinted == "left":
+        df = broadcast(left).join(right, on="id", how=how)
+    else:
+        df = left.join(broadcast(right), on="id", how=how)
+
+    assert sorted(df.collect(), key=str) == expected
+
+
+def test_unpivot(spark_session):
+    df = spark_session.createDataFrame([(1, 11, 12), (2, 21, 22)], ["id", "a", "b"])
+
+    unpivoted = df.unpivot("id", ["a", "b"], "var", "val")
+
+    assert unpivoted.columns == ["id", "var", "val"]
+    assert sorted(unpivoted.collect()) == [
+        Row(id=1, var="a", val=11),
+        Row(id=1, var="b", val=12),
+        Row(id=2, var="a", val=21),
+        Row(id=2, var="b", val=22),
+    ]
+
+
+def test_to_schema(spark_session):
+    df = spark_session.createDataFrame([(1, "a"), (2, "b")], "id int, x string")
+    schema = StructType([StructField("x", StringType()), StructField("id", LongType())])
+
+    df = df.to(schema)
+
+    assert df.columns == ["x", "id"]
+    assert df.schema["id"].dataType == LongType()
+    assert sorted(df.collect()) == [Row(x="a", id=1), Row(x="b", id=2)]
+
+
+def test_alias(spark_session):
+    df = spark_session.range(3).alias("t")
+
+    assert [row.id for row in df.collect()] == [0, 1, 2]
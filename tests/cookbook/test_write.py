@@ -9,7 +9,7 @@ import pytest
 from pyarrow import dataset as pads
 
 import daft
-from tests.conftest import assert_df_equals
+from tests.conftest import assert_df_equals, get_tests_daft_runner_name
 from tests.cookbook.assets import COOKBOOK_DATA_CSV
 
 PYARROW_GE_7_0_0 = tuple(int(s) for s in pa.__version__.split(".") if s.isnumeric()) >= (7, 0, 0)
@@ -292,6 +292,20 @@ def test_csv_write_with_some_empty_partitions(tmp_path, with_morsel_size):
     assert read_back == data
 
 
+def test_csv_write_with_delimiter(tmp_path, with_morsel_size):
+    data = {"x": [1, 2, 3], "y": ["a", "b,c", "d"]}
+    daft.from_pydict(data).write_csv(tmp_path, delimiter="|")
+
+    read_back = daft.read_csv(tmp_path.as_posix() + "/*.csv", delimiter="|").sort("x").to_pydict()
+    assert read_back == data
+
+
+@pytest.mark.parametrize("option", ["delimiter", "quote"])
+def test_csv_write_with_non_ascii_option(tmp_path, option):
+    with pytest.raises(ValueError, match="must be a single ASCII character"):
+        daft.from_pydict({"x": [1]}).write_csv(tmp_path, **{option: "é"})
+
+
 def test_csv_partitioned_write_with_some_empty_partitions(tmp_path, with_morsel_size):
     data = {"x": [1, 2, 3], "y": ["a", "b", "c"]}
     output_files = daft.from_pydict(data).into_partitions(4).write_csv(tmp_path, partition_cols=["x"])
@@ -300,3 +314,55 @@ def test_csv_partitioned_write_with_some_empty_partitions(tmp_path, with_morsel_
 
     read_back = daft.read_csv(tmp_path.as_posix() + "/**/*.csv").sort("x").to_pydict()
     assert read_back == data
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() != "native", reason="JSON writes are only supported on the native runner")
+def test_json_write(tmp_path, with_morsel_size):
+    data = {"x": [1, 2, None], "y": ["a", None, "c"], "z": [[1], [], [2, 3]]}
+    df = daft.from_pydict(data)
+
+    output_files = df.write_json(tmp_path)
+    assert len(output_files) == 1
+
+    read_back = daft.read_json(tmp_path.as_posix() + "/*.json").sort("x").to_pydict()
+    assert read_back == daft.from_pydict(data).sort("x").to_pydict()
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() != "native", reason="JSON writes are only supported on the native runner")
+def test_json_write_with_partitioning(tmp_path, with_morsel_size):
+    data = {"x": [1, 2, 3], "y": ["a", "a", "b"]}
+    output_files = daft.from_pydict(data).write_json(tmp_path, partition_cols=["y"])
+    assert len(output_files) == 2
+
+    read_back = daft.read_json(tmp_path.as_posix() + "/**/*.json").sort("x").to_pydict()
+    assert read_back["x"] == data["x"]
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Arrow IPC writes are only supported on the native runner"
+)
+@pytest.mark.parametrize("compression", [None, "lz4", "zstd"])
+def test_ipc_write(tmp_path, compression, with_morsel_size):
+    data = {"x": [1, 2, None], "y": ["a", None, "c"], "z": [[1], [], [2, 3]]}
+    df = daft.from_pydict(data)
+
+    output_files = df.write_ipc(tmp_path, compression=compression)
+    assert len(output_files) == 1
+
+    read_back = daft.read_ipc(tmp_path.as_posix() + "/*.arrow").sort("x").to_pydict()
+    assert read_back == daft.from_pydict(data).sort("x").to_pydict()
+
+    pa_table = pa.ipc.open_file(output_files.to_pydict()["path"][0]).read_all()
+    assert pa_table.num_rows == 3
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Arrow IPC writes are only supported on the native runner"
+)
+def test_ipc_write_with_partitioning(tmp_path, with_morsel_size):
+    data = {"x": [1, 2, 3], "y": ["a", "a", "b"]}
+    output_files = daft.from_pydict(data).write_ipc(tmp_path, partition_cols=["y"])
+    assert len(output_files) == 2
+
+    read_back = daft.read_ipc(tmp_path.as_posix() + "/**/*.arrow").sort("x").to_pydict()
+    assert read_back["x"] == data["x"]
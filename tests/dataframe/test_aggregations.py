@@ -866,3 +866,33 @@ def test_bool_agg_type_error(make_df):
     with pytest.raises(Exception) as exc_info:
         df.agg(col("int_col").bool_or()).collect()
     assert "bool_or is not implemented for type Int64" in str(exc_info.value)
+
+
+@pytest.mark.parametrize("num_groups", [1, 10, 1000])
+def test_groupby_spills_to_disk(make_df, num_groups, with_morsel_size):
+    # A budget of 1 byte forces the native runner to spill the aggregation state after every morsel.
+    num_rows = 4000
+    with daft.execution_config_ctx(grouped_aggregate_spill_threshold=1):
+        df = make_df(
+            {
+                "group": [i % num_groups for i in range(num_rows)],
+                "value": [i for i in range(num_rows)],
+            },
+            repartition=4,
+        )
+        df = df.groupby("group").agg(
+            col("value").sum().alias("sum"),
+            col("value").mean().alias("mean"),
+            col("value").count().alias("count"),
+            col("value").max().alias("max"),
+        )
+        res = df.sort("group").to_pydict()
+
+    expected_groups = [[v for v in range(num_rows) if v % num_groups == g] for g in range(num_groups)]
+    assert res == {
+        "group": list(range(num_groups)),
+        "sum": [sum(values) for values in expected_groups],
+        "mean": [sum(values) / len(values) for values in expected_groups],
+        "count": [len(values) for values in expected_groups],
+        "max": [max(values) for values in expected_groups],
+    }
@@ -0,0 +1,93 @@
+from __future__ import annotations
+
+import pytest
+
+from daft import col
+
+DATA = {
+    "region": ["eu", "eu", "eu", "us", "us"],
+    "city": ["ams", "ams", "ber", "nyc", "sfo"],
+    "sales": [1, 2, 3, 4, 5],
+}
+
+
+def sorted_rows(df, keys):
+    return sorted(
+        zip(*[df[key] for key in keys]),
+        key=lambda row: tuple((value is None, value) for value in row),
+    )
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 5])
+def test_rollup(make_df, repartition_nparts, with_morsel_size):
+    df = make_df(DATA, repartition=repartition_nparts)
+    result = df.rollup("region", "city").agg(col("sales").sum()).to_pydict()
+
+    assert sorted_rows(result, ["grouping_id", "region", "city", "sales"]) == [
+        (0, "eu", "ams", 3),
+        (0, "eu", "ber", 3),
+        (0, "us", "nyc", 4),
+        (0, "us", "sfo", 5),
+        (1, "eu", None, 6),
+        (1, "us", None, 9),
+        (3, None, None, 15),
+    ]
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 5])
+def test_cube(make_df, repartition_nparts, with_morsel_size):
+    df = make_df(DATA, repartition=repartition_nparts)
+    result = df.cube("region", "city").agg(col("sales").sum(), col("sales").count().alias("count")).to_pydict()
+
+    rows = sorted_rows(result, ["grouping_id", "region", "city", "sales", "count"])
+    assert len(rows) == 4 + 2 + 4 + 1
+    assert [row for row in rows if row[0] == 2] == [
+        (2, None, "ams", 3, 2),
+        (2, None, "ber", 3, 1),
+        (2, None, "nyc", 4, 1),
+        (2, None, "sfo", 5, 1),
+    ]
+    assert rows[-1] == (3, None, None, 15, 5)
+
+
+def test_grouping_sets(make_df):
+    df = make_df(DATA)
+    result = df.grouping_sets("region", ["city"], []).agg(col("sales").max()).to_pydict()
+
+    assert sorted_rows(result, ["grouping_id", "region", "city", "sales"]) == [
+        (1, "eu", None, 3),
+        (1, "us", None, 5),
+        (2, None, "ams", 2),
+        (2, None, "ber", 3),
+        (2, None, "nyc", 4),
+        (2, None, "sfo", 5),
+        (3, None, None, 5),
+    ]
+
+
+def test_grouping_sets_aggregates_see_grouping_keys(make_df):
+    df = make_df({"a": [1, 1, 2], "b": [10, 20, 30]})
+    result = df.rollup("a").agg(col("a").sum().alias("a_sum"), col("b").sum()).to_pydict()
+
+    assert sorted_rows(result, ["grouping_id", "a", "a_sum", "b"]) == [
+        (0, 1, 2, 30),
+        (0, 2, 2, 30),
+        (1, None, 4, 60),
+    ]
+
+
+def test_grouping_sets_keeps_null_keys_apart_from_subtotals(make_df):
+    df = make_df({"a": [None, None, 1], "b": [1, 2, 3]})
+    result = df.rollup("a").agg(col("b").sum()).to_pydict()
+
+    assert sorted_rows(result, ["grouping_id", "a", "b"]) == [
+        (0, 1, 3),
+        (0, None, 3),
+        (1, None, 6),
+    ]
+
+
+def test_grouping_sets_requires_a_grouping_set(make_df):
+    df = make_df(DATA)
+    with pytest.raises(ValueError):
+        df.grouping_sets()
@@ -0,0 +1,127 @@
+from __future__ import annotations
+
+import datetime
+
+import pytest
+
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="ASOF joins are only supported on the native runner"
+)
+
+
+@pytest.fixture
+def trades_and_quotes(make_df):
+    trades = make_df(
+        {
+            "time": [1, 3, 5, 8, 10],
+            "ticker": ["A", "B", "A", "A", "B"],
+            "price": [10, 20, 11, 12, 21],
+        },
+        repartition=2,
+    )
+    quotes = make_df(
+        {
+            "time": [0, 2, 3, 6, 9, 11],
+            "ticker": ["A", "A", "B", "A", "B", "A"],
+            "bid": [100, 101, 200, 102, 201, 103],
+        },
+        repartition=2,
+    )
+    return trades, quotes
+
+
+@pytest.mark.parametrize(
+    "direction,expected_bid",
+    [
+        ("backward", [100, 200, 101, 102, 201]),
+        ("forward", [101, 200, 102, 103, None]),
+        ("nearest", [100, 200, 102, 102, 201]),
+    ],
+)
+def test_join_asof_by(trades_and_quotes, direction, expected_bid):
+    trades, quotes = trades_and_quotes
+    result = trades.join_asof(quotes, on="time", by="ticker", direction=direction).sort("time").to_pydict()
+    assert result == {
+        "time": [1, 3, 5, 8, 10],
+        "ticker": ["A", "B", "A", "A", "B"],
+        "price": [10, 20, 11, 12, 21],
+        "bid": expected_bid,
+    }
+
+
+def test_join_asof_keeps_unmatched_left_rows(make_df):
+    left = make_df({"time": [1, 5, None, 10], "a": ["w", "x", "y", "z"]})
+    right = make_df({"time": [2, 3, 7], "b": [10, 20, 30]})
+    result = left.join_asof(right, on="time").sort("a").to_pydict()
+    assert result == {"time": [1, 5, None, 10], "a": ["w", "x", "y", "z"], "b": [None, 20, None, 30]}
+
+
+def test_join_asof_tolerance(trades_and_quotes):
+    trades, quotes = trades_and_quotes
+    result = trades.join_asof(quotes, on="time", by="ticker", tolerance=1).sort("time").to_pydict()
+    assert result["bid"] == [100, 200, None, None, 201]
+
+
+def test_join_asof_left_right_keys(make_df):
+    left = make_df({"t": [1, 5, 10], "k": [1, 1, 2]})
+    right = make_df({"ts": [0, 4, 9], "key": [1, 1, 1], "v": ["a", "b", "c"]})
+    result = left.join_asof(right, left_on="t", right_on="ts", left_by="k", right_by="key").sort("t").to_pydict()
+    assert result == {
+        "t": [1, 5, 10],
+        "k": [1, 1, 2],
+        "ts": [0, 4, None],
+        "key": [1, 1, None],
+        "v": ["a", "b", None],
+    }
+
+
+def test_join_asof_conflicting_columns(make_df):
+    left = make_df({"time": [1, 5], "v": [1, 2]})
+    right = make_df({"time": [0, 4], "v": [10, 20]})
+    result = left.join_asof(right, on="time", suffix="_right").sort("time").to_pydict()
+    assert result == {"time": [1, 5], "v": [1, 2], "v_right": [10, 20]}
+
+
+def test_join_asof_timestamps(make_df):
+    base = datetime.datetime(2024, 1, 1)
+    left = make_df({"ts": [base + datetime.timedelta(minutes=m) for m in [1, 10]]})
+    right = make_df({"ts": [base, base + datetime.timedelta(minutes=8)], "v": [1, 2]})
+    result = (
+        left.join_asof(right, on="ts", tolerance=datetime.timedelta(minutes=5), direction="backward")
+        .sort("ts")
+        .to_pydict()
+    )
+    assert result["v"] == [1, 2]
+
+
+def test_join_asof_many_left_partitions(make_df):
+    # the right side is sorted once and reused for every left partition, so it must not be assumed to be sorted
+    right_times = [7, None, 3, 11, 0, 5, 9, 1]
+    left = make_df({"time": list(range(12))}, repartition=4)
+    right = make_df({"time": right_times, "v": list(range(len(right_times)))})
+    result = left.join_asof(right, on="time").sort("time").to_pydict()
+
+    def backward(t):
+        matches = [(rt, v) for v, rt in enumerate(right_times) if rt is not None and rt <= t]
+        return max(matches)[1] if matches else None
+
+    assert result == {"time": list(range(12)), "v": [backward(t) for t in range(12)]}
+
+
+def test_join_asof_empty_right(make_df):
+    left = make_df({"time": [1, 2], "a": [1, 2]})
+    right = make_df({"time": [0], "b": [1]}).where("b > 1")
+    result = left.join_asof(right, on="time").sort("time").to_pydict()
+    assert result == {"time": [1, 2], "a": [1, 2], "b": [None, None]}
+
+
+def test_join_asof_invalid_args(make_df):
+    df = make_df({"time": [1], "k": [1]})
+    with pytest.raises(ValueError):
+        df.join_asof(df, on="time", left_on="time", right_on="time")
+    with pytest.raises(ValueError):
+        df.join_asof(df, on="time", left_by="k")
+    with pytest.raises(ValueError):
+        df.join_asof(df, on="time", direction="sideways")
@@ -12,8 +12,10 @@ from tests.utils import sort_arrow_table
 
 def skip_invalid_join_strategies(join_strategy, join_type):
     if get_tests_daft_runner_name() == "native":
-        if join_strategy not in [None, "hash"]:
+        if join_strategy not in [None, "hash", "broadcast"]:
             pytest.skip("Native executor fails for these tests")
+        elif join_strategy == "broadcast" and join_type == "outer":
+            pytest.skip("Broadcast join does not support outer joins")
     else:
         if (join_strategy == "sort_merge" or join_strategy == "sort_merge_aligned_boundaries") and join_type != "inner":
             pytest.skip("Sort merge currently only supports inner joins")
@@ -1356,3 +1358,71 @@ def test_join_different_join_key_types(join_type, expected, repartition_nparts,
     assert sort_arrow_table(pa.Table.from_pydict(result.to_pydict()), *sort_by) == sort_arrow_table(
         pa.Table.from_pydict(expected), *sort_by
     )
+
+
+@pytest.mark.parametrize("join_type", ["inner", "left", "right", "outer", "anti", "semi"])
+@pytest.mark.parametrize("left_size,right_size", [(100, 1000), (1000, 100)])
+def test_join_spills_build_side(join_type, left_size, right_size, make_df, with_morsel_size):
+    left = {"a": [i % 150 for i in range(left_size)], "b": [str(i) for i in range(left_size)]}
+    right = {"a": [i % 120 for i in range(right_size)], "c": list(range(right_size))}
+
+    def run_join():
+        left_df = make_df(left, repartition=2)
+        right_df = make_df(right, repartition=2)
+        return pa.Table.from_pydict(left_df.join(right_df, on="a", how=join_type).to_pydict())
+
+    expected = run_join()
+    # A budget of 1 byte forces the native runner to partition both sides of the join to disk.
+    with daft.execution_config_ctx(hash_join_spill_threshold=1):
+        result = run_join()
+
+    sort_by = ["a", "b", "c"] if join_type in ["inner", "left", "right", "outer"] else ["a", "b"]
+    assert sort_arrow_table(result, *sort_by) == sort_arrow_table(expected, *sort_by)
+
+
+@pytest.mark.parametrize("join_type", ["inner", "left", "right", "outer", "anti", "semi"])
+def test_join_spills_oversized_partitions(join_type, make_df, with_morsel_size):
+    # half of the rows share a single key, so some partitions stay over budget however often they are split
+    left = {"a": [0 if i % 2 else i for i in range(600)], "b": [str(i) for i in range(600)]}
+    right = {"a": [0 if i % 2 else i for i in range(200)], "c": list(range(200))}
+
+    def run_join():
+        left_df = make_df(left, repartition=2)
+        right_df = make_df(right, repartition=2)
+        return pa.Table.from_pydict(left_df.join(right_df, on="a", how=join_type).to_pydict())
+
+    expected = run_join()
+    # Every partition exceeds a 1 byte budget, so partitions are split again with other hash seeds.
+    with daft.execution_config_ctx(hash_join_spill_threshold=1):
+        result = run_join()
+
+    sort_by = ["a", "b", "c"] if join_type in ["inner", "left", "right", "outer"] else ["a", "b"]
+    assert sort_arrow_table(result, *sort_by) == sort_arrow_table(expected, *sort_by)
+
+
+@pytest.mark.parametrize("python_side", ["left", "right"])
+def test_join_spill_keeps_python_objects_in_memory(python_side, make_df, with_morsel_size):
+    class Obj:
+        def __init__(self, value):
+            self.value = value
+
+    def with_objects(df, column):
+        return df.with_column("obj", df[column].apply(Obj, return_dtype=DataType.python()))
+
+    def run_join():
+        left_df = make_df({"a": [i % 50 for i in range(300)], "b": list(range(300))}, repartition=2)
+        right_df = make_df({"a": [i % 40 for i in range(100)], "c": list(range(100))}, repartition=2)
+        # Python objects can't be spilled to disk, so the side holding them is kept in memory
+        if python_side == "left":
+            left_df = with_objects(left_df, "b")
+        else:
+            right_df = with_objects(right_df, "c")
+        joined = left_df.join(right_df, on="a")
+        joined = joined.with_column("obj", col("obj").apply(lambda obj: obj.value, return_dtype=DataType.int64()))
+        return pa.Table.from_pydict(joined.to_pydict())
+
+    expected = run_join()
+    with daft.execution_config_ctx(hash_join_spill_threshold=1):
+        result = run_join()
+
+    assert sort_arrow_table(result, "a", "b", "c") == sort_arrow_table(expected, "a", "b", "c")
@@ -280,6 +280,13 @@ def test_sort_desc_nulls_first(make_df):
     assert result["A"] == [None, None, 3, 2, 1]
 
 
+def test_sort_desc_nulls_last(make_df):
+    df = make_df({"A": [None, 1, None, 3, 4, 2, 5]})
+
+    result = df.sort("A", desc=True, nulls_first=False).to_pydict()
+    assert result["A"] == [5, 4, 3, 2, 1, None, None]
+
+
 @pytest.mark.parametrize(
     "cast_to",
     [
@@ -0,0 +1,110 @@
+from __future__ import annotations
+
+import pytest
+
+from daft import col
+from daft.window import Window, dense_rank, rank, row_number
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native",
+    reason="Window functions are currently only supported on the native runner",
+)
+
+
+@pytest.fixture
+def sales(make_df):
+    return make_df(
+        {
+            "store": ["a", "a", "a", "b", "b", "a"],
+            "day": [1, 2, 3, 1, 2, 3],
+            "sales": [10, 20, 30, 5, None, 40],
+        }
+    )
+
+
+def test_window_partition_agg(sales):
+    window = Window().partition_by("store")
+    df = sales.with_column("total", col("sales").sum().over(window)).sort(["store", "day", "sales"])
+    assert df.to_pydict()["total"] == [100, 100, 100, 100, 5, 5]
+
+
+def test_window_running_sum(sales):
+    window = Window().partition_by("store").order_by("day")
+    df = sales.with_columns(
+        {
+            "running": col("sales").sum().over(window),
+            "running_count": col("sales").count().over(window),
+        }
+    ).sort(["store", "day", "sales"])
+    # Rows with the same order by key are peers, and are included in each other's frames.
+    assert df.to_pydict()["running"] == [10, 30, 100, 100, 5, 5]
+    assert df.to_pydict()["running_count"] == [1, 2, 4, 4, 1, 1]
+
+
+def test_window_rows_frame(sales):
+    window = Window().partition_by("store").order_by("day", "sales").rows_between(-1, Window.current_row)
+    df = sales.with_column("moving", col("sales").sum().over(window)).sort(["store", "day", "sales"])
+    assert df.to_pydict()["moving"] == [10, 30, 50, 70, 5, 5]
+
+
+def test_window_range_frame(make_df):
+    df = make_df({"t": [1, 2, 4, 5, 9], "x": [1, 2, 3, 4, 5]})
+    window = Window().order_by("t").range_between(-2, 0)
+    df = df.with_column("s", col("x").sum().over(window)).sort("t")
+    assert df.to_pydict()["s"] == [1, 3, 5, 7, 5]
+
+
+def test_window_ranking(make_df):
+    df = make_df({"g": [1, 1, 1, 1, 2], "x": [3, 1, 1, 2, 7]})
+    window = Window().partition_by("g").order_by("x")
+    df = df.select(
+        "g",
+        "x",
+        row_number().over(window).alias("row_number"),
+        rank().over(window).alias("rank"),
+        dense_rank().over(window).alias("dense_rank"),
+    ).sort(["g", "x", "row_number"])
+    assert df.to_pydict() == {
+        "g": [1, 1, 1, 1, 2],
+        "x": [1, 1, 2, 3, 7],
+        "row_number": [1, 2, 3, 4, 1],
+        "rank": [1, 1, 3, 4, 1],
+        "dense_rank": [1, 1, 2, 3, 1],
+    }
+
+
+def test_window_lag_lead(make_df):
+    df = make_df({"t": [3, 1, 2], "x": [30, 10, 20]})
+    window = Window().order_by("t")
+    df = df.with_columns(
+        {
+            "prev": col("x").lag(1).over(window),
+            "next": col("x").lead(1, default=0).over(window),
+        }
+    ).sort("t")
+    assert df.to_pydict() == {"t": [1, 2, 3], "x": [10, 20, 30], "prev": [None, 10, 20], "next": [20, 30, 0]}
+
+
+def test_window_in_expression(sales):
+    window = Window().partition_by("store")
+    df = sales.select("store", "sales", (col("sales") / col("sales").sum().over(window)).alias("share"))
+    df = df.where(col("store") == "b").sort("sales")
+    assert df.to_pydict()["share"] == [1.0, None]
+
+
+def test_window_not_allowed_in_filter(sales):
+    with pytest.raises(Exception, match="Window functions are currently only allowed in select and with_columns"):
+        sales.where(col("sales").sum().over(Window()) > 10)
+
+
+def test_over_requires_aggregation(sales):
+    with pytest.raises(Exception, match="Only aggregations and window functions can be evaluated over a window"):
+        col("sales").over(Window())
+
+
+def test_lag_lead_offset_out_of_range():
+    with pytest.raises(Exception, match="offset must be at most"):
+        col("x").lag(2**63)
+    with pytest.raises(Exception, match="offset must be at most"):
+        col("x").lead(2**63)
@@ -0,0 +1,75 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pyarrow.feather as feather
+import pytest
+
+import daft
+from daft import DataType, col
+
+TABLE = pa.table(
+    {
+        "id": pa.array([1, 2, 3, 4, 5], type=pa.int64()),
+        "name": ["a", "b", None, "d", "e"],
+        "score": [1.5, 2.5, 3.5, None, 5.5],
+    }
+)
+
+
+@pytest.fixture(scope="function")
+def ipc_file(tmp_path):
+    """Writes 1 Arrow IPC file with a record batch per 2 rows."""
+    path = tmp_path / "file.arrow"
+    with pa.ipc.new_file(str(path), TABLE.schema) as writer:
+        writer.write_table(TABLE, max_chunksize=2)
+    return str(path)
+
+
+@pytest.fixture(scope="function")
+def ipc_stream_file(tmp_path):
+    path = tmp_path / "stream.arrows"
+    with pa.ipc.new_stream(str(path), TABLE.schema) as writer:
+        writer.write_table(TABLE, max_chunksize=2)
+    return str(path)
+
+
+def test_read_ipc_file(ipc_file):
+    df = daft.read_ipc(ipc_file)
+    assert df.schema()["id"].dtype == DataType.int64()
+    assert df.schema()["name"].dtype == DataType.string()
+    assert df.schema()["score"].dtype == DataType.float64()
+    assert df.to_arrow() == TABLE
+
+
+def test_read_ipc_stream(ipc_stream_file):
+    df = daft.read_ipc(ipc_stream_file)
+    assert df.to_arrow() == TABLE
+
+
+@pytest.mark.parametrize("compression", ["uncompressed", "lz4", "zstd"])
+def test_read_feather(tmp_path, compression):
+    path = str(tmp_path / "file.feather")
+    feather.write_feather(TABLE, path, compression=compression)
+    assert daft.read_ipc(path).to_arrow() == TABLE
+
+
+def test_read_ipc_with_schema(ipc_file):
+    df = daft.read_ipc(ipc_file, infer_schema=False, schema={"id": DataType.int32(), "name": DataType.string()})
+    assert df.to_pydict() == {"id": [1, 2, 3, 4, 5], "name": ["a", "b", None, "d", "e"]}
+
+
+def test_read_ipc_column_pushdown(ipc_file):
+    df = daft.read_ipc(ipc_file).select("score", "id")
+    assert df.to_pydict() == {"score": [1.5, 2.5, 3.5, None, 5.5], "id": [1, 2, 3, 4, 5]}
+
+
+def test_read_ipc_filter_and_limit(ipc_file):
+    df = daft.read_ipc(ipc_file).where(col("id") > 1).select("name").limit(2)
+    assert df.to_pydict() == {"name": ["b", None]}
+
+
+def test_read_ipc_multiple_files(ipc_file, ipc_stream_file):
+    df = daft.read_ipc([ipc_file, ipc_stream_file], file_path_column="path")
+    assert df.count_rows() == 10
+    assert sorted(set(df.to_pydict()["path"])) == sorted([ipc_file, ipc_stream_file])
+
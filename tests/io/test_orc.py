@@ -0,0 +1,85 @@
+from __future__ import annotations
+
+import datetime
+
+import pyarrow as pa
+import pytest
+
+import daft
+from daft import DataType
+
+paorc = pytest.importorskip("pyarrow.orc")
+
+
+@pytest.fixture(scope="function")
+def orc_file(tmp_path):
+    tbl = pa.table(
+        {
+            "id": pa.array([1, 2, 3, 4, 5], type=pa.int64()),
+            "name": ["a", "b", None, "d", "e"],
+            "score": [1.5, 2.5, 3.5, None, 5.5],
+            "day": [datetime.date(2024, 1, i) for i in range(1, 6)],
+        }
+    )
+    path = tmp_path / "file.orc"
+    paorc.write_table(tbl, str(path))
+    return str(path)
+
+
+@pytest.fixture(scope="function")
+def multi_stripe_orc_file(tmp_path):
+    """Writes 1 ORC file with many small stripes."""
+    tbl = pa.table({"id": pa.array(range(10_000), type=pa.int64())})
+    path = tmp_path / "stripes.orc"
+    paorc.write_table(tbl, str(path), stripe_size=1024, batch_size=100)
+    assert paorc.ORCFile(str(path)).nstripes > 1
+    return str(path)
+
+
+def test_read_orc_infers_schema(orc_file):
+    df = daft.read_orc(orc_file)
+    assert df.schema()["id"].dtype == DataType.int64()
+    assert df.schema()["name"].dtype == DataType.string()
+    assert df.schema()["score"].dtype == DataType.float64()
+    assert df.schema()["day"].dtype == DataType.date()
+    assert df.to_pydict() == {
+        "id": [1, 2, 3, 4, 5],
+        "name": ["a", "b", None, "d", "e"],
+        "score": [1.5, 2.5, 3.5, None, 5.5],
+        "day": [datetime.date(2024, 1, i) for i in range(1, 6)],
+    }
+
+
+def test_read_orc_with_schema(orc_file):
+    df = daft.read_orc(orc_file, infer_schema=False, schema={"id": DataType.int32(), "name": DataType.string()})
+    assert df.to_pydict() == {"id": [1, 2, 3, 4, 5], "name": ["a", "b", None, "d", "e"]}
+
+
+def test_read_orc_column_pushdown(orc_file):
+    df = daft.read_orc(orc_file).select("score", "id")
+    assert df.to_pydict() == {"score": [1.5, 2.5, 3.5, None, 5.5], "id": [1, 2, 3, 4, 5]}
+
+
+def test_read_orc_predicate_pushdown(orc_file):
+    df = daft.read_orc(orc_file).where(daft.col("id") > 2).select("name")
+    assert df.to_pydict() == {"name": [None, "d", "e"]}
+
+
+def test_read_orc_limit(orc_file):
+    df = daft.read_orc(orc_file).limit(2)
+    assert df.to_pydict()["id"] == [1, 2]
+
+
+def test_split_orc_read(multi_stripe_orc_file):
+    with daft.execution_config_ctx(
+        scan_tasks_min_size_bytes=1,
+        scan_tasks_max_size_bytes=10,
+    ):
+        df = daft.read_orc(multi_stripe_orc_file)
+        assert df.num_partitions() == paorc.ORCFile(multi_stripe_orc_file).nstripes
+        assert df.to_pydict() == {"id": list(range(10_000))}
+
+
+def test_read_orc_predicate_skips_stripes(multi_stripe_orc_file):
+    df = daft.read_orc(multi_stripe_orc_file).where((daft.col("id") >= 5_000) & (daft.col("id") < 5_010))
+    assert df.to_pydict() == {"id": list(range(5_000, 5_010))}
@@ -2,7 +2,7 @@ import datetime
 
 import pytest
 
-from daft import col
+from daft import DataType, col
 from daft.recordbatch.micropartition import MicroPartition
 
 DATETIMES_WITHOUT_NULL = [
@@ -125,3 +125,63 @@ def test_table_time(input, expected):
     table = MicroPartition.from_pydict({"datetime": input})
     dates = table.eval_expression_list([col("datetime").dt.time()])
     assert dates.get_column("datetime").to_pylist() == expected
+
+
+@pytest.mark.parametrize(
+    "tz, expected",
+    [
+        (
+            "America/New_York",
+            [datetime.datetime(2021, 1, 1, 7, 0), datetime.datetime(2021, 7, 1, 8, 0), None],
+        ),
+        (
+            "+08:00",
+            [datetime.datetime(2021, 1, 1, 20, 0), datetime.datetime(2021, 7, 1, 20, 0), None],
+        ),
+    ],
+)
+def test_table_convert_timezone(tz, expected):
+    table = MicroPartition.from_pydict(
+        {"datetime": [datetime.datetime(2021, 1, 1, 12, 0), datetime.datetime(2021, 7, 1, 12, 0), None]}
+    )
+    converted = table.eval_expression_list([col("datetime").dt.convert_timezone(tz)])
+    assert converted.schema()["datetime"].dtype == DataType.timestamp("us", tz)
+
+    local = table.eval_expression_list([col("datetime").dt.convert_timezone(tz).dt.replace_timezone()])
+    assert local.get_column("datetime").to_pylist() == expected
+
+
+@pytest.mark.parametrize(
+    "input, expected",
+    [
+        # standard time
+        (datetime.datetime(2021, 1, 1, 12, 0), datetime.datetime(2021, 1, 1, 17, 0)),
+        # daylight saving time
+        (datetime.datetime(2021, 7, 1, 12, 0), datetime.datetime(2021, 7, 1, 16, 0)),
+        # repeated when the clocks go back, which resolves to the earliest occurrence
+        (datetime.datetime(2021, 11, 7, 1, 30), datetime.datetime(2021, 11, 7, 5, 30)),
+        # skipped when the clocks go forward, which is shifted forward by the length of the gap
+        (datetime.datetime(2021, 3, 14, 2, 30), datetime.datetime(2021, 3, 14, 7, 30)),
+        (None, None),
+    ],
+)
+def test_table_replace_timezone(input, expected):
+    table = MicroPartition.from_pydict({"datetime": [input]})
+    utc = table.eval_expression_list(
+        [col("datetime").dt.replace_timezone("America/New_York").dt.convert_timezone("UTC").dt.replace_timezone()]
+    )
+    assert utc.get_column("datetime").to_pylist() == [expected]
+
+
+def test_table_replace_timezone_keeps_wall_clock_time():
+    table = MicroPartition.from_pydict({"datetime": DATETIMES_WITH_NULL})
+    round_trip = table.eval_expression_list(
+        [col("datetime").dt.replace_timezone("Asia/Kolkata").dt.replace_timezone("-03:00").dt.replace_timezone()]
+    )
+    assert round_trip.get_column("datetime").to_pylist() == DATETIMES_WITH_NULL
+
+
+def test_table_convert_timezone_invalid():
+    table = MicroPartition.from_pydict({"datetime": DATETIMES_WITHOUT_NULL})
+    with pytest.raises(Exception, match="Cannot parse timezone"):
+        table.eval_expression_list([col("datetime").dt.convert_timezone("Not/A_Timezone")])
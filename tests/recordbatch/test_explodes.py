@@ -51,6 +51,21 @@ def test_explode_multiple_cols(data):
     }
 
 
+@pytest.mark.parametrize(
+    "data",
+    TEST_DATA,
+)
+def test_explode_with_index_column(data):
+    table = MicroPartition.from_pydict({"nested": data, "sidecar": ["a", "b", "c", "d"]})
+    table = table.explode([col("nested")._explode()], index_column="idx")
+    assert table.column_names() == ["nested", "sidecar", "idx"]
+    assert table.to_pydict() == {
+        "nested": [1, 2, 3, 4, None, None],
+        "sidecar": ["a", "a", "b", "b", "c", "d"],
+        "idx": [0, 1, 0, 1, None, None],
+    }
+
+
 def test_explode_multiple_cols_mixed_types():
     data1 = pa.array([[1, 2], [3, 4], None, None], type=pa.list_(pa.int64()))
     data2 = pa.array([[1, 2], [3, 4], None, None], type=pa.list_(pa.int64(), list_size=2))
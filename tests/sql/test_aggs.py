@@ -112,3 +112,116 @@ def test_having_non_grouped():
     ).to_pydict()
 
     assert actual == {"count": [10]}
+
+
+@pytest.fixture
+def sales_catalog():
+    df = daft.from_pydict(
+        {
+            "region": ["eu", "eu", "eu", "us", "us"],
+            "city": ["ams", "ams", "ber", "nyc", "sfo"],
+            "sales": [1, 2, 3, 4, 5],
+        }
+    )
+    return SQLCatalog({"sales": df})
+
+
+def test_group_by_rollup(sales_catalog):
+    actual = daft.sql(
+        """
+    SELECT region, city, sum(sales) as total, grouping(region, city) as level
+    FROM sales
+    GROUP BY ROLLUP(region, city)
+    ORDER BY level, region, city
+    """,
+        sales_catalog,
+    ).to_pydict()
+
+    assert actual == {
+        "region": ["eu", "eu", "us", "us", "eu", "us", None],
+        "city": ["ams", "ber", "nyc", "sfo", None, None, None],
+        "total": [3, 3, 4, 5, 6, 9, 15],
+        "level": [0, 0, 0, 0, 1, 1, 3],
+    }
+
+
+def test_group_by_cube(sales_catalog):
+    actual = daft.sql(
+        """
+    SELECT region, city, count(*) as count
+    FROM sales
+    GROUP BY CUBE(region, city)
+    HAVING grouping(region) = 1 AND grouping(city) = 0
+    ORDER BY city
+    """,
+        sales_catalog,
+    ).to_pydict()
+
+    assert actual == {
+        "region": [None, None, None, None],
+        "city": ["ams", "ber", "nyc", "sfo"],
+        "count": [2, 1, 1, 1],
+    }
+
+
+def test_group_by_grouping_sets(sales_catalog):
+    actual = daft.sql(
+        """
+    SELECT region, city, max(sales) as max_sales
+    FROM sales
+    GROUP BY GROUPING SETS ((region), (city), ())
+    ORDER BY grouping(region, city), region, city
+    """,
+        sales_catalog,
+    ).to_pydict()
+
+    assert actual == {
+        "region": ["eu", "us", None, None, None, None, None],
+        "city": [None, None, "ams", "ber", "nyc", "sfo", None],
+        "max_sales": [3, 5, 2, 3, 4, 5, 5],
+    }
+
+
+def test_group_by_duplicate_grouping_sets(sales_catalog):
+    actual = daft.sql(
+        """
+    SELECT region, sum(sales) as total, grouping(region) as level
+    FROM sales
+    GROUP BY GROUPING SETS ((region), (region), ())
+    ORDER BY level, region
+    """,
+        sales_catalog,
+    ).to_pydict()
+
+    # like in SQL, a repeated grouping set produces its groups once per occurrence
+    assert actual == {
+        "region": ["eu", "eu", "us", "us", None],
+        "total": [6, 6, 9, 9, 15],
+        "level": [0, 0, 0, 0, 1],
+    }
+
+
+def test_group_by_mixed_with_rollup(sales_catalog):
+    actual = daft.sql(
+        """
+    SELECT region, city, sum(sales) as total
+    FROM sales
+    GROUP BY region, ROLLUP(city)
+    ORDER BY region, city
+    """,
+        sales_catalog,
+    ).to_pydict()
+
+    assert actual == {
+        "region": ["eu", "eu", "eu", "us", "us", "us"],
+        "city": ["ams", "ber", None, "nyc", "sfo", None],
+        "total": [3, 3, 6, 4, 5, 9],
+    }
+
+
+def test_grouping_rejects_non_grouping_arguments(sales_catalog):
+    with pytest.raises(Exception, match="GROUPING"):
+        daft.sql(
+            "SELECT region, grouping(sales) FROM sales GROUP BY ROLLUP(region)",
+            sales_catalog,
+        ).collect()
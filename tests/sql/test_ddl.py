@@ -0,0 +1,202 @@
+import pytest
+
+import daft
+from daft.catalog import Catalog, Table
+from daft.session import Session
+
+df = daft.from_pydict({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+
+
+@pytest.fixture(autouse=True)
+def session():
+    """Runs each test against a fresh current session."""
+    prev = daft.current_session()
+    sess = Session()
+    daft.set_session(sess)
+    yield sess
+    daft.set_session(prev)
+
+
+def sorted_pydict(df, by):
+    return df.sort(by).to_pydict()
+
+
+def test_create_table_as_select(session):
+    daft.sql("CREATE TABLE t AS SELECT a, b FROM df WHERE a > 1")
+    assert session.has_table("t")
+    assert sorted_pydict(daft.sql("SELECT * FROM t"), "a") == {"a": [2, 3], "b": ["y", "z"]}
+
+
+def test_create_temp_table(session):
+    daft.sql("CREATE TEMP TABLE t AS SELECT a FROM df")
+    assert sorted_pydict(daft.sql("SELECT a * 2 AS a FROM t"), "a") == {"a": [2, 4, 6]}
+
+
+def test_create_table_materializes():
+    daft.sql("CREATE VIEW v AS SELECT a FROM df WHERE a = 1")
+    daft.sql("CREATE TABLE t AS SELECT a FROM v")
+    # the table keeps the rows of its query when it was created, unlike a view
+    daft.sql("CREATE OR REPLACE VIEW v AS SELECT a FROM df WHERE a = 2")
+    assert daft.sql("SELECT * FROM t").to_pydict() == {"a": [1]}
+
+
+def test_create_table_already_exists():
+    daft.sql("CREATE TABLE t AS SELECT a FROM df")
+    with pytest.raises(Exception, match="already exists"):
+        daft.sql("CREATE TABLE t AS SELECT b FROM df")
+    # IF NOT EXISTS keeps the existing table, OR REPLACE replaces it
+    daft.sql("CREATE TABLE IF NOT EXISTS t AS SELECT b FROM df")
+    assert daft.sql("SELECT * FROM t").column_names == ["a"]
+    daft.sql("CREATE OR REPLACE TABLE t AS SELECT b FROM df")
+    assert daft.sql("SELECT * FROM t").column_names == ["b"]
+
+
+def test_create_table_unsupported():
+    with pytest.raises(Exception, match="CREATE TABLE without AS <query> is not supported"):
+        daft.sql("CREATE TABLE t (a INT)")
+
+
+def test_create_view():
+    daft.sql("CREATE VIEW v AS SELECT a FROM df WHERE a < 3")
+    assert sorted_pydict(daft.sql("SELECT * FROM v"), "a") == {"a": [1, 2]}
+
+
+def test_create_view_with_columns():
+    daft.sql("CREATE VIEW v (x, y) AS SELECT a, b FROM df")
+    assert sorted_pydict(daft.sql("SELECT * FROM v"), "x") == {"x": [1, 2, 3], "y": ["x", "y", "z"]}
+
+
+def test_insert_into():
+    daft.sql("CREATE TABLE t AS SELECT a, b FROM df")
+    daft.sql("INSERT INTO t SELECT a + 10, b FROM df WHERE a = 1").collect()
+    assert sorted_pydict(daft.sql("SELECT * FROM t"), "a") == {"a": [1, 2, 3, 11], "b": ["x", "y", "z", "x"]}
+
+
+def test_insert_into_columns():
+    daft.sql("CREATE TABLE t AS SELECT a, b FROM df WHERE a = 1")
+    daft.sql("INSERT INTO t (b) SELECT 'w'").collect()
+    assert sorted_pydict(daft.sql("SELECT * FROM t"), "b") == {"a": [None, 1], "b": ["w", "x"]}
+
+
+def test_insert_overwrite():
+    daft.sql("CREATE TABLE t AS SELECT a, b FROM df")
+    daft.sql("INSERT OVERWRITE t SELECT a, b FROM df WHERE a = 3").collect()
+    assert daft.sql("SELECT * FROM t").to_pydict() == {"a": [3], "b": ["z"]}
+
+
+def test_insert_into_catalog_table(session):
+    tbl = Table.from_df("tbl", daft.from_pydict({"a": [1]}))
+    session.attach_catalog(Catalog.from_pydict({"ns.tbl": tbl}), alias="cat")
+    daft.sql("INSERT INTO cat.ns.tbl SELECT a + 1 FROM df WHERE a < 3").collect()
+    assert sorted_pydict(daft.sql("SELECT * FROM cat.ns.tbl"), "a") == {"a": [1, 2, 3]}
+    daft.sql("INSERT OVERWRITE cat.ns.tbl SELECT 7").collect()
+    assert daft.sql("SELECT * FROM cat.ns.tbl").to_pydict() == {"a": [7]}
+
+
+def test_statement_results():
+    assert daft.sql("CREATE TABLE t AS SELECT a FROM df").to_pydict() == {}
+    assert daft.sql("INSERT INTO t SELECT a FROM df WHERE a > 1").to_pydict() == {"rows": [2]}
+    assert daft.sql("SHOW TABLES").to_pydict() == {"table": ["t"]}
+
+
+def test_insert_is_lazy():
+    daft.sql("CREATE TABLE t AS SELECT a FROM df")
+    insert = daft.sql("INSERT INTO t SELECT 4")
+    # the rows are only written once the insert is collected
+    assert sorted_pydict(daft.sql("SELECT * FROM t"), "a") == {"a": [1, 2, 3]}
+    insert.collect()
+    assert sorted_pydict(daft.sql("SELECT * FROM t"), "a") == {"a": [1, 2, 3, 4]}
+
+
+def test_insert_into_empty_query():
+    daft.sql("CREATE TABLE t AS SELECT a FROM df")
+    assert daft.sql("INSERT INTO t SELECT a FROM df WHERE a > 3").to_pydict() == {"rows": [0]}
+    assert sorted_pydict(daft.sql("SELECT * FROM t"), "a") == {"a": [1, 2, 3]}
+
+
+def test_insert_into_view():
+    daft.sql("CREATE VIEW v AS SELECT a FROM df")
+    with pytest.raises(Exception, match="writing to a read-only table"):
+        daft.sql("INSERT INTO v SELECT 4")
+
+
+def test_insert_errors():
+    with pytest.raises(Exception, match="Table not found"):
+        daft.sql("INSERT INTO t SELECT a FROM df")
+    daft.sql("CREATE TABLE t AS SELECT a, b FROM df")
+    with pytest.raises(Exception, match="2 target columns but its query returns 1 columns"):
+        daft.sql("INSERT INTO t SELECT a FROM df")
+    with pytest.raises(Exception, match="Column c not found"):
+        daft.sql("INSERT INTO t (c) SELECT a FROM df")
+
+
+def test_describe_ddl_and_dml(session):
+    daft.sql("CREATE TABLE t AS SELECT a FROM df")
+    for statement in ["CREATE TABLE u AS SELECT a FROM df", "INSERT INTO t SELECT 4", "DROP TABLE t"]:
+        with pytest.raises(Exception, match="DESCRIBE is not supported for DDL and DML statements"):
+            daft.sql(f"DESCRIBE {statement}")
+    # describing a statement must not apply it
+    assert not session.has_table("u")
+    assert sorted_pydict(daft.sql("SELECT * FROM t"), "a") == {"a": [1, 2, 3]}
+
+
+def test_insert_into_directory(tmp_path):
+    result = daft.sql(f"INSERT INTO DIRECTORY '{tmp_path}' STORED AS PARQUET SELECT a, b FROM df")
+    assert len(result.to_pydict()["path"]) > 0
+    assert sorted_pydict(daft.read_parquet(str(tmp_path)), "a") == df.to_pydict()
+
+
+def test_drop_table(session):
+    daft.sql("CREATE TABLE t AS SELECT a FROM df")
+    daft.sql("CREATE VIEW v AS SELECT a FROM df")
+    daft.sql("DROP TABLE t")
+    daft.sql("DROP VIEW v")
+    assert not session.has_table("t")
+    assert not session.has_table("v")
+    with pytest.raises(Exception, match="Table not found"):
+        daft.sql("DROP TABLE t")
+    daft.sql("DROP TABLE IF EXISTS t")
+
+
+def test_show_tables():
+    daft.sql("CREATE TABLE t1 AS SELECT a FROM df")
+    daft.sql("CREATE TABLE t2 AS SELECT a FROM df")
+    daft.sql("CREATE VIEW v AS SELECT a FROM df")
+    assert daft.sql("SHOW TABLES").to_pydict() == {"table": ["t1", "t2", "v"]}
+    assert daft.sql("SHOW TABLES LIKE 't%'").to_pydict() == {"table": ["t1", "t2"]}
+
+
+def test_show_catalogs(session):
+    session.attach_catalog(Catalog.from_pydict({}), alias="cat2")
+    session.attach_catalog(Catalog.from_pydict({}), alias="cat1")
+    assert daft.sql("SHOW CATALOGS").to_pydict() == {"catalog": ["cat1", "cat2"]}
+
+
+def test_use_catalog_and_namespace(session):
+    tables = {
+        "t": Table.from_df("t", daft.from_pydict({"x": [1]})),
+        "ns.t": Table.from_df("t", daft.from_pydict({"x": [2]})),
+    }
+    session.attach_catalog(Catalog.from_pydict(tables), alias="cat")
+    #
+    # fully qualified
+    assert daft.sql("SELECT * FROM cat.ns.t").to_pydict() == {"x": [2]}
+    #
+    # current catalog
+    daft.sql("USE cat")
+    assert session.current_namespace() == []
+    assert daft.sql("SELECT * FROM t").to_pydict() == {"x": [1]}
+    #
+    # current catalog and namespace
+    daft.sql("USE cat.ns")
+    assert session.current_namespace() == ["ns"]
+    assert daft.sql("SELECT * FROM t").to_pydict() == {"x": [2]}
+    #
+    # session tables shadow catalog tables
+    daft.sql("CREATE TABLE t AS SELECT 3 AS x")
+    assert daft.sql("SELECT * FROM t").to_pydict() == {"x": [3]}
+
+
+def test_use_unknown_catalog():
+    with pytest.raises(Exception, match="Catalog with name unknown not found"):
+        daft.sql("USE unknown")
@@ -248,3 +248,167 @@ def test_coalesce():
     ).to_pydict()
 
     assert actual == expected
+
+
+def test_case_with_operand():
+    df = daft.from_pydict({"a": [1, 2, 3, 4]})
+    catalog = SQLCatalog({"df": df})
+
+    actual = daft.sql(
+        "SELECT CASE a WHEN 1 THEN 'one' WHEN 2 THEN 'two' ELSE 'many' END AS r FROM df",
+        catalog=catalog,
+    ).to_pydict()
+
+    assert actual == {"r": ["one", "two", "many", "many"]}
+
+
+def test_case_without_else():
+    df = daft.from_pydict({"a": [1, 2, 3, 4]})
+    catalog = SQLCatalog({"df": df})
+
+    actual = daft.sql(
+        "SELECT CASE WHEN a > 2 THEN 'big' END AS r, CASE a WHEN 1 THEN a * 10 END AS s FROM df",
+        catalog=catalog,
+    ).to_pydict()
+
+    assert actual == {"r": [None, None, "big", "big"], "s": [10, None, None, None]}
+
+
+def test_any_all_list():
+    df = daft.from_pydict({"a": [1, 2, 3, 4], "l": [[1, 5], [3, 4], [], None]})
+    catalog = SQLCatalog({"df": df})
+
+    actual = daft.sql(
+        """
+    SELECT
+        a = ANY(l) AS eq_any,
+        a <> ALL(l) AS neq_all,
+        a < ALL(l) AS lt_all
+    FROM df
+    """,
+        catalog=catalog,
+    ).to_pydict()
+
+    assert actual == {
+        "eq_any": [True, False, False, None],
+        "neq_all": [False, True, True, None],
+        "lt_all": [False, True, True, None],
+    }
+
+    actual = daft.sql("SELECT a FROM df WHERE a >= ANY(l)", catalog=catalog).to_pydict()
+    assert actual == {"a": [1]}
+
+
+@pytest.mark.parametrize(
+    "predicate,expected",
+    [
+        ("a = ANY((SELECT b FROM u))", [2, 3]),
+        ("a < ANY((SELECT b FROM u))", [1, 2]),
+        ("a > ALL((SELECT b FROM u))", [4]),
+        ("a <> ALL((SELECT b FROM u))", [1, 4]),
+    ],
+)
+def test_any_all_subquery(predicate, expected):
+    t = daft.from_pydict({"a": [1, 2, 3, 4]})
+    u = daft.from_pydict({"b": [2, 3]})
+    catalog = SQLCatalog({"t": t, "u": u})
+
+    actual = daft.sql(f"SELECT a FROM t WHERE {predicate} ORDER BY a", catalog=catalog).to_pydict()
+
+    assert actual == {"a": expected}
+
+
+@pytest.mark.parametrize(
+    "predicate,expected",
+    [
+        ("a = ANY((SELECT b FROM u))", [2]),
+        ("a <> ALL((SELECT b FROM u))", []),
+        ("NOT a = ANY((SELECT b FROM u))", []),
+        ("a = ANY((SELECT b FROM u)) OR a = 3", [2, 3]),
+        ("a <> ALL((SELECT b FROM u WHERE b IS NOT NULL))", [1, 3]),
+    ],
+)
+def test_any_all_subquery_with_nulls_in_where(predicate, expected):
+    # a NULL in u leaves every comparison but a = 2 unknown, which filters the row out
+    t = daft.from_pydict({"a": [1, 2, 3]})
+    u = daft.from_pydict({"b": [2, None]})
+    catalog = SQLCatalog({"t": t, "u": u})
+
+    actual = daft.sql(f"SELECT a FROM t WHERE {predicate} ORDER BY a", catalog=catalog).to_pydict()
+
+    assert actual == {"a": expected}
+
+
+def test_any_all_list_with_nulls():
+    df = daft.from_pydict({"a": [2, 5, 2, None, 3], "l": [[1, None], [1, None], [2, None], [1], []]})
+    catalog = SQLCatalog({"df": df})
+
+    actual = daft.sql(
+        """
+    SELECT
+        a = ANY(l) AS eq_any,
+        a <> ALL(l) AS neq_all,
+        a > ALL(l) AS gt_all,
+        a > ANY(l) AS gt_any
+    FROM df
+    """,
+        catalog=catalog,
+    ).to_pydict()
+
+    assert actual == {
+        "eq_any": [None, None, True, None, False],
+        "neq_all": [None, None, False, None, True],
+        "gt_all": [None, None, False, None, True],
+        "gt_any": [True, True, None, None, False],
+    }
+
+
+def test_any_all_subquery_with_nulls():
+    t = daft.from_pydict({"a": [1, 2, 3]})
+    u = daft.from_pydict({"b": [2, None]})
+    empty = daft.from_pydict({"c": [1]}).where(daft.col("c") > 1)
+    catalog = SQLCatalog({"t": t, "u": u, "empty": empty})
+
+    actual = daft.sql(
+        """
+    SELECT
+        a,
+        a = ANY((SELECT b FROM u)) AS eq_any,
+        a <> ALL((SELECT b FROM u)) AS neq_all,
+        a >= ALL((SELECT b FROM u)) AS gte_all,
+        a = ANY((SELECT c FROM empty)) AS eq_any_empty,
+        a > ALL((SELECT c FROM empty)) AS gt_all_empty
+    FROM t
+    ORDER BY a
+    """,
+        catalog=catalog,
+    ).to_pydict()
+
+    assert actual == {
+        "a": [1, 2, 3],
+        "eq_any": [None, True, None],
+        "neq_all": [None, False, None],
+        "gte_all": [False, None, None],
+        "eq_any_empty": [False, False, False],
+        "gt_all_empty": [True, True, True],
+    }
+
+
+@pytest.mark.parametrize(
+    "predicate,expected",
+    [
+        ("a = ANY((SELECT b FROM u WHERE u.k = t.k))", [2]),
+        ("a > ANY((SELECT b FROM u WHERE u.k = t.k))", [3]),
+        ("a > ALL((SELECT b FROM u WHERE u.k = t.k))", [3, 4]),
+        ("a <> ALL((SELECT b FROM u WHERE u.k = t.k))", [1, 3, 4]),
+    ],
+)
+def test_any_all_correlated_subquery(predicate, expected):
+    # k = 2 has no rows in u, so ALL holds and ANY doesn't for a = 4
+    t = daft.from_pydict({"k": [0, 0, 1, 2], "a": [1, 2, 3, 4]})
+    u = daft.from_pydict({"k": [0, 0, 1], "b": [2, 5, 1]})
+    catalog = SQLCatalog({"t": t, "u": u})
+
+    actual = daft.sql(f"SELECT a FROM t WHERE {predicate} ORDER BY a", catalog=catalog).to_pydict()
+
+    assert actual == {"a": expected}
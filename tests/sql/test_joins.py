@@ -2,6 +2,7 @@ import pytest
 
 import daft
 from daft.sql import SQLCatalog
+from tests.conftest import get_tests_daft_runner_name
 
 
 def test_joins_using():
@@ -195,3 +196,103 @@ def test_cross_join():
         "C": [2, 4, 6, 8, 2, 4, 6, 8, 2, 4, 6, 8],
         "D": ["d", "e", "f", "g", "d", "e", "f", "g", "d", "e", "f", "g"],
     }
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="ASOF joins are only supported on the native runner"
+)
+def test_asof_join():
+    trades = daft.from_pydict({"time": [1, 3, 5], "ticker": ["A", "B", "A"], "price": [10, 20, 11]})
+    quotes = daft.from_pydict({"time": [0, 2, 4], "ticker": ["A", "B", "A"], "bid": [100, 200, 101]})
+
+    df_sql = daft.sql(
+        "select t.time, t.ticker, q.bid from trades t "
+        "asof join quotes q match_condition (t.time >= q.time) on t.ticker = q.ticker"
+    )
+    actual = df_sql.sort("time").to_pydict()
+
+    expected = trades.join_asof(quotes, on="time", by="ticker").select("time", "ticker", "bid").sort("time").to_pydict()
+
+    assert actual == expected
+    assert actual["bid"] == [100, 200, 101]
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="ASOF joins are only supported on the native runner"
+)
+def test_asof_join_forward_using():
+    df1 = daft.from_pydict({"ts": [1, 5, 10], "k": [1, 1, 1]})
+    df2 = daft.from_pydict({"ts": [2, 6], "k": [1, 1], "v": ["a", "b"]})
+
+    df_sql = daft.sql("select df1.ts, v from df1 asof join df2 match_condition (df2.ts >= df1.ts) using (k)")
+    actual = df_sql.sort("ts").to_pydict()
+
+    assert actual == {"ts": [1, 5, 10], "v": ["a", "b", None]}
+
+
+def test_join_on_range():
+    events = daft.from_pydict({"ts": [1, 5, 7, 12], "name": ["a", "b", "c", "d"]})
+    buckets = daft.from_pydict({"lo": [0, 5, 10], "hi": [4, 9, 14], "label": ["x", "y", "z"]})
+
+    df_sql = daft.sql("select name, label from events e join buckets b on e.ts between b.lo and b.hi")
+    actual = df_sql.sort("name").to_pydict()
+
+    assert actual == {"name": ["a", "b", "c", "d"], "label": ["x", "y", "y", "z"]}
+
+
+def test_join_on_inequality():
+    df1 = daft.from_pydict({"a": [1, 2, 3]})
+    df2 = daft.from_pydict({"b": [1, 2, 3]})
+
+    df_sql = daft.sql("select a, b from df1 join df2 on df1.a < df2.b")
+    actual = df_sql.sort(["a", "b"]).to_pydict()
+
+    assert actual == {"a": [1, 1, 2], "b": [2, 3, 3]}
+
+
+def test_join_on_key_and_inequality():
+    df1 = daft.from_pydict({"k": [1, 1, 2, 2], "v": [10, 20, 30, 40]})
+    df2 = daft.from_pydict({"k": [1, 2], "threshold": [15, 35]})
+
+    df_sql = daft.sql("select df1.k, v from df1 join df2 on df1.k = df2.k and df1.v > df2.threshold")
+    actual = df_sql.sort("v").to_pydict()
+
+    assert actual == {"k": [1, 2], "v": [20, 40]}
+
+
+def test_join_on_expression_over_both_tables():
+    df1 = daft.from_pydict({"x": [1, 2, 3], "y": [1, 1, 1]})
+    df2 = daft.from_pydict({"z": [2, 3, 5]})
+
+    df_sql = daft.sql("select x, z from df1 join df2 on df1.x + df1.y = df2.z")
+    actual = df_sql.sort("x").to_pydict()
+
+    assert actual == {"x": [1, 2], "z": [2, 3]}
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native",
+    reason="Outer joins with non-equi predicates are only supported on the native runner",
+)
+def test_left_join_on_range():
+    events = daft.from_pydict({"ts": [1, 5, 20]})
+    buckets = daft.from_pydict({"lo": [0, 5], "hi": [4, 9], "label": ["x", "y"]})
+
+    df_sql = daft.sql("select ts, label from events left join buckets on ts >= lo and ts <= hi")
+    actual = df_sql.sort("ts").to_pydict()
+
+    assert actual == {"ts": [1, 5, 20], "label": ["x", "y", None]}
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native",
+    reason="Outer joins with non-equi predicates are only supported on the native runner",
+)
+def test_full_outer_join_on_inequality():
+    df1 = daft.from_pydict({"a": [1, 5]})
+    df2 = daft.from_pydict({"b": [3, 0]})
+
+    df_sql = daft.sql("select a, b from df1 full outer join df2 on df1.a > df2.b + 2")
+    actual = df_sql.sort(["a", "b"]).to_pydict()
+
+    assert actual == {"a": [1, 5, None], "b": [None, 0, 3]}
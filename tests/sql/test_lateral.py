@@ -0,0 +1,110 @@
+import pytest
+
+import daft
+from daft import col
+from daft.sql.sql import SQLCatalog
+
+depts = daft.from_pydict({"id": [1, 2, 3], "name": ["eng", "ops", "hr"]})
+emps = daft.from_pydict(
+    {
+        "emp": ["a", "b", "c", "d", "e"],
+        "dept": [1, 1, 1, 2, 2],
+        "salary": [100, 300, 200, 50, 80],
+    }
+)
+
+
+def numbers(n, start=0):
+    return daft.from_pydict({"n": list(range(start, start + n))})
+
+
+def top_earners(dept, n):
+    return emps.where(col("dept") == dept).sort("salary", desc=True).limit(n)
+
+
+def sorted_pydict(df, by, desc=False):
+    return df.sort(by, desc=desc).to_pydict()
+
+
+def test_lateral_top_n_per_group():
+    actual = daft.sql(
+        """
+        SELECT d.name, e.emp, e.salary
+        FROM depts d, LATERAL (
+            SELECT emp, salary FROM emps WHERE emps.dept = d.id ORDER BY salary DESC LIMIT 2
+        ) e
+        """
+    )
+    assert sorted_pydict(actual, ["name", "salary"], desc=[False, True]) == {
+        "name": ["eng", "eng", "ops", "ops"],
+        "emp": ["b", "c", "e", "d"],
+        "salary": [300, 200, 80, 50],
+    }
+
+
+def test_cross_join_lateral():
+    actual = daft.sql(
+        """
+        SELECT d.name, e.emp
+        FROM depts d CROSS JOIN LATERAL (
+            SELECT emp FROM emps WHERE dept = d.id ORDER BY salary LIMIT 1
+        ) e
+        """
+    )
+    assert sorted_pydict(actual, "name") == {"name": ["eng", "ops"], "emp": ["a", "d"]}
+
+
+def test_left_join_lateral():
+    actual = daft.sql(
+        """
+        SELECT d.name, e.emp
+        FROM depts d LEFT JOIN LATERAL (
+            SELECT emp FROM emps WHERE dept = d.id ORDER BY salary DESC LIMIT 1
+        ) e ON TRUE
+        """
+    )
+    assert sorted_pydict(actual, "name") == {"name": ["eng", "hr", "ops"], "emp": ["b", None, "e"]}
+
+
+def test_lateral_aggregate():
+    actual = daft.sql(
+        """
+        SELECT d.name, s.total
+        FROM depts d JOIN LATERAL (SELECT SUM(salary) AS total FROM emps WHERE dept = d.id) s ON TRUE
+        """
+    )
+    assert sorted_pydict(actual, "name") == {"name": ["eng", "ops"], "total": [600, 130]}
+
+
+def test_lateral_uncorrelated():
+    actual = daft.sql("SELECT d.name, m.top FROM depts d, LATERAL (SELECT MAX(salary) AS top FROM emps) m")
+    assert sorted_pydict(actual, "name") == {"name": ["eng", "hr", "ops"], "top": [300, 300, 300]}
+
+
+def test_lateral_join_constraint_unsupported():
+    with pytest.raises(Exception, match="LATERAL joins only support"):
+        daft.sql("SELECT * FROM depts d JOIN LATERAL (SELECT emp FROM emps WHERE dept = d.id) e ON e.emp = d.name")
+
+
+def test_registered_table_function():
+    catalog = SQLCatalog({"numbers": numbers})
+    assert daft.sql("SELECT * FROM numbers(3)", catalog=catalog).to_pydict() == {"n": [0, 1, 2]}
+    assert daft.sql("SELECT * FROM numbers(2, start => 5)", catalog=catalog).to_pydict() == {"n": [5, 6]}
+    assert daft.sql("SELECT * FROM TABLE(numbers(1)) AS t", catalog=catalog).to_pydict() == {"n": [0]}
+
+
+def test_registered_table_function_cross_join():
+    catalog = SQLCatalog({"numbers": numbers})
+    actual = daft.sql("SELECT d.name, t.n FROM depts d, numbers(2) t WHERE d.id = 1", catalog=catalog)
+    assert sorted_pydict(actual, "n") == {"name": ["eng", "eng"], "n": [0, 1]}
+
+
+def test_lateral_registered_table_function():
+    catalog = SQLCatalog({"top_earners": top_earners})
+    actual = daft.sql("SELECT d.name, t.emp FROM depts d, LATERAL top_earners(d.id, 1) t", catalog=catalog)
+    assert sorted_pydict(actual, "name") == {"name": ["eng", "ops"], "emp": ["b", "e"]}
+
+
+def test_table_function_not_found():
+    with pytest.raises(Exception, match="Function `unknown` not found"):
+        daft.sql("SELECT * FROM unknown(1)")
@@ -0,0 +1,115 @@
+import pytest
+
+import daft
+
+sales = daft.from_pydict(
+    {
+        "store": ["A", "A", "A", "B", "B"],
+        "quarter": ["q1", "q2", "q2", "q1", "q3"],
+        "amount": [10, 20, 5, 30, 40],
+    }
+)
+
+wide = daft.from_pydict(
+    {
+        "store": ["A", "B"],
+        "q1": [10, 30],
+        "q2": [25, None],
+    }
+)
+
+
+def test_pivot():
+    actual = daft.sql("SELECT * FROM sales PIVOT (SUM(amount) FOR quarter IN ('q1', 'q2'))")
+    assert actual.sort("store").to_pydict() == {
+        "store": ["A", "B"],
+        "q1": [10, 30],
+        "q2": [25, None],
+    }
+
+
+def test_pivot_with_aliases():
+    actual = daft.sql("SELECT * FROM sales PIVOT (MAX(amount) FOR quarter IN ('q1' AS first, 'q3' AS third)) AS p")
+    assert actual.sort("store").to_pydict() == {
+        "store": ["A", "B"],
+        "first": [10, 30],
+        "third": [None, 40],
+    }
+
+
+def test_pivot_default_on_null():
+    actual = daft.sql("SELECT * FROM sales PIVOT (SUM(amount) FOR quarter IN ('q2', 'q3') DEFAULT ON NULL (0))")
+    assert actual.sort("store").to_pydict() == {
+        "store": ["A", "B"],
+        "q2": [25, 0],
+        "q3": [0, 40],
+    }
+
+
+def test_pivot_numeric_values():
+    df = daft.from_pydict({"k": ["x", "x", "y"], "year": [2023, 2024, 2024], "v": [1, 2, 3]})
+    actual = daft.sql("SELECT * FROM df PIVOT (SUM(v) FOR year IN (2023, 2024))")
+    assert actual.sort("k").to_pydict() == {
+        "k": ["x", "y"],
+        "2023": [1, None],
+        "2024": [2, 3],
+    }
+
+
+def test_pivot_any():
+    actual = daft.sql("SELECT * FROM sales PIVOT (SUM(amount) FOR quarter IN (ANY))")
+    assert actual.column_names == ["store", "q1", "q2", "q3"]
+    assert actual.sort("store").to_pydict() == {
+        "store": ["A", "B"],
+        "q1": [10, 30],
+        "q2": [25, None],
+        "q3": [None, 40],
+    }
+
+
+def test_pivot_any_order_by():
+    actual = daft.sql("SELECT * FROM sales PIVOT (SUM(amount) FOR quarter IN (ANY ORDER BY quarter DESC))")
+    assert actual.column_names == ["store", "q3", "q2", "q1"]
+
+
+def test_pivot_subquery():
+    actual = daft.sql(
+        "SELECT * FROM sales PIVOT (SUM(amount) FOR quarter IN (SELECT quarter FROM sales WHERE amount > 15))"
+    )
+    assert actual.sort("store").to_pydict() == {
+        "store": ["A", "B"],
+        "q2": [25, None],
+        "q3": [None, 40],
+    }
+
+
+def test_pivot_errors():
+    with pytest.raises(Exception, match="PIVOT with multiple aggregate functions"):
+        daft.sql("SELECT * FROM sales PIVOT (SUM(amount), MAX(amount) FOR quarter IN ('q1'))")
+    with pytest.raises(Exception, match="PIVOT expects an aggregate function"):
+        daft.sql("SELECT * FROM sales PIVOT (abs(amount) FOR quarter IN ('q1'))")
+
+
+def test_unpivot():
+    actual = daft.sql("SELECT * FROM wide UNPIVOT (amount FOR quarter IN (q1, q2))")
+    assert actual.column_names == ["store", "quarter", "amount"]
+    assert actual.sort(["store", "quarter"]).to_pydict() == {
+        "store": ["A", "A", "B"],
+        "quarter": ["q1", "q2", "q1"],
+        "amount": [10, 25, 30],
+    }
+
+
+def test_unpivot_with_alias():
+    actual = daft.sql("SELECT u.quarter, u.amount FROM wide UNPIVOT (amount FOR quarter IN (q2)) AS u")
+    assert actual.to_pydict() == {"quarter": ["q2"], "amount": [25]}
+
+
+def test_pivot_roundtrip():
+    actual = daft.sql(
+        """
+        SELECT * FROM (SELECT * FROM wide UNPIVOT (amount FOR quarter IN (q1, q2)))
+        PIVOT (SUM(amount) FOR quarter IN ('q1', 'q2'))
+        """
+    )
+    assert actual.sort("store").to_pydict() == wide.to_pydict()
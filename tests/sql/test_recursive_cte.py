@@ -0,0 +1,129 @@
+import pytest
+
+import daft
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Recursive CTEs are only supported on the native runner"
+)
+
+employees = daft.from_pydict(
+    {
+        "id": [1, 2, 3, 4, 5],
+        "name": ["ceo", "cto", "cfo", "engineer", "intern"],
+        "manager_id": [None, 1, 1, 2, 4],
+    }
+)
+
+edges = daft.from_pydict(
+    {
+        "src": [1, 2, 3, 3, 5],
+        "dst": [2, 3, 1, 4, 6],
+    }
+)
+
+chain = daft.from_pydict({"src": list(range(10)), "dst": list(range(1, 11))})
+
+
+def test_recursive_cte_counter():
+    actual = daft.sql(
+        """
+        WITH RECURSIVE t(n) AS (
+            SELECT 1
+            UNION ALL
+            SELECT n + 1 FROM t WHERE n < 5
+        )
+        SELECT n FROM t
+        """
+    )
+    assert actual.sort("n").to_pydict() == {"n": [1, 2, 3, 4, 5]}
+
+
+def test_recursive_cte_ancestors():
+    actual = daft.sql(
+        """
+        WITH RECURSIVE chain AS (
+            SELECT id, name, manager_id, 0 AS depth FROM employees WHERE name = 'intern'
+            UNION ALL
+            SELECT e.id, e.name, e.manager_id, chain.depth + 1
+            FROM employees e JOIN chain ON e.id = chain.manager_id
+        )
+        SELECT name, depth FROM chain
+        """
+    )
+    assert actual.sort("depth").to_pydict() == {
+        "name": ["intern", "engineer", "cto", "ceo"],
+        "depth": [0, 1, 2, 3],
+    }
+
+
+def test_recursive_cte_join_many_iterations():
+    # Every iteration runs the recursive term's join again, so its build side is rebuilt each time.
+    actual = daft.sql(
+        """
+        WITH RECURSIVE path(node, hops) AS (
+            SELECT CAST(0 AS BIGINT), 0
+            UNION ALL
+            SELECT chain.dst, path.hops + 1 FROM chain JOIN path ON chain.src = path.node
+        )
+        SELECT node, hops FROM path
+        """
+    )
+    assert actual.sort("node").to_pydict() == {"node": list(range(11)), "hops": list(range(11))}
+
+
+def test_recursive_cte_reachability_with_cycle():
+    # UNION discards rows which were already produced, so the cycle 1 -> 2 -> 3 -> 1 terminates.
+    actual = daft.sql(
+        """
+        WITH RECURSIVE reachable(node) AS (
+            SELECT CAST(1 AS BIGINT)
+            UNION
+            SELECT edges.dst FROM edges JOIN reachable ON edges.src = reachable.node
+        )
+        SELECT node FROM reachable
+        """
+    )
+    assert actual.sort("node").to_pydict() == {"node": [1, 2, 3, 4]}
+
+
+def test_recursive_cte_with_non_recursive_cte():
+    actual = daft.sql(
+        """
+        WITH RECURSIVE roots AS (
+            SELECT id FROM employees WHERE manager_id IS NULL
+        ), tree AS (
+            SELECT id, 0 AS level FROM roots
+            UNION ALL
+            SELECT e.id, tree.level + 1 FROM employees e JOIN tree ON e.manager_id = tree.id
+        )
+        SELECT level, COUNT(id) AS n FROM tree GROUP BY level
+        """
+    )
+    assert actual.sort("level").to_pydict() == {"level": [0, 1, 2, 3], "n": [1, 2, 1, 1]}
+
+
+def test_recursive_cte_max_iterations():
+    query = """
+        WITH RECURSIVE t(n) AS (
+            SELECT 1
+            UNION ALL
+            SELECT n + 1 FROM t
+        )
+        SELECT n FROM t
+    """
+    with daft.execution_config_ctx(recursive_cte_max_iterations=10):
+        with pytest.raises(Exception, match="did not terminate after 10 iterations"):
+            daft.sql(query).collect()
+
+
+def test_recursive_cte_order_by_unsupported():
+    with pytest.raises(Exception, match="not supported in recursive CTEs"):
+        daft.sql(
+            """
+            WITH RECURSIVE t(n) AS (
+                SELECT 1 UNION ALL SELECT n + 1 FROM t WHERE n < 3 ORDER BY n
+            )
+            SELECT * FROM t
+            """
+        )
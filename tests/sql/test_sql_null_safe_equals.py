@@ -104,3 +104,32 @@ def test_null_safe_equals_types(query, expected):
     catalog = SQLCatalog({"df": df})
     result = daft.sql(query, catalog).to_pydict()
     assert result == expected
+
+
+@pytest.mark.parametrize(
+    "query,expected",
+    [
+        ("SELECT * FROM df1 WHERE val IS NOT DISTINCT FROM 20", {"id": [2], "val": [20]}),
+        ("SELECT * FROM df1 WHERE val IS NOT DISTINCT FROM NULL", {"id": [3], "val": [None]}),
+        (
+            "SELECT * FROM df1 WHERE val IS DISTINCT FROM NULL",
+            {"id": [1, 2, None], "val": [10, 20, 40]},
+        ),
+        (
+            "SELECT * FROM df1 WHERE id IS DISTINCT FROM 1",
+            {"id": [2, 3, None], "val": [20, None, 40]},
+        ),
+        (
+            "SELECT df1.id, df1.val, df2.score FROM df1 JOIN df2 ON df1.id IS NOT DISTINCT FROM df2.id",
+            {"id": [1, 2, None], "val": [10, 20, 40], "score": [0.1, 0.2, 0.3]},
+        ),
+    ],
+)
+def test_is_distinct_from(query, expected):
+    """Test IS [NOT] DISTINCT FROM, the standard spelling of null-safe (in)equality."""
+    df1 = daft.from_pydict({"id": [1, 2, 3, None], "val": [10, 20, None, 40]})
+    df2 = daft.from_pydict({"id": [1, 2, None, 4], "score": [0.1, 0.2, 0.3, 0.4]})
+
+    catalog = SQLCatalog({"df1": df1, "df2": df2})
+    result = daft.sql(query, catalog).sort("id", nulls_first=False).to_pydict()
+    assert result == expected
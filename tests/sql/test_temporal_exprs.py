@@ -1,5 +1,7 @@
 import datetime
 
+import pytest
+
 import daft
 from daft.sql.sql import SQLCatalog
 
@@ -96,3 +98,54 @@ def test_date_comparison():
     expected = date_df.filter(daft.col("date") == "2020-01-01").select("date").to_pydict()
     actual = daft.sql("select date from date_df where date == '2020-01-01'").to_pydict()
     assert actual == expected
+
+
+def test_at_time_zone():
+    df = daft.from_pydict(
+        {
+            "ts": [
+                datetime.datetime(2021, 1, 1, 12, 0),
+                datetime.datetime(2021, 7, 1, 12, 0),
+                None,
+            ]
+        }
+    )
+
+    actual = daft.sql(
+        """
+    SELECT
+        (ts AT TIME ZONE 'America/New_York') AT TIME ZONE 'UTC' as new_york_to_utc,
+        (ts AT TIME ZONE 'UTC') AT TIME ZONE 'Asia/Tokyo' as utc_to_tokyo,
+    FROM df
+    """
+    ).to_pydict()
+
+    assert actual == {
+        "new_york_to_utc": [datetime.datetime(2021, 1, 1, 17, 0), datetime.datetime(2021, 7, 1, 16, 0), None],
+        "utc_to_tokyo": [datetime.datetime(2021, 1, 1, 21, 0), datetime.datetime(2021, 7, 1, 21, 0), None],
+    }
+
+
+def test_at_time_zone_across_dst_transitions():
+    df = daft.from_pydict(
+        {
+            "ts": [
+                # skipped when the clocks go forward, so shifted forward by the length of the gap
+                datetime.datetime(2021, 3, 14, 2, 30),
+                # repeated when the clocks go back, so resolved to its earliest occurrence
+                datetime.datetime(2021, 11, 7, 1, 30),
+            ]
+        }
+    )
+
+    actual = daft.sql("SELECT (ts AT TIME ZONE 'America/New_York') AT TIME ZONE 'UTC' AS utc FROM df").to_pydict()
+
+    assert actual == {"utc": [datetime.datetime(2021, 3, 14, 7, 30), datetime.datetime(2021, 11, 7, 5, 30)]}
+
+
+def test_at_time_zone_invalid_time_zone():
+    df = daft.from_pydict({"ts": [datetime.datetime(2021, 1, 1, 12, 0)]})
+
+    # the time zone is validated when the query is planned rather than when it runs
+    with pytest.raises(Exception, match="Cannot parse timezone: Mars/Olympus_Mons"):
+        daft.sql("SELECT ts AT TIME ZONE 'Mars/Olympus_Mons' AS ts FROM df")
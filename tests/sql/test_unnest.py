@@ -0,0 +1,67 @@
+import pytest
+
+import daft
+
+
+def test_unnest():
+    df = daft.sql("SELECT * FROM UNNEST([1, 2, 3])")
+    assert df.to_pydict() == {"unnest": [1, 2, 3]}
+
+
+def test_unnest_with_alias():
+    df = daft.sql("SELECT t.x FROM UNNEST([1, 2, 3]) AS t(x) WHERE t.x > 1")
+    assert df.to_pydict() == {"x": [2, 3]}
+
+
+def test_unnest_with_ordinality():
+    df = daft.sql("SELECT * FROM UNNEST(['a', 'b', 'c']) WITH ORDINALITY AS t(x, n)")
+    assert df.to_pydict() == {"x": ["a", "b", "c"], "n": [1, 2, 3]}
+
+
+def test_unnest_multiple_lists():
+    df = daft.sql("SELECT * FROM UNNEST([1, 2], ['a', 'b'])")
+    assert df.to_pydict() == {"unnest_1": [1, 2], "unnest_2": ["a", "b"]}
+
+
+def test_unnest_pads_shorter_lists_with_nulls():
+    df = daft.sql("SELECT * FROM UNNEST([1, 2, 3], ['a']) WITH ORDINALITY AS t(x, y, n)")
+    assert df.to_pydict() == {"x": [1, 2, 3], "y": ["a", None, None], "n": [1, 2, 3]}
+
+
+def test_cross_join_unnest_multiple_lists():
+    df = daft.from_pydict({"id": [1, 2, 3], "xs": [[1], [], None], "ys": [["a", "b"], ["c"], None]})
+    actual = daft.sql("SELECT id, x, y FROM df CROSS JOIN UNNEST(xs, ys) AS t(x, y)")
+    assert actual.sort(["id", "y"]).to_pydict() == {"id": [1, 1, 2], "x": [1, None, None], "y": ["a", "b", "c"]}
+
+
+def test_cross_join_unnest_name_conflict():
+    df = daft.from_pydict({"id": [1], "tags": [["a"]]})
+    with pytest.raises(Exception, match="UNNEST column id conflicts with a column of the same name"):
+        daft.sql("SELECT * FROM df CROSS JOIN UNNEST(tags) AS t(id)")
+
+
+def test_cross_join_unnest():
+    df = daft.from_pydict({"id": [1, 2, 3], "tags": [["a", "b"], [], None]})
+    actual = daft.sql("SELECT id, tag FROM df CROSS JOIN UNNEST(tags) AS t(tag)")
+    assert actual.sort(["id", "tag"]).to_pydict() == {"id": [1, 1], "tag": ["a", "b"]}
+
+
+def test_cross_join_unnest_with_ordinality():
+    df = daft.from_pydict({"id": [1, 2], "tags": [["a", "b", "c"], ["d", "e"]]})
+    actual = daft.sql("SELECT id, tag, pos FROM df CROSS JOIN UNNEST(df.tags) WITH ORDINALITY AS t(tag, pos)")
+    assert actual.sort(["id", "pos"]).to_pydict() == {
+        "id": [1, 1, 1, 2, 2],
+        "tag": ["a", "b", "c", "d", "e"],
+        "pos": [1, 2, 3, 1, 2],
+    }
+
+
+def test_comma_join_unnest():
+    df = daft.from_pydict({"id": [1, 2], "tags": [["a"], ["b", "c"]]})
+    actual = daft.sql("SELECT id, unnest FROM df, UNNEST(tags)")
+    assert actual.sort(["id", "unnest"]).to_pydict() == {"id": [1, 2, 2], "unnest": ["a", "b", "c"]}
+
+
+def test_unnest_alias_mismatch():
+    with pytest.raises(Exception, match="Column count mismatch: expected 1 columns, found 2"):
+        daft.sql("SELECT * FROM UNNEST([1, 2]) AS t(x, y)")
@@ -120,3 +120,89 @@ def test_utf8_exprs():
     )
     actual = actual.to_pydict()
     assert actual == expected
+
+
+def test_utf8_special_syntax():
+    df = daft.from_pydict({"a": ["xxfooxx", "  bar  ", "baz", None]})
+
+    actual = daft.sql(
+        """
+    SELECT
+        TRIM(a) as trim_a,
+        TRIM(LEADING ' ' FROM a) as ltrim_a,
+        TRIM(BOTH 'x' FROM a) as trim_x_a,
+        TRIM(LEADING 'x' FROM a) as ltrim_x_a,
+        TRIM(TRAILING 'x' FROM a) as rtrim_x_a,
+        POSITION('a' IN a) as position_a,
+        SUBSTRING(a FROM 2 FOR 3) as substring_from_for_a,
+        SUBSTRING(a FROM 3) as substring_from_a,
+        SUBSTRING(a, 2) as substring_a,
+        OVERLAY(a PLACING 'ZZ' FROM 2) as overlay_a,
+        OVERLAY(a PLACING 'ZZ' FROM 1 FOR 1) as overlay_for_a,
+    FROM df
+    """
+    ).to_pydict()
+
+    assert actual == {
+        "trim_a": ["xxfooxx", "bar", "baz", None],
+        "ltrim_a": ["xxfooxx", "bar  ", "baz", None],
+        "trim_x_a": ["foo", "  bar  ", "baz", None],
+        "ltrim_x_a": ["fooxx", "  bar  ", "baz", None],
+        "rtrim_x_a": ["xxfoo", "  bar  ", "baz", None],
+        "position_a": [0, 4, 2, None],
+        "substring_from_for_a": ["xfo", " ba", "az", None],
+        "substring_from_a": ["fooxx", "bar  ", "z", None],
+        "substring_a": ["xfooxx", " bar  ", "az", None],
+        "overlay_a": ["xZZooxx", " ZZar  ", "bZZ", None],
+        "overlay_for_a": ["ZZxfooxx", "ZZ bar  ", "ZZaz", None],
+    }
+
+
+def test_utf8_position_counts_characters():
+    df = daft.from_pydict({"a": ["éb", "日本語", "naïve café", None]})
+
+    actual = daft.sql(
+        """
+    SELECT
+        POSITION('b' IN a) as position_b,
+        POSITION('語' IN a) as position_kanji,
+        POSITION('café' IN a) as position_cafe,
+    FROM df
+    """
+    ).to_pydict()
+
+    assert actual == {
+        "position_b": [2, 0, 0, None],
+        "position_kanji": [0, 3, 0, None],
+        "position_cafe": [0, 0, 7, None],
+    }
+
+
+def test_utf8_pattern_matching():
+    df = daft.from_pydict({"a": ["100%", "1000", "a.b", "axb", "abc", "ABC", None]})
+
+    actual = daft.sql(
+        """
+    SELECT
+        a LIKE '100!%' ESCAPE '!' as like_escape_a,
+        a ILIKE 'A_%' ESCAPE '#' as ilike_escape_a,
+        a LIKE 'a#.b' ESCAPE '#' as like_escape_dot_a,
+        a SIMILAR TO '(a|b)%' as similar_a,
+        a SIMILAR TO 'a.b' as similar_dot_a,
+        a NOT SIMILAR TO '[0-9]+%?' as not_similar_a,
+        a RLIKE '^a.b$' as rlike_a,
+        a NOT REGEXP '[0-9]' as not_regexp_a,
+    FROM df
+    """
+    ).to_pydict()
+
+    assert actual == {
+        "like_escape_a": [True, False, False, False, False, False, None],
+        "ilike_escape_a": [False, False, True, True, True, True, None],
+        "like_escape_dot_a": [False, False, True, False, False, False, None],
+        "similar_a": [False, False, True, True, True, False, None],
+        "similar_dot_a": [False, False, True, False, False, False, None],
+        "not_similar_a": [False, False, True, True, True, True, None],
+        "rlike_a": [False, False, True, True, False, False, None],
+        "not_regexp_a": [False, False, True, True, True, True, None],
+    }
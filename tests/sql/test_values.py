@@ -0,0 +1,45 @@
+import pytest
+
+import daft
+
+
+def test_values():
+    df = daft.sql("VALUES (1, 'a'), (2, 'b'), (3, NULL)")
+    assert df.to_pydict() == {"column1": [1, 2, 3], "column2": ["a", "b", None]}
+
+
+def test_values_with_alias():
+    df = daft.sql("SELECT id, name FROM (VALUES (1, 'a'), (2, 'b')) AS t(id, name) WHERE id > 1")
+    assert df.to_pydict() == {"id": [2], "name": ["b"]}
+
+
+def test_values_supertype():
+    df = daft.sql("VALUES (1), (2.5), (NULL)")
+    assert df.schema()["column1"].dtype == daft.DataType.float64()
+    assert df.to_pydict() == {"column1": [1.0, 2.5, None]}
+
+
+def test_values_expressions():
+    df = daft.sql("VALUES (1 + 1, upper('a')), (-3, lower('BC'))")
+    assert df.to_pydict() == {"column1": [2, -3], "column2": ["A", "bc"]}
+
+
+def test_values_order_by_and_limit():
+    df = daft.sql("VALUES (3), (1), (2) ORDER BY column1 DESC LIMIT 2")
+    assert df.to_pydict() == {"column1": [3, 2]}
+
+
+def test_values_as_lookup_table():
+    df = daft.from_pydict({"code": ["a", "b", "c"]})
+    actual = daft.sql(
+        """
+        WITH lookup AS (SELECT * FROM (VALUES ('a', 'apple'), ('b', 'banana')) AS t(code, name))
+        SELECT df.code, lookup.name FROM df LEFT JOIN lookup ON df.code = lookup.code ORDER BY df.code
+        """
+    )
+    assert actual.to_pydict() == {"code": ["a", "b", "c"], "name": ["apple", "banana", None]}
+
+
+def test_values_mismatched_rows():
+    with pytest.raises(Exception, match="same number of values"):
+        daft.sql("VALUES (1, 2), (3)")
@@ -0,0 +1,75 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native",
+    reason="Window functions are currently only supported on the native runner",
+)
+
+
+@pytest.fixture
+def df():
+    return daft.from_pydict(
+        {
+            "store": ["a", "a", "a", "b", "b"],
+            "day": [1, 2, 3, 1, 2],
+            "sales": [10, 20, 30, 5, 15],
+        }
+    )
+
+
+def test_sql_window_agg(df):
+    actual = daft.sql(
+        """
+        SELECT
+            store,
+            day,
+            sum(sales) OVER (PARTITION BY store) AS total,
+            sum(sales) OVER (PARTITION BY store ORDER BY day) AS running,
+            avg(sales) OVER (PARTITION BY store ORDER BY day ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) AS moving
+        FROM df
+        ORDER BY store, day
+        """
+    ).to_pydict()
+    assert actual == {
+        "store": ["a", "a", "a", "b", "b"],
+        "day": [1, 2, 3, 1, 2],
+        "total": [60, 60, 60, 20, 20],
+        "running": [10, 30, 60, 5, 20],
+        "moving": [10.0, 15.0, 25.0, 5.0, 10.0],
+    }
+
+
+def test_sql_window_ranking_and_offsets(df):
+    actual = daft.sql(
+        """
+        SELECT
+            store,
+            sales,
+            row_number() OVER (PARTITION BY store ORDER BY sales DESC) AS rn,
+            rank() OVER (ORDER BY store) AS rk,
+            dense_rank() OVER (ORDER BY store) AS drk,
+            lag(sales) OVER (PARTITION BY store ORDER BY day) AS prev,
+            lead(sales, 1, 0) OVER (PARTITION BY store ORDER BY day) AS next
+        FROM df
+        ORDER BY store, sales
+        """
+    ).to_pydict()
+    assert actual == {
+        "store": ["a", "a", "a", "b", "b"],
+        "sales": [10, 20, 30, 5, 15],
+        "rn": [3, 2, 1, 2, 1],
+        "rk": [1, 1, 1, 4, 4],
+        "drk": [1, 1, 1, 2, 2],
+        "prev": [None, 10, 20, None, 5],
+        "next": [20, 30, 0, 15, 0],
+    }
+
+
+def test_sql_named_window_unsupported(df):
+    with pytest.raises(Exception, match="(?i)window"):
+        daft.sql("SELECT sum(sales) OVER w FROM df WINDOW w AS (PARTITION BY store)").collect()